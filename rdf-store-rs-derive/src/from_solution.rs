@@ -0,0 +1,58 @@
+// Copyright (c) 2018-2023, agnos.ai UK Ltd, all rights reserved.
+//---------------------------------------------------------------
+//! `#[derive(FromSolution)]`
+
+use {
+    crate::{field_name, named_fields},
+    proc_macro2::TokenStream,
+    quote::quote,
+    syn::{DeriveInput, Expr, Field, LitStr},
+};
+
+pub(crate) fn from_solution(input: &DeriveInput) -> syn::Result<TokenStream> {
+    let fields = named_fields(input, "FromSolution")?;
+    let fields = fields
+        .iter()
+        .map(field_from_solution)
+        .collect::<syn::Result<Vec<_>>>()?;
+    let name = &input.ident;
+    let (impl_generics, type_generics, where_clause) = input.generics.split_for_impl();
+    Ok(quote! {
+        impl #impl_generics ::rdf_store_rs::sparql::FromSolution for #name #type_generics
+        #where_clause
+        {
+            fn from_solution(
+                solution: &::rdf_store_rs::sparql::Solution,
+            ) -> ::std::result::Result<Self, ::rdf_store_rs::RDFStoreError> {
+                ::std::result::Result::Ok(Self { #(#fields,)* })
+            }
+        }
+    })
+}
+
+/// The initialization of a field from the variable it is bound to.
+fn field_from_solution(field: &Field) -> syn::Result<TokenStream> {
+    let ident = field.ident.as_ref().unwrap();
+    let mut variable = field_name(ident);
+    let mut namespace: Option<Expr> = None;
+    for attribute in field.attrs.iter() {
+        if !attribute.path().is_ident("solution") {
+            continue
+        }
+        attribute.parse_nested_meta(|meta| {
+            if meta.path.is_ident("rename") {
+                variable = meta.value()?.parse::<LitStr>()?.value();
+                Ok(())
+            } else if meta.path.is_ident("namespace") {
+                namespace = Some(meta.value()?.parse::<Expr>()?);
+                Ok(())
+            } else {
+                Err(meta.error("expected `rename` or `namespace`"))
+            }
+        })?;
+    }
+    Ok(match namespace {
+        Some(namespace) => quote! { #ident: solution.get_local_name(#variable, &(#namespace))? },
+        None => quote! { #ident: solution.get_value(#variable)? },
+    })
+}
@@ -0,0 +1,166 @@
+// Copyright (c) 2018-2023, agnos.ai UK Ltd, all rights reserved.
+//---------------------------------------------------------------
+//! Derive macros for [rdf-store-rs](https://docs.rs/rdf-store-rs), which
+//! re-exports them with its `derive` feature.
+//!
+//! # `#[derive(FromSolution)]`
+//!
+//! Reads a struct from a query solution, with a field for each projected
+//! variable of the same name:
+//!
+//! ```rust
+//! use {
+//!     rdf_store_rs::{sparql::Solution, Namespace},
+//!     rdf_store_rs_derive::FromSolution,
+//! };
+//!
+//! fn rdf() -> Namespace {
+//!     Namespace::declare_from_str_unchecked(
+//!         "rdf:",
+//!         "http://www.w3.org/1999/02/22-rdf-syntax-ns#",
+//!     )
+//! }
+//!
+//! #[derive(FromSolution)]
+//! struct Person {
+//!     name:   String,
+//!     #[solution(rename = "yearsOld")]
+//!     age:    Option<u8>,
+//!     #[solution(namespace = rdf())]
+//!     r#type: String,
+//! }
+//! ```
+//!
+//! Each field is read with `FromLiteral`, so an unbound variable is only
+//! allowed for an `Option` field. The field attribute `#[solution(...)]`
+//! takes:
+//!
+//! - `rename = "name"`: the name of the variable, without `?`, if it is not the
+//!   name of the field.
+//! - `namespace = expression`: the variable is bound to an IRI in the given
+//!   `Namespace` and the field is read from its local name, so `rdf:type`
+//!   becomes `"type"`.
+//!
+//! # `#[derive(ToTriples)]`
+//!
+//! Writes a struct as triples about a subject, with a triple for each field
+//! that has a predicate:
+//!
+//! ```rust
+//! use {
+//!     rdf_store_rs::{vocab::foaf, Class, Predicate, Term, ToTriples},
+//!     rdf_store_rs_derive::ToTriples,
+//! };
+//!
+//! #[derive(ToTriples)]
+//! struct Address {
+//!     #[triples(predicate = Predicate::declare(foaf::NAMESPACE.clone(), "based_near"))]
+//!     city: String,
+//! }
+//!
+//! #[derive(ToTriples)]
+//! #[triples(
+//!     class = Class::declare(foaf::NAMESPACE.clone(), "Person"),
+//!     subject = "https://ekgf.org/person/{id}"
+//! )]
+//! struct Person {
+//!     id:      u32,
+//!     #[triples(predicate = Predicate::declare(foaf::NAMESPACE.clone(), "name"))]
+//!     name:    String,
+//!     #[triples(predicate = Predicate::declare(foaf::NAMESPACE.clone(), "address"), nested)]
+//!     address: Option<Address>,
+//! }
+//!
+//! let person = Person {
+//!     id:      42,
+//!     name:    "Alice".to_string(),
+//!     address: Some(Address { city: "London".to_string() }),
+//! };
+//! let mut triples: Vec<[Term; 3]> = Vec::new();
+//! let subject = person.to_triples(&mut triples).unwrap();
+//! assert_eq!(
+//!     subject,
+//!     Term::new_iri_from_str("https://ekgf.org/person/42").unwrap()
+//! );
+//! assert_eq!(triples.len(), 4);
+//! ```
+//!
+//! The struct attribute `#[triples(...)]` takes:
+//!
+//! - `class = expression`: the `Class` of the `rdf:type` triple, if any.
+//! - `subject = "template"`: the IRI of the subject, where `{field}` is
+//!   replaced with the percent-encoded lexical form of that field. Without a
+//!   template, the subject is a new blank node.
+//!
+//! The field attribute `#[triples(...)]` takes:
+//!
+//! - `predicate = expression`: the `Predicate` of the triple of the field,
+//!   whose value is written with `ToLiteral`. Fields without a predicate are
+//!   not written.
+//! - `nested`: the value of the field is written with `ToTriples` instead, and
+//!   the triple links to its subject.
+//!
+//! Fields that are `None` are not written.
+
+mod from_solution;
+mod to_triples;
+
+use {
+    proc_macro::TokenStream,
+    syn::{
+        parse_macro_input,
+        punctuated::Punctuated,
+        token::Comma,
+        Data,
+        DeriveInput,
+        Field,
+        Fields,
+        Ident,
+    },
+};
+
+#[proc_macro_derive(FromSolution, attributes(solution))]
+pub fn derive_from_solution(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    from_solution::from_solution(&input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+#[proc_macro_derive(ToTriples, attributes(triples))]
+pub fn derive_to_triples(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    to_triples::to_triples(&input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+/// The fields of a struct with named fields, which is the only kind of type
+/// the derive macros support.
+fn named_fields<'a>(
+    input: &'a DeriveInput,
+    derive: &str,
+) -> syn::Result<&'a Punctuated<Field, Comma>> {
+    match &input.data {
+        Data::Struct(data) => {
+            match &data.fields {
+                Fields::Named(fields) => Ok(&fields.named),
+                _ => {
+                    Err(syn::Error::new_spanned(
+                        input,
+                        format!("{derive} can only be derived for structs with named fields"),
+                    ))
+                },
+            }
+        },
+        _ => {
+            Err(syn::Error::new_spanned(
+                input,
+                format!("{derive} can only be derived for structs"),
+            ))
+        },
+    }
+}
+
+/// The name of a field, without the `r#` of a raw identifier.
+fn field_name(ident: &Ident) -> String { ident.to_string().trim_start_matches("r#").to_string() }
@@ -0,0 +1,145 @@
+// Copyright (c) 2018-2023, agnos.ai UK Ltd, all rights reserved.
+//---------------------------------------------------------------
+//! `#[derive(ToTriples)]`
+
+use {
+    crate::{field_name, named_fields},
+    proc_macro2::TokenStream,
+    quote::quote,
+    syn::{punctuated::Punctuated, token::Comma, DeriveInput, Expr, Field, LitStr},
+};
+
+pub(crate) fn to_triples(input: &DeriveInput) -> syn::Result<TokenStream> {
+    let fields = named_fields(input, "ToTriples")?;
+    let mut class: Option<Expr> = None;
+    let mut template: Option<LitStr> = None;
+    for attribute in input.attrs.iter() {
+        if !attribute.path().is_ident("triples") {
+            continue
+        }
+        attribute.parse_nested_meta(|meta| {
+            if meta.path.is_ident("class") {
+                class = Some(meta.value()?.parse::<Expr>()?);
+                Ok(())
+            } else if meta.path.is_ident("subject") {
+                template = Some(meta.value()?.parse::<LitStr>()?);
+                Ok(())
+            } else {
+                Err(meta.error("expected `class` or `subject`"))
+            }
+        })?;
+    }
+    let writer = match template {
+        Some(template) => {
+            let parts = subject_template(&template, fields)?;
+            quote! {
+                ::rdf_store_rs::TripleWriter::new_iri(sink, [#(#parts),*].concat().as_str())?
+            }
+        },
+        None => quote! { ::rdf_store_rs::TripleWriter::new_blank_node(sink)? },
+    };
+    let class = class.map(|class| quote! { writer.class(&(#class))?; });
+    let fields = fields
+        .iter()
+        .map(field_to_triples)
+        .collect::<syn::Result<Vec<_>>>()?;
+    let name = &input.ident;
+    let (impl_generics, type_generics, where_clause) = input.generics.split_for_impl();
+    Ok(quote! {
+        impl #impl_generics ::rdf_store_rs::ToTriples for #name #type_generics
+        #where_clause
+        {
+            fn to_triples(
+                &self,
+                sink: &mut dyn ::rdf_store_rs::TripleSink,
+            ) -> ::std::result::Result<::rdf_store_rs::Term, ::rdf_store_rs::RDFStoreError> {
+                let mut writer = #writer;
+                #class
+                #(#fields)*
+                ::std::result::Result::Ok(writer.finish())
+            }
+        }
+    })
+}
+
+/// The expressions of the parts of a subject IRI template such as
+/// `https://ekgf.org/person/{id}`, which are either literal text or a
+/// field.
+fn subject_template(
+    template: &LitStr,
+    fields: &Punctuated<Field, Comma>,
+) -> syn::Result<Vec<TokenStream>> {
+    let error = |message: String| syn::Error::new_spanned(template, message);
+    let mut parts = Vec::new();
+    let value = template.value();
+    let mut rest = value.as_str();
+    while let Some(start) = rest.find(['{', '}']) {
+        if rest[start..].starts_with('}') {
+            return Err(error(
+                "unmatched `}` in the subject template".to_string(),
+            ))
+        }
+        let end = rest[start..]
+            .find('}')
+            .ok_or_else(|| error("unmatched `{` in the subject template".to_string()))? +
+            start;
+        let text = &rest[..start];
+        if !text.is_empty() {
+            parts.push(quote! { #text });
+        }
+        let name = &rest[start + 1..end];
+        let field = fields
+            .iter()
+            .find_map(|field| {
+                field
+                    .ident
+                    .as_ref()
+                    .filter(|ident| field_name(ident) == name)
+            })
+            .ok_or_else(|| {
+                error(format!(
+                    "the subject template refers to unknown field `{name}`"
+                ))
+            })?;
+        parts.push(quote! { ::rdf_store_rs::TripleWriter::iri_segment(&self.#field)?.as_str() });
+        rest = &rest[end + 1..];
+    }
+    if !rest.is_empty() {
+        parts.push(quote! { #rest });
+    }
+    Ok(parts)
+}
+
+/// The triple of a field, if it has a predicate.
+fn field_to_triples(field: &Field) -> syn::Result<TokenStream> {
+    let ident = field.ident.as_ref().unwrap();
+    let mut predicate: Option<Expr> = None;
+    let mut nested = false;
+    for attribute in field.attrs.iter() {
+        if !attribute.path().is_ident("triples") {
+            continue
+        }
+        attribute.parse_nested_meta(|meta| {
+            if meta.path.is_ident("predicate") {
+                predicate = Some(meta.value()?.parse::<Expr>()?);
+                Ok(())
+            } else if meta.path.is_ident("nested") {
+                nested = true;
+                Ok(())
+            } else {
+                Err(meta.error("expected `predicate` or `nested`"))
+            }
+        })?;
+    }
+    Ok(match predicate {
+        Some(predicate) if nested => quote! { writer.nested(&(#predicate), &self.#ident)?; },
+        Some(predicate) => quote! { writer.literal(&(#predicate), &self.#ident)?; },
+        None if nested => {
+            return Err(syn::Error::new_spanned(
+                field,
+                "a nested field needs a predicate",
+            ))
+        },
+        None => TokenStream::new(),
+    })
+}
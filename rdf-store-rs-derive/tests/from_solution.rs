@@ -0,0 +1,104 @@
+// Copyright (c) 2018-2023, agnos.ai UK Ltd, all rights reserved.
+//---------------------------------------------------------------
+
+use rdf_store_rs::{
+    sparql::{FromSolution, Solution, Variable},
+    DataType,
+    Literal,
+    Namespace,
+    RDFStoreError,
+    Term,
+};
+
+const FOAF: &str = "http://xmlns.com/foaf/0.1/";
+
+fn foaf() -> Namespace { Namespace::declare_from_str_unchecked("foaf:", FOAF) }
+
+#[derive(Debug, PartialEq, rdf_store_rs_derive::FromSolution)]
+struct Person {
+    name:   String,
+    #[solution(rename = "yearsOld")]
+    age:    Option<u8>,
+    #[solution(namespace = foaf())]
+    r#type: String,
+}
+
+fn solution(bindings: &[(&str, Term)]) -> Solution {
+    bindings
+        .iter()
+        .map(|(name, term)| (Variable(name.to_string()), term.clone()))
+        .collect()
+}
+
+fn integer(value: i64) -> Term {
+    Term::Literal(
+        Literal::from_lexical_form(DataType::Integer, value.to_string().as_str()).unwrap(),
+    )
+}
+
+fn foaf_iri(local_name: &str) -> Term {
+    Term::new_iri_from_str(format!("{FOAF}{local_name}").as_str()).unwrap()
+}
+
+#[test]
+fn test_all_fields_bound() {
+    let person = Person::from_solution(&solution(&[
+        ("name", Term::new_str("Alice").unwrap()),
+        ("yearsOld", integer(42)),
+        ("type", foaf_iri("Person")),
+    ]))
+    .unwrap();
+    assert_eq!(person, Person {
+        name:   "Alice".to_string(),
+        age:    Some(42),
+        r#type: "Person".to_string(),
+    });
+}
+
+#[test]
+fn test_optional_field_unbound() {
+    let person = Person::from_solution(&solution(&[
+        ("name", Term::new_str("Bob").unwrap()),
+        ("type", foaf_iri("Agent")),
+    ]))
+    .unwrap();
+    assert_eq!(person.age, None);
+    assert_eq!(person.r#type, "Agent");
+}
+
+#[test]
+fn test_errors_name_the_variable() {
+    let missing = Person::from_solution(&solution(&[("type", foaf_iri("Person"))]));
+    assert!(matches!(
+        missing,
+        Err(RDFStoreError::UnboundVariable { variable }) if variable == "name"
+    ));
+
+    let out_of_range = Person::from_solution(&solution(&[
+        ("name", Term::new_str("Carol").unwrap()),
+        ("yearsOld", integer(1000)),
+        ("type", foaf_iri("Person")),
+    ]));
+    assert!(matches!(
+        out_of_range,
+        Err(RDFStoreError::InvalidVariableValue { variable, .. }) if variable == "yearsOld"
+    ));
+
+    let other_namespace = Person::from_solution(&solution(&[
+        ("name", Term::new_str("Dave").unwrap()),
+        (
+            "type",
+            Term::new_iri_from_str("https://schema.org/Person").unwrap(),
+        ),
+    ]));
+    match other_namespace {
+        Err(RDFStoreError::InvalidVariableValue { variable, source }) => {
+            assert_eq!(variable, "type");
+            assert!(matches!(
+                *source,
+                RDFStoreError::NotInNamespace { .. }
+            ));
+        },
+        other => panic!("unexpected result {other:?}"),
+    }
+}
@@ -0,0 +1,136 @@
+// Copyright (c) 2018-2023, agnos.ai UK Ltd, all rights reserved.
+//---------------------------------------------------------------
+
+use {
+    rdf_store_rs::{
+        sparql::{MemoryStore, TripleSource},
+        Class,
+        Namespace,
+        Predicate,
+        Term,
+        ToLiteral,
+        ToTriples,
+    },
+    std::sync::Arc,
+};
+
+const TEST: &str = "https://whatever.kom/test#";
+
+rdf_store_rs::lazy_static::lazy_static! {
+    static ref NAMESPACE: Arc<Namespace> =
+        Arc::new(Namespace::declare_from_str_unchecked("test:", TEST));
+}
+
+#[derive(rdf_store_rs_derive::ToTriples)]
+#[triples(class = Class::declare(NAMESPACE.clone(), "Address"))]
+struct Address {
+    #[triples(predicate = Predicate::declare(NAMESPACE.clone(), "city"))]
+    city: String,
+}
+
+#[derive(rdf_store_rs_derive::ToTriples)]
+#[triples(subject = "https://whatever.kom/company/{code}")]
+struct Company {
+    code: String,
+}
+
+#[derive(rdf_store_rs_derive::ToTriples)]
+#[triples(
+    class = Class::declare(NAMESPACE.clone(), "Person"),
+    subject = "https://whatever.kom/person/{id}#{name}"
+)]
+struct Person {
+    id:       u32,
+    #[triples(predicate = Predicate::declare(NAMESPACE.clone(), "name"))]
+    name:     String,
+    #[triples(predicate = Predicate::declare(NAMESPACE.clone(), "age"))]
+    age:      Option<u8>,
+    #[triples(predicate = Predicate::declare(NAMESPACE.clone(), "address"), nested)]
+    address:  Address,
+    #[triples(predicate = Predicate::declare(NAMESPACE.clone(), "employer"), nested)]
+    employer: Option<Company>,
+}
+
+fn iri(local_name: &str) -> Term {
+    Term::new_iri_from_str(format!("{TEST}{local_name}").as_str()).unwrap()
+}
+
+fn string(value: &str) -> Term { Term::from(value.to_literal().unwrap()) }
+
+fn rdf_type() -> Term {
+    Term::new_iri_from_str("http://www.w3.org/1999/02/22-rdf-syntax-ns#type").unwrap()
+}
+
+#[test]
+fn test_subject_template_and_nested_blank_node() {
+    let person = Person {
+        id:       7,
+        name:     "Jane Doe".to_string(),
+        age:      None,
+        address:  Address { city: "London".to_string() },
+        employer: None,
+    };
+    let mut triples: Vec<[Term; 3]> = Vec::new();
+    let subject = person.to_triples(&mut triples).unwrap();
+
+    assert_eq!(
+        subject,
+        Term::new_iri_from_str("https://whatever.kom/person/7#Jane%20Doe").unwrap()
+    );
+    let objects = |predicate: &Term| {
+        triples
+            .iter()
+            .filter(|[s, p, _]| s == &subject && p == predicate)
+            .map(|[_, _, o]| o.clone())
+            .collect::<Vec<_>>()
+    };
+    assert_eq!(objects(&rdf_type()), vec![iri("Person")]);
+    assert_eq!(objects(&iri("name")), vec![string("Jane Doe")]);
+    assert!(objects(&iri("age")).is_empty());
+    assert!(objects(&iri("employer")).is_empty());
+
+    let address = objects(&iri("address"));
+    assert_eq!(address.len(), 1);
+    assert!(matches!(address[0], Term::BlankNode(_)));
+    assert!(triples.contains(&[address[0].clone(), iri("city"), string("London")]));
+    assert_eq!(triples.len(), 5);
+}
+
+#[test]
+fn test_nested_resource_in_store() {
+    let person = Person {
+        id:       8,
+        name:     "John".to_string(),
+        age:      Some(42),
+        address:  Address { city: "Paris".to_string() },
+        employer: Some(Company { code: "ACME".to_string() }),
+    };
+    let mut store = MemoryStore::new();
+    let subject = person.to_triples(&mut store).unwrap();
+
+    let employers = store.triples_matching(Some(&subject), Some(&iri("employer")), None, None);
+    assert_eq!(employers.len(), 1);
+    assert_eq!(
+        employers[0][2],
+        Term::new_iri_from_str("https://whatever.kom/company/ACME").unwrap()
+    );
+    // A company without predicates only links to its IRI
+    assert!(store
+        .triples_matching(Some(&employers[0][2]), None, None, None)
+        .is_empty());
+    assert_eq!(store.len(), 7);
+}
+
+#[test]
+fn test_insert_data() {
+    let company = Company { code: "ACME".to_string() };
+    assert_eq!(
+        company.to_insert_data().unwrap(),
+        "INSERT DATA {\n}"
+    );
+
+    let address = Address { city: "Rome".to_string() };
+    let sparql = address.to_insert_data().unwrap();
+    assert!(sparql.starts_with("INSERT DATA {\n    _:b"));
+    assert!(sparql.contains(" <https://whatever.kom/test#city> \"Rome\" .\n"));
+}
@@ -1,53 +1,31 @@
 // Copyright (c) 2018-2023, agnos.ai UK Ltd, all rights reserved.
 //---------------------------------------------------------------
 
-use crate::{Literal, Namespace, RDFStoreError};
+use {
+    crate::{Literal, Namespace, PrefixedIri},
+    std::sync::Arc,
+};
 
 /// The `Class` struct represents an RDFS or OWL class identifier
-/// consisting of a [`Namespace`] (i.e. a namespace) and a "local name".
-#[derive(Debug, Clone)]
+/// consisting of a [`Namespace`] (i.e. a namespace) and a "local name",
+/// see [`PrefixedIri`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct Class {
-    pub namespace:  Namespace,
-    pub local_name: String,
+    pub(crate) iri: PrefixedIri,
 }
 
 impl std::fmt::Display for Class {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(
-            f,
-            "{}{}",
-            self.namespace.name.as_str(),
-            self.local_name.as_str()
-        )
+        write!(f, "{}", self.iri)
     }
 }
 
 impl Class {
-    pub fn declare(namespace: Namespace, local_name: &str) -> Self {
-        Self { namespace, local_name: local_name.to_string() }
-    }
-
-    pub fn as_iri(&self) -> Result<iref::IriBuf, RDFStoreError> {
-        let iri = iref::IriBuf::try_from(format!("{}{}", self.namespace.iri.as_str(), self.local_name.as_str()))?;
-        Ok(iri)
-    }
-
-    #[allow(clippy::needless_lifetimes)]
-    pub fn display_turtle<'a>(&'a self) -> impl std::fmt::Display + 'a {
-        struct TurtleClass<'a>(&'a Class);
-        impl<'a> std::fmt::Display for TurtleClass<'a> {
-            fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
-                write!(
-                    f,
-                    "{}{}",
-                    self.0.namespace.name, self.0.local_name
-                )
-            }
-        }
-        TurtleClass(self)
+    pub fn declare(namespace: impl Into<Arc<Namespace>>, local_name: &str) -> Self {
+        Self { iri: PrefixedIri::declare(namespace, local_name) }
     }
 
-    pub fn plural_label(&self) -> String { format!("{}s", self.local_name) }
+    pub fn plural_label(&self) -> String { format!("{}s", self.local_name()) }
 
     // TODO: Make this slightly smarter
 
@@ -74,7 +52,7 @@ mod tests {
 
     #[test]
     fn test_a_class_01() {
-        let namespace = Namespace::declare(
+        let namespace = Namespace::declare_unchecked(
             "test:",
             iref::Iri::new("https://whatever.com/test#").unwrap(),
         );
@@ -85,7 +63,7 @@ mod tests {
 
     #[test]
     fn test_a_class_02() {
-        let namespace = Namespace::declare(
+        let namespace = Namespace::declare_unchecked(
             "test:",
             iref::Iri::new("https://whatever.com/test#").unwrap(),
         );
@@ -96,7 +74,7 @@ mod tests {
 
     #[test]
     fn test_is_literal() {
-        let namespace = Namespace::declare(
+        let namespace = Namespace::declare_unchecked(
             "test:",
             iref::Iri::new("https://whatever.com/test#").unwrap(),
         );
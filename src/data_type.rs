@@ -2,44 +2,70 @@
 //---------------------------------------------------------------
 #[cfg(feature = "serde")]
 use serde::Serialize;
-use {num_enum::TryFromPrimitive, phf::phf_map};
-
-static DATA_TYPE_MAP: phf::Map<&'static str, DataType> = phf_map! {
-    "Unbound Value" => DataType::UnboundValue,
-    "Blank Node" => DataType::BlankNode,
-    "IRI Reference" => DataType::IriReference,
-    "http://www.w3.org/2000/01/rdf-schema#Literal" => DataType::Literal,
-    "http://www.w3.org/2001/XMLSchema#anyURI" => DataType::AnyUri,
-    "http://www.w3.org/2001/XMLSchema#boolean" => DataType::Boolean,
-    "http://www.w3.org/2001/XMLSchema#byte" => DataType::Byte,
-    "http://www.w3.org/2001/XMLSchema#date" => DataType::Date,
-    "http://www.w3.org/2001/XMLSchema#dateTime" => DataType::DateTime,
-    "http://www.w3.org/2001/XMLSchema#dateTimeStamp" => DataType::DateTimeStamp,
-    "http://www.w3.org/2001/XMLSchema#gDay" => DataType::Day,
-    "http://www.w3.org/2001/XMLSchema#dayTimeDuration" => DataType::DayTimeDuration,
-    "http://www.w3.org/2001/XMLSchema#decimal" => DataType::Decimal,
-    "http://www.w3.org/2001/XMLSchema#double" => DataType::Double,
-    "http://www.w3.org/2001/XMLSchema#duration" => DataType::Duration,
-    "http://www.w3.org/2001/XMLSchema#float" => DataType::Float,
-    "http://www.w3.org/2001/XMLSchema#int" => DataType::Int,
-    "http://www.w3.org/2001/XMLSchema#integer" => DataType::Integer,
-    "http://www.w3.org/2001/XMLSchema#long" => DataType::Long,
-    "http://www.w3.org/2001/XMLSchema#gMonth" => DataType::Month,
-    "http://www.w3.org/2001/XMLSchema#gMonthDay" => DataType::MonthDay,
-    "http://www.w3.org/2001/XMLSchema#negativeInteger" => DataType::NegativeInteger,
-    "http://www.w3.org/2001/XMLSchema#nonNegativeInteger" => DataType::NonNegativeInteger,
-    "http://www.w3.org/2001/XMLSchema#nonPositiveInteger" => DataType::NonPositiveInteger,
-    "http://www.w3.org/2001/XMLSchema#short" => DataType::Short,
-    "http://www.w3.org/2001/XMLSchema#string" => DataType::String,
-    "http://www.w3.org/2001/XMLSchema#time" => DataType::Time,
-    "http://www.w3.org/2001/XMLSchema#unsignedByte" => DataType::UnsignedByte,
-    "http://www.w3.org/2001/XMLSchema#unsignedInt" => DataType::UnsignedInt,
-    "http://www.w3.org/2001/XMLSchema#unsignedLong" => DataType::UnsignedLong,
-    "http://www.w3.org/2001/XMLSchema#unsignedShort" => DataType::UnsignedShort,
-    "http://www.w3.org/2001/XMLSchema#gYear" => DataType::Year,
-    "http://www.w3.org/2001/XMLSchema#gYearMonth" => DataType::YearMonth,
-    "http://www.w3.org/2001/XMLSchema#yearMonthDuration" => DataType::YearMonthDuration,
-};
+use {num_bigint::BigInt, num_enum::TryFromPrimitive, phf::phf_map};
+
+// The IRI of every [`DataType`], from which both directions of the mapping
+// are generated, so that they cannot get out of sync. The `match` in
+// `data_type_iri` fails to compile if a datatype has no IRI and `phf_map!`
+// fails to compile if two datatypes have the same IRI.
+macro_rules! data_type_iris {
+    ($($data_type:ident => $iri:literal,)*) => {
+        static DATA_TYPE_MAP: phf::Map<&'static str, DataType> = phf_map! {
+            $($iri => DataType::$data_type,)*
+            // Language tags are not kept, so a language tagged string is a
+            // plain literal
+            "http://www.w3.org/1999/02/22-rdf-syntax-ns#langString" => DataType::PlainLiteral,
+        };
+
+        const fn data_type_iri(data_type: DataType) -> &'static str {
+            match data_type {
+                $(DataType::$data_type => $iri,)*
+                DataType::Custom => "Custom Data Type",
+            }
+        }
+    };
+}
+
+data_type_iris! {
+    UnboundValue => "Unbound Value",
+    BlankNode => "Blank Node",
+    IriReference => "IRI Reference",
+    Literal => "http://www.w3.org/2000/01/rdf-schema#Literal",
+    AnyUri => "http://www.w3.org/2001/XMLSchema#anyURI",
+    String => "http://www.w3.org/2001/XMLSchema#string",
+    PlainLiteral => "http://www.w3.org/1999/02/22-rdf-syntax-ns#PlainLiteral",
+    Boolean => "http://www.w3.org/2001/XMLSchema#boolean",
+    DateTime => "http://www.w3.org/2001/XMLSchema#dateTime",
+    DateTimeStamp => "http://www.w3.org/2001/XMLSchema#dateTimeStamp",
+    Time => "http://www.w3.org/2001/XMLSchema#time",
+    Date => "http://www.w3.org/2001/XMLSchema#date",
+    YearMonth => "http://www.w3.org/2001/XMLSchema#gYearMonth",
+    Year => "http://www.w3.org/2001/XMLSchema#gYear",
+    MonthDay => "http://www.w3.org/2001/XMLSchema#gMonthDay",
+    Day => "http://www.w3.org/2001/XMLSchema#gDay",
+    Month => "http://www.w3.org/2001/XMLSchema#gMonth",
+    Duration => "http://www.w3.org/2001/XMLSchema#duration",
+    YearMonthDuration => "http://www.w3.org/2001/XMLSchema#yearMonthDuration",
+    DayTimeDuration => "http://www.w3.org/2001/XMLSchema#dayTimeDuration",
+    Double => "http://www.w3.org/2001/XMLSchema#double",
+    Float => "http://www.w3.org/2001/XMLSchema#float",
+    Decimal => "http://www.w3.org/2001/XMLSchema#decimal",
+    Integer => "http://www.w3.org/2001/XMLSchema#integer",
+    NonNegativeInteger => "http://www.w3.org/2001/XMLSchema#nonNegativeInteger",
+    NonPositiveInteger => "http://www.w3.org/2001/XMLSchema#nonPositiveInteger",
+    NegativeInteger => "http://www.w3.org/2001/XMLSchema#negativeInteger",
+    PositiveInteger => "http://www.w3.org/2001/XMLSchema#positiveInteger",
+    Long => "http://www.w3.org/2001/XMLSchema#long",
+    Int => "http://www.w3.org/2001/XMLSchema#int",
+    Short => "http://www.w3.org/2001/XMLSchema#short",
+    Byte => "http://www.w3.org/2001/XMLSchema#byte",
+    UnsignedLong => "http://www.w3.org/2001/XMLSchema#unsignedLong",
+    UnsignedInt => "http://www.w3.org/2001/XMLSchema#unsignedInt",
+    UnsignedShort => "http://www.w3.org/2001/XMLSchema#unsignedShort",
+    UnsignedByte => "http://www.w3.org/2001/XMLSchema#unsignedByte",
+    HexBinary => "http://www.w3.org/2001/XMLSchema#hexBinary",
+    Base64Binary => "http://www.w3.org/2001/XMLSchema#base64Binary",
+}
 
 /// The XSD DataType of a given [`Literal`](crate::Literal).
 ///
@@ -120,6 +146,14 @@ pub enum DataType {
     UnsignedShort      = 34,
     /// XSD_UNSIGNED_BYTE
     UnsignedByte       = 35,
+    /// XSD_HEX_BINARY
+    HexBinary          = 36,
+    /// XSD_BASE64_BINARY
+    Base64Binary       = 37,
+    /// Any datatype that is not built in, such as `geo:wktLiteral`, whose
+    /// IRI is kept in the [`Literal`](crate::Literal) itself, see
+    /// [`Literal::data_type_iri`](crate::Literal::data_type_iri).
+    Custom             = 38,
 }
 
 impl Default for DataType {
@@ -142,16 +176,14 @@ impl DataType {
         }
     }
 
-    pub fn as_xsd_iri_str(&self) -> &'static str {
-        DATA_TYPE_MAP
-            .entries()
-            .find_map(
-                |(key, val)| {
-                    if val == self { Some(key) } else { None }
-                },
-            )
-            .unwrap_or_else(|| panic!("You've managed to create an unknown DataType instance"))
-    }
+    /// The IRI of this datatype, such as
+    /// `http://www.w3.org/2001/XMLSchema#integer`. A [`DataType::Custom`]
+    /// does not have one (use
+    /// [`Literal::data_type_iri`](crate::Literal::data_type_iri) for that
+    /// instead), and neither do the datatypes of IRIs, blank nodes and
+    /// unbound values, which have a name instead.
+    #[inline]
+    pub const fn as_xsd_iri_str(&self) -> &'static str { data_type_iri(*self) }
 
     #[inline]
     pub fn is_string(&self) -> bool {
@@ -173,16 +205,24 @@ impl DataType {
         matches!(self, DataType::Date)
     }
 
+    /// An `xsd:dateTime` or an `xsd:dateTimeStamp` (which is a date time
+    /// that always has a timezone).
     #[inline]
     pub fn is_date_time(&self) -> bool {
-        matches!(self, DataType::DateTime)
+        matches!(self, DataType::DateTime | DataType::DateTimeStamp)
     }
 
+    #[inline]
+    pub fn is_time(&self) -> bool { matches!(self, DataType::Time) }
+
     #[inline]
     pub fn is_decimal(&self) -> bool {
         matches!(self, DataType::Decimal)
     }
 
+    #[inline]
+    pub fn is_floating_point(&self) -> bool { matches!(self, DataType::Float | DataType::Double) }
+
     #[inline]
     pub fn is_date_time_stamp(&self) -> bool {
         matches!(self, DataType::DateTimeStamp)
@@ -190,7 +230,34 @@ impl DataType {
 
     #[inline]
     pub fn is_duration(&self) -> bool {
-        matches!(self, DataType::Duration)
+        matches!(
+            self,
+            DataType::Duration | DataType::YearMonthDuration | DataType::DayTimeDuration
+        )
+    }
+
+    /// An `xsd:gYear`, `xsd:gYearMonth`, `xsd:gMonthDay`, `xsd:gDay` or
+    /// `xsd:gMonth`.
+    #[inline]
+    pub fn is_gregorian(&self) -> bool {
+        matches!(
+            self,
+            DataType::Year |
+                DataType::YearMonth |
+                DataType::MonthDay |
+                DataType::Day |
+                DataType::Month
+        )
+    }
+
+    /// A datatype that is not built in, see [`DataType::Custom`].
+    #[inline]
+    pub fn is_custom(&self) -> bool { matches!(self, DataType::Custom) }
+
+    /// An `xsd:hexBinary` or an `xsd:base64Binary`.
+    #[inline]
+    pub fn is_binary(&self) -> bool {
+        matches!(self, DataType::HexBinary | DataType::Base64Binary)
     }
 
     #[inline]
@@ -202,7 +269,8 @@ impl DataType {
             DataType::NegativeInteger |
             DataType::NonPositiveInteger |
             DataType::Long |
-            DataType::Short
+            DataType::Short |
+            DataType::Byte
         )
     }
 
@@ -222,9 +290,141 @@ impl DataType {
     #[inline]
     pub fn is_integer(&self) -> bool { self.is_unsigned_integer() || self.is_signed_integer() }
 
+    /// Whether the values of this datatype have arbitrary precision, as is
+    /// the case for `xsd:integer` and the datatypes derived from it that
+    /// are only bounded on one side, such as `xsd:nonNegativeInteger`.
+    /// Such values are not limited to an `i64` or a `u64`.
+    #[inline]
+    pub fn is_big_integer(&self) -> bool {
+        matches!(
+            self,
+            DataType::Integer |
+                DataType::NonPositiveInteger |
+                DataType::NegativeInteger |
+                DataType::NonNegativeInteger |
+                DataType::PositiveInteger
+        )
+    }
+
+    /// The smallest and largest value of an integer datatype, where `None`
+    /// means that there is no bound on that side, or `None` for a datatype
+    /// that is not an integer datatype.
+    pub(crate) fn integer_range(&self) -> Option<(Option<BigInt>, Option<BigInt>)> {
+        let range = match self {
+            DataType::Integer => (None, None),
+            DataType::NonPositiveInteger => (None, Some(0.into())),
+            DataType::NegativeInteger => (None, Some((-1).into())),
+            DataType::NonNegativeInteger => (Some(0.into()), None),
+            DataType::PositiveInteger => (Some(1.into()), None),
+            DataType::Long => (Some(i64::MIN.into()), Some(i64::MAX.into())),
+            DataType::Int => (Some(i32::MIN.into()), Some(i32::MAX.into())),
+            DataType::Short => (Some(i16::MIN.into()), Some(i16::MAX.into())),
+            DataType::Byte => (Some(i8::MIN.into()), Some(i8::MAX.into())),
+            DataType::UnsignedLong => (Some(0.into()), Some(u64::MAX.into())),
+            DataType::UnsignedInt => (Some(0.into()), Some(u32::MAX.into())),
+            DataType::UnsignedShort => (Some(0.into()), Some(u16::MAX.into())),
+            DataType::UnsignedByte => (Some(0.into()), Some(u8::MAX.into())),
+            _ => return None,
+        };
+        Some(range)
+    }
+
+    /// The datatype that this datatype is derived from by restriction in
+    /// the [XSD type hierarchy](https://www.w3.org/TR/xmlschema11-2/#built-in-datatypes),
+    /// such as `xsd:long` for `xsd:int` or `xsd:decimal` for `xsd:integer`,
+    /// or `None` for a primitive datatype such as `xsd:decimal`.
+    pub const fn base_type(&self) -> Option<DataType> {
+        let base_type = match self {
+            DataType::Integer => DataType::Decimal,
+            DataType::NonPositiveInteger | DataType::Long | DataType::NonNegativeInteger => {
+                DataType::Integer
+            },
+            DataType::NegativeInteger => DataType::NonPositiveInteger,
+            DataType::Int => DataType::Long,
+            DataType::Short => DataType::Int,
+            DataType::Byte => DataType::Short,
+            DataType::UnsignedLong | DataType::PositiveInteger => DataType::NonNegativeInteger,
+            DataType::UnsignedInt => DataType::UnsignedLong,
+            DataType::UnsignedShort => DataType::UnsignedInt,
+            DataType::UnsignedByte => DataType::UnsignedShort,
+            DataType::DateTimeStamp => DataType::DateTime,
+            DataType::YearMonthDuration | DataType::DayTimeDuration => DataType::Duration,
+            _ => return None,
+        };
+        Some(base_type)
+    }
+
+    /// Whether this datatype is the given datatype or is derived from it,
+    /// so `xsd:unsignedByte` is derived from `xsd:integer` and
+    /// `xsd:decimal`, and a value of this datatype can be used wherever
+    /// one of the given datatype is expected.
+    pub fn is_derived_from(&self, data_type: DataType) -> bool {
+        let mut current = Some(*self);
+        while let Some(this) = current {
+            if this == data_type {
+                return true
+            }
+            current = this.base_type();
+        }
+        false
+    }
+
     #[inline]
     pub fn is_blank_node(&self) -> bool {
         // BLANK_NODE_TYPES
         matches!(self, DataType::BlankNode)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::DataType;
+
+    #[test_log::test]
+    fn test_iri_round_trip() -> Result<(), crate::RDFStoreError> {
+        let mut data_type_id = 0;
+        while let Ok(data_type) = DataType::from_datatype_id(data_type_id) {
+            if data_type != DataType::Custom {
+                assert_eq!(
+                    DataType::from_xsd_iri(data_type.as_xsd_iri_str())?,
+                    data_type
+                );
+            }
+            data_type_id += 1;
+        }
+        assert_eq!(data_type_id, DataType::Custom as u8 + 1);
+        assert_eq!(
+            DataType::PositiveInteger.as_xsd_iri_str(),
+            "http://www.w3.org/2001/XMLSchema#positiveInteger"
+        );
+        assert_eq!(
+            DataType::from_xsd_iri("http://www.w3.org/1999/02/22-rdf-syntax-ns#langString")?,
+            DataType::PlainLiteral
+        );
+        assert!(DataType::from_xsd_iri(DataType::Custom.as_xsd_iri_str()).is_err());
+        Ok(())
+    }
+
+    #[test_log::test]
+    fn test_type_hierarchy() {
+        assert!(DataType::UnsignedByte.is_derived_from(DataType::Integer));
+        assert!(DataType::UnsignedByte.is_derived_from(DataType::Decimal));
+        assert!(DataType::NegativeInteger.is_derived_from(DataType::NonPositiveInteger));
+        assert!(DataType::Int.is_derived_from(DataType::Int));
+        assert!(DataType::DayTimeDuration.is_derived_from(DataType::Duration));
+        assert!(!DataType::Int.is_derived_from(DataType::UnsignedInt));
+        assert!(!DataType::Decimal.is_derived_from(DataType::Integer));
+        assert!(!DataType::Float.is_derived_from(DataType::Double));
+        assert_eq!(DataType::Short.base_type(), Some(DataType::Int));
+        assert_eq!(DataType::Decimal.base_type(), None);
+        // Every integer datatype is derived from `xsd:integer`
+        for data_type_id in 0..=DataType::Custom as u8 {
+            let data_type = DataType::from_datatype_id(data_type_id).unwrap();
+            assert_eq!(
+                data_type.is_integer(),
+                data_type.is_derived_from(DataType::Integer),
+                "{data_type:?}"
+            );
+        }
+    }
+}
@@ -3,8 +3,7 @@
 
 extern crate alloc;
 
-use iref::InvalidIri;
-use {crate::DataType, thiserror::Error};
+use {crate::DataType, iref::InvalidIri, thiserror::Error};
 
 #[derive(Error, Debug)]
 pub enum RDFStoreError {
@@ -19,6 +18,10 @@ pub enum RDFStoreError {
     UnknownValueForDataType { data_type: DataType, value: String },
     #[error("Unknown XSD data type {data_type_iri}")]
     UnknownXsdDataType { data_type_iri: String },
+    #[error("Invalid value [{value}] for data type <{data_type_iri}>")]
+    InvalidValueForCustomDataType { data_type_iri: String, value: String },
+    #[error("Data type <{data_type_iri}> is built in and cannot be a custom data type")]
+    BuiltInDataType { data_type_iri: String },
     #[error("Unknown literal value in N-Triples format: {value}")]
     UnknownNTriplesValue { value: String },
     #[error(
@@ -45,10 +48,40 @@ pub enum RDFStoreError {
     CouldNotConnectToServer,
     #[error("Could not import RDF File")]
     CouldNotImportRDFFile,
-    #[error("Invalid prefix name")]
-    InvalidPrefixName,
+    #[error("Invalid prefix name {name:?}")]
+    InvalidPrefixName { name: String },
     #[error("Invalid literal value")]
     InvalidLiteral,
+    #[error("There is no literal for a value of type {type_name}")]
+    MissingLiteral { type_name: String },
+    #[error("There is no subject for a value of type {type_name}")]
+    MissingSubject { type_name: String },
+    #[error("Variable ?{variable} is not bound in the solution")]
+    UnboundVariable { variable: String },
+    #[error("Invalid value for variable ?{variable}: {source}")]
+    InvalidVariableValue { variable: String, source: Box<RDFStoreError> },
+    #[error("<{iri}> is not in namespace {namespace}")]
+    NotInNamespace { iri: String, namespace: String },
+    #[error("{local_name:?} is not a valid local name in namespace {namespace}")]
+    InvalidLocalName { namespace: String, local_name: String },
+    #[error("Parameter ${name} of the SPARQL template has not been bound")]
+    UnboundTemplateParameter { name: String },
+    #[error("The SPARQL template has no parameter ${name}")]
+    UnknownTemplateParameter { name: String },
+    #[error("Invalid value for parameter ${name} of the SPARQL template: {message}")]
+    InvalidTemplateParameterValue { name: String, message: String },
+    #[error("Graph {graph} is outside of the scope of the statement")]
+    GraphOutOfScope { graph: String },
+    #[error("Cannot evaluate {function}: {message}")]
+    ExpressionError { function: String, message: String },
+    #[error("Cannot cast a value of data type {from:?} to {to:?}")]
+    InvalidCast { from: DataType, to: DataType },
+    #[error("SPARQL feature not supported: {feature}")]
+    UnsupportedSparqlFeature { feature: String },
+    #[error("SPARQL syntax error at line {line}, column {column}: {message}")]
+    SparqlParseError { line: usize, column: usize, message: String },
+    #[error("Turtle syntax error at line {line}, column {column}: {message}")]
+    TurtleParseError { line: usize, column: usize, message: String },
     /// Represents all other cases of `std::io::Error`.
     #[error(transparent)]
     IOError(#[from] std::io::Error),
@@ -107,4 +140,4 @@ impl From<InvalidIri<&str>> for RDFStoreError {
 //             msg: format!("{:?}", error)
 //         }
 //     }
-// }
\ No newline at end of file
+// }
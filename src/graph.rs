@@ -2,46 +2,41 @@
 //---------------------------------------------------------------
 
 use {
-    crate::{Literal, Namespace},
-    std::ffi::CString,
+    crate::{Literal, Namespace, PrefixedIri},
+    std::{ffi::CString, sync::Arc},
 };
 
 /// Similar to [`Class`](crate::Class), the `Graph` struct represents an RDF
 /// named graph identifier, also known as a "context identifier", consisting of
-/// a [`Namespace`] (i.e. a namespace) and a "local name".
-#[derive(Debug, Clone)]
+/// a [`Namespace`] (i.e. a namespace) and a "local name", see [`PrefixedIri`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct Graph {
-    pub namespace:  Namespace,
-    pub local_name: String,
+    pub(crate) iri: PrefixedIri,
 }
 
 /// Print IRI in prefix:localName format
 impl std::fmt::Display for Graph {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(
-            f,
-            "{}{}",
-            self.namespace.name.as_str(),
-            self.local_name.as_str()
-        )
+        write!(f, "{}", self.iri)
     }
 }
 
 impl Graph {
-    pub fn declare(namespace: Namespace, local_name: &str) -> Self {
-        // TODO: Find a class for URI/IRIs that has separate base + local name
-        // and use that as param instead
-        Self { namespace, local_name: local_name.to_string() }
+    pub fn declare(namespace: impl Into<Arc<Namespace>>, local_name: &str) -> Self {
+        Self { iri: PrefixedIri::declare(namespace, local_name) }
     }
 
-    pub fn dataset_from_path(namespace: Namespace, path: &std::path::Path) -> Self {
+    pub fn dataset_from_path(namespace: impl Into<Arc<Namespace>>, path: &std::path::Path) -> Self {
         Self::declare(
             namespace,
             path.file_name().unwrap().to_str().unwrap(),
         )
     }
 
-    pub fn test_dataset_from_path(namespace: Namespace, path: &std::path::Path) -> Self {
+    pub fn test_dataset_from_path(
+        namespace: impl Into<Arc<Namespace>>,
+        path: &std::path::Path,
+    ) -> Self {
         Self::declare(
             namespace,
             format!(
@@ -52,11 +47,7 @@ impl Graph {
         )
     }
 
-    pub fn as_iri_buf(&self) -> Result<iref::IriBuf, crate::RDFStoreError> {
-        self.namespace
-            .with_local_name(self.local_name.as_str())
-            .map_err(crate::RDFStoreError::from)
-    }
+    pub fn as_iri_buf(&self) -> Result<iref::IriBuf, crate::RDFStoreError> { self.iri.as_iri() }
 
     pub fn as_display_iri(&self) -> GraphDisplayIRI { GraphDisplayIRI { graph: self } }
 
@@ -81,8 +72,8 @@ impl<'a> std::fmt::Display for GraphDisplayIRI<'a> {
         write!(
             f,
             "<{}{}>",
-            self.graph.namespace.iri.as_str(),
-            self.graph.local_name.as_str()
+            self.graph.namespace().iri.as_str(),
+            self.graph.local_name()
         )
     }
 }
@@ -92,7 +83,7 @@ mod tests {
     #[test]
     fn test_display_iri() {
         let ns = iref::Iri::new("https://whatever.kom/graph/").unwrap();
-        let graph_prefix = crate::Namespace::declare("graph:", ns);
+        let graph_prefix = crate::Namespace::declare_unchecked("graph:", ns);
         let graph = crate::Graph::declare(graph_prefix, "somedataset");
 
         assert_eq!(
@@ -108,7 +99,7 @@ mod tests {
     #[test]
     fn test_graph_ns() {
         let ns = iref::Iri::new("https://whatever.kom/graph/").unwrap();
-        let graph_prefix = crate::Namespace::declare("kggraph:", ns);
+        let graph_prefix = crate::Namespace::declare_unchecked("kggraph:", ns);
 
         let graph = crate::Graph::declare(graph_prefix, "somedataset");
         let c_string = graph.as_c_string().unwrap().into_string().unwrap();
@@ -0,0 +1,64 @@
+// Copyright (c) 2018-2023, agnos.ai UK Ltd, all rights reserved.
+//---------------------------------------------------------------
+//! Resolving relative IRI references against a base IRI, as done by both
+//! the SPARQL and the Turtle parser.
+
+use iref::{IriBuf, IriRef};
+
+/// The given IRI resolved against the given base IRI as described in
+/// [RFC 3986](https://www.rfc-editor.org/rfc/rfc3986#section-5.2), unless
+/// there is no (valid) base or the IRI is absolute already.
+pub(crate) fn resolve_relative(base: Option<&str>, iri: &str) -> String {
+    let base = match base.map(|base| IriBuf::new(base.to_string())) {
+        Some(Ok(base)) => base,
+        _ => return iri.to_string(),
+    };
+    match IriRef::new(iri) {
+        Ok(iri_ref) if iri_ref.scheme().is_none() => iri_ref.resolved(&base).to_string(),
+        _ => iri.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::resolve_relative;
+
+    /// The normal examples of
+    /// [RFC 3986](https://www.rfc-editor.org/rfc/rfc3986#section-5.4.1).
+    #[test_log::test]
+    fn test_resolve_relative() {
+        let base = Some("http://a/b/c/d;p?q");
+        for (reference, expected) in [
+            ("g:h", "g:h"),
+            ("g", "http://a/b/c/g"),
+            ("./g", "http://a/b/c/g"),
+            ("g/", "http://a/b/c/g/"),
+            ("/g", "http://a/g"),
+            ("//g", "http://g"),
+            ("?y", "http://a/b/c/d;p?y"),
+            ("g?y", "http://a/b/c/g?y"),
+            ("#s", "http://a/b/c/d;p?q#s"),
+            ("g#s", "http://a/b/c/g#s"),
+            ("g?y#s", "http://a/b/c/g?y#s"),
+            (";x", "http://a/b/c/;x"),
+            ("g;x", "http://a/b/c/g;x"),
+            ("g;x?y#s", "http://a/b/c/g;x?y#s"),
+            ("", "http://a/b/c/d;p?q"),
+            (".", "http://a/b/c/"),
+            ("./", "http://a/b/c/"),
+            ("..", "http://a/b/"),
+            ("../", "http://a/b/"),
+            ("../g", "http://a/b/g"),
+            ("../..", "http://a/"),
+            ("../../", "http://a/"),
+            ("../../g", "http://a/g"),
+        ] {
+            assert_eq!(
+                resolve_relative(base, reference),
+                expected,
+                "resolving <{reference}>"
+            );
+        }
+        assert_eq!(resolve_relative(None, "../g"), "../g");
+    }
+}
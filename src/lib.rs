@@ -2,16 +2,29 @@
 //---------------------------------------------------------------
 #![doc = include_str!("../README.md")]
 
+// So that the vocabulary modules can be generated like those of other crates
+extern crate self as rdf_store_rs;
+
 mod c_utils;
 mod class;
 pub mod consts;
 mod data_type;
 mod error;
 mod graph;
+mod iri;
 mod literal;
 mod namespace;
 mod predicate;
+mod prefixed_iri;
+mod prefixes;
+pub mod sparql;
 mod term;
+mod triples;
+#[cfg(feature = "nom-support")]
+mod turtle;
+pub mod vocab;
+#[cfg(feature = "nom-support")]
+mod vocabulary;
 
 pub use {
     c_utils::ptr_to_cstr,
@@ -19,8 +32,31 @@ pub use {
     data_type::DataType,
     error::RDFStoreError,
     graph::Graph,
-    literal::{Literal, LiteralIdUrlDisplay, LiteralUrlDisplay, LiteralValue},
+    literal::{
+        CustomDataType,
+        CustomDataTypeRegistry,
+        FromLiteral,
+        Literal,
+        LiteralIdUrlDisplay,
+        LiteralSparqlDisplay,
+        LiteralUrlDisplay,
+        LiteralValue,
+        ToLiteral,
+        XsdDuration,
+        XsdPartialDate,
+    },
     namespace::Namespace,
     predicate::Predicate,
+    prefixed_iri::PrefixedIri,
+    prefixes::{generate_namespaces, well_known_namespace, well_known_prefix},
     term::Term,
+    triples::{ToTriples, TripleSink, TripleWriter},
 };
+/// Derive [`ToTriples`] for a struct, see the `rdf-store-rs-derive` crate.
+#[cfg(feature = "derive")]
+pub use rdf_store_rs_derive::ToTriples;
+#[cfg(feature = "nom-support")]
+pub use vocabulary::{generate_vocabulary, Vocabulary, VocabularyTerm};
+/// Used by the modules generated from a [`Vocabulary`].
+#[doc(hidden)]
+pub use lazy_static;
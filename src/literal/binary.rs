@@ -0,0 +1,128 @@
+// Copyright (c) 2018-2023, agnos.ai UK Ltd, all rights reserved.
+//---------------------------------------------------------------
+//! `xsd:hexBinary` and `xsd:base64Binary` values.
+//!
+//! Both are kept as their decoded bytes, so `"0fb7"^^xsd:hexBinary` and
+//! `"0FB7"^^xsd:hexBinary` are the same literal, and are only encoded again
+//! to get their canonical lexical form: upper case hexadecimal digits for
+//! `xsd:hexBinary` and base64 without any whitespace for `xsd:base64Binary`.
+
+use crate::{DataType, Literal, RDFStoreError};
+
+const BASE64_ALPHABET: &[u8; 64] =
+    b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+impl Literal {
+    /// Parse the lexical form of an `xsd:hexBinary`, such as `0FB7`, or of an
+    /// `xsd:base64Binary`, such as `D7c=`.
+    pub(crate) fn binary_from_str(
+        buffer: &str,
+        data_type: DataType,
+    ) -> Result<Literal, RDFStoreError> {
+        let bytes = match data_type {
+            DataType::HexBinary => decode_hex(buffer.trim()),
+            DataType::Base64Binary => decode_base64(buffer),
+            _ => None,
+        };
+        match bytes {
+            Some(bytes) => Literal::new_binary_with_datatype(bytes.as_slice(), data_type),
+            None => {
+                Err(RDFStoreError::UnknownValueForDataType { data_type, value: buffer.to_string() })
+            },
+        }
+    }
+
+    /// Create an `xsd:hexBinary` holding the given bytes.
+    pub fn new_hex_binary(bytes: &[u8]) -> Result<Literal, RDFStoreError> {
+        Literal::new_binary_with_datatype(bytes, DataType::HexBinary)
+    }
+
+    /// Create an `xsd:base64Binary` holding the given bytes.
+    pub fn new_base64_binary(bytes: &[u8]) -> Result<Literal, RDFStoreError> {
+        Literal::new_binary_with_datatype(bytes, DataType::Base64Binary)
+    }
+}
+
+/// The canonical lexical form of the given bytes in the given binary
+/// datatype.
+pub(crate) fn canonical_binary(bytes: &[u8], data_type: DataType) -> String {
+    if data_type == DataType::Base64Binary {
+        encode_base64(bytes)
+    } else {
+        encode_hex(bytes)
+    }
+}
+
+fn encode_hex(bytes: &[u8]) -> String { bytes.iter().map(|byte| format!("{byte:02X}")).collect() }
+
+fn decode_hex(hex: &str) -> Option<Vec<u8>> {
+    if hex.len() % 2 != 0 || !hex.bytes().all(|digit| digit.is_ascii_hexdigit()) {
+        return None
+    }
+    (0..hex.len())
+        .step_by(2)
+        .map(|index| u8::from_str_radix(&hex[index..index + 2], 16).ok())
+        .collect()
+}
+
+fn encode_base64(bytes: &[u8]) -> String {
+    let mut base64 = String::with_capacity((bytes.len() + 2) / 3 * 4);
+    for chunk in bytes.chunks(3) {
+        let group = chunk.iter().enumerate().fold(0u32, |group, (index, byte)| {
+            group | u32::from(*byte) << (16 - 8 * index)
+        });
+        for index in 0..4 {
+            if index <= chunk.len() {
+                let sextet = (group >> (18 - 6 * index)) & 0x3F;
+                base64.push(BASE64_ALPHABET[sextet as usize] as char);
+            } else {
+                base64.push('=');
+            }
+        }
+    }
+    base64
+}
+
+/// Decode base64 as allowed by XSD: whitespace may appear anywhere, there
+/// is at most two `=` of padding and the bits that the padding leaves over
+/// must be zero, so `D7c=` is valid but `D7d=` is not.
+fn decode_base64(base64: &str) -> Option<Vec<u8>> {
+    let characters = base64
+        .bytes()
+        .filter(|character| !character.is_ascii_whitespace())
+        .collect::<Vec<u8>>();
+    if characters.len() % 4 != 0 {
+        return None
+    }
+    let padding = characters
+        .iter()
+        .rev()
+        .take_while(|character| **character == b'=')
+        .count();
+    if padding > 2 {
+        return None
+    }
+    let mut bytes = Vec::with_capacity(characters.len() / 4 * 3);
+    for (group_index, group) in characters.chunks(4).enumerate() {
+        let is_last = (group_index + 1) * 4 == characters.len();
+        let length = if is_last { 4 - padding } else { 4 };
+        let mut bits = 0u32;
+        for (index, character) in group.iter().enumerate() {
+            let sextet = if index < length {
+                BASE64_ALPHABET
+                    .iter()
+                    .position(|letter| letter == character)? as u32
+            } else {
+                0
+            };
+            bits = bits << 6 | sextet;
+        }
+        let byte_count = length * 6 / 8;
+        // The bits after the last whole byte must be zero
+        if bits & (0xFF_FFFF >> (8 * byte_count)) != 0 {
+            return None
+        }
+        bytes.extend_from_slice(&bits.to_be_bytes()[1..=byte_count]);
+    }
+    Some(bytes)
+}
@@ -0,0 +1,184 @@
+// Copyright (c) 2018-2023, agnos.ai UK Ltd, all rights reserved.
+//---------------------------------------------------------------
+//! Casting literals from one [`DataType`] to another, following the
+//! [XPath casting rules](https://www.w3.org/TR/xpath-functions/#casting)
+//! as used by SPARQL's
+//! [XSD constructor functions](https://www.w3.org/TR/sparql11-query/#FunctionMapping).
+
+use {
+    crate::{literal::Numeric, DataType, Literal, RDFStoreError, XsdDuration},
+    bigdecimal::FromPrimitive,
+    num_bigint::BigInt,
+};
+
+impl Literal {
+    /// Cast this literal to the given datatype:
+    ///
+    /// - anything but a blank node can be cast to a string;
+    /// - a string can be cast to any datatype of which it is a valid lexical
+    ///   form;
+    /// - numbers and booleans can be cast to each other, where `0` and `NaN`
+    ///   are `false` and any other number is `true`;
+    /// - numbers can be cast to any other numeric datatype, decimals being
+    ///   truncated towards zero when cast to an integer type, as long as the
+    ///   value is within the range of that datatype (so `300` cannot be cast to
+    ///   `xsd:unsignedByte`, and `INF` cannot be cast to any integer type);
+    /// - an `xsd:dateTime` can be cast to an `xsd:date` or an `xsd:time` (of
+    ///   its local date or time), and to an `xsd:dateTimeStamp` if it has a
+    ///   timezone;
+    /// - an `xsd:date` can be cast to an `xsd:dateTime` at midnight, without a
+    ///   timezone;
+    /// - an `xsd:dateTime` or an `xsd:date` can be cast to an `xsd:gYear`,
+    ///   `xsd:gYearMonth`, `xsd:gMonthDay`, `xsd:gDay` or `xsd:gMonth`, which
+    ///   keeps the timezone of a date time;
+    /// - an `xsd:hexBinary` and an `xsd:base64Binary` can be cast to each
+    ///   other, keeping the same bytes;
+    /// - durations can be cast to each other, where an `xsd:yearMonthDuration`
+    ///   only keeps the years and months and an `xsd:dayTimeDuration` only the
+    ///   days, hours, minutes and seconds.
+    ///
+    /// An invalid lexical form or a value that is out of range is an
+    /// [`RDFStoreError::UnknownValueForDataType`], a cast that is not in the
+    /// table above (say from a boolean to a date) is an
+    /// [`RDFStoreError::InvalidCast`].
+    pub fn cast_to(&self, data_type: DataType) -> Result<Literal, RDFStoreError> {
+        let from = self.data_type;
+        let invalid_value =
+            || RDFStoreError::UnknownValueForDataType { data_type, value: self.lexical_form() };
+        if from == data_type {
+            return Ok(self.clone())
+        }
+        if from.is_blank_node() {
+            return Err(RDFStoreError::InvalidCast { from, to: data_type })
+        }
+        if data_type.is_string() {
+            return Literal::new_string_with_datatype(self.lexical_form().as_str(), data_type)
+        }
+        if from.is_string() {
+            if data_type.is_blank_node() || data_type == DataType::UnboundValue {
+                return Err(RDFStoreError::InvalidCast { from, to: data_type })
+            }
+            let lexical_form = self.as_str().unwrap_or_default();
+            return Literal::from_lexical_form(data_type, lexical_form).map_err(|_| invalid_value())
+        }
+        if let Some(integer) = self.as_big_integer() {
+            if data_type.is_integer() {
+                return Literal::new_integer_with_datatype(&integer, data_type)?
+                    .ok_or_else(invalid_value)
+            }
+            if data_type.is_decimal() {
+                return Literal::new_decimal_with_datatype(
+                    format!("{integer}.0").as_str(),
+                    data_type,
+                )
+            }
+            if data_type.is_boolean() {
+                return Literal::new_boolean(integer != BigInt::default())
+            }
+        }
+        if let Some(decimal) = self.as_decimal() {
+            if data_type.is_integer() {
+                let integer_part = decimal.split('.').next().unwrap_or_default();
+                let integer = match integer_part {
+                    "" | "+" | "-" => BigInt::default(),
+                    integer_part => integer_part.parse().map_err(|_| invalid_value())?,
+                };
+                return Literal::new_integer_with_datatype(&integer, data_type)?
+                    .ok_or_else(invalid_value)
+            }
+            if data_type.is_boolean() {
+                return Literal::new_boolean(decimal != "0.0")
+            }
+        }
+        if let Some(boolean) = self.as_boolean() {
+            if data_type.is_integer() {
+                return Literal::new_integer_with_datatype(&BigInt::from(boolean as u8), data_type)?
+                    .ok_or_else(invalid_value)
+            }
+            if data_type.is_decimal() {
+                return Literal::new_decimal_with_datatype(
+                    if boolean { "1.0" } else { "0.0" },
+                    data_type,
+                )
+            }
+        }
+        if data_type.is_floating_point() {
+            let double = match self.as_boolean() {
+                Some(boolean) => Some(if boolean { 1.0 } else { 0.0 }),
+                None => Numeric::from_literal(self).map(|numeric| numeric.as_f64()),
+            };
+            if let Some(double) = double {
+                return Literal::new_double_with_datatype(double, data_type)
+            }
+        }
+        if let Some(double) = self.as_double() {
+            if data_type.is_boolean() {
+                return Literal::new_boolean(double != 0.0 && !double.is_nan())
+            }
+            // `NaN` and the infinities have no integer or decimal value
+            if data_type.is_integer() && double.is_finite() {
+                let integer = BigInt::from_f64(double.trunc()).ok_or_else(invalid_value)?;
+                return Literal::new_integer_with_datatype(&integer, data_type)?
+                    .ok_or_else(invalid_value)
+            }
+            if data_type.is_decimal() && double.is_finite() {
+                // The shortest decimal that reads back as the same float or
+                // double
+                let shortest = if from == DataType::Float {
+                    format!("{}", double as f32)
+                } else {
+                    format!("{double}")
+                };
+                return Literal::decimal_from_str(shortest.as_str())
+            }
+            if data_type.is_integer() || data_type.is_decimal() {
+                return Err(invalid_value())
+            }
+        }
+        if let Some(date_time) = self.as_naive_date_time() {
+            let offset = self.timezone_offset();
+            if data_type.is_date() {
+                return Literal::new_local_date_with_datatype(date_time.date(), offset, data_type)
+            }
+            if data_type.is_date_time() {
+                return Literal::new_local_date_time_with_datatype(date_time, offset, data_type)
+                    .map_err(|_| invalid_value())
+            }
+            if data_type.is_time() {
+                return Literal::new_time_with_datatype(date_time.time(), offset, data_type)
+            }
+        }
+        if data_type.is_date_time() && from.is_date() {
+            if let Some(midnight) = self.as_date().and_then(|date| date.and_hms_opt(0, 0, 0)) {
+                let offset = self.timezone_offset();
+                return Literal::new_local_date_time_with_datatype(midnight, offset, data_type)
+                    .map_err(|_| invalid_value())
+            }
+        }
+        if data_type.is_gregorian() && (from.is_date_time() || from.is_date()) {
+            if let Some(date) = self.partial_date_of(data_type) {
+                return Literal::new_partial_date(&date)
+            }
+        }
+        if let Some(binary) = self.as_binary().filter(|_| data_type.is_binary()) {
+            return Literal::new_binary_with_datatype(binary, data_type)
+        }
+        if let Some(duration) = self.as_xsd_duration() {
+            // Casting to a subtype drops the components it cannot have
+            let duration = match data_type {
+                DataType::YearMonthDuration => XsdDuration::new(duration.months(), 0.into()),
+                DataType::DayTimeDuration => XsdDuration::new(0, duration.seconds().clone()),
+                DataType::Duration => Some(duration),
+                _ => None,
+            };
+            if let Some(duration) = duration {
+                return Literal::new_xsd_duration(&duration, data_type)
+            }
+        }
+        if data_type.is_iri() && from.is_iri() {
+            return Literal::from_type_and_buffer(data_type, self.lexical_form().as_str(), None)?
+                .ok_or_else(invalid_value)
+        }
+        Err(RDFStoreError::InvalidCast { from, to: data_type })
+    }
+}
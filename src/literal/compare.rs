@@ -0,0 +1,208 @@
+// Copyright (c) 2018-2023, agnos.ai UK Ltd, all rights reserved.
+//---------------------------------------------------------------
+//! Comparing literals by value, and the total ordering of literals.
+//!
+//! RDF distinguishes two kinds of equality:
+//!
+//! - *term equality*, implemented by [`PartialEq`]: two literals are the same
+//!   term if they have the same datatype and the same value, so `"1"^^xsd:int`
+//!   and `"1"^^xsd:integer` are different terms;
+//! - *value equality*, implemented by [`Literal::value_equals`] and used by the
+//!   SPARQL `=` operator, under which `"1"^^xsd:int` equals `"01"^^xsd:integer`
+//!   and `"1.0"^^xsd:decimal`.
+//!
+//! The [`Ord`] implementation is a total ordering that is compatible with
+//! [`Literal::value_compare`] wherever values can be compared, as needed
+//! for SPARQL `ORDER BY`.
+
+use {
+    crate::{literal::Numeric, Literal, RDFStoreError, XsdDuration, XsdPartialDate},
+    std::cmp::Ordering,
+};
+
+/// The value of a literal, grouped by the types that can be compared with
+/// each other.
+enum Value<'a> {
+    Numeric(Numeric),
+    String(&'a str),
+    Boolean(bool),
+    DateTime(chrono::DateTime<chrono::Utc>),
+    /// The start of a date in UTC, see [`Literal::date_on_timeline`].
+    Date(chrono::NaiveDateTime),
+    /// A time on the reference date 1972-12-31, as XPath does to compare
+    /// times in different timezones.
+    Time(chrono::NaiveDateTime),
+    Duration(XsdDuration),
+    PartialDate(XsdPartialDate),
+    /// The bytes of an `xsd:hexBinary` or an `xsd:base64Binary`, which are
+    /// compared with each other regardless of their encoding.
+    Binary(&'a [u8]),
+    Other,
+}
+
+impl<'a> Value<'a> {
+    fn of(literal: &'a Literal) -> Self {
+        let data_type = literal.data_type;
+        if data_type.is_iri() || data_type.is_blank_node() {
+            Value::Other
+        } else if let Some(numeric) = Numeric::from_literal(literal) {
+            Value::Numeric(numeric)
+        } else if let Some(str) = literal.as_str().filter(|_| data_type.is_string()) {
+            Value::String(str)
+        } else if let Some(boolean) = literal.as_boolean() {
+            Value::Boolean(boolean)
+        } else if let Some(date_time) = literal.as_date_time() {
+            Value::DateTime(date_time)
+        } else if let Some(date) = literal.date_on_timeline() {
+            Value::Date(date)
+        } else if let Some(time) = literal.time_on_reference_date() {
+            Value::Time(time)
+        } else if let Some(duration) = literal.as_xsd_duration() {
+            Value::Duration(duration)
+        } else if let Some(date) = literal.as_partial_date() {
+            Value::PartialDate(date)
+        } else if let Some(binary) = literal.as_binary() {
+            Value::Binary(binary)
+        } else {
+            Value::Other
+        }
+    }
+
+    /// The position of the group of this value in the total ordering.
+    fn rank(&self) -> u8 {
+        match self {
+            Value::Numeric(_) => 0,
+            Value::String(_) => 1,
+            Value::Boolean(_) => 2,
+            Value::DateTime(_) => 3,
+            Value::Date(_) => 4,
+            Value::Time(_) => 5,
+            Value::Duration(_) => 6,
+            Value::PartialDate(_) => 7,
+            Value::Binary(_) => 8,
+            Value::Other => 9,
+        }
+    }
+
+    /// Like [`Value::compare`], but with `NaN` before all other numbers.
+    fn total_compare(&self, other: &Value) -> Ordering {
+        match (self, other) {
+            (Value::Numeric(a), Value::Numeric(b)) => {
+                a.compare(b)
+                    .unwrap_or_else(|| (!a.is_nan()).cmp(&!b.is_nan()))
+            },
+            (a, b) => a.compare(b).unwrap_or(Ordering::Equal),
+        }
+    }
+
+    fn compare(&self, other: &Value) -> Option<Ordering> {
+        match (self, other) {
+            (Value::Numeric(a), Value::Numeric(b)) => a.compare(b),
+            // Comparing UTF-8 bytes is the same as comparing codepoints
+            (Value::String(a), Value::String(b)) => Some(a.cmp(b)),
+            (Value::Boolean(a), Value::Boolean(b)) => Some(a.cmp(b)),
+            (Value::DateTime(a), Value::DateTime(b)) => Some(a.cmp(b)),
+            (Value::Date(a), Value::Date(b)) => Some(a.cmp(b)),
+            (Value::Time(a), Value::Time(b)) => Some(a.cmp(b)),
+            (Value::Duration(a), Value::Duration(b)) => a.partial_cmp(b),
+            (Value::PartialDate(a), Value::PartialDate(b)) => a.partial_cmp(b),
+            (Value::Binary(a), Value::Binary(b)) => Some(a.cmp(b)),
+            _ => None,
+        }
+    }
+}
+
+impl Literal {
+    /// Compare the values of two literals, as done by the SPARQL `<`, `>`,
+    /// `<=` and `>=` operators: numbers of any numeric datatype with each
+    /// other, strings by codepoint, booleans (`false` < `true`) and
+    /// `xsd:dateTime`s, `xsd:date`s, `xsd:time`s, durations or Gregorian
+    /// partial dates of the same datatype with each other, and the bytes of
+    /// `xsd:hexBinary`s and `xsd:base64Binary`s with each other, where dates,
+    /// date times, times and partial dates without a timezone are taken to
+    /// be in UTC and durations are compared as in XSD, which cannot order
+    /// every two durations.
+    ///
+    /// Returns an [`RDFStoreError::ExpressionError`] for values that cannot
+    /// be compared, such as a string and a number, or `NaN` and any number.
+    pub fn value_compare(&self, other: &Literal) -> Result<Ordering, RDFStoreError> {
+        Value::of(self).compare(&Value::of(other)).ok_or_else(|| {
+            RDFStoreError::ExpressionError {
+                function: "comparison".to_string(),
+                message:  format!(
+                    "cannot compare {:?} with {:?}",
+                    self.data_type, other.data_type
+                ),
+            }
+        })
+    }
+
+    /// Value equality, as done by the SPARQL `=` operator: `true` for the
+    /// same term or for literals with the same value, such as `"1"^^xsd:int`
+    /// and `"01"^^xsd:integer`.
+    ///
+    /// Two different literals whose values cannot be compared are neither
+    /// equal nor unequal, which is an [`RDFStoreError::ExpressionError`].
+    /// IRIs and blank nodes are only equal to themselves.
+    pub fn value_equals(&self, other: &Literal) -> Result<bool, RDFStoreError> {
+        match (Value::of(self), Value::of(other)) {
+            // `NaN` is the same term as `NaN`, but not an equal value
+            (Value::Numeric(a), Value::Numeric(b)) => {
+                return Ok(a.compare(&b) == Some(Ordering::Equal))
+            },
+            // Durations that cannot be ordered, such as `P1M` and `P30D`,
+            // are still unequal
+            (Value::Duration(a), Value::Duration(b)) => return Ok(a == b),
+            // As are partial dates such as `2000Z` and `2000`
+            (Value::PartialDate(a), Value::PartialDate(b))
+                if a.data_type() == b.data_type() =>
+            {
+                return Ok(a == b)
+            },
+            _ => {},
+        }
+        if self == other {
+            return Ok(true)
+        }
+        match self.value_compare(other) {
+            Ok(ordering) => Ok(ordering == Ordering::Equal),
+            Err(error) if self.is_literal_value() && other.is_literal_value() => Err(error),
+            Err(_) => Ok(false),
+        }
+    }
+
+    fn is_literal_value(&self) -> bool {
+        !self.data_type.is_iri() && !self.data_type.is_blank_node()
+    }
+}
+
+impl PartialOrd for Literal {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> { Some(self.cmp(other)) }
+}
+
+/// Orders blank nodes first, then IRIs and then all other literals, which
+/// are ordered by value within numbers (with `NaN` first), strings,
+/// booleans, date-times, dates, times, durations, Gregorian partial dates and
+/// binary values (in that order). Equal
+/// values of different datatypes are ordered by datatype IRI, and anything else
+/// by lexical form.
+impl Ord for Literal {
+    fn cmp(&self, other: &Self) -> Ordering {
+        fn kind(literal: &Literal) -> u8 {
+            if literal.data_type.is_blank_node() {
+                0
+            } else if literal.data_type.is_iri() {
+                1
+            } else {
+                2
+            }
+        }
+        let (a, b) = (Value::of(self), Value::of(other));
+        kind(self)
+            .cmp(&kind(other))
+            .then_with(|| a.rank().cmp(&b.rank()))
+            .then_with(|| a.total_compare(&b))
+            .then_with(|| self.data_type_iri().cmp(other.data_type_iri()))
+            .then_with(|| self.lexical_form().cmp(&other.lexical_form()))
+    }
+}
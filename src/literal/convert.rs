@@ -0,0 +1,341 @@
+// Copyright (c) 2018-2023, agnos.ai UK Ltd, all rights reserved.
+//---------------------------------------------------------------
+//! Converting Rust values to and from literals with [`ToLiteral`] and
+//! [`FromLiteral`], or `TryFrom<&Literal>`.
+//!
+//! Each Rust type maps to the XSD datatype that has the same range, so an
+//! `i32` becomes an `xsd:int` and a `u8` an `xsd:unsignedByte`. In the other
+//! direction, any literal whose value fits is accepted, so an `i32` can be
+//! read from an `xsd:integer` as long as it is within the range of an `i32`.
+//!
+//! ```rust
+//! use rdf_store_rs::{DataType, FromLiteral, Literal, ToLiteral};
+//!
+//! let literal = 42_u16.to_literal().unwrap();
+//! assert_eq!(literal.data_type, DataType::UnsignedShort);
+//! assert_eq!(i64::from_literal(&literal).unwrap(), 42);
+//! assert!(i8::try_from(&(1000_i32.to_literal().unwrap())).is_err());
+//! ```
+
+use {
+    crate::{DataType, Literal, RDFStoreError},
+    chrono::{DateTime, FixedOffset, NaiveDate, NaiveDateTime, NaiveTime, Utc},
+    iref::IriBuf,
+    num_bigint::BigInt,
+    std::str::FromStr,
+};
+
+/// A Rust value that can be turned into a [`Literal`].
+pub trait ToLiteral {
+    fn to_literal(&self) -> Result<Literal, RDFStoreError>;
+
+    /// The literal of this value, or `None` if there is no value, which is
+    /// only the case for [`Option::None`].
+    fn to_optional_literal(&self) -> Result<Option<Literal>, RDFStoreError> {
+        self.to_literal().map(Some)
+    }
+}
+
+/// A Rust value that can be read from a [`Literal`].
+pub trait FromLiteral: Sized {
+    /// Read a value from the given literal, which is an
+    /// [`RDFStoreError::InvalidCast`] if the literal has another kind of
+    /// datatype or an [`RDFStoreError::UnknownValueForDataType`] if its
+    /// value is out of range.
+    fn from_literal(literal: &Literal) -> Result<Self, RDFStoreError>;
+
+    /// Read a value from a literal that may be missing, such as an unbound
+    /// variable in a query result, which is only allowed for an [`Option`].
+    fn from_optional_literal(literal: Option<&Literal>) -> Result<Self, RDFStoreError> {
+        match literal {
+            Some(literal) => Self::from_literal(literal),
+            None => {
+                Err(RDFStoreError::MissingLiteral {
+                    type_name: std::any::type_name::<Self>().to_string(),
+                })
+            },
+        }
+    }
+}
+
+impl<T: ToLiteral + ?Sized> ToLiteral for &T {
+    fn to_literal(&self) -> Result<Literal, RDFStoreError> { (**self).to_literal() }
+
+    fn to_optional_literal(&self) -> Result<Option<Literal>, RDFStoreError> {
+        (**self).to_optional_literal()
+    }
+}
+
+impl<T: ToLiteral> ToLiteral for Option<T> {
+    /// Returns an [`RDFStoreError::MissingLiteral`] for `None`, see
+    /// [`ToLiteral::to_optional_literal`] for the conversion that allows it.
+    fn to_literal(&self) -> Result<Literal, RDFStoreError> {
+        match self {
+            Some(value) => value.to_literal(),
+            None => {
+                Err(RDFStoreError::MissingLiteral {
+                    type_name: std::any::type_name::<T>().to_string(),
+                })
+            },
+        }
+    }
+
+    fn to_optional_literal(&self) -> Result<Option<Literal>, RDFStoreError> {
+        match self {
+            Some(value) => value.to_optional_literal(),
+            None => Ok(None),
+        }
+    }
+}
+
+impl<T: FromLiteral> FromLiteral for Option<T> {
+    fn from_literal(literal: &Literal) -> Result<Self, RDFStoreError> {
+        T::from_literal(literal).map(Some)
+    }
+
+    fn from_optional_literal(literal: Option<&Literal>) -> Result<Self, RDFStoreError> {
+        literal.map(T::from_literal).transpose()
+    }
+}
+
+/// Implements `TryFrom<&Literal>` for types that implement [`FromLiteral`].
+macro_rules! try_from_literal {
+    ($($type:ty),* $(,)?) => {
+        $(
+            impl TryFrom<&Literal> for $type {
+                type Error = RDFStoreError;
+
+                fn try_from(literal: &Literal) -> Result<Self, Self::Error> {
+                    <$type as FromLiteral>::from_literal(literal)
+                }
+            }
+        )*
+    };
+}
+
+/// Implements [`ToLiteral`] and [`FromLiteral`] for integer types, mapped
+/// to the XSD datatype with the same range.
+macro_rules! integer_literal {
+    ($($type:ty => $data_type:expr),* $(,)?) => {
+        $(
+            impl ToLiteral for $type {
+                fn to_literal(&self) -> Result<Literal, RDFStoreError> {
+                    Literal::new_integer_with_datatype(&BigInt::from(*self), $data_type)?
+                        .ok_or_else(|| out_of_range($data_type, self))
+                }
+            }
+
+            impl FromLiteral for $type {
+                fn from_literal(literal: &Literal) -> Result<Self, RDFStoreError> {
+                    let integer = literal
+                        .as_big_integer()
+                        .ok_or_else(|| invalid_cast(literal, $data_type))?;
+                    <$type>::try_from(&integer).map_err(|_| out_of_range($data_type, &integer))
+                }
+            }
+
+            try_from_literal!($type);
+        )*
+    };
+}
+
+integer_literal! {
+    i8 => DataType::Byte,
+    i16 => DataType::Short,
+    i32 => DataType::Int,
+    i64 => DataType::Long,
+    isize => DataType::Long,
+    i128 => DataType::Integer,
+    u8 => DataType::UnsignedByte,
+    u16 => DataType::UnsignedShort,
+    u32 => DataType::UnsignedInt,
+    u64 => DataType::UnsignedLong,
+    usize => DataType::UnsignedLong,
+    u128 => DataType::Integer,
+}
+
+impl ToLiteral for BigInt {
+    fn to_literal(&self) -> Result<Literal, RDFStoreError> { Literal::new_big_integer(self) }
+}
+
+impl FromLiteral for BigInt {
+    fn from_literal(literal: &Literal) -> Result<Self, RDFStoreError> {
+        literal
+            .as_big_integer()
+            .ok_or_else(|| invalid_cast(literal, DataType::Integer))
+    }
+}
+
+impl ToLiteral for bool {
+    fn to_literal(&self) -> Result<Literal, RDFStoreError> { Literal::new_boolean(*self) }
+}
+
+impl FromLiteral for bool {
+    fn from_literal(literal: &Literal) -> Result<Self, RDFStoreError> {
+        literal
+            .as_boolean()
+            .ok_or_else(|| invalid_cast(literal, DataType::Boolean))
+    }
+}
+
+impl ToLiteral for f32 {
+    fn to_literal(&self) -> Result<Literal, RDFStoreError> { Literal::new_float(*self) }
+}
+
+impl FromLiteral for f32 {
+    /// Only an `xsd:float` is read, as an `xsd:double` may lose precision.
+    fn from_literal(literal: &Literal) -> Result<Self, RDFStoreError> {
+        match literal.as_double() {
+            Some(float) if literal.data_type == DataType::Float => Ok(float as f32),
+            _ => Err(invalid_cast(literal, DataType::Float)),
+        }
+    }
+}
+
+impl ToLiteral for f64 {
+    fn to_literal(&self) -> Result<Literal, RDFStoreError> { Literal::new_double(*self) }
+}
+
+impl FromLiteral for f64 {
+    fn from_literal(literal: &Literal) -> Result<Self, RDFStoreError> {
+        literal
+            .as_double()
+            .ok_or_else(|| invalid_cast(literal, DataType::Double))
+    }
+}
+
+impl ToLiteral for str {
+    fn to_literal(&self) -> Result<Literal, RDFStoreError> {
+        Literal::new_string_with_datatype(self, DataType::String)
+    }
+}
+
+impl ToLiteral for String {
+    fn to_literal(&self) -> Result<Literal, RDFStoreError> { self.as_str().to_literal() }
+}
+
+impl FromLiteral for String {
+    /// Only strings are read, use [`Literal::lexical_form`] for the lexical
+    /// form of any other literal.
+    fn from_literal(literal: &Literal) -> Result<Self, RDFStoreError> {
+        literal
+            .as_str()
+            .filter(|_| literal.data_type.is_string())
+            .map(str::to_string)
+            .ok_or_else(|| invalid_cast(literal, DataType::String))
+    }
+}
+
+impl ToLiteral for NaiveDate {
+    fn to_literal(&self) -> Result<Literal, RDFStoreError> {
+        Literal::new_date_with_datatype(*self, DataType::Date)
+    }
+}
+
+impl FromLiteral for NaiveDate {
+    fn from_literal(literal: &Literal) -> Result<Self, RDFStoreError> {
+        literal
+            .as_date()
+            .filter(|_| literal.data_type.is_date())
+            .ok_or_else(|| invalid_cast(literal, DataType::Date))
+    }
+}
+
+impl ToLiteral for NaiveDateTime {
+    /// An `xsd:dateTime` without a timezone.
+    fn to_literal(&self) -> Result<Literal, RDFStoreError> {
+        Literal::new_local_date_time_with_datatype(*self, None, DataType::DateTime)
+    }
+}
+
+impl FromLiteral for NaiveDateTime {
+    /// The local date and time, without the timezone, if any.
+    fn from_literal(literal: &Literal) -> Result<Self, RDFStoreError> {
+        literal
+            .as_naive_date_time()
+            .ok_or_else(|| invalid_cast(literal, DataType::DateTime))
+    }
+}
+
+impl ToLiteral for DateTime<Utc> {
+    fn to_literal(&self) -> Result<Literal, RDFStoreError> {
+        Literal::new_date_time_with_datatype(*self, DataType::DateTime)
+    }
+}
+
+impl FromLiteral for DateTime<Utc> {
+    /// A date time without a timezone is taken to be in UTC.
+    fn from_literal(literal: &Literal) -> Result<Self, RDFStoreError> {
+        literal
+            .as_date_time()
+            .ok_or_else(|| invalid_cast(literal, DataType::DateTime))
+    }
+}
+
+impl ToLiteral for DateTime<FixedOffset> {
+    fn to_literal(&self) -> Result<Literal, RDFStoreError> {
+        Literal::new_date_time_with_offset(*self, DataType::DateTime)
+    }
+}
+
+impl FromLiteral for DateTime<FixedOffset> {
+    /// A date time without a timezone is taken to be in UTC.
+    fn from_literal(literal: &Literal) -> Result<Self, RDFStoreError> {
+        let utc = DateTime::<Utc>::from_literal(literal)?;
+        let offset = literal
+            .timezone_offset()
+            .unwrap_or_else(|| FixedOffset::east_opt(0).unwrap());
+        Ok(utc.with_timezone(&offset))
+    }
+}
+
+impl ToLiteral for NaiveTime {
+    /// An `xsd:time` without a timezone.
+    fn to_literal(&self) -> Result<Literal, RDFStoreError> {
+        Literal::new_time_with_datatype(*self, None, DataType::Time)
+    }
+}
+
+impl FromLiteral for NaiveTime {
+    /// The local time, without the timezone, if any.
+    fn from_literal(literal: &Literal) -> Result<Self, RDFStoreError> {
+        literal
+            .as_time()
+            .ok_or_else(|| invalid_cast(literal, DataType::Time))
+    }
+}
+
+impl ToLiteral for IriBuf {
+    fn to_literal(&self) -> Result<Literal, RDFStoreError> { Literal::from_iri(self.as_iri()) }
+}
+
+impl FromLiteral for IriBuf {
+    fn from_literal(literal: &Literal) -> Result<Self, RDFStoreError> {
+        let iri = literal
+            .as_iri()
+            .ok_or_else(|| invalid_cast(literal, DataType::IriReference))?;
+        Ok(IriBuf::from_str(iri.as_str())?)
+    }
+}
+
+try_from_literal!(
+    BigInt,
+    bool,
+    f32,
+    f64,
+    String,
+    NaiveDate,
+    NaiveDateTime,
+    DateTime<Utc>,
+    DateTime<FixedOffset>,
+    NaiveTime,
+    IriBuf,
+);
+
+fn invalid_cast(literal: &Literal, data_type: DataType) -> RDFStoreError {
+    RDFStoreError::InvalidCast { from: literal.data_type, to: data_type }
+}
+
+fn out_of_range(data_type: DataType, value: &impl ToString) -> RDFStoreError {
+    RDFStoreError::UnknownValueForDataType { data_type, value: value.to_string() }
+}
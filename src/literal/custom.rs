@@ -0,0 +1,150 @@
+// Copyright (c) 2018-2023, agnos.ai UK Ltd, all rights reserved.
+//---------------------------------------------------------------
+//! Literals of datatypes that are not built in, such as `geo:wktLiteral`,
+//! `rdf:HTML` or any datatype of an application.
+//!
+//! Such a literal is a [`DataType::Custom`] that keeps its datatype IRI and
+//! its lexical form. By default, any lexical form is accepted and kept as
+//! is, and two custom literals are only equal if they are the same term.
+//! Applications can register a [`CustomDataType`] for a datatype IRI in the
+//! [`CustomDataTypeRegistry`] to validate and canonicalize its lexical forms.
+
+use {
+    crate::{DataType, Literal, RDFStoreError},
+    iref::Iri,
+    lazy_static::lazy_static,
+    std::{
+        collections::HashMap,
+        sync::{Arc, RwLock},
+    },
+};
+
+/// The validation and canonicalization of the lexical forms of a datatype
+/// that is not built in.
+///
+/// Any function or closure `Fn(&str) -> Option<String>` is a
+/// `CustomDataType`.
+pub trait CustomDataType: Send + Sync {
+    /// The canonical form of the given lexical form, or `None` if it is not
+    /// a valid lexical form of this datatype.
+    fn canonical_form(&self, lexical_form: &str) -> Option<String>;
+}
+
+impl<F> CustomDataType for F
+where F: Fn(&str) -> Option<String> + Send + Sync
+{
+    fn canonical_form(&self, lexical_form: &str) -> Option<String> { self(lexical_form) }
+}
+
+lazy_static! {
+    static ref REGISTRY: RwLock<HashMap<String, Arc<dyn CustomDataType>>> =
+        RwLock::new(HashMap::new());
+}
+
+/// The process-wide registry of [`CustomDataType`]s by datatype IRI, which
+/// [`Literal::new_custom`] consults.
+///
+/// ```rust
+/// use rdf_store_rs::{CustomDataTypeRegistry, Literal};
+///
+/// const CURRENCY: &str = "https://ekgf.org/ontology/currency";
+///
+/// CustomDataTypeRegistry::register(CURRENCY, |code: &str| {
+///     let code = code.to_uppercase();
+///     (code.len() == 3 && code.chars().all(|c| c.is_ascii_alphabetic())).then_some(code)
+/// })
+/// .unwrap();
+///
+/// let currency = Literal::new_custom(CURRENCY, "eur").unwrap();
+/// assert_eq!(currency.lexical_form(), "EUR");
+/// assert!(Literal::new_custom(CURRENCY, "euro").is_err());
+/// ```
+pub struct CustomDataTypeRegistry;
+
+impl CustomDataTypeRegistry {
+    /// Register the validation and canonicalization of the datatype with
+    /// the given IRI, replacing any earlier registration. Built-in
+    /// datatypes such as `xsd:integer` cannot be registered.
+    pub fn register(
+        data_type_iri: &str,
+        data_type: impl CustomDataType + 'static,
+    ) -> Result<(), RDFStoreError> {
+        check_data_type_iri(data_type_iri)?;
+        REGISTRY
+            .write()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+            .insert(data_type_iri.to_string(), Arc::new(data_type));
+        Ok(())
+    }
+
+    /// Remove the registration of the datatype with the given IRI, returns
+    /// `false` if it was not registered.
+    pub fn unregister(data_type_iri: &str) -> bool {
+        REGISTRY
+            .write()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+            .remove(data_type_iri)
+            .is_some()
+    }
+
+    pub fn is_registered(data_type_iri: &str) -> bool { Self::get(data_type_iri).is_some() }
+
+    fn get(data_type_iri: &str) -> Option<Arc<dyn CustomDataType>> {
+        REGISTRY
+            .read()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+            .get(data_type_iri)
+            .cloned()
+    }
+}
+
+impl Literal {
+    /// Create a literal of a datatype that is not built in, such as
+    /// `"POINT(4.9 52.4)"^^geo:wktLiteral`. If a [`CustomDataType`] has been
+    /// registered for the datatype IRI, the lexical form is validated and
+    /// canonicalized by it, otherwise it is kept as is.
+    ///
+    /// Returns an [`RDFStoreError::BuiltInDataType`] for the IRI of a built-in
+    /// datatype, use [`Literal::from_data_type_iri`] for any datatype.
+    pub fn new_custom(data_type_iri: &str, lexical_form: &str) -> Result<Literal, RDFStoreError> {
+        check_data_type_iri(data_type_iri)?;
+        let lexical_form = match CustomDataTypeRegistry::get(data_type_iri) {
+            Some(data_type) => {
+                data_type.canonical_form(lexical_form).ok_or_else(|| {
+                    RDFStoreError::InvalidValueForCustomDataType {
+                        data_type_iri: data_type_iri.to_string(),
+                        value:         lexical_form.to_string(),
+                    }
+                })?
+            },
+            None => lexical_form.to_string(),
+        };
+        Ok(Literal::new_custom_with_datatype_iri(
+            data_type_iri,
+            lexical_form.as_str(),
+        ))
+    }
+
+    /// Create a literal from its lexical form and the IRI of its datatype,
+    /// using [`Literal::from_lexical_form`] for built-in datatypes and
+    /// [`Literal::new_custom`] for any other datatype.
+    pub fn from_data_type_iri(
+        data_type_iri: &str,
+        lexical_form: &str,
+    ) -> Result<Literal, RDFStoreError> {
+        match DataType::from_xsd_iri(data_type_iri) {
+            Ok(data_type) => Literal::from_lexical_form(data_type, lexical_form),
+            Err(_) => Literal::new_custom(data_type_iri, lexical_form),
+        }
+    }
+}
+
+/// A custom datatype must have an absolute IRI that is not the IRI of a
+/// built-in datatype.
+fn check_data_type_iri(data_type_iri: &str) -> Result<(), RDFStoreError> {
+    if DataType::from_xsd_iri(data_type_iri).is_ok() {
+        return Err(RDFStoreError::BuiltInDataType { data_type_iri: data_type_iri.to_string() })
+    }
+    Iri::new(data_type_iri)?;
+    Ok(())
+}
@@ -0,0 +1,296 @@
+// Copyright (c) 2018-2023, agnos.ai UK Ltd, all rights reserved.
+//---------------------------------------------------------------
+//! `xsd:date`, `xsd:dateTime`, `xsd:dateTimeStamp` and `xsd:time` values.
+//!
+//! These are kept as the local date, date time or time together with the
+//! timezone offset of the lexical form, or without one if the lexical form
+//! has no timezone, so that `2023-01-01T10:00:00+01:00` is written back as
+//! it was read rather than as `2023-01-01T09:00:00Z`. As RDF terms, those two
+//! are different literals, but they have the same value, see
+//! [`Literal::value_equals`].
+//!
+//! A value without a timezone is compared with values that have one as if it
+//! were in UTC.
+
+use {
+    crate::{DataType, Literal, RDFStoreError},
+    chrono::{Datelike, Duration, FixedOffset, NaiveDate, NaiveDateTime, NaiveTime, Timelike},
+    lazy_static::lazy_static,
+};
+
+lazy_static! {
+    /// The lexical space of `xsd:dateTime`, such as
+    /// `2023-01-01T10:00:00.5+01:00`.
+    static ref DATE_TIME: fancy_regex::Regex = fancy_regex::Regex::new(
+        r"^(-?\d{4,})-(\d{2})-(\d{2})T(\d{2}):(\d{2}):(\d{2})(\.\d+)?(Z|[+-]\d{2}:\d{2})?$"
+    )
+    .unwrap();
+    /// The lexical space of `xsd:date`, such as `2023-01-01+01:00`.
+    static ref DATE: fancy_regex::Regex =
+        fancy_regex::Regex::new(r"^(-?\d{4,})-(\d{2})-(\d{2})(Z|[+-]\d{2}:\d{2})?$").unwrap();
+    /// The lexical space of `xsd:time`, such as `10:00:00Z`.
+    static ref TIME: fancy_regex::Regex =
+        fancy_regex::Regex::new(r"^(\d{2}):(\d{2}):(\d{2})(\.\d+)?(Z|[+-]\d{2}:\d{2})?$")
+            .unwrap();
+}
+
+impl Literal {
+    /// Parse an `xsd:dateTime` or `xsd:dateTimeStamp`, strictly following
+    /// the XSD syntax: `[-]YYYY-MM-DDThh:mm:ss[.s+][Z|(+|-)hh:mm]`, where
+    /// `24:00:00` is midnight at the end of the day. An `xsd:dateTimeStamp`
+    /// must have a timezone.
+    pub(crate) fn date_time_from_str(
+        buffer: &str,
+        data_type: DataType,
+    ) -> Result<Literal, RDFStoreError> {
+        let invalid_value =
+            || RDFStoreError::UnknownValueForDataType { data_type, value: buffer.to_string() };
+        let captures = DATE_TIME
+            .captures(buffer.trim())
+            .ok()
+            .flatten()
+            .ok_or_else(invalid_value)?;
+        let number = |index: usize| {
+            captures
+                .get(index)
+                .and_then(|m| m.as_str().parse::<i32>().ok())
+        };
+        let date = match (number(1), number(2), number(3)) {
+            (Some(year), Some(month), Some(day)) => {
+                NaiveDate::from_ymd_opt(year, month as u32, day as u32)
+            },
+            _ => None,
+        };
+        let time = parse_time(
+            number(4),
+            number(5),
+            number(6),
+            captures.get(7).map(|m| m.as_str()),
+        );
+        let offset = parse_timezone(captures.get(8).map(|m| m.as_str()));
+        match (date, time, offset) {
+            (Some(date), Some((time, next_day)), Some(offset)) => {
+                let mut date_time = date.and_time(time);
+                if next_day {
+                    date_time = date_time
+                        .checked_add_signed(Duration::days(1))
+                        .ok_or_else(invalid_value)?;
+                }
+                Literal::new_local_date_time_with_datatype(date_time, offset, data_type)
+            },
+            _ => Err(invalid_value()),
+        }
+    }
+
+    /// Parse an `xsd:date`, strictly following the XSD syntax:
+    /// `[-]YYYY-MM-DD[Z|(+|-)hh:mm]`.
+    pub(crate) fn xsd_date_from_str(buffer: &str) -> Result<Literal, RDFStoreError> {
+        let invalid_value = || {
+            RDFStoreError::UnknownValueForDataType {
+                data_type: DataType::Date,
+                value:     buffer.to_string(),
+            }
+        };
+        let captures = DATE
+            .captures(buffer.trim())
+            .ok()
+            .flatten()
+            .ok_or_else(invalid_value)?;
+        let number = |index: usize| {
+            captures
+                .get(index)
+                .and_then(|m| m.as_str().parse::<i32>().ok())
+        };
+        let date = match (number(1), number(2), number(3)) {
+            (Some(year), Some(month), Some(day)) => {
+                NaiveDate::from_ymd_opt(year, month as u32, day as u32)
+            },
+            _ => None,
+        };
+        match (
+            date,
+            parse_timezone(captures.get(4).map(|m| m.as_str())),
+        ) {
+            (Some(date), Some(offset)) => {
+                Literal::new_local_date_with_datatype(date, offset, DataType::Date)
+            },
+            _ => Err(invalid_value()),
+        }
+    }
+
+    /// Parse an `xsd:time`, strictly following the XSD syntax:
+    /// `hh:mm:ss[.s+][Z|(+|-)hh:mm]`, where `24:00:00` is the same as
+    /// `00:00:00`.
+    pub(crate) fn time_from_str(buffer: &str) -> Result<Literal, RDFStoreError> {
+        let invalid_value = || {
+            RDFStoreError::UnknownValueForDataType {
+                data_type: DataType::Time,
+                value:     buffer.to_string(),
+            }
+        };
+        let captures = TIME
+            .captures(buffer.trim())
+            .ok()
+            .flatten()
+            .ok_or_else(invalid_value)?;
+        let number = |index: usize| {
+            captures
+                .get(index)
+                .and_then(|m| m.as_str().parse::<i32>().ok())
+        };
+        let time = parse_time(
+            number(1),
+            number(2),
+            number(3),
+            captures.get(4).map(|m| m.as_str()),
+        );
+        match (
+            time,
+            parse_timezone(captures.get(5).map(|m| m.as_str())),
+        ) {
+            (Some((time, _)), Some(offset)) => {
+                Literal::new_time_with_datatype(time, offset, DataType::Time)
+            },
+            _ => Err(invalid_value()),
+        }
+    }
+}
+
+impl Literal {
+    /// The start of an `xsd:date` as a date time in UTC, which is how dates
+    /// in different timezones are compared and subtracted. A date without a
+    /// timezone is taken to be in UTC.
+    pub(crate) fn date_on_timeline(&self) -> Option<NaiveDateTime> {
+        if !self.data_type.is_date() {
+            return None
+        }
+        let offset = self
+            .timezone_offset()
+            .map_or(0, |offset| offset.local_minus_utc());
+        let date_time = self.as_date()?.and_hms_opt(0, 0, 0)?;
+        Some(date_time - Duration::seconds(offset.into()))
+    }
+
+    /// An `xsd:time` as a date time in UTC on the reference date 1972-12-31,
+    /// which XPath uses to compare and subtract times in different
+    /// timezones. A time without a timezone is taken to be in UTC.
+    pub(crate) fn time_on_reference_date(&self) -> Option<NaiveDateTime> {
+        let offset = self
+            .timezone_offset()
+            .map_or(0, |offset| offset.local_minus_utc());
+        let date_time = NaiveDate::from_ymd_opt(1972, 12, 31)?.and_time(self.as_time()?);
+        Some(date_time - Duration::seconds(offset.into()))
+    }
+}
+
+/// The time of day of the given components, and whether it is `24:00:00`,
+/// which is returned as `00:00:00` on the next day.
+fn parse_time(
+    hour: Option<i32>,
+    minute: Option<i32>,
+    second: Option<i32>,
+    fraction: Option<&str>,
+) -> Option<(NaiveTime, bool)> {
+    let (hour, minute, second) = (hour? as u32, minute? as u32, second? as u32);
+    // Nanoseconds are the highest precision we keep, further digits are
+    // dropped
+    let digits = fraction.map_or("", |fraction| &fraction[1..]);
+    let nanoseconds = format!("{:0<9.9}", digits).parse::<u32>().ok()?;
+    if hour == 24 {
+        return if minute == 0 && second == 0 && nanoseconds == 0 {
+            NaiveTime::from_hms_opt(0, 0, 0).map(|midnight| (midnight, true))
+        } else {
+            None
+        }
+    }
+    // Unlike chrono, XSD has no leap seconds
+    if second > 59 {
+        return None
+    }
+    NaiveTime::from_hms_nano_opt(hour, minute, second, nanoseconds).map(|time| (time, false))
+}
+
+/// The offset of a timezone such as `Z` or `-05:00`, which is at most 14
+/// hours, or `Some(None)` for no timezone at all.
+pub(crate) fn parse_timezone(timezone: Option<&str>) -> Option<Option<FixedOffset>> {
+    let timezone = match timezone {
+        None => return Some(None),
+        Some("Z") => return Some(FixedOffset::east_opt(0)),
+        Some(timezone) => timezone,
+    };
+    let sign = if timezone.starts_with('-') { -1 } else { 1 };
+    let hours: i32 = timezone.get(1..3)?.parse().ok()?;
+    let minutes: i32 = timezone.get(4..6)?.parse().ok()?;
+    if minutes > 59 || hours * 60 + minutes > 14 * 60 {
+        return None
+    }
+    FixedOffset::east_opt(sign * (hours * 3600 + minutes * 60)).map(Some)
+}
+
+/// The canonical lexical form of a date such as `2023-01-01+01:00`.
+pub(crate) fn canonical_date(date: &NaiveDate, offset: Option<FixedOffset>) -> String {
+    format!(
+        "{}{}",
+        canonical_year_month_day(date),
+        canonical_timezone(offset)
+    )
+}
+
+/// The canonical lexical form of a date time such as
+/// `2023-01-01T10:00:00.5+01:00`.
+pub(crate) fn canonical_date_time(
+    date_time: &NaiveDateTime,
+    offset: Option<FixedOffset>,
+) -> String {
+    format!(
+        "{}T{}",
+        canonical_year_month_day(&date_time.date()),
+        canonical_time(&date_time.time(), offset)
+    )
+}
+
+/// The date part of a date or date time such as `2023-01-01`, with at least
+/// four digits for the year.
+fn canonical_year_month_day(date: &NaiveDate) -> String {
+    let year = date.year();
+    let year = if year < 0 {
+        format!("-{:04}", -year)
+    } else {
+        format!("{year:04}")
+    };
+    format!("{year}-{:02}-{:02}", date.month(), date.day())
+}
+
+/// The canonical lexical form of a time such as `10:00:00.5+01:00`, without
+/// trailing zeros in the fractional seconds.
+pub(crate) fn canonical_time(time: &NaiveTime, offset: Option<FixedOffset>) -> String {
+    let fraction = match time.nanosecond() {
+        0 => String::new(),
+        nanoseconds => {
+            format!(".{nanoseconds:09}")
+                .trim_end_matches('0')
+                .to_string()
+        },
+    };
+    format!(
+        "{:02}:{:02}:{:02}{fraction}{}",
+        time.hour(),
+        time.minute(),
+        time.second(),
+        canonical_timezone(offset)
+    )
+}
+
+/// The canonical lexical form of a timezone: `Z` for UTC, `+hh:mm` or
+/// `-hh:mm` for any other offset, and nothing for no timezone.
+pub(crate) fn canonical_timezone(offset: Option<FixedOffset>) -> String {
+    match offset.map(|offset| offset.local_minus_utc()) {
+        None => String::new(),
+        Some(0) => "Z".to_string(),
+        Some(seconds) => {
+            let sign = if seconds < 0 { '-' } else { '+' };
+            let minutes = seconds.abs() / 60;
+            format!("{sign}{:02}:{:02}", minutes / 60, minutes % 60)
+        },
+    }
+}
@@ -0,0 +1,57 @@
+// Copyright (c) 2018-2023, agnos.ai UK Ltd, all rights reserved.
+//---------------------------------------------------------------
+//! Exact `xsd:decimal` values.
+//!
+//! Decimals are kept in their
+//! [canonical lexical form](https://www.w3.org/TR/xmlschema-2/#decimal-canonical-representation)
+//! (no leading `+`, no leading or trailing zeros other than a single zero on
+//! either side of the decimal point), so that `1.0` and `01.00` are the same
+//! value, the same term and have the same hash. Calculations are done on a
+//! [`BigDecimal`], without any rounding errors.
+
+use {
+    crate::{DataType, Literal, RDFStoreError},
+    bigdecimal::BigDecimal,
+};
+
+impl Literal {
+    /// The exact value of an `xsd:decimal`.
+    pub fn as_big_decimal(&self) -> Option<BigDecimal> {
+        self.as_decimal().and_then(|decimal| decimal.parse().ok())
+    }
+
+    /// Parse an `xsd:decimal`, which is an optional sign followed by digits
+    /// with at most one decimal point (and no exponent).
+    pub(crate) fn decimal_from_str(buffer: &str) -> Result<Literal, RDFStoreError> {
+        let lexical_form = buffer.trim();
+        let unsigned = lexical_form
+            .strip_prefix(|c| c == '+' || c == '-')
+            .unwrap_or(lexical_form);
+        let digits = |str: &str| str.bytes().all(|byte| byte.is_ascii_digit());
+        let valid = match unsigned.split_once('.') {
+            Some((integer, fraction)) => {
+                !(integer.is_empty() && fraction.is_empty()) && digits(integer) && digits(fraction)
+            },
+            None => !unsigned.is_empty() && digits(unsigned),
+        };
+        match lexical_form.parse::<BigDecimal>() {
+            Ok(decimal) if valid => Literal::new_decimal(&decimal),
+            _ => {
+                Err(RDFStoreError::UnknownValueForDataType {
+                    data_type: DataType::Decimal,
+                    value:     buffer.to_string(),
+                })
+            },
+        }
+    }
+}
+
+/// The canonical lexical form of a decimal, such as `100.0` or `-0.25`.
+pub(crate) fn canonical_decimal(decimal: &BigDecimal) -> String {
+    let decimal = decimal.normalized();
+    if decimal.fractional_digit_count() <= 0 {
+        format!("{}.0", decimal.with_scale(0).to_plain_string())
+    } else {
+        decimal.to_plain_string()
+    }
+}
@@ -0,0 +1,93 @@
+// Copyright (c) 2018-2023, agnos.ai UK Ltd, all rights reserved.
+//---------------------------------------------------------------
+//! `xsd:float` and `xsd:double` values.
+//!
+//! Both are kept as an `f64`, where an `xsd:float` is always rounded to the
+//! nearest `f32`. Their lexical forms are written in the
+//! [canonical form](https://www.w3.org/TR/xmlschema11-2/#f-doubleCanonicalMap)
+//! with a mantissa and an exponent (such as `1.5E2`), or as `INF`, `-INF` or
+//! `NaN`.
+//!
+//! As RDF terms, two floating point literals are equal if their canonical
+//! lexical forms are equal, so `NaN` is the same term as `NaN` (even though
+//! it is not equal to itself in SPARQL comparisons) and `0.0E0` is a
+//! different term than `-0.0E0`.
+
+use crate::{DataType, Literal, RDFStoreError};
+
+/// The canonical lexical form of a float or double.
+pub(crate) fn canonical_double(double: f64, data_type: DataType) -> String {
+    if double.is_nan() {
+        return "NaN".to_string()
+    }
+    if double.is_infinite() {
+        return if double > 0.0 { "INF" } else { "-INF" }.to_string()
+    }
+    // The shortest representation that reads back as the same number
+    let scientific = if data_type == DataType::Float {
+        format!("{:E}", double as f32)
+    } else {
+        format!("{double:E}")
+    };
+    match scientific.split_once('E') {
+        Some((mantissa, exponent)) if !mantissa.contains('.') => {
+            format!("{mantissa}.0E{exponent}")
+        },
+        _ => scientific,
+    }
+}
+
+/// The key by which floats and doubles are compared and hashed as terms:
+/// all `NaN`s are the same.
+pub(crate) fn double_term_bits(double: f64) -> u64 {
+    if double.is_nan() {
+        f64::NAN.to_bits()
+    } else {
+        double.to_bits()
+    }
+}
+
+impl Literal {
+    /// Parse an `xsd:float` or `xsd:double`: `INF`, `+INF`, `-INF`, `NaN`
+    /// or a decimal number with an optional exponent, such as `-1.5e-3`.
+    pub(crate) fn double_from_str(
+        buffer: &str,
+        data_type: DataType,
+    ) -> Result<Literal, RDFStoreError> {
+        let lexical_form = buffer.trim();
+        let double = match lexical_form {
+            "INF" | "+INF" => Some(f64::INFINITY),
+            "-INF" => Some(f64::NEG_INFINITY),
+            "NaN" => Some(f64::NAN),
+            _ if is_double(lexical_form) => lexical_form.parse().ok(),
+            _ => None,
+        };
+        match double {
+            Some(double) => Literal::new_double_with_datatype(double, data_type),
+            None => {
+                Err(RDFStoreError::UnknownValueForDataType { data_type, value: buffer.to_string() })
+            },
+        }
+    }
+}
+
+/// Whether the given string is a number with an optional exponent, which
+/// unlike Rust's `f64::from_str` excludes `inf`, `infinity` and `nan`.
+fn is_double(str: &str) -> bool {
+    fn unsigned(str: &str) -> &str { str.strip_prefix(|c| c == '+' || c == '-').unwrap_or(str) }
+    let digits = |str: &str| str.bytes().all(|byte| byte.is_ascii_digit());
+    let (mantissa, exponent) = match unsigned(str).split_once(['e', 'E']) {
+        Some((mantissa, exponent)) => (mantissa, Some(unsigned(exponent))),
+        None => (unsigned(str), None),
+    };
+    let valid_mantissa = match mantissa.split_once('.') {
+        Some((integer, fraction)) => {
+            !(integer.is_empty() && fraction.is_empty()) && digits(integer) && digits(fraction)
+        },
+        None => !mantissa.is_empty() && digits(mantissa),
+    };
+    valid_mantissa &&
+        exponent.map_or(true, |exponent| {
+            !exponent.is_empty() && digits(exponent)
+        })
+}
@@ -0,0 +1,450 @@
+// Copyright (c) 2018-2023, agnos.ai UK Ltd, all rights reserved.
+//---------------------------------------------------------------
+//! `xsd:duration`, `xsd:yearMonthDuration` and `xsd:dayTimeDuration`
+//! values.
+//!
+//! A duration is a number of months and a number of seconds, which XSD keeps
+//! apart because a month has no fixed number of seconds: `P1M` is neither
+//! more nor less than `P30D`. Durations are ordered as in
+//! [XSD](https://www.w3.org/TR/xmlschema11-2/#duration), by adding them to
+//! four reference date times. Durations are kept in their
+//! [canonical lexical form](https://www.w3.org/TR/xmlschema11-2/#f-durationCanMap)
+//! such as `P1Y2M3DT4H5M6.7S`, so that `PT36H` and `P1DT12H` are the same
+//! term.
+//!
+//! The arithmetic follows the
+//! [XPath operators on durations, dates and times](https://www.w3.org/TR/xpath-functions/#dateTime-arithmetic),
+//! and also allows adding any `xsd:duration` to a date time, which is done
+//! by adding the months first and then the seconds, as in
+//! [XSD](https://www.w3.org/TR/xmlschema11-2/#vp-dt-dateTimePlusDuration).
+
+use {
+    crate::{
+        literal::{functions::expression_error, Numeric},
+        DataType,
+        Literal,
+        RDFStoreError,
+    },
+    bigdecimal::{BigDecimal, RoundingMode, Signed, ToPrimitive, Zero},
+    chrono::{Datelike, NaiveDate, NaiveDateTime},
+    lazy_static::lazy_static,
+    num_bigint::{BigInt, ToBigInt},
+    std::cmp::Ordering,
+};
+
+lazy_static! {
+    /// The lexical space of `xsd:duration`, such as `P1Y2M3DT4H5M6.7S`: at
+    /// least one component, and a `T` only if followed by a time component.
+    static ref DURATION: fancy_regex::Regex = fancy_regex::Regex::new(
+        r"^(-)?P(?=\d|T\d)(?:(\d+)Y)?(?:(\d+)M)?(?:(\d+)D)?(?:T(?=\d)(?:(\d+)H)?(?:(\d+)M)?(?:(\d+(?:\.\d+)?)S)?)?$"
+    )
+    .unwrap();
+}
+
+/// The date times that durations are added to in order to compare them,
+/// which between them have the shortest and longest months and years. They
+/// all start a month, so that adding months never has to clamp the day.
+const REFERENCE_DATES: [(i32, u32); 4] = [(1696, 9), (1697, 2), (1903, 3), (1903, 7)];
+
+/// The number of months and days in 400 years of the Gregorian calendar,
+/// after which the calendar repeats itself.
+const MONTHS_PER_CYCLE: i64 = 4800;
+const DAYS_PER_CYCLE: i128 = 146_097;
+
+/// The value of a duration, where the months and the seconds have the same
+/// sign.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct XsdDuration {
+    months:  i64,
+    seconds: BigDecimal,
+}
+
+impl XsdDuration {
+    /// A duration of the given months and seconds, or `None` if one is
+    /// positive and the other negative.
+    pub fn new(months: i64, seconds: BigDecimal) -> Option<Self> {
+        if (months < 0 && seconds.is_positive()) || (months > 0 && seconds.is_negative()) {
+            return None
+        }
+        Some(Self { months, seconds: seconds.normalized() })
+    }
+
+    pub fn months(&self) -> i64 { self.months }
+
+    pub fn seconds(&self) -> &BigDecimal { &self.seconds }
+
+    /// Parse the lexical form of a duration of the given datatype, where an
+    /// `xsd:yearMonthDuration` can only have years and months and an
+    /// `xsd:dayTimeDuration` only days, hours, minutes and seconds.
+    pub fn parse(lexical_form: &str, data_type: DataType) -> Option<Self> {
+        let captures = DURATION.captures(lexical_form).ok()??;
+        let component = |index: usize| captures.get(index).map(|m| m.as_str());
+        let has_day_time = (4..=7).any(|index| component(index).is_some());
+        let has_year_month = component(2).is_some() || component(3).is_some();
+        match data_type {
+            DataType::YearMonthDuration if has_day_time => return None,
+            DataType::DayTimeDuration if has_year_month => return None,
+            _ => {},
+        }
+        let number =
+            |index: usize| -> Option<i64> { component(index).map_or(Some(0), |n| n.parse().ok()) };
+        let months = number(2)?.checked_mul(12)?.checked_add(number(3)?)?;
+        let seconds = BigDecimal::from(number(4)?) * BigDecimal::from(86400) +
+            BigDecimal::from(number(5)?) * BigDecimal::from(3600) +
+            BigDecimal::from(number(6)?) * BigDecimal::from(60) +
+            component(7).map_or(Some(BigDecimal::zero()), |seconds| {
+                seconds.parse().ok()
+            })?;
+        if component(1).is_some() {
+            Self::new(-months, -seconds)
+        } else {
+            Self::new(months, seconds)
+        }
+    }
+
+    /// The canonical lexical form of this duration as the given datatype,
+    /// such as `-P1DT2H`. A zero duration is `P0M` as an
+    /// `xsd:yearMonthDuration` and `PT0S` otherwise.
+    pub fn lexical_form(&self, data_type: DataType) -> String {
+        if self.months == 0 && self.seconds.is_zero() {
+            return if data_type == DataType::YearMonthDuration {
+                "P0M"
+            } else {
+                "PT0S"
+            }
+            .to_string()
+        }
+        let mut lexical_form = if self.months < 0 || self.seconds.is_negative() {
+            "-P".to_string()
+        } else {
+            "P".to_string()
+        };
+        let months = self.months.unsigned_abs();
+        for (value, designator) in [(months / 12, 'Y'), (months % 12, 'M')] {
+            if value != 0 {
+                lexical_form.push_str(format!("{value}{designator}").as_str());
+            }
+        }
+        let seconds = self.seconds.abs();
+        let whole_seconds = seconds.with_scale_round(0, RoundingMode::Down);
+        let fraction = &seconds - &whole_seconds;
+        let whole_seconds: BigInt = whole_seconds.to_bigint().unwrap_or_default();
+        let (day, hour, minute) = (
+            BigInt::from(86400),
+            BigInt::from(3600),
+            BigInt::from(60),
+        );
+        let days = &whole_seconds / &day;
+        if !days.is_zero() {
+            lexical_form.push_str(format!("{days}D").as_str());
+        }
+        let hours = &whole_seconds % &day / &hour;
+        let minutes = &whole_seconds % &hour / &minute;
+        let seconds = BigDecimal::from(&whole_seconds % &minute) + fraction;
+        if !hours.is_zero() || !minutes.is_zero() || !seconds.is_zero() {
+            lexical_form.push('T');
+            for (value, designator) in [(hours, 'H'), (minutes, 'M')] {
+                if !value.is_zero() {
+                    lexical_form.push_str(format!("{value}{designator}").as_str());
+                }
+            }
+            if !seconds.is_zero() {
+                let seconds = seconds.normalized();
+                let seconds = if seconds.fractional_digit_count() <= 0 {
+                    seconds.with_scale(0)
+                } else {
+                    seconds
+                };
+                lexical_form.push_str(format!("{}S", seconds.to_plain_string()).as_str());
+            }
+        }
+        lexical_form
+    }
+
+    /// The seconds of this duration, truncated to nanoseconds.
+    fn chrono_seconds(&self) -> Option<chrono::Duration> {
+        let whole_seconds = self.seconds.with_scale_round(0, RoundingMode::Down);
+        let nanoseconds = ((&self.seconds - &whole_seconds) * BigDecimal::from(1_000_000_000))
+            .with_scale_round(0, RoundingMode::Down)
+            .to_i64()?;
+        let whole_seconds = whole_seconds.to_i64()?;
+        if whole_seconds.unsigned_abs() > (i64::MAX / 1000) as u64 {
+            return None
+        }
+        Some(chrono::Duration::seconds(whole_seconds) + chrono::Duration::nanoseconds(nanoseconds))
+    }
+
+    /// The given date time plus this duration, with the day clamped to the
+    /// last day of the month after adding the months, so that January 31 plus
+    /// one month is February 28 (or 29).
+    fn add_to(&self, date_time: NaiveDateTime) -> Option<NaiveDateTime> {
+        let months = i64::from(date_time.year()) * 12 + i64::from(date_time.month0()) + self.months;
+        let year = i32::try_from(months.div_euclid(12)).ok()?;
+        let month = months.rem_euclid(12) as u32 + 1;
+        let last_day = (28..=31)
+            .rev()
+            .find(|day| NaiveDate::from_ymd_opt(year, month, *day).is_some())?;
+        NaiveDate::from_ymd_opt(year, month, date_time.day().min(last_day))?
+            .and_time(date_time.time())
+            .checked_add_signed(self.chrono_seconds()?)
+    }
+
+    /// The number of seconds from the start of the given month until that
+    /// start plus this duration, counted exactly however long the duration
+    /// is.
+    fn seconds_from(&self, year: i32, month: u32) -> Option<BigDecimal> {
+        // Whole 400 year cycles are counted without going through the
+        // calendar, so that chrono is only asked for dates near the start
+        let cycles = self.months.div_euclid(MONTHS_PER_CYCLE);
+        let months =
+            i64::from(year) * 12 + i64::from(month - 1) + self.months.rem_euclid(MONTHS_PER_CYCLE);
+        let start = NaiveDate::from_ymd_opt(year, month, 1)?;
+        let end = NaiveDate::from_ymd_opt(
+            i32::try_from(months.div_euclid(12)).ok()?,
+            months.rem_euclid(12) as u32 + 1,
+            1,
+        )?;
+        let days = i128::from(cycles) * DAYS_PER_CYCLE +
+            i128::from(end.signed_duration_since(start).num_days());
+        Some(BigDecimal::from(BigInt::from(days) * 86400) + &self.seconds)
+    }
+}
+
+/// Durations are ordered as in XSD: one duration is less than another if
+/// it is less when added to each of the reference date times 1696-09-01,
+/// 1697-02-01, 1903-03-01 and 1903-07-01. So `P1Y` is more than `P364D` and
+/// `P1M` more than `P27D`, but `P1M` and `P30D` cannot be compared.
+impl PartialOrd for XsdDuration {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        if self == other {
+            return Some(Ordering::Equal)
+        }
+        let mut orderings = REFERENCE_DATES.iter().map(|(year, month)| {
+            self.seconds_from(*year, *month)
+                .zip(other.seconds_from(*year, *month))
+                .map(|(a, b)| a.cmp(&b))
+        });
+        let first = orderings.next().flatten()?;
+        if first != Ordering::Equal && orderings.all(|ordering| ordering == Some(first)) {
+            Some(first)
+        } else {
+            None
+        }
+    }
+}
+
+impl Literal {
+    /// The value of an `xsd:duration`, `xsd:yearMonthDuration` or
+    /// `xsd:dayTimeDuration`.
+    pub fn as_xsd_duration(&self) -> Option<XsdDuration> {
+        XsdDuration::parse(self.as_duration()?, self.data_type)
+    }
+
+    /// A duration literal of the given datatype, which is an error if the
+    /// duration does not fit that datatype, such as `P1M` as an
+    /// `xsd:dayTimeDuration`.
+    pub fn new_xsd_duration(
+        duration: &XsdDuration,
+        data_type: DataType,
+    ) -> Result<Self, RDFStoreError> {
+        Literal::new_duration_with_datatype(
+            duration.lexical_form(data_type).as_str(),
+            data_type,
+        )
+    }
+
+    /// Add a duration to this date time, date, time or duration, as done by
+    /// the `+` operator. Only an `xsd:dayTimeDuration` can be added to a
+    /// time, and only durations of the same datatype can be added to each
+    /// other.
+    pub fn add_duration(&self, duration: &Literal) -> Result<Literal, RDFStoreError> {
+        let value = duration
+            .as_xsd_duration()
+            .ok_or_else(|| expression_error("+", "expected a duration"))?;
+        let overflow = || expression_error("+", "the result is out of range");
+        if let Some(date_time) = self.as_naive_date_time() {
+            let date_time = value.add_to(date_time).ok_or_else(overflow)?;
+            return Literal::new_local_date_time_with_datatype(
+                date_time,
+                self.timezone_offset(),
+                self.data_type,
+            )
+        }
+        if self.data_type.is_date() {
+            let midnight = self.as_date().and_then(|date| date.and_hms_opt(0, 0, 0));
+            let date_time = midnight
+                .and_then(|midnight| value.add_to(midnight))
+                .ok_or_else(overflow)?;
+            return Literal::new_local_date_with_datatype(
+                date_time.date(),
+                self.timezone_offset(),
+                self.data_type,
+            )
+        }
+        if let Some(time) = self.as_time() {
+            if value.months != 0 {
+                return Err(expression_error(
+                    "+",
+                    "only a dayTimeDuration can be added to a time",
+                ))
+            }
+            let (time, _) =
+                time.overflowing_add_signed(value.chrono_seconds().ok_or_else(overflow)?);
+            return Literal::new_time_with_datatype(time, self.timezone_offset(), self.data_type)
+        }
+        match self.as_xsd_duration() {
+            Some(this)
+                if self.data_type == duration.data_type && self.data_type != DataType::Duration =>
+            {
+                let months = this.months.checked_add(value.months).ok_or_else(overflow)?;
+                let duration =
+                    XsdDuration::new(months, this.seconds + value.seconds).ok_or_else(overflow)?;
+                Literal::new_xsd_duration(&duration, self.data_type)
+            },
+            _ => {
+                Err(expression_error(
+                    "+",
+                    "cannot add a duration to this value",
+                ))
+            },
+        }
+    }
+
+    /// Subtract a duration from this date time, date, time or duration, as
+    /// done by the `-` operator.
+    pub fn subtract_duration(&self, duration: &Literal) -> Result<Literal, RDFStoreError> {
+        let value = duration
+            .as_xsd_duration()
+            .ok_or_else(|| expression_error("-", "expected a duration"))?;
+        let negated = XsdDuration::new(-value.months, -value.seconds)
+            .ok_or_else(|| expression_error("-", "the result is out of range"))?;
+        self.add_duration(&Literal::new_xsd_duration(
+            &negated,
+            duration.data_type,
+        )?)
+    }
+
+    /// The `xsd:dayTimeDuration` between two date times, dates or times, as
+    /// done by the `-` operator, where values without a timezone are taken
+    /// to be in UTC.
+    pub fn subtract_date_time(&self, other: &Literal) -> Result<Literal, RDFStoreError> {
+        let difference = if self.data_type.is_date() && other.data_type.is_date() {
+            self.date_on_timeline()
+                .zip(other.date_on_timeline())
+                .map(|(a, b)| a.signed_duration_since(b))
+        } else if self.data_type.is_time() && other.data_type.is_time() {
+            self.time_on_reference_date()
+                .zip(other.time_on_reference_date())
+                .map(|(a, b)| a.signed_duration_since(b))
+        } else {
+            self.as_date_time()
+                .zip(other.as_date_time())
+                .map(|(a, b)| a.signed_duration_since(b))
+        };
+        let difference = difference
+            .ok_or_else(|| expression_error("-", "expected two date times, dates or times"))?;
+        let whole_seconds = difference.num_seconds();
+        let nanoseconds = (difference - chrono::Duration::seconds(whole_seconds))
+            .num_nanoseconds()
+            .unwrap_or_default();
+        let seconds = BigDecimal::new(
+            BigInt::from(whole_seconds) * 1_000_000_000 + nanoseconds,
+            9,
+        );
+        let duration = XsdDuration { months: 0, seconds: seconds.normalized() };
+        Literal::new_xsd_duration(&duration, DataType::DayTimeDuration)
+    }
+
+    /// Multiply an `xsd:yearMonthDuration` or `xsd:dayTimeDuration` by a
+    /// number, as done by the `*` operator, where months are rounded to
+    /// the nearest whole month.
+    pub fn multiply_duration(&self, factor: &Literal) -> Result<Literal, RDFStoreError> {
+        let factor = duration_factor("*", factor)?;
+        self.scale_duration("*", |value| value * &factor)
+    }
+
+    /// Divide an `xsd:yearMonthDuration` or `xsd:dayTimeDuration` by a
+    /// number, which returns a duration, or by a duration of the same
+    /// datatype, which returns a decimal, as done by the `/` operator.
+    pub fn divide_duration(&self, divisor: &Literal) -> Result<Literal, RDFStoreError> {
+        let division_by_zero = || expression_error("/", "division by zero");
+        if divisor.data_type.is_duration() {
+            let (a, b) = match (
+                self.subtype_duration("/")?,
+                divisor.as_xsd_duration(),
+            ) {
+                (a, Some(b)) if self.data_type == divisor.data_type => (a, b),
+                _ => {
+                    return Err(expression_error(
+                        "/",
+                        "expected durations of the same datatype",
+                    ))
+                },
+            };
+            let (a, b) = if self.data_type == DataType::YearMonthDuration {
+                (
+                    BigDecimal::from(a.months),
+                    BigDecimal::from(b.months),
+                )
+            } else {
+                (a.seconds, b.seconds)
+            };
+            if b.is_zero() {
+                return Err(division_by_zero())
+            }
+            return Numeric::Decimal(a / b).into_literal()
+        }
+        let divisor = duration_factor("/", divisor)?;
+        if divisor.is_zero() {
+            return Err(division_by_zero())
+        }
+        self.scale_duration("/", |value| value / &divisor)
+    }
+
+    /// The value of this duration, which must be an `xsd:yearMonthDuration`
+    /// or an `xsd:dayTimeDuration`.
+    fn subtype_duration(&self, function: &str) -> Result<XsdDuration, RDFStoreError> {
+        match self.data_type {
+            DataType::YearMonthDuration | DataType::DayTimeDuration => self.as_xsd_duration(),
+            _ => None,
+        }
+        .ok_or_else(|| {
+            expression_error(
+                function,
+                "expected a yearMonthDuration or a dayTimeDuration",
+            )
+        })
+    }
+
+    /// Multiply or divide this duration, rounding the months to the nearest
+    /// whole month (with halves rounded up, as `ROUND` does).
+    fn scale_duration(
+        &self,
+        function: &str,
+        scale: impl Fn(BigDecimal) -> BigDecimal,
+    ) -> Result<Literal, RDFStoreError> {
+        let out_of_range = || expression_error(function, "the result is out of range");
+        let duration = self.subtype_duration(function)?;
+        let months = (scale(BigDecimal::from(duration.months)) + BigDecimal::new(5.into(), 1))
+            .with_scale_round(0, RoundingMode::Floor)
+            .to_i64()
+            .ok_or_else(out_of_range)?;
+        let duration =
+            XsdDuration::new(months, scale(duration.seconds)).ok_or_else(out_of_range)?;
+        Literal::new_xsd_duration(&duration, self.data_type)
+    }
+}
+
+/// A finite number to multiply or divide a duration with.
+fn duration_factor(function: &str, factor: &Literal) -> Result<BigDecimal, RDFStoreError> {
+    match Numeric::from_literal(factor) {
+        Some(Numeric::Float(double) | Numeric::Double(double)) if !double.is_finite() => {
+            Err(expression_error(
+                function,
+                "expected a finite number",
+            ))
+        },
+        Some(numeric) => Ok(numeric.into_decimal()),
+        None => Err(expression_error(function, "expected a number")),
+    }
+}
@@ -0,0 +1,600 @@
+// Copyright (c) 2018-2023, agnos.ai UK Ltd, all rights reserved.
+//---------------------------------------------------------------
+//! The operators and functions that SPARQL borrows from
+//! [XPath](https://www.w3.org/TR/xpath-functions/), see
+//! [Function Definitions](https://www.w3.org/TR/sparql11-query/#SparqlOps).
+//!
+//! They follow the SPARQL error semantics: applying a function to an
+//! argument of the wrong type (say `STRLEN` to an integer) or to an invalid
+//! value (say a division by zero) returns an
+//! [`RDFStoreError::ExpressionError`] rather than some made-up value.
+//! Query engines turn that error into an unbound value or a failing
+//! `FILTER`.
+
+use {
+    crate::{
+        literal::date_time::canonical_timezone,
+        DataType,
+        Literal,
+        RDFStoreError,
+        XsdDuration,
+    },
+    bigdecimal::{BigDecimal, FromPrimitive, RoundingMode, Signed, ToPrimitive, Zero},
+    chrono::{Datelike, Timelike},
+    num_bigint::BigInt,
+    std::cmp::Ordering,
+};
+
+/// The value of a numeric literal after
+/// [type promotion](https://www.w3.org/TR/xpath20/#promotion): all
+/// integer types are promoted to `xsd:integer`, and integers and decimals
+/// are promoted to `xsd:float` or `xsd:double` when combined with one.
+/// Integers and decimals are exact.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) enum Numeric {
+    Integer(BigInt),
+    Decimal(BigDecimal),
+    Float(f64),
+    Double(f64),
+}
+
+impl Numeric {
+    pub(crate) fn from_literal(literal: &Literal) -> Option<Self> {
+        if let Some(integer) = literal.as_big_integer() {
+            Some(Numeric::Integer(integer))
+        } else if let Some(double) = literal.as_double() {
+            match literal.data_type {
+                DataType::Float => Some(Numeric::Float(double)),
+                _ => Some(Numeric::Double(double)),
+            }
+        } else {
+            literal.as_big_decimal().map(Numeric::Decimal)
+        }
+    }
+
+    pub(crate) fn as_f64(&self) -> f64 {
+        match self {
+            Numeric::Integer(integer) => integer.to_f64().unwrap_or(f64::NAN),
+            Numeric::Decimal(decimal) => decimal.to_f64().unwrap_or(f64::NAN),
+            Numeric::Float(double) | Numeric::Double(double) => *double,
+        }
+    }
+
+    fn is_floating_point(&self) -> bool { matches!(self, Numeric::Float(_) | Numeric::Double(_)) }
+
+    /// The exact value of this number, or zero for `NaN` and the
+    /// infinities.
+    pub(crate) fn into_decimal(self) -> BigDecimal {
+        match self {
+            Numeric::Integer(integer) => BigDecimal::from(integer),
+            Numeric::Decimal(decimal) => decimal,
+            float => BigDecimal::from_f64(float.as_f64()).unwrap_or_default(),
+        }
+    }
+
+    fn is_zero(&self) -> bool {
+        match self {
+            Numeric::Integer(integer) => integer.is_zero(),
+            Numeric::Decimal(decimal) => decimal.is_zero(),
+            Numeric::Float(double) | Numeric::Double(double) => *double == 0.0,
+        }
+    }
+
+    pub(crate) fn is_nan(&self) -> bool {
+        match self {
+            Numeric::Float(double) | Numeric::Double(double) => double.is_nan(),
+            _ => false,
+        }
+    }
+
+    pub(crate) fn into_literal(self) -> Result<Literal, RDFStoreError> {
+        match self {
+            Numeric::Integer(integer) => Literal::new_big_integer(&integer),
+            Numeric::Decimal(decimal) => Literal::new_decimal(&decimal),
+            Numeric::Float(float) => Literal::new_double_with_datatype(float, DataType::Float),
+            Numeric::Double(double) => Literal::new_double(double),
+        }
+    }
+
+    /// Compare two numbers, which is not possible if one of them is `NaN`.
+    pub(crate) fn compare(&self, other: &Numeric) -> Option<Ordering> {
+        match (self, other) {
+            (Numeric::Integer(a), Numeric::Integer(b)) => Some(a.cmp(b)),
+            (a, b) if a.is_floating_point() || b.is_floating_point() => {
+                a.as_f64().partial_cmp(&b.as_f64())
+            },
+            (a, b) => {
+                a.clone()
+                    .into_decimal()
+                    .partial_cmp(&b.clone().into_decimal())
+            },
+        }
+    }
+}
+
+pub(crate) fn expression_error(function: &str, message: &str) -> RDFStoreError {
+    RDFStoreError::ExpressionError {
+        function: function.to_string(),
+        message:  message.to_string(),
+    }
+}
+
+impl Literal {
+    /// The [effective boolean value](https://www.w3.org/TR/sparql11-query/#ebv)
+    /// of this literal, as used by `FILTER`, `&&`, `||` and `!`.
+    pub fn effective_boolean_value(&self) -> Result<bool, RDFStoreError> {
+        if let Some(boolean) = self.as_boolean() {
+            Ok(boolean)
+        } else if self.data_type.is_string() {
+            Ok(!self.string_value("EBV")?.is_empty())
+        } else {
+            match Numeric::from_literal(self) {
+                Some(numeric) => Ok(!numeric.is_zero() && !numeric.is_nan()),
+                None => {
+                    Err(expression_error(
+                        "EBV",
+                        "no effective boolean value",
+                    ))
+                },
+            }
+        }
+    }
+
+    /// `true` for literals of one of the numeric XSD types.
+    pub fn is_numeric(&self) -> bool { Numeric::from_literal(self).is_some() }
+
+    /// `op:numeric-add`, the `+` operator.
+    pub fn numeric_add(&self, other: &Literal) -> Result<Literal, RDFStoreError> {
+        self.arithmetic(
+            "+",
+            other,
+            |a, b| a + b,
+            |a, b| a + b,
+            |a, b| a + b,
+        )
+    }
+
+    /// `op:numeric-subtract`, the `-` operator.
+    pub fn numeric_subtract(&self, other: &Literal) -> Result<Literal, RDFStoreError> {
+        self.arithmetic(
+            "-",
+            other,
+            |a, b| a - b,
+            |a, b| a - b,
+            |a, b| a - b,
+        )
+    }
+
+    /// `op:numeric-multiply`, the `*` operator.
+    pub fn numeric_multiply(&self, other: &Literal) -> Result<Literal, RDFStoreError> {
+        self.arithmetic(
+            "*",
+            other,
+            |a, b| a * b,
+            |a, b| a * b,
+            |a, b| a * b,
+        )
+    }
+
+    /// `op:numeric-divide`, the `/` operator. Dividing two integers
+    /// returns a decimal. Dividing a float or double by zero returns `INF`,
+    /// `-INF` or `NaN` rather than an error.
+    pub fn numeric_divide(&self, other: &Literal) -> Result<Literal, RDFStoreError> {
+        let (a, b) = (self.numeric("/")?, other.numeric("/")?);
+        if a.is_floating_point() || b.is_floating_point() {
+            return self.arithmetic(
+                "/",
+                other,
+                |a, b| a / b,
+                |a, b| a / b,
+                |a, b| a / b,
+            )
+        }
+        if b.is_zero() {
+            return Err(expression_error("/", "division by zero"))
+        }
+        Numeric::Decimal(a.into_decimal() / b.into_decimal()).into_literal()
+    }
+
+    /// `op:numeric-unary-minus`
+    pub fn numeric_unary_minus(&self) -> Result<Literal, RDFStoreError> {
+        match self.numeric("-")? {
+            Numeric::Integer(integer) => Numeric::Integer(-integer),
+            Numeric::Decimal(decimal) => Numeric::Decimal(-decimal),
+            Numeric::Float(float) => Numeric::Float(-float),
+            Numeric::Double(double) => Numeric::Double(-double),
+        }
+        .into_literal()
+    }
+
+    /// `ABS`
+    pub fn abs(&self) -> Result<Literal, RDFStoreError> {
+        match self.numeric("ABS")? {
+            Numeric::Integer(integer) => Numeric::Integer(integer.abs()),
+            Numeric::Decimal(decimal) => Numeric::Decimal(decimal.abs()),
+            Numeric::Float(float) => Numeric::Float(float.abs()),
+            Numeric::Double(double) => Numeric::Double(double.abs()),
+        }
+        .into_literal()
+    }
+
+    /// `ROUND`, which rounds halves towards positive infinity.
+    pub fn round(&self) -> Result<Literal, RDFStoreError> {
+        self.rounding(
+            "ROUND",
+            |decimal| {
+                (decimal + BigDecimal::new(5.into(), 1)).with_scale_round(0, RoundingMode::Floor)
+            },
+            |double| (double + 0.5).floor(),
+        )
+    }
+
+    /// `CEIL`
+    pub fn ceil(&self) -> Result<Literal, RDFStoreError> {
+        self.rounding(
+            "CEIL",
+            |decimal| decimal.with_scale_round(0, RoundingMode::Ceiling),
+            f64::ceil,
+        )
+    }
+
+    /// `FLOOR`
+    pub fn floor(&self) -> Result<Literal, RDFStoreError> {
+        self.rounding(
+            "FLOOR",
+            |decimal| decimal.with_scale_round(0, RoundingMode::Floor),
+            f64::floor,
+        )
+    }
+
+    /// `STRLEN`, the number of characters of a string.
+    pub fn str_len(&self) -> Result<Literal, RDFStoreError> {
+        integer_literal(self.string_value("STRLEN")?.chars().count() as i64)
+    }
+
+    /// `SUBSTR`, with a 1-based start position that is counted in
+    /// characters.
+    pub fn substr(
+        &self,
+        start: &Literal,
+        length: Option<&Literal>,
+    ) -> Result<Literal, RDFStoreError> {
+        let str = self.string_value("SUBSTR")?;
+        let start = start.numeric("SUBSTR")?.as_f64().round();
+        let end = match length {
+            Some(length) => Some(start + length.numeric("SUBSTR")?.as_f64().round()),
+            None => None,
+        };
+        let substring: String = str
+            .chars()
+            .enumerate()
+            .filter(|(idx, _)| {
+                let position = (*idx + 1) as f64;
+                position >= start && end.map_or(true, |end| position < end)
+            })
+            .map(|(_, c)| c)
+            .collect();
+        self.with_string(substring.as_str())
+    }
+
+    /// `UCASE`
+    pub fn ucase(&self) -> Result<Literal, RDFStoreError> {
+        self.with_string(self.string_value("UCASE")?.to_uppercase().as_str())
+    }
+
+    /// `LCASE`
+    pub fn lcase(&self) -> Result<Literal, RDFStoreError> {
+        self.with_string(self.string_value("LCASE")?.to_lowercase().as_str())
+    }
+
+    /// `STRSTARTS`
+    pub fn str_starts(&self, prefix: &Literal) -> Result<Literal, RDFStoreError> {
+        let (str, prefix) = self.string_pair("STRSTARTS", prefix)?;
+        Literal::new_boolean(str.starts_with(prefix))
+    }
+
+    /// `STRENDS`
+    pub fn str_ends(&self, suffix: &Literal) -> Result<Literal, RDFStoreError> {
+        let (str, suffix) = self.string_pair("STRENDS", suffix)?;
+        Literal::new_boolean(str.ends_with(suffix))
+    }
+
+    /// `CONTAINS`
+    pub fn contains(&self, part: &Literal) -> Result<Literal, RDFStoreError> {
+        let (str, part) = self.string_pair("CONTAINS", part)?;
+        Literal::new_boolean(str.contains(part))
+    }
+
+    /// `STRBEFORE`, an empty simple literal if `part` does not occur.
+    pub fn str_before(&self, part: &Literal) -> Result<Literal, RDFStoreError> {
+        let (str, part) = self.string_pair("STRBEFORE", part)?;
+        match str.find(part) {
+            Some(position) => self.with_string(&str[..position]),
+            None => Literal::new_plain_literal_string(""),
+        }
+    }
+
+    /// `STRAFTER`, an empty simple literal if `part` does not occur.
+    pub fn str_after(&self, part: &Literal) -> Result<Literal, RDFStoreError> {
+        let (str, part) = self.string_pair("STRAFTER", part)?;
+        match str.find(part) {
+            Some(position) => self.with_string(&str[position + part.len()..]),
+            None => Literal::new_plain_literal_string(""),
+        }
+    }
+
+    /// `CONCAT`, which returns an `xsd:string` if all arguments are
+    /// `xsd:string`s and a simple literal otherwise.
+    pub fn concat(literals: &[&Literal]) -> Result<Literal, RDFStoreError> {
+        let mut concatenated = String::new();
+        for literal in literals.iter() {
+            concatenated.push_str(literal.string_value("CONCAT")?);
+        }
+        let data_type = if !literals.is_empty() &&
+            literals
+                .iter()
+                .all(|literal| literal.data_type == DataType::String)
+        {
+            DataType::String
+        } else {
+            DataType::PlainLiteral
+        };
+        Literal::new_string_with_datatype(concatenated.as_str(), data_type)
+    }
+
+    /// `ENCODE_FOR_URI`
+    pub fn encode_for_uri(&self) -> Result<Literal, RDFStoreError> {
+        Literal::new_plain_literal_string(
+            urlencoding::encode(self.string_value("ENCODE_FOR_URI")?).as_ref(),
+        )
+    }
+
+    /// `REGEX`, with the flags of
+    /// [`fn:matches`](https://www.w3.org/TR/xpath-functions/#flags).
+    pub fn regex(
+        &self,
+        pattern: &Literal,
+        flags: Option<&Literal>,
+    ) -> Result<Literal, RDFStoreError> {
+        let (text, pattern) = self.string_pair("REGEX", pattern)?;
+        let regex = compile_regex("REGEX", pattern, flags)?;
+        let is_match = regex
+            .is_match(text)
+            .map_err(|error| expression_error("REGEX", error.to_string().as_str()))?;
+        Literal::new_boolean(is_match)
+    }
+
+    /// `REPLACE`, where `$1` and so on in the replacement refer to the
+    /// groups of the pattern.
+    pub fn replace(
+        &self,
+        pattern: &Literal,
+        replacement: &Literal,
+        flags: Option<&Literal>,
+    ) -> Result<Literal, RDFStoreError> {
+        let (text, pattern) = self.string_pair("REPLACE", pattern)?;
+        let replacement = replacement.string_value("REPLACE")?;
+        let regex = compile_regex("REPLACE", pattern, flags)?;
+        self.with_string(regex.replace_all(text, replacement).as_ref())
+    }
+
+    /// `YEAR` of a date or date time.
+    pub fn year(&self) -> Result<Literal, RDFStoreError> {
+        integer_literal(self.date_value("YEAR")?.year() as i64)
+    }
+
+    /// `MONTH` of a date or date time.
+    pub fn month(&self) -> Result<Literal, RDFStoreError> {
+        integer_literal(self.date_value("MONTH")?.month() as i64)
+    }
+
+    /// `DAY` of a date or date time.
+    pub fn day(&self) -> Result<Literal, RDFStoreError> {
+        integer_literal(self.date_value("DAY")?.day() as i64)
+    }
+
+    /// `HOURS` of a date time or time, in its own timezone.
+    pub fn hours(&self) -> Result<Literal, RDFStoreError> {
+        integer_literal(self.time_value("HOURS")?.hour() as i64)
+    }
+
+    /// `MINUTES` of a date time or time, in its own timezone.
+    pub fn minutes(&self) -> Result<Literal, RDFStoreError> {
+        integer_literal(self.time_value("MINUTES")?.minute() as i64)
+    }
+
+    /// `SECONDS` of a date time or time, as a decimal.
+    pub fn seconds(&self) -> Result<Literal, RDFStoreError> {
+        let time = self.time_value("SECONDS")?;
+        let nanoseconds = i64::from(time.second()) * 1_000_000_000 + i64::from(time.nanosecond());
+        Literal::new_decimal(&BigDecimal::new(nanoseconds.into(), 9))
+    }
+
+    /// `TIMEZONE` of a date time or time, as a duration such as `-PT5H`.
+    /// This is an error for a date time without a timezone.
+    pub fn timezone(&self) -> Result<Literal, RDFStoreError> {
+        self.time_value("TIMEZONE")?;
+        let offset = self
+            .timezone_offset()
+            .ok_or_else(|| expression_error("TIMEZONE", "the date time has no timezone"))?
+            .local_minus_utc();
+        let duration = XsdDuration::new(0, BigDecimal::from(offset))
+            .ok_or_else(|| expression_error("TIMEZONE", "invalid timezone"))?;
+        Literal::new_xsd_duration(&duration, DataType::DayTimeDuration)
+    }
+
+    /// `TZ` of a date time or time, such as `Z` or `-05:00`, or an empty
+    /// string for a date time without a timezone.
+    pub fn tz(&self) -> Result<Literal, RDFStoreError> {
+        self.time_value("TZ")?;
+        Literal::new_plain_literal_string(canonical_timezone(self.timezone_offset()).as_str())
+    }
+
+    /// `MD5`, the hex digest of a string.
+    pub fn md5(&self) -> Result<Literal, RDFStoreError> {
+        use md5::Digest;
+        hex_digest(md5::Md5::digest(self.hash_input("MD5")?).as_slice())
+    }
+
+    /// `SHA1`, the hex digest of a string.
+    pub fn sha1(&self) -> Result<Literal, RDFStoreError> {
+        use sha1::Digest;
+        hex_digest(sha1::Sha1::digest(self.hash_input("SHA1")?).as_slice())
+    }
+
+    /// `SHA256`, the hex digest of a string.
+    pub fn sha256(&self) -> Result<Literal, RDFStoreError> {
+        use sha2::Digest;
+        hex_digest(sha2::Sha256::digest(self.hash_input("SHA256")?).as_slice())
+    }
+
+    /// `SHA384`, the hex digest of a string.
+    pub fn sha384(&self) -> Result<Literal, RDFStoreError> {
+        use sha2::Digest;
+        hex_digest(sha2::Sha384::digest(self.hash_input("SHA384")?).as_slice())
+    }
+
+    /// `SHA512`, the hex digest of a string.
+    pub fn sha512(&self) -> Result<Literal, RDFStoreError> {
+        use sha2::Digest;
+        hex_digest(sha2::Sha512::digest(self.hash_input("SHA512")?).as_slice())
+    }
+
+    fn numeric(&self, function: &str) -> Result<Numeric, RDFStoreError> {
+        Numeric::from_literal(self)
+            .ok_or_else(|| expression_error(function, "expected a numeric literal"))
+    }
+
+    fn arithmetic(
+        &self,
+        function: &str,
+        other: &Literal,
+        integer_operation: fn(BigInt, BigInt) -> BigInt,
+        decimal_operation: fn(BigDecimal, BigDecimal) -> BigDecimal,
+        double_operation: fn(f64, f64) -> f64,
+    ) -> Result<Literal, RDFStoreError> {
+        match (self.numeric(function)?, other.numeric(function)?) {
+            (Numeric::Integer(a), Numeric::Integer(b)) => Numeric::Integer(integer_operation(a, b)),
+            (a @ Numeric::Double(_), b) | (a, b @ Numeric::Double(_)) => {
+                Numeric::Double(double_operation(a.as_f64(), b.as_f64()))
+            },
+            (a @ Numeric::Float(_), b) | (a, b @ Numeric::Float(_)) => {
+                Numeric::Float(double_operation(a.as_f64(), b.as_f64()))
+            },
+            (a, b) => {
+                Numeric::Decimal(decimal_operation(
+                    a.into_decimal(),
+                    b.into_decimal(),
+                ))
+            },
+        }
+        .into_literal()
+    }
+
+    fn rounding(
+        &self,
+        function: &str,
+        decimal_operation: fn(BigDecimal) -> BigDecimal,
+        double_operation: fn(f64) -> f64,
+    ) -> Result<Literal, RDFStoreError> {
+        match self.numeric(function)? {
+            integer @ Numeric::Integer(_) => integer,
+            Numeric::Decimal(decimal) => Numeric::Decimal(decimal_operation(decimal)),
+            Numeric::Float(float) => Numeric::Float(double_operation(float)),
+            Numeric::Double(double) => Numeric::Double(double_operation(double)),
+        }
+        .into_literal()
+    }
+
+    fn string_value(&self, function: &str) -> Result<&str, RDFStoreError> {
+        if self.data_type.is_string() {
+            if let Some(str) = self.as_str() {
+                return Ok(str)
+            }
+        }
+        Err(expression_error(
+            function,
+            "expected a string literal",
+        ))
+    }
+
+    fn string_pair<'a>(
+        &'a self,
+        function: &str,
+        other: &'a Literal,
+    ) -> Result<(&'a str, &'a str), RDFStoreError> {
+        Ok((
+            self.string_value(function)?,
+            other.string_value(function)?,
+        ))
+    }
+
+    /// The hash functions only accept simple literals and `xsd:string`s.
+    fn hash_input(&self, function: &str) -> Result<&[u8], RDFStoreError> {
+        Ok(self.string_value(function)?.as_bytes())
+    }
+
+    /// A string with the same datatype as this literal.
+    fn with_string(&self, str: &str) -> Result<Literal, RDFStoreError> {
+        Literal::new_string_with_datatype(str, self.data_type)
+    }
+
+    fn date_value(&self, function: &str) -> Result<chrono::NaiveDate, RDFStoreError> {
+        self.as_date()
+            .ok_or_else(|| expression_error(function, "expected a date or date time"))
+    }
+
+    /// The local time of a date time or time.
+    fn time_value(&self, function: &str) -> Result<chrono::NaiveTime, RDFStoreError> {
+        self.as_naive_date_time()
+            .map(|date_time| date_time.time())
+            .or_else(|| self.as_time())
+            .ok_or_else(|| expression_error(function, "expected a date time or time"))
+    }
+}
+
+fn integer_literal(integer: i64) -> Result<Literal, RDFStoreError> {
+    Literal::new_signed_integer_with_datatype(integer, DataType::Integer)
+}
+
+fn hex_digest(digest: &[u8]) -> Result<Literal, RDFStoreError> {
+    let hex: String = digest.iter().map(|byte| format!("{byte:02x}")).collect();
+    Literal::new_plain_literal_string(hex.as_str())
+}
+
+fn compile_regex(
+    function: &str,
+    pattern: &str,
+    flags: Option<&Literal>,
+) -> Result<fancy_regex::Regex, RDFStoreError> {
+    let flags = match flags {
+        Some(flags) => flags.string_value(function)?,
+        None => "",
+    };
+    let mut inline_flags = String::new();
+    for flag in flags.chars() {
+        match flag {
+            'i' | 's' | 'm' | 'x' => inline_flags.push(flag),
+            // `q` means that the pattern is taken literally
+            'q' => {},
+            _ => {
+                return Err(expression_error(
+                    function,
+                    format!("invalid flag {flag}").as_str(),
+                ))
+            },
+        }
+    }
+    let pattern = if flags.contains('q') {
+        fancy_regex::escape(pattern).into_owned()
+    } else {
+        pattern.to_string()
+    };
+    let pattern = if inline_flags.is_empty() {
+        pattern
+    } else {
+        format!("(?{inline_flags}){pattern}")
+    };
+    fancy_regex::Regex::new(pattern.as_str())
+        .map_err(|error| expression_error(function, error.to_string().as_str()))
+}
@@ -0,0 +1,259 @@
+// Copyright (c) 2018-2023, agnos.ai UK Ltd, all rights reserved.
+//---------------------------------------------------------------
+//! The Gregorian partial dates `xsd:gYear`, `xsd:gYearMonth`,
+//! `xsd:gMonthDay`, `xsd:gDay` and `xsd:gMonth`, such as `-0044`, `1999-12`,
+//! `--12-25`, `---01` and `--12`, each with an optional timezone.
+//!
+//! Years are not limited to four digits, so `-12000` and `2000000` are valid
+//! `xsd:gYear`s. Partial dates are kept in their canonical lexical form.
+//!
+//! Partial dates of the same datatype are ordered by the moment they start
+//! at, where one without a timezone is taken to be in UTC, as is done for
+//! date times. The ones without a year are taken to be in the leap year 1972
+//! (and a `xsd:gDay` in December), as XSD does.
+
+use {
+    crate::{
+        literal::date_time::{canonical_timezone, parse_timezone},
+        DataType,
+        Literal,
+        RDFStoreError,
+    },
+    chrono::{Datelike, FixedOffset},
+    lazy_static::lazy_static,
+    std::cmp::Ordering,
+};
+
+lazy_static! {
+    static ref YEAR: fancy_regex::Regex =
+        fancy_regex::Regex::new(r"^(-?(?:[1-9]\d{4,}|\d{4}))(Z|[+-]\d{2}:\d{2})?$").unwrap();
+    static ref YEAR_MONTH: fancy_regex::Regex =
+        fancy_regex::Regex::new(r"^(-?(?:[1-9]\d{4,}|\d{4}))-(\d{2})(Z|[+-]\d{2}:\d{2})?$")
+            .unwrap();
+    static ref MONTH_DAY: fancy_regex::Regex =
+        fancy_regex::Regex::new(r"^--(\d{2})-(\d{2})(Z|[+-]\d{2}:\d{2})?$").unwrap();
+    static ref DAY: fancy_regex::Regex =
+        fancy_regex::Regex::new(r"^---(\d{2})(Z|[+-]\d{2}:\d{2})?$").unwrap();
+    static ref MONTH: fancy_regex::Regex =
+        fancy_regex::Regex::new(r"^--(\d{2})(Z|[+-]\d{2}:\d{2})?$").unwrap();
+}
+
+/// The value of a Gregorian partial date, where the components that are
+/// present determine its datatype, see [`XsdPartialDate::data_type`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct XsdPartialDate {
+    year:     Option<i64>,
+    month:    Option<u32>,
+    day:      Option<u32>,
+    timezone: Option<FixedOffset>,
+}
+
+impl XsdPartialDate {
+    /// An `xsd:gYear`, where year 0 is 1 BCE.
+    pub fn new_year(year: i64, timezone: Option<FixedOffset>) -> Self {
+        Self { year: Some(year), month: None, day: None, timezone }
+    }
+
+    /// An `xsd:gYearMonth`, or `None` if the month is not 1 to 12.
+    pub fn new_year_month(year: i64, month: u32, timezone: Option<FixedOffset>) -> Option<Self> {
+        Self {
+            year: Some(year),
+            month: Some(month),
+            day: None,
+            timezone,
+        }
+        .validated()
+    }
+
+    /// An `xsd:gMonthDay`, or `None` if there is no such day in a leap year
+    /// (so `--02-29` is valid).
+    pub fn new_month_day(month: u32, day: u32, timezone: Option<FixedOffset>) -> Option<Self> {
+        Self {
+            year: None,
+            month: Some(month),
+            day: Some(day),
+            timezone,
+        }
+        .validated()
+    }
+
+    /// An `xsd:gDay`, or `None` if the day is not 1 to 31.
+    pub fn new_day(day: u32, timezone: Option<FixedOffset>) -> Option<Self> {
+        Self { year: None, month: None, day: Some(day), timezone }.validated()
+    }
+
+    /// An `xsd:gMonth`, or `None` if the month is not 1 to 12.
+    pub fn new_month(month: u32, timezone: Option<FixedOffset>) -> Option<Self> {
+        Self {
+            year: None,
+            month: Some(month),
+            day: None,
+            timezone,
+        }
+        .validated()
+    }
+
+    pub fn year(&self) -> Option<i64> { self.year }
+
+    pub fn month(&self) -> Option<u32> { self.month }
+
+    pub fn day(&self) -> Option<u32> { self.day }
+
+    pub fn timezone(&self) -> Option<FixedOffset> { self.timezone }
+
+    /// The datatype of this partial date, such as [`DataType::YearMonth`]
+    /// for an `xsd:gYearMonth`.
+    pub fn data_type(&self) -> DataType {
+        match (self.year, self.month, self.day) {
+            (Some(_), Some(_), _) => DataType::YearMonth,
+            (Some(_), None, _) => DataType::Year,
+            (None, Some(_), Some(_)) => DataType::MonthDay,
+            (None, Some(_), None) => DataType::Month,
+            (None, None, _) => DataType::Day,
+        }
+    }
+
+    /// Parse the XSD lexical form of a partial date of the given datatype,
+    /// such as `1999-12+01:00` for an `xsd:gYearMonth`.
+    pub fn parse(lexical_form: &str, data_type: DataType) -> Option<Self> {
+        let regex: &fancy_regex::Regex = match data_type {
+            DataType::Year => &YEAR,
+            DataType::YearMonth => &YEAR_MONTH,
+            DataType::MonthDay => &MONTH_DAY,
+            DataType::Day => &DAY,
+            DataType::Month => &MONTH,
+            _ => return None,
+        };
+        let captures = regex.captures(lexical_form).ok()??;
+        // The timezone is always the last group
+        let timezone = parse_timezone(
+            captures
+                .get(captures.len() - 1)
+                .map(|timezone| timezone.as_str()),
+        )?;
+        let year = || captures.get(1)?.as_str().parse::<i64>().ok();
+        let number = |index: usize| captures.get(index)?.as_str().parse::<u32>().ok();
+        match data_type {
+            DataType::Year => Some(Self::new_year(year()?, timezone)),
+            DataType::YearMonth => Self::new_year_month(year()?, number(2)?, timezone),
+            DataType::MonthDay => Self::new_month_day(number(1)?, number(2)?, timezone),
+            DataType::Day => Self::new_day(number(1)?, timezone),
+            _ => Self::new_month(number(1)?, timezone),
+        }
+    }
+
+    /// The canonical lexical form, such as `-0044` or `--12-25Z`.
+    pub fn lexical_form(&self) -> String {
+        let year = self.year.map(|year| {
+            if year < 0 {
+                format!("-{:04}", year.unsigned_abs())
+            } else {
+                format!("{year:04}")
+            }
+        });
+        let date = match (year, self.month, self.day) {
+            (Some(year), Some(month), _) => format!("{year}-{month:02}"),
+            (Some(year), None, _) => year,
+            (None, Some(month), Some(day)) => format!("--{month:02}-{day:02}"),
+            (None, Some(month), None) => format!("--{month:02}"),
+            (None, None, day) => format!("---{:02}", day.unwrap_or_default()),
+        };
+        format!("{date}{}", canonical_timezone(self.timezone))
+    }
+
+    fn validated(self) -> Option<Self> {
+        let month = self.month.unwrap_or(12);
+        let days = days_in_month(self.year.unwrap_or(1972), month)?;
+        match self.day {
+            Some(day) if day < 1 || day > days => None,
+            _ => Some(self),
+        }
+    }
+
+    /// The minute this partial date starts at, counted from 1970-01-01T00:00Z.
+    fn start_minute(&self) -> i128 {
+        let days = days_from_civil(
+            self.year.unwrap_or(1972),
+            self.month
+                .unwrap_or(if self.day.is_some() { 12 } else { 1 }),
+            self.day.unwrap_or(1),
+        );
+        let offset = self
+            .timezone
+            .map_or(0, |timezone| timezone.local_minus_utc() / 60);
+        days * 1440 - i128::from(offset)
+    }
+}
+
+/// Partial dates of the same datatype are ordered by the moment they start
+/// at, so `2000Z` is before `2000-01:00`. Partial dates that start at the
+/// same moment without being equal, such as `2000Z` and `2000`, are not
+/// ordered (their order is indeterminate in XSD).
+impl PartialOrd for XsdPartialDate {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        if self.data_type() != other.data_type() {
+            return None
+        }
+        match self.start_minute().cmp(&other.start_minute()) {
+            Ordering::Equal if self != other => None,
+            ordering => Some(ordering),
+        }
+    }
+}
+
+/// The number of days in the given month of the given year, or `None` for
+/// a month that is not 1 to 12.
+fn days_in_month(year: i64, month: u32) -> Option<u32> {
+    let leap_year =
+        year.rem_euclid(4) == 0 && (year.rem_euclid(100) != 0 || year.rem_euclid(400) == 0);
+    match month {
+        2 if leap_year => Some(29),
+        2 => Some(28),
+        4 | 6 | 9 | 11 => Some(30),
+        1..=12 => Some(31),
+        _ => None,
+    }
+}
+
+/// The number of days since 1970-01-01 in the proleptic Gregorian calendar,
+/// for any year.
+fn days_from_civil(year: i64, month: u32, day: u32) -> i128 {
+    // See http://howardhinnant.github.io/date_algorithms.html#days_from_civil
+    let year = i128::from(year) - i128::from(month <= 2);
+    let era = year.div_euclid(400);
+    let year_of_era = year.rem_euclid(400);
+    let month = i128::from(month);
+    let day_of_year =
+        (153 * (if month > 2 { month - 3 } else { month + 9 }) + 2) / 5 + i128::from(day) - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    era * 146097 + day_of_era - 719468
+}
+
+impl Literal {
+    /// The value of an `xsd:gYear`, `xsd:gYearMonth`, `xsd:gMonthDay`,
+    /// `xsd:gDay` or `xsd:gMonth`.
+    pub fn as_partial_date(&self) -> Option<XsdPartialDate> {
+        XsdPartialDate::parse(self.as_gregorian()?, self.data_type)
+    }
+
+    /// A literal of the datatype of the given partial date.
+    pub fn new_partial_date(date: &XsdPartialDate) -> Result<Literal, RDFStoreError> {
+        Literal::new_gregorian_with_datatype(date.lexical_form().as_str(), date.data_type())
+    }
+
+    /// The part of a date time or date that is kept by the given partial
+    /// date datatype, as done when casting.
+    pub(crate) fn partial_date_of(&self, data_type: DataType) -> Option<XsdPartialDate> {
+        let date = self.as_date()?;
+        let timezone = self.timezone_offset();
+        let year = i64::from(date.year());
+        match data_type {
+            DataType::Year => Some(XsdPartialDate::new_year(year, timezone)),
+            DataType::YearMonth => XsdPartialDate::new_year_month(year, date.month(), timezone),
+            DataType::MonthDay => XsdPartialDate::new_month_day(date.month(), date.day(), timezone),
+            DataType::Day => XsdPartialDate::new_day(date.day(), timezone),
+            DataType::Month => XsdPartialDate::new_month(date.month(), timezone),
+            _ => None,
+        }
+    }
+}
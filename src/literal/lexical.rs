@@ -0,0 +1,70 @@
+// Copyright (c) 2018-2023, agnos.ai UK Ltd, all rights reserved.
+//---------------------------------------------------------------
+//! Parsing literals from their lexical forms.
+//!
+//! There are two parsers, and the caller chooses which one applies:
+//!
+//! - [`Literal::from_lexical_form`] is strict and only accepts the lexical
+//!   forms defined by [XML Schema](https://www.w3.org/TR/xmlschema11-2/), as
+//!   found in RDF documents and SPARQL queries;
+//! - [`Literal::from_type_and_buffer`] is lenient and guesses the format of
+//!   values from other sources, so it also accepts RFC 2822 date times,
+//!   `12/31/2023`, `2023-12-31 13:21` and `<` `>` around IRIs.
+
+use crate::{DataType, Literal, RDFStoreError};
+
+impl Literal {
+    /// Parse the lexical form of a literal of the given datatype, strictly
+    /// following the XSD syntax, such as `2023-01-01T10:00:00+01:00` for an
+    /// `xsd:dateTime`, `1.5E2` for an `xsd:double` or `true` or `1` for an
+    /// `xsd:boolean`. Leading and trailing whitespace is ignored.
+    ///
+    /// Returns an [`RDFStoreError::UnknownValueForDataType`] for anything
+    /// else, or for a datatype that has no XSD lexical space.
+    pub fn from_lexical_form(
+        data_type: DataType,
+        lexical_form: &str,
+    ) -> Result<Literal, RDFStoreError> {
+        let lexical_form = lexical_form.trim();
+        let invalid_value = || {
+            RDFStoreError::UnknownValueForDataType { data_type, value: lexical_form.to_string() }
+        };
+        match data_type {
+            DataType::String | DataType::PlainLiteral => {
+                Literal::new_string_with_datatype(lexical_form, data_type)
+            },
+            DataType::Boolean => {
+                match lexical_form {
+                    "true" | "1" => Literal::new_boolean(true),
+                    "false" | "0" => Literal::new_boolean(false),
+                    _ => Err(invalid_value()),
+                }
+            },
+            data_type if data_type.is_integer() => {
+                Literal::integer_from_str(lexical_form, data_type)
+            },
+            DataType::Decimal => Literal::decimal_from_str(lexical_form),
+            DataType::Float | DataType::Double => Literal::double_from_str(lexical_form, data_type),
+            DataType::Date => Literal::xsd_date_from_str(lexical_form),
+            DataType::DateTime | DataType::DateTimeStamp => {
+                Literal::date_time_from_str(lexical_form, data_type)
+            },
+            DataType::Time => Literal::time_from_str(lexical_form),
+            data_type if data_type.is_duration() => {
+                Literal::new_duration_with_datatype(lexical_form, data_type)
+            },
+            data_type if data_type.is_gregorian() => {
+                Literal::new_gregorian_with_datatype(lexical_form, data_type)
+            },
+            data_type if data_type.is_binary() => Literal::binary_from_str(lexical_form, data_type),
+            // Unlike in Turtle, an IRI is not enclosed in `<` and `>`
+            DataType::AnyUri | DataType::IriReference if !lexical_form.starts_with('<') => {
+                Literal::from_type_and_buffer(data_type, lexical_form, None)
+                    .ok()
+                    .flatten()
+                    .ok_or_else(invalid_value)
+            },
+            _ => Err(invalid_value()),
+        }
+    }
+}
@@ -1,14 +1,33 @@
 // Copyright (c) 2018-2023, agnos.ai UK Ltd, all rights reserved.
 //---------------------------------------------------------------
 
+mod binary;
+mod cast;
+mod compare;
+mod convert;
+mod custom;
+mod date_time;
+mod decimal;
+mod double;
+mod duration;
+mod functions;
+mod gregorian;
 mod id_url_display;
+mod lexical;
+mod sparql_display;
 mod tests;
 mod this;
 mod url_display;
 mod value;
 
+pub(crate) use {functions::Numeric, sparql_display::escape_string};
 pub use {
+    convert::{FromLiteral, ToLiteral},
+    custom::{CustomDataType, CustomDataTypeRegistry},
     id_url_display::LiteralIdUrlDisplay,
+    sparql_display::LiteralSparqlDisplay,
+    duration::XsdDuration,
+    gregorian::XsdPartialDate,
     this::Literal,
     url_display::LiteralUrlDisplay,
     value::LiteralValue,
@@ -0,0 +1,56 @@
+// Copyright (c) 2018-2023, agnos.ai UK Ltd, all rights reserved.
+//---------------------------------------------------------------
+
+use {
+    crate::{literal::this::Literal, DataType},
+    std::fmt::{Display, Formatter},
+};
+
+/// Displays a [`Literal`] as a SPARQL (or Turtle) term, i.e. IRIs between
+/// angle brackets, escaped and quoted strings and typed literals with their
+/// full datatype IRI, so that the result can safely be injected into a query.
+pub struct LiteralSparqlDisplay<'a> {
+    pub(crate) literal: &'a Literal,
+}
+
+impl<'a> Display for LiteralSparqlDisplay<'a> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let data_type = self.literal.data_type;
+        let lexical_form = self.literal.lexical_form();
+        match data_type {
+            DataType::IriReference | DataType::AnyUri => write!(f, "<{lexical_form}>"),
+            DataType::BlankNode => write!(f, "_:{lexical_form}"),
+            DataType::PlainLiteral | DataType::String => {
+                write!(f, "\"{}\"", escape_string(lexical_form.as_str()))
+            },
+            DataType::Boolean | DataType::Integer => write!(f, "{lexical_form}"),
+            _ => {
+                write!(
+                    f,
+                    "\"{}\"^^<{}>",
+                    escape_string(lexical_form.as_str()),
+                    self.literal.data_type_iri()
+                )
+            },
+        }
+    }
+}
+
+/// Escape the given string according to the `ECHAR` production of
+/// SPARQL and Turtle so that it can be placed between double quotes.
+pub(crate) fn escape_string(str: &str) -> String {
+    let mut escaped = String::with_capacity(str.len());
+    for c in str.chars() {
+        match c {
+            '\\' => escaped.push_str("\\\\"),
+            '"' => escaped.push_str("\\\""),
+            '\n' => escaped.push_str("\\n"),
+            '\r' => escaped.push_str("\\r"),
+            '\t' => escaped.push_str("\\t"),
+            '\u{08}' => escaped.push_str("\\b"),
+            '\u{0C}' => escaped.push_str("\\f"),
+            _ => escaped.push(c),
+        }
+    }
+    escaped
+}
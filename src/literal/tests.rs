@@ -5,7 +5,7 @@
 #[cfg(feature = "serde")]
 use serde_json::json;
 use {
-    crate::{literal::this::Literal, RDFStoreError},
+    crate::{literal::this::Literal, DataType, RDFStoreError},
     iref::IriBuf,
     std::str::FromStr,
 };
@@ -110,7 +110,7 @@ fn test_deserialize_06() -> Result<(), serde_json::Error> {
     let literal: Literal = serde_json::from_value(json!("2023-12-31 13:21"))?;
     assert_eq!(
         format!("{literal:?}"),
-        "Literal(DateTime,2023-12-31 13:21:00 UTC)"
+        "Literal(DateTime,2023-12-31T13:21:00)"
     );
     Ok(())
 }
@@ -121,7 +121,7 @@ fn test_deserialize_07() -> Result<(), serde_json::Error> {
     let literal: Literal = serde_json::from_value(json!("2023-12-31 13:21:22"))?;
     assert_eq!(
         format!("{literal:?}"),
-        "Literal(DateTime,2023-12-31 13:21:22 UTC)"
+        "Literal(DateTime,2023-12-31T13:21:22)"
     );
     Ok(())
 }
@@ -133,7 +133,7 @@ fn test_deserialize_08() -> Result<(), serde_json::Error> {
     let literal: Literal = serde_json::from_value(json!("1996-12-19T16:39:57-08:00"))?;
     assert_eq!(
         format!("{literal:?}"),
-        "Literal(DateTime,1996-12-20 00:39:57 UTC)"
+        "Literal(DateTime,1996-12-19T16:39:57-08:00)"
     );
     Ok(())
 }
@@ -145,7 +145,1187 @@ fn test_deserialize_09() -> Result<(), serde_json::Error> {
     let literal: Literal = serde_json::from_value(json!("Wed, 18 Feb 2015 23:16:09 EST"))?;
     assert_eq!(
         format!("{literal:?}"),
-        "Literal(DateTime,2015-02-19 04:16:09 UTC)"
+        "Literal(DateTime,2015-02-18T23:16:09-05:00)"
     );
     Ok(())
 }
+
+fn integer(value: i64) -> Literal {
+    Literal::new_signed_integer_with_datatype(value, DataType::Integer).unwrap()
+}
+
+fn decimal(value: &str) -> Literal {
+    Literal::new_decimal_with_datatype(value, DataType::Decimal).unwrap()
+}
+
+fn string(value: &str) -> Literal { Literal::new_plain_literal_string(value).unwrap() }
+
+#[test]
+fn test_numeric_promotion() -> Result<(), RDFStoreError> {
+    let byte = Literal::new_unsigned_integer_with_datatype(2, DataType::UnsignedByte)?;
+    let sum = integer(40).numeric_add(&byte)?;
+    assert_eq!(sum.data_type, DataType::Integer);
+    assert_eq!(sum.lexical_form(), "42");
+
+    let product = integer(3).numeric_multiply(&decimal("1.5"))?;
+    assert_eq!(product.data_type, DataType::Decimal);
+    assert_eq!(product.lexical_form(), "4.5");
+
+    assert_eq!(
+        integer(3).numeric_divide(&integer(2))?.lexical_form(),
+        "1.5"
+    );
+    assert!(matches!(
+        integer(3).numeric_divide(&integer(0)),
+        Err(RDFStoreError::ExpressionError { .. })
+    ));
+    assert_eq!(
+        integer(i64::MAX).numeric_add(&integer(1))?.lexical_form(),
+        "9223372036854775808"
+    );
+    assert!(string("3").numeric_add(&integer(1)).is_err());
+    Ok(())
+}
+
+#[test]
+fn test_rounding() -> Result<(), RDFStoreError> {
+    assert_eq!(decimal("2.5").round()?.lexical_form(), "3.0");
+    assert_eq!(decimal("-2.5").round()?.lexical_form(), "-2.0");
+    assert_eq!(decimal("-2.5").abs()?.lexical_form(), "2.5");
+    assert_eq!(decimal("2.1").ceil()?.lexical_form(), "3.0");
+    assert_eq!(integer(-7).abs()?.lexical_form(), "7");
+    Ok(())
+}
+
+#[test]
+fn test_string_functions() -> Result<(), RDFStoreError> {
+    let str = Literal::new_string_with_datatype("foobar", DataType::String)?;
+    assert_eq!(str.str_len()?.lexical_form(), "6");
+    let substring = str.substr(&integer(4), None)?;
+    assert_eq!(substring.lexical_form(), "bar");
+    assert_eq!(substring.data_type, DataType::String);
+    assert_eq!(
+        str.substr(&integer(0), Some(&integer(3)))?.lexical_form(),
+        "fo"
+    );
+    assert_eq!(str.ucase()?.lexical_form(), "FOOBAR");
+    assert_eq!(
+        str.str_before(&string("b"))?.lexical_form(),
+        "foo"
+    );
+    assert_eq!(str.str_after(&string("x"))?.lexical_form(), "");
+    assert_eq!(
+        str.contains(&string("oba"))?.as_boolean(),
+        Some(true)
+    );
+    assert_eq!(
+        Literal::concat(&[&str, &string("!")])?.lexical_form(),
+        "foobar!"
+    );
+    assert_eq!(
+        string("a b/c").encode_for_uri()?.lexical_form(),
+        "a%20b%2Fc"
+    );
+    assert!(integer(1).str_len().is_err());
+    Ok(())
+}
+
+#[test]
+fn test_regex_and_replace() -> Result<(), RDFStoreError> {
+    let str = string("Alice in Wonderland");
+    assert_eq!(
+        str.regex(&string("^alice"), None)?.as_boolean(),
+        Some(false)
+    );
+    assert_eq!(
+        str.regex(&string("^alice"), Some(&string("i")))?
+            .as_boolean(),
+        Some(true)
+    );
+    assert_eq!(
+        str.regex(&string("."), Some(&string("q")))?.as_boolean(),
+        Some(false)
+    );
+    assert_eq!(
+        str.replace(&string("(\\w+) in"), &string("$1 from"), None)?
+            .lexical_form(),
+        "Alice from Wonderland"
+    );
+    assert!(str.regex(&string("("), None).is_err());
+    assert!(str.regex(&string("a"), Some(&string("z"))).is_err());
+    Ok(())
+}
+
+#[test]
+fn test_date_time_accessors_and_hashes() -> Result<(), RDFStoreError> {
+    let date_time =
+        Literal::from_type_and_buffer(DataType::DateTime, "2023-03-14T15:09:26Z", None)?
+            .unwrap();
+    assert_eq!(date_time.year()?.lexical_form(), "2023");
+    assert_eq!(date_time.month()?.lexical_form(), "3");
+    assert_eq!(date_time.hours()?.lexical_form(), "15");
+    assert_eq!(date_time.seconds()?.lexical_form(), "26.0");
+    assert_eq!(date_time.tz()?.lexical_form(), "Z");
+    assert!(string("2023").year().is_err());
+
+    assert_eq!(
+        string("abc").md5()?.lexical_form(),
+        "900150983cd24fb0d6963f7d28e17f72"
+    );
+    assert_eq!(
+        string("abc").sha1()?.lexical_form(),
+        "a9993e364706816aba3e25717850c26c9cd0d89d"
+    );
+    assert_eq!(
+        string("abc").sha256()?.lexical_form(),
+        "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad"
+    );
+    Ok(())
+}
+
+#[test]
+fn test_effective_boolean_value() -> Result<(), RDFStoreError> {
+    assert!(!string("").effective_boolean_value()?);
+    assert!(string("false").effective_boolean_value()?);
+    assert!(!integer(0).effective_boolean_value()?);
+    assert!(decimal("0.1").effective_boolean_value()?);
+    assert!(Literal::new_boolean(true)?.effective_boolean_value()?);
+    let date = Literal::from_type_and_buffer(DataType::Date, "2023-03-14", None)?.unwrap();
+    assert!(date.effective_boolean_value().is_err());
+    Ok(())
+}
+
+#[test]
+fn test_value_equality_is_not_term_equality() -> Result<(), RDFStoreError> {
+    let int = Literal::new_signed_integer_with_datatype(1, DataType::Int)?;
+    let padded = Literal::from_type_and_buffer(DataType::Integer, "01", None)?.unwrap();
+    assert_ne!(int, padded);
+    assert!(int.value_equals(&padded)?);
+    assert!(int.value_equals(&decimal("1.0"))?);
+    assert!(!int.value_equals(&integer(2))?);
+
+    let str = Literal::new_string_with_datatype("a", DataType::String)?;
+    assert_ne!(str, string("a"));
+    assert!(str.value_equals(&string("a"))?);
+    assert!(matches!(
+        string("1").value_equals(&int),
+        Err(RDFStoreError::ExpressionError { .. })
+    ));
+    Ok(())
+}
+
+#[test]
+fn test_value_compare() -> Result<(), RDFStoreError> {
+    assert_eq!(
+        integer(2).value_compare(&decimal("10.5"))?,
+        std::cmp::Ordering::Less
+    );
+    assert_eq!(
+        string("B").value_compare(&string("a"))?,
+        std::cmp::Ordering::Less
+    );
+    assert_eq!(
+        Literal::new_boolean(false)?.value_compare(&Literal::new_boolean(true)?)?,
+        std::cmp::Ordering::Less
+    );
+    let earlier = Literal::from_type_and_buffer(
+        DataType::DateTime,
+        "2023-01-01T12:00:00+02:00",
+        None,
+    )?
+    .unwrap();
+    let later = Literal::from_type_and_buffer(DataType::DateTime, "2023-01-01T11:00:00Z", None)?
+        .unwrap();
+    assert_eq!(
+        earlier.value_compare(&later)?,
+        std::cmp::Ordering::Less
+    );
+    assert!(string("a").value_compare(&integer(1)).is_err());
+    Ok(())
+}
+
+#[test]
+fn test_total_ordering() -> Result<(), RDFStoreError> {
+    let mut literals = [
+        string("b"),
+        integer(10),
+        Literal::from_type_and_buffer(DataType::BlankNode, "b0", None)?.unwrap(),
+        decimal("2.5"),
+        crate::Term::new_iri_from_str("https://example.com/a")?.as_literal().clone(),
+        string("a"),
+        integer(2),
+    ];
+    literals.sort();
+    let lexical_forms: Vec<String> = literals.iter().map(Literal::lexical_form).collect();
+    assert_eq!(
+        lexical_forms,
+        vec!["b0", "https://example.com/a", "2", "2.5", "10", "a", "b"]
+    );
+
+    let int = Literal::new_signed_integer_with_datatype(1, DataType::Int)?;
+    assert_ne!(int.cmp(&integer(1)), std::cmp::Ordering::Equal);
+    assert_eq!(int.cmp(&int.clone()), std::cmp::Ordering::Equal);
+    Ok(())
+}
+
+#[test]
+fn test_cast_between_strings_and_numbers() -> Result<(), RDFStoreError> {
+    let byte = string(" -12 ").cast_to(DataType::Byte)?;
+    assert_eq!(byte.data_type, DataType::Byte);
+    assert_eq!(byte.as_signed_long(), Some(-12));
+    assert_eq!(
+        integer(42).cast_to(DataType::String)?.as_str(),
+        Some("42")
+    );
+    assert_eq!(
+        string("+1.50").cast_to(DataType::Decimal)?.as_decimal(),
+        Some("1.5")
+    );
+    assert!(matches!(
+        string("1.5").cast_to(DataType::Integer),
+        Err(RDFStoreError::UnknownValueForDataType { .. })
+    ));
+    assert!(string("12abc").cast_to(DataType::Decimal).is_err());
+    assert!(string("P1Y2MT3.5S").cast_to(DataType::Duration).is_ok());
+    assert!(string("P1YT").cast_to(DataType::Duration).is_err());
+    Ok(())
+}
+
+#[test]
+fn test_cast_numeric_narrowing() -> Result<(), RDFStoreError> {
+    assert_eq!(
+        decimal("-3.99").cast_to(DataType::Integer)?.as_signed_long(),
+        Some(-3)
+    );
+    assert_eq!(
+        integer(255).cast_to(DataType::UnsignedByte)?.as_unsigned_long(),
+        Some(255)
+    );
+    assert!(matches!(
+        integer(300).cast_to(DataType::UnsignedByte),
+        Err(RDFStoreError::UnknownValueForDataType { .. })
+    ));
+    assert!(integer(-1).cast_to(DataType::UnsignedInt).is_err());
+    assert!(integer(40_000).cast_to(DataType::Short).is_err());
+    assert!(integer(0).cast_to(DataType::PositiveInteger).is_err());
+    assert_eq!(
+        integer(7).cast_to(DataType::Decimal)?.as_decimal(),
+        Some("7.0")
+    );
+    Ok(())
+}
+
+#[test]
+fn test_cast_booleans_and_dates() -> Result<(), RDFStoreError> {
+    assert_eq!(
+        decimal("0.0").cast_to(DataType::Boolean)?.as_boolean(),
+        Some(false)
+    );
+    assert_eq!(
+        string("1").cast_to(DataType::Boolean)?.as_boolean(),
+        Some(true)
+    );
+    assert!(string("yes").cast_to(DataType::Boolean).is_err());
+    assert_eq!(
+        Literal::new_boolean(true)?
+            .cast_to(DataType::Integer)?
+            .as_signed_long(),
+        Some(1)
+    );
+
+    let date_time = string("2023-05-06T23:30:00-02:00").cast_to(DataType::DateTime)?;
+    let date = date_time.cast_to(DataType::Date)?;
+    assert_eq!(date.lexical_form(), "2023-05-06-02:00");
+    assert_eq!(
+        date.cast_to(DataType::DateTime)?.lexical_form(),
+        "2023-05-06T00:00:00-02:00"
+    );
+    assert!(matches!(
+        Literal::new_boolean(true)?.cast_to(DataType::Date),
+        Err(RDFStoreError::InvalidCast { .. })
+    ));
+    assert!(string("2023-13-01").cast_to(DataType::Date).is_err());
+    Ok(())
+}
+
+#[test]
+fn test_integer_ranges() {
+    let parse = |data_type: DataType, value: &str| {
+        Literal::from_type_and_buffer(data_type, value, None)
+    };
+    assert!(matches!(
+        parse(DataType::Byte, "300"),
+        Err(RDFStoreError::UnknownValueForDataType { .. })
+    ));
+    assert!(parse(DataType::Byte, "-128").is_ok());
+    assert!(parse(DataType::PositiveInteger, "-5").is_err());
+    assert!(parse(DataType::PositiveInteger, "0").is_err());
+    assert!(parse(DataType::NegativeInteger, "0").is_err());
+    assert!(parse(DataType::NonPositiveInteger, "0").is_ok());
+    assert!(parse(DataType::UnsignedShort, "65536").is_err());
+    assert!(parse(DataType::UnsignedLong, "abc").is_err());
+    assert!(parse(DataType::Int, "1_000").is_err());
+    assert!(parse(DataType::Long, "9223372036854775808").is_err());
+    assert!(parse(DataType::UnsignedLong, "18446744073709551616").is_err());
+}
+
+#[test]
+fn test_unbounded_integer_ranges() -> Result<(), RDFStoreError> {
+    let parse = |data_type: DataType, value: &str| {
+        Literal::from_type_and_buffer(data_type, value, None).map(Option::unwrap)
+    };
+    let beyond_u64 = "18446744073709551616";
+    let below_i64 = "-9223372036854775809";
+    for (data_type, value) in [
+        (DataType::NonNegativeInteger, beyond_u64),
+        (DataType::PositiveInteger, beyond_u64),
+        (DataType::NonPositiveInteger, below_i64),
+        (DataType::NegativeInteger, below_i64),
+    ] {
+        let literal = parse(data_type, value)?;
+        assert_eq!(literal.data_type, data_type);
+        assert_eq!(literal.lexical_form(), value);
+        assert_eq!(
+            literal.as_big_integer().unwrap().to_string(),
+            value
+        );
+        assert_eq!(literal.as_signed_long(), None);
+        assert_eq!(literal.as_unsigned_long(), None);
+        assert_eq!(literal.clone(), literal);
+    }
+    assert!(parse(DataType::NonNegativeInteger, "-1").is_err());
+    assert!(parse(DataType::NegativeInteger, beyond_u64).is_err());
+
+    let positive = parse(DataType::PositiveInteger, "+0042")?;
+    assert_eq!(positive.lexical_form(), "42");
+    assert_eq!(positive.as_unsigned_long(), Some(42));
+    assert_eq!(
+        positive,
+        Literal::new_unsigned_integer_with_datatype(42, DataType::PositiveInteger)?
+    );
+    assert!(parse(DataType::PositiveInteger, beyond_u64)?
+        .value_compare(&positive)?
+        .is_gt());
+    Ok(())
+}
+
+#[test]
+fn test_arbitrary_precision_integer() -> Result<(), RDFStoreError> {
+    let big = Literal::from_type_and_buffer(
+        DataType::Integer,
+        "+000123456789012345678901234567890",
+        None,
+    )?
+    .unwrap();
+    assert_eq!(big.lexical_form(), "123456789012345678901234567890");
+    assert_eq!(big.as_signed_long(), None);
+    assert_eq!(
+        big.as_big_integer().unwrap().to_string(),
+        "123456789012345678901234567890"
+    );
+    assert_eq!(big.clone(), big);
+    assert!(big.value_compare(&integer(i64::MAX))?.is_gt());
+
+    let seven = Literal::from_type_and_buffer(DataType::Integer, "+007", None)?.unwrap();
+    assert_eq!(seven, integer(7));
+    assert_eq!(seven.as_signed_long(), Some(7));
+    Ok(())
+}
+
+#[test]
+fn test_decimal_canonical_form() -> Result<(), RDFStoreError> {
+    assert_eq!(decimal("01.500").lexical_form(), "1.5");
+    assert_eq!(decimal("+100").lexical_form(), "100.0");
+    assert_eq!(decimal("-.25").lexical_form(), "-0.25");
+    assert_eq!(decimal("-0.0").lexical_form(), "0.0");
+    assert_eq!(decimal("1.0"), decimal("1.00"));
+
+    let mut decimals = std::collections::HashSet::new();
+    decimals.insert(decimal("1.0"));
+    assert!(decimals.contains(&decimal("001.000")));
+
+    for invalid in ["1e3", "1.2.3", ".", "", "abc", "--1"] {
+        assert!(matches!(
+            Literal::from_type_and_buffer(DataType::Decimal, invalid, None),
+            Err(RDFStoreError::UnknownValueForDataType { .. })
+        ));
+    }
+    Ok(())
+}
+
+#[test]
+fn test_exact_decimal_arithmetic() -> Result<(), RDFStoreError> {
+    assert_eq!(
+        decimal("0.1").numeric_add(&decimal("0.2"))?,
+        decimal("0.3")
+    );
+    assert_eq!(
+        decimal("12345678901234567890.01")
+            .numeric_multiply(&integer(100))?
+            .lexical_form(),
+        "1234567890123456789001.0"
+    );
+    assert_eq!(
+        decimal("1.10").numeric_subtract(&decimal("0.1"))?.lexical_form(),
+        "1.0"
+    );
+    assert_eq!(
+        integer(1).numeric_divide(&integer(8))?.lexical_form(),
+        "0.125"
+    );
+    assert!(decimal("0.30").value_equals(&decimal("0.3"))?);
+    assert!(decimal("2.5").value_compare(&integer(2))?.is_gt());
+    Ok(())
+}
+
+fn double(value: &str) -> Literal {
+    Literal::from_type_and_buffer(DataType::Double, value, None)
+        .unwrap()
+        .unwrap()
+}
+
+#[test]
+fn test_double_lexical_forms() -> Result<(), RDFStoreError> {
+    assert_eq!(double("1").lexical_form(), "1.0E0");
+    assert_eq!(double("150").lexical_form(), "1.5E2");
+    assert_eq!(double("-1.25e-3").lexical_form(), "-1.25E-3");
+    assert_eq!(double("0.0").lexical_form(), "0.0E0");
+    assert_eq!(double("-0").lexical_form(), "-0.0E0");
+    assert_eq!(double("INF").lexical_form(), "INF");
+    assert_eq!(double("-INF").lexical_form(), "-INF");
+    assert_eq!(double("NaN").lexical_form(), "NaN");
+    let float = Literal::from_type_and_buffer(DataType::Float, "0.1", None)?.unwrap();
+    assert_eq!(float.lexical_form(), "1.0E-1");
+
+    for invalid in ["inf", "nan", "Infinity", "1e", "e3", "1.2.3", ""] {
+        assert!(matches!(
+            Literal::from_type_and_buffer(DataType::Double, invalid, None),
+            Err(RDFStoreError::UnknownValueForDataType { .. })
+        ));
+    }
+    Ok(())
+}
+
+#[test]
+fn test_double_output() {
+    assert_eq!(
+        format!("{}", double("150").display_turtle()),
+        "1.5E2"
+    );
+    assert_eq!(
+        format!("{}", double("NaN").display_turtle()),
+        "\"NaN\"^^xsd:double"
+    );
+    let float = Literal::new_float(2.5).unwrap();
+    assert_eq!(
+        format!("{}", float.display_turtle()),
+        "\"2.5E0\"^^xsd:float"
+    );
+    assert_eq!(format!("{}", double("1")), "1.0E0");
+    assert_eq!(format!("{float}"), "2.5E0");
+    assert_eq!(format!("{}", double("150").display_json()), "1.5E2");
+    assert_eq!(
+        format!("{}", double("-INF").display_json()),
+        "\"-INF\""
+    );
+}
+
+#[test]
+fn test_double_term_equality() -> Result<(), RDFStoreError> {
+    assert_eq!(double("NaN"), double("NaN"));
+    assert_ne!(double("0"), double("-0"));
+    assert_eq!(double("1e0"), double("1.0"));
+    assert_ne!(
+        double("1"),
+        Literal::from_type_and_buffer(DataType::Float, "1", None)?.unwrap()
+    );
+    let mut doubles = std::collections::HashSet::new();
+    doubles.insert(double("NaN"));
+    assert!(doubles.contains(&double("NaN")));
+
+    assert!(!double("NaN").value_equals(&double("NaN"))?);
+    assert!(double("0").value_equals(&double("-0"))?);
+    assert!(double("NaN").value_compare(&double("1")).is_err());
+    let mut sorted = [double("2"), double("NaN"), integer(1)];
+    sorted.sort();
+    assert_eq!(sorted[0], double("NaN"));
+    Ok(())
+}
+
+#[test]
+fn test_double_arithmetic_and_casts() -> Result<(), RDFStoreError> {
+    let sum = integer(1).numeric_add(&double("0.5"))?;
+    assert_eq!(sum.data_type, DataType::Double);
+    assert_eq!(sum.lexical_form(), "1.5E0");
+    let float = Literal::new_float(1.5)?;
+    assert_eq!(
+        float.numeric_multiply(&decimal("2.0"))?.data_type,
+        DataType::Float
+    );
+    assert_eq!(
+        integer(1).numeric_divide(&double("0"))?.lexical_form(),
+        "INF"
+    );
+    assert!(!double("NaN").effective_boolean_value()?);
+    assert_eq!(double("-2.5").round()?.lexical_form(), "-2.0E0");
+
+    assert_eq!(
+        double("1.0e2").cast_to(DataType::Integer)?.lexical_form(),
+        "100"
+    );
+    assert_eq!(
+        Literal::new_float(0.1)?.cast_to(DataType::Decimal)?.lexical_form(),
+        "0.1"
+    );
+    assert!(double("INF").cast_to(DataType::Integer).is_err());
+    assert_eq!(
+        string("-INF").cast_to(DataType::Double)?.lexical_form(),
+        "-INF"
+    );
+    assert_eq!(
+        decimal("2.5").cast_to(DataType::Float)?.lexical_form(),
+        "2.5E0"
+    );
+    Ok(())
+}
+
+#[test]
+fn test_date_time_keeps_timezone() -> Result<(), RDFStoreError> {
+    let date_time = Literal::from_lexical_form(DataType::DateTime, "2023-01-01T10:00:00.50+01:00")?;
+    assert_eq!(date_time.lexical_form(), "2023-01-01T10:00:00.5+01:00");
+    assert_eq!(
+        format!("{}", date_time.display_turtle()),
+        "\"2023-01-01T10:00:00.5+01:00\"^^xsd:dateTime"
+    );
+    assert_eq!(date_time.hours()?.lexical_form(), "10");
+    assert_eq!(date_time.tz()?.lexical_form(), "+01:00");
+    assert_eq!(date_time.timezone()?.lexical_form(), "PT1H");
+
+    let utc = Literal::from_lexical_form(DataType::DateTime, "2023-01-01T09:00:00.5Z")?;
+    assert_ne!(date_time, utc);
+    assert!(date_time.value_equals(&utc)?);
+    assert_eq!(date_time.as_date_time(), utc.as_date_time());
+
+    let local = Literal::from_lexical_form(DataType::DateTime, "2023-01-01T24:00:00")?;
+    assert_eq!(local.lexical_form(), "2023-01-02T00:00:00");
+    assert_eq!(local.timezone_offset(), None);
+    assert_eq!(local.tz()?.lexical_form(), "");
+    assert!(local.timezone().is_err());
+    Ok(())
+}
+
+#[test]
+fn test_date_keeps_timezone() -> Result<(), RDFStoreError> {
+    let date = Literal::from_lexical_form(DataType::Date, "2023-01-01+05:00")?;
+    assert_eq!(date.lexical_form(), "2023-01-01+05:00");
+    assert_eq!(
+        date.timezone_offset(),
+        chrono::FixedOffset::east_opt(5 * 3600)
+    );
+    assert_eq!(
+        format!("{}", date.display_turtle()),
+        "\"2023-01-01+05:00\"^^xsd:date"
+    );
+    assert_eq!(date.clone(), date);
+
+    let utc = Literal::from_lexical_form(DataType::Date, "2023-01-01Z")?;
+    assert_eq!(utc.lexical_form(), "2023-01-01Z");
+    assert_ne!(date, utc);
+    assert!(!date.value_equals(&utc)?);
+    assert_eq!(
+        date.value_compare(&utc)?,
+        std::cmp::Ordering::Less
+    );
+    assert_eq!(
+        utc.subtract_date_time(&date)?.lexical_form(),
+        "PT5H"
+    );
+    assert_eq!(
+        Literal::from_type_and_buffer(DataType::Date, "2023-01-01+05:00", None)?,
+        Some(date)
+    );
+
+    let local = Literal::from_lexical_form(DataType::Date, "2023-01-01")?;
+    assert_eq!(local.timezone_offset(), None);
+    assert_ne!(local, utc);
+    assert!(local.value_equals(&utc)?);
+    Ok(())
+}
+
+#[test]
+fn test_time_and_date_time_stamp() -> Result<(), RDFStoreError> {
+    let time = Literal::from_lexical_form(DataType::Time, "23:30:00-05:00")?;
+    assert_eq!(time.lexical_form(), "23:30:00-05:00");
+    assert_eq!(time.minutes()?.lexical_form(), "30");
+    let earlier = Literal::from_lexical_form(DataType::Time, "01:00:00Z")?;
+    assert_eq!(
+        earlier.value_compare(&time)?,
+        std::cmp::Ordering::Less
+    );
+    assert_eq!(
+        string("2023-05-06T23:30:00-05:00")
+            .cast_to(DataType::DateTime)?
+            .cast_to(DataType::Time)?
+            .lexical_form(),
+        time.lexical_form()
+    );
+
+    let stamp = Literal::from_lexical_form(DataType::DateTimeStamp, "2023-05-06T23:30:00Z")?;
+    assert_eq!(
+        format!("{}", stamp.display_turtle()),
+        "\"2023-05-06T23:30:00Z\"^^xsd:dateTimeStamp"
+    );
+    assert!(Literal::from_lexical_form(DataType::DateTimeStamp, "2023-05-06T23:30:00").is_err());
+    assert!(string("2023-05-06T23:30:00")
+        .cast_to(DataType::DateTimeStamp)
+        .is_err());
+    Ok(())
+}
+
+#[test]
+fn test_strict_and_lenient_parsing() -> Result<(), RDFStoreError> {
+    for lenient in ["12/31/2023", "2023-12-31 13:21", "Wed, 18 Feb 2015 23:16:09 EST"] {
+        assert!(Literal::from_type_and_buffer(DataType::Date, lenient, None)?.is_some());
+        assert!(Literal::from_lexical_form(DataType::Date, lenient).is_err());
+        assert!(Literal::from_lexical_form(DataType::DateTime, lenient).is_err());
+    }
+    for invalid in [
+        "2023-01-01T10:00:60Z",
+        "2023-01-01T10:00:00+15:00",
+        "2023-02-30T10:00:00",
+        "2023-01-01T24:00:01",
+        "2023-01-01 10:00:00",
+    ] {
+        assert!(Literal::from_lexical_form(DataType::DateTime, invalid).is_err());
+    }
+    assert_eq!(
+        Literal::from_lexical_form(DataType::Date, "2023-12-31-05:00")?.lexical_form(),
+        "2023-12-31-05:00"
+    );
+    assert!(Literal::from_lexical_form(DataType::Date, "2023-12-31+15:00").is_err());
+    assert!(Literal::from_lexical_form(DataType::Boolean, "yes").is_err());
+    assert!(Literal::from_lexical_form(DataType::AnyUri, "<https://example.com>").is_err());
+    Ok(())
+}
+
+fn duration(data_type: DataType, value: &str) -> Literal {
+    Literal::from_lexical_form(data_type, value).unwrap()
+}
+
+#[test]
+fn test_duration_canonical_forms() -> Result<(), RDFStoreError> {
+    assert_eq!(
+        duration(DataType::Duration, "P0Y14M").lexical_form(),
+        "P1Y2M"
+    );
+    assert_eq!(
+        duration(DataType::Duration, "PT36H").lexical_form(),
+        "P1DT12H"
+    );
+    assert_eq!(
+        duration(DataType::DayTimeDuration, "-PT90.50S").lexical_form(),
+        "-PT1M30.5S"
+    );
+    assert_eq!(
+        duration(DataType::YearMonthDuration, "P0Y").lexical_form(),
+        "P0M"
+    );
+    assert_eq!(
+        duration(DataType::Duration, "P0D").lexical_form(),
+        "PT0S"
+    );
+    assert_eq!(
+        duration(DataType::Duration, "PT36H"),
+        duration(DataType::Duration, "P1DT12H")
+    );
+    let value = duration(DataType::Duration, "P1Y2M3DT4H5M6.7S")
+        .as_xsd_duration()
+        .unwrap();
+    assert_eq!(value.months(), 14);
+    assert_eq!(value.seconds().to_string(), "273906.7");
+
+    for invalid in ["P", "PT", "P1YT", "1Y", "P-1Y", "P1.5Y", "PT1H2S3M"] {
+        assert!(Literal::from_lexical_form(DataType::Duration, invalid).is_err());
+    }
+    assert!(Literal::from_lexical_form(DataType::YearMonthDuration, "P1D").is_err());
+    assert!(Literal::from_lexical_form(DataType::DayTimeDuration, "P1M").is_err());
+    assert_eq!(
+        duration(DataType::Duration, "P1Y2DT3H")
+            .cast_to(DataType::YearMonthDuration)?
+            .lexical_form(),
+        "P1Y"
+    );
+    assert_eq!(
+        format!(
+            "{}",
+            duration(DataType::DayTimeDuration, "PT1H").display_turtle()
+        ),
+        "\"PT1H\"^^xsd:dayTimeDuration"
+    );
+    Ok(())
+}
+
+#[test]
+fn test_duration_comparison() -> Result<(), RDFStoreError> {
+    let compare = |a: &str, b: &str| {
+        duration(DataType::Duration, a).value_compare(&duration(DataType::Duration, b))
+    };
+    assert_eq!(compare("P1Y", "P11M")?, std::cmp::Ordering::Greater);
+    assert_eq!(compare("PT23H", "P1D")?, std::cmp::Ordering::Less);
+    assert!(compare("P1M", "P30D").is_err());
+    // The examples of the XSD specification
+    for (a, b) in [
+        ("P1Y", "P364D"),
+        ("P1M", "P27D"),
+        ("P3M", "P88D"),
+        ("P5M", "P149D"),
+        ("P1Y", "-P1M"),
+        ("P1M", "PT1H"),
+    ] {
+        assert_eq!(compare(a, b)?, std::cmp::Ordering::Greater);
+        assert_eq!(compare(b, a)?, std::cmp::Ordering::Less);
+    }
+    assert_eq!(compare("P1Y", "P367D")?, std::cmp::Ordering::Less);
+    assert_eq!(compare("P1M", "P32D")?, std::cmp::Ordering::Less);
+    for (a, b) in [
+        ("P1Y", "P365D"),
+        ("P1Y", "P366D"),
+        ("P1M", "P28D"),
+        ("P1M", "P31D"),
+        ("P5M", "P153D"),
+    ] {
+        assert!(compare(a, b).is_err());
+    }
+    assert_eq!(
+        compare("P1Y2M", "P14M")?,
+        std::cmp::Ordering::Equal
+    );
+    // Long durations are still compared exactly
+    assert_eq!(
+        compare("P400000000000000Y", "P146096999999999999D")?,
+        std::cmp::Ordering::Greater
+    );
+    assert!(duration(DataType::YearMonthDuration, "P12M")
+        .value_equals(&duration(DataType::Duration, "P1Y"))?);
+    assert!(!duration(DataType::Duration, "P1M")
+        .value_equals(&duration(DataType::Duration, "P30D"))?);
+    Ok(())
+}
+
+#[test]
+fn test_date_time_and_duration_arithmetic() -> Result<(), RDFStoreError> {
+    let date_time = Literal::from_lexical_form(DataType::DateTime, "2024-01-31T10:00:00-05:00")?;
+    assert_eq!(
+        date_time
+            .add_duration(&duration(DataType::Duration, "P1M1DT14H"))?
+            .lexical_form(),
+        "2024-03-02T00:00:00-05:00"
+    );
+    assert_eq!(
+        date_time
+            .subtract_duration(&duration(DataType::YearMonthDuration, "P1Y"))?
+            .lexical_form(),
+        "2023-01-31T10:00:00-05:00"
+    );
+    let date = Literal::from_lexical_form(DataType::Date, "2024-02-29")?;
+    assert_eq!(
+        date.add_duration(&duration(DataType::YearMonthDuration, "P1Y"))?
+            .lexical_form(),
+        "2025-02-28"
+    );
+    let time = Literal::from_lexical_form(DataType::Time, "23:00:00")?;
+    assert_eq!(
+        time.add_duration(&duration(DataType::DayTimeDuration, "PT2H"))?
+            .lexical_form(),
+        "01:00:00"
+    );
+    assert!(time
+        .add_duration(&duration(DataType::YearMonthDuration, "P1M"))
+        .is_err());
+
+    let utc = Literal::from_lexical_form(DataType::DateTime, "2024-01-31T12:00:00.5Z")?;
+    assert_eq!(
+        utc.subtract_date_time(&date_time)?.lexical_form(),
+        "-PT2H59M59.5S"
+    );
+    assert_eq!(
+        duration(DataType::DayTimeDuration, "PT1H")
+            .add_duration(&duration(DataType::DayTimeDuration, "PT30M"))?
+            .lexical_form(),
+        "PT1H30M"
+    );
+    assert_eq!(
+        duration(DataType::YearMonthDuration, "P1Y")
+            .multiply_duration(&decimal("1.5"))?
+            .lexical_form(),
+        "P1Y6M"
+    );
+    assert_eq!(
+        duration(DataType::DayTimeDuration, "P1D")
+            .divide_duration(&integer(4))?
+            .lexical_form(),
+        "PT6H"
+    );
+    assert_eq!(
+        duration(DataType::DayTimeDuration, "P1D")
+            .divide_duration(&duration(DataType::DayTimeDuration, "PT8H"))?
+            .lexical_form(),
+        "3.0"
+    );
+    assert!(duration(DataType::Duration, "P1D")
+        .multiply_duration(&integer(2))
+        .is_err());
+    Ok(())
+}
+
+fn partial_date(data_type: DataType, value: &str) -> Literal {
+    Literal::from_lexical_form(data_type, value).unwrap()
+}
+
+#[test]
+fn test_gregorian_lexical_forms() -> Result<(), RDFStoreError> {
+    for (data_type, lexical_form, canonical) in [
+        (DataType::Year, "1999", "1999"),
+        (DataType::Year, "-0044", "-0044"),
+        (DataType::Year, "0000", "0000"),
+        (DataType::Year, "12345+14:00", "12345+14:00"),
+        (DataType::Year, "-12000Z", "-12000Z"),
+        (DataType::YearMonth, "2023-05+00:00", "2023-05Z"),
+        (DataType::MonthDay, "--02-29", "--02-29"),
+        (DataType::MonthDay, " --12-25-05:00 ", "--12-25-05:00"),
+        (DataType::Day, "---31", "---31"),
+        (DataType::Month, "--12Z", "--12Z"),
+    ] {
+        let literal = Literal::from_lexical_form(data_type, lexical_form)?;
+        assert_eq!(literal.lexical_form(), canonical);
+        assert_eq!(literal.clone(), literal);
+    }
+    for (data_type, lexical_form) in [
+        (DataType::Year, "99"),
+        (DataType::Year, "01999"),
+        (DataType::Year, "+1999"),
+        (DataType::Year, "1999+15:00"),
+        (DataType::YearMonth, "2023-13"),
+        (DataType::YearMonth, "2023-5"),
+        (DataType::MonthDay, "--02-30"),
+        (DataType::MonthDay, "--04-31"),
+        (DataType::Day, "---32"),
+        (DataType::Day, "---00"),
+        (DataType::Month, "--00"),
+        (DataType::Month, "--12--"),
+    ] {
+        assert!(
+            Literal::from_lexical_form(data_type, lexical_form).is_err(),
+            "{lexical_form} should not be a valid {data_type:?}"
+        );
+    }
+
+    let year_month = partial_date(DataType::YearMonth, "-0044-03+01:00");
+    let value = year_month.as_partial_date().unwrap();
+    assert_eq!(value.year(), Some(-44));
+    assert_eq!(value.month(), Some(3));
+    assert_eq!(value.day(), None);
+    assert_eq!(
+        value.timezone().map(|timezone| timezone.local_minus_utc()),
+        Some(3600)
+    );
+    assert_eq!(
+        format!("{}", year_month.display_turtle()),
+        "\"-0044-03+01:00\"^^xsd:gYearMonth"
+    );
+    assert_eq!(Literal::new_partial_date(&value)?, year_month);
+    assert_eq!(
+        Literal::from_type_and_buffer(DataType::Day, "---05", None)?,
+        Some(partial_date(DataType::Day, "---05"))
+    );
+    Ok(())
+}
+
+#[test]
+fn test_gregorian_comparison_and_casts() -> Result<(), RDFStoreError> {
+    use std::cmp::Ordering;
+
+    assert_eq!(
+        partial_date(DataType::Year, "-0044")
+            .value_compare(&partial_date(DataType::Year, "0001"))?,
+        Ordering::Less
+    );
+    assert_eq!(
+        partial_date(DataType::Year, "10000")
+            .value_compare(&partial_date(DataType::Year, "9999"))?,
+        Ordering::Greater
+    );
+    // The start of 2000 in UTC+01:00 is an hour before the start in UTC
+    assert_eq!(
+        partial_date(DataType::Year, "2000+01:00")
+            .value_compare(&partial_date(DataType::Year, "2000Z"))?,
+        Ordering::Less
+    );
+    // A year without a timezone and one in UTC start at the same moment
+    // without being equal, so they are not ordered
+    let (local, utc) = (
+        partial_date(DataType::Year, "2000"),
+        partial_date(DataType::Year, "2000Z"),
+    );
+    assert_eq!(
+        local
+            .as_partial_date()
+            .unwrap()
+            .partial_cmp(&utc.as_partial_date().unwrap()),
+        None
+    );
+    assert!(local.value_compare(&utc).is_err());
+    assert!(!local.value_equals(&utc)?);
+    assert!(partial_date(DataType::Day, "---01+01:00")
+        .value_equals(&partial_date(DataType::Day, "---01+01:00"))?);
+    assert!(!partial_date(DataType::MonthDay, "--12-31Z")
+        .value_equals(&partial_date(DataType::MonthDay, "--01-01+14:00"))?);
+    assert!(partial_date(DataType::Month, "--01")
+        .value_compare(&partial_date(DataType::Day, "---01"))
+        .is_err());
+
+    let date_time = Literal::from_lexical_form(DataType::DateTime, "2023-05-06T10:00:00-05:00")?;
+    for (data_type, expected) in [
+        (DataType::Year, "2023-05:00"),
+        (DataType::YearMonth, "2023-05-05:00"),
+        (DataType::MonthDay, "--05-06-05:00"),
+        (DataType::Day, "---06-05:00"),
+        (DataType::Month, "--05-05:00"),
+    ] {
+        assert_eq!(date_time.cast_to(data_type)?.lexical_form(), expected);
+    }
+    let date = Literal::from_lexical_form(DataType::Date, "2023-05-06")?;
+    assert_eq!(
+        date.cast_to(DataType::YearMonth)?.lexical_form(),
+        "2023-05"
+    );
+    assert_eq!(
+        string("--11").cast_to(DataType::Month)?,
+        partial_date(DataType::Month, "--11")
+    );
+    assert!(partial_date(DataType::Year, "2023")
+        .cast_to(DataType::Date)
+        .is_err());
+    Ok(())
+}
+
+#[test]
+fn test_binary() -> Result<(), RDFStoreError> {
+    let hex = Literal::from_lexical_form(DataType::HexBinary, "0fb7")?;
+    assert_eq!(hex.as_binary(), Some([0x0F, 0xB7].as_slice()));
+    assert_eq!(hex.lexical_form(), "0FB7");
+    assert_eq!(hex, Literal::from_lexical_form(DataType::HexBinary, "0FB7")?);
+    assert_eq!(hex.clone(), hex);
+    assert_eq!(format!("{}", hex.display_turtle()), "\"0FB7\"^^xsd:hexBinary");
+
+    let base64 = hex.cast_to(DataType::Base64Binary)?;
+    assert_eq!(base64.lexical_form(), "D7c=");
+    assert_eq!(base64.cast_to(DataType::HexBinary)?, hex);
+    assert!(base64.value_equals(&hex)?);
+    assert!(!base64.value_equals(&Literal::new_base64_binary(b"\x0F")?)?);
+    assert_eq!(
+        Literal::from_lexical_form(DataType::Base64Binary, "SGVs bG8s IHdv cmxk IQ==")?
+            .as_binary(),
+        Some(b"Hello, world!".as_slice())
+    );
+    for (bytes, base64) in [
+        (b"".as_slice(), ""),
+        (b"f".as_slice(), "Zg=="),
+        (b"fo".as_slice(), "Zm8="),
+        (b"foo".as_slice(), "Zm9v"),
+        (b"foob".as_slice(), "Zm9vYg=="),
+    ] {
+        let literal = Literal::new_base64_binary(bytes)?;
+        assert_eq!(literal.lexical_form(), base64);
+        assert_eq!(
+            Literal::from_lexical_form(DataType::Base64Binary, base64)?,
+            literal
+        );
+    }
+    for (data_type, lexical_form) in [
+        (DataType::HexBinary, "0FB"),
+        (DataType::HexBinary, "0G"),
+        (DataType::Base64Binary, "Zm9"),
+        (DataType::Base64Binary, "Zh=="),
+        (DataType::Base64Binary, "Z==="),
+        (DataType::Base64Binary, "Zg==Zm8="),
+    ] {
+        assert!(
+            Literal::from_lexical_form(data_type, lexical_form).is_err(),
+            "{lexical_form} should not be a valid {data_type:?}"
+        );
+    }
+    assert_eq!(string("0fb7").cast_to(DataType::HexBinary)?, hex);
+    assert_eq!(hex.cast_to(DataType::String)?.as_str(), Some("0FB7"));
+    Ok(())
+}
+
+#[test]
+fn test_custom_data_types() -> Result<(), RDFStoreError> {
+    use crate::CustomDataTypeRegistry;
+
+    const WKT: &str = "http://www.opengis.net/ont/geosparql#wktLiteral";
+    const CURRENCY: &str = "https://ekgf.org/ontology/test/currency";
+
+    let point = Literal::from_data_type_iri(WKT, "POINT(4.9 52.4)")?;
+    assert_eq!(point.data_type, DataType::Custom);
+    assert_eq!(point.data_type_iri(), WKT);
+    assert_eq!(point.lexical_form(), "POINT(4.9 52.4)");
+    assert_eq!(point, Literal::new_custom(WKT, "POINT(4.9 52.4)")?);
+    assert_ne!(point, Literal::new_custom(WKT, "POINT(4.90 52.4)")?);
+    assert_ne!(
+        point,
+        Literal::new_custom("https://ekgf.org/ontology/test/wkt", "POINT(4.9 52.4)")?
+    );
+    assert_eq!(
+        format!("{}", point.display_turtle()),
+        format!("\"POINT(4.9 52.4)\"^^<{WKT}>")
+    );
+    assert_eq!(point.clone(), point);
+    assert!(point.value_equals(&point)?);
+    assert!(point
+        .value_equals(&Literal::new_custom(WKT, "POINT(0 0)")?)
+        .is_err());
+
+    // Built-in datatypes are never custom
+    let integer = Literal::from_data_type_iri("http://www.w3.org/2001/XMLSchema#integer", "42")?;
+    assert_eq!(integer.data_type, DataType::Integer);
+    assert!(Literal::new_custom("http://www.w3.org/2001/XMLSchema#integer", "42").is_err());
+    assert!(Literal::new_custom("not an IRI", "42").is_err());
+
+    CustomDataTypeRegistry::register(CURRENCY, |code: &str| {
+        let code = code.trim().to_uppercase();
+        (code.len() == 3).then_some(code)
+    })?;
+    assert!(CustomDataTypeRegistry::is_registered(CURRENCY));
+    assert_eq!(
+        Literal::new_custom(CURRENCY, " eur")?.lexical_form(),
+        "EUR"
+    );
+    assert!(matches!(
+        Literal::new_custom(CURRENCY, "euro"),
+        Err(RDFStoreError::InvalidValueForCustomDataType { .. })
+    ));
+    assert!(CustomDataTypeRegistry::unregister(CURRENCY));
+    assert_eq!(
+        Literal::new_custom(CURRENCY, "euro")?.lexical_form(),
+        "euro"
+    );
+    assert!(CustomDataTypeRegistry::register(
+        "http://www.w3.org/2001/XMLSchema#string",
+        |value: &str| Some(value.to_string())
+    )
+    .is_err());
+    Ok(())
+}
+
+#[test]
+fn test_to_and_from_literal() -> Result<(), RDFStoreError> {
+    use {
+        crate::{FromLiteral, ToLiteral},
+        chrono::{DateTime, FixedOffset, NaiveDate, NaiveTime, Utc},
+        num_bigint::BigInt,
+    };
+
+    assert_eq!((-5_i8).to_literal()?.data_type, DataType::Byte);
+    assert_eq!(5_i32.to_literal()?.data_type, DataType::Int);
+    assert_eq!(5_u64.to_literal()?.data_type, DataType::UnsignedLong);
+    assert_eq!(u128::MAX.to_literal()?.data_type, DataType::Integer);
+    assert_eq!(u128::try_from(&u128::MAX.to_literal()?)?, u128::MAX);
+    assert_eq!(i16::from_literal(&integer(-300))?, -300);
+    assert_eq!(u8::try_from(&integer(255))?, 255);
+    assert!(matches!(
+        u8::try_from(&integer(256)),
+        Err(RDFStoreError::UnknownValueForDataType { data_type: DataType::UnsignedByte, .. })
+    ));
+    assert!(matches!(
+        u32::try_from(&integer(-1)),
+        Err(RDFStoreError::UnknownValueForDataType { .. })
+    ));
+    assert!(matches!(
+        i32::try_from(&string("1")),
+        Err(RDFStoreError::InvalidCast { from: DataType::PlainLiteral, to: DataType::Int })
+    ));
+    assert!(BigInt::try_from(&decimal("1.5")).is_err());
+
+    assert!(bool::try_from(&true.to_literal()?)?);
+    assert_eq!(String::try_from(&"abc".to_literal()?)?, "abc");
+    assert_eq!("abc".to_string().to_literal()?.data_type, DataType::String);
+    assert!(String::try_from(&integer(1)).is_err());
+    assert_eq!(f64::try_from(&1.5_f64.to_literal()?)?, 1.5);
+    assert_eq!(f32::try_from(&1.5_f32.to_literal()?)?, 1.5);
+
+    let date = NaiveDate::from_ymd_opt(2023, 5, 6).unwrap();
+    assert_eq!(NaiveDate::try_from(&date.to_literal()?)?, date);
+    let time = NaiveTime::from_hms_opt(10, 30, 0).unwrap();
+    assert_eq!(time.to_literal()?.lexical_form(), "10:30:00");
+    assert_eq!(NaiveTime::try_from(&time.to_literal()?)?, time);
+    let date_time = DateTime::parse_from_rfc3339("2023-05-06T10:30:00-05:00").unwrap();
+    let literal = date_time.to_literal()?;
+    assert_eq!(literal.lexical_form(), "2023-05-06T10:30:00-05:00");
+    assert_eq!(DateTime::<FixedOffset>::try_from(&literal)?, date_time);
+    assert_eq!(
+        DateTime::<Utc>::try_from(&literal)?.to_rfc3339(),
+        "2023-05-06T15:30:00+00:00"
+    );
+    assert_eq!(
+        date.and_time(time).to_literal()?.lexical_form(),
+        "2023-05-06T10:30:00"
+    );
+
+    let iri = IriBuf::from_str("https://ekgf.org/ontology/test").unwrap();
+    assert_eq!(IriBuf::try_from(&iri.to_literal()?)?, iri);
+
+    assert_eq!(Some(5_u8).to_optional_literal()?, Some(5_u8.to_literal()?));
+    assert_eq!(None::<u8>.to_optional_literal()?, None);
+    assert!(None::<u8>.to_literal().is_err());
+    assert_eq!(Option::<u8>::from_optional_literal(None)?, None);
+    assert_eq!(Option::<u8>::from_optional_literal(Some(&integer(5)))?, Some(5));
+    assert!(matches!(
+        u8::from_optional_literal(None),
+        Err(RDFStoreError::MissingLiteral { .. })
+    ));
+    Ok(())
+}
+
+#[test]
+fn test_lexical_form_of_every_data_type() -> Result<(), RDFStoreError> {
+    let mut data_type_id = 0;
+    while let Ok(data_type) = DataType::from_datatype_id(data_type_id) {
+        data_type_id += 1;
+        let lexical_form = match data_type {
+            DataType::UnboundValue | DataType::Literal | DataType::Custom => continue,
+            DataType::IriReference | DataType::AnyUri => "https://ekgf.org/test",
+            DataType::BlankNode => "b0",
+            DataType::String | DataType::PlainLiteral => "test",
+            DataType::Boolean => "true",
+            DataType::DateTime | DataType::DateTimeStamp => "2023-05-06T10:30:00Z",
+            DataType::Time => "10:30:00",
+            DataType::Date => "2023-05-06",
+            DataType::YearMonth => "2023-05",
+            DataType::Year => "2023",
+            DataType::MonthDay => "--05-06",
+            DataType::Day => "---06",
+            DataType::Month => "--05",
+            DataType::Duration | DataType::DayTimeDuration => "P1D",
+            DataType::YearMonthDuration => "P1Y",
+            DataType::Double | DataType::Float => "1.5E0",
+            DataType::Decimal => "1.5",
+            DataType::NonPositiveInteger | DataType::NegativeInteger => "-1",
+            DataType::HexBinary => "0FB7",
+            DataType::Base64Binary => "D7c=",
+            _ => "1",
+        };
+        let literal = Literal::from_type_and_buffer(data_type, lexical_form, None)?.unwrap();
+        assert_eq!(literal.lexical_form(), lexical_form);
+        assert!(literal.as_sparql_display().to_string().contains(lexical_form));
+    }
+    assert_eq!(
+        Literal::new_custom("https://ekgf.org/ontology/test", "x")?.lexical_form(),
+        "x"
+    );
+    let mut unbound = Literal::default();
+    unbound.data_type = DataType::UnboundValue;
+    assert_eq!(unbound.lexical_form(), "");
+    Ok(())
+}
@@ -5,14 +5,26 @@
 use serde::{de::Visitor, Deserialize, Deserializer, Serialize, Serializer};
 use {
     crate::{
+        literal::{
+            binary::canonical_binary,
+            escape_string,
+            date_time::{canonical_date, canonical_date_time, canonical_time},
+            decimal::canonical_decimal,
+            duration::XsdDuration,
+            double::{canonical_double, double_term_bits},
+            gregorian::XsdPartialDate,
+        },
         DataType,
         LiteralIdUrlDisplay,
+        LiteralSparqlDisplay,
         LiteralUrlDisplay,
         LiteralValue,
         RDFStoreError::{self, Unknown},
         Term,
     },
+    bigdecimal::BigDecimal,
     iref::{Iri, IriBuf, IriRef},
+    num_bigint::BigInt,
     std::{
         fmt::{Debug, Display, Formatter},
         mem::ManuallyDrop,
@@ -56,18 +68,30 @@ impl PartialEq for Literal {
                 self.literal_value.string == other.literal_value.string
             } else if data_type.is_boolean() {
                 self.literal_value.boolean == other.literal_value.boolean
+            } else if data_type.is_big_integer() {
+                self.literal_value.string == other.literal_value.string
+            } else if data_type.is_floating_point() {
+                double_term_bits(self.literal_value.double) ==
+                    double_term_bits(other.literal_value.double)
             } else if data_type.is_signed_integer() {
                 self.literal_value.signed_integer == other.literal_value.signed_integer
             } else if data_type.is_unsigned_integer() {
                 self.literal_value.unsigned_integer == other.literal_value.unsigned_integer
             } else if data_type.is_blank_node() {
                 self.literal_value.blank_node == other.literal_value.blank_node
-            } else if data_type.is_decimal() {
+            } else if data_type.is_decimal() || data_type.is_duration() || data_type.is_gregorian()
+            {
                 self.literal_value.string == other.literal_value.string
             } else if data_type.is_date() {
                 self.literal_value.date == other.literal_value.date
             } else if data_type.is_date_time() {
                 self.literal_value.date_time == other.literal_value.date_time
+            } else if data_type.is_time() {
+                self.literal_value.time == other.literal_value.time
+            } else if data_type.is_binary() {
+                self.literal_value.binary == other.literal_value.binary
+            } else if data_type.is_custom() {
+                self.literal_value.custom == other.literal_value.custom
             } else {
                 panic!("Cannot compare, unimplemented datatype {data_type:?}")
             }
@@ -92,18 +116,28 @@ impl std::hash::Hash for Literal {
                 self.literal_value.blank_node.hash(state)
             } else if data_type.is_boolean() {
                 self.literal_value.boolean.hash(state)
+            } else if data_type.is_big_integer() {
+                self.literal_value.string.hash(state)
+            } else if data_type.is_floating_point() {
+                double_term_bits(self.literal_value.double).hash(state)
             } else if data_type.is_signed_integer() {
                 self.literal_value.signed_integer.hash(state)
             } else if data_type.is_unsigned_integer() {
                 self.literal_value.unsigned_integer.hash(state)
             } else if data_type.is_decimal() {
                 self.literal_value.string.hash(state)
-            } else if data_type.is_duration() {
+            } else if data_type.is_duration() || data_type.is_gregorian() {
                 self.literal_value.string.hash(state)
             } else if data_type.is_date() {
                 self.literal_value.date.hash(state)
             } else if data_type.is_date_time() {
                 self.literal_value.date_time.hash(state)
+            } else if data_type.is_time() {
+                self.literal_value.time.hash(state)
+            } else if data_type.is_binary() {
+                self.literal_value.binary.hash(state)
+            } else if data_type.is_custom() {
+                self.literal_value.custom.hash(state)
             } else {
                 panic!("Cannot hash, unimplemented datatype {data_type:?}")
             }
@@ -125,22 +159,27 @@ impl Debug for Literal {
                 write!(f, "_:{}", self.literal_value.blank_node.as_str())?
             } else if data_type.is_boolean() {
                 write!(f, "{}", self.literal_value.boolean)?
+            } else if data_type.is_big_integer() {
+                write!(f, "{}", self.literal_value.string.as_str())?
+            } else if data_type.is_floating_point() {
+                write!(f, "{}", self.lexical_form())?
             } else if data_type.is_signed_integer() {
                 write!(f, "{}", self.literal_value.signed_integer)?
             } else if data_type.is_unsigned_integer() {
                 write!(f, "{}", self.literal_value.unsigned_integer)?
             } else if data_type.is_decimal() {
                 write!(f, "{}", self.literal_value.string.as_str())?
-            } else if data_type.is_duration() || data_type.is_date_time_stamp() {
+            } else if data_type.is_duration() || data_type.is_gregorian() {
                 write!(f, "{}", self.literal_value.string.as_str())?
-            } else if data_type.is_date() {
-                write!(
-                    f,
-                    "{}",
-                    self.literal_value.date.format("%Y-%m-%d")
-                )?
-            } else if data_type.is_date_time() {
-                write!(f, "{:}", self.literal_value.date_time)?
+            } else if data_type.is_date() ||
+                data_type.is_date_time() ||
+                data_type.is_time() ||
+                data_type.is_binary()
+            {
+                write!(f, "{}", self.lexical_form())?
+            } else if data_type.is_custom() {
+                let (data_type_iri, lexical_form) = &*self.literal_value.custom;
+                write!(f, "<{data_type_iri}>,{lexical_form}")?
             } else {
                 panic!("Cannot format, unimplemented datatype {data_type:?}")
             }
@@ -163,10 +202,16 @@ impl Display for Literal {
             }
         } else if self.data_type.is_boolean() {
             write!(f, "{}", self.as_boolean().unwrap())
-        } else if self.data_type.is_date() {
-            write!(f, "{}", self.as_date().unwrap())
-        } else if self.data_type.is_date_time() {
-            write!(f, "{}", self.as_date_time().unwrap())
+        } else if self.data_type.is_date() ||
+            self.data_type.is_date_time() ||
+            self.data_type.is_time() ||
+            self.data_type.is_floating_point()
+        {
+            write!(f, "{}", self.lexical_form())
+        } else if self.data_type.is_binary() {
+            write!(f, "{} ({:?})", self.lexical_form(), self.data_type)
+        } else if self.data_type.is_custom() {
+            write!(f, "{} (<{}>)", self.lexical_form(), self.data_type_iri())
         } else if let Some(strng) = self.as_string() {
             write!(f, "{} ({:?})", strng.as_str(), self.data_type)
         } else {
@@ -210,16 +255,43 @@ impl Clone for Literal {
                 todo!("the situation where the boolean in a lexical value is not a boolean")
             }
         } else if self.data_type.is_date() {
-            if let Some(date) = self.as_date() {
-                Literal::new_date_with_datatype(date, self.data_type).unwrap()
-            } else {
-                todo!("the situation where the naive date in a lexical value is not a naive date")
+            Literal {
+                data_type:     self.data_type,
+                literal_value: LiteralValue { date: unsafe { self.literal_value.date } },
             }
         } else if self.data_type.is_date_time() {
-            if let Some(date_time) = self.as_date_time() {
-                Literal::new_date_time_with_datatype(*date_time, self.data_type).unwrap()
-            } else {
-                todo!("the situation where the boolean in a lexical value is not a boolean")
+            Literal {
+                data_type:     self.data_type,
+                literal_value: LiteralValue { date_time: unsafe { self.literal_value.date_time } },
+            }
+        } else if self.data_type.is_time() {
+            Literal {
+                data_type:     self.data_type,
+                literal_value: LiteralValue { time: unsafe { self.literal_value.time } },
+            }
+        } else if self.data_type.is_binary() {
+            Literal {
+                data_type:     self.data_type,
+                literal_value: LiteralValue {
+                    binary: unsafe { self.literal_value.binary.clone() },
+                },
+            }
+        } else if self.data_type.is_custom() {
+            Literal {
+                data_type:     self.data_type,
+                literal_value: LiteralValue {
+                    custom: unsafe { self.literal_value.custom.clone() },
+                },
+            }
+        } else if self.data_type.is_big_integer() {
+            Literal {
+                data_type:     self.data_type,
+                literal_value: LiteralValue::new_string(self.lexical_form().as_str()),
+            }
+        } else if self.data_type.is_floating_point() {
+            Literal {
+                data_type:     self.data_type,
+                literal_value: LiteralValue::new_double(unsafe { self.literal_value.double }),
             }
         } else if self.data_type.is_signed_integer() {
             if let Some(long) = self.as_signed_long() {
@@ -235,7 +307,10 @@ impl Clone for Literal {
             }
         } else if self.data_type.is_decimal() {
             if let Some(decimal) = self.as_decimal() {
-                Literal::new_decimal_with_datatype(decimal, self.data_type).unwrap()
+                Literal {
+                    data_type:     self.data_type,
+                    literal_value: LiteralValue::new_string(decimal),
+                }
             } else {
                 todo!("the situation where the decimal value is not a decimal")
             }
@@ -245,6 +320,13 @@ impl Clone for Literal {
             } else {
                 todo!("the situation where the duration value is not a duration")
             }
+        } else if self.data_type.is_gregorian() {
+            Literal {
+                data_type:     self.data_type,
+                literal_value: LiteralValue {
+                    string: unsafe { self.literal_value.string.clone() },
+                },
+            }
         } else {
             todo!(
                 "dealing with other datatypes: {:?}",
@@ -268,18 +350,32 @@ impl Serialize for Literal {
                 serializer.serialize_str(self.literal_value.blank_node.as_str())
             } else if data_type.is_boolean() {
                 serializer.serialize_bool(self.literal_value.boolean)
+            } else if data_type.is_big_integer() {
+                match (self.as_signed_long(), self.as_unsigned_long()) {
+                    (Some(long), _) => serializer.serialize_i64(long),
+                    (None, Some(long)) => serializer.serialize_u64(long),
+                    (None, None) => serializer.serialize_str(self.literal_value.string.as_str()),
+                }
+            } else if data_type.is_floating_point() {
+                match self.literal_value.double {
+                    double if double.is_finite() => serializer.serialize_f64(double),
+                    _ => serializer.serialize_str(self.lexical_form().as_str()),
+                }
             } else if data_type.is_signed_integer() {
                 serializer.serialize_i64(self.literal_value.signed_integer)
             } else if data_type.is_unsigned_integer() {
                 serializer.serialize_u64(self.literal_value.unsigned_integer)
             } else if data_type.is_decimal() {
                 serializer.serialize_str(self.literal_value.string.as_str())
-            } else if data_type.is_duration() || data_type.is_date_time_stamp() {
+            } else if data_type.is_duration() || data_type.is_gregorian() {
                 serializer.serialize_str(self.literal_value.string.as_str())
-            } else if data_type.is_date() {
-                serializer.serialize_str(self.literal_value.date.to_string().as_str())
-            } else if data_type.is_date_time() {
-                serializer.serialize_str(self.literal_value.date_time.to_string().as_str())
+            } else if data_type.is_date() ||
+                data_type.is_date_time() ||
+                data_type.is_time() ||
+                data_type.is_binary() ||
+                data_type.is_custom()
+            {
+                serializer.serialize_str(self.lexical_form().as_str())
             } else {
                 panic!("Cannot serialize, unimplemented datatype {data_type:?}")
             }
@@ -361,6 +457,8 @@ impl Literal {
             None
         } else if self.data_type.is_unsigned_integer() {
             None
+        } else if self.data_type.is_floating_point() {
+            None
         } else if self.data_type.is_blank_node() {
             unsafe { Some(self.literal_value.blank_node.as_str()) }
         } else if self.data_type.is_boolean() {
@@ -373,10 +471,16 @@ impl Literal {
             }
         } else if self.data_type.is_decimal() {
             unsafe { Some(self.literal_value.string.as_str()) }
-        } else if self.data_type.is_duration() {
-            unsafe { Some(self.literal_value.string.as_str()) }
-        } else if self.data_type.is_date_time() {
+        } else if self.data_type.is_duration() || self.data_type.is_gregorian() {
             unsafe { Some(self.literal_value.string.as_str()) }
+        } else if self.data_type.is_custom() {
+            unsafe { Some(self.literal_value.custom.1.as_str()) }
+        } else if self.data_type.is_date() ||
+            self.data_type.is_date_time() ||
+            self.data_type.is_time() ||
+            self.data_type.is_binary()
+        {
+            None
         } else {
             panic!("Data type {:?} not yet supported", self.data_type);
         }
@@ -392,7 +496,9 @@ impl Literal {
     }
 
     pub fn as_signed_long(&self) -> Option<i64> {
-        if self.data_type.is_signed_integer() {
+        if self.data_type.is_big_integer() {
+            unsafe { self.literal_value.string.parse().ok() }
+        } else if self.data_type.is_signed_integer() {
             Some(unsafe { self.literal_value.signed_integer })
         } else {
             None
@@ -400,25 +506,75 @@ impl Literal {
     }
 
     pub fn as_unsigned_long(&self) -> Option<u64> {
-        if self.data_type.is_unsigned_integer() {
+        if self.data_type.is_big_integer() {
+            unsafe { self.literal_value.string.parse().ok() }
+        } else if self.data_type.is_unsigned_integer() {
             Some(unsafe { self.literal_value.unsigned_integer })
         } else {
             None
         }
     }
 
+    /// The value of a literal of any integer datatype, without the limits
+    /// of [`Literal::as_signed_long`] and [`Literal::as_unsigned_long`].
+    pub fn as_big_integer(&self) -> Option<BigInt> {
+        if self.data_type.is_big_integer() {
+            unsafe { self.literal_value.string.parse().ok() }
+        } else if let Some(long) = self.as_signed_long() {
+            Some(BigInt::from(long))
+        } else {
+            self.as_unsigned_long().map(BigInt::from)
+        }
+    }
+
     pub fn as_date(&self) -> Option<chrono::NaiveDate> {
         match self.data_type {
-            DataType::Date => Some(unsafe { *self.literal_value.date }),
-            DataType::DateTime => self.as_date_time().map(|dt| dt.naive_utc().date()),
-            _ => None,
+            DataType::Date => Some(unsafe { self.literal_value.date.0 }),
+            _ => self.as_naive_date_time().map(|date_time| date_time.date()),
         }
     }
 
-    pub fn as_date_time(&self) -> Option<&chrono::DateTime<chrono::Utc>> {
-        match self.data_type {
-            DataType::DateTime => Some(unsafe { &self.literal_value.date_time }),
-            _ => None,
+    /// The point in time of an `xsd:dateTime` or `xsd:dateTimeStamp`, where
+    /// a date time without a timezone is taken to be in UTC.
+    pub fn as_date_time(&self) -> Option<chrono::DateTime<chrono::Utc>> {
+        let date_time = self.as_naive_date_time()?;
+        let utc = match self.timezone_offset() {
+            Some(offset) => date_time - chrono::Duration::seconds(offset.local_minus_utc().into()),
+            None => date_time,
+        };
+        Some(chrono::DateTime::from_naive_utc_and_offset(utc, chrono::Utc))
+    }
+
+    /// The local date and time of an `xsd:dateTime` or `xsd:dateTimeStamp`
+    /// as written, without its timezone.
+    pub fn as_naive_date_time(&self) -> Option<chrono::NaiveDateTime> {
+        if self.data_type.is_date_time() {
+            Some(unsafe { self.literal_value.date_time.0 })
+        } else {
+            None
+        }
+    }
+
+    /// The local time of an `xsd:time` as written, without its timezone.
+    pub fn as_time(&self) -> Option<chrono::NaiveTime> {
+        if self.data_type.is_time() {
+            Some(unsafe { self.literal_value.time.0 })
+        } else {
+            None
+        }
+    }
+
+    /// The timezone offset of a date, date time or time, or `None` if it has
+    /// no timezone (or is not a date, date time or time).
+    pub fn timezone_offset(&self) -> Option<chrono::FixedOffset> {
+        if self.data_type.is_date() {
+            unsafe { self.literal_value.date.1 }
+        } else if self.data_type.is_date_time() {
+            unsafe { self.literal_value.date_time.1 }
+        } else if self.data_type.is_time() {
+            unsafe { self.literal_value.time.1 }
+        } else {
+            None
         }
     }
 
@@ -429,10 +585,54 @@ impl Literal {
         }
     }
 
+    /// The value of an `xsd:double` or `xsd:float`.
+    pub fn as_double(&self) -> Option<f64> {
+        if self.data_type.is_floating_point() {
+            Some(unsafe { self.literal_value.double })
+        } else {
+            None
+        }
+    }
+
+    /// The canonical lexical form of a duration, see
+    /// [`Literal::as_xsd_duration`] for its value.
     pub fn as_duration(&self) -> Option<&str> {
-        match self.data_type {
-            DataType::Duration => Some(unsafe { &self.literal_value.string }),
-            _ => None,
+        if self.data_type.is_duration() {
+            Some(unsafe { &self.literal_value.string })
+        } else {
+            None
+        }
+    }
+
+    /// The IRI of the datatype of this literal, such as
+    /// `http://www.w3.org/2001/XMLSchema#integer`, or the IRI of a
+    /// [`DataType::Custom`] such as
+    /// `http://www.opengis.net/ont/geosparql#wktLiteral`.
+    pub fn data_type_iri(&self) -> &str {
+        if self.data_type.is_custom() {
+            unsafe { self.literal_value.custom.0.as_str() }
+        } else {
+            self.data_type.as_xsd_iri_str()
+        }
+    }
+
+    /// The bytes of an `xsd:hexBinary` or an `xsd:base64Binary`.
+    pub fn as_binary(&self) -> Option<&[u8]> {
+        if self.data_type.is_binary() {
+            Some(unsafe { self.literal_value.binary.as_slice() })
+        } else {
+            None
+        }
+    }
+
+    /// The canonical lexical form of an `xsd:gYear`, `xsd:gYearMonth`,
+    /// `xsd:gMonthDay`, `xsd:gDay` or `xsd:gMonth`, see
+    /// [`Literal::as_partial_date`] for its value.
+    pub fn as_gregorian(&self) -> Option<&str> {
+        if self.data_type.is_gregorian() {
+            Some(unsafe { &self.literal_value.string })
+        } else {
+            None
         }
     }
 
@@ -529,40 +729,37 @@ impl Literal {
                 )?))
             },
             DataType::Date | DataType::DateTime => Self::date_from_str(buffer),
-            DataType::Int |
-            DataType::Integer |
-            DataType::NegativeInteger |
-            DataType::NonPositiveInteger |
-            DataType::Long |
-            DataType::Short => {
-                let signed_integer: i64 = buffer.parse()?; // TODO: Remove unwrap
-                Ok(Some(Literal::new_signed_integer_with_datatype(
-                    signed_integer,
-                    data_type,
-                )?))
+            DataType::DateTimeStamp => Self::date_time_from_str(buffer, data_type).map(Some),
+            DataType::Time => {
+                match chrono::NaiveTime::parse_from_str(buffer, "%H:%M") {
+                    Ok(time) => Literal::new_time_with_datatype(time, None, data_type).map(Some),
+                    Err(_) => Self::time_from_str(buffer).map(Some),
+                }
             },
-            DataType::PositiveInteger |
-            DataType::NonNegativeInteger |
-            DataType::UnsignedByte |
-            DataType::UnsignedInt |
-            DataType::UnsignedShort |
-            DataType::UnsignedLong => {
-                let unsigned_integer: u64 = buffer.parse().unwrap(); // TODO: Remove unwrap
-                Ok(Some(Literal::new_unsigned_integer_with_datatype(
-                    unsigned_integer,
-                    data_type,
-                )?))
+            data_type if data_type.is_integer() => {
+                Self::integer_from_str(buffer, data_type).map(Some)
+            },
+            DataType::Float | DataType::Double => {
+                Self::double_from_str(buffer, data_type).map(Some)
             },
             DataType::Decimal => {
                 Ok(Some(Literal::new_decimal_with_datatype(
                     buffer, data_type,
                 )?))
             },
-            DataType::Duration => {
+            data_type if data_type.is_duration() => {
                 Ok(Some(Literal::new_duration_with_datatype(
                     buffer, data_type,
                 )?))
             },
+            data_type if data_type.is_gregorian() => {
+                Ok(Some(Literal::new_gregorian_with_datatype(
+                    buffer, data_type,
+                )?))
+            },
+            data_type if data_type.is_binary() => {
+                Ok(Some(Literal::binary_from_str(buffer, data_type)?))
+            },
             DataType::UnboundValue => Ok(None),
             _ => {
                 tracing::warn!(
@@ -574,42 +771,102 @@ impl Literal {
         }
     }
 
+    /// Parse an integer of the given datatype, checking that it is a valid
+    /// lexical form (an optional sign and decimal digits) of a value within
+    /// the range of the datatype, so that `"300"^^xsd:byte` is rejected.
+    pub(crate) fn integer_from_str(
+        buffer: &str,
+        data_type: DataType,
+    ) -> Result<Literal, RDFStoreError> {
+        let invalid_value = || {
+            RDFStoreError::UnknownValueForDataType {
+                data_type,
+                value: buffer.to_string(),
+            }
+        };
+        let lexical_form = buffer.trim();
+        let digits = lexical_form
+            .strip_prefix(|c| c == '+' || c == '-')
+            .unwrap_or(lexical_form);
+        if digits.is_empty() || !digits.bytes().all(|byte| byte.is_ascii_digit()) {
+            return Err(invalid_value())
+        }
+        let integer: BigInt = lexical_form.parse().map_err(|_| invalid_value())?;
+        Literal::new_integer_with_datatype(&integer, data_type)?.ok_or_else(invalid_value)
+    }
+
+    /// Create an integer literal of the given datatype, or `None` if the
+    /// value is out of the range of that datatype.
+    pub(crate) fn new_integer_with_datatype(
+        integer: &BigInt,
+        data_type: DataType,
+    ) -> Result<Option<Literal>, RDFStoreError> {
+        let in_range = match data_type.integer_range() {
+            Some((min, max)) => {
+                min.map_or(true, |min| &min <= integer) && max.map_or(true, |max| integer <= &max)
+            },
+            None => false,
+        };
+        if !in_range {
+            return Ok(None)
+        }
+        if data_type.is_big_integer() {
+            Ok(Some(Literal {
+                data_type,
+                literal_value: LiteralValue::new_string(integer.to_string().as_str()),
+            }))
+        } else if data_type.is_signed_integer() {
+            Literal::new_signed_integer_with_datatype(i64::try_from(integer).unwrap(), data_type)
+                .map(Some)
+        } else {
+            Literal::new_unsigned_integer_with_datatype(u64::try_from(integer).unwrap(), data_type)
+                .map(Some)
+        }
+    }
+
+    /// Guess the format of a date or date time: an XSD date time or date,
+    /// RFC 2822 (`Wed, 18 Feb 2015 23:16:09 EST`), RFC 3339, or one of a
+    /// few other common notations such as `2023-12-31 13:21` or
+    /// `12/31/2023`. Timezone offsets are kept, a date time without a
+    /// timezone has none.
     fn date_from_str(buffer: &str) -> Result<Option<Literal>, RDFStoreError> {
+        if let Ok(date_time) = Literal::date_time_from_str(buffer, DataType::DateTime) {
+            return Ok(Some(date_time))
+        }
         if let Ok(date_time) = chrono::DateTime::parse_from_rfc2822(buffer) {
-            return Ok(Some(Literal::new_date_time_with_datatype(
-                chrono::DateTime::from(date_time),
+            return Ok(Some(Literal::new_date_time_with_offset(
+                date_time,
                 DataType::DateTime,
             )?))
         }
         if let Ok(date_time) = chrono::DateTime::parse_from_rfc3339(buffer) {
-            return Ok(Some(Literal::new_date_time_with_datatype(
-                chrono::DateTime::from(date_time),
+            return Ok(Some(Literal::new_date_time_with_offset(
+                date_time,
                 DataType::DateTime,
             )?))
         }
         if let Ok(date_time) = chrono::DateTime::parse_from_str(buffer, "%Y-%m-%d %H:%M:%S %z") {
-            return Ok(Some(Literal::new_date_time_with_datatype(
-                chrono::DateTime::from(date_time),
+            return Ok(Some(Literal::new_date_time_with_offset(
+                date_time,
                 DataType::DateTime,
             )?))
         }
         if let Ok(date_time) = chrono::NaiveDateTime::parse_from_str(buffer, "%Y-%m-%d %H:%M:%S") {
-            return Ok(Some(Literal::new_date_time_with_datatype(
-                chrono::DateTime::from_naive_utc_and_offset(date_time, chrono::Utc),
+            return Ok(Some(Literal::new_local_date_time_with_datatype(
+                date_time,
+                None,
                 DataType::DateTime,
             )?))
         }
         if let Ok(date_time) = chrono::NaiveDateTime::parse_from_str(buffer, "%Y-%m-%d %H:%M") {
-            return Ok(Some(Literal::new_date_time_with_datatype(
-                chrono::DateTime::from_naive_utc_and_offset(date_time, chrono::Utc),
+            return Ok(Some(Literal::new_local_date_time_with_datatype(
+                date_time,
+                None,
                 DataType::DateTime,
             )?))
         }
-        if let Ok(date) = chrono::NaiveDate::parse_from_str(buffer, "%Y-%m-%d") {
-            return Ok(Some(Literal::new_date_with_datatype(
-                date,
-                DataType::Date,
-            )?))
+        if let Ok(date) = Literal::xsd_date_from_str(buffer) {
+            return Ok(Some(date))
         }
         if let Ok(date) = chrono::NaiveDate::parse_from_str(buffer, "%Y/%m/%d") {
             return Ok(Some(Literal::new_date_with_datatype(
@@ -678,6 +935,15 @@ impl Literal {
     pub fn new_date_with_datatype(
         date: chrono::NaiveDate,
         data_type: DataType,
+    ) -> Result<Self, RDFStoreError> {
+        Self::new_local_date_with_datatype(date, None, data_type)
+    }
+
+    /// A date with its timezone offset, if any.
+    pub fn new_local_date_with_datatype(
+        date: chrono::NaiveDate,
+        offset: Option<chrono::FixedOffset>,
+        data_type: DataType,
     ) -> Result<Self, RDFStoreError> {
         assert!(
             &data_type.is_date(),
@@ -685,21 +951,67 @@ impl Literal {
         );
         Ok(Literal {
             data_type,
-            literal_value: LiteralValue::new_date(date),
+            literal_value: LiteralValue::new_date(date, offset),
         })
     }
 
+    /// A date time in UTC, written with the `Z` timezone.
     pub fn new_date_time_with_datatype(
         date_time: chrono::DateTime<chrono::Utc>,
         data_type: DataType,
+    ) -> Result<Self, RDFStoreError> {
+        Self::new_local_date_time_with_datatype(
+            date_time.naive_utc(),
+            chrono::FixedOffset::east_opt(0),
+            data_type,
+        )
+    }
+
+    /// A date time that keeps the timezone offset of the given date time.
+    pub fn new_date_time_with_offset(
+        date_time: chrono::DateTime<chrono::FixedOffset>,
+        data_type: DataType,
+    ) -> Result<Self, RDFStoreError> {
+        Self::new_local_date_time_with_datatype(
+            date_time.naive_local(),
+            Some(*date_time.offset()),
+            data_type,
+        )
+    }
+
+    /// A date time from its local date and time and its timezone offset, if
+    /// any. An `xsd:dateTimeStamp` must have a timezone offset.
+    pub fn new_local_date_time_with_datatype(
+        date_time: chrono::NaiveDateTime,
+        offset: Option<chrono::FixedOffset>,
+        data_type: DataType,
     ) -> Result<Self, RDFStoreError> {
         assert!(
             &data_type.is_date_time(),
             "{data_type:?} is not a dateTime"
         );
+        if data_type.is_date_time_stamp() && offset.is_none() {
+            return Err(RDFStoreError::UnknownValueForDataType {
+                data_type,
+                value: canonical_date_time(&date_time, offset),
+            })
+        }
         Ok(Literal {
             data_type,
-            literal_value: LiteralValue::new_date_time(date_time),
+            literal_value: LiteralValue::new_date_time(date_time, offset),
+        })
+    }
+
+    /// A time of day with its timezone offset, if any.
+    pub fn new_time_with_datatype(
+        time: chrono::NaiveTime,
+        offset: Option<chrono::FixedOffset>,
+        data_type: DataType,
+    ) -> Result<Self, RDFStoreError> {
+        assert!(&data_type.is_time(), "{data_type:?} is not a time");
+        Ok(Literal {
+            data_type,
+            literal_value: LiteralValue::new_time(time, offset),
         })
     }
 
@@ -711,12 +1023,50 @@ impl Literal {
             &data_type.is_decimal(),
             "{data_type:?} is not a decimal"
         );
+        Literal::decimal_from_str(str)
+    }
+
+    /// Create an `xsd:double`.
+    pub fn new_double(double: f64) -> Result<Self, RDFStoreError> {
+        Literal::new_double_with_datatype(double, DataType::Double)
+    }
+
+    /// Create an `xsd:float`.
+    pub fn new_float(float: f32) -> Result<Self, RDFStoreError> {
+        Literal::new_double_with_datatype(f64::from(float), DataType::Float)
+    }
+
+    /// Create an `xsd:double` or an `xsd:float`, in which case the value is
+    /// rounded to the nearest `f32`.
+    pub fn new_double_with_datatype(
+        double: f64,
+        data_type: DataType,
+    ) -> Result<Self, RDFStoreError> {
+        assert!(
+            &data_type.is_floating_point(),
+            "{data_type:?} is not a float or double"
+        );
+        let double = if data_type == DataType::Float {
+            f64::from(double as f32)
+        } else {
+            double
+        };
         Ok(Literal {
             data_type,
-            literal_value: LiteralValue::new_string(str),
+            literal_value: LiteralValue::new_double(double),
+        })
+    }
+
+    /// Create an `xsd:decimal`.
+    pub fn new_decimal(decimal: &BigDecimal) -> Result<Self, RDFStoreError> {
+        Ok(Literal {
+            data_type:     DataType::Decimal,
+            literal_value: LiteralValue::new_string(canonical_decimal(decimal).as_str()),
         })
     }
 
+    /// A duration such as `P1Y2M3DT4H5M6.7S`, which is kept in its
+    /// canonical lexical form.
     pub fn new_duration_with_datatype(
         str: &str,
         data_type: DataType,
@@ -725,9 +1075,52 @@ impl Literal {
             &data_type.is_duration(),
             "{data_type:?} is not a duration"
         );
+        let duration = XsdDuration::parse(str.trim(), data_type).ok_or_else(|| {
+            RDFStoreError::UnknownValueForDataType { data_type, value: str.to_string() }
+        })?;
         Ok(Literal {
             data_type,
-            literal_value: LiteralValue::new_string(str),
+            literal_value: LiteralValue::new_string(duration.lexical_form(data_type).as_str()),
+        })
+    }
+
+    /// An `xsd:hexBinary` or an `xsd:base64Binary` holding the given bytes.
+    pub fn new_binary_with_datatype(
+        binary: &[u8],
+        data_type: DataType,
+    ) -> Result<Self, RDFStoreError> {
+        assert!(
+            &data_type.is_binary(),
+            "{data_type:?} is not a binary datatype"
+        );
+        Ok(Literal { data_type, literal_value: LiteralValue::new_binary(binary) })
+    }
+
+    /// A literal of a datatype that is not built in, see
+    /// [`Literal::new_custom`] for the one that validates the lexical form.
+    pub(crate) fn new_custom_with_datatype_iri(data_type_iri: &str, lexical_form: &str) -> Self {
+        Literal {
+            data_type:     DataType::Custom,
+            literal_value: LiteralValue::new_custom(data_type_iri, lexical_form),
+        }
+    }
+
+    /// A Gregorian partial date such as `2023-05` for an `xsd:gYearMonth`,
+    /// which is kept in its canonical lexical form.
+    pub fn new_gregorian_with_datatype(
+        str: &str,
+        data_type: DataType,
+    ) -> Result<Self, RDFStoreError> {
+        assert!(
+            &data_type.is_gregorian(),
+            "{data_type:?} is not a Gregorian partial date"
+        );
+        let date = XsdPartialDate::parse(str.trim(), data_type).ok_or_else(|| {
+            RDFStoreError::UnknownValueForDataType { data_type, value: str.to_string() }
+        })?;
+        Ok(Literal {
+            data_type,
+            literal_value: LiteralValue::new_string(date.lexical_form().as_str()),
         })
     }
 
@@ -837,12 +1230,26 @@ impl Literal {
             &data_type.is_signed_integer(),
             "{data_type:?} is not an signed integer type"
         );
+        if data_type.is_big_integer() {
+            return Ok(Literal {
+                data_type,
+                literal_value: LiteralValue::new_string(signed_integer.to_string().as_str()),
+            })
+        }
         Ok(Literal {
             data_type,
             literal_value: LiteralValue::new_signed_integer(signed_integer),
         })
     }
 
+    /// Create an `xsd:integer` of arbitrary precision.
+    pub fn new_big_integer(integer: &BigInt) -> Result<Self, RDFStoreError> {
+        Ok(Literal {
+            data_type:     DataType::Integer,
+            literal_value: LiteralValue::new_string(integer.to_string().as_str()),
+        })
+    }
+
     pub fn new_unsigned_integer(unsigned_integer: u64) -> Result<Self, RDFStoreError> {
         Self::new_unsigned_integer_with_datatype(unsigned_integer, DataType::PositiveInteger)
     }
@@ -855,6 +1262,12 @@ impl Literal {
             &data_type.is_unsigned_integer(),
             "{data_type:?} is not an unsigned integer type"
         );
+        if data_type.is_big_integer() {
+            return Ok(Literal {
+                data_type,
+                literal_value: LiteralValue::new_string(unsigned_integer.to_string().as_str()),
+            })
+        }
         Ok(Literal {
             data_type,
             literal_value: LiteralValue::new_unsigned_integer(unsigned_integer),
@@ -880,23 +1293,65 @@ impl Literal {
                         )?
                     } else if data_type.is_boolean() {
                         write!(f, "{}", self.0.literal_value.boolean)?
+                    } else if data_type.is_big_integer() {
+                        write!(f, "{}", self.0.literal_value.string.as_str())?
+                    } else if data_type == DataType::Double &&
+                        self.0.literal_value.double.is_finite()
+                    {
+                        write!(f, "{}", self.0.lexical_form())?
+                    } else if data_type.is_floating_point() {
+                        write!(
+                            f,
+                            "\"{}\"^^xsd:{}",
+                            self.0.lexical_form(),
+                            if data_type == DataType::Float { "float" } else { "double" }
+                        )?
                     } else if data_type.is_signed_integer() {
                         write!(f, "{}", self.0.literal_value.signed_integer)?
                     } else if data_type.is_unsigned_integer() {
                         write!(f, "{}", self.0.literal_value.unsigned_integer)?
-                    } else if data_type.is_date_time() {
+                    } else if data_type.is_date() ||
+                        data_type.is_date_time() ||
+                        data_type.is_time()
+                    {
                         write!(
                             f,
-                            "\"{}\"^^xsd:dateTime",
-                            self.0.literal_value.string.as_str()
+                            "\"{}\"^^xsd:{}",
+                            self.0.lexical_form(),
+                            match data_type {
+                                DataType::Date => "date",
+                                DataType::DateTimeStamp => "dateTimeStamp",
+                                DataType::Time => "time",
+                                _ => "dateTime",
+                            }
                         )?
                     } else if data_type.is_decimal() {
                         write!(f, "{}", self.0.literal_value.string.as_str())?
                     } else if data_type.is_duration() {
                         write!(
                             f,
-                            "\"{}\"^^xsd:duration",
-                            self.0.literal_value.string.as_str()
+                            "\"{}\"^^xsd:{}",
+                            self.0.literal_value.string.as_str(),
+                            match data_type {
+                                DataType::YearMonthDuration => "yearMonthDuration",
+                                DataType::DayTimeDuration => "dayTimeDuration",
+                                _ => "duration",
+                            }
+                        )?
+                    } else if data_type.is_gregorian() || data_type.is_binary() {
+                        let iri = data_type.as_xsd_iri_str();
+                        write!(
+                            f,
+                            "\"{}\"^^xsd:{}",
+                            self.0.lexical_form(),
+                            &iri[iri.rfind('#').map_or(0, |hash| hash + 1)..]
+                        )?
+                    } else if data_type.is_custom() {
+                        let (data_type_iri, lexical_form) = &*self.0.literal_value.custom;
+                        write!(
+                            f,
+                            "\"{}\"^^<{data_type_iri}>",
+                            escape_string(lexical_form)
                         )?
                     } else {
                         panic!("Cannot format for turtle, unimplemented datatype {data_type:?}")
@@ -931,15 +1386,28 @@ impl Literal {
                         )?
                     } else if data_type.is_boolean() {
                         write!(f, "{}", self.0.literal_value.boolean)?
+                    } else if data_type.is_big_integer() {
+                        write!(f, "{}", self.0.literal_value.string.as_str())?
+                    } else if data_type.is_floating_point() {
+                        if self.0.literal_value.double.is_finite() {
+                            write!(f, "{}", self.0.lexical_form())?
+                        } else {
+                            write!(f, "\"{}\"", self.0.lexical_form())?
+                        }
                     } else if data_type.is_signed_integer() {
                         write!(f, "{}", self.0.literal_value.signed_integer)?
                     } else if data_type.is_unsigned_integer() {
                         write!(f, "{}", self.0.literal_value.unsigned_integer)?
-                    } else if data_type.is_date_time() {
-                        write!(f, "\"{}\"", self.0.literal_value.string.as_str())?
+                    } else if data_type.is_date() ||
+                        data_type.is_date_time() ||
+                        data_type.is_time() ||
+                        data_type.is_binary() ||
+                        data_type.is_custom()
+                    {
+                        write!(f, "\"{}\"", self.0.lexical_form())?
                     } else if data_type.is_decimal() {
                         write!(f, "{}", self.0.literal_value.string.as_str())?
-                    } else if data_type.is_duration() {
+                    } else if data_type.is_duration() || data_type.is_gregorian() {
                         write!(f, "\"{}\"", self.0.literal_value.string.as_str())?
                     } else {
                         panic!("Cannot format for JSON, unimplemented datatype {data_type:?}")
@@ -957,6 +1425,72 @@ impl Literal {
         LiteralIdUrlDisplay { literal: self, id_base_iri }
     }
 
+    /// Display this literal as a SPARQL term, for instance to bind it to a
+    /// parameter of a [`SparqlTemplate`](crate::sparql::SparqlTemplate).
+    pub fn as_sparql_display(&self) -> LiteralSparqlDisplay<'_> {
+        LiteralSparqlDisplay { literal: self }
+    }
+
+    /// Return the lexical form of this literal, i.e. its value without any
+    /// quotes, angle brackets or datatype suffix.
+    /// An unbound value (and `rdfs:Literal`, which no literal is created
+    /// with) has an empty lexical form.
+    pub fn lexical_form(&self) -> String {
+        let data_type = self.data_type;
+        unsafe {
+            match data_type {
+                DataType::IriReference | DataType::AnyUri => {
+                    self.literal_value.iri.as_str().to_string()
+                },
+                DataType::String | DataType::PlainLiteral => self.literal_value.string.to_string(),
+                DataType::BlankNode => self.literal_value.blank_node.to_string(),
+                DataType::Boolean => self.literal_value.boolean.to_string(),
+                DataType::Integer |
+                DataType::NonPositiveInteger |
+                DataType::NegativeInteger |
+                DataType::NonNegativeInteger |
+                DataType::PositiveInteger |
+                DataType::Decimal => self.literal_value.string.to_string(),
+                DataType::Float | DataType::Double => {
+                    canonical_double(self.literal_value.double, data_type)
+                },
+                DataType::Long |
+                DataType::Int |
+                DataType::Short |
+                DataType::Byte => self.literal_value.signed_integer.to_string(),
+                DataType::UnsignedLong |
+                DataType::UnsignedInt |
+                DataType::UnsignedShort |
+                DataType::UnsignedByte => self.literal_value.unsigned_integer.to_string(),
+                DataType::Duration |
+                DataType::YearMonthDuration |
+                DataType::DayTimeDuration |
+                DataType::YearMonth |
+                DataType::Year |
+                DataType::MonthDay |
+                DataType::Day |
+                DataType::Month => self.literal_value.string.to_string(),
+                DataType::Date => {
+                    let (date, offset) = self.literal_value.date;
+                    canonical_date(&date, offset)
+                },
+                DataType::DateTime | DataType::DateTimeStamp => {
+                    let (date_time, offset) = self.literal_value.date_time;
+                    canonical_date_time(&date_time, offset)
+                },
+                DataType::Time => {
+                    let (time, offset) = self.literal_value.time;
+                    canonical_time(&time, offset)
+                },
+                DataType::HexBinary | DataType::Base64Binary => {
+                    canonical_binary(self.literal_value.binary.as_slice(), data_type)
+                },
+                DataType::Custom => self.literal_value.custom.1.clone(),
+                DataType::UnboundValue | DataType::Literal => String::new(),
+            }
+        }
+    }
+
     /// Is the given Literal an IRI whose base is the given IRI?
     pub fn is_id_iri(&self, id_base_iri: &IriBuf) -> bool {
         match self.data_type {
@@ -15,9 +15,15 @@ pub union LiteralValue {
     pub boolean:          bool,
     pub unsigned_integer: u64,
     pub signed_integer:   i64,
-    pub date:             ManuallyDrop<chrono::NaiveDate>,
-    pub date_time:        chrono::DateTime<chrono::Utc>,
+    pub double:           f64,
+    pub date:             (chrono::NaiveDate, Option<chrono::FixedOffset>),
+    pub date_time:        (chrono::NaiveDateTime, Option<chrono::FixedOffset>),
+    pub time:             (chrono::NaiveTime, Option<chrono::FixedOffset>),
     pub blank_node:       ManuallyDrop<String>,
+    pub binary:           ManuallyDrop<Vec<u8>>,
+    /// The datatype IRI and the lexical form of a literal of a
+    /// [`DataType::Custom`](crate::DataType::Custom).
+    pub custom:           ManuallyDrop<(String, String)>,
 }
 
 impl Default for LiteralValue {
@@ -35,9 +41,30 @@ impl LiteralValue {
 
     pub fn new_signed_integer(signed_integer: i64) -> Self { Self { signed_integer } }
 
-    pub fn new_date(date: chrono::NaiveDate) -> Self { Self { date: ManuallyDrop::new(date) } }
+    pub fn new_double(double: f64) -> Self { Self { double } }
 
-    pub fn new_date_time(date_time: chrono::DateTime<chrono::Utc>) -> Self { Self { date_time } }
+    pub fn new_date(date: chrono::NaiveDate, offset: Option<chrono::FixedOffset>) -> Self {
+        Self { date: (date, offset) }
+    }
+
+    pub fn new_date_time(
+        date_time: chrono::NaiveDateTime,
+        offset: Option<chrono::FixedOffset>,
+    ) -> Self {
+        Self { date_time: (date_time, offset) }
+    }
+
+    pub fn new_time(time: chrono::NaiveTime, offset: Option<chrono::FixedOffset>) -> Self {
+        Self { time: (time, offset) }
+    }
+
+    pub fn new_binary(binary: &[u8]) -> Self { Self { binary: ManuallyDrop::new(binary.to_vec()) } }
+
+    pub fn new_custom(data_type_iri: &str, lexical_form: &str) -> Self {
+        Self {
+            custom: ManuallyDrop::new((data_type_iri.to_string(), lexical_form.to_string())),
+        }
+    }
 
     pub fn new_blank_node(blank_node: &str) -> Self {
         Self {
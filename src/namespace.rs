@@ -1,8 +1,10 @@
-use std::str::FromStr;
 // Copyright (c) 2018-2023, agnos.ai UK Ltd, all rights reserved.
 //---------------------------------------------------------------
-use iref::{Iri, IriBuf};
-use crate::RDFStoreError;
+use iref::{InvalidIri, Iri, IriBuf};
+use crate::{
+    prefixed_iri::{is_pn_chars, is_pn_chars_base},
+    RDFStoreError,
+};
 
 /// A `Namespace` represents a namespace IRI that can also be shown
 /// in abbreviated format, also known as "prefix".
@@ -14,7 +16,7 @@ use crate::RDFStoreError;
 /// like <http://www.w3.org/1999/02/22-rdf-syntax-ns#type> or like `rdf:type`.
 #[derive(Debug, PartialEq, Eq, Clone)]
 pub struct Namespace {
-    /// assumed to end with ':'
+    /// assumed to end with ':', see [`Namespace::try_declare`]
     pub name: String,
     /// assumed to end with either '/' or '#'
     pub iri:  IriBuf,
@@ -32,36 +34,73 @@ impl std::fmt::Display for Namespace {
 }
 
 impl Namespace {
-    pub fn declare(name: &str, iri: &Iri) -> Self {
-        match iri.as_str().chars().last() {
-            Some('/') | Some('#') => Self { name: name.to_string(), iri: IriBuf::from_str(iri.as_str()).unwrap() },
-            _ => {
-                Self {
-                    name: name.to_string(),
-                    iri:  IriBuf::new(format!("{}/", iri)).unwrap(),
-                }
-            },
+    /// Declare a namespace with a prefix name such as `rdf:`, which has to
+    /// be a `PN_PREFIX` (or nothing) followed by a `:`, and an IRI that has
+    /// to end with either '/' or '#'.
+    /// Returns an [`RDFStoreError::InvalidPrefixName`] or an
+    /// [`RDFStoreError::InvalidIri`] otherwise.
+    pub fn try_declare(name: &str, iri: &Iri) -> Result<Self, RDFStoreError> {
+        Self::try_declare_from_str(name, iri.as_str())
+    }
+
+    /// Like [`Namespace::try_declare`], with an IRI that still has to be
+    /// checked, which returns an [`RDFStoreError::InvalidIri`] if it is not
+    /// valid.
+    pub fn try_declare_from_str(name: &str, iri: &str) -> Result<Self, RDFStoreError> {
+        if !is_prefix_name(name) {
+            return Err(RDFStoreError::InvalidPrefixName { name: name.to_string() })
+        }
+        if !iri.ends_with(['/', '#']) {
+            return Err(RDFStoreError::InvalidIri(InvalidIri(iri.to_string())))
         }
+        Self::new(name, iri)
+    }
+
+    /// Declare a namespace without checking the prefix name.
+    /// Panics if the IRI (with a '/' added if it does not end with either
+    /// '/' or '#') is not valid.
+    pub fn declare_unchecked(name: &str, iri: &Iri) -> Self {
+        Self::declare_from_str_unchecked(name, iri.as_str())
     }
 
+    /// Declare a namespace without checking the prefix name.
+    /// Panics if the IRI (with a '/' added if it does not end with either
+    /// '/' or '#') is not valid.
+    pub fn declare_from_str_unchecked(name: &str, iri: &str) -> Self {
+        Self::new(name, iri).unwrap_or_else(|error| panic!("{error}"))
+    }
+
+    #[deprecated(note = "use `try_declare` or `declare_unchecked`")]
+    pub fn declare(name: &str, iri: &Iri) -> Self { Self::declare_unchecked(name, iri) }
+
+    #[deprecated(note = "use `try_declare_from_str` or `declare_from_str_unchecked`")]
     pub fn declare_from_str(name: &str, iri: &str) -> Self {
-        Self::declare(name, &Iri::new(iri).unwrap())
+        Self::declare_from_str_unchecked(name, iri)
+    }
+
+    fn new(name: &str, iri: &str) -> Result<Self, RDFStoreError> {
+        let iri = match iri.chars().last() {
+            Some('/') | Some('#') => iri.to_string(),
+            _ => format!("{iri}/"),
+        };
+        Ok(Self { name: name.to_string(), iri: IriBuf::new(iri)? })
     }
 
     /// Return an identifier based on the current namespace IRI and the given
     /// local name within that namespace.
+    /// Returns an [`RDFStoreError::InvalidIri`] if the namespace IRI does not
+    /// end with either '/' or '#'.
     pub fn with_local_name(&self, name: &str) -> Result<IriBuf, RDFStoreError> {
-        let iri_str = match *self.iri.as_bytes().last().unwrap() as char {
-            '/' | '#' => format!("{}{name}", self.iri.as_str()),
-            _ => {
-                panic!(
-                    "{} does not end with either / or #",
-                    self.iri.as_str()
-                )
-            },
-        };
+        match self.iri.as_str().chars().last() {
+            Some('/') | Some('#') => Ok(IriBuf::new(format!("{}{name}", self.iri.as_str()))?),
+            _ => Err(RDFStoreError::InvalidIri(InvalidIri(self.iri.as_str().to_string()))),
+        }
+    }
 
-        Ok(IriBuf::from_str(iri_str.as_str())?)
+    /// Return the local name of the given IRI within this namespace, or
+    /// `None` if the IRI is not in this namespace.
+    pub fn local_name_of<'a>(&self, iri: &'a str) -> Option<&'a str> {
+        iri.strip_prefix(self.iri.as_str())
     }
 
     #[cfg(all(feature = "rdftk-support", not(target_arch = "wasm32")))]
@@ -76,12 +115,30 @@ impl Namespace {
     }
 }
 
+/// Whether the given name is a `PN_PREFIX` (or nothing) followed by a `:`.
+fn is_prefix_name(name: &str) -> bool {
+    let prefix = match name.strip_suffix(':') {
+        Some(prefix) => prefix,
+        None => return false,
+    };
+    let chars = prefix.chars().collect::<Vec<_>>();
+    match chars.as_slice() {
+        [] => true,
+        [only] => is_pn_chars_base(*only),
+        [first, middle @ .., last] => {
+            is_pn_chars_base(*first) &&
+                middle.iter().all(|c| *c == '.' || is_pn_chars(*c)) &&
+                is_pn_chars(*last)
+        },
+    }
+}
+
 #[cfg(test)]
 mod tests {
 
     #[test_log::test]
     fn test_a_prefix() -> Result<(), crate::RDFStoreError> {
-        let namespace = crate::Namespace::declare(
+        let namespace = crate::Namespace::declare_unchecked(
             "test:",
             iref::iri::Iri::new("http://whatever.kom/test#").unwrap(),
         );
@@ -93,7 +150,7 @@ mod tests {
 
     #[test_log::test]
     fn test_b_prefix() -> Result<(), crate::RDFStoreError> {
-        let namespace = crate::Namespace::declare(
+        let namespace = crate::Namespace::declare_unchecked(
             "test:",
             iref::iri::Iri::new("http://whatever.kom/test/").unwrap(),
         );
@@ -102,4 +159,69 @@ mod tests {
         assert_eq!(x.as_str(), "http://whatever.kom/test/abc");
         Ok(())
     }
+
+    #[test_log::test]
+    fn test_local_name_of() {
+        let namespace =
+            crate::Namespace::declare_from_str_unchecked("test:", "http://whatever.kom/test#");
+
+        assert_eq!(
+            namespace.local_name_of("http://whatever.kom/test#abc"),
+            Some("abc")
+        );
+        assert_eq!(
+            namespace.local_name_of("http://whatever.kom/other#abc"),
+            None
+        );
+    }
+
+    #[test_log::test]
+    fn test_try_declare() -> Result<(), crate::RDFStoreError> {
+        use crate::{Namespace, RDFStoreError};
+
+        for name in ["test:", ":", "t:", "test.v2:", "ex-1:", "é:"] {
+            assert_eq!(
+                Namespace::try_declare_from_str(name, "http://whatever.kom/test#")?.name,
+                name
+            );
+        }
+        for name in ["test", "_test:", "1test:", "test.:", "te st:", "test::"] {
+            assert!(
+                matches!(
+                    Namespace::try_declare_from_str(name, "http://whatever.kom/test#"),
+                    Err(RDFStoreError::InvalidPrefixName { .. })
+                ),
+                "{name}"
+            );
+        }
+        assert!(matches!(
+            Namespace::try_declare_from_str("test:", "not an iri"),
+            Err(RDFStoreError::InvalidIri(_))
+        ));
+        for iri in ["http://whatever.kom/test", "urn:test"] {
+            assert!(
+                matches!(
+                    Namespace::try_declare("test:", iref::iri::Iri::new(iri).unwrap()),
+                    Err(RDFStoreError::InvalidIri(_))
+                ),
+                "{iri}"
+            );
+        }
+        let namespace = Namespace::declare_unchecked(
+            "test:",
+            iref::iri::Iri::new("http://whatever.kom/test").unwrap(),
+        );
+        assert_eq!(namespace.iri.as_str(), "http://whatever.kom/test/");
+        Ok(())
+    }
+
+    #[test_log::test]
+    fn test_with_local_name_without_separator() {
+        let namespace = crate::Namespace {
+            name: "test:".to_string(),
+            iri:  iref::IriBuf::new("urn:test".to_string()).unwrap(),
+        };
+
+        assert!(namespace.with_local_name("abc").is_err());
+    }
 }
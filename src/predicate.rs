@@ -1,37 +1,32 @@
 // Copyright (c) 2018-2023, agnos.ai UK Ltd, all rights reserved.
 //---------------------------------------------------------------
 
-use crate::Namespace;
-
-pub struct Predicate<'a> {
-    pub namespace:  &'a Namespace,
-    pub local_name: String,
+use {
+    crate::{Namespace, PrefixedIri},
+    std::sync::Arc,
+};
+
+/// An RDF property identifier consisting of a [`Namespace`] and a "local
+/// name", see [`PrefixedIri`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Predicate {
+    pub(crate) iri: PrefixedIri,
 }
 
-impl<'a> std::fmt::Display for Predicate<'a> {
+impl std::fmt::Display for Predicate {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "<{}{}>", self.namespace.iri, self.local_name)
+        write!(
+            f,
+            "<{}{}>",
+            self.namespace().iri,
+            self.local_name()
+        )
     }
 }
 
-impl<'a> Predicate<'a> {
-    pub fn display_turtle<'b>(&'a self) -> impl std::fmt::Display + 'a + 'b
-    where 'a: 'b {
-        struct TurtlePredicate<'b>(&'b Predicate<'b>);
-        impl<'b> std::fmt::Display for TurtlePredicate<'b> {
-            fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
-                write!(
-                    f,
-                    "{}{}",
-                    self.0.namespace.name, self.0.local_name
-                )
-            }
-        }
-        TurtlePredicate(self)
-    }
-
-    pub fn declare(namespace: &'a Namespace, local_name: &str) -> Self {
-        Self { namespace, local_name: local_name.to_string() }
+impl Predicate {
+    pub fn declare(namespace: impl Into<Arc<Namespace>>, local_name: &str) -> Self {
+        Self { iri: PrefixedIri::declare(namespace, local_name) }
     }
 
     #[cfg(all(feature = "rdftk-support", not(target_arch = "wasm32")))]
@@ -44,11 +39,11 @@ impl<'a> Predicate<'a> {
         use std::str::FromStr;
         let path = format!(
             "{}{}",
-            self.namespace.iri.path().as_str(),
-            self.local_name.as_str()
+            self.namespace().iri.path().as_str(),
+            self.local_name()
         );
         Ok(self
-            .namespace
+            .namespace()
             .as_rdftk_iri()?
             .with_new_path(rdftk_iri::Path::from_str(path.as_str())?))
     }
@@ -63,11 +58,11 @@ mod tests {
 
     #[test]
     fn test_predicate() {
-        let ns = Namespace::declare(
+        let ns = Namespace::declare_unchecked(
             "abc:",
             Iri::new("https://whatever.kg/def/").unwrap(),
         );
-        let prd = Predicate::declare(&ns, "xyz");
+        let prd = Predicate::declare(ns, "xyz");
 
         let str_prd = format!("{:}", prd);
 
@@ -81,11 +76,11 @@ mod tests {
     #[cfg(all(feature = "rdftk-support", not(target_arch = "wasm32")))]
     #[test]
     fn test_predicate_as_iri_ref() {
-        let ns = Namespace::declare(
+        let ns = Namespace::declare_unchecked(
             "abc:",
             Iri::new("https://whatever.kg/def/").unwrap(),
         );
-        let prd = Predicate::declare(&ns, "xyz");
+        let prd = Predicate::declare(ns, "xyz");
 
         let iri_ref_result = prd.as_rdftk_iri_ref();
         assert!(iri_ref_result.is_ok());
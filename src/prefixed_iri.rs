@@ -0,0 +1,283 @@
+// Copyright (c) 2018-2023, agnos.ai UK Ltd, all rights reserved.
+//---------------------------------------------------------------
+
+use {
+    crate::{Class, Graph, Namespace, Predicate, RDFStoreError, Term},
+    std::{
+        hash::{Hash, Hasher},
+        sync::Arc,
+    },
+};
+
+/// An IRI that consists of a [`Namespace`] and a "local name", such as
+/// `rdf:type`, which is what [`Class`], [`Predicate`] and [`Graph`] are
+/// built on.
+///
+/// The namespace is shared, so cloning a `PrefixedIri` only copies its
+/// local name.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PrefixedIri {
+    namespace:  Arc<Namespace>,
+    local_name: String,
+}
+
+impl Hash for PrefixedIri {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.namespace.name.hash(state);
+        self.namespace.iri.as_str().hash(state);
+        self.local_name.hash(state);
+    }
+}
+
+/// Print the IRI as a Turtle or SPARQL prefixed name, see
+/// [`PrefixedIri::display_turtle`].
+impl std::fmt::Display for PrefixedIri {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.display_turtle())
+    }
+}
+
+impl PrefixedIri {
+    /// A `PrefixedIri` for the given local name, which is not checked, see
+    /// [`PrefixedIri::new`] for that.
+    pub fn declare(namespace: impl Into<Arc<Namespace>>, local_name: &str) -> Self {
+        Self {
+            namespace:  namespace.into(),
+            local_name: local_name.to_string(),
+        }
+    }
+
+    /// A `PrefixedIri` for the given local name, which has to make a valid
+    /// IRI in the given namespace.
+    pub fn new(
+        namespace: impl Into<Arc<Namespace>>,
+        local_name: &str,
+    ) -> Result<Self, RDFStoreError> {
+        let iri = Self::declare(namespace, local_name);
+        match iri.as_iri() {
+            Ok(_) if !local_name.is_empty() => Ok(iri),
+            _ => {
+                Err(RDFStoreError::InvalidLocalName {
+                    namespace:  iri.namespace.name.clone(),
+                    local_name: local_name.to_string(),
+                })
+            },
+        }
+    }
+
+    pub fn namespace(&self) -> &Arc<Namespace> { &self.namespace }
+
+    pub fn local_name(&self) -> &str { self.local_name.as_str() }
+
+    pub fn as_iri(&self) -> Result<iref::IriBuf, RDFStoreError> {
+        Ok(iref::IriBuf::new(format!(
+            "{}{}",
+            self.namespace.iri.as_str(),
+            self.local_name.as_str()
+        ))?)
+    }
+
+    /// The local name as the local part of a Turtle or SPARQL prefixed
+    /// name (`PN_LOCAL`), with a backslash before the characters that need
+    /// one, or `None` if the local name cannot be written that way, for
+    /// instance because it contains a space.
+    pub fn escaped_local_name(&self) -> Option<String> {
+        let chars = self.local_name.chars().collect::<Vec<_>>();
+        let mut escaped = String::with_capacity(self.local_name.len());
+        for (idx, c) in chars.iter().copied().enumerate() {
+            let is_first = idx == 0;
+            let is_last = idx == chars.len() - 1;
+            let is_percent_encoded = c == '%' &&
+                chars.len() > idx + 2 &&
+                chars[idx + 1].is_ascii_hexdigit() &&
+                chars[idx + 2].is_ascii_hexdigit();
+            // The characters that PN_LOCAL allows unescaped at this position
+            if is_pn_chars_u(c) ||
+                c == ':' ||
+                c.is_ascii_digit() ||
+                is_percent_encoded ||
+                (c == '.' && !is_first && !is_last) ||
+                (is_pn_chars(c) && !is_first)
+            {
+                escaped.push(c);
+            } else if "_~.-!$&'()*+,;=/?#@%".contains(c) {
+                escaped.push('\\');
+                escaped.push(c);
+            } else {
+                return None
+            }
+        }
+        Some(escaped)
+    }
+
+    /// Print the IRI as a Turtle or SPARQL prefixed name such as
+    /// `rdf:type`, or as `<iri>` if the local name cannot be written as the
+    /// local part of a prefixed name.
+    #[allow(clippy::needless_lifetimes)]
+    pub fn display_turtle<'a>(&'a self) -> impl std::fmt::Display + 'a {
+        struct TurtlePrefixedIri<'a>(&'a PrefixedIri);
+        impl<'a> std::fmt::Display for TurtlePrefixedIri<'a> {
+            fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+                match self.0.escaped_local_name() {
+                    Some(local_name) => write!(f, "{}{local_name}", self.0.namespace.name),
+                    None => {
+                        write!(
+                            f,
+                            "<{}{}>",
+                            self.0.namespace.iri.as_str(),
+                            self.0.local_name
+                        )
+                    },
+                }
+            }
+        }
+        TurtlePrefixedIri(self)
+    }
+}
+
+impl TryFrom<&PrefixedIri> for Term {
+    type Error = RDFStoreError;
+
+    fn try_from(iri: &PrefixedIri) -> Result<Self, Self::Error> {
+        Term::new_iri(iri.as_iri()?.as_iri())
+    }
+}
+
+impl AsRef<PrefixedIri> for PrefixedIri {
+    fn as_ref(&self) -> &PrefixedIri { self }
+}
+
+macro_rules! prefixed_iri_conversions {
+    ($type:ident) => {
+        impl From<PrefixedIri> for $type {
+            fn from(iri: PrefixedIri) -> Self { Self { iri } }
+        }
+
+        impl From<$type> for PrefixedIri {
+            fn from(value: $type) -> Self { value.iri }
+        }
+
+        impl AsRef<PrefixedIri> for $type {
+            fn as_ref(&self) -> &PrefixedIri { &self.iri }
+        }
+
+        impl std::ops::Deref for $type {
+            type Target = PrefixedIri;
+
+            fn deref(&self) -> &PrefixedIri { &self.iri }
+        }
+
+        impl TryFrom<&$type> for Term {
+            type Error = RDFStoreError;
+
+            fn try_from(value: &$type) -> Result<Self, Self::Error> { Term::try_from(&value.iri) }
+        }
+    };
+}
+
+prefixed_iri_conversions!(Class);
+prefixed_iri_conversions!(Graph);
+prefixed_iri_conversions!(Predicate);
+
+/// `PN_CHARS_BASE` in the Turtle and SPARQL grammars.
+pub(crate) fn is_pn_chars_base(c: char) -> bool {
+    matches!(c,
+        'A'..='Z' |
+        'a'..='z' |
+        '\u{C0}'..='\u{D6}' |
+        '\u{D8}'..='\u{F6}' |
+        '\u{F8}'..='\u{2FF}' |
+        '\u{370}'..='\u{37D}' |
+        '\u{37F}'..='\u{1FFF}' |
+        '\u{200C}'..='\u{200D}' |
+        '\u{2070}'..='\u{218F}' |
+        '\u{2C00}'..='\u{2FEF}' |
+        '\u{3001}'..='\u{D7FF}' |
+        '\u{F900}'..='\u{FDCF}' |
+        '\u{FDF0}'..='\u{FFFD}' |
+        '\u{10000}'..='\u{EFFFF}'
+    )
+}
+
+/// `PN_CHARS_BASE` or `_` (`PN_CHARS_U`).
+pub(crate) fn is_pn_chars_u(c: char) -> bool { c == '_' || is_pn_chars_base(c) }
+
+/// `PN_CHARS`.
+pub(crate) fn is_pn_chars(c: char) -> bool {
+    is_pn_chars_u(c) ||
+        matches!(c,
+            '-' |
+            '0'..='9' |
+            '\u{B7}' |
+            '\u{300}'..='\u{36F}' |
+            '\u{203F}'..='\u{2040}'
+        )
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::PrefixedIri,
+        crate::{Class, Graph, Namespace, Predicate, RDFStoreError, Term},
+        std::sync::Arc,
+    };
+
+    fn test() -> Namespace {
+        Namespace::declare_from_str_unchecked("test:", "https://whatever.kom/test#")
+    }
+
+    #[test_log::test]
+    fn test_escaped_local_name() {
+        for (local_name, turtle) in [
+            ("name", "test:name"),
+            ("1st", "test:1st"),
+            ("a.b", "test:a.b"),
+            ("file.ttl.", "test:file.ttl\\."),
+            ("-x", "test:\\-x"),
+            ("a/b(c)", "test:a\\/b\\(c\\)"),
+            ("100%25", "test:100%25"),
+            ("100%", "test:100\\%"),
+            ("a b", "<https://whatever.kom/test#a b>"),
+        ] {
+            let iri = PrefixedIri::declare(test(), local_name);
+            assert_eq!(iri.display_turtle().to_string(), turtle);
+        }
+    }
+
+    #[test_log::test]
+    fn test_new() {
+        assert!(PrefixedIri::new(test(), "name").is_ok());
+        assert!(matches!(
+            PrefixedIri::new(test(), "a b"),
+            Err(RDFStoreError::InvalidLocalName { .. })
+        ));
+        assert!(PrefixedIri::new(test(), "").is_err());
+    }
+
+    #[test_log::test]
+    fn test_conversions() -> Result<(), RDFStoreError> {
+        let namespace = Arc::new(test());
+        let class = Class::declare(namespace.clone(), "Person");
+        let predicate = Predicate::declare(namespace.clone(), "knows");
+        let graph = Graph::declare(test(), "people");
+
+        assert!(Arc::ptr_eq(class.namespace(), &namespace));
+        assert!(Arc::ptr_eq(predicate.namespace(), &namespace));
+        assert_eq!(graph.local_name(), "people");
+
+        let iri = PrefixedIri::from(class.clone());
+        assert_eq!(
+            Class::from(iri.clone()).to_string(),
+            "test:Person"
+        );
+        assert_eq!(
+            Predicate::from(iri).to_string(),
+            "<https://whatever.kom/test#Person>"
+        );
+        assert_eq!(
+            Term::try_from(&class)?,
+            Term::new_iri_from_str("https://whatever.kom/test#Person")?
+        );
+        Ok(())
+    }
+}
@@ -0,0 +1,333 @@
+// Copyright (c) 2018-2023, agnos.ai UK Ltd, all rights reserved.
+//---------------------------------------------------------------
+//! Prefix names for namespaces, taken from a bundled table of well-known
+//! prefixes (in the style of [prefix.cc](https://prefix.cc)) or made up
+//! from the namespace IRI, so that IRIs from any source can be written as
+//! prefixed names.
+
+use {
+    crate::{Namespace, RDFStoreError},
+    lazy_static::lazy_static,
+    std::collections::HashSet,
+};
+
+lazy_static! {
+    /// The prefix names and namespace IRIs in `prefixes.csv`.
+    static ref WELL_KNOWN_PREFIXES: Vec<(&'static str, &'static str)> =
+        include_str!("prefixes.csv")
+            .lines()
+            .filter(|line| !line.is_empty() && !line.starts_with('#'))
+            .skip(1)
+            .filter_map(|line| line.split_once(','))
+            .collect();
+}
+
+/// Path segments that say little about a namespace, such as the `ontology`
+/// in `https://ekgf.org/ontology/`.
+const GENERIC_SEGMENTS: &[&str] = &[
+    "class",
+    "data",
+    "def",
+    "entity",
+    "id",
+    "index",
+    "latest",
+    "ns",
+    "ont",
+    "onto",
+    "ontologies",
+    "ontology",
+    "properties",
+    "property",
+    "resource",
+    "resources",
+    "schema",
+    "schemas",
+    "term",
+    "terms",
+    "vocab",
+    "vocabularies",
+    "vocabulary",
+];
+
+/// File extensions that are left out of a path segment, such as the `.owl`
+/// in `http://purl.org/NET/c4dm/event.owl#`.
+const FILE_EXTENSIONS: &[&str] = &["html", "jsonld", "n3", "nt", "owl", "rdf", "ttl", "xml"];
+
+/// The prefix name (without the `:`) of the given namespace IRI in the table
+/// of well-known prefixes, such as `foaf` for `http://xmlns.com/foaf/0.1/`.
+pub fn well_known_prefix(namespace_iri: &str) -> Option<&'static str> {
+    WELL_KNOWN_PREFIXES
+        .iter()
+        .find(|(_, iri)| *iri == namespace_iri)
+        .map(|(prefix, _)| *prefix)
+}
+
+/// The namespace of the given prefix name (with or without the `:`) in the
+/// table of well-known prefixes, such as `http://xmlns.com/foaf/0.1/` for
+/// `foaf:`.
+pub fn well_known_namespace(name: &str) -> Option<Namespace> {
+    let prefix = name.strip_suffix(':').unwrap_or(name);
+    WELL_KNOWN_PREFIXES
+        .iter()
+        .find(|(well_known, _)| *well_known == prefix)
+        .map(|(prefix, iri)| {
+            Namespace::declare_from_str_unchecked(format!("{prefix}:").as_str(), iri)
+        })
+}
+
+/// Namespaces for the given IRIs that are not in any of the given
+/// namespaces yet, so that these IRIs can be written as prefixed names as
+/// well, for instance when serializing data from another source as Turtle.
+///
+/// The namespace IRI of an IRI is everything up to and including its last
+/// `/` or `#`, IRIs with nothing after that (or without a path) are left
+/// out. A namespace IRI in the table of well-known prefixes gets that
+/// prefix name, any other namespace IRI gets a prefix name made from its
+/// last meaningful path segment or otherwise its domain name, such as
+/// `people:` for `https://ekgf.org/v1/people/` and `ekgf:` for
+/// `https://ekgf.org/ontology/`. A number is added to a prefix name that is
+/// already taken, and made-up prefix names never take a well-known one.
+///
+/// ```rust
+/// use rdf_store_rs::{generate_namespaces, RDFStoreError};
+///
+/// let namespaces = generate_namespaces(
+///     [
+///         "http://xmlns.com/foaf/0.1/Person",
+///         "https://ekgf.org/v1/people/alice",
+///     ],
+///     &[],
+/// )?;
+/// assert_eq!(namespaces[0].name, "foaf:");
+/// assert_eq!(
+///     namespaces[1].to_string(),
+///     "people: <https://ekgf.org/v1/people/>"
+/// );
+/// # Ok::<(), RDFStoreError>(())
+/// ```
+pub fn generate_namespaces<I>(
+    iris: I,
+    namespaces: &[Namespace],
+) -> Result<Vec<Namespace>, RDFStoreError>
+where
+    I: IntoIterator,
+    I::Item: AsRef<str>,
+{
+    let mut namespace_iris: Vec<String> = Vec::new();
+    for iri in iris {
+        let iri = iri.as_ref();
+        if namespaces
+            .iter()
+            .any(|namespace| namespace.local_name_of(iri).is_some())
+        {
+            continue
+        }
+        if let Some(namespace_iri) = namespace_iri_of(iri) {
+            if !namespace_iris.iter().any(|known| known == namespace_iri) {
+                namespace_iris.push(namespace_iri.to_string());
+            }
+        }
+    }
+    let mut taken = namespaces
+        .iter()
+        .map(|namespace| namespace.name.trim_end_matches(':').to_string())
+        .collect::<HashSet<_>>();
+    let mut generated = Vec::with_capacity(namespace_iris.len());
+    for namespace_iri in namespace_iris {
+        let prefix = match well_known_prefix(namespace_iri.as_str()) {
+            Some(prefix) if !taken.contains(prefix) => prefix.to_string(),
+            _ => {
+                let prefix = made_up_prefix(namespace_iri.as_str());
+                unique_prefix(prefix.as_deref().unwrap_or("ns"), &taken)
+            },
+        };
+        generated.push(Namespace::try_declare_from_str(
+            format!("{prefix}:").as_str(),
+            namespace_iri.as_str(),
+        )?);
+        taken.insert(prefix);
+    }
+    Ok(generated)
+}
+
+/// The namespace IRI of the given IRI, which is everything up to and
+/// including its last `/` or `#`, unless that is part of the `//` before
+/// the host or the last character of the IRI.
+fn namespace_iri_of(iri: &str) -> Option<&str> {
+    let end = iri.rfind(['/', '#'])? + 1;
+    let path_start = iri.find("://").map_or(0, |idx| idx + 3);
+    if end <= path_start || end == iri.len() {
+        return None
+    }
+    Some(&iri[..end])
+}
+
+/// A prefix name made from the last path segment of the given namespace IRI
+/// that is not generic or a version, or otherwise from the name of its
+/// domain, such as `example` for `https://data.example.org/`.
+fn made_up_prefix(namespace_iri: &str) -> Option<String> {
+    let rest = namespace_iri
+        .split_once("://")
+        .map_or(namespace_iri, |(_, rest)| rest);
+    let (authority, path) = rest.split_once(['/', '#']).unwrap_or((rest, ""));
+    path.split(['/', '#'])
+        .rev()
+        .map(|segment| {
+            match segment.rsplit_once('.') {
+                Some((stem, extension)) if FILE_EXTENSIONS.contains(&extension) => stem,
+                _ => segment,
+            }
+        })
+        .filter(|segment| !is_version(segment))
+        .filter(|segment| !GENERIC_SEGMENTS.contains(&segment.to_lowercase().as_str()))
+        .find_map(prefix_from_word)
+        .or_else(|| {
+            let host = authority.rsplit('@').next().unwrap_or(authority);
+            let host = host.split(':').next().unwrap_or(host);
+            let labels = host
+                .split('.')
+                .filter(|label| *label != "www")
+                .collect::<Vec<_>>();
+            match labels.as_slice() {
+                [.., domain, _top_level_domain] => prefix_from_word(domain),
+                [domain] => prefix_from_word(domain),
+                [] => None,
+            }
+        })
+}
+
+/// Whether the given path segment is a version such as `0.1`, `v2` or
+/// `2023-01`.
+fn is_version(segment: &str) -> bool {
+    let digits = segment.strip_prefix(['v', 'V']).unwrap_or(segment);
+    digits.starts_with(|c: char| c.is_ascii_digit())
+}
+
+/// The given word in lower case, without the characters that would need
+/// more thought in a prefix name, or `None` if nothing is left of it.
+fn prefix_from_word(word: &str) -> Option<String> {
+    let prefix = word
+        .chars()
+        .filter(char::is_ascii_alphanumeric)
+        .skip_while(char::is_ascii_digit)
+        .collect::<String>()
+        .to_lowercase();
+    if prefix.is_empty() {
+        None
+    } else {
+        Some(prefix)
+    }
+}
+
+/// The given prefix name, or that prefix name with the lowest number from
+/// 2 up that is neither taken nor a well-known prefix name.
+fn unique_prefix(prefix: &str, taken: &HashSet<String>) -> String {
+    let is_free = |candidate: &str| {
+        !taken.contains(candidate) &&
+            !WELL_KNOWN_PREFIXES
+                .iter()
+                .any(|(well_known, _)| *well_known == candidate)
+    };
+    if is_free(prefix) {
+        return prefix.to_string()
+    }
+    (2..)
+        .map(|number| format!("{prefix}{number}"))
+        .find(|candidate| is_free(candidate))
+        .unwrap()
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::{
+            generate_namespaces,
+            well_known_namespace,
+            well_known_prefix,
+            WELL_KNOWN_PREFIXES,
+        },
+        crate::{vocab, Namespace, RDFStoreError},
+    };
+
+    #[test_log::test]
+    fn test_well_known_prefixes() -> Result<(), RDFStoreError> {
+        for (prefix, iri) in WELL_KNOWN_PREFIXES.iter() {
+            Namespace::try_declare_from_str(format!("{prefix}:").as_str(), iri)?;
+        }
+        let namespaces: [&Namespace; 11] = [
+            &vocab::dcat::NAMESPACE,
+            &vocab::dcterms::NAMESPACE,
+            &vocab::foaf::NAMESPACE,
+            &vocab::geo::NAMESPACE,
+            &vocab::owl::NAMESPACE,
+            &vocab::rdf::NAMESPACE,
+            &vocab::rdfox::NAMESPACE,
+            &vocab::rdfs::NAMESPACE,
+            &vocab::schema::NAMESPACE,
+            &vocab::skos::NAMESPACE,
+            &vocab::xsd::NAMESPACE,
+        ];
+        for namespace in namespaces {
+            let prefix = well_known_prefix(namespace.iri.as_str());
+            assert_eq!(prefix, namespace.name.strip_suffix(':'));
+        }
+        assert_eq!(
+            well_known_prefix("http://schema.org/"),
+            Some("schema")
+        );
+        assert_eq!(
+            well_known_namespace("schema:").map(|namespace| namespace.to_string()),
+            Some("schema: <https://schema.org/>".to_string())
+        );
+        assert_eq!(
+            well_known_namespace("foaf").map(|namespace| namespace.name),
+            Some("foaf:".to_string())
+        );
+        assert!(well_known_namespace("whatever").is_none());
+        Ok(())
+    }
+
+    #[test_log::test]
+    fn test_generate_namespaces() -> Result<(), RDFStoreError> {
+        let existing = [
+            Namespace::declare_from_str_unchecked("test:", "https://whatever.kom/test#"),
+            Namespace::declare_from_str_unchecked("foaf:", "https://whatever.kom/friends/"),
+        ];
+        let namespaces = generate_namespaces(
+            [
+                "https://whatever.kom/test#name",
+                "http://www.w3.org/2004/02/skos/core#prefLabel",
+                "http://xmlns.com/foaf/0.1/Person",
+                "https://data.ekgf.org/ontology/v2/core#Thing",
+                "https://data.ekgf.org/ontology/v2/core#thing",
+                "https://ekgf.org/people/alice",
+                "https://whatever.kom/2023/people/bob",
+                "http://www.ekgf.org/resource/x",
+                "https://whatever.kom/ontology.owl#Person",
+                "http://www.whatever.kom:8080/rdf/org#Unit",
+                "http://192.168.0.1/1",
+                "http://whatever.kom",
+                "http://whatever.kom/",
+                "urn:isbn:0451450523",
+            ],
+            &existing,
+        )?;
+        let namespaces = namespaces
+            .iter()
+            .map(|namespace| namespace.to_string())
+            .collect::<Vec<_>>();
+        assert_eq!(namespaces, [
+            "skos: <http://www.w3.org/2004/02/skos/core#>",
+            "foaf2: <http://xmlns.com/foaf/0.1/>",
+            "core: <https://data.ekgf.org/ontology/v2/core#>",
+            "people: <https://ekgf.org/people/>",
+            "people2: <https://whatever.kom/2023/people/>",
+            "ekgf: <http://www.ekgf.org/resource/>",
+            "whatever: <https://whatever.kom/ontology.owl#>",
+            "org2: <http://www.whatever.kom:8080/rdf/org#>",
+            "ns: <http://192.168.0.1/>",
+        ]);
+        Ok(())
+    }
+}
@@ -0,0 +1,627 @@
+// Copyright (c) 2018-2023, agnos.ai UK Ltd, all rights reserved.
+//---------------------------------------------------------------
+//! The abstract syntax tree of SPARQL 1.1 queries and updates.
+//!
+//! The tree stays close to the grammar in the
+//! [SPARQL 1.1 Query Language](https://www.w3.org/TR/sparql11-query/#grammar)
+//! specification: prefixed names are kept as they were written so that a
+//! statement can be serialized again without losing its readability.
+//! Syntactic sugar such as `[ ... ]` property lists and `( ... )` collections
+//! is expanded into plain triple patterns with fresh blank nodes though.
+
+use {
+    crate::{DataType, Namespace, RDFStoreError, Term},
+    iref::IriBuf,
+    std::str::FromStr,
+};
+
+/// A parsed SPARQL statement, either a query or an update.
+#[allow(clippy::large_enum_variant)]
+#[derive(Debug, Clone, PartialEq)]
+pub enum SparqlStatement {
+    Query(Query),
+    Update(Update),
+}
+
+/// The kind of a [`SparqlStatement`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SparqlStatementKind {
+    Select,
+    Construct,
+    Describe,
+    Ask,
+    Update,
+}
+
+/// The `BASE` and `PREFIX` declarations at the start of a statement.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct Prologue {
+    pub base:     Option<String>,
+    /// The prefix names (without the trailing `:`) and their namespace IRIs,
+    /// in order of declaration.
+    pub prefixes: Vec<(String, String)>,
+}
+
+/// A SPARQL variable, without its leading `?` or `$`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Variable(pub String);
+
+/// An IRI as written in a SPARQL statement.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum SparqlIri {
+    /// An IRI between angle brackets, possibly relative to the `BASE`.
+    Iri(String),
+    /// A prefixed name such as `rdf:type`.
+    PrefixedName { prefix: String, local_name: String },
+}
+
+/// A literal as written in a SPARQL statement.
+///
+/// Numeric and boolean shorthands (such as `42` or `true`) get their
+/// implicit XSD datatype.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct SparqlLiteral {
+    pub lexical_form: String,
+    pub language:     Option<String>,
+    pub data_type:    Option<SparqlIri>,
+}
+
+/// A term or variable in a triple pattern or an expression.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum TermPattern {
+    Variable(Variable),
+    Iri(SparqlIri),
+    BlankNode(String),
+    Literal(SparqlLiteral),
+}
+
+/// A property path, see
+/// [Property Paths](https://www.w3.org/TR/sparql11-query/#propertypaths).
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum PropertyPath {
+    Iri(SparqlIri),
+    Inverse(Box<PropertyPath>),
+    Sequence(Vec<PropertyPath>),
+    Alternative(Vec<PropertyPath>),
+    ZeroOrMore(Box<PropertyPath>),
+    OneOrMore(Box<PropertyPath>),
+    ZeroOrOne(Box<PropertyPath>),
+    /// `!(...)`, each IRI flagged with whether it is inverted (`^`)
+    NegatedPropertySet(Vec<(SparqlIri, bool)>),
+}
+
+/// The predicate position of a triple pattern.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Verb {
+    Variable(Variable),
+    Path(PropertyPath),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct TriplePattern {
+    pub subject:   TermPattern,
+    pub predicate: Verb,
+    pub object:    TermPattern,
+}
+
+/// A `VALUES` block, `None` stands for `UNDEF`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InlineData {
+    pub variables: Vec<Variable>,
+    pub rows:      Vec<Vec<Option<TermPattern>>>,
+}
+
+/// A group graph pattern, i.e. the part between curly braces.
+#[derive(Debug, Clone, PartialEq)]
+pub enum GroupGraphPattern {
+    Group(Vec<PatternElement>),
+    SubSelect(Box<Query>),
+}
+
+/// An element of a [`GroupGraphPattern`].
+#[derive(Debug, Clone, PartialEq)]
+pub enum PatternElement {
+    Triples(Vec<TriplePattern>),
+    Filter(Expression),
+    Optional(GroupGraphPattern),
+    Minus(GroupGraphPattern),
+    /// One or more group graph patterns separated by `UNION`, a single
+    /// element stands for a nested group.
+    Union(Vec<GroupGraphPattern>),
+    Graph {
+        name:    TermPattern,
+        pattern: GroupGraphPattern,
+    },
+    Service {
+        silent:  bool,
+        name:    TermPattern,
+        pattern: GroupGraphPattern,
+    },
+    Bind {
+        expression: Expression,
+        variable:   Variable,
+    },
+    Values(InlineData),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum BinaryOperator {
+    Or,
+    And,
+    Equal,
+    NotEqual,
+    Less,
+    Greater,
+    LessOrEqual,
+    GreaterOrEqual,
+    Add,
+    Subtract,
+    Multiply,
+    Divide,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum UnaryOperator {
+    Not,
+    Plus,
+    Minus,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum AggregateFunction {
+    Count,
+    Sum,
+    Min,
+    Max,
+    Avg,
+    Sample,
+    GroupConcat,
+}
+
+/// The function of a [`Expression::FunctionCall`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Function {
+    /// A built-in function such as `STR` or `REGEX`, in upper case
+    BuiltIn(String),
+    /// An extension function (or XSD cast) identified by an IRI
+    Iri(SparqlIri),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Expression {
+    Term(TermPattern),
+    Binary(BinaryOperator, Box<Expression>, Box<Expression>),
+    Unary(UnaryOperator, Box<Expression>),
+    In {
+        expression: Box<Expression>,
+        list:       Vec<Expression>,
+        negated:    bool,
+    },
+    FunctionCall {
+        function:  Function,
+        arguments: Vec<Expression>,
+        distinct:  bool,
+    },
+    Exists {
+        pattern: Box<GroupGraphPattern>,
+        negated: bool,
+    },
+    Aggregate {
+        function:   AggregateFunction,
+        distinct:   bool,
+        /// `None` for `COUNT(*)`
+        expression: Option<Box<Expression>>,
+        separator:  Option<String>,
+    },
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SelectModifier {
+    None,
+    Distinct,
+    Reduced,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum ProjectionItem {
+    Variable(Variable),
+    Expression(Expression, Variable),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Projection {
+    /// `SELECT *`
+    All,
+    Items(Vec<ProjectionItem>),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum QueryForm {
+    Select {
+        modifier:   SelectModifier,
+        projection: Projection,
+    },
+    /// `template` is `None` for the `CONSTRUCT WHERE { ... }` short form
+    Construct {
+        template: Option<Vec<TriplePattern>>,
+    },
+    /// An empty list of resources stands for `DESCRIBE *`
+    Describe {
+        resources: Vec<TermPattern>,
+    },
+    Ask,
+}
+
+/// A `FROM` (default graph) or `FROM NAMED` clause.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DatasetClause {
+    pub named: bool,
+    pub iri:   SparqlIri,
+}
+
+/// A `GROUP BY` condition, optionally bound to a variable with `AS`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct GroupCondition {
+    pub expression: Expression,
+    pub variable:   Option<Variable>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct OrderCondition {
+    pub expression: Expression,
+    pub descending: bool,
+}
+
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct SolutionModifiers {
+    pub group_by: Vec<GroupCondition>,
+    pub having:   Vec<Expression>,
+    pub order_by: Vec<OrderCondition>,
+    pub limit:    Option<u64>,
+    pub offset:   Option<u64>,
+}
+
+/// A SPARQL query. Sub-queries (nested `SELECT`s) use the same structure
+/// with an empty prologue and no dataset clauses.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Query {
+    pub prologue:     Prologue,
+    pub form:         QueryForm,
+    pub dataset:      Vec<DatasetClause>,
+    /// `None` only for a `DESCRIBE` without a `WHERE` clause
+    pub where_clause: Option<GroupGraphPattern>,
+    pub modifiers:    SolutionModifiers,
+    pub values:       Option<InlineData>,
+}
+
+/// A `GRAPH <iri> { ... }` block or plain triples in quad data or quad
+/// patterns of updates.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum QuadsElement {
+    Triples(Vec<TriplePattern>),
+    Graph { name: TermPattern, triples: Vec<TriplePattern> },
+}
+
+/// The target of `CLEAR` and `DROP`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum GraphTarget {
+    Graph(SparqlIri),
+    Default,
+    Named,
+    All,
+}
+
+/// The source or destination of `ADD`, `MOVE` and `COPY`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum GraphOrDefault {
+    Graph(SparqlIri),
+    Default,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GraphTransfer {
+    Add,
+    Move,
+    Copy,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum UpdateOperation {
+    Load {
+        silent:      bool,
+        source:      SparqlIri,
+        destination: Option<SparqlIri>,
+    },
+    Clear {
+        silent: bool,
+        target: GraphTarget,
+    },
+    Drop {
+        silent: bool,
+        target: GraphTarget,
+    },
+    Create {
+        silent: bool,
+        graph:  SparqlIri,
+    },
+    Transfer {
+        operation:   GraphTransfer,
+        silent:      bool,
+        source:      GraphOrDefault,
+        destination: GraphOrDefault,
+    },
+    InsertData(Vec<QuadsElement>),
+    DeleteData(Vec<QuadsElement>),
+    DeleteWhere(Vec<QuadsElement>),
+    Modify {
+        with:         Option<SparqlIri>,
+        delete:       Vec<QuadsElement>,
+        insert:       Vec<QuadsElement>,
+        using:        Vec<DatasetClause>,
+        where_clause: GroupGraphPattern,
+    },
+}
+
+/// A SPARQL update, i.e. a sequence of operations separated by `;`.
+///
+/// The prologues in between the operations are merged into one.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Update {
+    pub prologue:   Prologue,
+    pub operations: Vec<UpdateOperation>,
+}
+
+impl SparqlStatement {
+    /// Parse and validate the given SPARQL 1.1 query or update.
+    #[cfg(feature = "nom-support")]
+    pub fn parse(sparql: &str) -> Result<Self, RDFStoreError> { super::parser::parse(sparql) }
+
+    pub fn kind(&self) -> SparqlStatementKind {
+        match self {
+            SparqlStatement::Query(query) => query.kind(),
+            SparqlStatement::Update(_) => SparqlStatementKind::Update,
+        }
+    }
+
+    pub fn is_query(&self) -> bool { matches!(self, SparqlStatement::Query(_)) }
+
+    pub fn is_update(&self) -> bool { matches!(self, SparqlStatement::Update(_)) }
+
+    pub fn prologue(&self) -> &Prologue {
+        match self {
+            SparqlStatement::Query(query) => &query.prologue,
+            SparqlStatement::Update(update) => &update.prologue,
+        }
+    }
+
+    /// The variables of the result set of a `SELECT` query, empty for any
+    /// other kind of statement.
+    pub fn projected_variables(&self) -> Vec<Variable> {
+        match self {
+            SparqlStatement::Query(query) => query.projected_variables(),
+            SparqlStatement::Update(_) => Vec::new(),
+        }
+    }
+}
+
+#[cfg(feature = "nom-support")]
+impl FromStr for SparqlStatement {
+    type Err = RDFStoreError;
+
+    fn from_str(str: &str) -> Result<Self, Self::Err> { Self::parse(str) }
+}
+
+impl Prologue {
+    /// The declared prefixes as [`Namespace`]s.
+    pub fn namespaces(&self) -> Result<Vec<Namespace>, RDFStoreError> {
+        self.prefixes
+            .iter()
+            .map(|(prefix, iri)| {
+                Ok(Namespace {
+                    name: format!("{prefix}:"),
+                    iri:  IriBuf::from_str(self.resolve_relative(iri).as_str())?,
+                })
+            })
+            .collect()
+    }
+
+    /// Return the full IRI of the given [`SparqlIri`], expanding prefixed
+    /// names and resolving relative IRIs against the `BASE`.
+    pub fn resolve(&self, iri: &SparqlIri) -> Result<String, RDFStoreError> {
+        match iri {
+            SparqlIri::Iri(iri) => Ok(self.resolve_relative(iri)),
+            SparqlIri::PrefixedName { prefix, local_name } => {
+                let namespace = self
+                    .prefixes
+                    .iter()
+                    .rev()
+                    .find_map(|(name, iri)| (name == prefix).then_some(iri))
+                    .ok_or_else(|| {
+                        RDFStoreError::InvalidPrefixName { name: format!("{prefix}:") }
+                    })?;
+                Ok(format!(
+                    "{}{}",
+                    self.resolve_relative(namespace),
+                    unescape_local_name(local_name)
+                ))
+            },
+        }
+    }
+
+    fn resolve_relative(&self, iri: &str) -> String {
+        crate::iri::resolve_relative(self.base.as_deref(), iri)
+    }
+}
+
+impl Query {
+    pub fn kind(&self) -> SparqlStatementKind {
+        match self.form {
+            QueryForm::Select { .. } => SparqlStatementKind::Select,
+            QueryForm::Construct { .. } => SparqlStatementKind::Construct,
+            QueryForm::Describe { .. } => SparqlStatementKind::Describe,
+            QueryForm::Ask => SparqlStatementKind::Ask,
+        }
+    }
+
+    /// The variables of the result set of a `SELECT` query, in order.
+    /// For `SELECT *` these are the variables that are in scope in the
+    /// `WHERE` clause.
+    pub fn projected_variables(&self) -> Vec<Variable> {
+        match &self.form {
+            QueryForm::Select { projection: Projection::Items(items), .. } => {
+                items
+                    .iter()
+                    .map(|item| {
+                        match item {
+                            ProjectionItem::Variable(variable) => variable.clone(),
+                            ProjectionItem::Expression(_, variable) => variable.clone(),
+                        }
+                    })
+                    .collect()
+            },
+            QueryForm::Select { projection: Projection::All, .. } => {
+                let mut variables = Vec::new();
+                if let Some(pattern) = &self.where_clause {
+                    pattern.collect_in_scope_variables(&mut variables);
+                }
+                if let Some(values) = &self.values {
+                    for variable in values.variables.iter() {
+                        push_unique(&mut variables, variable);
+                    }
+                }
+                variables
+            },
+            _ => Vec::new(),
+        }
+    }
+}
+
+impl GroupGraphPattern {
+    /// Collect the variables that are in scope after evaluating this
+    /// pattern, see
+    /// [Variable Scope](https://www.w3.org/TR/sparql11-query/#variableScope).
+    pub fn collect_in_scope_variables(&self, variables: &mut Vec<Variable>) {
+        match self {
+            GroupGraphPattern::SubSelect(query) => {
+                for variable in query.projected_variables().iter() {
+                    push_unique(variables, variable);
+                }
+            },
+            GroupGraphPattern::Group(elements) => {
+                for element in elements.iter() {
+                    match element {
+                        PatternElement::Triples(triples) => {
+                            for triple in triples.iter() {
+                                triple.collect_variables(variables);
+                            }
+                        },
+                        PatternElement::Optional(pattern) => {
+                            pattern.collect_in_scope_variables(variables)
+                        },
+                        PatternElement::Union(patterns) => {
+                            for pattern in patterns.iter() {
+                                pattern.collect_in_scope_variables(variables)
+                            }
+                        },
+                        PatternElement::Graph { name, pattern } |
+                        PatternElement::Service { name, pattern, .. } => {
+                            if let TermPattern::Variable(variable) = name {
+                                push_unique(variables, variable);
+                            }
+                            pattern.collect_in_scope_variables(variables)
+                        },
+                        PatternElement::Bind { variable, .. } => push_unique(variables, variable),
+                        PatternElement::Values(values) => {
+                            for variable in values.variables.iter() {
+                                push_unique(variables, variable);
+                            }
+                        },
+                        PatternElement::Filter(_) | PatternElement::Minus(_) => {},
+                    }
+                }
+            },
+        }
+    }
+}
+
+impl TriplePattern {
+    fn collect_variables(&self, variables: &mut Vec<Variable>) {
+        if let TermPattern::Variable(variable) = &self.subject {
+            push_unique(variables, variable);
+        }
+        if let Verb::Variable(variable) = &self.predicate {
+            push_unique(variables, variable);
+        }
+        if let TermPattern::Variable(variable) = &self.object {
+            push_unique(variables, variable);
+        }
+    }
+}
+
+impl From<&Term> for TermPattern {
+    fn from(term: &Term) -> Self {
+        match term {
+            Term::Iri(literal) => TermPattern::Iri(SparqlIri::Iri(literal.lexical_form())),
+            Term::BlankNode(literal) => TermPattern::BlankNode(literal.lexical_form()),
+            Term::Literal(literal) => {
+                let lexical_form = literal.lexical_form();
+                match literal.data_type {
+                    DataType::PlainLiteral | DataType::String => {
+                        TermPattern::Literal(SparqlLiteral::new_simple(lexical_form.as_str()))
+                    },
+                    _ => {
+                        TermPattern::Literal(SparqlLiteral::new_typed(
+                            lexical_form.as_str(),
+                            literal.data_type_iri(),
+                        ))
+                    },
+                }
+            },
+        }
+    }
+}
+
+impl SparqlIri {
+    /// Return `true` if this IRI, written in full, is the given IRI.
+    pub fn is(&self, iri: &str) -> bool { matches!(self, SparqlIri::Iri(this) if this == iri) }
+}
+
+impl SparqlLiteral {
+    pub fn new_simple(lexical_form: &str) -> Self {
+        Self {
+            lexical_form: lexical_form.to_string(),
+            language:     None,
+            data_type:    None,
+        }
+    }
+
+    pub fn new_typed(lexical_form: &str, data_type: &str) -> Self {
+        Self {
+            lexical_form: lexical_form.to_string(),
+            language:     None,
+            data_type:    Some(SparqlIri::Iri(data_type.to_string())),
+        }
+    }
+}
+
+fn push_unique(variables: &mut Vec<Variable>, variable: &Variable) {
+    if !variables.contains(variable) {
+        variables.push(variable.clone());
+    }
+}
+
+/// Remove the `\` of the `PN_LOCAL_ESC` escapes in a local name.
+pub(crate) fn unescape_local_name(local_name: &str) -> String {
+    let mut unescaped = String::with_capacity(local_name.len());
+    let mut chars = local_name.chars();
+    while let Some(c) = chars.next() {
+        if c == '\\' {
+            if let Some(escaped) = chars.next() {
+                unescaped.push(escaped);
+            }
+        } else {
+            unescaped.push(c);
+        }
+    }
+    unescaped
+}
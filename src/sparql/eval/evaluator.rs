@@ -0,0 +1,1537 @@
+// Copyright (c) 2018-2023, agnos.ai UK Ltd, all rights reserved.
+//---------------------------------------------------------------
+//! A straightforward evaluator of the SPARQL algebra: group graph patterns
+//! are evaluated element by element with nested loops, there is no query
+//! planning of any kind.
+
+use {
+    super::{
+        functions::{
+            self,
+            boolean_term,
+            effective_boolean_value,
+            order_compare,
+            same_term,
+            values_equal,
+            ArithmeticOperator,
+        },
+        path::{Path, PathEvaluator},
+        Quad,
+        QueryResults,
+        Solution,
+        TripleSource,
+    },
+    crate::{
+        sparql::{
+            AggregateFunction,
+            BinaryOperator,
+            Expression,
+            Function,
+            GroupGraphPattern,
+            InlineData,
+            PatternElement,
+            Projection,
+            ProjectionItem,
+            Prologue,
+            PropertyPath,
+            Query,
+            QueryForm,
+            SelectModifier,
+            SparqlIri,
+            SparqlLiteral,
+            TermPattern,
+            TriplePattern,
+            UnaryOperator,
+            Variable,
+            Verb,
+        },
+        DataType,
+        Literal,
+        RDFStoreError,
+        Term,
+    },
+    std::{
+        cell::{Cell, RefCell},
+        cmp::Ordering,
+    },
+};
+
+const XSD_NS: &str = "http://www.w3.org/2001/XMLSchema#";
+
+/// The graph that triple patterns are matched against.
+#[derive(Debug, Clone)]
+pub(crate) enum ActiveGraph {
+    /// The default graph of the dataset of the query
+    Default,
+    Named(Term),
+}
+
+/// A row that is being built by the solution modifiers of a query: the
+/// solution and, for grouped queries, the solutions of its group.
+type Row = (Solution, Option<Vec<Solution>>);
+
+pub(crate) struct Evaluator<'a, S: TripleSource + ?Sized> {
+    source:               &'a S,
+    prologue:             &'a Prologue,
+    /// The graphs that are merged into the default graph (`FROM`), `None`
+    /// for the default graph of the store.
+    default_graphs:       Option<Vec<Term>>,
+    /// The graphs that `GRAPH` patterns can match
+    named_graphs:         Vec<Term>,
+    blank_nodes:          Cell<usize>,
+    /// The blank nodes created by `BNODE` with a string, with the solution
+    /// and the string that they were created for.
+    labelled_blank_nodes: RefCell<Vec<(Solution, String, Term)>>,
+    now:                  Term,
+}
+
+impl<'a, S: TripleSource + ?Sized> Evaluator<'a, S> {
+    pub(crate) fn new(source: &'a S, query: &'a Query) -> Result<Self, RDFStoreError> {
+        let mut default_graphs = Vec::new();
+        let mut named_graphs = Vec::new();
+        for clause in query.dataset.iter() {
+            let graph = Term::new_iri_from_str(query.prologue.resolve(&clause.iri)?.as_str())?;
+            if clause.named {
+                named_graphs.push(graph);
+            } else {
+                default_graphs.push(graph);
+            }
+        }
+        if query.dataset.is_empty() {
+            named_graphs = source.graph_names();
+        }
+        Ok(Self {
+            source,
+            prologue: &query.prologue,
+            default_graphs: (!default_graphs.is_empty()).then_some(default_graphs),
+            named_graphs,
+            blank_nodes: Cell::new(0),
+            labelled_blank_nodes: RefCell::new(Vec::new()),
+            now: Literal::new_date_time_with_datatype(chrono::Utc::now(), DataType::DateTime)?
+                .as_term(),
+        })
+    }
+
+    pub(crate) fn evaluate_query(&self, query: &Query) -> Result<QueryResults, RDFStoreError> {
+        let solutions = self.solutions(query, &ActiveGraph::Default)?;
+        match &query.form {
+            QueryForm::Select { .. } => {
+                Ok(QueryResults::Solutions { variables: query.projected_variables(), solutions })
+            },
+            QueryForm::Ask => Ok(QueryResults::Boolean(!solutions.is_empty())),
+            QueryForm::Construct { template } => {
+                let where_triples;
+                let template = match template {
+                    Some(template) => template,
+                    None => {
+                        where_triples = triples_of(query.where_clause.as_ref());
+                        &where_triples
+                    },
+                };
+                Ok(QueryResults::Graph(
+                    self.construct(template, &solutions)?,
+                ))
+            },
+            QueryForm::Describe { resources } => {
+                Ok(QueryResults::Graph(
+                    self.describe(resources, &solutions)?,
+                ))
+            },
+        }
+    }
+
+    /// Evaluate the `WHERE` clause of the given query and apply its solution
+    /// modifiers.
+    fn solutions(
+        &self,
+        query: &Query,
+        graph: &ActiveGraph,
+    ) -> Result<Vec<Solution>, RDFStoreError> {
+        let mut solutions = match &query.where_clause {
+            Some(pattern) => self.eval_group(pattern, graph)?,
+            None => vec![Solution::new()],
+        };
+        if let Some(values) = &query.values {
+            solutions = join(&solutions, &self.inline_data(values)?);
+        }
+
+        let modifiers = &query.modifiers;
+        let projection_items: &[ProjectionItem] = match &query.form {
+            QueryForm::Select { projection: Projection::Items(items), .. } => items.as_slice(),
+            _ => &[],
+        };
+        let is_grouped = !modifiers.group_by.is_empty() ||
+            projection_items.iter().any(|item| {
+                matches!(item, ProjectionItem::Expression(expression, _) if has_aggregate(expression))
+            }) ||
+            modifiers.having.iter().any(has_aggregate) ||
+            modifiers
+                .order_by
+                .iter()
+                .any(|condition| has_aggregate(&condition.expression));
+
+        let mut rows: Vec<Row> = if is_grouped {
+            self.group(solutions, query, graph)?
+        } else {
+            solutions
+                .into_iter()
+                .map(|solution| (solution, None))
+                .collect()
+        };
+
+        for item in projection_items.iter() {
+            if let ProjectionItem::Expression(expression, variable) = item {
+                for (solution, group) in rows.iter_mut() {
+                    if let Some(term) =
+                        self.eval_expression(expression, solution, graph, group.as_deref())?
+                    {
+                        solution.insert(variable.clone(), term);
+                    }
+                }
+            }
+        }
+
+        for condition in modifiers.having.iter() {
+            let mut kept = Vec::with_capacity(rows.len());
+            for (solution, group) in rows.into_iter() {
+                if self.eval_condition(condition, &solution, graph, group.as_deref())? {
+                    kept.push((solution, group));
+                }
+            }
+            rows = kept;
+        }
+
+        if !modifiers.order_by.is_empty() {
+            let mut keyed = Vec::with_capacity(rows.len());
+            for (solution, group) in rows.into_iter() {
+                let mut keys = Vec::with_capacity(modifiers.order_by.len());
+                for condition in modifiers.order_by.iter() {
+                    keys.push(self.eval_expression(
+                        &condition.expression,
+                        &solution,
+                        graph,
+                        group.as_deref(),
+                    )?);
+                }
+                keyed.push((keys, solution));
+            }
+            keyed.sort_by(|(a, _), (b, _)| {
+                modifiers
+                    .order_by
+                    .iter()
+                    .zip(a.iter().zip(b.iter()))
+                    .map(|(condition, (a, b))| {
+                        let ordering = order_compare(a.as_ref(), b.as_ref());
+                        if condition.descending {
+                            ordering.reverse()
+                        } else {
+                            ordering
+                        }
+                    })
+                    .find(|ordering| *ordering != Ordering::Equal)
+                    .unwrap_or(Ordering::Equal)
+            });
+            rows = keyed
+                .into_iter()
+                .map(|(_, solution)| (solution, None))
+                .collect();
+        }
+
+        let mut solutions: Vec<Solution> = match &query.form {
+            QueryForm::Select { modifier, .. } => {
+                let variables = query.projected_variables();
+                let mut solutions: Vec<Solution> = rows
+                    .into_iter()
+                    .map(|(solution, _)| solution.project(variables.as_slice()))
+                    .collect();
+                if *modifier != SelectModifier::None {
+                    let mut distinct: Vec<Solution> = Vec::with_capacity(solutions.len());
+                    for solution in solutions.into_iter() {
+                        if !distinct.contains(&solution) {
+                            distinct.push(solution);
+                        }
+                    }
+                    solutions = distinct;
+                }
+                solutions
+            },
+            _ => rows.into_iter().map(|(solution, _)| solution).collect(),
+        };
+
+        let offset = modifiers.offset.unwrap_or(0) as usize;
+        solutions.drain(..offset.min(solutions.len()));
+        if let Some(limit) = modifiers.limit {
+            solutions.truncate(limit as usize);
+        }
+        Ok(solutions)
+    }
+
+    /// Partition the solutions into groups, as per `GROUP BY`. A grouped
+    /// query without `GROUP BY` has exactly one group.
+    fn group(
+        &self,
+        solutions: Vec<Solution>,
+        query: &Query,
+        graph: &ActiveGraph,
+    ) -> Result<Vec<Row>, RDFStoreError> {
+        let conditions = &query.modifiers.group_by;
+        if conditions.is_empty() {
+            return Ok(vec![(Solution::new(), Some(solutions))])
+        }
+        let mut groups: Vec<(Vec<Option<Term>>, Solution, Vec<Solution>)> = Vec::new();
+        for solution in solutions.into_iter() {
+            let mut key = Vec::with_capacity(conditions.len());
+            let mut group_solution = Solution::new();
+            for condition in conditions.iter() {
+                let term = self.eval_expression(&condition.expression, &solution, graph, None)?;
+                let variable = match (&condition.variable, &condition.expression) {
+                    (Some(variable), _) => Some(variable),
+                    (None, Expression::Term(TermPattern::Variable(variable))) => Some(variable),
+                    _ => None,
+                };
+                if let (Some(variable), Some(term)) = (variable, &term) {
+                    group_solution.insert(variable.clone(), term.clone());
+                }
+                key.push(term);
+            }
+            match groups.iter_mut().find(|(this, ..)| *this == key) {
+                Some((_, _, members)) => members.push(solution),
+                None => groups.push((key, group_solution, vec![solution])),
+            }
+        }
+        Ok(groups
+            .into_iter()
+            .map(|(_, solution, members)| (solution, Some(members)))
+            .collect())
+    }
+
+    fn construct(
+        &self,
+        template: &[TriplePattern],
+        solutions: &[Solution],
+    ) -> Result<Vec<Quad>, RDFStoreError> {
+        let mut triples: Vec<Quad> = Vec::new();
+        for solution in solutions.iter() {
+            // Blank nodes in the template are fresh for every solution
+            let mut blank_nodes: Vec<(String, Term)> = Vec::new();
+            let mut instantiate = |pattern: &TermPattern| -> Result<Option<Term>, RDFStoreError> {
+                match pattern {
+                    TermPattern::BlankNode(label) => {
+                        if let Some((_, term)) = blank_nodes.iter().find(|(this, _)| this == label)
+                        {
+                            return Ok(Some(term.clone()))
+                        }
+                        let term = self.new_blank_node()?;
+                        blank_nodes.push((label.clone(), term.clone()));
+                        Ok(Some(term))
+                    },
+                    pattern => self.instantiate(pattern, solution),
+                }
+            };
+            for triple in template.iter() {
+                let subject = instantiate(&triple.subject)?;
+                let predicate = match &triple.predicate {
+                    Verb::Variable(variable) => solution.get_variable(variable).cloned(),
+                    Verb::Path(PropertyPath::Iri(iri)) => Some(self.iri_term(iri)?),
+                    Verb::Path(_) => None,
+                };
+                let object = instantiate(&triple.object)?;
+                if let (Some(subject), Some(predicate @ Term::Iri(_)), Some(object)) =
+                    (subject, predicate, object)
+                {
+                    if matches!(subject, Term::Literal(_)) {
+                        continue
+                    }
+                    let quad = Quad::new(subject, predicate, object, None);
+                    if !triples.contains(&quad) {
+                        triples.push(quad);
+                    }
+                }
+            }
+        }
+        Ok(triples)
+    }
+
+    /// The triples of the default graph that have one of the given resources
+    /// (or, for `DESCRIBE *`, any of the bound terms) as subject.
+    fn describe(
+        &self,
+        resources: &[TermPattern],
+        solutions: &[Solution],
+    ) -> Result<Vec<Quad>, RDFStoreError> {
+        let mut terms: Vec<Term> = Vec::new();
+        for resource in resources.iter() {
+            match resource {
+                TermPattern::Variable(variable) => {
+                    terms.extend(
+                        solutions
+                            .iter()
+                            .filter_map(|solution| solution.get_variable(variable).cloned()),
+                    )
+                },
+                resource => terms.extend(self.instantiate(resource, &Solution::new())?),
+            }
+        }
+        if resources.is_empty() {
+            for solution in solutions.iter() {
+                terms.extend(solution.iter().map(|(_, term)| term.clone()));
+            }
+        }
+        let mut triples: Vec<Quad> = Vec::new();
+        for term in terms
+            .iter()
+            .filter(|term| !matches!(term, Term::Literal(_)))
+        {
+            for [subject, predicate, object] in
+                self.triples(&ActiveGraph::Default, Some(term), None, None)
+            {
+                let quad = Quad::new(subject, predicate, object, None);
+                if !triples.contains(&quad) {
+                    triples.push(quad);
+                }
+            }
+        }
+        Ok(triples)
+    }
+
+    fn eval_group(
+        &self,
+        pattern: &GroupGraphPattern,
+        graph: &ActiveGraph,
+    ) -> Result<Vec<Solution>, RDFStoreError> {
+        self.eval_group_from(pattern, graph, vec![Solution::new()])
+    }
+
+    /// Evaluate the given group starting from the given solutions, which is
+    /// how `EXISTS` substitutes the bindings of the solution being filtered.
+    fn eval_group_from(
+        &self,
+        pattern: &GroupGraphPattern,
+        graph: &ActiveGraph,
+        seed: Vec<Solution>,
+    ) -> Result<Vec<Solution>, RDFStoreError> {
+        let (solutions, filters) = self.eval_group_unfiltered(pattern, graph, seed)?;
+        self.filter(solutions, filters.as_slice(), graph)
+    }
+
+    /// Evaluate the given group but return its filters rather than applying
+    /// them, so that `OPTIONAL` can apply them to the joined solutions.
+    fn eval_group_unfiltered<'p>(
+        &self,
+        pattern: &'p GroupGraphPattern,
+        graph: &ActiveGraph,
+        seed: Vec<Solution>,
+    ) -> Result<(Vec<Solution>, Vec<&'p Expression>), RDFStoreError> {
+        let elements = match pattern {
+            GroupGraphPattern::SubSelect(query) => {
+                let solutions = self.solutions(query, graph)?;
+                return Ok((join(&seed, &solutions), Vec::new()))
+            },
+            GroupGraphPattern::Group(elements) => elements,
+        };
+        let mut solutions = seed;
+        let mut filters = Vec::new();
+        for element in elements.iter() {
+            solutions = match element {
+                PatternElement::Triples(triples) => self.eval_triples(triples, graph, solutions)?,
+                PatternElement::Filter(expression) => {
+                    filters.push(expression);
+                    solutions
+                },
+                PatternElement::Optional(pattern) => {
+                    let (right, filters) =
+                        self.eval_group_unfiltered(pattern, graph, vec![Solution::new()])?;
+                    let mut joined = Vec::with_capacity(solutions.len());
+                    for left in solutions.iter() {
+                        let mut matched = false;
+                        for right in right.iter().filter(|right| left.is_compatible(right)) {
+                            let merged = left.merge(right);
+                            if self.passes(&merged, filters.as_slice(), graph)? {
+                                joined.push(merged);
+                                matched = true;
+                            }
+                        }
+                        if !matched {
+                            joined.push(left.clone());
+                        }
+                    }
+                    joined
+                },
+                PatternElement::Minus(pattern) => {
+                    let right = self.eval_group(pattern, graph)?;
+                    solutions
+                        .into_iter()
+                        .filter(|left| {
+                            !right.iter().any(|right| {
+                                left.shares_variables(right) && left.is_compatible(right)
+                            })
+                        })
+                        .collect()
+                },
+                PatternElement::Union(patterns) => {
+                    let mut union = Vec::new();
+                    for pattern in patterns.iter() {
+                        union.extend(self.eval_group(pattern, graph)?);
+                    }
+                    join(&solutions, &union)
+                },
+                PatternElement::Graph { name, pattern } => {
+                    let graphs = match name {
+                        TermPattern::Variable(_) | TermPattern::BlankNode(_) => {
+                            self.named_graphs.clone()
+                        },
+                        name => {
+                            self.instantiate(name, &Solution::new())?
+                                .into_iter()
+                                .filter(|graph| {
+                                    self.named_graphs.iter().any(|this| same_term(this, graph))
+                                })
+                                .collect()
+                        },
+                    };
+                    let mut matches = Vec::new();
+                    for graph in graphs.into_iter() {
+                        let mut binding = Solution::new();
+                        if let Some(variable) = pattern_variable(name) {
+                            binding.insert(variable, graph.clone());
+                        }
+                        for solution in self.eval_group(pattern, &ActiveGraph::Named(graph))? {
+                            if solution.is_compatible(&binding) {
+                                matches.push(solution.merge(&binding));
+                            }
+                        }
+                    }
+                    join(&solutions, &matches)
+                },
+                PatternElement::Service { silent: true, .. } => solutions,
+                PatternElement::Service { .. } => {
+                    return Err(RDFStoreError::UnsupportedSparqlFeature {
+                        feature: "SERVICE".to_string(),
+                    })
+                },
+                PatternElement::Bind { expression, variable } => {
+                    let mut extended = Vec::with_capacity(solutions.len());
+                    for mut solution in solutions.into_iter() {
+                        if let Some(term) =
+                            self.eval_expression(expression, &solution, graph, None)?
+                        {
+                            solution.insert(variable.clone(), term);
+                        }
+                        extended.push(solution);
+                    }
+                    extended
+                },
+                PatternElement::Values(values) => join(&solutions, &self.inline_data(values)?),
+            };
+        }
+        Ok((solutions, filters))
+    }
+
+    fn filter(
+        &self,
+        solutions: Vec<Solution>,
+        filters: &[&Expression],
+        graph: &ActiveGraph,
+    ) -> Result<Vec<Solution>, RDFStoreError> {
+        if filters.is_empty() {
+            return Ok(solutions)
+        }
+        let mut kept = Vec::with_capacity(solutions.len());
+        for solution in solutions.into_iter() {
+            if self.passes(&solution, filters, graph)? {
+                kept.push(solution);
+            }
+        }
+        Ok(kept)
+    }
+
+    fn passes(
+        &self,
+        solution: &Solution,
+        filters: &[&Expression],
+        graph: &ActiveGraph,
+    ) -> Result<bool, RDFStoreError> {
+        for filter in filters.iter() {
+            if !self.eval_condition(filter, solution, graph, None)? {
+                return Ok(false)
+            }
+        }
+        Ok(true)
+    }
+
+    /// Evaluate a basic graph pattern by extending each solution with the
+    /// matches of each triple pattern in turn.
+    fn eval_triples(
+        &self,
+        triples: &[TriplePattern],
+        graph: &ActiveGraph,
+        mut solutions: Vec<Solution>,
+    ) -> Result<Vec<Solution>, RDFStoreError> {
+        for triple in triples.iter() {
+            // Plain predicates are matched directly, other paths are evaluated
+            // as a whole
+            let (predicate, path) = match &triple.predicate {
+                Verb::Variable(variable) => {
+                    (
+                        Some(TermPattern::Variable(variable.clone())),
+                        None,
+                    )
+                },
+                Verb::Path(PropertyPath::Iri(iri)) => (Some(TermPattern::Iri(iri.clone())), None),
+                Verb::Path(path) => (None, Some(self.resolve_path(path)?)),
+            };
+            let patterns: Vec<&TermPattern> = match &predicate {
+                Some(predicate) => vec![&triple.subject, predicate, &triple.object],
+                None => vec![&triple.subject, &triple.object],
+            };
+            let mut extended = Vec::new();
+            for solution in solutions.iter() {
+                let mut bound: Vec<Option<Term>> = Vec::with_capacity(patterns.len());
+                let mut resolvable = true;
+                for pattern in patterns.iter() {
+                    match pattern_variable(pattern) {
+                        Some(variable) => bound.push(solution.get_variable(&variable).cloned()),
+                        None => {
+                            match self.instantiate(pattern, solution) {
+                                Ok(Some(term)) => bound.push(Some(term)),
+                                Err(error @ RDFStoreError::UnsupportedSparqlFeature { .. }) => {
+                                    return Err(error)
+                                },
+                                // A term that cannot exist in the store
+                                _ => resolvable = false,
+                            }
+                        },
+                    }
+                }
+                if !resolvable {
+                    continue
+                }
+                let matches: Vec<Vec<Term>> = match (&path, bound.as_slice()) {
+                    (None, [subject, predicate, object]) => {
+                        self.triples(
+                            graph,
+                            subject.as_ref(),
+                            predicate.as_ref(),
+                            object.as_ref(),
+                        )
+                        .into_iter()
+                        .map(Vec::from)
+                        .collect()
+                    },
+                    (Some(path), [subject, object]) => {
+                        let lookup = |subject: Option<&Term>,
+                                      predicate: Option<&Term>,
+                                      object: Option<&Term>| {
+                            self.triples(graph, subject, predicate, object)
+                        };
+                        PathEvaluator::new(&lookup)
+                            .pairs(path, subject.as_ref(), object.as_ref())
+                            .into_iter()
+                            .map(|(subject, object)| vec![subject, object])
+                            .collect()
+                    },
+                    _ => Vec::new(),
+                };
+                'matches: for terms in matches.into_iter() {
+                    let mut solution = solution.clone();
+                    for (pattern, term) in patterns.iter().zip(terms) {
+                        if let Some(variable) = pattern_variable(pattern) {
+                            match solution.get_variable(&variable) {
+                                Some(bound) if !same_term(bound, &term) => continue 'matches,
+                                Some(_) => {},
+                                None => {
+                                    solution.insert(variable, term);
+                                },
+                            }
+                        }
+                    }
+                    extended.push(solution);
+                }
+            }
+            solutions = extended;
+        }
+        Ok(solutions)
+    }
+
+    fn resolve_path(&self, path: &PropertyPath) -> Result<Path, RDFStoreError> {
+        let resolve_all = |paths: &[PropertyPath]| -> Result<Vec<Path>, RDFStoreError> {
+            paths.iter().map(|path| self.resolve_path(path)).collect()
+        };
+        Ok(match path {
+            PropertyPath::Iri(iri) => Path::Predicate(self.iri_term(iri)?),
+            PropertyPath::Inverse(path) => Path::Inverse(Box::new(self.resolve_path(path)?)),
+            PropertyPath::Sequence(paths) => Path::Sequence(resolve_all(paths)?),
+            PropertyPath::Alternative(paths) => Path::Alternative(resolve_all(paths)?),
+            PropertyPath::ZeroOrMore(path) => Path::ZeroOrMore(Box::new(self.resolve_path(path)?)),
+            PropertyPath::OneOrMore(path) => Path::OneOrMore(Box::new(self.resolve_path(path)?)),
+            PropertyPath::ZeroOrOne(path) => Path::ZeroOrOne(Box::new(self.resolve_path(path)?)),
+            PropertyPath::NegatedPropertySet(iris) => {
+                let mut forward = Vec::new();
+                let mut inverse = Vec::new();
+                for (iri, inverted) in iris.iter() {
+                    if *inverted {
+                        inverse.push(self.iri_term(iri)?);
+                    } else {
+                        forward.push(self.iri_term(iri)?);
+                    }
+                }
+                Path::NegatedPropertySet { forward, inverse }
+            },
+        })
+    }
+
+    /// The triples of the given graph that match the given terms, where
+    /// `None` matches anything.
+    fn triples(
+        &self,
+        graph: &ActiveGraph,
+        subject: Option<&Term>,
+        predicate: Option<&Term>,
+        object: Option<&Term>,
+    ) -> Vec<[Term; 3]> {
+        match (graph, &self.default_graphs) {
+            (ActiveGraph::Named(graph), _) => {
+                self.source
+                    .triples_matching(subject, predicate, object, Some(graph))
+            },
+            (ActiveGraph::Default, None) => {
+                self.source
+                    .triples_matching(subject, predicate, object, None)
+            },
+            (ActiveGraph::Default, Some(graphs)) => {
+                // The merge of the `FROM` graphs, without duplicates
+                let mut triples: Vec<[Term; 3]> = Vec::new();
+                for graph in graphs.iter() {
+                    for triple in
+                        self.source
+                            .triples_matching(subject, predicate, object, Some(graph))
+                    {
+                        if !triples.contains(&triple) {
+                            triples.push(triple);
+                        }
+                    }
+                }
+                triples
+            },
+        }
+    }
+
+    fn inline_data(&self, values: &InlineData) -> Result<Vec<Solution>, RDFStoreError> {
+        let mut solutions = Vec::with_capacity(values.rows.len());
+        for row in values.rows.iter() {
+            let mut solution = Solution::new();
+            for (variable, value) in values.variables.iter().zip(row.iter()) {
+                if let Some(value) = value {
+                    if let Some(term) = self.instantiate(value, &Solution::new())? {
+                        solution.insert(variable.clone(), term);
+                    }
+                }
+            }
+            solutions.push(solution);
+        }
+        Ok(solutions)
+    }
+
+    /// The term that the given pattern stands for in the given solution,
+    /// `None` for an unbound variable.
+    fn instantiate(
+        &self,
+        pattern: &TermPattern,
+        solution: &Solution,
+    ) -> Result<Option<Term>, RDFStoreError> {
+        match pattern {
+            TermPattern::Variable(variable) => Ok(solution.get_variable(variable).cloned()),
+            TermPattern::BlankNode(label) => {
+                Ok(solution.get_variable(&blank_node_variable(label)).cloned())
+            },
+            TermPattern::Iri(iri) => self.iri_term(iri).map(Some),
+            TermPattern::Literal(literal) => self.literal_term(literal).map(Some),
+        }
+    }
+
+    fn iri_term(&self, iri: &SparqlIri) -> Result<Term, RDFStoreError> {
+        Term::new_iri_from_str(self.prologue.resolve(iri)?.as_str())
+    }
+
+    /// Language tags are not supported by [`Literal`], so language tagged
+    /// strings are rejected rather than matched as plain strings.
+    fn literal_term(&self, literal: &SparqlLiteral) -> Result<Term, RDFStoreError> {
+        if let Some(language) = &literal.language {
+            return Err(RDFStoreError::UnsupportedSparqlFeature {
+                feature: format!(
+                    "language tagged literal \"{}\"@{language}",
+                    literal.lexical_form
+                ),
+            })
+        }
+        let lexical_form = literal.lexical_form.as_str();
+        match &literal.data_type {
+            Some(iri) => {
+                Literal::from_data_type_iri(self.prologue.resolve(iri)?.as_str(), lexical_form)
+            },
+            None => Literal::from_lexical_form(DataType::PlainLiteral, lexical_form),
+        }
+        .map(|literal| literal.as_term())
+    }
+
+    fn new_blank_node(&self) -> Result<Term, RDFStoreError> {
+        let id = self.blank_nodes.get() + 1;
+        self.blank_nodes.set(id);
+        Term::new_blank_node(format!("b{id}").as_str())
+    }
+
+    /// The blank node of `BNODE` with the given string, which is the same
+    /// for the same string within one solution. The variables that earlier
+    /// calls bound to such blank nodes (with `BIND` or in the projection)
+    /// do not make it another solution.
+    fn labelled_blank_node(&self, solution: &Solution, label: &str) -> Result<Term, RDFStoreError> {
+        let mut key = Solution::new();
+        {
+            let blank_nodes = self.labelled_blank_nodes.borrow();
+            for (variable, term) in solution.iter() {
+                if !blank_nodes.iter().any(|(.., this)| this == term) {
+                    key.insert(variable.clone(), term.clone());
+                }
+            }
+            if let Some((.., term)) = blank_nodes
+                .iter()
+                .find(|(solution, this, _)| this == label && *solution == key)
+            {
+                return Ok(term.clone())
+            }
+        }
+        let term = self.new_blank_node()?;
+        self.labelled_blank_nodes
+            .borrow_mut()
+            .push((key, label.to_string(), term.clone()));
+        Ok(term)
+    }
+
+    /// Evaluate a `FILTER` or `HAVING` condition, an error counts as `false`.
+    fn eval_condition(
+        &self,
+        expression: &Expression,
+        solution: &Solution,
+        graph: &ActiveGraph,
+        group: Option<&[Solution]>,
+    ) -> Result<bool, RDFStoreError> {
+        Ok(self
+            .eval_expression(expression, solution, graph, group)?
+            .and_then(|term| effective_boolean_value(&term))
+            .unwrap_or(false))
+    }
+
+    /// Evaluate an expression, returns `None` for a SPARQL expression error
+    /// (such as an unbound variable or a type error) and only fails for
+    /// features that are not supported.
+    fn eval_expression(
+        &self,
+        expression: &Expression,
+        solution: &Solution,
+        graph: &ActiveGraph,
+        group: Option<&[Solution]>,
+    ) -> Result<Option<Term>, RDFStoreError> {
+        let eval =
+            |expression: &Expression| self.eval_expression(expression, solution, graph, group);
+        let ebv = |expression: &Expression| -> Result<Option<bool>, RDFStoreError> {
+            Ok(eval(expression)?.and_then(|term| effective_boolean_value(&term)))
+        };
+        let term = match expression {
+            Expression::Term(TermPattern::Literal(literal)) => {
+                match self.literal_term(literal) {
+                    Ok(term) => Some(term),
+                    Err(error @ RDFStoreError::UnsupportedSparqlFeature { .. }) => {
+                        return Err(error)
+                    },
+                    // An ill-typed literal such as "abc"^^xsd:integer
+                    Err(_) => None,
+                }
+            },
+            Expression::Term(pattern) => self.instantiate(pattern, solution)?,
+            Expression::Binary(BinaryOperator::Or, left, right) => {
+                match (ebv(left)?, ebv(right)?) {
+                    (Some(true), _) | (_, Some(true)) => boolean_term(true),
+                    (Some(false), Some(false)) => boolean_term(false),
+                    _ => None,
+                }
+            },
+            Expression::Binary(BinaryOperator::And, left, right) => {
+                match (ebv(left)?, ebv(right)?) {
+                    (Some(false), _) | (_, Some(false)) => boolean_term(false),
+                    (Some(true), Some(true)) => boolean_term(true),
+                    _ => None,
+                }
+            },
+            Expression::Binary(operator, left, right) => {
+                match (eval(left)?, eval(right)?) {
+                    (Some(left), Some(right)) => binary(*operator, &left, &right),
+                    _ => None,
+                }
+            },
+            Expression::Unary(operator, operand) => {
+                match operator {
+                    UnaryOperator::Not => ebv(operand)?.and_then(|value| boolean_term(!value)),
+                    UnaryOperator::Plus => eval(operand)?.filter(functions::is_numeric),
+                    UnaryOperator::Minus => {
+                        eval(operand)?.and_then(|term| functions::negate(&term))
+                    },
+                }
+            },
+            Expression::In { expression, list, negated } => {
+                match eval(expression)? {
+                    Some(term) => {
+                        let mut found = false;
+                        let mut error = false;
+                        for item in list.iter() {
+                            match eval(item)?.and_then(|item| values_equal(&term, &item)) {
+                                Some(true) => {
+                                    found = true;
+                                    break
+                                },
+                                Some(false) => {},
+                                None => error = true,
+                            }
+                        }
+                        if found {
+                            boolean_term(!negated)
+                        } else if error {
+                            None
+                        } else {
+                            boolean_term(*negated)
+                        }
+                    },
+                    None => None,
+                }
+            },
+            Expression::Exists { pattern, negated } => {
+                let solutions = self.eval_group_from(pattern, graph, vec![solution.clone()])?;
+                boolean_term(solutions.is_empty() == *negated)
+            },
+            Expression::Aggregate { function, distinct, expression, separator } => {
+                let group = group.ok_or_else(|| {
+                    RDFStoreError::UnsupportedSparqlFeature {
+                        feature: "aggregates outside of a grouped query".to_string(),
+                    }
+                })?;
+                self.aggregate(
+                    *function,
+                    *distinct,
+                    expression.as_deref(),
+                    separator.as_deref(),
+                    group,
+                    graph,
+                )?
+            },
+            Expression::FunctionCall { function: Function::BuiltIn(name), arguments, .. } => {
+                match name.as_str() {
+                    "BOUND" => {
+                        match arguments.first() {
+                            Some(Expression::Term(TermPattern::Variable(variable))) => {
+                                boolean_term(solution.get_variable(variable).is_some())
+                            },
+                            _ => None,
+                        }
+                    },
+                    "IF" => {
+                        match (
+                            arguments.first().map(ebv).transpose()?.flatten(),
+                            arguments.get(1),
+                            arguments.get(2),
+                        ) {
+                            (Some(true), Some(then), _) => eval(then)?,
+                            (Some(false), _, Some(otherwise)) => eval(otherwise)?,
+                            _ => None,
+                        }
+                    },
+                    "COALESCE" => {
+                        let mut first = None;
+                        for argument in arguments.iter() {
+                            if let Some(term) = eval(argument)? {
+                                first = Some(term);
+                                break
+                            }
+                        }
+                        first
+                    },
+                    "BNODE" => {
+                        match arguments.first() {
+                            Some(argument) => {
+                                match eval(argument)?.as_ref().and_then(functions::string) {
+                                    Some(label) => Some(self.labelled_blank_node(solution, label)?),
+                                    None => None,
+                                }
+                            },
+                            None => Some(self.new_blank_node()?),
+                        }
+                    },
+                    "NOW" => Some(self.now.clone()),
+                    name => {
+                        let mut values = Vec::with_capacity(arguments.len());
+                        for argument in arguments.iter() {
+                            match eval(argument)? {
+                                Some(term) => values.push(term),
+                                None => return Ok(None),
+                            }
+                        }
+                        functions::call_built_in(name, values.as_slice())?
+                    },
+                }
+            },
+            Expression::FunctionCall { function: Function::Iri(iri), arguments, .. } => {
+                let iri = self.prologue.resolve(iri)?;
+                let data_type = match DataType::from_xsd_iri(iri.as_str()) {
+                    Ok(data_type) if iri.starts_with(XSD_NS) => data_type,
+                    _ => {
+                        return Err(RDFStoreError::UnsupportedSparqlFeature {
+                            feature: format!("function <{iri}>"),
+                        })
+                    },
+                };
+                match arguments.first() {
+                    Some(argument) if arguments.len() == 1 => {
+                        eval(argument)?.and_then(|term| functions::cast_to(&term, data_type))
+                    },
+                    _ => None,
+                }
+            },
+        };
+        Ok(term)
+    }
+
+    fn aggregate(
+        &self,
+        function: AggregateFunction,
+        distinct: bool,
+        expression: Option<&Expression>,
+        separator: Option<&str>,
+        group: &[Solution],
+        graph: &ActiveGraph,
+    ) -> Result<Option<Term>, RDFStoreError> {
+        let expression = match expression {
+            Some(expression) => expression,
+            // COUNT(*)
+            None => {
+                let count = if distinct {
+                    let mut solutions: Vec<&Solution> = Vec::new();
+                    for solution in group.iter() {
+                        if !solutions.contains(&solution) {
+                            solutions.push(solution);
+                        }
+                    }
+                    solutions.len()
+                } else {
+                    group.len()
+                };
+                return Ok(functions::integer_term(count as i64))
+            },
+        };
+        let mut values: Vec<Option<Term>> = Vec::with_capacity(group.len());
+        for solution in group.iter() {
+            let value = self.eval_expression(expression, solution, graph, None)?;
+            if !distinct || !values.contains(&value) {
+                values.push(value);
+            }
+        }
+        let bound = || values.iter().flatten();
+        let sum = || {
+            bound().try_fold(functions::integer_term(0)?, |sum, term| {
+                functions::arithmetic(ArithmeticOperator::Add, &sum, term)
+            })
+        };
+        // Unbound values (and errors) are skipped by all aggregates
+        let result = match function {
+            AggregateFunction::Count => functions::integer_term(bound().count() as i64),
+            AggregateFunction::Sum => sum(),
+            AggregateFunction::Avg => {
+                match bound().count() {
+                    0 => functions::integer_term(0),
+                    count => {
+                        sum().and_then(|sum| {
+                            functions::arithmetic(
+                                ArithmeticOperator::Divide,
+                                &sum,
+                                &functions::integer_term(count as i64)?,
+                            )
+                        })
+                    },
+                }
+            },
+            AggregateFunction::Min => {
+                bound()
+                    .min_by(|a, b| order_compare(Some(a), Some(b)))
+                    .cloned()
+            },
+            AggregateFunction::Max => {
+                bound()
+                    .max_by(|a, b| order_compare(Some(a), Some(b)))
+                    .cloned()
+            },
+            AggregateFunction::Sample => bound().next().cloned(),
+            AggregateFunction::GroupConcat => {
+                bound()
+                    .map(functions::str)
+                    .collect::<Option<Vec<_>>>()
+                    .and_then(|strings| {
+                        functions::string_term(strings.join(separator.unwrap_or(" ")).as_str())
+                    })
+            },
+        };
+        Ok(result)
+    }
+}
+
+fn binary(operator: BinaryOperator, left: &Term, right: &Term) -> Option<Term> {
+    let compare = |test: fn(Ordering) -> bool| {
+        functions::compare_values(left, right).and_then(|ordering| boolean_term(test(ordering)))
+    };
+    match operator {
+        BinaryOperator::Equal => values_equal(left, right).and_then(boolean_term),
+        BinaryOperator::NotEqual => {
+            values_equal(left, right).and_then(|equal| boolean_term(!equal))
+        },
+        BinaryOperator::Less => compare(|ordering| ordering == Ordering::Less),
+        BinaryOperator::Greater => compare(|ordering| ordering == Ordering::Greater),
+        BinaryOperator::LessOrEqual => compare(|ordering| ordering != Ordering::Greater),
+        BinaryOperator::GreaterOrEqual => compare(|ordering| ordering != Ordering::Less),
+        BinaryOperator::Add => functions::arithmetic(ArithmeticOperator::Add, left, right),
+        BinaryOperator::Subtract => {
+            functions::arithmetic(ArithmeticOperator::Subtract, left, right)
+        },
+        BinaryOperator::Multiply => {
+            functions::arithmetic(ArithmeticOperator::Multiply, left, right)
+        },
+        BinaryOperator::Divide => functions::arithmetic(ArithmeticOperator::Divide, left, right),
+        // Handled with three-valued logic by the caller
+        BinaryOperator::Or | BinaryOperator::And => None,
+    }
+}
+
+/// The join of two sequences of solutions: all merges of compatible pairs.
+fn join(left: &[Solution], right: &[Solution]) -> Vec<Solution> {
+    let mut joined = Vec::new();
+    for left in left.iter() {
+        for right in right.iter().filter(|right| left.is_compatible(right)) {
+            joined.push(left.merge(right));
+        }
+    }
+    joined
+}
+
+/// Blank nodes in patterns behave like variables that cannot be projected.
+fn blank_node_variable(label: &str) -> Variable { Variable(format!("_:{label}")) }
+
+fn pattern_variable(pattern: &TermPattern) -> Option<Variable> {
+    match pattern {
+        TermPattern::Variable(variable) => Some(variable.clone()),
+        TermPattern::BlankNode(label) => Some(blank_node_variable(label)),
+        _ => None,
+    }
+}
+
+fn has_aggregate(expression: &Expression) -> bool {
+    match expression {
+        Expression::Aggregate { .. } => true,
+        Expression::Term(_) | Expression::Exists { .. } => false,
+        Expression::Binary(_, left, right) => has_aggregate(left) || has_aggregate(right),
+        Expression::Unary(_, operand) => has_aggregate(operand),
+        Expression::In { expression, list, .. } => {
+            has_aggregate(expression) || list.iter().any(has_aggregate)
+        },
+        Expression::FunctionCall { arguments, .. } => arguments.iter().any(has_aggregate),
+    }
+}
+
+/// The triple patterns of the `CONSTRUCT WHERE { ... }` short form.
+fn triples_of(pattern: Option<&GroupGraphPattern>) -> Vec<TriplePattern> {
+    match pattern {
+        Some(GroupGraphPattern::Group(elements)) => {
+            elements
+                .iter()
+                .filter_map(|element| {
+                    match element {
+                        PatternElement::Triples(triples) => Some(triples.iter().cloned()),
+                        _ => None,
+                    }
+                })
+                .flatten()
+                .collect()
+        },
+        _ => Vec::new(),
+    }
+}
+
+#[cfg(all(test, feature = "nom-support"))]
+mod tests {
+    use {
+        crate::{
+            sparql::{MemoryStore, Quad, TripleSource},
+            DataType,
+            Literal,
+            RDFStoreError,
+            Term,
+        },
+        indoc::indoc,
+    };
+
+    const NS: &str = "https://example.com/";
+
+    fn iri(local_name: &str) -> Term {
+        Term::new_iri_from_str(format!("{NS}{local_name}").as_str()).unwrap()
+    }
+
+    fn integer(value: i64) -> Term {
+        Literal::new_signed_integer_with_datatype(value, DataType::Integer)
+            .unwrap()
+            .as_term()
+    }
+
+    fn store() -> MemoryStore {
+        let mut store = MemoryStore::new();
+        let mut add = |s: &str, p: &str, o: Term, g: Option<&str>| {
+            store.insert(Quad::new(iri(s), iri(p), o, g.map(iri)));
+        };
+        add(
+            "alice",
+            "name",
+            Term::new_str("Alice").unwrap(),
+            None,
+        );
+        add("alice", "age", integer(42), None);
+        add("alice", "knows", iri("bob"), None);
+        add("bob", "name", Term::new_str("Bob").unwrap(), None);
+        add("bob", "age", integer(27), None);
+        add("bob", "knows", iri("carol"), None);
+        add(
+            "carol",
+            "name",
+            Term::new_str("Carol").unwrap(),
+            None,
+        );
+        add(
+            "alice",
+            "email",
+            Term::new_str("alice@example.com").unwrap(),
+            Some("contacts"),
+        );
+        add(
+            "carol",
+            "email",
+            Term::new_str("carol@example.com").unwrap(),
+            Some("contacts"),
+        );
+        store
+    }
+
+    /// The values of the given variable in all rows, `None` if unbound.
+    fn column(sparql: &str, variable: &str) -> Vec<Option<String>> {
+        let results = store().query(sparql).unwrap();
+        results
+            .solutions()
+            .iter()
+            .map(|solution| {
+                solution
+                    .get(variable)
+                    .map(|term| term.as_literal().lexical_form())
+            })
+            .collect()
+    }
+
+    fn strings(values: &[&str]) -> Vec<Option<String>> {
+        values.iter().map(|value| Some(value.to_string())).collect()
+    }
+
+    #[test_log::test]
+    fn test_basic_graph_pattern_and_join() {
+        let names = column(
+            indoc! {r#"
+                PREFIX : <https://example.com/>
+                SELECT ?name WHERE {
+                    ?person :knows ?friend .
+                    ?friend :name ?name .
+                    ?friend :age ?age .
+                }
+            "#},
+            "name",
+        );
+        assert_eq!(names, strings(&["Bob"]));
+    }
+
+    #[test_log::test]
+    fn test_optional_union_and_filter() {
+        let emails = column(
+            indoc! {r#"
+                PREFIX : <https://example.com/>
+                SELECT ?name ?email WHERE {
+                    ?person :name ?name .
+                    OPTIONAL { GRAPH :contacts { ?person :email ?email } }
+                }
+                ORDER BY ?name
+            "#},
+            "email",
+        );
+        assert_eq!(emails, vec![
+            Some("alice@example.com".to_string()),
+            None,
+            Some("carol@example.com".to_string())
+        ]);
+
+        let names = column(
+            indoc! {r#"
+                PREFIX : <https://example.com/>
+                SELECT ?name WHERE {
+                    { ?person :age ?age FILTER(?age > 30) } UNION { ?person :knows :carol }
+                    ?person :name ?name .
+                    FILTER(STRSTARTS(?name, "A") || REGEX(?name, "^b", "i"))
+                }
+                ORDER BY DESC(?name)
+            "#},
+            "name",
+        );
+        assert_eq!(names, strings(&["Bob", "Alice"]));
+    }
+
+    #[test_log::test]
+    fn test_bind_aggregates_and_having() {
+        let sparql = indoc! {r#"
+            PREFIX : <https://example.com/>
+            SELECT ?graph (COUNT(*) AS ?count) (GROUP_CONCAT(?initial; separator=",") AS ?initials)
+            WHERE {
+                { ?person :name ?name } UNION { GRAPH ?graph { ?person :email ?name } }
+                BIND(UCASE(SUBSTR(?name, 1, 1)) AS ?initial)
+            }
+            GROUP BY ?graph
+            HAVING (COUNT(*) > 2)
+        "#};
+        let results = store().query(sparql).unwrap();
+        assert_eq!(results.solutions().len(), 1);
+        let solution = &results.solutions()[0];
+        assert!(solution.get("graph").is_none());
+        assert_eq!(solution.get("count"), Some(&integer(3)));
+        assert_eq!(
+            solution.get("initials").unwrap().as_literal().as_str(),
+            Some("A,B,C")
+        );
+
+        let totals = column(
+            indoc! {r#"
+                PREFIX : <https://example.com/>
+                SELECT (SUM(?age) AS ?total) (AVG(?age) AS ?average) (MAX(?age) AS ?oldest)
+                WHERE { ?person :age ?age }
+            "#},
+            "average",
+        );
+        assert_eq!(totals, strings(&["34.5"]));
+
+        // Carol has no age, which SUM and AVG skip
+        let sparql = indoc! {r#"
+            PREFIX : <https://example.com/>
+            SELECT (SUM(?age) AS ?total) (AVG(?age) AS ?average) (COUNT(?age) AS ?count)
+            WHERE { ?person :name ?name OPTIONAL { ?person :age ?age } }
+        "#};
+        assert_eq!(column(sparql, "total"), strings(&["69"]));
+        assert_eq!(column(sparql, "average"), strings(&["34.5"]));
+        assert_eq!(column(sparql, "count"), strings(&["2"]));
+    }
+
+    #[test_log::test]
+    fn test_ill_typed_and_language_tagged_literals() {
+        let values = column(
+            indoc! {r#"
+                PREFIX xsd: <http://www.w3.org/2001/XMLSchema#>
+                SELECT ?value WHERE { BIND(COALESCE("abc"^^xsd:integer, "error") AS ?value) }
+            "#},
+            "value",
+        );
+        assert_eq!(values, strings(&["error"]));
+
+        for sparql in [
+            r#"SELECT ?name WHERE { ?person ?p ?name FILTER(?name = "Alice"@en) }"#,
+            r#"SELECT ?person WHERE { ?person ?p "Alice"@en }"#,
+            r#"SELECT ?name WHERE { BIND(STRLANG("Alice", "en") AS ?name) }"#,
+        ] {
+            assert!(
+                matches!(
+                    store().query(sparql),
+                    Err(RDFStoreError::UnsupportedSparqlFeature { .. })
+                ),
+                "should be rejected: {sparql}"
+            );
+        }
+    }
+
+    #[test_log::test]
+    fn test_blank_node_and_random_functions() {
+        let results = store()
+            .query(indoc! {r#"
+                SELECT ?a ?b ?c ?d
+                WHERE {
+                    VALUES ?x { 1 2 }
+                    BIND(BNODE("n") AS ?a)
+                    BIND(BNODE("n") AS ?b)
+                    BIND(BNODE("m") AS ?c)
+                    BIND(BNODE() AS ?d)
+                }
+            "#})
+            .unwrap();
+        let rows = results.solutions();
+        assert_eq!(rows.len(), 2);
+        for row in rows.iter() {
+            assert!(matches!(row.get("a"), Some(Term::BlankNode(_))));
+            assert_eq!(row.get("a"), row.get("b"));
+            assert_ne!(row.get("a"), row.get("c"));
+            assert_ne!(row.get("a"), row.get("d"));
+        }
+        assert_ne!(rows[0].get("a"), rows[1].get("a"));
+
+        let results = store()
+            .query(indoc! {r#"
+                SELECT ?random ?uuid ?struuid ?other
+                WHERE {
+                    BIND(RAND() AS ?random)
+                    BIND(UUID() AS ?uuid)
+                    BIND(STRUUID() AS ?struuid)
+                    BIND(STRUUID() AS ?other)
+                }
+            "#})
+            .unwrap();
+        let row = &results.solutions()[0];
+        let random = row.get("random").unwrap().as_literal();
+        assert_eq!(random.data_type, DataType::Double);
+        let random = random.lexical_form().parse::<f64>().unwrap();
+        assert!((0.0..1.0).contains(&random));
+        let uuid = match row.get("uuid") {
+            Some(Term::Iri(iri)) => iri.lexical_form(),
+            term => panic!("not an IRI: {term:?}"),
+        };
+        let struuid = row.get("struuid").unwrap().as_literal().lexical_form();
+        assert!(uuid.starts_with("urn:uuid:"));
+        assert_eq!(uuid.len(), 45);
+        assert_eq!(struuid.len(), 36);
+        assert_eq!(struuid.as_bytes()[14], b'4');
+        assert_ne!(
+            Some(struuid),
+            row.get("other")
+                .map(|term| term.as_literal().lexical_form())
+        );
+    }
+
+    #[test_log::test]
+    fn test_order_by_limit_offset_and_subquery() {
+        let names = column(
+            indoc! {r#"
+                PREFIX : <https://example.com/>
+                SELECT ?name WHERE {
+                    ?person :name ?name .
+                    { SELECT ?person WHERE { ?someone :knows ?person } }
+                }
+                ORDER BY ?name
+                LIMIT 1
+                OFFSET 1
+            "#},
+            "name",
+        );
+        assert_eq!(names, strings(&["Carol"]));
+    }
+
+    #[test_log::test]
+    fn test_ask_and_construct() {
+        let store = store();
+        let ask = store
+            .query("ASK { <https://example.com/alice> <https://example.com/knows> ?x FILTER NOT EXISTS { ?x <https://example.com/age> ?age } }")
+            .unwrap();
+        assert_eq!(ask.as_boolean(), Some(false));
+
+        let construct = store
+            .query(indoc! {r#"
+                PREFIX : <https://example.com/>
+                CONSTRUCT { ?b :knownBy ?a . ?a :friend [ :name ?name ] }
+                WHERE { ?a :knows ?b . ?b :name ?name }
+            "#})
+            .unwrap();
+        assert_eq!(construct.triples().len(), 6);
+        assert!(construct.triples().contains(&Quad::new(
+            iri("carol"),
+            iri("knownBy"),
+            iri("bob"),
+            None
+        )));
+    }
+
+    #[test_log::test]
+    fn test_dataset_and_graph() {
+        let names = column(
+            indoc! {r#"
+                PREFIX : <https://example.com/>
+                SELECT ?graph ?email
+                FROM :contacts
+                FROM NAMED :contacts
+                WHERE {
+                    ?person :email ?email .
+                    GRAPH ?graph { ?person :email ?email }
+                }
+                ORDER BY ?email
+            "#},
+            "email",
+        );
+        assert_eq!(
+            names,
+            strings(&["alice@example.com", "carol@example.com"])
+        );
+    }
+
+    #[test_log::test]
+    fn test_date_time_and_duration_arithmetic() {
+        let deadlines = column(
+            indoc! {r#"
+                PREFIX xsd: <http://www.w3.org/2001/XMLSchema#>
+                SELECT ?deadline
+                WHERE {
+                    VALUES (?opened ?sla) {
+                        ("2023-01-31T09:00:00+01:00"^^xsd:dateTime "P1M"^^xsd:yearMonthDuration)
+                        ("2023-12-31T22:00:00Z"^^xsd:dateTime "PT4H"^^xsd:dayTimeDuration)
+                    }
+                    BIND(?opened + ?sla AS ?deadline)
+                }
+            "#},
+            "deadline",
+        );
+        assert_eq!(
+            deadlines,
+            strings(&["2023-02-28T09:00:00+01:00", "2024-01-01T02:00:00Z"])
+        );
+        let elapsed = column(
+            indoc! {r#"
+                PREFIX xsd: <http://www.w3.org/2001/XMLSchema#>
+                SELECT ?elapsed
+                WHERE {
+                    BIND("2023-01-02T12:00:00Z"^^xsd:dateTime - "2023-01-01T00:00:00Z"^^xsd:dateTime
+                        AS ?elapsed)
+                    FILTER(?elapsed > "P1D"^^xsd:dayTimeDuration)
+                }
+            "#},
+            "elapsed",
+        );
+        assert_eq!(elapsed, strings(&["P1DT12H"]));
+    }
+
+    #[test_log::test]
+    fn test_custom_data_types() {
+        let data_types = column(
+            indoc! {r#"
+                PREFIX geo: <http://www.opengis.net/ont/geosparql#>
+                SELECT ?data_type
+                WHERE {
+                    VALUES ?point { "POINT(4.9 52.4)"^^geo:wktLiteral }
+                    BIND(STRDT(STR(?point), geo:wktLiteral) AS ?copy)
+                    FILTER(?copy = ?point)
+                    BIND(DATATYPE(?copy) AS ?data_type)
+                }
+            "#},
+            "data_type",
+        );
+        assert_eq!(
+            data_types,
+            strings(&["http://www.opengis.net/ont/geosparql#wktLiteral"])
+        );
+    }
+}
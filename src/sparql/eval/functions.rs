@@ -0,0 +1,318 @@
+// Copyright (c) 2018-2023, agnos.ai UK Ltd, all rights reserved.
+//---------------------------------------------------------------
+//! The operators and strict built-in functions of SPARQL over [`Term`]s,
+//! mostly delegating to the functions on [`Literal`].
+//!
+//! Following the SPARQL error semantics, a function that is applied to
+//! arguments of the wrong type does not fail the query but returns `None`,
+//! which makes a `FILTER` reject the solution and leaves the variable of a
+//! `BIND` unbound.
+
+use {
+    crate::{DataType, Literal, RDFStoreError, Term},
+    std::cmp::Ordering,
+};
+
+/// Term equality, except that simple literals and `xsd:string`s with the
+/// same value are considered the same term, as they are in RDF 1.1.
+pub(crate) fn same_term(a: &Term, b: &Term) -> bool {
+    match (a, b) {
+        (Term::Literal(a), Term::Literal(b))
+            if a.data_type.is_string() && b.data_type.is_string() =>
+        {
+            a.as_str() == b.as_str()
+        },
+        _ => a == b,
+    }
+}
+
+pub(crate) fn string_term(str: &str) -> Option<Term> {
+    Literal::new_plain_literal_string(str)
+        .ok()
+        .map(Term::Literal)
+}
+
+pub(crate) fn boolean_term(boolean: bool) -> Option<Term> {
+    Literal::new_boolean(boolean).ok().map(Term::Literal)
+}
+
+pub(crate) fn integer_term(integer: i64) -> Option<Term> {
+    Literal::new_signed_integer_with_datatype(integer, DataType::Integer)
+        .ok()
+        .map(Term::Literal)
+}
+
+fn literal(term: &Term) -> Option<&Literal> {
+    match term {
+        Term::Literal(literal) => Some(literal),
+        _ => None,
+    }
+}
+
+/// The value of a string literal.
+pub(crate) fn string(term: &Term) -> Option<&str> {
+    literal(term)
+        .filter(|literal| literal.data_type.is_string())
+        .and_then(|literal| literal.as_str())
+}
+
+/// The [effective boolean value](https://www.w3.org/TR/sparql11-query/#ebv)
+/// of a term.
+pub(crate) fn effective_boolean_value(term: &Term) -> Option<bool> {
+    literal(term)?.effective_boolean_value().ok()
+}
+
+pub(crate) fn is_numeric(term: &Term) -> bool { literal(term).map_or(false, Literal::is_numeric) }
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum ArithmeticOperator {
+    Add,
+    Subtract,
+    Multiply,
+    Divide,
+}
+
+/// The arithmetic operators on numbers and, following the XPath operator
+/// mapping, on durations, dates and times (such as a date time plus a
+/// duration, or the duration between two date times).
+pub(crate) fn arithmetic(operator: ArithmeticOperator, a: &Term, b: &Term) -> Option<Term> {
+    let (a, b) = (literal(a)?, literal(b)?);
+    let (a_duration, b_duration) = (
+        a.data_type.is_duration(),
+        b.data_type.is_duration(),
+    );
+    let is_temporal = |literal: &Literal| {
+        literal.data_type.is_date_time() ||
+            literal.data_type.is_date() ||
+            literal.data_type.is_time()
+    };
+    match operator {
+        ArithmeticOperator::Add if b_duration => a.add_duration(b),
+        ArithmeticOperator::Add if a_duration => b.add_duration(a),
+        ArithmeticOperator::Add => a.numeric_add(b),
+        ArithmeticOperator::Subtract if b_duration => a.subtract_duration(b),
+        ArithmeticOperator::Subtract if is_temporal(a) => a.subtract_date_time(b),
+        ArithmeticOperator::Subtract => a.numeric_subtract(b),
+        ArithmeticOperator::Multiply if a_duration => a.multiply_duration(b),
+        ArithmeticOperator::Multiply if b_duration => b.multiply_duration(a),
+        ArithmeticOperator::Multiply => a.numeric_multiply(b),
+        ArithmeticOperator::Divide if a_duration => a.divide_duration(b),
+        ArithmeticOperator::Divide => a.numeric_divide(b),
+    }
+    .ok()
+    .map(Term::Literal)
+}
+
+pub(crate) fn negate(term: &Term) -> Option<Term> {
+    literal(term)?.numeric_unary_minus().ok().map(Term::Literal)
+}
+
+/// Compare the values of two literals of compatible types, as used by the
+/// `<`, `>`, `<=` and `>=` operators.
+pub(crate) fn compare_values(a: &Term, b: &Term) -> Option<Ordering> {
+    literal(a)?.value_compare(literal(b)?).ok()
+}
+
+/// The `=` operator: the same term or literals with the same value.
+pub(crate) fn values_equal(a: &Term, b: &Term) -> Option<bool> {
+    if same_term(a, b) {
+        return Some(true)
+    }
+    a.value_equals(b).ok()
+}
+
+/// The ordering used by `ORDER BY` (and by `MIN` and `MAX`), which orders
+/// all terms: unbound values first, then blank nodes, IRIs and literals.
+pub(crate) fn order_compare(a: Option<&Term>, b: Option<&Term>) -> Ordering { a.cmp(&b) }
+
+/// The lexical form of an IRI or literal, as returned by `STR`.
+pub(crate) fn str(term: &Term) -> Option<String> {
+    match term {
+        Term::Iri(iri) => Some(iri.lexical_form()),
+        Term::Literal(literal) => Some(literal.lexical_form()),
+        Term::BlankNode(_) => None,
+    }
+}
+
+/// Call one of the strict built-in functions (those that need all their
+/// arguments to be bound and free of errors). Returns an error for
+/// functions that are not supported.
+pub(crate) fn call_built_in(name: &str, args: &[Term]) -> Result<Option<Term>, RDFStoreError> {
+    let term = |idx: usize| args.get(idx);
+    let literals: Option<Vec<&Literal>> = args.iter().map(literal).collect();
+    let literals = literals.unwrap_or_default();
+    let arg = |idx: usize| literals.get(idx).copied();
+    // The functions that only take literals
+    let unary = |function: fn(&Literal) -> Result<Literal, RDFStoreError>| {
+        arg(0).and_then(|a| function(a).ok()).map(Term::Literal)
+    };
+    let binary = |function: fn(&Literal, &Literal) -> Result<Literal, RDFStoreError>| {
+        match (arg(0), arg(1)) {
+            (Some(a), Some(b)) => function(a, b).ok().map(Term::Literal),
+            _ => None,
+        }
+    };
+    let result = match name {
+        "STR" => {
+            term(0)
+                .and_then(str)
+                .and_then(|str| string_term(str.as_str()))
+        },
+        "LANG" => term(0).and_then(literal).and_then(|_| string_term("")),
+        "LANGMATCHES" => {
+            match (term(0).and_then(string), term(1).and_then(string)) {
+                (Some(tag), Some(range)) => boolean_term(lang_matches(tag, range)),
+                _ => None,
+            }
+        },
+        "DATATYPE" => {
+            term(0).and_then(literal).and_then(|literal| {
+                let iri = match literal.data_type {
+                    DataType::PlainLiteral => DataType::String.as_xsd_iri_str(),
+                    _ => literal.data_type_iri(),
+                };
+                Term::new_iri_from_str(iri).ok()
+            })
+        },
+        "IRI" | "URI" => {
+            match term(0) {
+                Some(term @ Term::Iri(_)) => Some(term.clone()),
+                Some(term) => string(term).and_then(|iri| Term::new_iri_from_str(iri).ok()),
+                None => None,
+            }
+        },
+        "ISIRI" | "ISURI" => term(0).and_then(|term| boolean_term(matches!(term, Term::Iri(_)))),
+        "ISBLANK" => term(0).and_then(|term| boolean_term(matches!(term, Term::BlankNode(_)))),
+        "ISLITERAL" => term(0).and_then(|term| boolean_term(matches!(term, Term::Literal(_)))),
+        "ISNUMERIC" => term(0).and_then(|term| boolean_term(is_numeric(term))),
+        "SAMETERM" => {
+            match (term(0), term(1)) {
+                (Some(a), Some(b)) => boolean_term(same_term(a, b)),
+                _ => None,
+            }
+        },
+        "STRLEN" => unary(Literal::str_len),
+        "SUBSTR" => {
+            arg(0)
+                .and_then(|str| str.substr(arg(1)?, arg(2)).ok())
+                .map(Term::Literal)
+        },
+        "UCASE" => unary(Literal::ucase),
+        "LCASE" => unary(Literal::lcase),
+        "STRSTARTS" => binary(Literal::str_starts),
+        "STRENDS" => binary(Literal::str_ends),
+        "CONTAINS" => binary(Literal::contains),
+        "STRBEFORE" => binary(Literal::str_before),
+        "STRAFTER" => binary(Literal::str_after),
+        "CONCAT" if literals.len() == args.len() => {
+            Literal::concat(literals.as_slice()).ok().map(Term::Literal)
+        },
+        "CONCAT" => None,
+        "ENCODE_FOR_URI" => unary(Literal::encode_for_uri),
+        "REGEX" => {
+            arg(0)
+                .and_then(|text| text.regex(arg(1)?, arg(2)).ok())
+                .map(Term::Literal)
+        },
+        "REPLACE" => {
+            arg(0)
+                .and_then(|text| text.replace(arg(1)?, arg(2)?, arg(3)).ok())
+                .map(Term::Literal)
+        },
+        "ABS" => unary(Literal::abs),
+        "CEIL" => unary(Literal::ceil),
+        "FLOOR" => unary(Literal::floor),
+        "ROUND" => unary(Literal::round),
+        "YEAR" => unary(Literal::year),
+        "MONTH" => unary(Literal::month),
+        "DAY" => unary(Literal::day),
+        "HOURS" => unary(Literal::hours),
+        "MINUTES" => unary(Literal::minutes),
+        "SECONDS" => unary(Literal::seconds),
+        "TIMEZONE" => unary(Literal::timezone),
+        "TZ" => unary(Literal::tz),
+        "MD5" => unary(Literal::md5),
+        "SHA1" => unary(Literal::sha1),
+        "SHA256" => unary(Literal::sha256),
+        "SHA384" => unary(Literal::sha384),
+        "SHA512" => unary(Literal::sha512),
+        "STRDT" => {
+            match (term(0).and_then(string), term(1)) {
+                (Some(lexical_form), Some(Term::Iri(data_type))) => {
+                    cast(lexical_form, data_type.lexical_form().as_str())
+                },
+                _ => None,
+            }
+        },
+        "RAND" => {
+            random_double().and_then(|double| Literal::new_double(double).ok().map(Term::Literal))
+        },
+        "UUID" => {
+            random_uuid()
+                .and_then(|uuid| Term::new_iri_from_str(format!("urn:uuid:{uuid}").as_str()).ok())
+        },
+        "STRUUID" => random_uuid().and_then(|uuid| string_term(uuid.as_str())),
+        _ => {
+            return Err(RDFStoreError::UnsupportedSparqlFeature {
+                feature: format!("function {name}"),
+            })
+        },
+    };
+    Ok(result)
+}
+
+/// Create a literal of the given datatype from the given lexical form, as
+/// done by `STRDT`.
+pub(crate) fn cast(lexical_form: &str, data_type_iri: &str) -> Option<Term> {
+    Literal::from_data_type_iri(data_type_iri, lexical_form)
+        .ok()
+        .map(|literal| literal.as_term())
+}
+
+/// Cast a term to the given datatype, as done by the XSD constructor
+/// functions such as `xsd:integer(?x)`.
+pub(crate) fn cast_to(term: &Term, data_type: DataType) -> Option<Term> {
+    match term {
+        Term::Iri(literal) | Term::Literal(literal) => {
+            literal.cast_to(data_type).ok().map(Term::from)
+        },
+        Term::BlankNode(_) => None,
+    }
+}
+
+/// A random number between 0 (inclusive) and 1 (exclusive), as returned by
+/// `RAND`.
+fn random_double() -> Option<f64> {
+    let mut bytes = [0u8; 8];
+    getrandom::getrandom(&mut bytes).ok()?;
+    // The 53 bits of the mantissa of an f64
+    Some((u64::from_le_bytes(bytes) >> 11) as f64 / (1u64 << 53) as f64)
+}
+
+/// A random (version 4) UUID in its string form, as used by `UUID` and
+/// `STRUUID`.
+fn random_uuid() -> Option<String> {
+    let mut bytes = [0u8; 16];
+    getrandom::getrandom(&mut bytes).ok()?;
+    bytes[6] = (bytes[6] & 0x0f) | 0x40;
+    bytes[8] = (bytes[8] & 0x3f) | 0x80;
+    let hex: String = bytes.iter().map(|byte| format!("{byte:02x}")).collect();
+    Some(format!(
+        "{}-{}-{}-{}-{}",
+        &hex[0..8],
+        &hex[8..12],
+        &hex[12..16],
+        &hex[16..20],
+        &hex[20..32]
+    ))
+}
+
+/// Basic language range matching of
+/// [RFC 4647](https://www.rfc-editor.org/rfc/rfc4647#section-3.3.1).
+fn lang_matches(tag: &str, range: &str) -> bool {
+    if range == "*" {
+        return !tag.is_empty()
+    }
+    let (tag, range) = (tag.to_lowercase(), range.to_lowercase());
+    tag == range || tag.starts_with(format!("{range}-").as_str())
+}
@@ -0,0 +1,17 @@
+// Copyright (c) 2018-2023, agnos.ai UK Ltd, all rights reserved.
+//---------------------------------------------------------------
+//! A small SPARQL query engine that evaluates queries in memory over any
+//! [`TripleSource`], such as the [`MemoryStore`].
+
+mod evaluator;
+mod functions;
+mod path;
+mod solution;
+mod source;
+mod store;
+
+pub use {
+    solution::{FromSolution, QueryResults, Solution},
+    source::TripleSource,
+    store::{MemoryStore, Quad},
+};
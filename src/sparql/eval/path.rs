@@ -0,0 +1,362 @@
+// Copyright (c) 2018-2023, agnos.ai UK Ltd, all rights reserved.
+//---------------------------------------------------------------
+//! Evaluation of [property paths](https://www.w3.org/TR/sparql11-query/#propertypaths).
+//!
+//! Paths are evaluated with set semantics: every pair of connected nodes is
+//! returned once, however many routes there are between them. The
+//! transitive closures (`*` and `+`) keep track of the nodes they visited so
+//! that cycles in the data do not make them loop.
+
+use {
+    crate::Term,
+    std::collections::{HashSet, VecDeque},
+};
+
+/// A [`crate::sparql::PropertyPath`] with its IRIs resolved to terms.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) enum Path {
+    Predicate(Term),
+    Inverse(Box<Path>),
+    Sequence(Vec<Path>),
+    Alternative(Vec<Path>),
+    ZeroOrMore(Box<Path>),
+    OneOrMore(Box<Path>),
+    ZeroOrOne(Box<Path>),
+    NegatedPropertySet { forward: Vec<Term>, inverse: Vec<Term> },
+}
+
+/// Evaluates paths over a function that returns the triples of the active
+/// graph that match a triple pattern.
+pub(crate) struct PathEvaluator<'l, L>
+where L: Fn(Option<&Term>, Option<&Term>, Option<&Term>) -> Vec<[Term; 3]>
+{
+    lookup: &'l L,
+}
+
+impl<'l, L> PathEvaluator<'l, L>
+where L: Fn(Option<&Term>, Option<&Term>, Option<&Term>) -> Vec<[Term; 3]>
+{
+    pub(crate) fn new(lookup: &'l L) -> Self { Self { lookup } }
+
+    /// The distinct (start, end) pairs of nodes that are connected by the
+    /// given path, restricted to the given start and/or end node.
+    pub(crate) fn pairs(
+        &self,
+        path: &Path,
+        subject: Option<&Term>,
+        object: Option<&Term>,
+    ) -> Vec<(Term, Term)> {
+        let mut pairs = Vec::new();
+        let mut seen = HashSet::new();
+        let mut push = |pair: (Term, Term)| {
+            if seen.insert(pair.clone()) {
+                pairs.push(pair);
+            }
+        };
+        match path {
+            Path::Predicate(predicate) => {
+                for [s, _, o] in (self.lookup)(subject, Some(predicate), object) {
+                    push((s, o));
+                }
+            },
+            Path::Inverse(path) => {
+                for (s, o) in self.pairs(path, object, subject) {
+                    push((o, s));
+                }
+            },
+            Path::Sequence(paths) => {
+                for pair in self.sequence(paths.as_slice(), subject, object) {
+                    push(pair);
+                }
+            },
+            Path::Alternative(paths) => {
+                for path in paths.iter() {
+                    for pair in self.pairs(path, subject, object) {
+                        push(pair);
+                    }
+                }
+            },
+            Path::ZeroOrOne(path) => {
+                for pair in self
+                    .zero_length(subject, object)
+                    .into_iter()
+                    .chain(self.pairs(path, subject, object))
+                {
+                    push(pair);
+                }
+            },
+            Path::ZeroOrMore(path) => {
+                for pair in self.closure(path, subject, object, true) {
+                    push(pair);
+                }
+            },
+            Path::OneOrMore(path) => {
+                for pair in self.closure(path, subject, object, false) {
+                    push(pair);
+                }
+            },
+            Path::NegatedPropertySet { forward, inverse } => {
+                if !forward.is_empty() || inverse.is_empty() {
+                    for [s, p, o] in (self.lookup)(subject, None, object) {
+                        if !forward.contains(&p) {
+                            push((s, o));
+                        }
+                    }
+                }
+                if !inverse.is_empty() {
+                    for [s, p, o] in (self.lookup)(object, None, subject) {
+                        if !inverse.contains(&p) {
+                            push((o, s));
+                        }
+                    }
+                }
+            },
+        }
+        pairs
+    }
+
+    /// A sequence is evaluated from the end that is bound, so that a
+    /// pattern like `?x :p/:q :o` does not need to scan all `:p` triples.
+    fn sequence(
+        &self,
+        paths: &[Path],
+        subject: Option<&Term>,
+        object: Option<&Term>,
+    ) -> Vec<(Term, Term)> {
+        let mut pairs = Vec::new();
+        match paths {
+            [] => return self.zero_length(subject, object),
+            [path] => return self.pairs(path, subject, object),
+            [init @ .., last] if subject.is_none() && object.is_some() => {
+                for (middle, end) in self.pairs(last, None, object) {
+                    for (start, _) in self.sequence(init, None, Some(&middle)) {
+                        pairs.push((start, end.clone()));
+                    }
+                }
+            },
+            [first, rest @ ..] => {
+                for (start, middle) in self.pairs(first, subject, None) {
+                    for (_, end) in self.sequence(rest, Some(&middle), object) {
+                        pairs.push((start.clone(), end));
+                    }
+                }
+            },
+        }
+        pairs
+    }
+
+    /// The pairs connected by one or more (or, if `reflexive`, zero or
+    /// more) repetitions of the given path.
+    fn closure(
+        &self,
+        path: &Path,
+        subject: Option<&Term>,
+        object: Option<&Term>,
+        reflexive: bool,
+    ) -> Vec<(Term, Term)> {
+        match (subject, object) {
+            (Some(subject), _) => {
+                self.reachable(path, subject, true, reflexive)
+                    .into_iter()
+                    .filter(|end| object.map_or(true, |object| object == end))
+                    .map(|end| (subject.clone(), end))
+                    .collect()
+            },
+            (None, Some(object)) => {
+                self.reachable(path, object, false, reflexive)
+                    .into_iter()
+                    .map(|start| (start, object.clone()))
+                    .collect()
+            },
+            (None, None) => {
+                let starts = if reflexive {
+                    self.nodes()
+                } else {
+                    let mut starts = Vec::new();
+                    let mut seen = HashSet::new();
+                    for (start, _) in self.pairs(path, None, None) {
+                        if seen.insert(start.clone()) {
+                            starts.push(start);
+                        }
+                    }
+                    starts
+                };
+                starts
+                    .into_iter()
+                    .flat_map(|start| {
+                        self.reachable(path, &start, true, reflexive)
+                            .into_iter()
+                            .map(move |end| (start.clone(), end))
+                    })
+                    .collect()
+            },
+        }
+    }
+
+    /// A breadth-first traversal from the given node, following the path
+    /// forward or backward, that visits every node only once.
+    fn reachable(&self, path: &Path, start: &Term, forward: bool, reflexive: bool) -> Vec<Term> {
+        let mut visited = HashSet::new();
+        let mut reached = Vec::new();
+        if reflexive {
+            visited.insert(start.clone());
+            reached.push(start.clone());
+        }
+        let mut queue = VecDeque::from([start.clone()]);
+        while let Some(node) = queue.pop_front() {
+            let next_nodes: Vec<Term> = if forward {
+                self.pairs(path, Some(&node), None)
+                    .into_iter()
+                    .map(|(_, end)| end)
+                    .collect()
+            } else {
+                self.pairs(path, None, Some(&node))
+                    .into_iter()
+                    .map(|(start, _)| start)
+                    .collect()
+            };
+            for next in next_nodes.into_iter() {
+                if visited.insert(next.clone()) {
+                    reached.push(next.clone());
+                    queue.push_back(next);
+                }
+            }
+        }
+        reached
+    }
+
+    /// The zero-length path connects every node to itself, including
+    /// given nodes that do not occur in the graph.
+    fn zero_length(&self, subject: Option<&Term>, object: Option<&Term>) -> Vec<(Term, Term)> {
+        match (subject, object) {
+            (Some(subject), Some(object)) if subject == object => {
+                vec![(subject.clone(), object.clone())]
+            },
+            (Some(_), Some(_)) => Vec::new(),
+            (Some(node), None) | (None, Some(node)) => vec![(node.clone(), node.clone())],
+            (None, None) => {
+                self.nodes()
+                    .into_iter()
+                    .map(|node| (node.clone(), node))
+                    .collect()
+            },
+        }
+    }
+
+    /// All subjects and objects in the graph.
+    fn nodes(&self) -> Vec<Term> {
+        let mut nodes = Vec::new();
+        let mut seen = HashSet::new();
+        for [subject, _, object] in (self.lookup)(None, None, None) {
+            for node in [subject, object] {
+                if seen.insert(node.clone()) {
+                    nodes.push(node);
+                }
+            }
+        }
+        nodes
+    }
+}
+
+#[cfg(all(test, feature = "nom-support"))]
+mod tests {
+    use {
+        crate::{
+            sparql::{MemoryStore, Quad, TripleSource},
+            Term,
+        },
+        indoc::indoc,
+    };
+
+    fn iri(local_name: &str) -> Term {
+        Term::new_iri_from_str(format!("https://example.com/{local_name}").as_str()).unwrap()
+    }
+
+    /// A taxonomy with a cycle: `a` < `b` < `c` < `a`, and `d` < `c`.
+    fn store() -> MemoryStore {
+        let mut store = MemoryStore::new();
+        for (narrower, broader) in [("a", "b"), ("b", "c"), ("c", "a"), ("d", "c")] {
+            store.insert(Quad::new(
+                iri(narrower),
+                iri("broader"),
+                iri(broader),
+                None,
+            ));
+        }
+        store.insert(Quad::new(
+            iri("a"),
+            iri("label"),
+            Term::new_str("A").unwrap(),
+            None,
+        ));
+        store
+    }
+
+    fn column(sparql: &str) -> Vec<String> {
+        let mut values: Vec<String> = store()
+            .query(sparql)
+            .unwrap()
+            .solutions()
+            .iter()
+            .map(|solution| solution.get("x").unwrap().as_literal().lexical_form())
+            .map(|iri| iri.trim_start_matches("https://example.com/").to_string())
+            .collect();
+        values.sort();
+        values
+    }
+
+    #[test_log::test]
+    fn test_transitive_closure_with_cycle() {
+        let prologue = "PREFIX : <https://example.com/>\n";
+        assert_eq!(
+            column(format!("{prologue}SELECT ?x WHERE {{ :d :broader+ ?x }}").as_str()),
+            vec!["a", "b", "c"]
+        );
+        assert_eq!(
+            column(format!("{prologue}SELECT ?x WHERE {{ :d :broader* ?x }}").as_str()),
+            vec!["a", "b", "c", "d"]
+        );
+        assert_eq!(
+            column(format!("{prologue}SELECT ?x WHERE {{ ?x :broader+ :d }}").as_str()),
+            Vec::<String>::new()
+        );
+        assert_eq!(
+            column(format!("{prologue}SELECT ?x WHERE {{ ?x ^:broader+ :b }}").as_str()),
+            vec!["a", "b", "c"]
+        );
+        assert_eq!(
+            column(format!("{prologue}SELECT ?x WHERE {{ :a :broader? ?x }}").as_str()),
+            vec!["a", "b"]
+        );
+    }
+
+    #[test_log::test]
+    fn test_sequence_alternative_and_negated_set() {
+        let sparql = indoc! {r#"
+            PREFIX : <https://example.com/>
+            SELECT ?x WHERE { ?x :broader/:broader/:label "A" }
+        "#};
+        assert_eq!(column(sparql), vec!["b", "d"]);
+
+        let sparql = indoc! {r#"
+            PREFIX : <https://example.com/>
+            SELECT DISTINCT ?x WHERE { :a (:label|:broader) ?x FILTER(isIRI(?x)) }
+        "#};
+        assert_eq!(column(sparql), vec!["b"]);
+
+        let sparql = indoc! {r#"
+            PREFIX : <https://example.com/>
+            SELECT ?x WHERE { :a !(:broader|^:broader) ?x }
+        "#};
+        assert_eq!(
+            store().query(sparql).unwrap().solutions().len(),
+            1
+        );
+
+        let sparql = indoc! {r#"
+            PREFIX : <https://example.com/>
+            SELECT ?x WHERE { :a !^:label ?x }
+        "#};
+        assert_eq!(column(sparql), vec!["c"]);
+    }
+}
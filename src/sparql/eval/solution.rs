@@ -0,0 +1,209 @@
+// Copyright (c) 2018-2023, agnos.ai UK Ltd, all rights reserved.
+//---------------------------------------------------------------
+
+use {
+    super::{functions::same_term, Quad},
+    crate::{sparql::Variable, DataType, FromLiteral, Literal, Namespace, RDFStoreError, Term},
+    std::collections::BTreeMap,
+};
+
+/// A Rust value that can be read from a [`Solution`], usually a struct with
+/// a field for each projected variable, see `#[derive(FromSolution)]` in
+/// the `rdf-store-rs-derive` crate (with the `derive` feature).
+pub trait FromSolution: Sized {
+    fn from_solution(solution: &Solution) -> Result<Self, RDFStoreError>;
+}
+
+/// One row of a result set: the terms that are bound to the variables of a
+/// query. Unbound variables are simply absent.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct Solution {
+    bindings: BTreeMap<Variable, Term>,
+}
+
+impl Solution {
+    pub fn new() -> Self { Self::default() }
+
+    /// The term bound to the variable with the given name (without `?`).
+    pub fn get(&self, name: &str) -> Option<&Term> {
+        self.bindings.get(&Variable(name.to_string()))
+    }
+
+    pub fn get_variable(&self, variable: &Variable) -> Option<&Term> { self.bindings.get(variable) }
+
+    /// The value of the variable with the given name, read with
+    /// [`FromLiteral`]. An unbound variable is only allowed for an
+    /// [`Option`], otherwise it is an [`RDFStoreError::UnboundVariable`].
+    pub fn get_value<T: FromLiteral>(&self, name: &str) -> Result<T, RDFStoreError> {
+        T::from_optional_literal(self.get(name).map(Term::as_literal))
+            .map_err(|error| variable_error(name, error))
+    }
+
+    /// The local name of the IRI bound to the variable with the given name
+    /// within the given namespace, read with [`FromLiteral`] from an
+    /// `xsd:string`. So for `?type` bound to `rdf:Property`, this is
+    /// `"Property"` in the `rdf:` namespace.
+    pub fn get_local_name<T: FromLiteral>(
+        &self,
+        name: &str,
+        namespace: &Namespace,
+    ) -> Result<T, RDFStoreError> {
+        let local_name = self
+            .get(name)
+            .map(|term| local_name_literal(term.as_literal(), namespace))
+            .transpose()
+            .map_err(|error| variable_error(name, error))?;
+        T::from_optional_literal(local_name.as_ref()).map_err(|error| variable_error(name, error))
+    }
+
+    pub fn insert(&mut self, variable: Variable, term: Term) -> Option<Term> {
+        self.bindings.insert(variable, term)
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (&Variable, &Term)> { self.bindings.iter() }
+
+    pub fn len(&self) -> usize { self.bindings.len() }
+
+    pub fn is_empty(&self) -> bool { self.bindings.is_empty() }
+
+    /// Two solutions are compatible if they bind the variables they have in
+    /// common to the same terms.
+    pub(crate) fn is_compatible(&self, other: &Solution) -> bool {
+        let (small, large) = if self.len() <= other.len() {
+            (self, other)
+        } else {
+            (other, self)
+        };
+        small.bindings.iter().all(|(variable, term)| {
+            large
+                .bindings
+                .get(variable)
+                .map_or(true, |other| same_term(term, other))
+        })
+    }
+
+    pub(crate) fn shares_variables(&self, other: &Solution) -> bool {
+        self.bindings
+            .keys()
+            .any(|variable| other.bindings.contains_key(variable))
+    }
+
+    pub(crate) fn merge(&self, other: &Solution) -> Solution {
+        let mut merged = self.clone();
+        for (variable, term) in other.bindings.iter() {
+            merged
+                .bindings
+                .entry(variable.clone())
+                .or_insert_with(|| term.clone());
+        }
+        merged
+    }
+
+    /// Keep only the given variables.
+    pub(crate) fn project(&self, variables: &[Variable]) -> Solution {
+        Solution {
+            bindings: self
+                .bindings
+                .iter()
+                .filter(|(variable, _)| variables.contains(variable))
+                .map(|(variable, term)| (variable.clone(), term.clone()))
+                .collect(),
+        }
+    }
+}
+
+impl FromSolution for Solution {
+    fn from_solution(solution: &Solution) -> Result<Self, RDFStoreError> { Ok(solution.clone()) }
+}
+
+/// The local name of an IRI within the given namespace as an `xsd:string`.
+fn local_name_literal(literal: &Literal, namespace: &Namespace) -> Result<Literal, RDFStoreError> {
+    let iri = literal.as_iri().ok_or(RDFStoreError::InvalidCast {
+        from: literal.data_type,
+        to:   DataType::IriReference,
+    })?;
+    let local_name = namespace.local_name_of(iri.as_str()).ok_or_else(|| {
+        RDFStoreError::NotInNamespace {
+            iri:       iri.as_str().to_string(),
+            namespace: namespace.to_string(),
+        }
+    })?;
+    Literal::new_string_with_datatype(local_name, DataType::String)
+}
+
+/// Name the variable in the error of reading its value.
+fn variable_error(name: &str, error: RDFStoreError) -> RDFStoreError {
+    match error {
+        RDFStoreError::MissingLiteral { .. } => {
+            RDFStoreError::UnboundVariable { variable: name.to_string() }
+        },
+        error => {
+            RDFStoreError::InvalidVariableValue {
+                variable: name.to_string(),
+                source:   Box::new(error),
+            }
+        },
+    }
+}
+
+impl FromIterator<(Variable, Term)> for Solution {
+    fn from_iter<T: IntoIterator<Item = (Variable, Term)>>(iter: T) -> Self {
+        Self { bindings: iter.into_iter().collect() }
+    }
+}
+
+/// The result of a query: a sequence of solutions for `SELECT`, a boolean
+/// for `ASK` and triples (as [`Quad`]s in the default graph) for
+/// `CONSTRUCT` and `DESCRIBE`.
+#[derive(Debug, Clone, PartialEq)]
+pub enum QueryResults {
+    Solutions { variables: Vec<Variable>, solutions: Vec<Solution> },
+    Boolean(bool),
+    Graph(Vec<Quad>),
+}
+
+impl QueryResults {
+    /// The projected variables, empty unless these are the results of a
+    /// `SELECT` query.
+    pub fn variables(&self) -> &[Variable] {
+        match self {
+            QueryResults::Solutions { variables, .. } => variables.as_slice(),
+            _ => &[],
+        }
+    }
+
+    pub fn solutions(&self) -> &[Solution] {
+        match self {
+            QueryResults::Solutions { solutions, .. } => solutions.as_slice(),
+            _ => &[],
+        }
+    }
+
+    /// The solutions as rows of terms in the order of the projected
+    /// variables, with `None` for unbound variables.
+    pub fn rows(&self) -> Vec<Vec<Option<Term>>> {
+        self.solutions()
+            .iter()
+            .map(|solution| {
+                self.variables()
+                    .iter()
+                    .map(|variable| solution.get_variable(variable).cloned())
+                    .collect()
+            })
+            .collect()
+    }
+
+    pub fn as_boolean(&self) -> Option<bool> {
+        match self {
+            QueryResults::Boolean(boolean) => Some(*boolean),
+            _ => None,
+        }
+    }
+
+    pub fn triples(&self) -> &[Quad] {
+        match self {
+            QueryResults::Graph(triples) => triples.as_slice(),
+            _ => &[],
+        }
+    }
+}
@@ -0,0 +1,44 @@
+// Copyright (c) 2018-2023, agnos.ai UK Ltd, all rights reserved.
+//---------------------------------------------------------------
+
+use {
+    super::{evaluator::Evaluator, QueryResults},
+    crate::{sparql::Query, RDFStoreError, Term},
+};
+
+/// Any store that can look up the triples that match a triple pattern can
+/// be queried with SPARQL by implementing this trait, the evaluation of
+/// the query itself (joins, property paths, filters, aggregates and so on)
+/// is done in memory.
+pub trait TripleSource {
+    /// The triples in the given graph (the default graph if `graph` is
+    /// `None`) that match the given subject, predicate and object, where
+    /// `None` matches anything.
+    fn triples_matching(
+        &self,
+        subject: Option<&Term>,
+        predicate: Option<&Term>,
+        object: Option<&Term>,
+        graph: Option<&Term>,
+    ) -> Vec<[Term; 3]>;
+
+    /// The names of the named graphs that `GRAPH` patterns can match when
+    /// a query does not have `FROM` or `FROM NAMED` clauses.
+    fn graph_names(&self) -> Vec<Term>;
+
+    /// Parse and evaluate the given SPARQL query.
+    #[cfg(feature = "nom-support")]
+    fn query(&self, sparql: &str) -> Result<QueryResults, RDFStoreError> {
+        match crate::sparql::SparqlStatement::parse(sparql)? {
+            crate::sparql::SparqlStatement::Query(query) => self.evaluate(&query),
+            crate::sparql::SparqlStatement::Update(_) => {
+                Err(RDFStoreError::UnsupportedSparqlFeature { feature: "updates".to_string() })
+            },
+        }
+    }
+
+    /// Evaluate the given (parsed) SPARQL query.
+    fn evaluate(&self, query: &Query) -> Result<QueryResults, RDFStoreError> {
+        Evaluator::new(self, query)?.evaluate_query(query)
+    }
+}
@@ -0,0 +1,144 @@
+// Copyright (c) 2018-2023, agnos.ai UK Ltd, all rights reserved.
+//---------------------------------------------------------------
+
+use {
+    super::{functions::same_term, TripleSource},
+    crate::{Graph, RDFStoreError, Term},
+    std::collections::HashSet,
+};
+
+/// A triple in either the default graph (when `graph` is `None`) or a named
+/// graph.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Quad {
+    pub subject:   Term,
+    pub predicate: Term,
+    pub object:    Term,
+    pub graph:     Option<Term>,
+}
+
+impl Quad {
+    pub fn new(subject: Term, predicate: Term, object: Term, graph: Option<Term>) -> Self {
+        Self { subject, predicate, object, graph }
+    }
+}
+
+/// A simple in-memory set of [`Quad`]s that can answer SPARQL queries
+/// (see [`TripleSource`]) locally, for instance in tests that should not
+/// depend on a running store. It is not indexed, every triple pattern is a
+/// scan.
+///
+/// Unless a query has `FROM` or `FROM NAMED` clauses, its default graph is
+/// the default graph of the store and all named graphs of the store are
+/// available to `GRAPH` patterns.
+#[derive(Debug, Default, Clone)]
+pub struct MemoryStore {
+    quads: Vec<Quad>,
+    index: HashSet<Quad>,
+}
+
+impl MemoryStore {
+    pub fn new() -> Self { Self::default() }
+
+    pub fn len(&self) -> usize { self.quads.len() }
+
+    pub fn is_empty(&self) -> bool { self.quads.is_empty() }
+
+    /// Add the given quad, returns `false` if it was already there.
+    pub fn insert(&mut self, quad: Quad) -> bool {
+        if self.index.contains(&quad) {
+            return false
+        }
+        self.index.insert(quad.clone());
+        self.quads.push(quad);
+        true
+    }
+
+    /// Add a triple to the given graph, or to the default graph if no graph
+    /// is given.
+    pub fn insert_triple(
+        &mut self,
+        subject: Term,
+        predicate: Term,
+        object: Term,
+        graph: Option<&Graph>,
+    ) -> Result<bool, RDFStoreError> {
+        let graph = match graph {
+            Some(graph) => Some(Term::new_iri(graph.as_iri_buf()?.as_iri())?),
+            None => None,
+        };
+        Ok(self.insert(Quad::new(subject, predicate, object, graph)))
+    }
+
+    /// Remove the given quad, returns `false` if it was not there.
+    pub fn remove(&mut self, quad: &Quad) -> bool {
+        if !self.index.remove(quad) {
+            return false
+        }
+        self.quads.retain(|this| this != quad);
+        true
+    }
+
+    pub fn contains(&self, quad: &Quad) -> bool { self.index.contains(quad) }
+
+    /// All quads, in the order in which they were added.
+    pub fn iter(&self) -> impl Iterator<Item = &Quad> { self.quads.iter() }
+
+    /// The names of all named graphs that contain at least one triple.
+    pub fn named_graphs(&self) -> Vec<&Term> {
+        let mut graphs: Vec<&Term> = Vec::new();
+        for graph in self.quads.iter().filter_map(|quad| quad.graph.as_ref()) {
+            if !graphs.contains(&graph) {
+                graphs.push(graph);
+            }
+        }
+        graphs
+    }
+
+    /// The quads in the given graph (the default graph if `graph` is `None`)
+    /// that match the given subject, predicate and object, where `None`
+    /// matches anything.
+    pub fn quads_matching<'a>(
+        &'a self,
+        subject: Option<&'a Term>,
+        predicate: Option<&'a Term>,
+        object: Option<&'a Term>,
+        graph: Option<&'a Term>,
+    ) -> impl Iterator<Item = &'a Quad> + 'a {
+        let matches = |pattern: Option<&Term>, term: &Term| {
+            pattern.map_or(true, |pattern| same_term(pattern, term))
+        };
+        self.quads.iter().filter(move |quad| {
+            match (graph, quad.graph.as_ref()) {
+                (None, None) => {},
+                (Some(graph), Some(quad_graph)) if same_term(graph, quad_graph) => {},
+                _ => return false,
+            }
+            matches(subject, &quad.subject) &&
+                matches(predicate, &quad.predicate) &&
+                matches(object, &quad.object)
+        })
+    }
+}
+
+impl TripleSource for MemoryStore {
+    fn triples_matching(
+        &self,
+        subject: Option<&Term>,
+        predicate: Option<&Term>,
+        object: Option<&Term>,
+        graph: Option<&Term>,
+    ) -> Vec<[Term; 3]> {
+        self.quads_matching(subject, predicate, object, graph)
+            .map(|quad| {
+                [
+                    quad.subject.clone(),
+                    quad.predicate.clone(),
+                    quad.object.clone(),
+                ]
+            })
+            .collect()
+    }
+
+    fn graph_names(&self) -> Vec<Term> { self.named_graphs().into_iter().cloned().collect() }
+}
@@ -0,0 +1,86 @@
+// Copyright (c) 2018-2023, agnos.ai UK Ltd, all rights reserved.
+//---------------------------------------------------------------
+//! Terminals shared by the SPARQL and Turtle grammars, see
+//! <https://www.w3.org/TR/sparql11-query/#rPN_CHARS_BASE>. The character
+//! classes that they are made of are also needed without the `nom-support`
+//! feature, so they are defined with [`PrefixedIri`](crate::PrefixedIri).
+
+use crate::prefixed_iri::{is_pn_chars, is_pn_chars_base, is_pn_chars_u};
+
+/// The characters that may follow the first character of a `VARNAME`.
+pub(crate) fn is_var_name_char(c: char) -> bool { c != '-' && is_pn_chars(c) }
+
+/// The characters that can be escaped with a `\` in a `PN_LOCAL`
+/// (`PN_LOCAL_ESC`).
+pub(crate) fn is_pn_local_escapable(c: char) -> bool { "_~.-!$&'()*+,;=/?#@%".contains(c) }
+
+/// Return the length (in bytes) of the `PN_PREFIX` at the start of the given
+/// string, zero if there is none.
+pub(crate) fn scan_pn_prefix(str: &str) -> usize {
+    let mut chars = str.char_indices();
+    let mut last_valid_end = match chars.next() {
+        Some((_, c)) if is_pn_chars_base(c) => c.len_utf8(),
+        _ => return 0,
+    };
+    for (idx, c) in chars {
+        if is_pn_chars(c) {
+            last_valid_end = idx + c.len_utf8();
+        } else if c != '.' {
+            // Dots are allowed, but not at the end of a prefix
+            break
+        }
+    }
+    last_valid_end
+}
+
+/// Return the length (in bytes) of the `PN_LOCAL` at the start of the given
+/// string, zero if there is none. Escapes (`PLX`) are included as they are.
+pub(crate) fn scan_pn_local(str: &str) -> usize {
+    let bytes = str.as_bytes();
+    let mut idx = 0;
+    let mut last_valid_end = 0;
+    let mut first = true;
+    while idx < str.len() {
+        let c = str[idx..].chars().next().unwrap();
+        let len = if c == '%' {
+            if bytes.len() > idx + 2 &&
+                bytes[idx + 1].is_ascii_hexdigit() &&
+                bytes[idx + 2].is_ascii_hexdigit()
+            {
+                3
+            } else {
+                break
+            }
+        } else if c == '\\' {
+            match str[idx + 1..].chars().next() {
+                Some(escaped) if is_pn_local_escapable(escaped) => 1 + escaped.len_utf8(),
+                _ => break,
+            }
+        } else if c == ':' || c.is_ascii_digit() || is_pn_chars_u(c) || (!first && is_pn_chars(c)) {
+            c.len_utf8()
+        } else if c == '.' && !first {
+            idx += 1;
+            continue
+        } else {
+            break
+        };
+        idx += len;
+        last_valid_end = idx;
+        first = false;
+    }
+    last_valid_end
+}
+
+/// Return the length (in bytes) of the `VARNAME` at the start of the given
+/// string, zero if there is none.
+pub(crate) fn scan_var_name(str: &str) -> usize {
+    let mut chars = str.char_indices();
+    match chars.next() {
+        Some((_, c)) if is_pn_chars_u(c) || c.is_ascii_digit() => {},
+        _ => return 0,
+    }
+    chars
+        .find(|(_, c)| !is_var_name_char(*c))
+        .map(|(idx, _)| idx)
+        .unwrap_or(str.len())
+}
@@ -0,0 +1,59 @@
+// Copyright (c) 2018-2023, agnos.ai UK Ltd, all rights reserved.
+//---------------------------------------------------------------
+//! Utilities to parse, prepare and rewrite SPARQL statements before they are
+//! sent to a store, and to evaluate queries against an in-memory store.
+
+mod ast;
+mod eval;
+#[cfg(feature = "nom-support")]
+mod grammar;
+#[cfg(feature = "nom-support")]
+pub(crate) mod parser;
+mod rewrite;
+mod serializer;
+mod template;
+
+pub use {
+    ast::{
+        AggregateFunction,
+        BinaryOperator,
+        DatasetClause,
+        Expression,
+        Function,
+        GraphOrDefault,
+        GraphTarget,
+        GraphTransfer,
+        GroupCondition,
+        GroupGraphPattern,
+        InlineData,
+        OrderCondition,
+        PatternElement,
+        Projection,
+        ProjectionItem,
+        Prologue,
+        PropertyPath,
+        QuadsElement,
+        Query,
+        QueryForm,
+        SelectModifier,
+        SolutionModifiers,
+        SparqlIri,
+        SparqlLiteral,
+        SparqlStatement,
+        SparqlStatementKind,
+        TermPattern,
+        TriplePattern,
+        UnaryOperator,
+        Update,
+        UpdateOperation,
+        Variable,
+        Verb,
+    },
+    eval::{FromSolution, MemoryStore, Quad, QueryResults, Solution, TripleSource},
+    rewrite::{GraphScope, RowLimitPolicy, SparqlRewriter},
+    template::{SparqlBindingStyle, SparqlTemplate},
+};
+/// Derive [`FromSolution`] for a struct with a field for each projected
+/// variable, see the `rdf-store-rs-derive` crate.
+#[cfg(feature = "derive")]
+pub use rdf_store_rs_derive::FromSolution;
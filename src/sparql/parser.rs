@@ -0,0 +1,2158 @@
+// Copyright (c) 2018-2023, agnos.ai UK Ltd, all rights reserved.
+//---------------------------------------------------------------
+//! A [nom](https://docs.rs/nom) based parser for the
+//! [SPARQL 1.1 grammar](https://www.w3.org/TR/sparql11-query/#grammar)
+//! of queries and updates.
+
+use {
+    super::{
+        ast::*,
+        grammar::{scan_pn_local, scan_pn_prefix, scan_var_name},
+    },
+    crate::{prefixed_iri::is_pn_chars, RDFStoreError},
+    nom::{
+        branch::alt,
+        combinator::{cut, map, opt},
+        error::{ErrorKind, ParseError},
+        multi::{many0, many1, separated_list0, separated_list1},
+        sequence::{delimited, pair, preceded, tuple},
+        IResult,
+    },
+    std::cell::{Cell, RefCell},
+};
+
+const RDF_TYPE: &str = "http://www.w3.org/1999/02/22-rdf-syntax-ns#type";
+const RDF_FIRST: &str = "http://www.w3.org/1999/02/22-rdf-syntax-ns#first";
+const RDF_REST: &str = "http://www.w3.org/1999/02/22-rdf-syntax-ns#rest";
+const RDF_NIL: &str = "http://www.w3.org/1999/02/22-rdf-syntax-ns#nil";
+const XSD_BOOLEAN: &str = "http://www.w3.org/2001/XMLSchema#boolean";
+const XSD_INTEGER: &str = "http://www.w3.org/2001/XMLSchema#integer";
+const XSD_DECIMAL: &str = "http://www.w3.org/2001/XMLSchema#decimal";
+const XSD_DOUBLE: &str = "http://www.w3.org/2001/XMLSchema#double";
+
+pub(crate) type PResult<'a, T> = IResult<&'a str, T, SyntaxError<'a>>;
+
+/// The built-in functions that are called with an expression list, with
+/// their minimum and maximum number of arguments.
+const BUILT_IN_FUNCTIONS: [(&str, usize, usize); 51] = [
+    ("STR", 1, 1),
+    ("LANG", 1, 1),
+    ("LANGMATCHES", 2, 2),
+    ("DATATYPE", 1, 1),
+    ("IRI", 1, 1),
+    ("URI", 1, 1),
+    ("BNODE", 0, 1),
+    ("RAND", 0, 0),
+    ("ABS", 1, 1),
+    ("CEIL", 1, 1),
+    ("FLOOR", 1, 1),
+    ("ROUND", 1, 1),
+    ("CONCAT", 0, usize::MAX),
+    ("SUBSTR", 2, 3),
+    ("STRLEN", 1, 1),
+    ("REGEX", 2, 3),
+    ("REPLACE", 3, 4),
+    ("UCASE", 1, 1),
+    ("LCASE", 1, 1),
+    ("ENCODE_FOR_URI", 1, 1),
+    ("CONTAINS", 2, 2),
+    ("STRSTARTS", 2, 2),
+    ("STRENDS", 2, 2),
+    ("STRBEFORE", 2, 2),
+    ("STRAFTER", 2, 2),
+    ("YEAR", 1, 1),
+    ("MONTH", 1, 1),
+    ("DAY", 1, 1),
+    ("HOURS", 1, 1),
+    ("MINUTES", 1, 1),
+    ("SECONDS", 1, 1),
+    ("TIMEZONE", 1, 1),
+    ("TZ", 1, 1),
+    ("NOW", 0, 0),
+    ("UUID", 0, 0),
+    ("STRUUID", 0, 0),
+    ("MD5", 1, 1),
+    ("SHA1", 1, 1),
+    ("SHA256", 1, 1),
+    ("SHA384", 1, 1),
+    ("SHA512", 1, 1),
+    ("COALESCE", 0, usize::MAX),
+    ("IF", 3, 3),
+    ("STRLANG", 2, 2),
+    ("STRDT", 2, 2),
+    ("SAMETERM", 2, 2),
+    ("ISIRI", 1, 1),
+    ("ISURI", 1, 1),
+    ("ISBLANK", 1, 1),
+    ("ISLITERAL", 1, 1),
+    ("ISNUMERIC", 1, 1),
+];
+
+/// The nom error type of the SPARQL parser. Plain errors only carry their
+/// position, the parser keeps track of what it expected at the furthest
+/// position it got to. Failures raised by validations carry a message.
+#[derive(Debug)]
+pub(crate) struct SyntaxError<'a> {
+    pub(crate) input:   &'a str,
+    pub(crate) message: Option<String>,
+}
+
+impl<'a> ParseError<&'a str> for SyntaxError<'a> {
+    fn from_error_kind(input: &'a str, _kind: ErrorKind) -> Self { Self { input, message: None } }
+
+    fn append(_input: &'a str, _kind: ErrorKind, other: Self) -> Self { other }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Expected {
+    Token(&'static str),
+    Description(&'static str),
+}
+
+impl std::fmt::Display for Expected {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Expected::Token(token) => write!(f, "'{token}'"),
+            Expected::Description(description) => write!(f, "{description}"),
+        }
+    }
+}
+
+/// The position (as the number of bytes that were left to parse) and the
+/// message of a syntax error.
+pub(crate) struct Failure {
+    remaining: usize,
+    message:   String,
+    expected:  Vec<Expected>,
+}
+
+/// Parse the given SPARQL query or update.
+pub(crate) fn parse(sparql: &str) -> Result<SparqlStatement, RDFStoreError> {
+    let query_error = match Parser::default().unit(sparql, Parser::query) {
+        Ok(query) => return Ok(SparqlStatement::Query(query)),
+        Err(error) => error,
+    };
+    let update_error = match Parser::default().unit(sparql, Parser::update) {
+        Ok(update) => return Ok(SparqlStatement::Update(update)),
+        Err(error) => error,
+    };
+    let failure = if update_error.remaining < query_error.remaining {
+        update_error
+    } else if update_error.remaining == query_error.remaining &&
+        query_error.message.is_empty() &&
+        update_error.message.is_empty()
+    {
+        let mut expected = query_error.expected;
+        for item in update_error.expected {
+            if !expected.contains(&item) {
+                expected.push(item);
+            }
+        }
+        Failure {
+            remaining: query_error.remaining,
+            message: String::new(),
+            expected,
+        }
+    } else {
+        query_error
+    };
+    Err(syntax_error(sparql, failure))
+}
+
+pub(crate) fn syntax_error(sparql: &str, failure: Failure) -> RDFStoreError {
+    let offset = sparql.len() - failure.remaining;
+    let before = &sparql[..offset];
+    let line = before.matches('\n').count() + 1;
+    let column = before
+        .rfind('\n')
+        .map(|pos| before[pos + 1..].chars().count())
+        .unwrap_or_else(|| before.chars().count()) +
+        1;
+    let found = sparql[offset..]
+        .lines()
+        .next()
+        .unwrap_or("")
+        .chars()
+        .take(20)
+        .collect::<String>();
+    let message = if !failure.message.is_empty() {
+        failure.message
+    } else {
+        let expected = failure
+            .expected
+            .iter()
+            .map(|expected| expected.to_string())
+            .collect::<Vec<_>>();
+        let expected = match expected.len() {
+            0 => "valid SPARQL".to_string(),
+            1 => expected[0].clone(),
+            len => {
+                format!(
+                    "{} or {}",
+                    expected[..len - 1].join(", "),
+                    expected[len - 1]
+                )
+            },
+        };
+        if found.is_empty() {
+            format!("expected {expected} but reached the end of the statement")
+        } else {
+            format!("expected {expected} but found \"{found}\"")
+        }
+    };
+    RDFStoreError::SparqlParseError { line, column, message }
+}
+
+/// Skip whitespace and comments.
+pub(crate) fn ws(mut i: &str) -> &str {
+    loop {
+        let trimmed = i.trim_start_matches([' ', '\t', '\r', '\n']);
+        match trimmed.strip_prefix('#') {
+            Some(comment) => i = comment.find('\n').map(|pos| &comment[pos..]).unwrap_or(""),
+            None => return trimmed,
+        }
+    }
+}
+
+#[derive(Default)]
+pub(crate) struct Parser {
+    blank_nodes: Cell<usize>,
+    /// The smallest number of remaining bytes at which the parser failed
+    /// and what it expected there
+    furthest:    RefCell<Option<(usize, Vec<Expected>)>>,
+}
+
+impl Parser {
+    pub(crate) fn unit<'a, T>(
+        &self,
+        sparql: &'a str,
+        parser: fn(&Self, &'a str) -> PResult<'a, T>,
+    ) -> Result<T, Failure> {
+        match parser(self, sparql) {
+            Ok((rest, result)) => {
+                let rest = ws(rest);
+                if rest.is_empty() {
+                    return Ok(result)
+                }
+                self.record(
+                    rest,
+                    Expected::Description("the end of the statement"),
+                );
+            },
+            Err(nom::Err::Failure(SyntaxError { input, message: Some(message) })) => {
+                return Err(Failure {
+                    remaining: input.len(),
+                    message,
+                    expected: Vec::new(),
+                })
+            },
+            Err(nom::Err::Error(error)) | Err(nom::Err::Failure(error)) => {
+                if self.furthest.borrow().is_none() {
+                    self.record(error.input, Expected::Description("valid SPARQL"));
+                }
+            },
+            Err(nom::Err::Incomplete(_)) => {},
+        }
+        let (remaining, expected) = self.furthest.take().unwrap_or((sparql.len(), Vec::new()));
+        Err(Failure { remaining, message: String::new(), expected })
+    }
+
+    fn record(&self, i: &str, expected: Expected) {
+        let mut furthest = self.furthest.borrow_mut();
+        match furthest.as_mut() {
+            Some((remaining, list)) if *remaining == i.len() => {
+                if !list.contains(&expected) {
+                    list.push(expected);
+                }
+            },
+            Some((remaining, _)) if *remaining < i.len() => {},
+            _ => *furthest = Some((i.len(), vec![expected])),
+        }
+    }
+
+    fn error<'a, T>(&self, i: &'a str, expected: Expected) -> PResult<'a, T> {
+        self.record(i, expected);
+        Err(nom::Err::Error(SyntaxError {
+            input:   i,
+            message: None,
+        }))
+    }
+
+    fn failure<'a, T>(&self, i: &'a str, message: String) -> PResult<'a, T> {
+        Err(nom::Err::Failure(SyntaxError {
+            input:   i,
+            message: Some(message),
+        }))
+    }
+
+    fn new_blank_node(&self) -> TermPattern {
+        let id = self.blank_nodes.get();
+        self.blank_nodes.set(id + 1);
+        TermPattern::BlankNode(format!("_gen{id}"))
+    }
+
+    // ------------------------------------------------------------------
+    // Tokens
+    // ------------------------------------------------------------------
+
+    pub(crate) fn tok<'a, 'p>(
+        &'p self,
+        token: &'static str,
+    ) -> impl FnMut(&'a str) -> PResult<'a, &'a str> + 'p {
+        move |i| {
+            let i = ws(i);
+            match i.strip_prefix(token) {
+                Some(rest) => Ok((rest, &i[..token.len()])),
+                None => self.error(i, Expected::Token(token)),
+            }
+        }
+    }
+
+    /// A case-insensitive keyword that is not immediately followed by a
+    /// character that could make it part of a longer name.
+    pub(crate) fn kw<'a, 'p>(&'p self, keyword: &'static str) -> impl FnMut(&'a str) -> PResult<'a, ()> + 'p {
+        move |i| {
+            let i = ws(i);
+            let len = keyword.len();
+            let matches = i.len() >= len &&
+                i.is_char_boundary(len) &&
+                i[..len].eq_ignore_ascii_case(keyword) &&
+                !i[len..].starts_with(|c: char| is_pn_chars(c) || c == ':');
+            if matches {
+                Ok((&i[len..], ()))
+            } else {
+                self.error(i, Expected::Token(keyword))
+            }
+        }
+    }
+
+    fn silent<'a>(&self, i: &'a str) -> PResult<'a, bool> {
+        map(opt(self.kw("SILENT")), |silent| silent.is_some())(i)
+    }
+
+    pub(crate) fn iriref<'a>(&self, i: &'a str) -> PResult<'a, String> {
+        let i = ws(i);
+        if !i.starts_with('<') {
+            return self.error(i, Expected::Description("an IRI"))
+        }
+        let mut iri = String::new();
+        let mut chars = i.char_indices().skip(1);
+        while let Some((idx, c)) = chars.next() {
+            match c {
+                '>' => return Ok((&i[idx + 1..], iri)),
+                '\\' => {
+                    match unescape_unicode(&i[idx..]) {
+                        Some((c, len)) => {
+                            iri.push(c);
+                            for _ in 1..len {
+                                chars.next();
+                            }
+                        },
+                        None => {
+                            return self.failure(
+                                &i[idx..],
+                                "invalid escape sequence in IRI".to_string(),
+                            )
+                        },
+                    }
+                },
+                '<' | '"' | '{' | '}' | '|' | '^' | '`' => break,
+                c if c <= ' ' => break,
+                c => iri.push(c),
+            }
+        }
+        self.error(i, Expected::Description("an IRI"))
+    }
+
+    pub(crate) fn pname_ns<'a>(&self, i: &'a str) -> PResult<'a, String> {
+        let i = ws(i);
+        match scan_pname_ns(i) {
+            Some((rest, prefix)) => Ok((rest, prefix.to_string())),
+            None => self.error(i, Expected::Description("a prefix name")),
+        }
+    }
+
+    fn prefixed_name<'a>(&self, i: &'a str) -> PResult<'a, SparqlIri> {
+        let i = ws(i);
+        let (rest, prefix) = match scan_pname_ns(i) {
+            Some(result) => result,
+            None => return self.error(i, Expected::Description("a prefixed name")),
+        };
+        let len = scan_pn_local(rest);
+        Ok((&rest[len..], SparqlIri::PrefixedName {
+            prefix:     prefix.to_string(),
+            local_name: rest[..len].to_string(),
+        }))
+    }
+
+    fn iri<'a>(&self, i: &'a str) -> PResult<'a, SparqlIri> {
+        alt((map(|i| self.iriref(i), SparqlIri::Iri), |i| {
+            self.prefixed_name(i)
+        }))(i)
+    }
+
+    fn var<'a>(&self, i: &'a str) -> PResult<'a, Variable> {
+        let i = ws(i);
+        if i.starts_with(['?', '$']) {
+            let len = scan_var_name(&i[1..]);
+            if len > 0 {
+                return Ok((&i[1 + len..], Variable(i[1..1 + len].to_string())))
+            }
+        }
+        self.error(i, Expected::Description("a variable"))
+    }
+
+    fn blank_node<'a>(&self, i: &'a str) -> PResult<'a, TermPattern> {
+        let i = ws(i);
+        if let Some(label) = i.strip_prefix("_:") {
+            let mut chars = label.char_indices();
+            if let Some((_, c)) = chars.next() {
+                if is_pn_chars(c) && c != '-' {
+                    let mut end = c.len_utf8();
+                    for (idx, c) in chars {
+                        if is_pn_chars(c) {
+                            end = idx + c.len_utf8();
+                        } else if c != '.' {
+                            break
+                        }
+                    }
+                    return Ok((
+                        &label[end..],
+                        TermPattern::BlankNode(label[..end].to_string()),
+                    ))
+                }
+            }
+        }
+        if let Ok((rest, _)) = pair(self.tok("["), self.tok("]"))(i) {
+            return Ok((rest, self.new_blank_node()))
+        }
+        self.error(i, Expected::Description("a blank node"))
+    }
+
+    fn string<'a>(&self, i: &'a str) -> PResult<'a, String> {
+        let i = ws(i);
+        let quote = match i.chars().next() {
+            Some(c @ ('"' | '\'')) => c,
+            _ => return self.error(i, Expected::Description("a string")),
+        };
+        let long = i[1..].starts_with(quote) && i[2..].starts_with(quote);
+        let start = if long { 3 } else { 1 };
+        let mut value = String::new();
+        let mut chars = i.char_indices().skip(start).peekable();
+        while let Some((idx, c)) = chars.next() {
+            match c {
+                '\\' => {
+                    let escaped = match chars.peek() {
+                        Some((_, 't')) => '\t',
+                        Some((_, 'b')) => '\u{08}',
+                        Some((_, 'n')) => '\n',
+                        Some((_, 'r')) => '\r',
+                        Some((_, 'f')) => '\u{0C}',
+                        Some((_, '"')) => '"',
+                        Some((_, '\'')) => '\'',
+                        Some((_, '\\')) => '\\',
+                        Some((_, 'u')) | Some((_, 'U')) => {
+                            match unescape_unicode(&i[idx..]) {
+                                Some((c, len)) => {
+                                    value.push(c);
+                                    for _ in 1..len {
+                                        chars.next();
+                                    }
+                                    continue
+                                },
+                                None => {
+                                    return self.failure(
+                                        &i[idx..],
+                                        "invalid escape sequence in string".to_string(),
+                                    )
+                                },
+                            }
+                        },
+                        _ => {
+                            return self.failure(
+                                &i[idx..],
+                                "invalid escape sequence in string".to_string(),
+                            )
+                        },
+                    };
+                    chars.next();
+                    value.push(escaped);
+                },
+                c if c == quote => {
+                    if !long {
+                        return Ok((&i[idx + 1..], value))
+                    }
+                    if i[idx + 1..].starts_with(quote) && i[idx + 2..].starts_with(quote) {
+                        // A long string can end with up to two quotes
+                        if !i[idx + 3..].starts_with(quote) {
+                            return Ok((&i[idx + 3..], value))
+                        }
+                    }
+                    value.push(c);
+                },
+                '\n' | '\r' if !long => break,
+                c => value.push(c),
+            }
+        }
+        self.failure(i, "unterminated string".to_string())
+    }
+
+    fn lang_tag<'a>(&self, i: &'a str) -> PResult<'a, String> {
+        let i = ws(i);
+        if let Some(tag) = i.strip_prefix('@') {
+            let primary = tag
+                .find(|c: char| !c.is_ascii_alphabetic())
+                .unwrap_or(tag.len());
+            if primary > 0 {
+                let mut end = primary;
+                while tag[end..].starts_with('-') {
+                    let len = tag[end + 1..]
+                        .find(|c: char| !c.is_ascii_alphanumeric())
+                        .unwrap_or(tag.len() - end - 1);
+                    if len == 0 {
+                        break
+                    }
+                    end += 1 + len;
+                }
+                return Ok((&tag[end..], tag[..end].to_string()))
+            }
+        }
+        self.error(i, Expected::Description("a language tag"))
+    }
+
+    fn rdf_literal<'a>(&self, i: &'a str) -> PResult<'a, SparqlLiteral> {
+        let (i, lexical_form) = self.string(i)?;
+        if let Ok((rest, language)) = self.lang_tag(i) {
+            return Ok((rest, SparqlLiteral {
+                lexical_form,
+                language: Some(language),
+                data_type: None,
+            }))
+        }
+        let (i, data_type) = opt(preceded(self.tok("^^"), cut(|i| self.iri(i))))(i)?;
+        Ok((i, SparqlLiteral {
+            lexical_form,
+            language: None,
+            data_type,
+        }))
+    }
+
+    /// `NumericLiteral`, optionally with a sign.
+    fn numeric_literal<'a>(&self, i: &'a str, signed: bool) -> PResult<'a, SparqlLiteral> {
+        let i = ws(i);
+        let bytes = i.as_bytes();
+        let mut end = 0;
+        if signed && matches!(bytes.first(), Some(b'+') | Some(b'-')) {
+            end = 1;
+        }
+        let digits = |from: usize| {
+            bytes[from..]
+                .iter()
+                .take_while(|b| b.is_ascii_digit())
+                .count()
+        };
+        let integer_digits = digits(end);
+        end += integer_digits;
+        let mut data_type = XSD_INTEGER;
+        if bytes.get(end) == Some(&b'.') {
+            let fraction_digits = digits(end + 1);
+            let has_exponent = matches!(
+                bytes.get(end + 1 + fraction_digits),
+                Some(b'e') | Some(b'E')
+            );
+            if fraction_digits > 0 || (integer_digits > 0 && has_exponent) {
+                end += 1 + fraction_digits;
+                data_type = XSD_DECIMAL;
+            }
+        }
+        if integer_digits == 0 && data_type == XSD_INTEGER {
+            return self.error(i, Expected::Description("a number"))
+        }
+        if let Some(b'e') | Some(b'E') = bytes.get(end) {
+            let mut exponent_end = end + 1;
+            if matches!(bytes.get(exponent_end), Some(b'+') | Some(b'-')) {
+                exponent_end += 1;
+            }
+            let exponent_digits = digits(exponent_end);
+            if exponent_digits > 0 {
+                end = exponent_end + exponent_digits;
+                data_type = XSD_DOUBLE;
+            }
+        }
+        Ok((
+            &i[end..],
+            SparqlLiteral::new_typed(&i[..end], data_type),
+        ))
+    }
+
+    fn boolean_literal<'a>(&self, i: &'a str) -> PResult<'a, SparqlLiteral> {
+        alt((
+            map(self.kw("true"), |_| {
+                SparqlLiteral::new_typed("true", XSD_BOOLEAN)
+            }),
+            map(self.kw("false"), |_| {
+                SparqlLiteral::new_typed("false", XSD_BOOLEAN)
+            }),
+        ))(i)
+    }
+
+    fn nil<'a>(&self, i: &'a str) -> PResult<'a, ()> {
+        map(pair(self.tok("("), self.tok(")")), |_| ())(i)
+    }
+
+    // ------------------------------------------------------------------
+    // Queries
+    // ------------------------------------------------------------------
+
+    fn prologue<'a>(&self, mut i: &'a str) -> PResult<'a, Prologue> {
+        let mut prologue = Prologue::default();
+        loop {
+            if let Ok((rest, _)) = self.kw("BASE")(i) {
+                let (rest, base) = cut(|i| self.iriref(i))(rest)?;
+                prologue.base = Some(base);
+                i = rest;
+            } else if let Ok((rest, _)) = self.kw("PREFIX")(i) {
+                let (rest, (prefix, iri)) =
+                    cut(pair(|i| self.pname_ns(i), |i| self.iriref(i)))(rest)?;
+                prologue.prefixes.push((prefix, iri));
+                i = rest;
+            } else {
+                return Ok((i, prologue))
+            }
+        }
+    }
+
+    fn query<'a>(&self, i: &'a str) -> PResult<'a, Query> {
+        let (i, prologue) = self.prologue(i)?;
+        let (i, mut query) = alt((
+            |i| self.select_query(i),
+            |i| self.construct_query(i),
+            |i| self.describe_query(i),
+            |i| self.ask_query(i),
+        ))(i)?;
+        let (i, values) = self.values_clause(i)?;
+        query.prologue = prologue;
+        query.values = values;
+        Ok((i, query))
+    }
+
+    fn new_query(
+        form: QueryForm,
+        dataset: Vec<DatasetClause>,
+        where_clause: Option<GroupGraphPattern>,
+        modifiers: SolutionModifiers,
+    ) -> Query {
+        Query {
+            prologue: Prologue::default(),
+            form,
+            dataset,
+            where_clause,
+            modifiers,
+            values: None,
+        }
+    }
+
+    fn select_query<'a>(&self, i: &'a str) -> PResult<'a, Query> {
+        let (i, form) = self.select_clause(i)?;
+        let (i, dataset) = many0(|i| self.dataset_clause(i))(i)?;
+        let (i, where_clause) = cut(|i| self.where_clause(i))(i)?;
+        let (i, modifiers) = self.solution_modifier(i)?;
+        Ok((
+            i,
+            Self::new_query(form, dataset, Some(where_clause), modifiers),
+        ))
+    }
+
+    fn sub_select<'a>(&self, i: &'a str) -> PResult<'a, Query> {
+        let (i, form) = self.select_clause(i)?;
+        let (i, where_clause) = cut(|i| self.where_clause(i))(i)?;
+        let (i, modifiers) = self.solution_modifier(i)?;
+        let (i, values) = self.values_clause(i)?;
+        let mut query = Self::new_query(form, Vec::new(), Some(where_clause), modifiers);
+        query.values = values;
+        Ok((i, query))
+    }
+
+    fn select_clause<'a>(&self, i: &'a str) -> PResult<'a, QueryForm> {
+        let (i, _) = self.kw("SELECT")(i)?;
+        let (i, modifier) = map(
+            opt(alt((
+                map(self.kw("DISTINCT"), |_| SelectModifier::Distinct),
+                map(self.kw("REDUCED"), |_| SelectModifier::Reduced),
+            ))),
+            |modifier| modifier.unwrap_or(SelectModifier::None),
+        )(i)?;
+        let (i, projection) = cut(alt((
+            map(self.tok("*"), |_| Projection::All),
+            map(
+                many1(alt((
+                    map(|i| self.var(i), ProjectionItem::Variable),
+                    map(
+                        delimited(
+                            self.tok("("),
+                            pair(
+                                |i| self.expression(i),
+                                preceded(self.kw("AS"), |i| self.var(i)),
+                            ),
+                            self.tok(")"),
+                        ),
+                        |(expression, variable)| ProjectionItem::Expression(expression, variable),
+                    ),
+                ))),
+                Projection::Items,
+            ),
+        )))(i)?;
+        Ok((i, QueryForm::Select { modifier, projection }))
+    }
+
+    fn construct_query<'a>(&self, i: &'a str) -> PResult<'a, Query> {
+        let (i, _) = self.kw("CONSTRUCT")(i)?;
+        if let Ok((i, template)) = self.construct_template(i) {
+            let (i, dataset) = many0(|i| self.dataset_clause(i))(i)?;
+            let (i, where_clause) = cut(|i| self.where_clause(i))(i)?;
+            let (i, modifiers) = self.solution_modifier(i)?;
+            return Ok((
+                i,
+                Self::new_query(
+                    QueryForm::Construct { template: Some(template) },
+                    dataset,
+                    Some(where_clause),
+                    modifiers,
+                ),
+            ))
+        }
+        let (i, dataset) = many0(|i| self.dataset_clause(i))(i)?;
+        let (i, _) = cut(self.kw("WHERE"))(i)?;
+        let (rest, triples) = cut(delimited(
+            self.tok("{"),
+            opt(|i| self.triples_block(i)),
+            self.tok("}"),
+        ))(i)?;
+        let triples = triples.unwrap_or_default();
+        self.check_template(i, &triples)?;
+        let (rest, modifiers) = self.solution_modifier(rest)?;
+        Ok((
+            rest,
+            Self::new_query(
+                QueryForm::Construct { template: None },
+                dataset,
+                Some(GroupGraphPattern::Group(vec![
+                    PatternElement::Triples(triples),
+                ])),
+                modifiers,
+            ),
+        ))
+    }
+
+    fn construct_template<'a>(&self, i: &'a str) -> PResult<'a, Vec<TriplePattern>> {
+        let (rest, triples) = delimited(
+            self.tok("{"),
+            opt(|i| self.triples_block(i)),
+            cut(self.tok("}")),
+        )(i)?;
+        let triples = triples.unwrap_or_default();
+        self.check_template(i, &triples)?;
+        Ok((rest, triples))
+    }
+
+    fn describe_query<'a>(&self, i: &'a str) -> PResult<'a, Query> {
+        let (i, _) = self.kw("DESCRIBE")(i)?;
+        let (i, resources) = cut(alt((
+            map(self.tok("*"), |_| Vec::new()),
+            many1(|i| self.var_or_iri(i)),
+        )))(i)?;
+        let (i, dataset) = many0(|i| self.dataset_clause(i))(i)?;
+        let (i, where_clause) = opt(|i| self.where_clause(i))(i)?;
+        let (i, modifiers) = self.solution_modifier(i)?;
+        Ok((
+            i,
+            Self::new_query(
+                QueryForm::Describe { resources },
+                dataset,
+                where_clause,
+                modifiers,
+            ),
+        ))
+    }
+
+    fn ask_query<'a>(&self, i: &'a str) -> PResult<'a, Query> {
+        let (i, _) = self.kw("ASK")(i)?;
+        let (i, dataset) = many0(|i| self.dataset_clause(i))(i)?;
+        let (i, where_clause) = cut(|i| self.where_clause(i))(i)?;
+        let (i, modifiers) = self.solution_modifier(i)?;
+        Ok((
+            i,
+            Self::new_query(
+                QueryForm::Ask,
+                dataset,
+                Some(where_clause),
+                modifiers,
+            ),
+        ))
+    }
+
+    fn dataset_clause<'a>(&self, i: &'a str) -> PResult<'a, DatasetClause> {
+        let (i, _) = self.kw("FROM")(i)?;
+        let (i, named) = opt(self.kw("NAMED"))(i)?;
+        let (i, iri) = cut(|i| self.iri(i))(i)?;
+        Ok((i, DatasetClause { named: named.is_some(), iri }))
+    }
+
+    fn where_clause<'a>(&self, i: &'a str) -> PResult<'a, GroupGraphPattern> {
+        preceded(opt(self.kw("WHERE")), |i| {
+            self.group_graph_pattern(i)
+        })(i)
+    }
+
+    fn solution_modifier<'a>(&self, i: &'a str) -> PResult<'a, SolutionModifiers> {
+        let (i, group_by) = opt(preceded(
+            pair(self.kw("GROUP"), cut(self.kw("BY"))),
+            cut(many1(|i| self.group_condition(i))),
+        ))(i)?;
+        let (i, having) = opt(preceded(
+            self.kw("HAVING"),
+            cut(many1(|i| self.constraint(i))),
+        ))(i)?;
+        let (mut i, order_by) = opt(preceded(
+            pair(self.kw("ORDER"), cut(self.kw("BY"))),
+            cut(many1(|i| self.order_condition(i))),
+        ))(i)?;
+        let mut modifiers = SolutionModifiers {
+            group_by: group_by.unwrap_or_default(),
+            having:   having.unwrap_or_default(),
+            order_by: order_by.unwrap_or_default(),
+            limit:    None,
+            offset:   None,
+        };
+        loop {
+            if modifiers.limit.is_none() {
+                if let Ok((rest, _)) = self.kw("LIMIT")(i) {
+                    let (rest, limit) = cut(|i| self.unsigned_integer(i))(rest)?;
+                    modifiers.limit = Some(limit);
+                    i = rest;
+                    continue
+                }
+            }
+            if modifiers.offset.is_none() {
+                if let Ok((rest, _)) = self.kw("OFFSET")(i) {
+                    let (rest, offset) = cut(|i| self.unsigned_integer(i))(rest)?;
+                    modifiers.offset = Some(offset);
+                    i = rest;
+                    continue
+                }
+            }
+            return Ok((i, modifiers))
+        }
+    }
+
+    fn unsigned_integer<'a>(&self, i: &'a str) -> PResult<'a, u64> {
+        let i = ws(i);
+        let len = i.find(|c: char| !c.is_ascii_digit()).unwrap_or(i.len());
+        match i[..len].parse::<u64>() {
+            Ok(value) => Ok((&i[len..], value)),
+            Err(_) => self.error(i, Expected::Description("an integer")),
+        }
+    }
+
+    fn group_condition<'a>(&self, i: &'a str) -> PResult<'a, GroupCondition> {
+        alt((
+            map(
+                |i| self.built_in_call(i),
+                |expression| GroupCondition { expression, variable: None },
+            ),
+            map(
+                |i| self.function_call(i),
+                |expression| GroupCondition { expression, variable: None },
+            ),
+            map(
+                delimited(
+                    self.tok("("),
+                    pair(
+                        |i| self.expression(i),
+                        opt(preceded(self.kw("AS"), |i| self.var(i))),
+                    ),
+                    self.tok(")"),
+                ),
+                |(expression, variable)| GroupCondition { expression, variable },
+            ),
+            map(
+                |i| self.var(i),
+                |variable| {
+                    GroupCondition {
+                        expression: Expression::Term(TermPattern::Variable(variable)),
+                        variable:   None,
+                    }
+                },
+            ),
+        ))(i)
+    }
+
+    fn order_condition<'a>(&self, i: &'a str) -> PResult<'a, OrderCondition> {
+        alt((
+            map(
+                pair(
+                    alt((
+                        map(self.kw("ASC"), |_| false),
+                        map(self.kw("DESC"), |_| true),
+                    )),
+                    cut(|i| self.bracketted_expression(i)),
+                ),
+                |(descending, expression)| OrderCondition { expression, descending },
+            ),
+            map(
+                |i| self.constraint(i),
+                |expression| OrderCondition { expression, descending: false },
+            ),
+            map(
+                |i| self.var(i),
+                |variable| {
+                    OrderCondition {
+                        expression: Expression::Term(TermPattern::Variable(variable)),
+                        descending: false,
+                    }
+                },
+            ),
+        ))(i)
+    }
+
+    fn values_clause<'a>(&self, i: &'a str) -> PResult<'a, Option<InlineData>> {
+        opt(preceded(
+            self.kw("VALUES"),
+            cut(|i| self.data_block(i)),
+        ))(i)
+    }
+
+    fn data_block<'a>(&self, i: &'a str) -> PResult<'a, InlineData> {
+        if let Ok((i, variable)) = self.var(i) {
+            let (i, values) = delimited(
+                self.tok("{"),
+                many0(|i| self.data_block_value(i)),
+                self.tok("}"),
+            )(i)?;
+            return Ok((i, InlineData {
+                variables: vec![variable],
+                rows:      values.into_iter().map(|value| vec![value]).collect(),
+            }))
+        }
+        let (i, variables) = delimited(
+            self.tok("("),
+            many0(|i| self.var(i)),
+            self.tok(")"),
+        )(i)?;
+        let (rest, rows) = delimited(
+            self.tok("{"),
+            many0(delimited(
+                self.tok("("),
+                many0(|i| self.data_block_value(i)),
+                self.tok(")"),
+            )),
+            self.tok("}"),
+        )(i)?;
+        if let Some(row) = rows.iter().find(|row| row.len() != variables.len()) {
+            return self.failure(
+                i,
+                format!(
+                    "VALUES block has {} variables but a row with {} values",
+                    variables.len(),
+                    row.len()
+                ),
+            )
+        }
+        Ok((rest, InlineData { variables, rows }))
+    }
+
+    fn data_block_value<'a>(&self, i: &'a str) -> PResult<'a, Option<TermPattern>> {
+        alt((
+            map(self.kw("UNDEF"), |_| None),
+            map(|i| self.iri(i), |iri| Some(TermPattern::Iri(iri))),
+            map(
+                |i| self.literal(i),
+                |literal| Some(TermPattern::Literal(literal)),
+            ),
+        ))(i)
+    }
+
+    /// `RDFLiteral | NumericLiteral | BooleanLiteral`
+    fn literal<'a>(&self, i: &'a str) -> PResult<'a, SparqlLiteral> {
+        alt((
+            |i| self.rdf_literal(i),
+            |i| self.numeric_literal(i, true),
+            |i| self.boolean_literal(i),
+        ))(i)
+    }
+
+    // ------------------------------------------------------------------
+    // Graph patterns
+    // ------------------------------------------------------------------
+
+    fn group_graph_pattern<'a>(&self, i: &'a str) -> PResult<'a, GroupGraphPattern> {
+        delimited(
+            self.tok("{"),
+            cut(alt((
+                map(
+                    |i| self.sub_select(i),
+                    |query| GroupGraphPattern::SubSelect(Box::new(query)),
+                ),
+                map(
+                    |i| self.group_graph_pattern_sub(i),
+                    GroupGraphPattern::Group,
+                ),
+            ))),
+            cut(self.tok("}")),
+        )(i)
+    }
+
+    fn group_graph_pattern_sub<'a>(&self, i: &'a str) -> PResult<'a, Vec<PatternElement>> {
+        let mut elements = Vec::new();
+        let (mut i, triples) = opt(|i| self.triples_block(i))(i)?;
+        if let Some(triples) = triples {
+            elements.push(PatternElement::Triples(triples));
+        }
+        loop {
+            match self.graph_pattern_not_triples(i) {
+                Ok((rest, element)) => {
+                    elements.push(element);
+                    let (rest, _) = opt(self.tok("."))(rest)?;
+                    let (rest, triples) = opt(|i| self.triples_block(i))(rest)?;
+                    if let Some(triples) = triples {
+                        elements.push(PatternElement::Triples(triples));
+                    }
+                    i = rest;
+                },
+                Err(nom::Err::Error(_)) => return Ok((i, elements)),
+                Err(error) => return Err(error),
+            }
+        }
+    }
+
+    fn graph_pattern_not_triples<'a>(&self, i: &'a str) -> PResult<'a, PatternElement> {
+        alt((
+            map(
+                separated_list1(self.kw("UNION"), |i| self.group_graph_pattern(i)),
+                PatternElement::Union,
+            ),
+            map(
+                preceded(
+                    self.kw("OPTIONAL"),
+                    cut(|i| self.group_graph_pattern(i)),
+                ),
+                PatternElement::Optional,
+            ),
+            map(
+                preceded(
+                    self.kw("MINUS"),
+                    cut(|i| self.group_graph_pattern(i)),
+                ),
+                PatternElement::Minus,
+            ),
+            map(
+                preceded(
+                    self.kw("GRAPH"),
+                    cut(pair(
+                        |i| self.var_or_iri(i),
+                        |i| self.group_graph_pattern(i),
+                    )),
+                ),
+                |(name, pattern)| PatternElement::Graph { name, pattern },
+            ),
+            map(
+                preceded(
+                    self.kw("SERVICE"),
+                    cut(tuple((
+                        |i| self.silent(i),
+                        |i| self.var_or_iri(i),
+                        |i| self.group_graph_pattern(i),
+                    ))),
+                ),
+                |(silent, name, pattern)| PatternElement::Service { silent, name, pattern },
+            ),
+            map(
+                preceded(self.kw("FILTER"), cut(|i| self.constraint(i))),
+                PatternElement::Filter,
+            ),
+            map(
+                preceded(
+                    self.kw("BIND"),
+                    cut(delimited(
+                        self.tok("("),
+                        pair(
+                            |i| self.expression(i),
+                            preceded(self.kw("AS"), |i| self.var(i)),
+                        ),
+                        self.tok(")"),
+                    )),
+                ),
+                |(expression, variable)| PatternElement::Bind { expression, variable },
+            ),
+            map(
+                preceded(self.kw("VALUES"), cut(|i| self.data_block(i))),
+                PatternElement::Values,
+            ),
+        ))(i)
+    }
+
+    /// `TriplesBlock`, `TriplesTemplate` and `ConstructTriples`, which only
+    /// differ in whether property paths are allowed, see
+    /// [`check_template`](Self::check_template).
+    fn triples_block<'a>(&self, i: &'a str) -> PResult<'a, Vec<TriplePattern>> {
+        let (mut i, mut triples) = self.triples_same_subject(i)?;
+        loop {
+            match self.tok(".")(i) {
+                Ok((rest, _)) => i = rest,
+                Err(_) => return Ok((i, triples)),
+            }
+            match self.triples_same_subject(i) {
+                Ok((rest, more)) => {
+                    triples.extend(more);
+                    i = rest;
+                },
+                Err(nom::Err::Error(_)) => return Ok((i, triples)),
+                Err(error) => return Err(error),
+            }
+        }
+    }
+
+    pub(crate) fn triples_same_subject<'a>(&self, i: &'a str) -> PResult<'a, Vec<TriplePattern>> {
+        let mut triples = Vec::new();
+        match self.var_or_term(i) {
+            Ok((i, subject)) => {
+                let (i, _) = self.property_list_not_empty(i, &subject, &mut triples)?;
+                return Ok((i, triples))
+            },
+            Err(nom::Err::Error(_)) => {},
+            Err(error) => return Err(error),
+        }
+        let (i, subject) = self.triples_node(i, &mut triples)?;
+        match self.property_list_not_empty(i, &subject, &mut triples) {
+            Ok((i, _)) => Ok((i, triples)),
+            Err(nom::Err::Error(_)) => Ok((i, triples)),
+            Err(error) => Err(error),
+        }
+    }
+
+    fn property_list_not_empty<'a>(
+        &self,
+        i: &'a str,
+        subject: &TermPattern,
+        triples: &mut Vec<TriplePattern>,
+    ) -> PResult<'a, ()> {
+        let (i, verb) = self.verb(i)?;
+        let (mut i, _) = self.object_list(i, subject, &verb, triples)?;
+        loop {
+            match self.tok(";")(i) {
+                Ok((rest, _)) => i = rest,
+                Err(_) => return Ok((i, ())),
+            }
+            match self.verb(i) {
+                Ok((rest, verb)) => i = self.object_list(rest, subject, &verb, triples)?.0,
+                Err(nom::Err::Error(_)) => {},
+                Err(error) => return Err(error),
+            }
+        }
+    }
+
+    fn verb<'a>(&self, i: &'a str) -> PResult<'a, Verb> {
+        alt((
+            map(|i| self.var(i), Verb::Variable),
+            map(|i| self.path(i), Verb::Path),
+        ))(i)
+    }
+
+    fn object_list<'a>(
+        &self,
+        i: &'a str,
+        subject: &TermPattern,
+        verb: &Verb,
+        triples: &mut Vec<TriplePattern>,
+    ) -> PResult<'a, ()> {
+        let mut i = i;
+        loop {
+            let (rest, object) = cut(|i| self.graph_node(i, triples))(i)?;
+            triples.push(TriplePattern {
+                subject: subject.clone(),
+                predicate: verb.clone(),
+                object,
+            });
+            match self.tok(",")(rest) {
+                Ok((rest, _)) => i = rest,
+                Err(_) => return Ok((rest, ())),
+            }
+        }
+    }
+
+    fn graph_node<'a>(
+        &self,
+        i: &'a str,
+        triples: &mut Vec<TriplePattern>,
+    ) -> PResult<'a, TermPattern> {
+        match self.var_or_term(i) {
+            Err(nom::Err::Error(_)) => self.triples_node(i, triples),
+            result => result,
+        }
+    }
+
+    fn triples_node<'a>(
+        &self,
+        i: &'a str,
+        triples: &mut Vec<TriplePattern>,
+    ) -> PResult<'a, TermPattern> {
+        if let Ok((i, _)) = self.tok("[")(i) {
+            let node = self.new_blank_node();
+            let (i, _) = cut(|i| self.property_list_not_empty(i, &node, triples))(i)?;
+            let (i, _) = cut(self.tok("]"))(i)?;
+            return Ok((i, node))
+        }
+        let (mut i, _) = self.tok("(")(i)?;
+        let mut items = Vec::new();
+        loop {
+            if let Ok((rest, _)) = self.tok(")")(i) {
+                i = rest;
+                break
+            }
+            let (rest, item) = cut(|i| self.graph_node(i, triples))(i)?;
+            items.push(item);
+            i = rest;
+        }
+        let nodes = items
+            .iter()
+            .map(|_| self.new_blank_node())
+            .collect::<Vec<_>>();
+        for (idx, item) in items.into_iter().enumerate() {
+            triples.push(TriplePattern {
+                subject:   nodes[idx].clone(),
+                predicate: Verb::Path(PropertyPath::Iri(SparqlIri::Iri(
+                    RDF_FIRST.to_string(),
+                ))),
+                object:    item,
+            });
+            triples.push(TriplePattern {
+                subject:   nodes[idx].clone(),
+                predicate: Verb::Path(PropertyPath::Iri(SparqlIri::Iri(
+                    RDF_REST.to_string(),
+                ))),
+                object:    nodes
+                    .get(idx + 1)
+                    .cloned()
+                    .unwrap_or_else(|| TermPattern::Iri(SparqlIri::Iri(RDF_NIL.to_string()))),
+            });
+        }
+        Ok((i, nodes[0].clone()))
+    }
+
+    fn var_or_term<'a>(&self, i: &'a str) -> PResult<'a, TermPattern> {
+        alt(
+            (map(|i| self.var(i), TermPattern::Variable), |i| {
+                self.graph_term(i)
+            }),
+        )(i)
+    }
+
+    fn var_or_iri<'a>(&self, i: &'a str) -> PResult<'a, TermPattern> {
+        alt((
+            map(|i| self.var(i), TermPattern::Variable),
+            map(|i| self.iri(i), TermPattern::Iri),
+        ))(i)
+    }
+
+    fn graph_term<'a>(&self, i: &'a str) -> PResult<'a, TermPattern> {
+        alt((
+            map(|i| self.iri(i), TermPattern::Iri),
+            map(|i| self.literal(i), TermPattern::Literal),
+            |i| self.blank_node(i),
+            map(
+                |i| self.nil(i),
+                |_| TermPattern::Iri(SparqlIri::Iri(RDF_NIL.to_string())),
+            ),
+        ))(i)
+    }
+
+    // ------------------------------------------------------------------
+    // Property paths
+    // ------------------------------------------------------------------
+
+    fn path<'a>(&self, i: &'a str) -> PResult<'a, PropertyPath> {
+        map(
+            separated_list1(self.tok("|"), |i| self.path_sequence(i)),
+            |mut paths| {
+                if paths.len() == 1 {
+                    paths.remove(0)
+                } else {
+                    PropertyPath::Alternative(paths)
+                }
+            },
+        )(i)
+    }
+
+    fn path_sequence<'a>(&self, i: &'a str) -> PResult<'a, PropertyPath> {
+        map(
+            separated_list1(self.tok("/"), |i| self.path_elt_or_inverse(i)),
+            |mut paths| {
+                if paths.len() == 1 {
+                    paths.remove(0)
+                } else {
+                    PropertyPath::Sequence(paths)
+                }
+            },
+        )(i)
+    }
+
+    fn path_elt_or_inverse<'a>(&self, i: &'a str) -> PResult<'a, PropertyPath> {
+        alt((
+            map(
+                preceded(self.tok("^"), cut(|i| self.path_elt(i))),
+                |path| PropertyPath::Inverse(Box::new(path)),
+            ),
+            |i| self.path_elt(i),
+        ))(i)
+    }
+
+    fn path_elt<'a>(&self, i: &'a str) -> PResult<'a, PropertyPath> {
+        let (i, primary) = self.path_primary(i)?;
+        let rest = ws(i);
+        let next = rest[1.min(rest.len())..].chars().next();
+        let path = match rest.chars().next() {
+            // `?` followed by a name is a variable, `+` followed by a digit a number
+            Some('?') if !matches!(next, Some(c) if scan_var_name(&c.to_string()) > 0) => {
+                PropertyPath::ZeroOrOne(Box::new(primary))
+            },
+            Some('*') => PropertyPath::ZeroOrMore(Box::new(primary)),
+            Some('+') if !matches!(next, Some(c) if c.is_ascii_digit() || c == '.') => {
+                PropertyPath::OneOrMore(Box::new(primary))
+            },
+            _ => return Ok((i, primary)),
+        };
+        Ok((&rest[1..], path))
+    }
+
+    fn path_primary<'a>(&self, i: &'a str) -> PResult<'a, PropertyPath> {
+        alt((
+            map(|i| self.iri_or_a(i), PropertyPath::Iri),
+            preceded(
+                self.tok("!"),
+                cut(|i| self.path_negated_property_set(i)),
+            ),
+            delimited(
+                self.tok("("),
+                cut(|i| self.path(i)),
+                cut(self.tok(")")),
+            ),
+        ))(i)
+    }
+
+    fn iri_or_a<'a>(&self, i: &'a str) -> PResult<'a, SparqlIri> {
+        alt((
+            |i| self.iri(i),
+            map(self.kw("a"), |_| {
+                SparqlIri::Iri(RDF_TYPE.to_string())
+            }),
+        ))(i)
+    }
+
+    fn path_negated_property_set<'a>(&self, i: &'a str) -> PResult<'a, PropertyPath> {
+        map(
+            alt((
+                map(
+                    |i| self.path_one_in_property_set(i),
+                    |iri| vec![iri],
+                ),
+                delimited(
+                    self.tok("("),
+                    separated_list0(self.tok("|"), |i| {
+                        self.path_one_in_property_set(i)
+                    }),
+                    self.tok(")"),
+                ),
+            )),
+            PropertyPath::NegatedPropertySet,
+        )(i)
+    }
+
+    fn path_one_in_property_set<'a>(&self, i: &'a str) -> PResult<'a, (SparqlIri, bool)> {
+        alt((
+            map(|i| self.iri_or_a(i), |iri| (iri, false)),
+            map(
+                preceded(self.tok("^"), |i| self.iri_or_a(i)),
+                |iri| (iri, true),
+            ),
+        ))(i)
+    }
+
+    // ------------------------------------------------------------------
+    // Expressions
+    // ------------------------------------------------------------------
+
+    fn constraint<'a>(&self, i: &'a str) -> PResult<'a, Expression> {
+        alt((
+            |i| self.bracketted_expression(i),
+            |i| self.built_in_call(i),
+            |i| self.function_call(i),
+        ))(i)
+    }
+
+    fn bracketted_expression<'a>(&self, i: &'a str) -> PResult<'a, Expression> {
+        delimited(
+            self.tok("("),
+            cut(|i| self.expression(i)),
+            cut(self.tok(")")),
+        )(i)
+    }
+
+    fn expression<'a>(&self, i: &'a str) -> PResult<'a, Expression> {
+        self.binary_chain(
+            i,
+            &[("||", BinaryOperator::Or)],
+            Self::conditional_and,
+        )
+    }
+
+    fn conditional_and<'a>(&self, i: &'a str) -> PResult<'a, Expression> {
+        self.binary_chain(
+            i,
+            &[("&&", BinaryOperator::And)],
+            Self::relational,
+        )
+    }
+
+    /// Parse operands separated by (left associative) binary operators.
+    fn binary_chain<'a>(
+        &self,
+        i: &'a str,
+        operators: &[(&'static str, BinaryOperator)],
+        operand: fn(&Self, &'a str) -> PResult<'a, Expression>,
+    ) -> PResult<'a, Expression> {
+        let (mut i, mut left) = operand(self, i)?;
+        'outer: loop {
+            for (token, operator) in operators {
+                if let Ok((rest, _)) = self.tok(token)(i) {
+                    let (rest, right) = cut(|i| operand(self, i))(rest)?;
+                    left = Expression::Binary(*operator, Box::new(left), Box::new(right));
+                    i = rest;
+                    continue 'outer
+                }
+            }
+            return Ok((i, left))
+        }
+    }
+
+    fn relational<'a>(&self, i: &'a str) -> PResult<'a, Expression> {
+        let (i, left) = self.additive(i)?;
+        for (token, operator) in [
+            ("=", BinaryOperator::Equal),
+            ("!=", BinaryOperator::NotEqual),
+            ("<=", BinaryOperator::LessOrEqual),
+            (">=", BinaryOperator::GreaterOrEqual),
+            ("<", BinaryOperator::Less),
+            (">", BinaryOperator::Greater),
+        ] {
+            if let Ok((rest, _)) = self.tok(token)(i) {
+                let (rest, right) = cut(|i| self.additive(i))(rest)?;
+                return Ok((
+                    rest,
+                    Expression::Binary(operator, Box::new(left), Box::new(right)),
+                ))
+            }
+        }
+        let (rest, negated) = match self.kw("IN")(i) {
+            Ok((rest, _)) => (rest, false),
+            Err(_) => {
+                match pair(self.kw("NOT"), self.kw("IN"))(i) {
+                    Ok((rest, _)) => (rest, true),
+                    Err(_) => return Ok((i, left)),
+                }
+            },
+        };
+        let (rest, list) = cut(|i| self.expression_list(i))(rest)?;
+        Ok((rest, Expression::In {
+            expression: Box::new(left),
+            list,
+            negated,
+        }))
+    }
+
+    fn additive<'a>(&self, i: &'a str) -> PResult<'a, Expression> {
+        self.binary_chain(
+            i,
+            &[("+", BinaryOperator::Add), ("-", BinaryOperator::Subtract)],
+            Self::multiplicative,
+        )
+    }
+
+    fn multiplicative<'a>(&self, i: &'a str) -> PResult<'a, Expression> {
+        self.binary_chain(
+            i,
+            &[
+                ("*", BinaryOperator::Multiply),
+                ("/", BinaryOperator::Divide),
+            ],
+            Self::unary,
+        )
+    }
+
+    fn unary<'a>(&self, i: &'a str) -> PResult<'a, Expression> {
+        alt((
+            map(
+                preceded(self.tok("!"), cut(|i| self.primary(i))),
+                |expression| Expression::Unary(UnaryOperator::Not, Box::new(expression)),
+            ),
+            map(
+                |i| self.numeric_literal(i, true),
+                |literal| Expression::Term(TermPattern::Literal(literal)),
+            ),
+            map(
+                preceded(self.tok("+"), cut(|i| self.primary(i))),
+                |expression| Expression::Unary(UnaryOperator::Plus, Box::new(expression)),
+            ),
+            map(
+                preceded(self.tok("-"), cut(|i| self.primary(i))),
+                |expression| Expression::Unary(UnaryOperator::Minus, Box::new(expression)),
+            ),
+            |i| self.primary(i),
+        ))(i)
+    }
+
+    fn primary<'a>(&self, i: &'a str) -> PResult<'a, Expression> {
+        alt((
+            |i| self.bracketted_expression(i),
+            |i| self.built_in_call(i),
+            |i| self.iri_or_function(i),
+            map(
+                |i| self.literal(i),
+                |literal| Expression::Term(TermPattern::Literal(literal)),
+            ),
+            map(
+                |i| self.var(i),
+                |variable| Expression::Term(TermPattern::Variable(variable)),
+            ),
+        ))(i)
+    }
+
+    fn iri_or_function<'a>(&self, i: &'a str) -> PResult<'a, Expression> {
+        let (i, iri) = self.iri(i)?;
+        match self.arg_list(i) {
+            Ok((i, (distinct, arguments))) => {
+                Ok((i, Expression::FunctionCall {
+                    function: Function::Iri(iri),
+                    arguments,
+                    distinct,
+                }))
+            },
+            Err(nom::Err::Error(_)) => Ok((i, Expression::Term(TermPattern::Iri(iri)))),
+            Err(error) => Err(error),
+        }
+    }
+
+    fn function_call<'a>(&self, i: &'a str) -> PResult<'a, Expression> {
+        let (i, iri) = self.iri(i)?;
+        let (i, (distinct, arguments)) = self.arg_list(i)?;
+        Ok((i, Expression::FunctionCall {
+            function: Function::Iri(iri),
+            arguments,
+            distinct,
+        }))
+    }
+
+    fn arg_list<'a>(&self, i: &'a str) -> PResult<'a, (bool, Vec<Expression>)> {
+        alt((
+            map(|i| self.nil(i), |_| (false, Vec::new())),
+            delimited(
+                self.tok("("),
+                pair(
+                    map(opt(self.kw("DISTINCT")), |distinct| {
+                        distinct.is_some()
+                    }),
+                    cut(separated_list1(self.tok(","), |i| {
+                        self.expression(i)
+                    })),
+                ),
+                cut(self.tok(")")),
+            ),
+        ))(i)
+    }
+
+    fn expression_list<'a>(&self, i: &'a str) -> PResult<'a, Vec<Expression>> {
+        alt((
+            map(|i| self.nil(i), |_| Vec::new()),
+            delimited(
+                self.tok("("),
+                cut(separated_list1(self.tok(","), |i| {
+                    self.expression(i)
+                })),
+                cut(self.tok(")")),
+            ),
+        ))(i)
+    }
+
+    fn built_in_call<'a>(&self, i: &'a str) -> PResult<'a, Expression> {
+        alt((
+            |i| self.aggregate(i),
+            map(
+                preceded(
+                    self.kw("EXISTS"),
+                    cut(|i| self.group_graph_pattern(i)),
+                ),
+                |pattern| Expression::Exists { pattern: Box::new(pattern), negated: false },
+            ),
+            map(
+                preceded(
+                    pair(self.kw("NOT"), self.kw("EXISTS")),
+                    cut(|i| self.group_graph_pattern(i)),
+                ),
+                |pattern| Expression::Exists { pattern: Box::new(pattern), negated: true },
+            ),
+            map(
+                preceded(
+                    self.kw("BOUND"),
+                    cut(delimited(
+                        self.tok("("),
+                        |i| self.var(i),
+                        self.tok(")"),
+                    )),
+                ),
+                |variable| {
+                    Expression::FunctionCall {
+                        function:  Function::BuiltIn("BOUND".to_string()),
+                        arguments: vec![Expression::Term(TermPattern::Variable(variable))],
+                        distinct:  false,
+                    }
+                },
+            ),
+            |i| self.built_in_function(i),
+        ))(i)
+    }
+
+    fn built_in_function<'a>(&self, i: &'a str) -> PResult<'a, Expression> {
+        let i = ws(i);
+        let len = i
+            .find(|c: char| !(c.is_ascii_alphanumeric() || c == '_'))
+            .unwrap_or(i.len());
+        let name = i[..len].to_ascii_uppercase();
+        let (min, max) = match BUILT_IN_FUNCTIONS
+            .iter()
+            .find(|(function, ..)| *function == name.as_str())
+        {
+            Some((_, min, max)) if !i[len..].starts_with(':') => (*min, *max),
+            _ => return self.error(i, Expected::Description("a function call")),
+        };
+        let (rest, arguments) = cut(|i| self.expression_list(i))(&i[len..])?;
+        if arguments.len() < min || arguments.len() > max {
+            let expected = if min == max {
+                format!("{min}")
+            } else if max == usize::MAX {
+                format!("at least {min}")
+            } else {
+                format!("{min} to {max}")
+            };
+            return self.failure(
+                i,
+                format!(
+                    "{name} expects {expected} argument(s) but got {}",
+                    arguments.len()
+                ),
+            )
+        }
+        Ok((rest, Expression::FunctionCall {
+            function: Function::BuiltIn(name),
+            arguments,
+            distinct: false,
+        }))
+    }
+
+    fn aggregate<'a>(&self, i: &'a str) -> PResult<'a, Expression> {
+        let (i, function) = alt((
+            map(self.kw("COUNT"), |_| AggregateFunction::Count),
+            map(self.kw("SUM"), |_| AggregateFunction::Sum),
+            map(self.kw("MIN"), |_| AggregateFunction::Min),
+            map(self.kw("MAX"), |_| AggregateFunction::Max),
+            map(self.kw("AVG"), |_| AggregateFunction::Avg),
+            map(self.kw("SAMPLE"), |_| AggregateFunction::Sample),
+            map(self.kw("GROUP_CONCAT"), |_| {
+                AggregateFunction::GroupConcat
+            }),
+        ))(i)?;
+        let (i, _) = cut(self.tok("("))(i)?;
+        let (i, distinct) = map(opt(self.kw("DISTINCT")), |distinct| {
+            distinct.is_some()
+        })(i)?;
+        let (i, expression) = if function == AggregateFunction::Count {
+            cut(alt((
+                map(self.tok("*"), |_| None),
+                map(
+                    |i| self.expression(i),
+                    |expression| Some(Box::new(expression)),
+                ),
+            )))(i)?
+        } else {
+            cut(map(
+                |i| self.expression(i),
+                |expression| Some(Box::new(expression)),
+            ))(i)?
+        };
+        let (i, separator) = if function == AggregateFunction::GroupConcat {
+            opt(preceded(
+                tuple((self.tok(";"), self.kw("SEPARATOR"), self.tok("="))),
+                cut(|i| self.string(i)),
+            ))(i)?
+        } else {
+            (i, None)
+        };
+        let (i, _) = cut(self.tok(")"))(i)?;
+        Ok((i, Expression::Aggregate {
+            function,
+            distinct,
+            expression,
+            separator,
+        }))
+    }
+
+    // ------------------------------------------------------------------
+    // Updates
+    // ------------------------------------------------------------------
+
+    fn update<'a>(&self, i: &'a str) -> PResult<'a, Update> {
+        let (mut i, prologue) = self.prologue(i)?;
+        let mut update = Update { prologue, operations: Vec::new() };
+        loop {
+            match self.update1(i) {
+                Ok((rest, operation)) => {
+                    update.operations.push(operation);
+                    i = rest;
+                },
+                Err(nom::Err::Error(_)) => return Ok((i, update)),
+                Err(error) => return Err(error),
+            }
+            match self.tok(";")(i) {
+                Ok((rest, _)) => {
+                    let (rest, prologue) = self.prologue(rest)?;
+                    if prologue.base.is_some() {
+                        update.prologue.base = prologue.base;
+                    }
+                    update.prologue.prefixes.extend(prologue.prefixes);
+                    i = rest;
+                },
+                Err(_) => return Ok((i, update)),
+            }
+        }
+    }
+
+    fn update1<'a>(&self, i: &'a str) -> PResult<'a, UpdateOperation> {
+        alt((
+            |i| self.load(i),
+            |i| self.clear_or_drop(i),
+            |i| self.create(i),
+            |i| self.transfer(i),
+            |i| self.insert_data(i),
+            |i| self.delete_data(i),
+            |i| self.delete_where(i),
+            |i| self.modify(i),
+        ))(i)
+    }
+
+    fn load<'a>(&self, i: &'a str) -> PResult<'a, UpdateOperation> {
+        let (i, _) = self.kw("LOAD")(i)?;
+        let (i, (silent, source, destination)) = cut(tuple((
+            |i| self.silent(i),
+            |i| self.iri(i),
+            opt(preceded(
+                self.kw("INTO"),
+                cut(|i| self.graph_ref(i)),
+            )),
+        )))(i)?;
+        Ok((i, UpdateOperation::Load {
+            silent,
+            source,
+            destination,
+        }))
+    }
+
+    fn clear_or_drop<'a>(&self, i: &'a str) -> PResult<'a, UpdateOperation> {
+        let (i, clear) = alt((
+            map(self.kw("CLEAR"), |_| true),
+            map(self.kw("DROP"), |_| false),
+        ))(i)?;
+        let (i, (silent, target)) = cut(pair(
+            |i| self.silent(i),
+            alt((
+                map(|i| self.graph_ref(i), GraphTarget::Graph),
+                map(self.kw("DEFAULT"), |_| GraphTarget::Default),
+                map(self.kw("NAMED"), |_| GraphTarget::Named),
+                map(self.kw("ALL"), |_| GraphTarget::All),
+            )),
+        ))(i)?;
+        if clear {
+            Ok((i, UpdateOperation::Clear { silent, target }))
+        } else {
+            Ok((i, UpdateOperation::Drop { silent, target }))
+        }
+    }
+
+    fn create<'a>(&self, i: &'a str) -> PResult<'a, UpdateOperation> {
+        let (i, _) = self.kw("CREATE")(i)?;
+        let (i, (silent, graph)) = cut(pair(|i| self.silent(i), |i| self.graph_ref(i)))(i)?;
+        Ok((i, UpdateOperation::Create { silent, graph }))
+    }
+
+    fn transfer<'a>(&self, i: &'a str) -> PResult<'a, UpdateOperation> {
+        let (i, operation) = alt((
+            map(self.kw("ADD"), |_| GraphTransfer::Add),
+            map(self.kw("MOVE"), |_| GraphTransfer::Move),
+            map(self.kw("COPY"), |_| GraphTransfer::Copy),
+        ))(i)?;
+        let (i, (silent, source, _, destination)) = cut(tuple((
+            |i| self.silent(i),
+            |i| self.graph_or_default(i),
+            self.kw("TO"),
+            |i| self.graph_or_default(i),
+        )))(i)?;
+        Ok((i, UpdateOperation::Transfer {
+            operation,
+            silent,
+            source,
+            destination,
+        }))
+    }
+
+    fn graph_ref<'a>(&self, i: &'a str) -> PResult<'a, SparqlIri> {
+        preceded(self.kw("GRAPH"), cut(|i| self.iri(i)))(i)
+    }
+
+    fn graph_or_default<'a>(&self, i: &'a str) -> PResult<'a, GraphOrDefault> {
+        alt((
+            map(self.kw("DEFAULT"), |_| GraphOrDefault::Default),
+            map(
+                preceded(opt(self.kw("GRAPH")), |i| self.iri(i)),
+                GraphOrDefault::Graph,
+            ),
+        ))(i)
+    }
+
+    fn insert_data<'a>(&self, i: &'a str) -> PResult<'a, UpdateOperation> {
+        let (i, _) = pair(self.kw("INSERT"), self.kw("DATA"))(i)?;
+        let (rest, quads) = cut(|i| self.quad_pattern(i))(i)?;
+        self.check_quads(i, &quads, false, false)?;
+        Ok((rest, UpdateOperation::InsertData(quads)))
+    }
+
+    fn delete_data<'a>(&self, i: &'a str) -> PResult<'a, UpdateOperation> {
+        let (i, _) = pair(self.kw("DELETE"), self.kw("DATA"))(i)?;
+        let (rest, quads) = cut(|i| self.quad_pattern(i))(i)?;
+        self.check_quads(i, &quads, false, true)?;
+        Ok((rest, UpdateOperation::DeleteData(quads)))
+    }
+
+    fn delete_where<'a>(&self, i: &'a str) -> PResult<'a, UpdateOperation> {
+        let (i, _) = pair(self.kw("DELETE"), self.kw("WHERE"))(i)?;
+        let (rest, quads) = cut(|i| self.quad_pattern(i))(i)?;
+        self.check_quads(i, &quads, true, true)?;
+        Ok((rest, UpdateOperation::DeleteWhere(quads)))
+    }
+
+    fn modify<'a>(&self, i: &'a str) -> PResult<'a, UpdateOperation> {
+        let (i, with) = opt(preceded(self.kw("WITH"), cut(|i| self.iri(i))))(i)?;
+        let (i, delete) = opt(preceded(
+            self.kw("DELETE"),
+            cut(|i| self.quad_pattern(i)),
+        ))(i)?;
+        if let Some(delete) = delete.as_ref() {
+            self.check_quads(i, delete, true, true)?;
+        }
+        let insert_start = i;
+        let (i, insert) = if delete.is_some() {
+            opt(preceded(
+                self.kw("INSERT"),
+                cut(|i| self.quad_pattern(i)),
+            ))(i)?
+        } else {
+            map(
+                preceded(self.kw("INSERT"), cut(|i| self.quad_pattern(i))),
+                Some,
+            )(i)?
+        };
+        if let Some(insert) = insert.as_ref() {
+            self.check_quads(insert_start, insert, true, false)?;
+        }
+        let (i, using) = many0(|i| self.using_clause(i))(i)?;
+        let (i, where_clause) = cut(preceded(self.kw("WHERE"), |i| {
+            self.group_graph_pattern(i)
+        }))(i)?;
+        Ok((i, UpdateOperation::Modify {
+            with,
+            delete: delete.unwrap_or_default(),
+            insert: insert.unwrap_or_default(),
+            using,
+            where_clause,
+        }))
+    }
+
+    fn using_clause<'a>(&self, i: &'a str) -> PResult<'a, DatasetClause> {
+        let (i, _) = self.kw("USING")(i)?;
+        let (i, named) = opt(self.kw("NAMED"))(i)?;
+        let (i, iri) = cut(|i| self.iri(i))(i)?;
+        Ok((i, DatasetClause { named: named.is_some(), iri }))
+    }
+
+    fn quad_pattern<'a>(&self, i: &'a str) -> PResult<'a, Vec<QuadsElement>> {
+        delimited(
+            self.tok("{"),
+            |i| self.quads(i),
+            cut(self.tok("}")),
+        )(i)
+    }
+
+    fn quads<'a>(&self, i: &'a str) -> PResult<'a, Vec<QuadsElement>> {
+        let mut quads = Vec::new();
+        let (mut i, triples) = opt(|i| self.triples_block(i))(i)?;
+        if let Some(triples) = triples {
+            quads.push(QuadsElement::Triples(triples));
+        }
+        loop {
+            match self.kw("GRAPH")(i) {
+                Ok((rest, _)) => {
+                    let (rest, (name, triples)) = cut(pair(
+                        |i| self.var_or_iri(i),
+                        delimited(
+                            self.tok("{"),
+                            opt(|i| self.triples_block(i)),
+                            self.tok("}"),
+                        ),
+                    ))(rest)?;
+                    quads.push(QuadsElement::Graph { name, triples: triples.unwrap_or_default() });
+                    let (rest, _) = opt(self.tok("."))(rest)?;
+                    let (rest, triples) = opt(|i| self.triples_block(i))(rest)?;
+                    if let Some(triples) = triples {
+                        quads.push(QuadsElement::Triples(triples));
+                    }
+                    i = rest;
+                },
+                Err(_) => return Ok((i, quads)),
+            }
+        }
+    }
+
+    // ------------------------------------------------------------------
+    // Validation
+    // ------------------------------------------------------------------
+
+    /// Templates (of `CONSTRUCT` queries and updates) cannot contain
+    /// property paths.
+    fn check_template<'a>(&self, i: &'a str, triples: &[TriplePattern]) -> PResult<'a, ()> {
+        for triple in triples {
+            match &triple.predicate {
+                Verb::Variable(_) | Verb::Path(PropertyPath::Iri(_)) => {},
+                Verb::Path(_) => {
+                    return self.failure(
+                        ws(i),
+                        "property paths are not allowed in a template".to_string(),
+                    )
+                },
+            }
+        }
+        Ok((i, ()))
+    }
+
+    fn check_quads<'a>(
+        &self,
+        i: &'a str,
+        quads: &[QuadsElement],
+        allow_variables: bool,
+        is_delete: bool,
+    ) -> PResult<'a, ()> {
+        for quad in quads {
+            let (name, triples) = match quad {
+                QuadsElement::Triples(triples) => (None, triples),
+                QuadsElement::Graph { name, triples } => (Some(name), triples),
+            };
+            self.check_template(i, triples)?;
+            let terms = triples
+                .iter()
+                .flat_map(|triple| {
+                    let predicate = match &triple.predicate {
+                        Verb::Variable(variable) => Some(TermPattern::Variable(variable.clone())),
+                        Verb::Path(_) => None,
+                    };
+                    [
+                        Some(triple.subject.clone()),
+                        predicate,
+                        Some(triple.object.clone()),
+                    ]
+                })
+                .flatten()
+                .chain(name.cloned());
+            for term in terms {
+                match term {
+                    TermPattern::Variable(variable) if !allow_variables => {
+                        return self.failure(
+                            ws(i),
+                            format!(
+                                "variable ?{} is not allowed in quad data",
+                                variable.0
+                            ),
+                        )
+                    },
+                    TermPattern::BlankNode(_) if is_delete => {
+                        return self.failure(
+                            ws(i),
+                            "blank nodes are not allowed in a DELETE clause".to_string(),
+                        )
+                    },
+                    _ => {},
+                }
+            }
+        }
+        Ok((i, ()))
+    }
+}
+
+/// Split a `PNAME_NS` off the start of the given string, returning the rest
+/// and the prefix without its `:`.
+fn scan_pname_ns(i: &str) -> Option<(&str, &str)> {
+    let len = scan_pn_prefix(i);
+    i[len..].strip_prefix(':').map(|rest| (rest, &i[..len]))
+}
+
+/// Decode a `\uXXXX` or `\UXXXXXXXX` escape at the start of the given string,
+/// returning the character and the number of characters of the escape.
+fn unescape_unicode(str: &str) -> Option<(char, usize)> {
+    let len = match str.get(..2) {
+        Some("\\u") => 4,
+        Some("\\U") => 8,
+        _ => return None,
+    };
+    let hex = str.get(2..2 + len)?;
+    let code = u32::from_str_radix(hex, 16).ok()?;
+    char::from_u32(code).map(|c| (c, len + 2))
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        crate::{
+            sparql::{SparqlStatement, SparqlStatementKind, Variable},
+            RDFStoreError,
+        },
+        indoc::indoc,
+    };
+
+    fn variables(names: &[&str]) -> Vec<Variable> {
+        names
+            .iter()
+            .map(|name| Variable(name.to_string()))
+            .collect()
+    }
+
+    #[test_log::test]
+    fn test_select_query() -> Result<(), RDFStoreError> {
+        let statement = SparqlStatement::parse(indoc! {r#"
+            PREFIX skos: <http://www.w3.org/2004/02/skos/core#>
+            PREFIX rdfs: <http://www.w3.org/2000/01/rdf-schema#>
+            SELECT DISTINCT ?concept (STR(?label) AS ?name)
+            FROM <https://example.com/graph/taxonomy>
+            WHERE {
+                ?concept a skos:Concept ;
+                    skos:broader+ / ^skos:narrower* ?parent ;
+                    rdfs:label ?label .
+                OPTIONAL { ?concept skos:notation ?notation }
+                FILTER(LANG(?label) = "en" && !BOUND(?notation))
+            }
+            ORDER BY DESC(?name) ?concept
+            LIMIT 10
+        "#})?;
+        assert_eq!(statement.kind(), SparqlStatementKind::Select);
+        assert_eq!(
+            statement.projected_variables(),
+            variables(&["concept", "name"])
+        );
+        let namespaces = statement.prologue().namespaces()?;
+        assert_eq!(namespaces.len(), 2);
+        assert_eq!(namespaces[0].name, "skos:");
+        assert_eq!(
+            namespaces[1].iri.as_str(),
+            "http://www.w3.org/2000/01/rdf-schema#"
+        );
+        Ok(())
+    }
+
+    #[test_log::test]
+    fn test_select_all_variables() -> Result<(), RDFStoreError> {
+        let statement = SparqlStatement::parse(indoc! {r#"
+            SELECT * WHERE {
+                { ?s ?p ?o } UNION { GRAPH ?g { ?s ?p ?x } }
+                BIND(CONCAT("a", ?o) AS ?label)
+                MINUS { ?s <http://example.com/hidden> ?hidden }
+                { SELECT ?s (COUNT(*) AS ?count) WHERE { ?s ?q ?r } GROUP BY ?s }
+            }
+            VALUES ?extra { 1 2.5 -3.0e1 UNDEF }
+        "#})?;
+        assert_eq!(
+            statement.projected_variables(),
+            variables(&["s", "p", "o", "g", "x", "label", "count", "extra"])
+        );
+        Ok(())
+    }
+
+    #[test_log::test]
+    fn test_other_query_forms() -> Result<(), RDFStoreError> {
+        let construct = SparqlStatement::parse(
+            "CONSTRUCT { ?s <http://example.com/p> [ <http://example.com/q> (1 2) ] } WHERE { ?s \
+             ?p ?o }",
+        )?;
+        assert_eq!(construct.kind(), SparqlStatementKind::Construct);
+        let construct = SparqlStatement::parse("CONSTRUCT WHERE { ?s ?p ?o }")?;
+        assert_eq!(construct.kind(), SparqlStatementKind::Construct);
+        let describe = SparqlStatement::parse("DESCRIBE <http://example.com/x>")?;
+        assert_eq!(describe.kind(), SparqlStatementKind::Describe);
+        let ask = SparqlStatement::parse("ASK { ?s ?p 'o'@en-GB }")?;
+        assert!(ask.is_query());
+        assert!(ask.projected_variables().is_empty());
+        Ok(())
+    }
+
+    #[test_log::test]
+    fn test_update() -> Result<(), RDFStoreError> {
+        let statement = SparqlStatement::parse(indoc! {r#"
+            PREFIX ex: <http://example.com/>
+            INSERT DATA { GRAPH ex:g { ex:a ex:b "c"^^ex:type } } ;
+            DELETE { ?s ex:old ?o } INSERT { ?s ex:new ?o } WHERE { ?s ex:old ?o } ;
+            DELETE WHERE { ?s ex:gone ?o } ;
+            CLEAR SILENT GRAPH ex:g ;
+            COPY DEFAULT TO ex:backup ;
+            LOAD <http://example.com/data.ttl> INTO GRAPH ex:g
+        "#})?;
+        assert!(statement.is_update());
+        match statement {
+            SparqlStatement::Update(update) => assert_eq!(update.operations.len(), 6),
+            _ => panic!("expected an update"),
+        }
+        Ok(())
+    }
+
+    #[test_log::test]
+    fn test_syntax_error_position() {
+        let error = SparqlStatement::parse("SELECT ?s\nWHERE {\n  ?s ?p ?o .\n  FILTER(?o > )\n}")
+            .unwrap_err();
+        match error {
+            RDFStoreError::SparqlParseError { line, column, .. } => {
+                assert_eq!((line, column), (4, 15));
+            },
+            _ => panic!("unexpected error {error:?}"),
+        }
+    }
+
+    #[test_log::test]
+    fn test_invalid_statements() {
+        for sparql in [
+            "SELECT ?s WHERE { ?s ?p }",
+            "SELECT ?s WHERE { ?s ?p ?o } LIMIT x",
+            "SELECT (STRLEN(?a, ?b) AS ?c) WHERE { ?a ?b ?c }",
+            "INSERT DATA { ?s <http://example.com/p> 1 }",
+            "DELETE DATA { _:b <http://example.com/p> 1 }",
+            "SELECT * WHERE { ?s ?p ?o } garbage",
+            "SELECT * WHERE { FILTER(\"unterminated) }",
+            "VALUES (?a ?b) { (1) }",
+        ] {
+            assert!(
+                SparqlStatement::parse(sparql).is_err(),
+                "should not parse: {sparql}"
+            );
+        }
+    }
+}
@@ -0,0 +1,789 @@
+// Copyright (c) 2018-2023, agnos.ai UK Ltd, all rights reserved.
+//---------------------------------------------------------------
+//! Programmatic rewrites of SPARQL statements, used to enforce policies such
+//! as tenant isolation centrally rather than in every query.
+
+use {
+    super::ast::*,
+    crate::{Graph, RDFStoreError, Term},
+    iref::Iri,
+};
+
+/// How a [`SparqlRewriter`] restricts a statement to its graphs.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum GraphScope {
+    /// Set the dataset of the statement with `FROM` and `FROM NAMED`
+    /// clauses (or `USING` and `USING NAMED` clauses for updates).
+    #[default]
+    Dataset,
+    /// Wrap the `WHERE` clause in a `GRAPH` pattern, or in a `UNION` of
+    /// `GRAPH` patterns when there is more than one graph.
+    GraphPattern,
+}
+
+/// What a [`SparqlRewriter`] does with a query that could return more rows
+/// than the configured maximum.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum RowLimitPolicy {
+    /// Add a `LIMIT` or lower the existing one to the maximum.
+    #[default]
+    Lower,
+    /// Fail with [`RDFStoreError::ExceededMaximumNumberOfRows`] if the query
+    /// has no `LIMIT` or one that is higher than the maximum.
+    Reject,
+}
+
+/// Rewrites SPARQL statements: scopes them to one or more graphs, enforces a
+/// maximum number of rows and adds filters. The result is serialized as
+/// canonical SPARQL.
+///
+/// ```rust
+/// use rdf_store_rs::{sparql::{RowLimitPolicy, SparqlRewriter}, Term};
+///
+/// # #[cfg(feature = "nom-support")]
+/// # fn main() {
+/// let mut rewriter = SparqlRewriter::new();
+/// rewriter
+///     .scope_to_graph_iri(iref::Iri::new("https://example.com/graph/tenant-a").unwrap())
+///     .max_rows(100, RowLimitPolicy::Lower)
+///     .tenant_filter("tenant", &Term::new_str("a").unwrap());
+///
+/// let sparql = rewriter.rewrite("SELECT ?s WHERE { ?s ?p ?tenant } LIMIT 1000").unwrap();
+///
+/// assert_eq!(
+///     sparql,
+///     "SELECT ?s\nFROM <https://example.com/graph/tenant-a>\n\
+///      FROM NAMED <https://example.com/graph/tenant-a>\nWHERE {\n    ?s ?p ?tenant .\n    \
+///      FILTER(?tenant = \"a\")\n}\nLIMIT 100"
+/// );
+/// # }
+/// # #[cfg(not(feature = "nom-support"))]
+/// # fn main() {}
+/// ```
+#[derive(Debug, Default, Clone)]
+pub struct SparqlRewriter {
+    graphs:           Vec<String>,
+    graph_scope:      GraphScope,
+    maxrow:           Option<usize>,
+    row_limit_policy: RowLimitPolicy,
+    filters:          Vec<Expression>,
+}
+
+impl SparqlRewriter {
+    pub fn new() -> Self { Self::default() }
+
+    /// Restrict statements to the given graph. Can be called multiple times
+    /// to allow more than one graph.
+    pub fn scope_to_graph(&mut self, graph: &Graph) -> Result<&mut Self, RDFStoreError> {
+        let iri = graph.as_iri_buf()?;
+        Ok(self.scope_to_graph_iri(iri.as_iri()))
+    }
+
+    pub fn scope_to_graph_iri(&mut self, iri: &Iri) -> &mut Self {
+        let iri = iri.as_str().to_string();
+        if !self.graphs.contains(&iri) {
+            self.graphs.push(iri);
+        }
+        self
+    }
+
+    pub fn graph_scope(&mut self, graph_scope: GraphScope) -> &mut Self {
+        self.graph_scope = graph_scope;
+        self
+    }
+
+    /// Enforce the given maximum number of rows on queries.
+    pub fn max_rows(&mut self, maxrow: usize, policy: RowLimitPolicy) -> &mut Self {
+        self.maxrow = Some(maxrow);
+        self.row_limit_policy = policy;
+        self
+    }
+
+    /// Add a `FILTER` with the given expression to the `WHERE` clause.
+    pub fn filter(&mut self, expression: Expression) -> &mut Self {
+        self.filters.push(expression);
+        self
+    }
+
+    /// Add `FILTER(?<variable> = <tenant>)` to the `WHERE` clause.
+    pub fn tenant_filter(&mut self, variable: &str, tenant: &Term) -> &mut Self {
+        self.filter(Expression::Binary(
+            BinaryOperator::Equal,
+            Box::new(Expression::Term(TermPattern::Variable(Variable(
+                variable.to_string(),
+            )))),
+            Box::new(Expression::Term(TermPattern::from(tenant))),
+        ))
+    }
+
+    /// Parse, rewrite and serialize the given SPARQL statement.
+    #[cfg(feature = "nom-support")]
+    pub fn rewrite(&self, sparql: &str) -> Result<String, RDFStoreError> {
+        let statement = SparqlStatement::parse(sparql)?;
+        Ok(self.rewrite_statement(statement)?.to_string())
+    }
+
+    pub fn rewrite_statement(
+        &self,
+        statement: SparqlStatement,
+    ) -> Result<SparqlStatement, RDFStoreError> {
+        match statement {
+            SparqlStatement::Query(query) => Ok(SparqlStatement::Query(self.rewrite_query(query)?)),
+            SparqlStatement::Update(update) => {
+                Ok(SparqlStatement::Update(
+                    self.rewrite_update(update)?,
+                ))
+            },
+        }
+    }
+
+    fn rewrite_query(&self, mut query: Query) -> Result<Query, RDFStoreError> {
+        self.limit_rows(&mut query)?;
+        if let Some(pattern) = query.where_clause.take() {
+            let pattern = self.add_filters(pattern);
+            query.where_clause = Some(self.scope_pattern(&query.prologue, pattern)?);
+        }
+        if !self.graphs.is_empty() {
+            self.check_dataset(&query.prologue, &query.dataset)?;
+            if self.graph_scope == GraphScope::Dataset && query.dataset.is_empty() {
+                query.dataset = self.dataset(None);
+            }
+        }
+        Ok(query)
+    }
+
+    fn limit_rows(&self, query: &mut Query) -> Result<(), RDFStoreError> {
+        let maxrow = match self.maxrow {
+            Some(maxrow) if query.kind() != SparqlStatementKind::Ask => maxrow,
+            _ => return Ok(()),
+        };
+        match query.modifiers.limit {
+            Some(limit) if limit <= maxrow as u64 => {},
+            _ if self.row_limit_policy == RowLimitPolicy::Lower => {
+                query.modifiers.limit = Some(maxrow as u64)
+            },
+            _ => {
+                return Err(RDFStoreError::ExceededMaximumNumberOfRows {
+                    maxrow,
+                    query: query.to_string(),
+                })
+            },
+        }
+        Ok(())
+    }
+
+    fn rewrite_update(&self, mut update: Update) -> Result<Update, RDFStoreError> {
+        let operations = std::mem::take(&mut update.operations);
+        for operation in operations.into_iter() {
+            let operation = self.rewrite_update_operation(&update.prologue, operation)?;
+            update.operations.push(operation);
+        }
+        Ok(update)
+    }
+
+    fn rewrite_update_operation(
+        &self,
+        prologue: &Prologue,
+        operation: UpdateOperation,
+    ) -> Result<UpdateOperation, RDFStoreError> {
+        if self.graphs.is_empty() {
+            return match operation {
+                UpdateOperation::Modify { with, delete, insert, using, where_clause } => {
+                    Ok(UpdateOperation::Modify {
+                        with,
+                        delete,
+                        insert,
+                        using,
+                        where_clause: self.add_filters(where_clause),
+                    })
+                },
+                operation => Ok(operation),
+            }
+        }
+        match operation {
+            UpdateOperation::Load { silent, source, destination: Some(destination) } => {
+                self.check_iri(prologue, &destination)?;
+                Ok(UpdateOperation::Load { silent, source, destination: Some(destination) })
+            },
+            UpdateOperation::Load { silent, source, destination: None } => {
+                Ok(UpdateOperation::Load {
+                    silent,
+                    source,
+                    destination: Some(SparqlIri::Iri(self.single_graph()?.to_string())),
+                })
+            },
+            UpdateOperation::Clear { ref target, .. } |
+            UpdateOperation::Drop { ref target, .. } => {
+                match target {
+                    GraphTarget::Graph(iri) => self.check_iri(prologue, iri)?,
+                    GraphTarget::Default => return Err(out_of_scope("DEFAULT")),
+                    GraphTarget::Named => return Err(out_of_scope("NAMED")),
+                    GraphTarget::All => return Err(out_of_scope("ALL")),
+                }
+                Ok(operation)
+            },
+            UpdateOperation::Create { ref graph, .. } => {
+                self.check_iri(prologue, graph)?;
+                Ok(operation)
+            },
+            UpdateOperation::Transfer { ref source, ref destination, .. } => {
+                for graph in [source, destination] {
+                    match graph {
+                        GraphOrDefault::Graph(iri) => self.check_iri(prologue, iri)?,
+                        GraphOrDefault::Default => return Err(out_of_scope("DEFAULT")),
+                    }
+                }
+                Ok(operation)
+            },
+            UpdateOperation::InsertData(quads) => {
+                Ok(UpdateOperation::InsertData(
+                    self.scope_quads(prologue, quads, None)?,
+                ))
+            },
+            UpdateOperation::DeleteData(quads) => {
+                Ok(UpdateOperation::DeleteData(
+                    self.scope_quads(prologue, quads, None)?,
+                ))
+            },
+            UpdateOperation::DeleteWhere(quads) => {
+                // The quads are also the pattern, which has to be scoped
+                // separately so turn it into `DELETE { ... } WHERE { ... }`
+                let where_clause = quads_as_pattern(&quads);
+                self.rewrite_update_operation(prologue, UpdateOperation::Modify {
+                    with: None,
+                    delete: quads,
+                    insert: Vec::new(),
+                    using: Vec::new(),
+                    where_clause,
+                })
+            },
+            UpdateOperation::Modify { with, delete, insert, mut using, where_clause } => {
+                if let Some(with) = with.as_ref() {
+                    self.check_iri(prologue, with)?;
+                }
+                // A `GRAPH ?g` in a template can write to any graph that the
+                // `WHERE` clause binds `?g` to, so these are filtered
+                let mut graph_filters = Vec::new();
+                for quad in delete.iter().chain(insert.iter()) {
+                    if let QuadsElement::Graph { name: TermPattern::Variable(variable), .. } = quad
+                    {
+                        let filter = self.graph_filter(variable);
+                        if !graph_filters.contains(&filter) {
+                            graph_filters.push(filter);
+                        }
+                    }
+                }
+                let delete = self.scope_quads(prologue, delete, with.as_ref())?;
+                let insert = self.scope_quads(prologue, insert, with.as_ref())?;
+                self.check_dataset(prologue, &using)?;
+                if self.graph_scope == GraphScope::Dataset && using.is_empty() {
+                    using = self.dataset(with.as_ref());
+                }
+                let where_clause = add_elements(self.add_filters(where_clause), graph_filters);
+                Ok(UpdateOperation::Modify {
+                    where_clause: self.scope_pattern(prologue, where_clause)?,
+                    with,
+                    delete,
+                    insert,
+                    using,
+                })
+            },
+        }
+    }
+
+    /// Put the triples of the default graph in the (only) graph of the
+    /// scope, unless they go into the (already checked) `WITH` graph, and
+    /// check the named graphs. Graph variables are left to a filter, see
+    /// [`SparqlRewriter::graph_filter`].
+    fn scope_quads(
+        &self,
+        prologue: &Prologue,
+        quads: Vec<QuadsElement>,
+        with: Option<&SparqlIri>,
+    ) -> Result<Vec<QuadsElement>, RDFStoreError> {
+        quads
+            .into_iter()
+            .map(|quad| {
+                match quad {
+                    QuadsElement::Triples(triples) if with.is_some() => {
+                        Ok(QuadsElement::Triples(triples))
+                    },
+                    QuadsElement::Triples(triples) => {
+                        Ok(QuadsElement::Graph {
+                            name: TermPattern::Iri(SparqlIri::Iri(
+                                self.single_graph()?.to_string(),
+                            )),
+                            triples,
+                        })
+                    },
+                    QuadsElement::Graph { name: TermPattern::Iri(iri), triples } => {
+                        self.check_iri(prologue, &iri)?;
+                        Ok(QuadsElement::Graph { name: TermPattern::Iri(iri), triples })
+                    },
+                    QuadsElement::Graph { name: TermPattern::Variable(variable), triples } => {
+                        Ok(QuadsElement::Graph { name: TermPattern::Variable(variable), triples })
+                    },
+                    QuadsElement::Graph { name, .. } => {
+                        Err(out_of_scope(name.to_string().as_str()))
+                    },
+                }
+            })
+            .collect()
+    }
+
+    /// Add the filters to the given (top level) pattern.
+    fn add_filters(&self, pattern: GroupGraphPattern) -> GroupGraphPattern {
+        add_elements(
+            pattern,
+            self.filters
+                .iter()
+                .cloned()
+                .map(PatternElement::Filter)
+                .collect(),
+        )
+    }
+
+    /// `FILTER(?<variable> IN (<graph>, ...))` for all graphs in scope.
+    fn graph_filter(&self, variable: &Variable) -> PatternElement {
+        PatternElement::Filter(Expression::In {
+            expression: Box::new(Expression::Term(TermPattern::Variable(
+                variable.clone(),
+            ))),
+            list:       self
+                .graphs
+                .iter()
+                .map(|graph| Expression::Term(TermPattern::Iri(SparqlIri::Iri(graph.clone()))))
+                .collect(),
+            negated:    false,
+        })
+    }
+
+    fn scope_pattern(
+        &self,
+        prologue: &Prologue,
+        mut pattern: GroupGraphPattern,
+    ) -> Result<GroupGraphPattern, RDFStoreError> {
+        if self.graphs.is_empty() || self.graph_scope == GraphScope::Dataset {
+            return Ok(pattern)
+        }
+        // `GRAPH` patterns inside the pattern override the graph we wrap it
+        // in, so these have to stay within the scope as well
+        self.scope_nested_graphs(prologue, &mut pattern)?;
+        let mut wrapped = self
+            .graphs
+            .iter()
+            .map(|graph| {
+                GroupGraphPattern::Group(vec![PatternElement::Graph {
+                    name:    TermPattern::Iri(SparqlIri::Iri(graph.clone())),
+                    pattern: pattern.clone(),
+                }])
+            })
+            .collect::<Vec<_>>();
+        if wrapped.len() == 1 {
+            Ok(wrapped.remove(0))
+        } else {
+            Ok(GroupGraphPattern::Group(vec![
+                PatternElement::Union(wrapped),
+            ]))
+        }
+    }
+
+    fn scope_nested_graphs(
+        &self,
+        prologue: &Prologue,
+        pattern: &mut GroupGraphPattern,
+    ) -> Result<(), RDFStoreError> {
+        let elements = match pattern {
+            GroupGraphPattern::Group(elements) => elements,
+            GroupGraphPattern::SubSelect(query) => {
+                return match query.where_clause.as_mut() {
+                    Some(pattern) => self.scope_nested_graphs(prologue, pattern),
+                    None => Ok(()),
+                }
+            },
+        };
+        let mut filters = Vec::new();
+        for element in elements.iter_mut() {
+            match element {
+                PatternElement::Graph { name, pattern } => {
+                    match name {
+                        TermPattern::Iri(iri) => self.check_iri(prologue, iri)?,
+                        TermPattern::Variable(variable) => {
+                            filters.push(self.graph_filter(variable))
+                        },
+                        _ => {},
+                    }
+                    self.scope_nested_graphs(prologue, pattern)?;
+                },
+                PatternElement::Optional(pattern) |
+                PatternElement::Minus(pattern) |
+                PatternElement::Service { pattern, .. } => {
+                    self.scope_nested_graphs(prologue, pattern)?
+                },
+                PatternElement::Union(patterns) => {
+                    for pattern in patterns.iter_mut() {
+                        self.scope_nested_graphs(prologue, pattern)?;
+                    }
+                },
+                PatternElement::Filter(expression) | PatternElement::Bind { expression, .. } => {
+                    self.scope_nested_graphs_in_expression(prologue, expression)?
+                },
+                PatternElement::Triples(_) | PatternElement::Values(_) => {},
+            }
+        }
+        for filter in filters {
+            if !elements.contains(&filter) {
+                elements.push(filter);
+            }
+        }
+        Ok(())
+    }
+
+    fn scope_nested_graphs_in_expression(
+        &self,
+        prologue: &Prologue,
+        expression: &mut Expression,
+    ) -> Result<(), RDFStoreError> {
+        match expression {
+            Expression::Exists { pattern, .. } => self.scope_nested_graphs(prologue, pattern),
+            Expression::Binary(_, left, right) => {
+                self.scope_nested_graphs_in_expression(prologue, left)?;
+                self.scope_nested_graphs_in_expression(prologue, right)
+            },
+            Expression::Unary(_, operand) => {
+                self.scope_nested_graphs_in_expression(prologue, operand)
+            },
+            Expression::In { expression, list, .. } => {
+                self.scope_nested_graphs_in_expression(prologue, expression)?;
+                list.iter_mut().try_for_each(|expression| {
+                    self.scope_nested_graphs_in_expression(prologue, expression)
+                })
+            },
+            Expression::FunctionCall { arguments, .. } => {
+                arguments.iter_mut().try_for_each(|expression| {
+                    self.scope_nested_graphs_in_expression(prologue, expression)
+                })
+            },
+            Expression::Aggregate { expression: Some(expression), .. } => {
+                self.scope_nested_graphs_in_expression(prologue, expression)
+            },
+            Expression::Term(_) | Expression::Aggregate { .. } => Ok(()),
+        }
+    }
+
+    /// `FROM` (or `USING`) clauses for all graphs in scope, or only for the
+    /// given default graph, followed by `FROM NAMED` clauses for all of them.
+    fn dataset(&self, default_graph: Option<&SparqlIri>) -> Vec<DatasetClause> {
+        let named = self
+            .graphs
+            .iter()
+            .map(|graph| DatasetClause { named: true, iri: SparqlIri::Iri(graph.clone()) });
+        match default_graph {
+            Some(iri) => {
+                std::iter::once(DatasetClause { named: false, iri: iri.clone() })
+                    .chain(named)
+                    .collect()
+            },
+            None => {
+                self.graphs
+                    .iter()
+                    .map(|graph| {
+                        DatasetClause { named: false, iri: SparqlIri::Iri(graph.clone()) }
+                    })
+                    .chain(named)
+                    .collect()
+            },
+        }
+    }
+
+    fn check_dataset(
+        &self,
+        prologue: &Prologue,
+        dataset: &[DatasetClause],
+    ) -> Result<(), RDFStoreError> {
+        dataset
+            .iter()
+            .try_for_each(|clause| self.check_iri(prologue, &clause.iri))
+    }
+
+    fn check_iri(&self, prologue: &Prologue, iri: &SparqlIri) -> Result<(), RDFStoreError> {
+        let iri = prologue.resolve(iri)?;
+        if self.graphs.contains(&iri) {
+            Ok(())
+        } else {
+            Err(out_of_scope(format!("<{iri}>").as_str()))
+        }
+    }
+
+    /// The graph that takes the place of the default graph, which only
+    /// exists if the scope is exactly one graph.
+    fn single_graph(&self) -> Result<&str, RDFStoreError> {
+        match self.graphs.as_slice() {
+            [graph] => Ok(graph.as_str()),
+            _ => Err(out_of_scope("DEFAULT")),
+        }
+    }
+}
+
+/// Add the given elements to the given (top level) pattern.
+fn add_elements(pattern: GroupGraphPattern, extra: Vec<PatternElement>) -> GroupGraphPattern {
+    if extra.is_empty() {
+        return pattern
+    }
+    let mut elements = match pattern {
+        GroupGraphPattern::Group(elements) => elements,
+        sub_select => vec![PatternElement::Union(vec![sub_select])],
+    };
+    elements.extend(extra);
+    GroupGraphPattern::Group(elements)
+}
+
+fn out_of_scope(graph: &str) -> RDFStoreError {
+    RDFStoreError::GraphOutOfScope { graph: graph.to_string() }
+}
+
+fn quads_as_pattern(quads: &[QuadsElement]) -> GroupGraphPattern {
+    GroupGraphPattern::Group(
+        quads
+            .iter()
+            .map(|quad| {
+                match quad {
+                    QuadsElement::Triples(triples) => PatternElement::Triples(triples.clone()),
+                    QuadsElement::Graph { name, triples } => {
+                        PatternElement::Graph {
+                            name:    name.clone(),
+                            pattern: GroupGraphPattern::Group(vec![PatternElement::Triples(
+                                triples.clone(),
+                            )]),
+                        }
+                    },
+                }
+            })
+            .collect(),
+    )
+}
+
+#[cfg(all(test, feature = "nom-support"))]
+mod tests {
+    use {
+        super::{GraphScope, RowLimitPolicy, SparqlRewriter},
+        crate::{RDFStoreError, Term},
+        indoc::indoc,
+    };
+
+    fn rewriter(graphs: &[&str]) -> SparqlRewriter {
+        let mut rewriter = SparqlRewriter::new();
+        for graph in graphs {
+            rewriter.scope_to_graph_iri(iref::Iri::new(graph).unwrap());
+        }
+        rewriter
+    }
+
+    #[test_log::test]
+    fn test_scope_to_dataset() -> Result<(), RDFStoreError> {
+        let sparql = rewriter(&["https://example.com/a", "https://example.com/b"]).rewrite(
+            "PREFIX ex: <https://example.com/> SELECT * FROM ex:a { GRAPH ?g { ?s ?p ?o } }",
+        )?;
+        assert_eq!(sparql, indoc! {"
+                PREFIX ex: <https://example.com/>
+                SELECT *
+                FROM ex:a
+                WHERE {
+                    GRAPH ?g {
+                        ?s ?p ?o .
+                    }
+                }"
+        });
+        let sparql = rewriter(&["https://example.com/a"]).rewrite("ASK { ?s a ?type }")?;
+        assert_eq!(sparql, indoc! {"
+                ASK
+                FROM <https://example.com/a>
+                FROM NAMED <https://example.com/a>
+                WHERE {
+                    ?s a ?type .
+                }"
+        });
+        Ok(())
+    }
+
+    #[test_log::test]
+    fn test_scope_to_graph_pattern() -> Result<(), RDFStoreError> {
+        let mut rewriter = rewriter(&["https://example.com/a", "https://example.com/b"]);
+        rewriter.graph_scope(GraphScope::GraphPattern);
+        let sparql =
+            rewriter.rewrite("SELECT ?s { ?s ?p ?o OPTIONAL { GRAPH ?g { ?s ?q ?x } } }")?;
+        assert_eq!(sparql, indoc! {"
+                SELECT ?s
+                WHERE {
+                    {
+                        GRAPH <https://example.com/a> {
+                            ?s ?p ?o .
+                            OPTIONAL {
+                                GRAPH ?g {
+                                    ?s ?q ?x .
+                                }
+                                FILTER(?g IN (<https://example.com/a>, <https://example.com/b>))
+                            }
+                        }
+                    } UNION {
+                        GRAPH <https://example.com/b> {
+                            ?s ?p ?o .
+                            OPTIONAL {
+                                GRAPH ?g {
+                                    ?s ?q ?x .
+                                }
+                                FILTER(?g IN (<https://example.com/a>, <https://example.com/b>))
+                            }
+                        }
+                    }
+                }"
+        });
+        assert!(matches!(
+            rewriter.rewrite("SELECT * { GRAPH <https://example.com/c> { ?s ?p ?o } }"),
+            Err(RDFStoreError::GraphOutOfScope { .. })
+        ));
+        Ok(())
+    }
+
+    #[test_log::test]
+    fn test_row_limit() -> Result<(), RDFStoreError> {
+        let mut rewriter = SparqlRewriter::new();
+        rewriter.max_rows(10, RowLimitPolicy::Lower);
+        assert_eq!(
+            rewriter.rewrite("SELECT * { ?s ?p ?o } LIMIT 5")?,
+            "SELECT *\nWHERE {\n    ?s ?p ?o .\n}\nLIMIT 5"
+        );
+        assert_eq!(
+            rewriter.rewrite("SELECT * { ?s ?p ?o } OFFSET 20")?,
+            "SELECT *\nWHERE {\n    ?s ?p ?o .\n}\nLIMIT 10\nOFFSET 20"
+        );
+        rewriter.max_rows(10, RowLimitPolicy::Reject);
+        assert!(matches!(
+            rewriter.rewrite("SELECT * { ?s ?p ?o } LIMIT 11"),
+            Err(RDFStoreError::ExceededMaximumNumberOfRows { maxrow: 10, .. })
+        ));
+        assert!(rewriter.rewrite("ASK { ?s ?p ?o }").is_ok());
+        Ok(())
+    }
+
+    #[test_log::test]
+    fn test_update() -> Result<(), RDFStoreError> {
+        let mut rewriter = rewriter(&["https://example.com/a"]);
+        rewriter.tenant_filter(
+            "tenant",
+            &Term::new_iri_from_str("https://example.com/tenant")?,
+        );
+        let sparql = rewriter.rewrite(indoc! {"
+            INSERT DATA { <https://example.com/x> a <https://example.com/Thing> } ;
+            DELETE WHERE { ?s <https://example.com/owner> ?tenant }
+        "})?;
+        assert_eq!(sparql, indoc! {"
+                INSERT DATA {
+                    GRAPH <https://example.com/a> {
+                        <https://example.com/x> a <https://example.com/Thing> .
+                    }
+                } ;
+                DELETE {
+                    GRAPH <https://example.com/a> {
+                        ?s <https://example.com/owner> ?tenant .
+                    }
+                }
+                USING <https://example.com/a>
+                USING NAMED <https://example.com/a>
+                WHERE {
+                    ?s <https://example.com/owner> ?tenant .
+                    FILTER(?tenant = <https://example.com/tenant>)
+                }"
+        });
+        assert!(matches!(
+            rewriter.rewrite("CLEAR ALL"),
+            Err(RDFStoreError::GraphOutOfScope { .. })
+        ));
+        Ok(())
+    }
+
+    #[test_log::test]
+    fn test_update_templates_stay_in_scope() -> Result<(), RDFStoreError> {
+        let rewriter = rewriter(&["https://example.com/a"]);
+        assert!(matches!(
+            rewriter.rewrite(indoc! {"
+                WITH <https://example.com/a>
+                DELETE { GRAPH <https://example.com/b> { ?s ?p ?o } }
+                WHERE { ?s ?p ?o }
+            "}),
+            Err(RDFStoreError::GraphOutOfScope { .. })
+        ));
+        let sparql = rewriter.rewrite(indoc! {"
+            WITH <https://example.com/a>
+            DELETE { ?s ?p ?o }
+            INSERT { GRAPH ?g { ?s ?p ?o } }
+            WHERE { ?s ?p ?o BIND(<https://example.com/b> AS ?g) }
+        "})?;
+        // The triples without a graph go into the `WITH` graph
+        assert_eq!(sparql, indoc! {"
+                WITH <https://example.com/a>
+                DELETE {
+                    ?s ?p ?o .
+                }
+                INSERT {
+                    GRAPH ?g {
+                        ?s ?p ?o .
+                    }
+                }
+                USING <https://example.com/a>
+                USING NAMED <https://example.com/a>
+                WHERE {
+                    ?s ?p ?o .
+                    BIND(<https://example.com/b> AS ?g)
+                    FILTER(?g IN (<https://example.com/a>))
+                }"
+        });
+        let sparql = rewriter.rewrite(indoc! {"
+            DELETE { GRAPH ?g { ?s ?p ?o } }
+            WHERE { VALUES ?g { <https://example.com/b> } ?s ?p ?o }
+        "})?;
+        assert_eq!(sparql, indoc! {"
+                DELETE {
+                    GRAPH ?g {
+                        ?s ?p ?o .
+                    }
+                }
+                USING <https://example.com/a>
+                USING NAMED <https://example.com/a>
+                WHERE {
+                    VALUES (?g) {
+                        (<https://example.com/b>)
+                    }
+                    ?s ?p ?o .
+                    FILTER(?g IN (<https://example.com/a>))
+                }"
+        });
+        Ok(())
+    }
+
+    #[test_log::test]
+    fn test_canonical_serialization() -> Result<(), RDFStoreError> {
+        let sparql = indoc! {r#"
+            PREFIX skos: <http://www.w3.org/2004/02/skos/core#>
+            SELECT DISTINCT ?concept (COUNT(DISTINCT ?label) AS ?count)
+            WHERE {
+                ?concept (skos:broader|^skos:narrower)+/skos:inScheme ?scheme .
+                ?concept skos:prefLabel ?label .
+                FILTER((?count + 1) * 2 > 3 && !(LANG(?label) IN ("en", "nl")))
+                BIND(GROUP_CONCAT(?label ; SEPARATOR = ", ") AS ?labels)
+                VALUES (?scheme) {
+                    (skos:x)
+                    (UNDEF)
+                }
+            }
+            GROUP BY ?concept
+            HAVING (COUNT(*) > 1)
+            ORDER BY DESC(?count) ?concept"#
+        };
+        let statement = crate::sparql::SparqlStatement::parse(sparql)?;
+        assert_eq!(statement.to_string(), sparql);
+        let reparsed = crate::sparql::SparqlStatement::parse(statement.to_string().as_str())?;
+        assert_eq!(reparsed, statement);
+        Ok(())
+    }
+}
@@ -0,0 +1,846 @@
+// Copyright (c) 2018-2023, agnos.ai UK Ltd, all rights reserved.
+//---------------------------------------------------------------
+//! Serializes the [abstract syntax tree](super::ast) back into SPARQL text.
+//!
+//! The output is canonical: keywords are upper case, every triple pattern
+//! and every clause starts on its own line, nested groups are indented with
+//! four spaces and parentheses are only added where operator precedence
+//! requires them. Parsing the output again yields the same tree.
+
+use {
+    super::ast::*,
+    crate::literal::escape_string,
+    std::fmt::{Display, Formatter, Write},
+};
+
+const RDF_TYPE: &str = "http://www.w3.org/1999/02/22-rdf-syntax-ns#type";
+const XSD_BOOLEAN: &str = "http://www.w3.org/2001/XMLSchema#boolean";
+const XSD_INTEGER: &str = "http://www.w3.org/2001/XMLSchema#integer";
+const XSD_DECIMAL: &str = "http://www.w3.org/2001/XMLSchema#decimal";
+const XSD_DOUBLE: &str = "http://www.w3.org/2001/XMLSchema#double";
+
+const INDENT: &str = "    ";
+
+impl Display for SparqlStatement {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SparqlStatement::Query(query) => query.fmt(f),
+            SparqlStatement::Update(update) => update.fmt(f),
+        }
+    }
+}
+
+impl Display for Query {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let mut serializer = Serializer::new(f);
+        serializer.prologue(&self.prologue)?;
+        serializer.query(self)
+    }
+}
+
+impl Display for Update {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let mut serializer = Serializer::new(f);
+        serializer.prologue(&self.prologue)?;
+        for (idx, operation) in self.operations.iter().enumerate() {
+            if idx > 0 {
+                serializer.f.write_str(" ;\n")?;
+            }
+            serializer.update_operation(operation)?;
+        }
+        Ok(())
+    }
+}
+
+impl Display for GroupGraphPattern {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        Serializer::new(f).group_graph_pattern(self)
+    }
+}
+
+impl Display for Expression {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        Serializer::new(f).expression(self, Precedence::Lowest)
+    }
+}
+
+impl Display for PropertyPath {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        Serializer::new(f).path(self, PathPrecedence::Alternative)
+    }
+}
+
+impl Display for TermPattern {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            TermPattern::Variable(variable) => variable.fmt(f),
+            TermPattern::Iri(iri) => iri.fmt(f),
+            TermPattern::BlankNode(label) => write!(f, "_:{label}"),
+            TermPattern::Literal(literal) => literal.fmt(f),
+        }
+    }
+}
+
+impl Display for Variable {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result { write!(f, "?{}", self.0) }
+}
+
+impl Display for SparqlIri {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SparqlIri::Iri(iri) => write!(f, "<{iri}>"),
+            SparqlIri::PrefixedName { prefix, local_name } => write!(f, "{prefix}:{local_name}"),
+        }
+    }
+}
+
+impl Display for SparqlLiteral {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        if self.language.is_none() {
+            if let Some(SparqlIri::Iri(data_type)) = &self.data_type {
+                if is_numeric_or_boolean_shorthand(data_type, self.lexical_form.as_str()) {
+                    return f.write_str(self.lexical_form.as_str())
+                }
+            }
+        }
+        write!(
+            f,
+            "\"{}\"",
+            escape_string(self.lexical_form.as_str())
+        )?;
+        if let Some(language) = &self.language {
+            write!(f, "@{language}")
+        } else if let Some(data_type) = &self.data_type {
+            write!(f, "^^{data_type}")
+        } else {
+            Ok(())
+        }
+    }
+}
+
+/// Return `true` if the given lexical form can be written without quotes
+/// and still has the given datatype when parsed again.
+fn is_numeric_or_boolean_shorthand(data_type: &str, lexical_form: &str) -> bool {
+    let unsigned = lexical_form
+        .strip_prefix(['+', '-'])
+        .unwrap_or(lexical_form);
+    let is_digits = |str: &str| !str.is_empty() && str.bytes().all(|b| b.is_ascii_digit());
+    let is_decimal = |str: &str| {
+        match str.split_once('.') {
+            Some((integer, fraction)) => {
+                (integer.is_empty() || is_digits(integer)) && is_digits(fraction)
+            },
+            None => false,
+        }
+    };
+    match data_type {
+        XSD_BOOLEAN => lexical_form == "true" || lexical_form == "false",
+        XSD_INTEGER => is_digits(unsigned),
+        XSD_DECIMAL => is_decimal(unsigned),
+        XSD_DOUBLE => {
+            match unsigned.split_once(['e', 'E']) {
+                Some((mantissa, exponent)) => {
+                    (is_digits(mantissa) ||
+                        is_decimal(mantissa) ||
+                        mantissa.strip_suffix('.').map_or(false, is_digits)) &&
+                        is_digits(exponent.strip_prefix(['+', '-']).unwrap_or(exponent))
+                },
+                None => false,
+            }
+        },
+        _ => false,
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+enum Precedence {
+    Lowest,
+    Or,
+    And,
+    Relational,
+    Additive,
+    Multiplicative,
+    Unary,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+enum PathPrecedence {
+    Alternative,
+    Sequence,
+    Inverse,
+    Primary,
+}
+
+fn precedence_of(operator: BinaryOperator) -> Precedence {
+    match operator {
+        BinaryOperator::Or => Precedence::Or,
+        BinaryOperator::And => Precedence::And,
+        BinaryOperator::Equal |
+        BinaryOperator::NotEqual |
+        BinaryOperator::Less |
+        BinaryOperator::Greater |
+        BinaryOperator::LessOrEqual |
+        BinaryOperator::GreaterOrEqual => Precedence::Relational,
+        BinaryOperator::Add | BinaryOperator::Subtract => Precedence::Additive,
+        BinaryOperator::Multiply | BinaryOperator::Divide => Precedence::Multiplicative,
+    }
+}
+
+fn operator_token(operator: BinaryOperator) -> &'static str {
+    match operator {
+        BinaryOperator::Or => "||",
+        BinaryOperator::And => "&&",
+        BinaryOperator::Equal => "=",
+        BinaryOperator::NotEqual => "!=",
+        BinaryOperator::Less => "<",
+        BinaryOperator::Greater => ">",
+        BinaryOperator::LessOrEqual => "<=",
+        BinaryOperator::GreaterOrEqual => ">=",
+        BinaryOperator::Add => "+",
+        BinaryOperator::Subtract => "-",
+        BinaryOperator::Multiply => "*",
+        BinaryOperator::Divide => "/",
+    }
+}
+
+fn aggregate_name(function: AggregateFunction) -> &'static str {
+    match function {
+        AggregateFunction::Count => "COUNT",
+        AggregateFunction::Sum => "SUM",
+        AggregateFunction::Min => "MIN",
+        AggregateFunction::Max => "MAX",
+        AggregateFunction::Avg => "AVG",
+        AggregateFunction::Sample => "SAMPLE",
+        AggregateFunction::GroupConcat => "GROUP_CONCAT",
+    }
+}
+
+struct Serializer<'a, 'b> {
+    f:     &'a mut Formatter<'b>,
+    depth: usize,
+}
+
+impl<'a, 'b> Serializer<'a, 'b> {
+    fn new(f: &'a mut Formatter<'b>) -> Self { Self { f, depth: 0 } }
+
+    /// Start a new line at the current indentation.
+    fn line(&mut self) -> std::fmt::Result {
+        self.f.write_char('\n')?;
+        for _ in 0..self.depth {
+            self.f.write_str(INDENT)?;
+        }
+        Ok(())
+    }
+
+    fn prologue(&mut self, prologue: &Prologue) -> std::fmt::Result {
+        if let Some(base) = &prologue.base {
+            writeln!(self.f, "BASE <{base}>")?;
+        }
+        for (prefix, iri) in prologue.prefixes.iter() {
+            writeln!(self.f, "PREFIX {prefix}: <{iri}>")?;
+        }
+        Ok(())
+    }
+
+    fn query(&mut self, query: &Query) -> std::fmt::Result {
+        match &query.form {
+            QueryForm::Select { modifier, projection } => {
+                self.f.write_str("SELECT")?;
+                match modifier {
+                    SelectModifier::None => {},
+                    SelectModifier::Distinct => self.f.write_str(" DISTINCT")?,
+                    SelectModifier::Reduced => self.f.write_str(" REDUCED")?,
+                }
+                match projection {
+                    Projection::All => self.f.write_str(" *")?,
+                    Projection::Items(items) => {
+                        for item in items.iter() {
+                            match item {
+                                ProjectionItem::Variable(variable) => {
+                                    write!(self.f, " {variable}")?
+                                },
+                                ProjectionItem::Expression(expression, variable) => {
+                                    self.f.write_str(" (")?;
+                                    self.expression(expression, Precedence::Lowest)?;
+                                    write!(self.f, " AS {variable})")?;
+                                },
+                            }
+                        }
+                    },
+                }
+            },
+            QueryForm::Construct { template: Some(template) } => {
+                self.f.write_str("CONSTRUCT ")?;
+                self.triples_block(template)?;
+            },
+            QueryForm::Construct { template: None } => self.f.write_str("CONSTRUCT")?,
+            QueryForm::Describe { resources } => {
+                self.f.write_str("DESCRIBE")?;
+                if resources.is_empty() {
+                    self.f.write_str(" *")?;
+                }
+                for resource in resources.iter() {
+                    write!(self.f, " {resource}")?;
+                }
+            },
+            QueryForm::Ask => self.f.write_str("ASK")?,
+        }
+        for clause in query.dataset.iter() {
+            self.line()?;
+            self.dataset_clause("FROM", clause)?;
+        }
+        if let Some(pattern) = &query.where_clause {
+            self.line()?;
+            self.f.write_str("WHERE ")?;
+            self.group_graph_pattern(pattern)?;
+        }
+        self.solution_modifiers(&query.modifiers)?;
+        if let Some(values) = &query.values {
+            self.line()?;
+            self.inline_data(values)?;
+        }
+        Ok(())
+    }
+
+    fn dataset_clause(&mut self, keyword: &str, clause: &DatasetClause) -> std::fmt::Result {
+        if clause.named {
+            write!(self.f, "{keyword} NAMED {}", clause.iri)
+        } else {
+            write!(self.f, "{keyword} {}", clause.iri)
+        }
+    }
+
+    fn solution_modifiers(&mut self, modifiers: &SolutionModifiers) -> std::fmt::Result {
+        if !modifiers.group_by.is_empty() {
+            self.line()?;
+            self.f.write_str("GROUP BY")?;
+            for condition in modifiers.group_by.iter() {
+                self.f.write_char(' ')?;
+                match (&condition.expression, &condition.variable) {
+                    (Expression::Term(TermPattern::Variable(variable)), None) => {
+                        write!(self.f, "{variable}")?
+                    },
+                    (expression @ Expression::FunctionCall { .. }, None) => {
+                        self.expression(expression, Precedence::Lowest)?
+                    },
+                    (expression, variable) => {
+                        self.f.write_char('(')?;
+                        self.expression(expression, Precedence::Lowest)?;
+                        if let Some(variable) = variable {
+                            write!(self.f, " AS {variable}")?;
+                        }
+                        self.f.write_char(')')?;
+                    },
+                }
+            }
+        }
+        if !modifiers.having.is_empty() {
+            self.line()?;
+            self.f.write_str("HAVING")?;
+            for expression in modifiers.having.iter() {
+                self.f.write_str(" (")?;
+                self.expression(expression, Precedence::Lowest)?;
+                self.f.write_char(')')?;
+            }
+        }
+        if !modifiers.order_by.is_empty() {
+            self.line()?;
+            self.f.write_str("ORDER BY")?;
+            for condition in modifiers.order_by.iter() {
+                match (&condition.expression, condition.descending) {
+                    (Expression::Term(TermPattern::Variable(variable)), false) => {
+                        write!(self.f, " {variable}")?
+                    },
+                    (expression, descending) => {
+                        self.f
+                            .write_str(if descending { " DESC(" } else { " ASC(" })?;
+                        self.expression(expression, Precedence::Lowest)?;
+                        self.f.write_char(')')?;
+                    },
+                }
+            }
+        }
+        if let Some(limit) = modifiers.limit {
+            self.line()?;
+            write!(self.f, "LIMIT {limit}")?;
+        }
+        if let Some(offset) = modifiers.offset {
+            self.line()?;
+            write!(self.f, "OFFSET {offset}")?;
+        }
+        Ok(())
+    }
+
+    fn inline_data(&mut self, values: &InlineData) -> std::fmt::Result {
+        self.f.write_str("VALUES (")?;
+        for (idx, variable) in values.variables.iter().enumerate() {
+            if idx > 0 {
+                self.f.write_char(' ')?;
+            }
+            write!(self.f, "{variable}")?;
+        }
+        self.f.write_str(") {")?;
+        self.depth += 1;
+        for row in values.rows.iter() {
+            self.line()?;
+            self.f.write_char('(')?;
+            for (idx, value) in row.iter().enumerate() {
+                if idx > 0 {
+                    self.f.write_char(' ')?;
+                }
+                match value {
+                    Some(value) => write!(self.f, "{value}")?,
+                    None => self.f.write_str("UNDEF")?,
+                }
+            }
+            self.f.write_char(')')?;
+        }
+        self.depth -= 1;
+        self.line()?;
+        self.f.write_char('}')
+    }
+
+    fn group_graph_pattern(&mut self, pattern: &GroupGraphPattern) -> std::fmt::Result {
+        match pattern {
+            GroupGraphPattern::Group(elements) if elements.is_empty() => self.f.write_str("{}"),
+            GroupGraphPattern::Group(elements) => {
+                self.f.write_char('{')?;
+                self.depth += 1;
+                for element in elements.iter() {
+                    self.pattern_element(element)?;
+                }
+                self.depth -= 1;
+                self.line()?;
+                self.f.write_char('}')
+            },
+            GroupGraphPattern::SubSelect(query) => {
+                self.f.write_char('{')?;
+                self.depth += 1;
+                self.line()?;
+                self.query(query)?;
+                self.depth -= 1;
+                self.line()?;
+                self.f.write_char('}')
+            },
+        }
+    }
+
+    fn pattern_element(&mut self, element: &PatternElement) -> std::fmt::Result {
+        match element {
+            PatternElement::Triples(triples) => {
+                for triple in triples.iter() {
+                    self.line()?;
+                    self.triple(triple)?;
+                }
+                Ok(())
+            },
+            PatternElement::Filter(expression) => {
+                self.line()?;
+                self.f.write_str("FILTER(")?;
+                self.expression(expression, Precedence::Lowest)?;
+                self.f.write_char(')')
+            },
+            PatternElement::Optional(pattern) => {
+                self.line()?;
+                self.f.write_str("OPTIONAL ")?;
+                self.group_graph_pattern(pattern)
+            },
+            PatternElement::Minus(pattern) => {
+                self.line()?;
+                self.f.write_str("MINUS ")?;
+                self.group_graph_pattern(pattern)
+            },
+            PatternElement::Union(patterns) => {
+                self.line()?;
+                for (idx, pattern) in patterns.iter().enumerate() {
+                    if idx > 0 {
+                        self.f.write_str(" UNION ")?;
+                    }
+                    self.group_graph_pattern(pattern)?;
+                }
+                Ok(())
+            },
+            PatternElement::Graph { name, pattern } => {
+                self.line()?;
+                write!(self.f, "GRAPH {name} ")?;
+                self.group_graph_pattern(pattern)
+            },
+            PatternElement::Service { silent, name, pattern } => {
+                self.line()?;
+                self.f.write_str("SERVICE ")?;
+                if *silent {
+                    self.f.write_str("SILENT ")?;
+                }
+                write!(self.f, "{name} ")?;
+                self.group_graph_pattern(pattern)
+            },
+            PatternElement::Bind { expression, variable } => {
+                self.line()?;
+                self.f.write_str("BIND(")?;
+                self.expression(expression, Precedence::Lowest)?;
+                write!(self.f, " AS {variable})")
+            },
+            PatternElement::Values(values) => {
+                self.line()?;
+                self.inline_data(values)
+            },
+        }
+    }
+
+    /// Triples between curly braces, as in `CONSTRUCT` templates and quad
+    /// data.
+    fn triples_block(&mut self, triples: &[TriplePattern]) -> std::fmt::Result {
+        if triples.is_empty() {
+            return self.f.write_str("{}")
+        }
+        self.f.write_char('{')?;
+        self.depth += 1;
+        for triple in triples.iter() {
+            self.line()?;
+            self.triple(triple)?;
+        }
+        self.depth -= 1;
+        self.line()?;
+        self.f.write_char('}')
+    }
+
+    fn triple(&mut self, triple: &TriplePattern) -> std::fmt::Result {
+        write!(self.f, "{} ", triple.subject)?;
+        match &triple.predicate {
+            Verb::Variable(variable) => write!(self.f, "{variable}")?,
+            Verb::Path(PropertyPath::Iri(iri)) if iri.is(RDF_TYPE) => self.f.write_char('a')?,
+            Verb::Path(path) => self.path(path, PathPrecedence::Alternative)?,
+        }
+        write!(self.f, " {} .", triple.object)
+    }
+
+    fn path(&mut self, path: &PropertyPath, context: PathPrecedence) -> std::fmt::Result {
+        let precedence = match path {
+            PropertyPath::Alternative(_) => PathPrecedence::Alternative,
+            PropertyPath::Sequence(_) => PathPrecedence::Sequence,
+            PropertyPath::Inverse(_) => PathPrecedence::Inverse,
+            _ => PathPrecedence::Primary,
+        };
+        let parenthesize = precedence < context;
+        if parenthesize {
+            self.f.write_char('(')?;
+        }
+        match path {
+            PropertyPath::Iri(iri) if iri.is(RDF_TYPE) => self.f.write_char('a')?,
+            PropertyPath::Iri(iri) => write!(self.f, "{iri}")?,
+            PropertyPath::Inverse(path) => {
+                self.f.write_char('^')?;
+                self.path(path, PathPrecedence::Primary)?;
+            },
+            PropertyPath::Sequence(paths) | PropertyPath::Alternative(paths) => {
+                let (separator, context) = match path {
+                    PropertyPath::Sequence(_) => ('/', PathPrecedence::Inverse),
+                    _ => ('|', PathPrecedence::Sequence),
+                };
+                for (idx, path) in paths.iter().enumerate() {
+                    if idx > 0 {
+                        self.f.write_char(separator)?;
+                    }
+                    self.path(path, context)?;
+                }
+            },
+            PropertyPath::ZeroOrMore(inner) |
+            PropertyPath::OneOrMore(inner) |
+            PropertyPath::ZeroOrOne(inner) => {
+                // A modifier only applies to a primary, anything else (such as
+                // another modified path) has to be put between parentheses
+                if matches!(
+                    inner.as_ref(),
+                    PropertyPath::Iri(_) | PropertyPath::NegatedPropertySet(_)
+                ) {
+                    self.path(inner, PathPrecedence::Primary)?;
+                } else {
+                    self.f.write_char('(')?;
+                    self.path(inner, PathPrecedence::Alternative)?;
+                    self.f.write_char(')')?;
+                }
+                self.f.write_char(match path {
+                    PropertyPath::ZeroOrMore(_) => '*',
+                    PropertyPath::OneOrMore(_) => '+',
+                    _ => '?',
+                })?;
+            },
+            PropertyPath::NegatedPropertySet(iris) => {
+                self.f.write_char('!')?;
+                match iris.as_slice() {
+                    [(iri, false)] => self.negated_iri(iri, false)?,
+                    iris => {
+                        self.f.write_char('(')?;
+                        for (idx, (iri, inverse)) in iris.iter().enumerate() {
+                            if idx > 0 {
+                                self.f.write_char('|')?;
+                            }
+                            self.negated_iri(iri, *inverse)?;
+                        }
+                        self.f.write_char(')')?;
+                    },
+                }
+            },
+        }
+        if parenthesize {
+            self.f.write_char(')')?;
+        }
+        Ok(())
+    }
+
+    fn negated_iri(&mut self, iri: &SparqlIri, inverse: bool) -> std::fmt::Result {
+        if inverse {
+            self.f.write_char('^')?;
+        }
+        if iri.is(RDF_TYPE) {
+            self.f.write_char('a')
+        } else {
+            write!(self.f, "{iri}")
+        }
+    }
+
+    fn expression(&mut self, expression: &Expression, context: Precedence) -> std::fmt::Result {
+        match expression {
+            Expression::Term(term) => write!(self.f, "{term}"),
+            Expression::Binary(operator, left, right) => {
+                let precedence = precedence_of(*operator);
+                let parenthesize = precedence < context;
+                if parenthesize {
+                    self.f.write_char('(')?;
+                }
+                // Operators are left associative, relational operators cannot
+                // be chained at all
+                let right_context = match precedence {
+                    Precedence::Relational => Precedence::Additive,
+                    _ => next_precedence(precedence),
+                };
+                let left_context = match precedence {
+                    Precedence::Relational => Precedence::Additive,
+                    _ => precedence,
+                };
+                self.expression(left, left_context)?;
+                write!(self.f, " {} ", operator_token(*operator))?;
+                self.expression(right, right_context)?;
+                if parenthesize {
+                    self.f.write_char(')')?;
+                }
+                Ok(())
+            },
+            Expression::Unary(operator, operand) => {
+                self.f.write_char(match operator {
+                    UnaryOperator::Not => '!',
+                    UnaryOperator::Plus => '+',
+                    UnaryOperator::Minus => '-',
+                })?;
+                match operand.as_ref() {
+                    Expression::Term(TermPattern::Literal(_)) |
+                    Expression::Binary(..) |
+                    Expression::Unary(..) |
+                    Expression::In { .. } => {
+                        // Avoid `- -1` or `--1` being read as a signed number
+                        self.f.write_char('(')?;
+                        self.expression(operand, Precedence::Lowest)?;
+                        self.f.write_char(')')
+                    },
+                    operand => self.expression(operand, Precedence::Unary),
+                }
+            },
+            Expression::In { expression, list, negated } => {
+                let parenthesize = Precedence::Relational < context;
+                if parenthesize {
+                    self.f.write_char('(')?;
+                }
+                self.expression(expression, Precedence::Additive)?;
+                self.f
+                    .write_str(if *negated { " NOT IN (" } else { " IN (" })?;
+                self.expression_list(list)?;
+                self.f.write_char(')')?;
+                if parenthesize {
+                    self.f.write_char(')')?;
+                }
+                Ok(())
+            },
+            Expression::FunctionCall { function, arguments, distinct } => {
+                match function {
+                    Function::BuiltIn(name) => self.f.write_str(name.as_str())?,
+                    Function::Iri(iri) => write!(self.f, "{iri}")?,
+                }
+                self.f.write_char('(')?;
+                if *distinct {
+                    self.f.write_str("DISTINCT ")?;
+                }
+                self.expression_list(arguments)?;
+                self.f.write_char(')')
+            },
+            Expression::Exists { pattern, negated } => {
+                self.f
+                    .write_str(if *negated { "NOT EXISTS " } else { "EXISTS " })?;
+                self.group_graph_pattern(pattern)
+            },
+            Expression::Aggregate { function, distinct, expression, separator } => {
+                write!(self.f, "{}(", aggregate_name(*function))?;
+                if *distinct {
+                    self.f.write_str("DISTINCT ")?;
+                }
+                match expression {
+                    Some(expression) => self.expression(expression, Precedence::Lowest)?,
+                    None => self.f.write_char('*')?,
+                }
+                if let Some(separator) = separator {
+                    write!(
+                        self.f,
+                        " ; SEPARATOR = \"{}\"",
+                        escape_string(separator.as_str())
+                    )?;
+                }
+                self.f.write_char(')')
+            },
+        }
+    }
+
+    fn expression_list(&mut self, expressions: &[Expression]) -> std::fmt::Result {
+        for (idx, expression) in expressions.iter().enumerate() {
+            if idx > 0 {
+                self.f.write_str(", ")?;
+            }
+            self.expression(expression, Precedence::Lowest)?;
+        }
+        Ok(())
+    }
+
+    fn update_operation(&mut self, operation: &UpdateOperation) -> std::fmt::Result {
+        let silent = |silent: &bool| if *silent { " SILENT" } else { "" };
+        match operation {
+            UpdateOperation::Load { silent: is_silent, source, destination } => {
+                write!(self.f, "LOAD{} {source}", silent(is_silent))?;
+                if let Some(destination) = destination {
+                    write!(self.f, " INTO GRAPH {destination}")?;
+                }
+                Ok(())
+            },
+            UpdateOperation::Clear { silent: is_silent, target } => {
+                write!(self.f, "CLEAR{} ", silent(is_silent))?;
+                self.graph_target(target)
+            },
+            UpdateOperation::Drop { silent: is_silent, target } => {
+                write!(self.f, "DROP{} ", silent(is_silent))?;
+                self.graph_target(target)
+            },
+            UpdateOperation::Create { silent: is_silent, graph } => {
+                write!(
+                    self.f,
+                    "CREATE{} GRAPH {graph}",
+                    silent(is_silent)
+                )
+            },
+            UpdateOperation::Transfer { operation, silent: is_silent, source, destination } => {
+                let keyword = match operation {
+                    GraphTransfer::Add => "ADD",
+                    GraphTransfer::Move => "MOVE",
+                    GraphTransfer::Copy => "COPY",
+                };
+                write!(self.f, "{keyword}{} ", silent(is_silent))?;
+                self.graph_or_default(source)?;
+                self.f.write_str(" TO ")?;
+                self.graph_or_default(destination)
+            },
+            UpdateOperation::InsertData(quads) => {
+                self.f.write_str("INSERT DATA ")?;
+                self.quads(quads)
+            },
+            UpdateOperation::DeleteData(quads) => {
+                self.f.write_str("DELETE DATA ")?;
+                self.quads(quads)
+            },
+            UpdateOperation::DeleteWhere(quads) => {
+                self.f.write_str("DELETE WHERE ")?;
+                self.quads(quads)
+            },
+            UpdateOperation::Modify { with, delete, insert, using, where_clause } => {
+                let mut first = true;
+                let mut next_line = |serializer: &mut Self| {
+                    if first {
+                        first = false;
+                        Ok(())
+                    } else {
+                        serializer.line()
+                    }
+                };
+                if let Some(with) = with {
+                    next_line(self)?;
+                    write!(self.f, "WITH {with}")?;
+                }
+                if !delete.is_empty() {
+                    next_line(self)?;
+                    self.f.write_str("DELETE ")?;
+                    self.quads(delete)?;
+                }
+                if !insert.is_empty() || delete.is_empty() {
+                    next_line(self)?;
+                    self.f.write_str("INSERT ")?;
+                    self.quads(insert)?;
+                }
+                for clause in using.iter() {
+                    self.line()?;
+                    self.dataset_clause("USING", clause)?;
+                }
+                self.line()?;
+                self.f.write_str("WHERE ")?;
+                self.group_graph_pattern(where_clause)
+            },
+        }
+    }
+
+    fn graph_target(&mut self, target: &GraphTarget) -> std::fmt::Result {
+        match target {
+            GraphTarget::Graph(iri) => write!(self.f, "GRAPH {iri}"),
+            GraphTarget::Default => self.f.write_str("DEFAULT"),
+            GraphTarget::Named => self.f.write_str("NAMED"),
+            GraphTarget::All => self.f.write_str("ALL"),
+        }
+    }
+
+    fn graph_or_default(&mut self, graph: &GraphOrDefault) -> std::fmt::Result {
+        match graph {
+            GraphOrDefault::Graph(iri) => write!(self.f, "GRAPH {iri}"),
+            GraphOrDefault::Default => self.f.write_str("DEFAULT"),
+        }
+    }
+
+    fn quads(&mut self, quads: &[QuadsElement]) -> std::fmt::Result {
+        if quads.is_empty() {
+            return self.f.write_str("{}")
+        }
+        self.f.write_char('{')?;
+        self.depth += 1;
+        for quad in quads.iter() {
+            match quad {
+                QuadsElement::Triples(triples) => {
+                    for triple in triples.iter() {
+                        self.line()?;
+                        self.triple(triple)?;
+                    }
+                },
+                QuadsElement::Graph { name, triples } => {
+                    self.line()?;
+                    write!(self.f, "GRAPH {name} ")?;
+                    self.triples_block(triples)?;
+                },
+            }
+        }
+        self.depth -= 1;
+        self.line()?;
+        self.f.write_char('}')
+    }
+}
+
+fn next_precedence(precedence: Precedence) -> Precedence {
+    match precedence {
+        Precedence::Lowest => Precedence::Or,
+        Precedence::Or => Precedence::And,
+        Precedence::And => Precedence::Relational,
+        Precedence::Relational => Precedence::Additive,
+        Precedence::Additive => Precedence::Multiplicative,
+        Precedence::Multiplicative | Precedence::Unary => Precedence::Unary,
+    }
+}
@@ -0,0 +1,525 @@
+// Copyright (c) 2018-2023, agnos.ai UK Ltd, all rights reserved.
+//---------------------------------------------------------------
+
+use {
+    crate::{DataType, Literal, RDFStoreError, Term},
+    iref::Iri,
+    std::{collections::HashMap, fmt::Write, path::Path, str::FromStr},
+};
+
+/// SPARQL keywords that, when found outside of any group graph pattern,
+/// indicate that a statement is an update rather than a query.
+const UPDATE_KEYWORDS: [&str; 10] = [
+    "INSERT", "DELETE", "LOAD", "CLEAR", "CREATE", "DROP", "COPY", "MOVE", "ADD", "WITH",
+];
+
+/// The way [`SparqlTemplate::render`] puts the bound values into the
+/// query text.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum SparqlBindingStyle {
+    /// Replace each `$parameter` with the SPARQL representation of the
+    /// [`Term`] that has been bound to it.
+    #[default]
+    Substitute,
+    /// Leave the `$parameter` variables in place and append a `VALUES` block
+    /// that binds them. Only possible for queries (not for updates) that do
+    /// not have a trailing `VALUES` clause yet.
+    ValuesBlock,
+}
+
+#[derive(Debug, Clone)]
+enum Segment {
+    Text(String),
+    Parameter(String),
+}
+
+/// A `SparqlTemplate` is a SPARQL statement with `$parameters` that can be
+/// bound to [`Term`] or [`Literal`] values before the statement is executed.
+///
+/// Variables starting with `?` are left alone, only the ones starting with
+/// `$` are treated as parameters, each of which has to be bound before the
+/// template can be rendered.
+///
+/// ```rust
+/// use rdf_store_rs::{sparql::SparqlTemplate, Literal};
+///
+/// let mut template = SparqlTemplate::parse(
+///     "SELECT ?person WHERE { ?person <http://xmlns.com/foaf/0.1/name> $name }",
+/// )
+/// .unwrap();
+/// template
+///     .bind_literal(
+///         "name",
+///         Literal::new_plain_literal_string("O'Reilly \"Tim\"").unwrap(),
+///     )
+///     .unwrap();
+///
+/// assert_eq!(
+///     template.render_substituted().unwrap(),
+///     "SELECT ?person WHERE { ?person <http://xmlns.com/foaf/0.1/name> \"O'Reilly \\\"Tim\\\"\" \
+///      }"
+/// );
+/// ```
+#[derive(Debug, Clone)]
+pub struct SparqlTemplate {
+    segments:          Vec<Segment>,
+    parameters:        Vec<String>,
+    data_types:        HashMap<String, DataType>,
+    bindings:          HashMap<String, Term>,
+    is_update:         bool,
+    has_values_clause: bool,
+}
+
+impl FromStr for SparqlTemplate {
+    type Err = RDFStoreError;
+
+    fn from_str(str: &str) -> Result<Self, Self::Err> { Self::parse(str) }
+}
+
+impl SparqlTemplate {
+    /// Parse the given SPARQL statement and find its `$parameters`, skipping
+    /// over comments, string literals and IRIs.
+    pub fn parse(sparql: &str) -> Result<Self, RDFStoreError> {
+        let mut template = Self {
+            segments:          Vec::new(),
+            parameters:        Vec::new(),
+            data_types:        HashMap::new(),
+            bindings:          HashMap::new(),
+            is_update:         false,
+            has_values_clause: false,
+        };
+        let chars = sparql.char_indices().collect::<Vec<_>>();
+        let mut text = String::new();
+        let mut depth = 0_usize;
+        let mut idx = 0;
+        while idx < chars.len() {
+            let (_, c) = chars[idx];
+            let previous = if idx > 0 {
+                Some(chars[idx - 1].1)
+            } else {
+                None
+            };
+            match c {
+                '#' => {
+                    let end = find_from(&chars, idx, |c| c == '\n').unwrap_or(chars.len());
+                    text.extend(chars[idx..end].iter().map(|(_, c)| c));
+                    idx = end;
+                    continue
+                },
+                '"' | '\'' => {
+                    let end = end_of_string(&chars, idx).ok_or_else(|| {
+                        RDFStoreError::Exception {
+                            action:  "parsing SPARQL template".to_string(),
+                            message: format!("unterminated string at offset {}", chars[idx].0),
+                        }
+                    })?;
+                    text.extend(chars[idx..end].iter().map(|(_, c)| c));
+                    idx = end;
+                    continue
+                },
+                '<' => {
+                    if let Some(end) = end_of_iri(&chars, idx) {
+                        text.extend(chars[idx..end].iter().map(|(_, c)| c));
+                        idx = end;
+                        continue
+                    }
+                },
+                '$' => {
+                    let end =
+                        find_from(&chars, idx + 1, |c| !is_var_name_char(c)).unwrap_or(chars.len());
+                    if end > idx + 1 {
+                        let name = chars[idx + 1..end]
+                            .iter()
+                            .map(|(_, c)| c)
+                            .collect::<String>();
+                        template
+                            .segments
+                            .push(Segment::Text(std::mem::take(&mut text)));
+                        if !template.parameters.contains(&name) {
+                            template.parameters.push(name.clone());
+                        }
+                        template.segments.push(Segment::Parameter(name));
+                        idx = end;
+                        continue
+                    }
+                },
+                '{' => depth += 1,
+                '}' => depth = depth.saturating_sub(1),
+                _ if depth == 0 &&
+                    c.is_ascii_alphabetic() &&
+                    !matches!(previous, Some(c) if is_word_char(c)) =>
+                {
+                    let end = find_from(&chars, idx, |c| !is_word_char(c)).unwrap_or(chars.len());
+                    let is_prefix = matches!(chars.get(end), Some((_, ':')));
+                    if !is_prefix {
+                        let word = chars[idx..end]
+                            .iter()
+                            .map(|(_, c)| c.to_ascii_uppercase())
+                            .collect::<String>();
+                        if UPDATE_KEYWORDS.contains(&word.as_str()) {
+                            template.is_update = true;
+                        } else if word == "VALUES" {
+                            template.has_values_clause = true;
+                        }
+                    }
+                    text.extend(chars[idx..end].iter().map(|(_, c)| c));
+                    idx = end;
+                    continue
+                },
+                _ => {},
+            }
+            text.push(c);
+            idx += 1;
+        }
+        template.segments.push(Segment::Text(text));
+        Ok(template)
+    }
+
+    /// Read and parse a template from a `.sparql` file.
+    pub fn from_path(path: &Path) -> Result<Self, RDFStoreError> {
+        Self::parse(std::fs::read_to_string(path)?.as_str())
+    }
+
+    /// The names of all parameters (without the `$`) in order of first
+    /// appearance.
+    pub fn parameters(&self) -> impl Iterator<Item = &str> {
+        self.parameters.iter().map(|name| name.as_str())
+    }
+
+    /// Does the template contain an update (as opposed to a query)?
+    pub fn is_update(&self) -> bool { self.is_update }
+
+    /// Declare the [`DataType`] that values bound to the given parameter
+    /// must have, so that mistyped values are rejected by
+    /// [`bind`](Self::bind).
+    pub fn expect_data_type(
+        &mut self,
+        name: &str,
+        data_type: DataType,
+    ) -> Result<&mut Self, RDFStoreError> {
+        self.check_parameter(name)?;
+        if let Some(term) = self.bindings.get(name) {
+            check_data_type(name, data_type, term)?;
+        }
+        self.data_types.insert(name.to_string(), data_type);
+        Ok(self)
+    }
+
+    /// Bind the given parameter to the given [`Term`], replacing any value
+    /// that was bound to it before.
+    pub fn bind(&mut self, name: &str, term: Term) -> Result<&mut Self, RDFStoreError> {
+        self.check_parameter(name)?;
+        if let Some(data_type) = self.data_types.get(name) {
+            check_data_type(name, *data_type, &term)?;
+        }
+        self.bindings.insert(name.to_string(), term);
+        Ok(self)
+    }
+
+    pub fn bind_literal(
+        &mut self,
+        name: &str,
+        literal: Literal,
+    ) -> Result<&mut Self, RDFStoreError> {
+        self.bind(name, Term::from(literal))
+    }
+
+    pub fn bind_iri(&mut self, name: &str, iri: &Iri) -> Result<&mut Self, RDFStoreError> {
+        self.bind(name, Term::new_iri(iri)?)
+    }
+
+    /// Render the template with each parameter replaced by its value.
+    pub fn render_substituted(&self) -> Result<String, RDFStoreError> {
+        self.render(SparqlBindingStyle::Substitute)
+    }
+
+    /// Render the template as SPARQL text, failing if any parameter has not
+    /// been bound or if the bound values cannot be used with the given
+    /// [`SparqlBindingStyle`].
+    pub fn render(&self, style: SparqlBindingStyle) -> Result<String, RDFStoreError> {
+        if let Some(name) = self
+            .parameters
+            .iter()
+            .find(|name| !self.bindings.contains_key(name.as_str()))
+        {
+            return Err(RDFStoreError::UnboundTemplateParameter { name: name.clone() })
+        }
+        match style {
+            SparqlBindingStyle::Substitute => self.render_with_substitution(),
+            SparqlBindingStyle::ValuesBlock => self.render_with_values_block(),
+        }
+    }
+
+    fn render_with_substitution(&self) -> Result<String, RDFStoreError> {
+        let mut sparql = String::new();
+        for segment in self.segments.iter() {
+            match segment {
+                Segment::Text(text) => sparql.push_str(text),
+                Segment::Parameter(name) => {
+                    write!(sparql, "{}", self.bindings[name].display_sparql())
+                        .map_err(|err| render_error(err.to_string()))?
+                },
+            }
+        }
+        Ok(sparql)
+    }
+
+    fn render_with_values_block(&self) -> Result<String, RDFStoreError> {
+        if self.is_update {
+            return Err(render_error(
+                "cannot add a VALUES block to an update".to_string(),
+            ))
+        }
+        if self.has_values_clause {
+            return Err(render_error(
+                "the query already has a trailing VALUES clause".to_string(),
+            ))
+        }
+        let mut sparql = String::new();
+        for segment in self.segments.iter() {
+            match segment {
+                Segment::Text(text) => sparql.push_str(text),
+                Segment::Parameter(name) => write!(sparql, "${name}").unwrap(),
+            }
+        }
+        if self.parameters.is_empty() {
+            return Ok(sparql)
+        }
+        let mut variables = Vec::new();
+        let mut values = Vec::new();
+        for name in self.parameters.iter() {
+            let term = &self.bindings[name];
+            if let Term::BlankNode(_) = term {
+                return Err(RDFStoreError::InvalidTemplateParameterValue {
+                    name:    name.clone(),
+                    message: "blank nodes are not allowed in a VALUES block".to_string(),
+                })
+            }
+            variables.push(format!("${name}"));
+            values.push(term.display_sparql().to_string());
+        }
+        if !sparql.ends_with('\n') {
+            sparql.push('\n');
+        }
+        writeln!(
+            sparql,
+            "VALUES ({}) {{\n    ({})\n}}",
+            variables.join(" "),
+            values.join(" ")
+        )
+        .map_err(|err| render_error(err.to_string()))?;
+        Ok(sparql)
+    }
+
+    fn check_parameter(&self, name: &str) -> Result<(), RDFStoreError> {
+        if self.parameters.iter().any(|parameter| parameter == name) {
+            Ok(())
+        } else {
+            Err(RDFStoreError::UnknownTemplateParameter { name: name.to_string() })
+        }
+    }
+}
+
+fn render_error(message: String) -> RDFStoreError {
+    RDFStoreError::Exception {
+        action: "rendering SPARQL template".to_string(),
+        message,
+    }
+}
+
+/// A value is of the expected datatype if its datatype is derived from it or
+/// if it is an integer within the range of the expected integer datatype,
+/// such as an `xsd:integer` 5 for an `xsd:unsignedByte`.
+fn check_data_type(name: &str, expected: DataType, term: &Term) -> Result<(), RDFStoreError> {
+    let literal = term.as_literal();
+    let actual = literal.data_type;
+    let in_range = || {
+        match (literal.as_big_integer(), expected.integer_range()) {
+            (Some(integer), Some((min, max))) => {
+                min.map_or(true, |min| min <= integer) && max.map_or(true, |max| integer <= max)
+            },
+            _ => false,
+        }
+    };
+    let compatible = actual.is_derived_from(expected) ||
+        (actual.is_iri() && expected.is_iri()) ||
+        (actual.is_string() && expected.is_string()) ||
+        (actual.is_integer() && expected.is_integer() && in_range());
+    if compatible {
+        Ok(())
+    } else {
+        Err(RDFStoreError::InvalidTemplateParameterValue {
+            name:    name.to_string(),
+            message: format!("expected a value of type {expected:?} but got {actual:?}"),
+        })
+    }
+}
+
+fn find_from(
+    chars: &[(usize, char)],
+    from: usize,
+    predicate: impl Fn(char) -> bool,
+) -> Option<usize> {
+    chars[from..]
+        .iter()
+        .position(|(_, c)| predicate(*c))
+        .map(|pos| pos + from)
+}
+
+/// Return the index just after the string literal that starts at `start`,
+/// supporting both short and long (triple quoted) strings.
+fn end_of_string(chars: &[(usize, char)], start: usize) -> Option<usize> {
+    let quote = chars[start].1;
+    let is_quote = |idx: usize| matches!(chars.get(idx), Some((_, c)) if *c == quote);
+    let long = is_quote(start + 1) && is_quote(start + 2);
+    let mut idx = if long { start + 3 } else { start + 1 };
+    while idx < chars.len() {
+        match chars[idx].1 {
+            '\\' => idx += 2,
+            c if c == quote => {
+                if !long {
+                    return Some(idx + 1)
+                }
+                if is_quote(idx + 1) && is_quote(idx + 2) {
+                    return Some(idx + 3)
+                }
+                idx += 1;
+            },
+            '\n' | '\r' if !long => return None,
+            _ => idx += 1,
+        }
+    }
+    None
+}
+
+/// Return the index just after the `IRIREF` that starts at `start` or `None`
+/// if the `<` at `start` is a less-than operator instead.
+fn end_of_iri(chars: &[(usize, char)], start: usize) -> Option<usize> {
+    for (idx, (_, c)) in chars.iter().enumerate().skip(start + 1) {
+        match c {
+            '>' => return Some(idx + 1),
+            '<' | '"' | '{' | '}' | '|' | '^' | '`' | '\\' => return None,
+            c if c.is_whitespace() => return None,
+            _ => {},
+        }
+    }
+    None
+}
+
+fn is_var_name_char(c: char) -> bool { c == '_' || c.is_alphanumeric() || c == '\u{B7}' }
+
+fn is_word_char(c: char) -> bool {
+    c.is_alphanumeric() || matches!(c, '_' | '?' | '$' | ':' | '-' | '.')
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::{SparqlBindingStyle, SparqlTemplate},
+        crate::{DataType, Literal, RDFStoreError, Term},
+        indoc::indoc,
+    };
+
+    #[test_log::test]
+    fn test_parameters() -> Result<(), RDFStoreError> {
+        let template = SparqlTemplate::parse(indoc! {r#"
+            # Find things called $notAParameter
+            SELECT ?thing WHERE {
+                ?thing <http://example.com/name$notAParameter> $name .
+                FILTER(?thing != "$notAParameter" && ?count < $max && $name != '''$no''')
+            }
+        "#})?;
+        assert_eq!(template.parameters().collect::<Vec<_>>(), vec![
+            "name", "max"
+        ]);
+        assert!(!template.is_update());
+        Ok(())
+    }
+
+    #[test_log::test]
+    fn test_substitution() -> Result<(), RDFStoreError> {
+        let mut template = SparqlTemplate::parse("SELECT * WHERE { $s ?p $o . FILTER(?p != $s) }")?;
+        template
+            .bind(
+                "s",
+                Term::new_iri_from_str("https://example.com/id/abc")?,
+            )?
+            .bind_literal("o", Literal::new_signed_integer(-5)?)?;
+        assert_eq!(
+            template.render_substituted()?,
+            "SELECT * WHERE { <https://example.com/id/abc> ?p \
+             \"-5\"^^<http://www.w3.org/2001/XMLSchema#negativeInteger> . FILTER(?p != \
+             <https://example.com/id/abc>) }"
+        );
+        Ok(())
+    }
+
+    #[test_log::test]
+    fn test_values_block() -> Result<(), RDFStoreError> {
+        let mut template = SparqlTemplate::parse("SELECT ?label WHERE { $s ?p ?label }")?;
+        template.bind(
+            "s",
+            Term::new_iri_from_str("https://example.com/id/abc")?,
+        )?;
+        assert_eq!(
+            template.render(SparqlBindingStyle::ValuesBlock)?,
+            "SELECT ?label WHERE { $s ?p ?label }\nVALUES ($s) {\n    \
+             (<https://example.com/id/abc>)\n}\n"
+        );
+        Ok(())
+    }
+
+    #[test_log::test]
+    fn test_values_block_for_update() -> Result<(), RDFStoreError> {
+        let mut template = SparqlTemplate::parse("DELETE WHERE { $s ?p ?o }")?;
+        template.bind_literal("s", Literal::new_plain_literal_string("x")?)?;
+        assert!(template.is_update());
+        assert!(template.render(SparqlBindingStyle::ValuesBlock).is_err());
+        Ok(())
+    }
+
+    #[test_log::test]
+    fn test_unbound_and_unknown_parameters() -> Result<(), RDFStoreError> {
+        let mut template = SparqlTemplate::parse("ASK { $s ?p $o }")?;
+        template.bind_literal("s", Literal::new_boolean(true)?)?;
+        assert!(matches!(
+            template.render_substituted(),
+            Err(RDFStoreError::UnboundTemplateParameter { name }) if name == "o"
+        ));
+        assert!(matches!(
+            template.bind_literal("x", Literal::new_boolean(true)?),
+            Err(RDFStoreError::UnknownTemplateParameter { name }) if name == "x"
+        ));
+        Ok(())
+    }
+
+    #[test_log::test]
+    fn test_mistyped_parameter() -> Result<(), RDFStoreError> {
+        let mut template = SparqlTemplate::parse("SELECT * WHERE { ?s ?p $count }")?;
+        template.expect_data_type("count", DataType::Integer)?;
+        assert!(matches!(
+            template.bind_literal("count", Literal::new_plain_literal_string("ten")?),
+            Err(RDFStoreError::InvalidTemplateParameterValue { .. })
+        ));
+        template.bind_literal("count", Literal::new_unsigned_integer(10)?)?;
+
+        template.expect_data_type("count", DataType::PositiveInteger)?;
+        assert!(matches!(
+            template.bind_literal("count", Literal::new_signed_integer(-5)?),
+            Err(RDFStoreError::InvalidTemplateParameterValue { .. })
+        ));
+        template.expect_data_type("count", DataType::UnsignedByte)?;
+        assert!(matches!(
+            template.bind_literal(
+                "count",
+                Literal::new_signed_integer_with_datatype(300, DataType::Integer)?
+            ),
+            Err(RDFStoreError::InvalidTemplateParameterValue { .. })
+        ));
+        template.bind_literal(
+            "count",
+            Literal::new_signed_integer_with_datatype(5, DataType::Integer)?,
+        )?;
+        Ok(())
+    }
+}
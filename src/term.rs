@@ -3,13 +3,13 @@
 use {
     crate::{DataType, Literal, RDFStoreError},
     iref::Iri,
-    std::str::FromStr,
+    std::{cmp::Ordering, str::FromStr},
 };
 
 /// An RDF Term is either an IRI, a literal or a blank node.
 ///
 /// See <https://www.w3.org/TR/rdf11-concepts/#section-triples>
-#[derive(Debug)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub enum Term {
     Iri(Literal),
     Literal(Literal),
@@ -60,6 +60,75 @@ impl Term {
         }
         TurtleTerm(self)
     }
+
+    /// Display a [`Term`] as a SPARQL term, with strings escaped and typed
+    /// literals suffixed with their full datatype IRI.
+    ///
+    /// ```rust
+    /// use rdf_store_rs::{Literal, Term};
+    ///
+    /// let term = Term::from(Literal::new_signed_integer(-42).unwrap());
+    /// let sparql = format!("{}", term.display_sparql());
+    ///
+    /// assert_eq!(
+    ///     sparql,
+    ///     "\"-42\"^^<http://www.w3.org/2001/XMLSchema#negativeInteger>"
+    /// );
+    /// ```
+    pub fn display_sparql<'a, 'b>(&'a self) -> impl std::fmt::Display + 'a + 'b
+    where 'a: 'b {
+        struct SparqlTerm<'b>(&'b Term);
+        impl<'b> std::fmt::Display for SparqlTerm<'b> {
+            fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+                self.0.as_literal().as_sparql_display().fmt(f)
+            }
+        }
+        SparqlTerm(self)
+    }
+
+    /// Return the [`Literal`] that holds the value of this term.
+    pub fn as_literal(&self) -> &Literal {
+        match self {
+            Term::Iri(value) => value,
+            Term::Literal(value) => value,
+            Term::BlankNode(value) => value,
+        }
+    }
+}
+
+impl Term {
+    /// Value equality, as done by the SPARQL `=` operator: literals are
+    /// compared with [`Literal::value_equals`], IRIs and blank nodes are only
+    /// equal to the same term. Use `==` for term equality.
+    pub fn value_equals(&self, other: &Term) -> Result<bool, RDFStoreError> {
+        match (self, other) {
+            (Term::Literal(a), Term::Literal(b)) => a.value_equals(b),
+            _ => Ok(self == other),
+        }
+    }
+}
+
+impl PartialOrd for Term {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> { Some(self.cmp(other)) }
+}
+
+/// The ordering of SPARQL `ORDER BY`: blank nodes first, then IRIs and then
+/// literals in the order of [`Literal`]'s [`Ord`] implementation.
+/// Since `None` comes before `Some`, sorting `Option<Term>`s also puts
+/// unbound values first, as `ORDER BY` does.
+impl Ord for Term {
+    fn cmp(&self, other: &Self) -> Ordering {
+        fn rank(term: &Term) -> u8 {
+            match term {
+                Term::BlankNode(_) => 0,
+                Term::Iri(_) => 1,
+                Term::Literal(_) => 2,
+            }
+        }
+        rank(self)
+            .cmp(&rank(other))
+            .then_with(|| self.as_literal().cmp(other.as_literal()))
+    }
 }
 
 impl FromStr for Term {
@@ -79,6 +148,26 @@ mod tests {
         iref::Iri,
     };
 
+    #[test_log::test]
+    fn test_order_by_ordering() -> Result<(), RDFStoreError> {
+        let mut terms = [
+            Some(Term::new_str("a")?),
+            Some(Term::new_iri_from_str("https://example.com/a")?),
+            None,
+            Some(Term::new_blank_node("b0")?),
+        ];
+        terms.sort();
+        assert_eq!(terms[0], None);
+        assert!(matches!(terms[1], Some(Term::BlankNode(_))));
+        assert!(matches!(terms[2], Some(Term::Iri(_))));
+        assert!(matches!(terms[3], Some(Term::Literal(_))));
+
+        let iri = Term::new_iri_from_str("https://example.com/a")?;
+        assert!(iri.value_equals(&iri.clone())?);
+        assert!(!iri.value_equals(&Term::new_str("https://example.com/a")?)?);
+        Ok(())
+    }
+
     #[test_log::test]
     fn test_term_01() {
         let term = Term::new_iri(&Iri::new("https://whatever.url").unwrap()).unwrap();
@@ -0,0 +1,358 @@
+// Copyright (c) 2018-2023, agnos.ai UK Ltd, all rights reserved.
+//---------------------------------------------------------------
+//! Turning Rust values into RDF triples with [`ToTriples`], the reverse of
+//! reading them from query solutions.
+//!
+//! A value writes its triples into any [`TripleSink`], such as a
+//! `Vec<[Term; 3]>` or a [`MemoryStore`], and returns its subject, so that a
+//! value that has other values as properties can link to them.
+//!
+//! ```rust
+//! use {
+//!     rdf_store_rs::{
+//!         Class,
+//!         Namespace,
+//!         Predicate,
+//!         RDFStoreError,
+//!         Term,
+//!         ToTriples,
+//!         TripleSink,
+//!         TripleWriter,
+//!     },
+//!     std::sync::Arc,
+//! };
+//!
+//! struct Person {
+//!     id:   u32,
+//!     name: String,
+//! }
+//!
+//! impl ToTriples for Person {
+//!     fn to_triples(&self, sink: &mut dyn TripleSink) -> Result<Term, RDFStoreError> {
+//!         let foaf = Arc::new(Namespace::declare_from_str_unchecked(
+//!             "foaf:",
+//!             "http://xmlns.com/foaf/0.1/",
+//!         ));
+//!         let iri = format!(
+//!             "https://ekgf.org/person/{}",
+//!             TripleWriter::iri_segment(&self.id)?
+//!         );
+//!         let mut writer = TripleWriter::new_iri(sink, iri.as_str())?;
+//!         writer.class(&Class::declare(foaf.clone(), "Person"))?;
+//!         writer.literal(&Predicate::declare(foaf, "name"), &self.name)?;
+//!         Ok(writer.finish())
+//!     }
+//! }
+//!
+//! let person = Person { id: 42, name: "Alice".to_string() };
+//! let mut triples: Vec<[Term; 3]> = Vec::new();
+//! person.to_triples(&mut triples).unwrap();
+//! assert_eq!(triples.len(), 2);
+//! ```
+//!
+//! With the `derive` feature, `#[derive(ToTriples)]` generates such an
+//! implementation from attributes, see the `rdf-store-rs-derive` crate.
+
+use {
+    crate::{
+        sparql::{MemoryStore, Quad},
+        vocab::rdf,
+        Class,
+        Predicate,
+        RDFStoreError,
+        Term,
+        ToLiteral,
+    },
+    std::{
+        fmt::Write,
+        sync::atomic::{AtomicU64, Ordering},
+    },
+};
+
+/// The number of blank nodes created by [`TripleSink::new_blank_node`] so
+/// far, to give each of them a different label.
+static BLANK_NODE_COUNT: AtomicU64 = AtomicU64::new(0);
+
+/// Anything that triples can be written into.
+pub trait TripleSink {
+    fn add_triple(
+        &mut self,
+        subject: Term,
+        predicate: Term,
+        object: Term,
+    ) -> Result<(), RDFStoreError>;
+
+    /// A blank node that has not been used before, for a value without an
+    /// IRI. By default, these are labelled `b1`, `b2` and so on, counting
+    /// for the whole process.
+    fn new_blank_node(&mut self) -> Result<Term, RDFStoreError> {
+        let count = BLANK_NODE_COUNT.fetch_add(1, Ordering::Relaxed) + 1;
+        Term::new_blank_node(format!("b{count}").as_str())
+    }
+}
+
+impl TripleSink for Vec<[Term; 3]> {
+    fn add_triple(
+        &mut self,
+        subject: Term,
+        predicate: Term,
+        object: Term,
+    ) -> Result<(), RDFStoreError> {
+        self.push([subject, predicate, object]);
+        Ok(())
+    }
+}
+
+/// Triples are added to the default graph of the store.
+impl TripleSink for MemoryStore {
+    fn add_triple(
+        &mut self,
+        subject: Term,
+        predicate: Term,
+        object: Term,
+    ) -> Result<(), RDFStoreError> {
+        self.insert(Quad::new(subject, predicate, object, None));
+        Ok(())
+    }
+}
+
+/// A Rust value that can be turned into RDF triples about a subject.
+pub trait ToTriples {
+    /// Write the triples of this value into the given sink and return
+    /// their subject.
+    fn to_triples(&self, sink: &mut dyn TripleSink) -> Result<Term, RDFStoreError>;
+
+    /// Write the triples of this value, if there is a value, which is only
+    /// not the case for [`Option::None`].
+    fn to_optional_triples(
+        &self,
+        sink: &mut dyn TripleSink,
+    ) -> Result<Option<Term>, RDFStoreError> {
+        self.to_triples(sink).map(Some)
+    }
+
+    /// A SPARQL `INSERT DATA` statement that inserts the triples of this
+    /// value.
+    fn to_insert_data(&self) -> Result<String, RDFStoreError> {
+        let mut triples: Vec<[Term; 3]> = Vec::new();
+        self.to_optional_triples(&mut triples)?;
+        let mut sparql = String::from("INSERT DATA {\n");
+        for [subject, predicate, object] in triples.iter() {
+            writeln!(
+                sparql,
+                "    {} {} {} .",
+                subject.display_sparql(),
+                predicate.display_sparql(),
+                object.display_sparql()
+            )
+            .unwrap();
+        }
+        sparql.push('}');
+        Ok(sparql)
+    }
+}
+
+impl<T: ToTriples + ?Sized> ToTriples for &T {
+    fn to_triples(&self, sink: &mut dyn TripleSink) -> Result<Term, RDFStoreError> {
+        (**self).to_triples(sink)
+    }
+
+    fn to_optional_triples(
+        &self,
+        sink: &mut dyn TripleSink,
+    ) -> Result<Option<Term>, RDFStoreError> {
+        (**self).to_optional_triples(sink)
+    }
+}
+
+impl<T: ToTriples> ToTriples for Option<T> {
+    /// Returns an [`RDFStoreError::MissingSubject`] for `None`, see
+    /// [`ToTriples::to_optional_triples`] for the conversion that allows it.
+    fn to_triples(&self, sink: &mut dyn TripleSink) -> Result<Term, RDFStoreError> {
+        match self {
+            Some(value) => value.to_triples(sink),
+            None => {
+                Err(RDFStoreError::MissingSubject {
+                    type_name: std::any::type_name::<T>().to_string(),
+                })
+            },
+        }
+    }
+
+    fn to_optional_triples(
+        &self,
+        sink: &mut dyn TripleSink,
+    ) -> Result<Option<Term>, RDFStoreError> {
+        match self {
+            Some(value) => value.to_optional_triples(sink),
+            None => Ok(None),
+        }
+    }
+}
+
+/// Writes the triples about one subject into a [`TripleSink`], which is
+/// what implementations of [`ToTriples`] (including derived ones) use.
+pub struct TripleWriter<'a> {
+    sink:    &'a mut dyn TripleSink,
+    subject: Term,
+}
+
+impl<'a> TripleWriter<'a> {
+    pub fn new(sink: &'a mut dyn TripleSink, subject: Term) -> Self { Self { sink, subject } }
+
+    /// A writer for triples about the resource with the given IRI.
+    pub fn new_iri(sink: &'a mut dyn TripleSink, iri: &str) -> Result<Self, RDFStoreError> {
+        Ok(Self::new(sink, Term::new_iri_from_str(iri)?))
+    }
+
+    /// A writer for triples about a new blank node of the sink.
+    pub fn new_blank_node(sink: &'a mut dyn TripleSink) -> Result<Self, RDFStoreError> {
+        let subject = sink.new_blank_node()?;
+        Ok(Self::new(sink, subject))
+    }
+
+    /// The lexical form of the given value, percent-encoded so that it can
+    /// be used as (part of) a segment of an IRI, such as the `42` in
+    /// `https://ekgf.org/person/42`.
+    pub fn iri_segment(value: &(impl ToLiteral + ?Sized)) -> Result<String, RDFStoreError> {
+        Ok(urlencoding::encode(value.to_literal()?.lexical_form().as_str()).into_owned())
+    }
+
+    pub fn subject(&self) -> &Term { &self.subject }
+
+    /// Add an `rdf:type` triple with the given class.
+    pub fn class(&mut self, class: &Class) -> Result<(), RDFStoreError> {
+        let predicate = Term::try_from(&*rdf::TYPE)?;
+        let object = Term::try_from(class)?;
+        self.sink
+            .add_triple(self.subject.clone(), predicate, object)
+    }
+
+    /// Add a triple with the literal (or IRI) of the given value, or
+    /// nothing if there is no value.
+    pub fn literal(
+        &mut self,
+        predicate: &Predicate,
+        value: &(impl ToLiteral + ?Sized),
+    ) -> Result<(), RDFStoreError> {
+        match value.to_optional_literal()? {
+            Some(literal) => self.add(predicate, Term::from(literal)),
+            None => Ok(()),
+        }
+    }
+
+    /// Write the triples of the given value and link to its subject, or do
+    /// nothing if there is no value.
+    pub fn nested(
+        &mut self,
+        predicate: &Predicate,
+        value: &(impl ToTriples + ?Sized),
+    ) -> Result<(), RDFStoreError> {
+        match value.to_optional_triples(&mut *self.sink)? {
+            Some(object) => self.add(predicate, object),
+            None => Ok(()),
+        }
+    }
+
+    /// The subject of the written triples.
+    pub fn finish(self) -> Term { self.subject }
+
+    fn add(&mut self, predicate: &Predicate, object: Term) -> Result<(), RDFStoreError> {
+        let predicate = Term::try_from(predicate)?;
+        self.sink
+            .add_triple(self.subject.clone(), predicate, object)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::{ToTriples, TripleSink, TripleWriter},
+        crate::{Namespace, Predicate, RDFStoreError, Term, ToLiteral},
+        std::sync::Arc,
+    };
+
+    fn test() -> Arc<Namespace> {
+        Arc::new(Namespace::declare_from_str_unchecked(
+            "test:",
+            "https://whatever.kom/test#",
+        ))
+    }
+
+    struct Address {
+        city: String,
+    }
+
+    impl ToTriples for Address {
+        fn to_triples(&self, sink: &mut dyn TripleSink) -> Result<Term, RDFStoreError> {
+            let mut writer = TripleWriter::new_blank_node(sink)?;
+            writer.literal(&Predicate::declare(test(), "city"), &self.city)?;
+            Ok(writer.finish())
+        }
+    }
+
+    struct Person {
+        name:    String,
+        age:     Option<u8>,
+        address: Option<Address>,
+    }
+
+    impl ToTriples for Person {
+        fn to_triples(&self, sink: &mut dyn TripleSink) -> Result<Term, RDFStoreError> {
+            let ns = test();
+            let iri = format!(
+                "https://whatever.kom/person/{}",
+                TripleWriter::iri_segment(&self.name)?
+            );
+            let mut writer = TripleWriter::new_iri(sink, iri.as_str())?;
+            writer.literal(
+                &Predicate::declare(ns.clone(), "name"),
+                &self.name,
+            )?;
+            writer.literal(&Predicate::declare(ns.clone(), "age"), &self.age)?;
+            writer.nested(&Predicate::declare(ns, "address"), &self.address)?;
+            Ok(writer.finish())
+        }
+    }
+
+    #[test_log::test]
+    fn test_nested_blank_node() -> Result<(), RDFStoreError> {
+        let person = Person {
+            name:    "Jane Doe".to_string(),
+            age:     None,
+            address: Some(Address { city: "London".to_string() }),
+        };
+        let mut triples: Vec<[Term; 3]> = Vec::new();
+        let subject = person.to_triples(&mut triples)?;
+
+        assert_eq!(
+            subject,
+            Term::new_iri_from_str("https://whatever.kom/person/Jane%20Doe")?
+        );
+        assert_eq!(triples.len(), 3);
+        let [city_subject, _, city] = &triples[1];
+        assert!(matches!(city_subject, Term::BlankNode(_)));
+        assert_eq!(city, &Term::from("London".to_literal()?));
+        let [_, _, address] = &triples[2];
+        assert_eq!(address, city_subject);
+        Ok(())
+    }
+
+    #[test_log::test]
+    fn test_insert_data() -> Result<(), RDFStoreError> {
+        let person = Person {
+            name:    "Jane".to_string(),
+            age:     Some(42),
+            address: None,
+        };
+
+        assert_eq!(
+            person.to_insert_data()?,
+            "INSERT DATA {\n    <https://whatever.kom/person/Jane> \
+             <https://whatever.kom/test#name> \"Jane\" .\n    \
+             <https://whatever.kom/person/Jane> <https://whatever.kom/test#age> \
+             \"42\"^^<http://www.w3.org/2001/XMLSchema#unsignedByte> .\n}"
+        );
+        Ok(())
+    }
+}
@@ -0,0 +1,228 @@
+// Copyright (c) 2018-2023, agnos.ai UK Ltd, all rights reserved.
+//---------------------------------------------------------------
+//! A parser for [Turtle](https://www.w3.org/TR/turtle/) (and N-Triples)
+//! documents, such as the ontologies that a
+//! [`Vocabulary`](crate::Vocabulary) is generated from.
+//!
+//! Turtle shares its triples with SPARQL, so this extends the SPARQL parser
+//! with the Turtle directives rather than repeating its grammar.
+
+use {
+    crate::{
+        iri::resolve_relative,
+        sparql::{
+            parser::{syntax_error, ws, PResult, Parser, SyntaxError},
+            Prologue,
+            PropertyPath,
+            SparqlIri,
+            SparqlLiteral,
+            TermPattern,
+            TriplePattern,
+            Verb,
+        },
+        RDFStoreError,
+    },
+    nom::{
+        combinator::cut,
+        sequence::{pair, tuple},
+    },
+};
+
+/// Parse the given Turtle (or N-Triples) document, returning all its
+/// `@prefix` and `@base` declarations and its triples, where every IRI has
+/// been resolved against the declarations that precede it.
+pub(crate) fn parse_turtle(turtle: &str) -> Result<(Prologue, Vec<TriplePattern>), RDFStoreError> {
+    Parser::default()
+        .unit(turtle, Parser::turtle_doc)
+        .map_err(|failure| {
+            match syntax_error(turtle, failure) {
+                RDFStoreError::SparqlParseError { line, column, message } => {
+                    RDFStoreError::TurtleParseError { line, column, message }
+                },
+                error => error,
+            }
+        })
+}
+
+impl Parser {
+    /// A [Turtle](https://www.w3.org/TR/turtle/#grammar-production-turtleDoc)
+    /// document, which shares its triples with SPARQL but has no variables
+    /// or property paths. Both the `@prefix` and the SPARQL `PREFIX` forms
+    /// of the directives are allowed.
+    fn turtle_doc<'a>(&self, mut i: &'a str) -> PResult<'a, (Prologue, Vec<TriplePattern>)> {
+        let mut prologue = Prologue::default();
+        let mut triples = Vec::new();
+        loop {
+            if ws(i).is_empty() {
+                return Ok((i, (prologue, triples)))
+            }
+            if let Ok((rest, _)) = pair(self.tok("@"), self.kw("prefix"))(i) {
+                let (rest, (prefix, iri, _)) = cut(tuple((
+                    |i| self.pname_ns(i),
+                    |i| self.iriref(i),
+                    self.tok("."),
+                )))(rest)?;
+                let iri = resolve_relative(prologue.base.as_deref(), iri.as_str());
+                prologue.prefixes.push((prefix, iri));
+                i = rest;
+            } else if let Ok((rest, _)) = pair(self.tok("@"), self.kw("base"))(i) {
+                let (rest, (base, _)) = cut(pair(|i| self.iriref(i), self.tok(".")))(rest)?;
+                prologue.base = Some(resolve_relative(
+                    prologue.base.as_deref(),
+                    base.as_str(),
+                ));
+                i = rest;
+            } else if let Ok((rest, _)) = self.kw("PREFIX")(i) {
+                let (rest, (prefix, iri)) =
+                    cut(pair(|i| self.pname_ns(i), |i| self.iriref(i)))(rest)?;
+                let iri = resolve_relative(prologue.base.as_deref(), iri.as_str());
+                prologue.prefixes.push((prefix, iri));
+                i = rest;
+            } else if let Ok((rest, _)) = self.kw("BASE")(i) {
+                let (rest, base) = cut(|i| self.iriref(i))(rest)?;
+                prologue.base = Some(resolve_relative(
+                    prologue.base.as_deref(),
+                    base.as_str(),
+                ));
+                i = rest;
+            } else {
+                let (rest, statement) = cut(|i| self.triples_same_subject(i))(i)?;
+                let (rest, _) = cut(self.tok("."))(rest)?;
+                for triple in statement {
+                    triples.push(self.resolve_turtle_triple(i, &prologue, triple)?.1);
+                }
+                i = rest;
+            }
+        }
+    }
+
+    /// Check that a triple of a Turtle document has no variables or
+    /// property paths, and resolve its IRIs.
+    fn resolve_turtle_triple<'a>(
+        &self,
+        i: &'a str,
+        prologue: &Prologue,
+        triple: TriplePattern,
+    ) -> PResult<'a, TriplePattern> {
+        let failure = |message: String| {
+            nom::Err::Failure(SyntaxError { input: ws(i), message: Some(message) })
+        };
+        let resolve_iri = |iri: &SparqlIri| {
+            match prologue.resolve(iri) {
+                Ok(resolved) => Ok(SparqlIri::Iri(resolved)),
+                Err(_) => {
+                    let prefix = match iri {
+                        SparqlIri::PrefixedName { prefix, .. } => prefix.as_str(),
+                        SparqlIri::Iri(_) => "",
+                    };
+                    Err(failure(format!(
+                        "prefix {prefix}: has not been declared"
+                    )))
+                },
+            }
+        };
+        let resolve = |term: TermPattern| {
+            match term {
+                TermPattern::Variable(variable) => {
+                    Err(failure(format!(
+                        "variable ?{} is not allowed in Turtle",
+                        variable.0
+                    )))
+                },
+                TermPattern::Iri(iri) => Ok(TermPattern::Iri(resolve_iri(&iri)?)),
+                TermPattern::Literal(SparqlLiteral { lexical_form, language, data_type }) => {
+                    Ok(TermPattern::Literal(SparqlLiteral {
+                        lexical_form,
+                        language,
+                        data_type: data_type.as_ref().map(resolve_iri).transpose()?,
+                    }))
+                },
+                blank_node => Ok(blank_node),
+            }
+        };
+        let predicate = match &triple.predicate {
+            Verb::Path(PropertyPath::Iri(iri)) => Verb::Path(PropertyPath::Iri(resolve_iri(iri)?)),
+            _ => {
+                return Err(failure(
+                    "only IRIs are allowed as predicates in Turtle".to_string(),
+                ))
+            },
+        };
+        Ok((i, TriplePattern {
+            subject: resolve(triple.subject)?,
+            predicate,
+            object: resolve(triple.object)?,
+        }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::parse_turtle,
+        crate::{
+            sparql::{PropertyPath, SparqlIri, SparqlLiteral, TermPattern, Verb},
+            RDFStoreError,
+        },
+        indoc::indoc,
+    };
+
+    #[test_log::test]
+    fn test_turtle() -> Result<(), RDFStoreError> {
+        let (prologue, triples) = parse_turtle(indoc! {r#"
+            @prefix foaf: <http://xmlns.com/foaf/0.1/> .
+            @base <https://example.com/people/> .
+            PREFIX rdfs: <http://www.w3.org/2000/01/rdf-schema#>
+
+            # Alice and Bob
+            <alice> a foaf:Person ;
+                foaf:name "Alice", "Alicia"@es ;
+                foaf:knows [ foaf:name "Bob" ] .
+            <http://xmlns.com/foaf/0.1/Person> rdfs:label "Person"@en .
+            @prefix foaf: <https://example.com/not-foaf#> .
+            <alice> foaf:age 42 .
+        "#})?;
+        assert_eq!(prologue.prefixes.len(), 3);
+        assert_eq!(
+            prologue.base.as_deref(),
+            Some("https://example.com/people/")
+        );
+        assert_eq!(triples.len(), 7);
+        assert_eq!(
+            triples[0].subject,
+            TermPattern::Iri(SparqlIri::Iri(
+                "https://example.com/people/alice".to_string()
+            ))
+        );
+        assert_eq!(
+            triples[2].object,
+            TermPattern::Literal(SparqlLiteral {
+                lexical_form: "Alicia".to_string(),
+                language:     Some("es".to_string()),
+                data_type:    None,
+            })
+        );
+        assert_eq!(
+            triples[6].predicate,
+            Verb::Path(PropertyPath::Iri(SparqlIri::Iri(
+                "https://example.com/not-foaf#age".to_string()
+            )))
+        );
+
+        for turtle in [
+            "<a> <b> <c>",
+            "<a> ex:b <c> .",
+            "<a> ?b <c> .",
+            "<a> <b>/<c> <d> .",
+        ] {
+            assert!(
+                matches!(
+                    parse_turtle(turtle),
+                    Err(RDFStoreError::TurtleParseError { .. })
+                ),
+                "should not parse: {turtle}"
+            );
+        }
+        Ok(())
+    }
+}
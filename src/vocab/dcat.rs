@@ -0,0 +1,491 @@
+// Copyright (c) 2018-2023, agnos.ai UK Ltd, all rights reserved.
+//---------------------------------------------------------------
+//! The DCAT 3 vocabulary, `dcat:` <http://www.w3.org/ns/dcat#>.
+
+// Generated by rdf_store_rs::Vocabulary, do not edit.
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// The `dcat:` namespace `<http://www.w3.org/ns/dcat#>`.
+    pub static ref NAMESPACE: ::std::sync::Arc<::rdf_store_rs::Namespace> =
+        ::std::sync::Arc::new(::rdf_store_rs::Namespace::declare_from_str_unchecked("dcat:", "http://www.w3.org/ns/dcat#"));
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// data access service
+    ///
+    /// A site or end-point that gives access to the distribution of the dataset.
+    ///
+    /// `dcat:accessService` (`ACCESS_SERVICE`)
+    pub static ref ACCESS_SERVICE: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "accessService");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// access address
+    ///
+    /// A URL of a resource that gives access to a distribution of the dataset. E.g. landing page, feed, SPARQL endpoint.
+    ///
+    /// `dcat:accessURL` (`ACCESS_URL`)
+    pub static ref ACCESS_URL: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "accessURL");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// bounding box
+    ///
+    /// The geographic bounding box of a resource.
+    ///
+    /// `dcat:bbox` (`BBOX`)
+    pub static ref BBOX: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "bbox");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// byte size
+    ///
+    /// The size of a distribution in bytes.
+    ///
+    /// `dcat:byteSize` (`BYTE_SIZE`)
+    pub static ref BYTE_SIZE: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "byteSize");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// catalog
+    ///
+    /// A catalog whose contents are of interest in the context of this catalog.
+    ///
+    /// `dcat:catalog` (`CATALOG_PROPERTY`)
+    pub static ref CATALOG_PROPERTY: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "catalog");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// centroid
+    ///
+    /// The geographic center (centroid) of a resource.
+    ///
+    /// `dcat:centroid` (`CENTROID`)
+    pub static ref CENTROID: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "centroid");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// compression format
+    ///
+    /// The compression format of the distribution in which the data is contained in a compressed form, e.g. to reduce the size of the downloadable file.
+    ///
+    /// `dcat:compressFormat` (`COMPRESS_FORMAT`)
+    pub static ref COMPRESS_FORMAT: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "compressFormat");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// contact point
+    ///
+    /// Relevant contact information for the catalogued resource. Use of vCard is recommended.
+    ///
+    /// `dcat:contactPoint` (`CONTACT_POINT`)
+    pub static ref CONTACT_POINT: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "contactPoint");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// dataset
+    ///
+    /// A collection of data that is listed in the catalog.
+    ///
+    /// `dcat:dataset` (`DATASET_PROPERTY`)
+    pub static ref DATASET_PROPERTY: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "dataset");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// distribution
+    ///
+    /// An available distribution of the dataset.
+    ///
+    /// `dcat:distribution` (`DISTRIBUTION_PROPERTY`)
+    pub static ref DISTRIBUTION_PROPERTY: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "distribution");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// download URL
+    ///
+    /// The URL of the downloadable file in a given format. E.g. CSV file or RDF file. The format is indicated by the distribution's dcterms:format and/or dcat:mediaType.
+    ///
+    /// `dcat:downloadURL` (`DOWNLOAD_URL`)
+    pub static ref DOWNLOAD_URL: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "downloadURL");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// end date
+    ///
+    /// The end of the period.
+    ///
+    /// `dcat:endDate` (`END_DATE`)
+    pub static ref END_DATE: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "endDate");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// description of service end-point
+    ///
+    /// A description of the service end-point, including its operations, parameters etc.
+    ///
+    /// `dcat:endpointDescription` (`ENDPOINT_DESCRIPTION`)
+    pub static ref ENDPOINT_DESCRIPTION: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "endpointDescription");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// service end-point
+    ///
+    /// The root location or primary endpoint of the service (a Web-resolvable IRI).
+    ///
+    /// `dcat:endpointURL` (`ENDPOINT_URL`)
+    pub static ref ENDPOINT_URL: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "endpointURL");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// first
+    ///
+    /// The first resource in an ordered collection or series of resources, to which the current resource belongs.
+    ///
+    /// `dcat:first` (`FIRST`)
+    pub static ref FIRST: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "first");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// hadRole
+    ///
+    /// The function of an entity or agent with respect to another entity or resource.
+    ///
+    /// `dcat:hadRole` (`HAD_ROLE`)
+    pub static ref HAD_ROLE: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "hadRole");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// current version
+    ///
+    /// This resource has a more specific, versioned resource with equivalent content.
+    ///
+    /// `dcat:hasCurrentVersion` (`HAS_CURRENT_VERSION`)
+    pub static ref HAS_CURRENT_VERSION: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "hasCurrentVersion");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// has version
+    ///
+    /// This resource has a more specific, versioned resource.
+    ///
+    /// `dcat:hasVersion` (`HAS_VERSION`)
+    pub static ref HAS_VERSION: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "hasVersion");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// in series
+    ///
+    /// A dataset series of which the dataset is part.
+    ///
+    /// `dcat:inSeries` (`IN_SERIES`)
+    pub static ref IN_SERIES: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "inSeries");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// is version of
+    ///
+    /// The previous version of a resource in a lineage.
+    ///
+    /// `dcat:isVersionOf` (`IS_VERSION_OF`)
+    pub static ref IS_VERSION_OF: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "isVersionOf");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// keyword
+    ///
+    /// A keyword or tag describing a resource.
+    ///
+    /// `dcat:keyword` (`KEYWORD`)
+    pub static ref KEYWORD: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "keyword");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// landing page
+    ///
+    /// A Web page that can be navigated to in a Web browser to gain access to the catalog, a dataset, its distributions and/or additional information.
+    ///
+    /// `dcat:landingPage` (`LANDING_PAGE`)
+    pub static ref LANDING_PAGE: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "landingPage");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// last
+    ///
+    /// The last resource in an ordered collection or series of resources, to which the current resource belongs.
+    ///
+    /// `dcat:last` (`LAST`)
+    pub static ref LAST: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "last");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// media type
+    ///
+    /// The media type of the distribution as defined by IANA.
+    ///
+    /// `dcat:mediaType` (`MEDIA_TYPE`)
+    pub static ref MEDIA_TYPE: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "mediaType");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// packaging format
+    ///
+    /// The package format of the distribution in which one or more data files are grouped together, e.g. to enable a set of related files to be downloaded together.
+    ///
+    /// `dcat:packageFormat` (`PACKAGE_FORMAT`)
+    pub static ref PACKAGE_FORMAT: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "packageFormat");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// previous
+    ///
+    /// The previous resource (before the current one) in an ordered collection or series of resources.
+    ///
+    /// `dcat:prev` (`PREV`)
+    pub static ref PREV: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "prev");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// previous version
+    ///
+    /// The previous version of a resource in a lineage.
+    ///
+    /// `dcat:previousVersion` (`PREVIOUS_VERSION`)
+    pub static ref PREVIOUS_VERSION: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "previousVersion");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// qualified relation
+    ///
+    /// Link to a description of a relationship with another resource.
+    ///
+    /// `dcat:qualifiedRelation` (`QUALIFIED_RELATION`)
+    pub static ref QUALIFIED_RELATION: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "qualifiedRelation");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// record
+    ///
+    /// A record describing the registration of a single dataset or data service that is part of the catalog.
+    ///
+    /// `dcat:record` (`RECORD`)
+    pub static ref RECORD: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "record");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// resource
+    ///
+    /// A resource that is listed in the catalog.
+    ///
+    /// `dcat:resource` (`RESOURCE_PROPERTY`)
+    pub static ref RESOURCE_PROPERTY: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "resource");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// series member
+    ///
+    /// A member of a dataset series.
+    ///
+    /// `dcat:seriesMember` (`SERIES_MEMBER`)
+    pub static ref SERIES_MEMBER: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "seriesMember");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// serves dataset
+    ///
+    /// A collection of data that this data service can distribute.
+    ///
+    /// `dcat:servesDataset` (`SERVES_DATASET`)
+    pub static ref SERVES_DATASET: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "servesDataset");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// service
+    ///
+    /// A site or endpoint that is listed in the catalog.
+    ///
+    /// `dcat:service` (`SERVICE`)
+    pub static ref SERVICE: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "service");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// spatial resolution (metres)
+    ///
+    /// Minimum spatial separation resolvable in a dataset, measured in meters.
+    ///
+    /// `dcat:spatialResolutionInMeters` (`SPATIAL_RESOLUTION_IN_METERS`)
+    pub static ref SPATIAL_RESOLUTION_IN_METERS: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "spatialResolutionInMeters");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// start date
+    ///
+    /// The start of the period.
+    ///
+    /// `dcat:startDate` (`START_DATE`)
+    pub static ref START_DATE: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "startDate");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// temporal resolution
+    ///
+    /// Minimum time period resolvable in the dataset.
+    ///
+    /// `dcat:temporalResolution` (`TEMPORAL_RESOLUTION`)
+    pub static ref TEMPORAL_RESOLUTION: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "temporalResolution");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// theme
+    ///
+    /// A main category of the resource. A resource can have multiple themes.
+    ///
+    /// `dcat:theme` (`THEME`)
+    pub static ref THEME: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "theme");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// themes
+    ///
+    /// The knowledge organization system (KOS) used to classify catalog's datasets.
+    ///
+    /// `dcat:themeTaxonomy` (`THEME_TAXONOMY`)
+    pub static ref THEME_TAXONOMY: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "themeTaxonomy");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// version
+    ///
+    /// The version indicator (name or identifier) of a resource.
+    ///
+    /// `dcat:version` (`VERSION`)
+    pub static ref VERSION: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "version");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// Catalog
+    ///
+    /// A curated collection of metadata about resources (e.g., datasets and data services in the context of a data catalog).
+    ///
+    /// `dcat:Catalog` (`CATALOG_CLASS`)
+    pub static ref CATALOG_CLASS: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "Catalog");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// Catalog Record
+    ///
+    /// A record in a data catalog, describing the registration of a single dataset or data service.
+    ///
+    /// `dcat:CatalogRecord` (`CATALOG_RECORD`)
+    pub static ref CATALOG_RECORD: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "CatalogRecord");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// Data service
+    ///
+    /// A collection of operations that provides access to one or more datasets or data processing functions.
+    ///
+    /// `dcat:DataService` (`DATA_SERVICE`)
+    pub static ref DATA_SERVICE: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "DataService");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// Dataset
+    ///
+    /// A collection of data, published or curated by a single source, and available for access or download in one or more representations.
+    ///
+    /// `dcat:Dataset` (`DATASET_CLASS`)
+    pub static ref DATASET_CLASS: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "Dataset");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// Dataset series
+    ///
+    /// A collection of datasets that are published separately, but share some characteristics that group them.
+    ///
+    /// `dcat:DatasetSeries` (`DATASET_SERIES`)
+    pub static ref DATASET_SERIES: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "DatasetSeries");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// Distribution
+    ///
+    /// A specific representation of a dataset. A dataset might be available in multiple serializations that may differ in various ways, including natural language, media-type or format, schematic organization, temporal and spatial resolution, level of detail or profiles (which might specify any or all of the above).
+    ///
+    /// `dcat:Distribution` (`DISTRIBUTION_CLASS`)
+    pub static ref DISTRIBUTION_CLASS: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "Distribution");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// Relationship
+    ///
+    /// An association class for attaching additional information to a relationship between DCAT Resources.
+    ///
+    /// `dcat:Relationship` (`RELATIONSHIP`)
+    pub static ref RELATIONSHIP: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "Relationship");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// Catalogued resource
+    ///
+    /// Resource published or curated by a single agent.
+    ///
+    /// `dcat:Resource` (`RESOURCE_CLASS`)
+    pub static ref RESOURCE_CLASS: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "Resource");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// Role
+    ///
+    /// A role is the function of a resource or agent with respect to another resource, in the context of resource attribution or resource relationships.
+    ///
+    /// `dcat:Role` (`ROLE`)
+    pub static ref ROLE: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "Role");
+}
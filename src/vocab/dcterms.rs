@@ -0,0 +1,901 @@
+// Copyright (c) 2018-2023, agnos.ai UK Ltd, all rights reserved.
+//---------------------------------------------------------------
+//! The DCMI Metadata Terms, `dcterms:` <http://purl.org/dc/terms/>.
+
+// Generated by rdf_store_rs::Vocabulary, do not edit.
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// The `dcterms:` namespace `<http://purl.org/dc/terms/>`.
+    pub static ref NAMESPACE: ::std::sync::Arc<::rdf_store_rs::Namespace> =
+        ::std::sync::Arc::new(::rdf_store_rs::Namespace::declare_from_str_unchecked("dcterms:", "http://purl.org/dc/terms/"));
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// Abstract
+    ///
+    /// A summary of the resource.
+    ///
+    /// `dcterms:abstract` (`ABSTRACT`)
+    pub static ref ABSTRACT: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "abstract");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// Access Rights
+    ///
+    /// Information about who access the resource or an indication of its security status.
+    ///
+    /// `dcterms:accessRights` (`ACCESS_RIGHTS`)
+    pub static ref ACCESS_RIGHTS: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "accessRights");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// Accrual Method
+    ///
+    /// The method by which items are added to a collection.
+    ///
+    /// `dcterms:accrualMethod` (`ACCRUAL_METHOD`)
+    pub static ref ACCRUAL_METHOD: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "accrualMethod");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// Accrual Periodicity
+    ///
+    /// The frequency with which items are added to a collection.
+    ///
+    /// `dcterms:accrualPeriodicity` (`ACCRUAL_PERIODICITY`)
+    pub static ref ACCRUAL_PERIODICITY: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "accrualPeriodicity");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// Accrual Policy
+    ///
+    /// The policy governing the addition of items to a collection.
+    ///
+    /// `dcterms:accrualPolicy` (`ACCRUAL_POLICY`)
+    pub static ref ACCRUAL_POLICY: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "accrualPolicy");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// Alternative Title
+    ///
+    /// An alternative name for the resource.
+    ///
+    /// `dcterms:alternative` (`ALTERNATIVE`)
+    pub static ref ALTERNATIVE: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "alternative");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// Audience
+    ///
+    /// A class of agents for whom the resource is intended or useful.
+    ///
+    /// `dcterms:audience` (`AUDIENCE`)
+    pub static ref AUDIENCE: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "audience");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// Date Available
+    ///
+    /// Date that the resource became or will become available.
+    ///
+    /// `dcterms:available` (`AVAILABLE`)
+    pub static ref AVAILABLE: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "available");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// Bibliographic Citation
+    ///
+    /// A bibliographic reference for the resource.
+    ///
+    /// `dcterms:bibliographicCitation` (`BIBLIOGRAPHIC_CITATION`)
+    pub static ref BIBLIOGRAPHIC_CITATION: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "bibliographicCitation");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// Conforms To
+    ///
+    /// An established standard to which the described resource conforms.
+    ///
+    /// `dcterms:conformsTo` (`CONFORMS_TO`)
+    pub static ref CONFORMS_TO: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "conformsTo");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// Contributor
+    ///
+    /// An entity responsible for making contributions to the resource.
+    ///
+    /// `dcterms:contributor` (`CONTRIBUTOR`)
+    pub static ref CONTRIBUTOR: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "contributor");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// Coverage
+    ///
+    /// The spatial or temporal topic of the resource, spatial applicability of the resource, or jurisdiction under which the resource is relevant.
+    ///
+    /// `dcterms:coverage` (`COVERAGE`)
+    pub static ref COVERAGE: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "coverage");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// Date Created
+    ///
+    /// Date of creation of the resource.
+    ///
+    /// `dcterms:created` (`CREATED`)
+    pub static ref CREATED: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "created");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// Creator
+    ///
+    /// An entity responsible for making the resource.
+    ///
+    /// `dcterms:creator` (`CREATOR`)
+    pub static ref CREATOR: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "creator");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// Date
+    ///
+    /// A point or period of time associated with an event in the lifecycle of the resource.
+    ///
+    /// `dcterms:date` (`DATE`)
+    pub static ref DATE: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "date");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// Date Accepted
+    ///
+    /// Date of acceptance of the resource.
+    ///
+    /// `dcterms:dateAccepted` (`DATE_ACCEPTED`)
+    pub static ref DATE_ACCEPTED: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "dateAccepted");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// Date Copyrighted
+    ///
+    /// Date of copyright of the resource.
+    ///
+    /// `dcterms:dateCopyrighted` (`DATE_COPYRIGHTED`)
+    pub static ref DATE_COPYRIGHTED: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "dateCopyrighted");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// Date Submitted
+    ///
+    /// Date of submission of the resource.
+    ///
+    /// `dcterms:dateSubmitted` (`DATE_SUBMITTED`)
+    pub static ref DATE_SUBMITTED: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "dateSubmitted");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// Description
+    ///
+    /// An account of the resource.
+    ///
+    /// `dcterms:description` (`DESCRIPTION`)
+    pub static ref DESCRIPTION: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "description");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// Audience Education Level
+    ///
+    /// A class of agents, defined in terms of progression through an educational or training context, for which the described resource is intended.
+    ///
+    /// `dcterms:educationLevel` (`EDUCATION_LEVEL`)
+    pub static ref EDUCATION_LEVEL: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "educationLevel");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// Extent
+    ///
+    /// The size or duration of the resource.
+    ///
+    /// `dcterms:extent` (`EXTENT`)
+    pub static ref EXTENT: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "extent");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// Format
+    ///
+    /// The file format, physical medium, or dimensions of the resource.
+    ///
+    /// `dcterms:format` (`FORMAT`)
+    pub static ref FORMAT: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "format");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// Has Format
+    ///
+    /// A related resource that is substantially the same as the pre-existing described resource, but in another format.
+    ///
+    /// `dcterms:hasFormat` (`HAS_FORMAT`)
+    pub static ref HAS_FORMAT: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "hasFormat");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// Has Part
+    ///
+    /// A related resource that is included either physically or logically in the described resource.
+    ///
+    /// `dcterms:hasPart` (`HAS_PART`)
+    pub static ref HAS_PART: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "hasPart");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// Has Version
+    ///
+    /// A related resource that is a version, edition, or adaptation of the described resource.
+    ///
+    /// `dcterms:hasVersion` (`HAS_VERSION`)
+    pub static ref HAS_VERSION: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "hasVersion");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// Identifier
+    ///
+    /// An unambiguous reference to the resource within a given context.
+    ///
+    /// `dcterms:identifier` (`IDENTIFIER`)
+    pub static ref IDENTIFIER: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "identifier");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// Instructional Method
+    ///
+    /// A process, used to engender knowledge, attitudes and skills, that the described resource is designed to support.
+    ///
+    /// `dcterms:instructionalMethod` (`INSTRUCTIONAL_METHOD`)
+    pub static ref INSTRUCTIONAL_METHOD: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "instructionalMethod");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// Is Format Of
+    ///
+    /// A pre-existing related resource that is substantially the same as the described resource, but in another format.
+    ///
+    /// `dcterms:isFormatOf` (`IS_FORMAT_OF`)
+    pub static ref IS_FORMAT_OF: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "isFormatOf");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// Is Part Of
+    ///
+    /// A related resource in which the described resource is physically or logically included.
+    ///
+    /// `dcterms:isPartOf` (`IS_PART_OF`)
+    pub static ref IS_PART_OF: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "isPartOf");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// Is Referenced By
+    ///
+    /// A related resource that references, cites, or otherwise points to the described resource.
+    ///
+    /// `dcterms:isReferencedBy` (`IS_REFERENCED_BY`)
+    pub static ref IS_REFERENCED_BY: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "isReferencedBy");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// Is Replaced By
+    ///
+    /// A related resource that supplants, displaces, or supersedes the described resource.
+    ///
+    /// `dcterms:isReplacedBy` (`IS_REPLACED_BY`)
+    pub static ref IS_REPLACED_BY: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "isReplacedBy");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// Is Required By
+    ///
+    /// A related resource that requires the described resource to support its function, delivery, or coherence.
+    ///
+    /// `dcterms:isRequiredBy` (`IS_REQUIRED_BY`)
+    pub static ref IS_REQUIRED_BY: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "isRequiredBy");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// Is Version Of
+    ///
+    /// A related resource of which the described resource is a version, edition, or adaptation.
+    ///
+    /// `dcterms:isVersionOf` (`IS_VERSION_OF`)
+    pub static ref IS_VERSION_OF: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "isVersionOf");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// Date Issued
+    ///
+    /// Date of formal issuance of the resource.
+    ///
+    /// `dcterms:issued` (`ISSUED`)
+    pub static ref ISSUED: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "issued");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// Language
+    ///
+    /// A language of the resource.
+    ///
+    /// `dcterms:language` (`LANGUAGE`)
+    pub static ref LANGUAGE: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "language");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// License
+    ///
+    /// A legal document giving official permission to do something with the resource.
+    ///
+    /// `dcterms:license` (`LICENSE`)
+    pub static ref LICENSE: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "license");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// Mediator
+    ///
+    /// An entity that mediates access to the resource.
+    ///
+    /// `dcterms:mediator` (`MEDIATOR`)
+    pub static ref MEDIATOR: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "mediator");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// Medium
+    ///
+    /// The material or physical carrier of the resource.
+    ///
+    /// `dcterms:medium` (`MEDIUM`)
+    pub static ref MEDIUM: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "medium");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// Date Modified
+    ///
+    /// Date on which the resource was changed.
+    ///
+    /// `dcterms:modified` (`MODIFIED`)
+    pub static ref MODIFIED: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "modified");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// Provenance
+    ///
+    /// A statement of any changes in ownership and custody of the resource since its creation that are significant for its authenticity, integrity, and interpretation.
+    ///
+    /// `dcterms:provenance` (`PROVENANCE`)
+    pub static ref PROVENANCE: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "provenance");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// Publisher
+    ///
+    /// An entity responsible for making the resource available.
+    ///
+    /// `dcterms:publisher` (`PUBLISHER`)
+    pub static ref PUBLISHER: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "publisher");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// References
+    ///
+    /// A related resource that is referenced, cited, or otherwise pointed to by the described resource.
+    ///
+    /// `dcterms:references` (`REFERENCES`)
+    pub static ref REFERENCES: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "references");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// Relation
+    ///
+    /// A related resource.
+    ///
+    /// `dcterms:relation` (`RELATION`)
+    pub static ref RELATION: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "relation");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// Replaces
+    ///
+    /// A related resource that is supplanted, displaced, or superseded by the described resource.
+    ///
+    /// `dcterms:replaces` (`REPLACES`)
+    pub static ref REPLACES: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "replaces");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// Requires
+    ///
+    /// A related resource that is required by the described resource to support its function, delivery, or coherence.
+    ///
+    /// `dcterms:requires` (`REQUIRES`)
+    pub static ref REQUIRES: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "requires");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// Rights
+    ///
+    /// Information about rights held in and over the resource.
+    ///
+    /// `dcterms:rights` (`RIGHTS`)
+    pub static ref RIGHTS: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "rights");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// Rights Holder
+    ///
+    /// A person or organization owning or managing rights over the resource.
+    ///
+    /// `dcterms:rightsHolder` (`RIGHTS_HOLDER`)
+    pub static ref RIGHTS_HOLDER: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "rightsHolder");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// Source
+    ///
+    /// A related resource from which the described resource is derived.
+    ///
+    /// `dcterms:source` (`SOURCE`)
+    pub static ref SOURCE: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "source");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// Spatial Coverage
+    ///
+    /// Spatial characteristics of the resource.
+    ///
+    /// `dcterms:spatial` (`SPATIAL`)
+    pub static ref SPATIAL: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "spatial");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// Subject
+    ///
+    /// A topic of the resource.
+    ///
+    /// `dcterms:subject` (`SUBJECT`)
+    pub static ref SUBJECT: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "subject");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// Table Of Contents
+    ///
+    /// A list of subunits of the resource.
+    ///
+    /// `dcterms:tableOfContents` (`TABLE_OF_CONTENTS`)
+    pub static ref TABLE_OF_CONTENTS: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "tableOfContents");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// Temporal Coverage
+    ///
+    /// Temporal characteristics of the resource.
+    ///
+    /// `dcterms:temporal` (`TEMPORAL`)
+    pub static ref TEMPORAL: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "temporal");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// Title
+    ///
+    /// A name given to the resource.
+    ///
+    /// `dcterms:title` (`TITLE`)
+    pub static ref TITLE: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "title");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// Type
+    ///
+    /// The nature or genre of the resource.
+    ///
+    /// `dcterms:type` (`TYPE`)
+    pub static ref TYPE: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "type");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// Date Valid
+    ///
+    /// Date (often a range) of validity of a resource.
+    ///
+    /// `dcterms:valid` (`VALID`)
+    pub static ref VALID: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "valid");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// Agent
+    ///
+    /// A resource that acts or has the power to act.
+    ///
+    /// `dcterms:Agent` (`AGENT`)
+    pub static ref AGENT: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "Agent");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// Agent Class
+    ///
+    /// A group of agents.
+    ///
+    /// `dcterms:AgentClass` (`AGENT_CLASS`)
+    pub static ref AGENT_CLASS: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "AgentClass");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// Bibliographic Resource
+    ///
+    /// A book, article, or other documentary resource.
+    ///
+    /// `dcterms:BibliographicResource` (`BIBLIOGRAPHIC_RESOURCE`)
+    pub static ref BIBLIOGRAPHIC_RESOURCE: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "BibliographicResource");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// DCMI Box
+    ///
+    /// The set of regions in space defined by their geographic coordinates according to the DCMI Box Encoding Scheme.
+    ///
+    /// `dcterms:Box` (`BOX`)
+    pub static ref BOX: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "Box");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// File Format
+    ///
+    /// A digital resource format.
+    ///
+    /// `dcterms:FileFormat` (`FILE_FORMAT`)
+    pub static ref FILE_FORMAT: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "FileFormat");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// Frequency
+    ///
+    /// A rate at which something recurs.
+    ///
+    /// `dcterms:Frequency` (`FREQUENCY`)
+    pub static ref FREQUENCY: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "Frequency");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// ISO 3166
+    ///
+    /// The set of codes listed in ISO 3166-1 for the representation of names of countries.
+    ///
+    /// `dcterms:ISO3166` (`ISO3166`)
+    pub static ref ISO3166: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "ISO3166");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// ISO 639-2
+    ///
+    /// The three-letter alphabetic codes listed in ISO639-2 for the representation of names of languages.
+    ///
+    /// `dcterms:ISO639-2` (`ISO639_2`)
+    pub static ref ISO639_2: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "ISO639-2");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// ISO 639-3
+    ///
+    /// The set of three-letter codes listed in ISO 639-3 for the representation of names of languages.
+    ///
+    /// `dcterms:ISO639-3` (`ISO639_3`)
+    pub static ref ISO639_3: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "ISO639-3");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// Jurisdiction
+    ///
+    /// The extent or range of judicial, law enforcement, or other authority.
+    ///
+    /// `dcterms:Jurisdiction` (`JURISDICTION`)
+    pub static ref JURISDICTION: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "Jurisdiction");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// License Document
+    ///
+    /// A legal document giving official permission to do something with a resource.
+    ///
+    /// `dcterms:LicenseDocument` (`LICENSE_DOCUMENT`)
+    pub static ref LICENSE_DOCUMENT: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "LicenseDocument");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// Linguistic System
+    ///
+    /// A system of signs, symbols, sounds, gestures, or rules used in communication.
+    ///
+    /// `dcterms:LinguisticSystem` (`LINGUISTIC_SYSTEM`)
+    pub static ref LINGUISTIC_SYSTEM: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "LinguisticSystem");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// Location
+    ///
+    /// A spatial region or named place.
+    ///
+    /// `dcterms:Location` (`LOCATION`)
+    pub static ref LOCATION: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "Location");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// Location, Period, or Jurisdiction
+    ///
+    /// A location, period of time, or jurisdiction.
+    ///
+    /// `dcterms:LocationPeriodOrJurisdiction` (`LOCATION_PERIOD_OR_JURISDICTION`)
+    pub static ref LOCATION_PERIOD_OR_JURISDICTION: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "LocationPeriodOrJurisdiction");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// Media Type
+    ///
+    /// A file format or physical medium.
+    ///
+    /// `dcterms:MediaType` (`MEDIA_TYPE`)
+    pub static ref MEDIA_TYPE: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "MediaType");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// Media Type or Extent
+    ///
+    /// A media type or extent.
+    ///
+    /// `dcterms:MediaTypeOrExtent` (`MEDIA_TYPE_OR_EXTENT`)
+    pub static ref MEDIA_TYPE_OR_EXTENT: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "MediaTypeOrExtent");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// Method of Accrual
+    ///
+    /// A method by which resources are added to a collection.
+    ///
+    /// `dcterms:MethodOfAccrual` (`METHOD_OF_ACCRUAL`)
+    pub static ref METHOD_OF_ACCRUAL: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "MethodOfAccrual");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// Method of Instruction
+    ///
+    /// A process that is used to engender knowledge, attitudes, and skills.
+    ///
+    /// `dcterms:MethodOfInstruction` (`METHOD_OF_INSTRUCTION`)
+    pub static ref METHOD_OF_INSTRUCTION: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "MethodOfInstruction");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// DCMI Period
+    ///
+    /// The set of time intervals defined by their limits according to the DCMI Period Encoding Scheme.
+    ///
+    /// `dcterms:Period` (`PERIOD`)
+    pub static ref PERIOD: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "Period");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// Period of Time
+    ///
+    /// An interval of time that is named or defined by its start and end dates.
+    ///
+    /// `dcterms:PeriodOfTime` (`PERIOD_OF_TIME`)
+    pub static ref PERIOD_OF_TIME: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "PeriodOfTime");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// Physical Medium
+    ///
+    /// A physical material or carrier.
+    ///
+    /// `dcterms:PhysicalMedium` (`PHYSICAL_MEDIUM`)
+    pub static ref PHYSICAL_MEDIUM: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "PhysicalMedium");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// Physical Resource
+    ///
+    /// A material thing.
+    ///
+    /// `dcterms:PhysicalResource` (`PHYSICAL_RESOURCE`)
+    pub static ref PHYSICAL_RESOURCE: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "PhysicalResource");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// DCMI Point
+    ///
+    /// The set of points in space defined by their geographic coordinates according to the DCMI Point Encoding Scheme.
+    ///
+    /// `dcterms:Point` (`POINT`)
+    pub static ref POINT: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "Point");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// Policy
+    ///
+    /// A plan or course of action by an authority, intended to influence and determine decisions, actions, and other matters.
+    ///
+    /// `dcterms:Policy` (`POLICY`)
+    pub static ref POLICY: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "Policy");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// Provenance Statement
+    ///
+    /// Any changes in ownership and custody of a resource since its creation that are significant for its authenticity, integrity, and interpretation.
+    ///
+    /// `dcterms:ProvenanceStatement` (`PROVENANCE_STATEMENT`)
+    pub static ref PROVENANCE_STATEMENT: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "ProvenanceStatement");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// RFC 1766
+    ///
+    /// The set of tags, constructed according to RFC 1766, for the identification of languages.
+    ///
+    /// `dcterms:RFC1766` (`RFC1766`)
+    pub static ref RFC1766: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "RFC1766");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// RFC 3066
+    ///
+    /// The set of tags constructed according to RFC 3066 for the identification of languages.
+    ///
+    /// `dcterms:RFC3066` (`RFC3066`)
+    pub static ref RFC3066: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "RFC3066");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// RFC 4646
+    ///
+    /// The set of tags constructed according to RFC 4646 for the identification of languages.
+    ///
+    /// `dcterms:RFC4646` (`RFC4646`)
+    pub static ref RFC4646: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "RFC4646");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// RFC 5646
+    ///
+    /// The set of tags constructed according to RFC 5646 for the identification of languages.
+    ///
+    /// `dcterms:RFC5646` (`RFC5646`)
+    pub static ref RFC5646: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "RFC5646");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// Rights Statement
+    ///
+    /// A statement about the intellectual property rights (IPR) held in or over a resource, a legal document giving official permission to do something with a resource, or a statement about access rights.
+    ///
+    /// `dcterms:RightsStatement` (`RIGHTS_STATEMENT`)
+    pub static ref RIGHTS_STATEMENT: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "RightsStatement");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// Size or Duration
+    ///
+    /// A dimension or extent, or a time taken to play or execute.
+    ///
+    /// `dcterms:SizeOrDuration` (`SIZE_OR_DURATION`)
+    pub static ref SIZE_OR_DURATION: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "SizeOrDuration");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// Standard
+    ///
+    /// A reference point against which other things can be evaluated or compared.
+    ///
+    /// `dcterms:Standard` (`STANDARD`)
+    pub static ref STANDARD: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "Standard");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// URI
+    ///
+    /// The set of identifiers constructed according to the generic syntax for Uniform Resource Identifiers as specified by the Internet Engineering Task Force.
+    ///
+    /// `dcterms:URI` (`URI`)
+    pub static ref URI: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "URI");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// W3C-DTF
+    ///
+    /// The set of dates and times constructed according to the W3C Date and Time Formats Specification.
+    ///
+    /// `dcterms:W3CDTF` (`W3_CDTF`)
+    pub static ref W3_CDTF: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "W3CDTF");
+}
@@ -0,0 +1,761 @@
+// Copyright (c) 2018-2023, agnos.ai UK Ltd, all rights reserved.
+//---------------------------------------------------------------
+//! The FOAF vocabulary, `foaf:` <http://xmlns.com/foaf/0.1/>.
+
+// Generated by rdf_store_rs::Vocabulary, do not edit.
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// The `foaf:` namespace `<http://xmlns.com/foaf/0.1/>`.
+    pub static ref NAMESPACE: ::std::sync::Arc<::rdf_store_rs::Namespace> =
+        ::std::sync::Arc::new(::rdf_store_rs::Namespace::declare_from_str_unchecked("foaf:", "http://xmlns.com/foaf/0.1/"));
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// account
+    ///
+    /// Indicates an account held by this agent.
+    ///
+    /// `foaf:account` (`ACCOUNT`)
+    pub static ref ACCOUNT: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "account");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// account name
+    ///
+    /// Indicates the name (identifier) associated with this online account.
+    ///
+    /// `foaf:accountName` (`ACCOUNT_NAME`)
+    pub static ref ACCOUNT_NAME: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "accountName");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// account service homepage
+    ///
+    /// Indicates a homepage of the service provide for this online account.
+    ///
+    /// `foaf:accountServiceHomepage` (`ACCOUNT_SERVICE_HOMEPAGE`)
+    pub static ref ACCOUNT_SERVICE_HOMEPAGE: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "accountServiceHomepage");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// age
+    ///
+    /// The age in years of some agent.
+    ///
+    /// `foaf:age` (`AGE`)
+    pub static ref AGE: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "age");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// AIM chat ID
+    ///
+    /// An AIM chat ID
+    ///
+    /// `foaf:aimChatID` (`AIM_CHAT_ID`)
+    pub static ref AIM_CHAT_ID: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "aimChatID");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// based near
+    ///
+    /// A location that something is based near, for some broadly human notion of near.
+    ///
+    /// `foaf:based_near` (`BASED_NEAR`)
+    pub static ref BASED_NEAR: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "based_near");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// birthday
+    ///
+    /// The birthday of this Agent, represented in mm-dd string form, eg. '12-31'.
+    ///
+    /// `foaf:birthday` (`BIRTHDAY`)
+    pub static ref BIRTHDAY: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "birthday");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// current project
+    ///
+    /// A current project this person works on.
+    ///
+    /// `foaf:currentProject` (`CURRENT_PROJECT`)
+    pub static ref CURRENT_PROJECT: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "currentProject");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// depiction
+    ///
+    /// A depiction of some thing.
+    ///
+    /// `foaf:depiction` (`DEPICTION`)
+    pub static ref DEPICTION: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "depiction");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// depicts
+    ///
+    /// A thing depicted in this representation.
+    ///
+    /// `foaf:depicts` (`DEPICTS`)
+    pub static ref DEPICTS: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "depicts");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// DNA checksum
+    ///
+    /// A checksum for the DNA of some thing. Joke.
+    ///
+    /// `foaf:dnaChecksum` (`DNA_CHECKSUM`)
+    pub static ref DNA_CHECKSUM: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "dnaChecksum");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// familyName
+    ///
+    /// The family name of some person.
+    ///
+    /// `foaf:familyName` (`FAMILY_NAME`)
+    pub static ref FAMILY_NAME: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "familyName");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// family_name
+    ///
+    /// The family name of some person.
+    ///
+    /// `foaf:family_name` (`FAMILY_NAME_2`)
+    pub static ref FAMILY_NAME_2: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "family_name");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// firstName
+    ///
+    /// The first name of a person.
+    ///
+    /// `foaf:firstName` (`FIRST_NAME`)
+    pub static ref FIRST_NAME: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "firstName");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// focus
+    ///
+    /// The underlying or 'focal' entity associated with some SKOS-described concept.
+    ///
+    /// `foaf:focus` (`FOCUS`)
+    pub static ref FOCUS: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "focus");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// funded by
+    ///
+    /// An organization funding a project or person.
+    ///
+    /// `foaf:fundedBy` (`FUNDED_BY`)
+    pub static ref FUNDED_BY: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "fundedBy");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// geekcode
+    ///
+    /// A textual geekcode for this person, see http://www.geekcode.com/geek.html
+    ///
+    /// `foaf:geekcode` (`GEEKCODE`)
+    pub static ref GEEKCODE: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "geekcode");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// gender
+    ///
+    /// The gender of this Agent (typically but not necessarily 'male' or 'female').
+    ///
+    /// `foaf:gender` (`GENDER`)
+    pub static ref GENDER: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "gender");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// Given name
+    ///
+    /// The given name of some person.
+    ///
+    /// `foaf:givenName` (`GIVEN_NAME`)
+    pub static ref GIVEN_NAME: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "givenName");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// Given name
+    ///
+    /// The given name of some person.
+    ///
+    /// `foaf:givenname` (`GIVENNAME`)
+    pub static ref GIVENNAME: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "givenname");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// account
+    ///
+    /// Indicates an account held by this agent.
+    ///
+    /// `foaf:holdsAccount` (`HOLDS_ACCOUNT`)
+    pub static ref HOLDS_ACCOUNT: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "holdsAccount");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// homepage
+    ///
+    /// A homepage for some thing.
+    ///
+    /// `foaf:homepage` (`HOMEPAGE`)
+    pub static ref HOMEPAGE: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "homepage");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// ICQ chat ID
+    ///
+    /// An ICQ chat ID
+    ///
+    /// `foaf:icqChatID` (`ICQ_CHAT_ID`)
+    pub static ref ICQ_CHAT_ID: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "icqChatID");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// image
+    ///
+    /// An image that can be used to represent some thing (ie. those depictions which are particularly representative of something, eg. one's photo on a homepage).
+    ///
+    /// `foaf:img` (`IMG`)
+    pub static ref IMG: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "img");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// interest
+    ///
+    /// A page about a topic of interest to this person.
+    ///
+    /// `foaf:interest` (`INTEREST`)
+    pub static ref INTEREST: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "interest");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// is primary topic of
+    ///
+    /// A document that this thing is the primary topic of.
+    ///
+    /// `foaf:isPrimaryTopicOf` (`IS_PRIMARY_TOPIC_OF`)
+    pub static ref IS_PRIMARY_TOPIC_OF: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "isPrimaryTopicOf");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// jabber ID
+    ///
+    /// A jabber ID for something.
+    ///
+    /// `foaf:jabberID` (`JABBER_ID`)
+    pub static ref JABBER_ID: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "jabberID");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// knows
+    ///
+    /// A person known by this person (indicating some level of reciprocated interaction between the parties).
+    ///
+    /// `foaf:knows` (`KNOWS`)
+    pub static ref KNOWS: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "knows");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// lastName
+    ///
+    /// The last name of a person.
+    ///
+    /// `foaf:lastName` (`LAST_NAME`)
+    pub static ref LAST_NAME: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "lastName");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// logo
+    ///
+    /// A logo representing some thing.
+    ///
+    /// `foaf:logo` (`LOGO`)
+    pub static ref LOGO: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "logo");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// made
+    ///
+    /// Something that was made by this agent.
+    ///
+    /// `foaf:made` (`MADE`)
+    pub static ref MADE: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "made");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// maker
+    ///
+    /// An agent that made this thing.
+    ///
+    /// `foaf:maker` (`MAKER`)
+    pub static ref MAKER: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "maker");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// personal mailbox
+    ///
+    /// A personal mailbox, ie. an Internet mailbox associated with exactly one owner, the first owner of this mailbox. This is a 'static inverse functional property', in that there is (across time and change) at most one individual that ever has any particular value for foaf:mbox.
+    ///
+    /// `foaf:mbox` (`MBOX`)
+    pub static ref MBOX: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "mbox");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// sha1sum of a personal mailbox URI name
+    ///
+    /// The sha1sum of the URI of an Internet mailbox associated with exactly one owner, the first owner of the mailbox.
+    ///
+    /// `foaf:mbox_sha1sum` (`MBOX_SHA1SUM`)
+    pub static ref MBOX_SHA1SUM: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "mbox_sha1sum");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// member
+    ///
+    /// Indicates a member of a Group
+    ///
+    /// `foaf:member` (`MEMBER`)
+    pub static ref MEMBER: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "member");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// membershipClass
+    ///
+    /// Indicates the class of individuals that are a member of a Group
+    ///
+    /// `foaf:membershipClass` (`MEMBERSHIP_CLASS`)
+    pub static ref MEMBERSHIP_CLASS: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "membershipClass");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// MSN chat ID
+    ///
+    /// An MSN chat ID
+    ///
+    /// `foaf:msnChatID` (`MSN_CHAT_ID`)
+    pub static ref MSN_CHAT_ID: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "msnChatID");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// myersBriggs
+    ///
+    /// A Myers Briggs (MBTI) personality classification.
+    ///
+    /// `foaf:myersBriggs` (`MYERS_BRIGGS`)
+    pub static ref MYERS_BRIGGS: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "myersBriggs");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// name
+    ///
+    /// A name for some thing.
+    ///
+    /// `foaf:name` (`NAME`)
+    pub static ref NAME: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "name");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// nickname
+    ///
+    /// A short informal nickname characterising an agent (includes login identifiers, IRC and other chat nicknames).
+    ///
+    /// `foaf:nick` (`NICK`)
+    pub static ref NICK: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "nick");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// openid
+    ///
+    /// An OpenID for an Agent.
+    ///
+    /// `foaf:openid` (`OPENID`)
+    pub static ref OPENID: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "openid");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// page
+    ///
+    /// A page or document about this thing.
+    ///
+    /// `foaf:page` (`PAGE`)
+    pub static ref PAGE: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "page");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// past project
+    ///
+    /// A project this person has previously worked on.
+    ///
+    /// `foaf:pastProject` (`PAST_PROJECT`)
+    pub static ref PAST_PROJECT: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "pastProject");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// phone
+    ///
+    /// A phone, specified using fully qualified tel: URI scheme (refs: http://www.w3.org/Addressing/schemes.html#tel).
+    ///
+    /// `foaf:phone` (`PHONE`)
+    pub static ref PHONE: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "phone");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// plan
+    ///
+    /// A .plan comment, in the tradition of finger and '.plan' files.
+    ///
+    /// `foaf:plan` (`PLAN`)
+    pub static ref PLAN: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "plan");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// primary topic
+    ///
+    /// The primary topic of some page or document.
+    ///
+    /// `foaf:primaryTopic` (`PRIMARY_TOPIC`)
+    pub static ref PRIMARY_TOPIC: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "primaryTopic");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// publications
+    ///
+    /// A link to the publications of this person.
+    ///
+    /// `foaf:publications` (`PUBLICATIONS`)
+    pub static ref PUBLICATIONS: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "publications");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// schoolHomepage
+    ///
+    /// A homepage of a school attended by the person.
+    ///
+    /// `foaf:schoolHomepage` (`SCHOOL_HOMEPAGE`)
+    pub static ref SCHOOL_HOMEPAGE: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "schoolHomepage");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// sha1sum (hex)
+    ///
+    /// A sha1sum hash, in hex.
+    ///
+    /// `foaf:sha1` (`SHA1`)
+    pub static ref SHA1: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "sha1");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// Skype ID
+    ///
+    /// A Skype ID
+    ///
+    /// `foaf:skypeID` (`SKYPE_ID`)
+    pub static ref SKYPE_ID: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "skypeID");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// status
+    ///
+    /// A string expressing what the user is happy for the general public (normally) to know about their current activity.
+    ///
+    /// `foaf:status` (`STATUS`)
+    pub static ref STATUS: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "status");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// Surname
+    ///
+    /// The surname of some person.
+    ///
+    /// `foaf:surname` (`SURNAME`)
+    pub static ref SURNAME: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "surname");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// theme
+    ///
+    /// A theme.
+    ///
+    /// `foaf:theme` (`THEME`)
+    pub static ref THEME: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "theme");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// thumbnail
+    ///
+    /// A derived thumbnail image.
+    ///
+    /// `foaf:thumbnail` (`THUMBNAIL`)
+    pub static ref THUMBNAIL: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "thumbnail");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// tipjar
+    ///
+    /// A tipjar document for this agent, describing means for payment and reward.
+    ///
+    /// `foaf:tipjar` (`TIPJAR`)
+    pub static ref TIPJAR: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "tipjar");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// title
+    ///
+    /// Title (Mr, Mrs, Ms, Dr. etc)
+    ///
+    /// `foaf:title` (`TITLE`)
+    pub static ref TITLE: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "title");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// topic
+    ///
+    /// A topic of some page or document.
+    ///
+    /// `foaf:topic` (`TOPIC`)
+    pub static ref TOPIC: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "topic");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// topic_interest
+    ///
+    /// A thing of interest to this person.
+    ///
+    /// `foaf:topic_interest` (`TOPIC_INTEREST`)
+    pub static ref TOPIC_INTEREST: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "topic_interest");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// weblog
+    ///
+    /// A weblog of some thing (whether person, group, company etc.).
+    ///
+    /// `foaf:weblog` (`WEBLOG`)
+    pub static ref WEBLOG: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "weblog");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// work info homepage
+    ///
+    /// A work info homepage of some person; a page about their work for some organization.
+    ///
+    /// `foaf:workInfoHomepage` (`WORK_INFO_HOMEPAGE`)
+    pub static ref WORK_INFO_HOMEPAGE: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "workInfoHomepage");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// workplace homepage
+    ///
+    /// A workplace homepage of some person; the homepage of an organization they work for.
+    ///
+    /// `foaf:workplaceHomepage` (`WORKPLACE_HOMEPAGE`)
+    pub static ref WORKPLACE_HOMEPAGE: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "workplaceHomepage");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// Yahoo chat ID
+    ///
+    /// A Yahoo chat ID
+    ///
+    /// `foaf:yahooChatID` (`YAHOO_CHAT_ID`)
+    pub static ref YAHOO_CHAT_ID: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "yahooChatID");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// Agent
+    ///
+    /// An agent (eg. person, group, software or physical artifact).
+    ///
+    /// `foaf:Agent` (`AGENT`)
+    pub static ref AGENT: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "Agent");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// Document
+    ///
+    /// A document.
+    ///
+    /// `foaf:Document` (`DOCUMENT`)
+    pub static ref DOCUMENT: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "Document");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// Group
+    ///
+    /// A class of Agents.
+    ///
+    /// `foaf:Group` (`GROUP`)
+    pub static ref GROUP: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "Group");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// Image
+    ///
+    /// An image.
+    ///
+    /// `foaf:Image` (`IMAGE`)
+    pub static ref IMAGE: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "Image");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// Label Property
+    ///
+    /// A foaf:LabelProperty is any RDF property with textual values that serve as labels.
+    ///
+    /// `foaf:LabelProperty` (`LABEL_PROPERTY`)
+    pub static ref LABEL_PROPERTY: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "LabelProperty");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// Online Account
+    ///
+    /// An online account.
+    ///
+    /// `foaf:OnlineAccount` (`ONLINE_ACCOUNT`)
+    pub static ref ONLINE_ACCOUNT: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "OnlineAccount");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// Online Chat Account
+    ///
+    /// An online chat account.
+    ///
+    /// `foaf:OnlineChatAccount` (`ONLINE_CHAT_ACCOUNT`)
+    pub static ref ONLINE_CHAT_ACCOUNT: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "OnlineChatAccount");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// Online E-commerce Account
+    ///
+    /// An online e-commerce account.
+    ///
+    /// `foaf:OnlineEcommerceAccount` (`ONLINE_ECOMMERCE_ACCOUNT`)
+    pub static ref ONLINE_ECOMMERCE_ACCOUNT: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "OnlineEcommerceAccount");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// Online Gaming Account
+    ///
+    /// An online gaming account.
+    ///
+    /// `foaf:OnlineGamingAccount` (`ONLINE_GAMING_ACCOUNT`)
+    pub static ref ONLINE_GAMING_ACCOUNT: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "OnlineGamingAccount");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// Organization
+    ///
+    /// An organization.
+    ///
+    /// `foaf:Organization` (`ORGANIZATION`)
+    pub static ref ORGANIZATION: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "Organization");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// Person
+    ///
+    /// A person.
+    ///
+    /// `foaf:Person` (`PERSON`)
+    pub static ref PERSON: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "Person");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// PersonalProfileDocument
+    ///
+    /// A personal profile RDF document.
+    ///
+    /// `foaf:PersonalProfileDocument` (`PERSONAL_PROFILE_DOCUMENT`)
+    pub static ref PERSONAL_PROFILE_DOCUMENT: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "PersonalProfileDocument");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// Project
+    ///
+    /// A project (a collective endeavour of some kind).
+    ///
+    /// `foaf:Project` (`PROJECT`)
+    pub static ref PROJECT: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "Project");
+}
@@ -0,0 +1,661 @@
+// Copyright (c) 2018-2023, agnos.ai UK Ltd, all rights reserved.
+//---------------------------------------------------------------
+//! The GeoSPARQL vocabulary, `geo:` <http://www.opengis.net/ont/geosparql#>.
+
+// Generated by rdf_store_rs::Vocabulary, do not edit.
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// The `geo:` namespace `<http://www.opengis.net/ont/geosparql#>`.
+    pub static ref NAMESPACE: ::std::sync::Arc<::rdf_store_rs::Namespace> =
+        ::std::sync::Arc::new(::rdf_store_rs::Namespace::declare_from_str_unchecked("geo:", "http://www.opengis.net/ont/geosparql#"));
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// as DGGS
+    ///
+    /// The Discrete Global Grid System (DGGS) serialization of a Geometry.
+    ///
+    /// `geo:asDGGS` (`AS_DGGS`)
+    pub static ref AS_DGGS: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "asDGGS");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// as GML
+    ///
+    /// The GML serialization of a Geometry.
+    ///
+    /// `geo:asGML` (`AS_GML`)
+    pub static ref AS_GML: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "asGML");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// as GeoJSON
+    ///
+    /// The GeoJSON serialization of a Geometry.
+    ///
+    /// `geo:asGeoJSON` (`AS_GEO_JSON`)
+    pub static ref AS_GEO_JSON: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "asGeoJSON");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// as KML
+    ///
+    /// The KML serialization of a Geometry.
+    ///
+    /// `geo:asKML` (`AS_KML`)
+    pub static ref AS_KML: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "asKML");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// as WKT
+    ///
+    /// The WKT serialization of a Geometry.
+    ///
+    /// `geo:asWKT` (`AS_WKT`)
+    pub static ref AS_WKT: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "asWKT");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// coordinate dimension
+    ///
+    /// The number of measurements or axes needed to describe the position of this Geometry in a coordinate system.
+    ///
+    /// `geo:coordinateDimension` (`COORDINATE_DIMENSION`)
+    pub static ref COORDINATE_DIMENSION: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "coordinateDimension");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// default geometry
+    ///
+    /// The default Geometry to be used in spatial calculations. It is usually the most detailed Geometry.
+    ///
+    /// `geo:defaultGeometry` (`DEFAULT_GEOMETRY`)
+    pub static ref DEFAULT_GEOMETRY: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "defaultGeometry");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// dimension
+    ///
+    /// The topological dimension of this geometric object, which must be less than or equal to the coordinate dimension. In non-homogeneous collections, this will return the largest topological dimension of the contained objects.
+    ///
+    /// `geo:dimension` (`DIMENSION`)
+    pub static ref DIMENSION: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "dimension");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// contains
+    ///
+    /// Exists if the subject SpatialObject spatially contains the object SpatialObject. DE-9IM: T*TFF*FF*
+    ///
+    /// `geo:ehContains` (`EH_CONTAINS`)
+    pub static ref EH_CONTAINS: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "ehContains");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// covered by
+    ///
+    /// Exists if the subject SpatialObject is spatially covered by the object SpatialObject. DE-9IM: TFF*TFT**
+    ///
+    /// `geo:ehCoveredBy` (`EH_COVERED_BY`)
+    pub static ref EH_COVERED_BY: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "ehCoveredBy");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// covers
+    ///
+    /// Exists if the subject SpatialObject spatially covers the object SpatialObject. DE-9IM: T*TFT*FF*
+    ///
+    /// `geo:ehCovers` (`EH_COVERS`)
+    pub static ref EH_COVERS: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "ehCovers");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// disjoint
+    ///
+    /// Exists if the subject SpatialObject is spatially disjoint from the object SpatialObject. DE-9IM: FF*FF****
+    ///
+    /// `geo:ehDisjoint` (`EH_DISJOINT`)
+    pub static ref EH_DISJOINT: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "ehDisjoint");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// equals
+    ///
+    /// Exists if the subject SpatialObject spatially equals the object SpatialObject. DE-9IM: TFFFTFFFT
+    ///
+    /// `geo:ehEquals` (`EH_EQUALS`)
+    pub static ref EH_EQUALS: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "ehEquals");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// inside
+    ///
+    /// Exists if the subject SpatialObject is spatially inside the object SpatialObject. DE-9IM: TFF*FFT**
+    ///
+    /// `geo:ehInside` (`EH_INSIDE`)
+    pub static ref EH_INSIDE: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "ehInside");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// meet
+    ///
+    /// Exists if the subject SpatialObject spatially meets the object SpatialObject. DE-9IM: FT******* ^ F**T***** ^ ***T****
+    ///
+    /// `geo:ehMeet` (`EH_MEET`)
+    pub static ref EH_MEET: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "ehMeet");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// overlap
+    ///
+    /// Exists if the subject SpatialObject spatially overlaps the object SpatialObject. DE-9IM: T*T***T**
+    ///
+    /// `geo:ehOverlap` (`EH_OVERLAP`)
+    pub static ref EH_OVERLAP: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "ehOverlap");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// has area
+    ///
+    /// The area of a Spatial Object.
+    ///
+    /// `geo:hasArea` (`HAS_AREA`)
+    pub static ref HAS_AREA: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "hasArea");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// has bounding box
+    ///
+    /// The minimum or smallest bounding or enclosing box of a given Feature.
+    ///
+    /// `geo:hasBoundingBox` (`HAS_BOUNDING_BOX`)
+    pub static ref HAS_BOUNDING_BOX: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "hasBoundingBox");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// has centroid
+    ///
+    /// The arithmetic mean position of all the geometry points of a given Feature.
+    ///
+    /// `geo:hasCentroid` (`HAS_CENTROID`)
+    pub static ref HAS_CENTROID: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "hasCentroid");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// has default geometry
+    ///
+    /// The default Geometry to be used in spatial calculations, usually the most detailed Geometry.
+    ///
+    /// `geo:hasDefaultGeometry` (`HAS_DEFAULT_GEOMETRY`)
+    pub static ref HAS_DEFAULT_GEOMETRY: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "hasDefaultGeometry");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// has geometry
+    ///
+    /// A spatial representation for a given Feature.
+    ///
+    /// `geo:hasGeometry` (`HAS_GEOMETRY`)
+    pub static ref HAS_GEOMETRY: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "hasGeometry");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// has length
+    ///
+    /// The length of a Spatial Object.
+    ///
+    /// `geo:hasLength` (`HAS_LENGTH`)
+    pub static ref HAS_LENGTH: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "hasLength");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// has area in square meters
+    ///
+    /// The area of a Spatial Object in square meters.
+    ///
+    /// `geo:hasMetricArea` (`HAS_METRIC_AREA`)
+    pub static ref HAS_METRIC_AREA: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "hasMetricArea");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// has length in meters
+    ///
+    /// The length of a Spatial Object in meters.
+    ///
+    /// `geo:hasMetricLength` (`HAS_METRIC_LENGTH`)
+    pub static ref HAS_METRIC_LENGTH: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "hasMetricLength");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// has perimeter length in meters
+    ///
+    /// The length of the perimeter of a Spatial Object in meters.
+    ///
+    /// `geo:hasMetricPerimeterLength` (`HAS_METRIC_PERIMETER_LENGTH`)
+    pub static ref HAS_METRIC_PERIMETER_LENGTH: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "hasMetricPerimeterLength");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// has metric size
+    ///
+    /// Subproperties of this property are used to indicate the size of a Spatial Object as a measurement or estimate of one or more dimensions of the Spatial Object's spatial presence. Units are always metric (meter, square meter or cubic meter).
+    ///
+    /// `geo:hasMetricSize` (`HAS_METRIC_SIZE`)
+    pub static ref HAS_METRIC_SIZE: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "hasMetricSize");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// has spatial accuracy in meters
+    ///
+    /// The positional accuracy of the coordinates of a Geometry in meters.
+    ///
+    /// `geo:hasMetricSpatialAccuracy` (`HAS_METRIC_SPATIAL_ACCURACY`)
+    pub static ref HAS_METRIC_SPATIAL_ACCURACY: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "hasMetricSpatialAccuracy");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// has spatial resolution in meters
+    ///
+    /// The spatial resolution of a Geometry in meters.
+    ///
+    /// `geo:hasMetricSpatialResolution` (`HAS_METRIC_SPATIAL_RESOLUTION`)
+    pub static ref HAS_METRIC_SPATIAL_RESOLUTION: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "hasMetricSpatialResolution");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// has volume in cubic meters
+    ///
+    /// The volume of a Spatial Object in cubic meters.
+    ///
+    /// `geo:hasMetricVolume` (`HAS_METRIC_VOLUME`)
+    pub static ref HAS_METRIC_VOLUME: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "hasMetricVolume");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// has perimeter length
+    ///
+    /// The length of the perimeter of a Spatial Object.
+    ///
+    /// `geo:hasPerimeterLength` (`HAS_PERIMETER_LENGTH`)
+    pub static ref HAS_PERIMETER_LENGTH: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "hasPerimeterLength");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// has serialization
+    ///
+    /// Connects a Geometry object with its text-based serialization.
+    ///
+    /// `geo:hasSerialization` (`HAS_SERIALIZATION`)
+    pub static ref HAS_SERIALIZATION: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "hasSerialization");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// has size
+    ///
+    /// Subproperties of this property are used to indicate the size of a Spatial Object as a measurement or estimate of one or more dimensions of the Spatial Object's spatial presence.
+    ///
+    /// `geo:hasSize` (`HAS_SIZE`)
+    pub static ref HAS_SIZE: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "hasSize");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// has spatial accuracy
+    ///
+    /// The positional accuracy of the coordinates of a Geometry.
+    ///
+    /// `geo:hasSpatialAccuracy` (`HAS_SPATIAL_ACCURACY`)
+    pub static ref HAS_SPATIAL_ACCURACY: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "hasSpatialAccuracy");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// has spatial resolution
+    ///
+    /// The spatial resolution of a Geometry.
+    ///
+    /// `geo:hasSpatialResolution` (`HAS_SPATIAL_RESOLUTION`)
+    pub static ref HAS_SPATIAL_RESOLUTION: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "hasSpatialResolution");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// has volume
+    ///
+    /// The volume of a three-dimensional Spatial Object.
+    ///
+    /// `geo:hasVolume` (`HAS_VOLUME`)
+    pub static ref HAS_VOLUME: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "hasVolume");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// is empty
+    ///
+    /// (true) if this geometric object is the empty Geometry. If true, then this geometric object represents the empty point set for the coordinate space.
+    ///
+    /// `geo:isEmpty` (`IS_EMPTY`)
+    pub static ref IS_EMPTY: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "isEmpty");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// is simple
+    ///
+    /// (true) if this geometric object has no anomalous geometric points, such as self intersection or self tangency.
+    ///
+    /// `geo:isSimple` (`IS_SIMPLE`)
+    pub static ref IS_SIMPLE: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "isSimple");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// disconnected
+    ///
+    /// Exists if the subject SpatialObject is spatially disjoint from the object SpatialObject. DE-9IM: FFTFFTTTT
+    ///
+    /// `geo:rcc8dc` (`RCC8DC`)
+    pub static ref RCC8DC: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "rcc8dc");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// externally connected
+    ///
+    /// Exists if the subject SpatialObject spatially meets the object SpatialObject. DE-9IM: FFTFTTTTT
+    ///
+    /// `geo:rcc8ec` (`RCC8EC`)
+    pub static ref RCC8EC: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "rcc8ec");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// equals
+    ///
+    /// Exists if the subject SpatialObject spatially equals the object SpatialObject. DE-9IM: TFFFTFFFT
+    ///
+    /// `geo:rcc8eq` (`RCC8EQ`)
+    pub static ref RCC8EQ: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "rcc8eq");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// non-tangential proper part
+    ///
+    /// Exists if the subject SpatialObject is spatially inside the object SpatialObject. DE-9IM: TFFTFFTTT
+    ///
+    /// `geo:rcc8ntpp` (`RCC8NTPP`)
+    pub static ref RCC8NTPP: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "rcc8ntpp");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// non-tangential proper part inverse
+    ///
+    /// Exists if the subject SpatialObject spatially contains the object SpatialObject. DE-9IM: TTTFFTFFT
+    ///
+    /// `geo:rcc8ntppi` (`RCC8NTPPI`)
+    pub static ref RCC8NTPPI: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "rcc8ntppi");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// partially overlapping
+    ///
+    /// Exists if the subject SpatialObject spatially overlaps the object SpatialObject. DE-9IM: TTTTTTTTT
+    ///
+    /// `geo:rcc8po` (`RCC8PO`)
+    pub static ref RCC8PO: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "rcc8po");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// tangential proper part
+    ///
+    /// Exists if the subject SpatialObject is spatially covered by the object SpatialObject. DE-9IM: TFFTTFTTT
+    ///
+    /// `geo:rcc8tpp` (`RCC8TPP`)
+    pub static ref RCC8TPP: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "rcc8tpp");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// tangential proper part inverse
+    ///
+    /// Exists if the subject SpatialObject spatially covers the object SpatialObject. DE-9IM: TTTFTTFFT
+    ///
+    /// `geo:rcc8tppi` (`RCC8TPPI`)
+    pub static ref RCC8TPPI: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "rcc8tppi");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// contains
+    ///
+    /// Exists if the subject SpatialObject spatially contains the object SpatialObject. DE-9IM: T*****FF*
+    ///
+    /// `geo:sfContains` (`SF_CONTAINS`)
+    pub static ref SF_CONTAINS: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "sfContains");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// crosses
+    ///
+    /// Exists if the subject SpatialObject spatially crosses the object SpatialObject. DE-9IM: T*T******
+    ///
+    /// `geo:sfCrosses` (`SF_CROSSES`)
+    pub static ref SF_CROSSES: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "sfCrosses");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// disjoint
+    ///
+    /// Exists if the subject SpatialObject is spatially disjoint from the object SpatialObject. DE-9IM: FF*FF****
+    ///
+    /// `geo:sfDisjoint` (`SF_DISJOINT`)
+    pub static ref SF_DISJOINT: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "sfDisjoint");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// equals
+    ///
+    /// Exists if the subject SpatialObject spatially equals the object SpatialObject. DE-9IM: TFFFTFFFT
+    ///
+    /// `geo:sfEquals` (`SF_EQUALS`)
+    pub static ref SF_EQUALS: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "sfEquals");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// intersects
+    ///
+    /// Exists if the subject SpatialObject is not spatially disjoint from the object SpatialObject. DE-9IM: T******** ^ *T******* ^ ***T***** ^ ****T****
+    ///
+    /// `geo:sfIntersects` (`SF_INTERSECTS`)
+    pub static ref SF_INTERSECTS: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "sfIntersects");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// overlaps
+    ///
+    /// Exists if the subject SpatialObject spatially overlaps the object SpatialObject. DE-9IM: T*T***T**
+    ///
+    /// `geo:sfOverlaps` (`SF_OVERLAPS`)
+    pub static ref SF_OVERLAPS: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "sfOverlaps");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// touches
+    ///
+    /// Exists if the subject SpatialObject spatially touches the object SpatialObject. DE-9IM: FT******* ^ F**T***** ^ F***T****
+    ///
+    /// `geo:sfTouches` (`SF_TOUCHES`)
+    pub static ref SF_TOUCHES: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "sfTouches");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// within
+    ///
+    /// Exists if the subject SpatialObject is spatially within the object SpatialObject. DE-9IM: T*F**F***
+    ///
+    /// `geo:sfWithin` (`SF_WITHIN`)
+    pub static ref SF_WITHIN: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "sfWithin");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// spatial dimension
+    ///
+    /// The number of measurements or axes needed to describe the spatial position of this Geometry in a coordinate system.
+    ///
+    /// `geo:spatialDimension` (`SPATIAL_DIMENSION`)
+    pub static ref SPATIAL_DIMENSION: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "spatialDimension");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// Feature
+    ///
+    /// A discrete spatial phenomenon in a universe of discourse.
+    ///
+    /// `geo:Feature` (`FEATURE`)
+    pub static ref FEATURE: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "Feature");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// Feature Collection
+    ///
+    /// A collection of individual Features.
+    ///
+    /// `geo:FeatureCollection` (`FEATURE_COLLECTION`)
+    pub static ref FEATURE_COLLECTION: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "FeatureCollection");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// Geometry
+    ///
+    /// A coherent set of direct positions in space. The positions are held within a Spatial Reference System (SRS).
+    ///
+    /// `geo:Geometry` (`GEOMETRY`)
+    pub static ref GEOMETRY: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "Geometry");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// Geometry Collection
+    ///
+    /// A collection of individual Geometries.
+    ///
+    /// `geo:GeometryCollection` (`GEOMETRY_COLLECTION`)
+    pub static ref GEOMETRY_COLLECTION: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "GeometryCollection");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// Spatial Object
+    ///
+    /// Anything spatial (being or having a shape, position or an extent).
+    ///
+    /// `geo:SpatialObject` (`SPATIAL_OBJECT`)
+    pub static ref SPATIAL_OBJECT: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "SpatialObject");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// Spatial Object Collection
+    ///
+    /// A collection of individual Spatial Objects.
+    ///
+    /// `geo:SpatialObjectCollection` (`SPATIAL_OBJECT_COLLECTION`)
+    pub static ref SPATIAL_OBJECT_COLLECTION: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "SpatialObjectCollection");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// DGGS Literal
+    ///
+    /// A textual serialization of a Discrete Global Grid System (DGGS) geometry object.
+    ///
+    /// `geo:dggsLiteral` (`DGGS_LITERAL`)
+    pub static ref DGGS_LITERAL: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "dggsLiteral");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// GeoJSON Literal
+    ///
+    /// A GeoJSON serialization of a geometry object.
+    ///
+    /// `geo:geoJSONLiteral` (`GEO_JSON_LITERAL`)
+    pub static ref GEO_JSON_LITERAL: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "geoJSONLiteral");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// GML Literal
+    ///
+    /// A GML serialization of a geometry object.
+    ///
+    /// `geo:gmlLiteral` (`GML_LITERAL`)
+    pub static ref GML_LITERAL: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "gmlLiteral");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// KML Literal
+    ///
+    /// A KML serialization of a geometry object.
+    ///
+    /// `geo:kmlLiteral` (`KML_LITERAL`)
+    pub static ref KML_LITERAL: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "kmlLiteral");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// Well-known Text Literal
+    ///
+    /// A Well-known Text serialization of a geometry object.
+    ///
+    /// `geo:wktLiteral` (`WKT_LITERAL`)
+    pub static ref WKT_LITERAL: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "wktLiteral");
+}
@@ -0,0 +1,125 @@
+// Copyright (c) 2018-2023, agnos.ai UK Ltd, all rights reserved.
+//---------------------------------------------------------------
+//! The terms of well-known vocabularies as typed [`Class`](crate::Class)
+//! and [`Predicate`](crate::Predicate) values, such as `rdf::TYPE` or
+//! `skos::PREF_LABEL`, with the [`Namespace`](crate::Namespace) of each
+//! vocabulary as its `NAMESPACE`.
+//!
+//! ```rust
+//! use rdf_store_rs::vocab::{rdf, skos};
+//!
+//! assert_eq!(
+//!     skos::PREF_LABEL.display_turtle().to_string(),
+//!     "skos:prefLabel"
+//! );
+//! assert_eq!(
+//!     rdf::TYPE.as_iri().unwrap().as_str(),
+//!     "http://www.w3.org/1999/02/22-rdf-syntax-ns#type"
+//! );
+//! ```
+//!
+//! Apart from `rdfox`, these modules are generated with `Vocabulary` (see
+//! the `nom-support` feature) from the ontologies in the `vocab` directory
+//! of the repository, and only contain the classes and properties of each
+//! vocabulary, not its individuals (such as `rdf:nil` or `sh:Violation`).
+//! A test checks that they are up to date, run the tests with
+//! `RDF_STORE_RS_GENERATE_VOCAB=1` to generate them again after changing an
+//! ontology. The constant of a class and a property with the same
+//! name have a `_CLASS` and `_PROPERTY` suffix, like `prov::AGENT_CLASS`
+//! and `prov::AGENT_PROPERTY`.
+
+pub mod dcat;
+pub mod dcterms;
+pub mod foaf;
+pub mod geo;
+pub mod org;
+pub mod owl;
+pub mod prov;
+pub mod rdf;
+pub mod rdfox;
+pub mod rdfs;
+pub mod schema;
+pub mod sh;
+pub mod skos;
+pub mod sosa;
+pub mod void;
+pub mod xsd;
+
+#[cfg(test)]
+mod tests {
+    #[cfg(feature = "nom-support")]
+    use {
+        super::{dcat, foaf, geo, org, owl, rdf, rdfs, schema, sh, skos, sosa, void},
+        crate::{Namespace, Vocabulary},
+        std::path::Path,
+    };
+    use {
+        super::{dcterms, prov, rdfox, xsd},
+        crate::RDFStoreError,
+    };
+
+    #[test_log::test]
+    fn test_vocab() -> Result<(), RDFStoreError> {
+        assert_eq!(
+            dcterms::ISO639_2.as_iri()?.as_str(),
+            "http://purl.org/dc/terms/ISO639-2"
+        );
+        assert_eq!(xsd::DATE_TIME.to_string(), "xsd:dateTime");
+        assert_eq!(prov::AGENT_CLASS.to_string(), "prov:Agent");
+        assert_eq!(
+            prov::AGENT_PROPERTY.display_turtle().to_string(),
+            "prov:agent"
+        );
+        assert_eq!(
+            rdfox::DEFAULT_TRIPLES.as_display_iri().to_string(),
+            "<http://oxfordsemantic.tech/RDFox#DefaultTriples>"
+        );
+        Ok(())
+    }
+
+    /// Check that each generated module is what [`Vocabulary`] generates
+    /// from its ontology, or generate it again if
+    /// `RDF_STORE_RS_GENERATE_VOCAB` is set. Only the module documentation
+    /// before the generated code is written by hand.
+    #[cfg(feature = "nom-support")]
+    #[test_log::test]
+    fn test_generated_vocabularies() -> Result<(), RDFStoreError> {
+        let vocabularies: [(&str, &Namespace); 15] = [
+            ("dcat", &dcat::NAMESPACE),
+            ("dcterms", &dcterms::NAMESPACE),
+            ("foaf", &foaf::NAMESPACE),
+            ("geo", &geo::NAMESPACE),
+            ("org", &org::NAMESPACE),
+            ("owl", &owl::NAMESPACE),
+            ("prov", &prov::NAMESPACE),
+            ("rdf", &rdf::NAMESPACE),
+            ("rdfs", &rdfs::NAMESPACE),
+            ("schema", &schema::NAMESPACE),
+            ("sh", &sh::NAMESPACE),
+            ("skos", &skos::NAMESPACE),
+            ("sosa", &sosa::NAMESPACE),
+            ("void", &void::NAMESPACE),
+            ("xsd", &xsd::NAMESPACE),
+        ];
+        let generate = std::env::var_os("RDF_STORE_RS_GENERATE_VOCAB").is_some();
+        let root = Path::new(env!("CARGO_MANIFEST_DIR"));
+        for (name, namespace) in vocabularies {
+            let ontology = std::fs::read_to_string(root.join(format!("vocab/{name}.ttl")))?;
+            let generated = Vocabulary::from_turtle(ontology.as_str(), namespace)?.to_rust();
+            let path = root.join(format!("src/vocab/{name}.rs"));
+            let module = std::fs::read_to_string(&path)?;
+            let first_line = generated.lines().next().unwrap_or_default();
+            let (documentation, code) = module.split_at(module.find(first_line).unwrap_or(0));
+            if generate {
+                std::fs::write(&path, format!("{documentation}{generated}"))?;
+            } else {
+                assert!(
+                    code == generated,
+                    "src/vocab/{name}.rs is not what vocab/{name}.ttl generates, run the tests \
+                     with RDF_STORE_RS_GENERATE_VOCAB=1 to generate it again"
+                );
+            }
+        }
+        Ok(())
+    }
+}
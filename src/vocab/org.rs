@@ -0,0 +1,451 @@
+// Copyright (c) 2018-2023, agnos.ai UK Ltd, all rights reserved.
+//---------------------------------------------------------------
+//! The W3C Organization Ontology, `org:` <http://www.w3.org/ns/org#>.
+
+// Generated by rdf_store_rs::Vocabulary, do not edit.
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// The `org:` namespace `<http://www.w3.org/ns/org#>`.
+    pub static ref NAMESPACE: ::std::sync::Arc<::rdf_store_rs::Namespace> =
+        ::std::sync::Arc::new(::rdf_store_rs::Namespace::declare_from_str_unchecked("org:", "http://www.w3.org/ns/org#"));
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// based at
+    ///
+    /// Indicates the site at which the person is based. We do not restrict the possibility that a person is based at multiple sites.
+    ///
+    /// `org:basedAt` (`BASED_AT`)
+    pub static ref BASED_AT: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "basedAt");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// changed by
+    ///
+    /// Indicates a change event which resulted in a change to this organization. Depending on the event the organization may or may not have continued to exist after the event. Inverse of org:originalOrganization.
+    ///
+    /// `org:changedBy` (`CHANGED_BY`)
+    pub static ref CHANGED_BY: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "changedBy");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// classification
+    ///
+    /// Indicates a classification for this Organization within some classification scheme.
+    ///
+    /// `org:classification` (`CLASSIFICATION`)
+    pub static ref CLASSIFICATION: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "classification");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// has member
+    ///
+    /// Indicates a person who is a member of the subject Organization. Inverse of org:memberOf, see that property for further clarification.
+    ///
+    /// `org:hasMember` (`HAS_MEMBER`)
+    pub static ref HAS_MEMBER: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "hasMember");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// has membership
+    ///
+    /// Indicates a membership relationship that the Agent plays. Inverse of org:member.
+    ///
+    /// `org:hasMembership` (`HAS_MEMBERSHIP`)
+    pub static ref HAS_MEMBERSHIP: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "hasMembership");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// has post
+    ///
+    /// Indicates a Post which exists within the Organization.
+    ///
+    /// `org:hasPost` (`HAS_POST`)
+    pub static ref HAS_POST: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "hasPost");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// has primary site
+    ///
+    /// Indicates a primary site for the Organization, this is the default means by which an Organization can be contacted and is not necessarily the formal headquarters.
+    ///
+    /// `org:hasPrimarySite` (`HAS_PRIMARY_SITE`)
+    pub static ref HAS_PRIMARY_SITE: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "hasPrimarySite");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// has registered site
+    ///
+    /// Indicates the legally registered site for the organization, in many legal jurisdictions there is a requirement that FormalOrganizations such as Companies or Charities have such a primary designed site.
+    ///
+    /// `org:hasRegisteredSite` (`HAS_REGISTERED_SITE`)
+    pub static ref HAS_REGISTERED_SITE: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "hasRegisteredSite");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// has site
+    ///
+    /// Indicates a site at which the Organization has some presence even if only indirect (e.g. virtual office or a professional service which is acting as the registered address for a company). Inverse of org:siteOf.
+    ///
+    /// `org:hasSite` (`HAS_SITE`)
+    pub static ref HAS_SITE: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "hasSite");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// has subOrganization
+    ///
+    /// Represents hierarchical containment of Organizations or Organizational Units; indicates an organization which is a sub-part or child of this organization. Inverse of org:subOrganizationOf.
+    ///
+    /// `org:hasSubOrganization` (`HAS_SUB_ORGANIZATION`)
+    pub static ref HAS_SUB_ORGANIZATION: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "hasSubOrganization");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// has Unit
+    ///
+    /// Indicates a unit which is part of this Organization, e.g. a Department within a larger FormalOrganization. Inverse of org:unitOf.
+    ///
+    /// `org:hasUnit` (`HAS_UNIT`)
+    pub static ref HAS_UNIT: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "hasUnit");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// head of
+    ///
+    /// Indicates that a person is the leader or formal head of the Organization.
+    ///
+    /// `org:headOf` (`HEAD_OF`)
+    pub static ref HEAD_OF: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "headOf");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// held by
+    ///
+    /// Indicates an Agent which holds a Post.
+    ///
+    /// `org:heldBy` (`HELD_BY`)
+    pub static ref HELD_BY: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "heldBy");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// holds
+    ///
+    /// Indicates a Post held by some Agent.
+    ///
+    /// `org:holds` (`HOLDS`)
+    pub static ref HOLDS: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "holds");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// identifier
+    ///
+    /// Gives an identifier, such as a company registration number, that can be used to uniquely identify the organization. Many different national and international identifier schemes are available.
+    ///
+    /// `org:identifier` (`IDENTIFIER`)
+    pub static ref IDENTIFIER: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "identifier");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// linked to
+    ///
+    /// Indicates an arbitrary relationship between two organizations. Specializations of this can be used to, for example, denote funding or supply chain relationships.
+    ///
+    /// `org:linkedTo` (`LINKED_TO`)
+    pub static ref LINKED_TO: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "linkedTo");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// location
+    ///
+    /// Gives a location description for a person within the organization, for example a Mail Stop for internal posting purposes.
+    ///
+    /// `org:location` (`LOCATION`)
+    pub static ref LOCATION: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "location");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// member
+    ///
+    /// Indicates the Person (or other Agent including Organization) involved in the Membership relationship. Inverse of org:hasMembership.
+    ///
+    /// `org:member` (`MEMBER`)
+    pub static ref MEMBER: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "member");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// member during
+    ///
+    /// Optional property to indicate the interval for which the membership is/was valid.
+    ///
+    /// `org:memberDuring` (`MEMBER_DURING`)
+    pub static ref MEMBER_DURING: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "memberDuring");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// member of
+    ///
+    /// Indicates that a person is a member of the Organization with no indication of the nature of that membership or the role played. Note that the choice of property name is not meant to limit the property to only formal membership arrangements, it is also intended to cover related concepts such as affiliation or other involvement in the organization.
+    ///
+    /// `org:memberOf` (`MEMBER_OF`)
+    pub static ref MEMBER_OF: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "memberOf");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// organization
+    ///
+    /// Indicates Organization in which the Agent is a member.
+    ///
+    /// `org:organization` (`ORGANIZATION_PROPERTY`)
+    pub static ref ORGANIZATION_PROPERTY: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "organization");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// original organization
+    ///
+    /// Indicates one or more organizations that existed before the change event. Depending on the event they may or may not have continued to exist after the event. Inverse of org:changedBy.
+    ///
+    /// `org:originalOrganization` (`ORIGINAL_ORGANIZATION`)
+    pub static ref ORIGINAL_ORGANIZATION: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "originalOrganization");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// post in
+    ///
+    /// Indicates the Organization in which the Post exists.
+    ///
+    /// `org:postIn` (`POST_IN`)
+    pub static ref POST_IN: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "postIn");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// purpose
+    ///
+    /// Indicates the purpose of this Organization. There can be many purposes at different levels of abstraction but the nature of an organization is to have a reason for existence and this property is a means to document that reason.
+    ///
+    /// `org:purpose` (`PURPOSE`)
+    pub static ref PURPOSE: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "purpose");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// remuneration
+    ///
+    /// Indicates a salary or other reward associated with the role. Typically this will be denoted using an existing representation scheme such as currency values but it could be something else.
+    ///
+    /// `org:remuneration` (`REMUNERATION`)
+    pub static ref REMUNERATION: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "remuneration");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// reports to
+    ///
+    /// Indicates a reporting relationship as might be depicted on an organizational chart. The precise semantics of the reporting relationship will vary by organization but is intended to encompass both direct supervisory relationships (e.g. carrying objective and salary setting authority) and more general reporting or accountability relationships (e.g. so called dotted line reporting).
+    ///
+    /// `org:reportsTo` (`REPORTS_TO`)
+    pub static ref REPORTS_TO: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "reportsTo");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// resulted from
+    ///
+    /// Indicates an event which resulted in this organization. Inverse of org:resultingOrganization.
+    ///
+    /// `org:resultedFrom` (`RESULTED_FROM`)
+    pub static ref RESULTED_FROM: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "resultedFrom");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// resulting organization
+    ///
+    /// Indicates an organization which was created or changed as a result of the event. Inverse of org:resultedFrom.
+    ///
+    /// `org:resultingOrganization` (`RESULTING_ORGANIZATION`)
+    pub static ref RESULTING_ORGANIZATION: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "resultingOrganization");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// role
+    ///
+    /// Indicates the Role that the Agent plays in a Membership relationship with an Organization.
+    ///
+    /// `org:role` (`ROLE_PROPERTY_2`)
+    pub static ref ROLE_PROPERTY_2: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "role");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// role property
+    ///
+    /// This is a metalevel property which is used to annotate an org:Role instance with a sub-property of org:memberOf that can be used to directly indicate the role for easy of query.
+    ///
+    /// `org:roleProperty` (`ROLE_PROPERTY`)
+    pub static ref ROLE_PROPERTY: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "roleProperty");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// site address
+    ///
+    /// Indicates an address for the site in a suitable encoding. Use of vCard (using the http://www.w3.org/TR/vcard-rdf/ vocabulary) is encouraged but the range is left open to allow other encodings to be used.
+    ///
+    /// `org:siteAddress` (`SITE_ADDRESS`)
+    pub static ref SITE_ADDRESS: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "siteAddress");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// site of
+    ///
+    /// Indicates an Organization which has some presence at the given site. This is the inverse of org:hasSite.
+    ///
+    /// `org:siteOf` (`SITE_OF`)
+    pub static ref SITE_OF: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "siteOf");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// subOrganization of
+    ///
+    /// Represents hierarchical containment of Organizations or OrganizationalUnits; indicates an Organization which contains this Organization. Inverse of org:hasSubOrganization.
+    ///
+    /// `org:subOrganizationOf` (`SUB_ORGANIZATION_OF`)
+    pub static ref SUB_ORGANIZATION_OF: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "subOrganizationOf");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// transitive sub-organization of
+    ///
+    /// The transitive closure of subOrganizationOf, giving a representation of all organizations that contain this one. Note that technically this is a super property of the transitive closure so it could contain additional assertions but such usage is discouraged.
+    ///
+    /// `org:transitiveSubOrganizationOf` (`TRANSITIVE_SUB_ORGANIZATION_OF`)
+    pub static ref TRANSITIVE_SUB_ORGANIZATION_OF: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "transitiveSubOrganizationOf");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// unit of
+    ///
+    /// Indicates an Organization of which this Unit is a part, e.g. a Department within a larger FormalOrganization. Inverse of org:hasUnit.
+    ///
+    /// `org:unitOf` (`UNIT_OF`)
+    pub static ref UNIT_OF: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "unitOf");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// Change Event
+    ///
+    /// Represents an event which resulted in a major change to an organization such as a merger or complete restructuring. It is intended for situations where the resulting organization is sufficiently distinct from the original organizations that it has a distinct identity and distinct URI.
+    ///
+    /// `org:ChangeEvent` (`CHANGE_EVENT`)
+    pub static ref CHANGE_EVENT: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "ChangeEvent");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// FormalOrganization
+    ///
+    /// An Organization which is recognized in the world at large, in particular in legal jurisdictions, with associated rights and responsibilities. Examples include a Corporation, Charity, Government or Church.
+    ///
+    /// `org:FormalOrganization` (`FORMAL_ORGANIZATION`)
+    pub static ref FORMAL_ORGANIZATION: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "FormalOrganization");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// Membership
+    ///
+    /// Indicates the nature of an Agent's membership of an organization. Represents an n-ary relation between an Agent, an Organization and a Role. It is possible to directly indicate membership, independent of the specific Role, through use of the org:memberOf property.
+    ///
+    /// `org:Membership` (`MEMBERSHIP`)
+    pub static ref MEMBERSHIP: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "Membership");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// Organization
+    ///
+    /// Represents a collection of people organized together into a community or other social, commercial or political structure. The group has some common purpose or reason for existence which goes beyond the set of people belonging to it and can act as an Agent. Organizations are often decomposable into hierarchical structures.
+    ///
+    /// `org:Organization` (`ORGANIZATION_CLASS`)
+    pub static ref ORGANIZATION_CLASS: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "Organization");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// Organizational Collaboration
+    ///
+    /// A collaboration between two or more Organizations such as a project. It meets the criteria for being an Organization in that it has an identity and defining purpose independent of its particular members but is neither a formally recognized legal entity nor a sub-unit within some larger organization.
+    ///
+    /// `org:OrganizationalCollaboration` (`ORGANIZATIONAL_COLLABORATION`)
+    pub static ref ORGANIZATIONAL_COLLABORATION: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "OrganizationalCollaboration");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// OrganizationalUnit
+    ///
+    /// An Organization such as a University Support Unit which is part of some larger FormalOrganization and only has full recognition within the context of that FormalOrganization, it is not a Legal Entity in its own right. Units can be large and complex containing other Units and even FormalOrganizations.
+    ///
+    /// `org:OrganizationalUnit` (`ORGANIZATIONAL_UNIT`)
+    pub static ref ORGANIZATIONAL_UNIT: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "OrganizationalUnit");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// Post
+    ///
+    /// A Post represents some position within an organization that exists independently of the person or persons filling it. Posts may be used to represent situations where a person is a member of an organization ex officio (for example the Secretary of State for Scotland is part of UK Cabinet by virtue of being Secretary of State for Scotland, not as an individual person).
+    ///
+    /// `org:Post` (`POST`)
+    pub static ref POST: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "Post");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// Role
+    ///
+    /// Denotes a role that a Person or other Agent can take in an organization. Instances of this class describe the abstract role; to denote a specific instance of a person playing that role in a specific organization use an instance of org:Membership.
+    ///
+    /// `org:Role` (`ROLE_CLASS`)
+    pub static ref ROLE_CLASS: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "Role");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// Site
+    ///
+    /// An office or other premise at which the organization is located. Many organizations are spread across multiple sites and many sites will host multiple locations. In most cases a Site will be a physical location. However, we don't exclude the possibility of non-physical sites such as a virtual office with an associated post box and phone reception service.
+    ///
+    /// `org:Site` (`SITE`)
+    pub static ref SITE: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "Site");
+}
@@ -0,0 +1,801 @@
+// Copyright (c) 2018-2023, agnos.ai UK Ltd, all rights reserved.
+//---------------------------------------------------------------
+//! The OWL 2 vocabulary, `owl:` <http://www.w3.org/2002/07/owl#>.
+
+// Generated by rdf_store_rs::Vocabulary, do not edit.
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// The `owl:` namespace `<http://www.w3.org/2002/07/owl#>`.
+    pub static ref NAMESPACE: ::std::sync::Arc<::rdf_store_rs::Namespace> =
+        ::std::sync::Arc::new(::rdf_store_rs::Namespace::declare_from_str_unchecked("owl:", "http://www.w3.org/2002/07/owl#"));
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// allValuesFrom
+    ///
+    /// The property that determines the class that a universal property restriction refers to.
+    ///
+    /// `owl:allValuesFrom` (`ALL_VALUES_FROM`)
+    pub static ref ALL_VALUES_FROM: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "allValuesFrom");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// annotatedProperty
+    ///
+    /// The property that determines the predicate of an annotated axiom or annotated annotation.
+    ///
+    /// `owl:annotatedProperty` (`ANNOTATED_PROPERTY`)
+    pub static ref ANNOTATED_PROPERTY: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "annotatedProperty");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// annotatedSource
+    ///
+    /// The property that determines the subject of an annotated axiom or annotated annotation.
+    ///
+    /// `owl:annotatedSource` (`ANNOTATED_SOURCE`)
+    pub static ref ANNOTATED_SOURCE: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "annotatedSource");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// annotatedTarget
+    ///
+    /// The property that determines the object of an annotated axiom or annotated annotation.
+    ///
+    /// `owl:annotatedTarget` (`ANNOTATED_TARGET`)
+    pub static ref ANNOTATED_TARGET: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "annotatedTarget");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// assertionProperty
+    ///
+    /// The property that determines the predicate of a negative property assertion.
+    ///
+    /// `owl:assertionProperty` (`ASSERTION_PROPERTY`)
+    pub static ref ASSERTION_PROPERTY: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "assertionProperty");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// backwardCompatibleWith
+    ///
+    /// The annotation property that indicates that a given ontology is backward compatible with another ontology.
+    ///
+    /// `owl:backwardCompatibleWith` (`BACKWARD_COMPATIBLE_WITH`)
+    pub static ref BACKWARD_COMPATIBLE_WITH: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "backwardCompatibleWith");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// bottomDataProperty
+    ///
+    /// The data property that does not relate any individual to any data value.
+    ///
+    /// `owl:bottomDataProperty` (`BOTTOM_DATA_PROPERTY`)
+    pub static ref BOTTOM_DATA_PROPERTY: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "bottomDataProperty");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// bottomObjectProperty
+    ///
+    /// The object property that does not relate any two individuals.
+    ///
+    /// `owl:bottomObjectProperty` (`BOTTOM_OBJECT_PROPERTY`)
+    pub static ref BOTTOM_OBJECT_PROPERTY: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "bottomObjectProperty");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// cardinality
+    ///
+    /// The property that determines the cardinality of an exact cardinality restriction.
+    ///
+    /// `owl:cardinality` (`CARDINALITY`)
+    pub static ref CARDINALITY: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "cardinality");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// complementOf
+    ///
+    /// The property that determines that a given class is the complement of another class.
+    ///
+    /// `owl:complementOf` (`COMPLEMENT_OF`)
+    pub static ref COMPLEMENT_OF: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "complementOf");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// datatypeComplementOf
+    ///
+    /// The property that determines that a given data range is the complement of another data range with respect to the data domain.
+    ///
+    /// `owl:datatypeComplementOf` (`DATATYPE_COMPLEMENT_OF`)
+    pub static ref DATATYPE_COMPLEMENT_OF: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "datatypeComplementOf");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// deprecated
+    ///
+    /// The annotation property that indicates that a given entity has been deprecated.
+    ///
+    /// `owl:deprecated` (`DEPRECATED`)
+    pub static ref DEPRECATED: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "deprecated");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// differentFrom
+    ///
+    /// The property that determines that two given individuals are different.
+    ///
+    /// `owl:differentFrom` (`DIFFERENT_FROM`)
+    pub static ref DIFFERENT_FROM: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "differentFrom");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// disjointUnionOf
+    ///
+    /// The property that determines that a given class is equivalent to the disjoint union of a collection of other classes.
+    ///
+    /// `owl:disjointUnionOf` (`DISJOINT_UNION_OF`)
+    pub static ref DISJOINT_UNION_OF: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "disjointUnionOf");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// disjointWith
+    ///
+    /// The property that determines that two given classes are disjoint.
+    ///
+    /// `owl:disjointWith` (`DISJOINT_WITH`)
+    pub static ref DISJOINT_WITH: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "disjointWith");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// distinctMembers
+    ///
+    /// The property that determines the collection of pairwise different individuals in a owl:AllDifferent axiom.
+    ///
+    /// `owl:distinctMembers` (`DISTINCT_MEMBERS`)
+    pub static ref DISTINCT_MEMBERS: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "distinctMembers");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// equivalentClass
+    ///
+    /// The property that determines that two given classes are equivalent, and that is used to specify datatype definitions.
+    ///
+    /// `owl:equivalentClass` (`EQUIVALENT_CLASS`)
+    pub static ref EQUIVALENT_CLASS: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "equivalentClass");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// equivalentProperty
+    ///
+    /// The property that determines that two given properties are equivalent.
+    ///
+    /// `owl:equivalentProperty` (`EQUIVALENT_PROPERTY`)
+    pub static ref EQUIVALENT_PROPERTY: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "equivalentProperty");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// hasKey
+    ///
+    /// The property that determines the collection of properties that jointly build a key.
+    ///
+    /// `owl:hasKey` (`HAS_KEY`)
+    pub static ref HAS_KEY: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "hasKey");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// hasSelf
+    ///
+    /// The property that determines the property that a self restriction refers to.
+    ///
+    /// `owl:hasSelf` (`HAS_SELF`)
+    pub static ref HAS_SELF: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "hasSelf");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// hasValue
+    ///
+    /// The property that determines the individual that a has-value restriction refers to.
+    ///
+    /// `owl:hasValue` (`HAS_VALUE`)
+    pub static ref HAS_VALUE: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "hasValue");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// imports
+    ///
+    /// The property that is used for importing other ontologies into a given ontology.
+    ///
+    /// `owl:imports` (`IMPORTS`)
+    pub static ref IMPORTS: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "imports");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// incompatibleWith
+    ///
+    /// The annotation property that indicates that a given ontology is incompatible with another ontology.
+    ///
+    /// `owl:incompatibleWith` (`INCOMPATIBLE_WITH`)
+    pub static ref INCOMPATIBLE_WITH: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "incompatibleWith");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// intersectionOf
+    ///
+    /// The property that determines the collection of classes or data ranges that build an intersection.
+    ///
+    /// `owl:intersectionOf` (`INTERSECTION_OF`)
+    pub static ref INTERSECTION_OF: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "intersectionOf");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// inverseOf
+    ///
+    /// The property that determines that two given properties are inverse.
+    ///
+    /// `owl:inverseOf` (`INVERSE_OF`)
+    pub static ref INVERSE_OF: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "inverseOf");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// maxCardinality
+    ///
+    /// The property that determines the cardinality of a maximum cardinality restriction.
+    ///
+    /// `owl:maxCardinality` (`MAX_CARDINALITY`)
+    pub static ref MAX_CARDINALITY: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "maxCardinality");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// maxQualifiedCardinality
+    ///
+    /// The property that determines the cardinality of a maximum qualified cardinality restriction.
+    ///
+    /// `owl:maxQualifiedCardinality` (`MAX_QUALIFIED_CARDINALITY`)
+    pub static ref MAX_QUALIFIED_CARDINALITY: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "maxQualifiedCardinality");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// members
+    ///
+    /// The property that determines the collection of members in either a owl:AllDifferent, owl:AllDisjointClasses or owl:AllDisjointProperties axiom.
+    ///
+    /// `owl:members` (`MEMBERS`)
+    pub static ref MEMBERS: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "members");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// minCardinality
+    ///
+    /// The property that determines the cardinality of a minimum cardinality restriction.
+    ///
+    /// `owl:minCardinality` (`MIN_CARDINALITY`)
+    pub static ref MIN_CARDINALITY: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "minCardinality");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// minQualifiedCardinality
+    ///
+    /// The property that determines the cardinality of a minimum qualified cardinality restriction.
+    ///
+    /// `owl:minQualifiedCardinality` (`MIN_QUALIFIED_CARDINALITY`)
+    pub static ref MIN_QUALIFIED_CARDINALITY: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "minQualifiedCardinality");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// onClass
+    ///
+    /// The property that determines the class that a qualified object cardinality restriction refers to.
+    ///
+    /// `owl:onClass` (`ON_CLASS`)
+    pub static ref ON_CLASS: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "onClass");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// onDataRange
+    ///
+    /// The property that determines the data range that a qualified data cardinality restriction refers to.
+    ///
+    /// `owl:onDataRange` (`ON_DATA_RANGE`)
+    pub static ref ON_DATA_RANGE: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "onDataRange");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// onDatatype
+    ///
+    /// The property that determines the datatype that a datatype restriction refers to.
+    ///
+    /// `owl:onDatatype` (`ON_DATATYPE`)
+    pub static ref ON_DATATYPE: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "onDatatype");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// onProperties
+    ///
+    /// The property that determines the n-tuple of properties that a property restriction on an n-ary data range refers to.
+    ///
+    /// `owl:onProperties` (`ON_PROPERTIES`)
+    pub static ref ON_PROPERTIES: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "onProperties");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// onProperty
+    ///
+    /// The property that determines the property that a property restriction refers to.
+    ///
+    /// `owl:onProperty` (`ON_PROPERTY`)
+    pub static ref ON_PROPERTY: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "onProperty");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// oneOf
+    ///
+    /// The property that determines the collection of individuals or data values that build an enumeration.
+    ///
+    /// `owl:oneOf` (`ONE_OF`)
+    pub static ref ONE_OF: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "oneOf");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// priorVersion
+    ///
+    /// The annotation property that indicates the predecessor ontology of a given ontology.
+    ///
+    /// `owl:priorVersion` (`PRIOR_VERSION`)
+    pub static ref PRIOR_VERSION: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "priorVersion");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// propertyChainAxiom
+    ///
+    /// The property that determines the n-tuple of properties that build a sub property chain of a given property.
+    ///
+    /// `owl:propertyChainAxiom` (`PROPERTY_CHAIN_AXIOM`)
+    pub static ref PROPERTY_CHAIN_AXIOM: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "propertyChainAxiom");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// propertyDisjointWith
+    ///
+    /// The property that determines that two given properties are disjoint.
+    ///
+    /// `owl:propertyDisjointWith` (`PROPERTY_DISJOINT_WITH`)
+    pub static ref PROPERTY_DISJOINT_WITH: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "propertyDisjointWith");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// qualifiedCardinality
+    ///
+    /// The property that determines the cardinality of an exact qualified cardinality restriction.
+    ///
+    /// `owl:qualifiedCardinality` (`QUALIFIED_CARDINALITY`)
+    pub static ref QUALIFIED_CARDINALITY: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "qualifiedCardinality");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// sameAs
+    ///
+    /// The property that determines that two given individuals are equal.
+    ///
+    /// `owl:sameAs` (`SAME_AS`)
+    pub static ref SAME_AS: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "sameAs");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// someValuesFrom
+    ///
+    /// The property that determines the class that an existential property restriction refers to.
+    ///
+    /// `owl:someValuesFrom` (`SOME_VALUES_FROM`)
+    pub static ref SOME_VALUES_FROM: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "someValuesFrom");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// sourceIndividual
+    ///
+    /// The property that determines the subject of a negative property assertion.
+    ///
+    /// `owl:sourceIndividual` (`SOURCE_INDIVIDUAL`)
+    pub static ref SOURCE_INDIVIDUAL: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "sourceIndividual");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// targetIndividual
+    ///
+    /// The property that determines the object of a negative object property assertion.
+    ///
+    /// `owl:targetIndividual` (`TARGET_INDIVIDUAL`)
+    pub static ref TARGET_INDIVIDUAL: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "targetIndividual");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// targetValue
+    ///
+    /// The property that determines the value of a negative data property assertion.
+    ///
+    /// `owl:targetValue` (`TARGET_VALUE`)
+    pub static ref TARGET_VALUE: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "targetValue");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// topDataProperty
+    ///
+    /// The data property that relates every individual to every data value.
+    ///
+    /// `owl:topDataProperty` (`TOP_DATA_PROPERTY`)
+    pub static ref TOP_DATA_PROPERTY: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "topDataProperty");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// topObjectProperty
+    ///
+    /// The object property that relates every two individuals.
+    ///
+    /// `owl:topObjectProperty` (`TOP_OBJECT_PROPERTY`)
+    pub static ref TOP_OBJECT_PROPERTY: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "topObjectProperty");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// unionOf
+    ///
+    /// The property that determines the collection of classes or data ranges that build a union.
+    ///
+    /// `owl:unionOf` (`UNION_OF`)
+    pub static ref UNION_OF: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "unionOf");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// versionIRI
+    ///
+    /// The property that identifies the version IRI of an ontology.
+    ///
+    /// `owl:versionIRI` (`VERSION_IRI`)
+    pub static ref VERSION_IRI: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "versionIRI");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// versionInfo
+    ///
+    /// The annotation property that provides version information for an ontology or another OWL construct.
+    ///
+    /// `owl:versionInfo` (`VERSION_INFO`)
+    pub static ref VERSION_INFO: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "versionInfo");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// withRestrictions
+    ///
+    /// The property that determines the collection of facet-value pairs that define a datatype restriction.
+    ///
+    /// `owl:withRestrictions` (`WITH_RESTRICTIONS`)
+    pub static ref WITH_RESTRICTIONS: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "withRestrictions");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// AllDifferent
+    ///
+    /// The class of collections of pairwise different individuals.
+    ///
+    /// `owl:AllDifferent` (`ALL_DIFFERENT`)
+    pub static ref ALL_DIFFERENT: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "AllDifferent");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// AllDisjointClasses
+    ///
+    /// The class of collections of pairwise disjoint classes.
+    ///
+    /// `owl:AllDisjointClasses` (`ALL_DISJOINT_CLASSES`)
+    pub static ref ALL_DISJOINT_CLASSES: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "AllDisjointClasses");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// AllDisjointProperties
+    ///
+    /// The class of collections of pairwise disjoint properties.
+    ///
+    /// `owl:AllDisjointProperties` (`ALL_DISJOINT_PROPERTIES`)
+    pub static ref ALL_DISJOINT_PROPERTIES: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "AllDisjointProperties");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// Annotation
+    ///
+    /// The class of annotated annotations for which the RDF serialization consists of an annotated subject, predicate and object.
+    ///
+    /// `owl:Annotation` (`ANNOTATION`)
+    pub static ref ANNOTATION: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "Annotation");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// AnnotationProperty
+    ///
+    /// The class of annotation properties.
+    ///
+    /// `owl:AnnotationProperty` (`ANNOTATION_PROPERTY`)
+    pub static ref ANNOTATION_PROPERTY: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "AnnotationProperty");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// AsymmetricProperty
+    ///
+    /// The class of asymmetric properties.
+    ///
+    /// `owl:AsymmetricProperty` (`ASYMMETRIC_PROPERTY`)
+    pub static ref ASYMMETRIC_PROPERTY: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "AsymmetricProperty");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// Axiom
+    ///
+    /// The class of annotated axioms for which the RDF serialization consists of an annotated subject, predicate and object.
+    ///
+    /// `owl:Axiom` (`AXIOM`)
+    pub static ref AXIOM: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "Axiom");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// Class
+    ///
+    /// The class of OWL classes.
+    ///
+    /// `owl:Class` (`CLASS`)
+    pub static ref CLASS: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "Class");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// DataRange
+    ///
+    /// The class of OWL data ranges, which are special kinds of datatypes. Note: The use of the IRI owl:DataRange has been deprecated as of OWL 2. The IRI rdfs:Datatype SHOULD be used instead.
+    ///
+    /// `owl:DataRange` (`DATA_RANGE`)
+    pub static ref DATA_RANGE: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "DataRange");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// DatatypeProperty
+    ///
+    /// The class of data properties.
+    ///
+    /// `owl:DatatypeProperty` (`DATATYPE_PROPERTY`)
+    pub static ref DATATYPE_PROPERTY: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "DatatypeProperty");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// DeprecatedClass
+    ///
+    /// The class of deprecated classes.
+    ///
+    /// `owl:DeprecatedClass` (`DEPRECATED_CLASS`)
+    pub static ref DEPRECATED_CLASS: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "DeprecatedClass");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// DeprecatedProperty
+    ///
+    /// The class of deprecated properties.
+    ///
+    /// `owl:DeprecatedProperty` (`DEPRECATED_PROPERTY`)
+    pub static ref DEPRECATED_PROPERTY: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "DeprecatedProperty");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// FunctionalProperty
+    ///
+    /// The class of functional properties.
+    ///
+    /// `owl:FunctionalProperty` (`FUNCTIONAL_PROPERTY`)
+    pub static ref FUNCTIONAL_PROPERTY: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "FunctionalProperty");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// InverseFunctionalProperty
+    ///
+    /// The class of inverse-functional properties.
+    ///
+    /// `owl:InverseFunctionalProperty` (`INVERSE_FUNCTIONAL_PROPERTY`)
+    pub static ref INVERSE_FUNCTIONAL_PROPERTY: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "InverseFunctionalProperty");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// IrreflexiveProperty
+    ///
+    /// The class of irreflexive properties.
+    ///
+    /// `owl:IrreflexiveProperty` (`IRREFLEXIVE_PROPERTY`)
+    pub static ref IRREFLEXIVE_PROPERTY: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "IrreflexiveProperty");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// NamedIndividual
+    ///
+    /// The class of named individuals.
+    ///
+    /// `owl:NamedIndividual` (`NAMED_INDIVIDUAL`)
+    pub static ref NAMED_INDIVIDUAL: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "NamedIndividual");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// NegativePropertyAssertion
+    ///
+    /// The class of negative property assertions.
+    ///
+    /// `owl:NegativePropertyAssertion` (`NEGATIVE_PROPERTY_ASSERTION`)
+    pub static ref NEGATIVE_PROPERTY_ASSERTION: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "NegativePropertyAssertion");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// Nothing
+    ///
+    /// This is the empty class.
+    ///
+    /// `owl:Nothing` (`NOTHING`)
+    pub static ref NOTHING: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "Nothing");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// ObjectProperty
+    ///
+    /// The class of object properties.
+    ///
+    /// `owl:ObjectProperty` (`OBJECT_PROPERTY`)
+    pub static ref OBJECT_PROPERTY: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "ObjectProperty");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// Ontology
+    ///
+    /// The class of ontologies.
+    ///
+    /// `owl:Ontology` (`ONTOLOGY`)
+    pub static ref ONTOLOGY: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "Ontology");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// OntologyProperty
+    ///
+    /// The class of ontology properties.
+    ///
+    /// `owl:OntologyProperty` (`ONTOLOGY_PROPERTY`)
+    pub static ref ONTOLOGY_PROPERTY: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "OntologyProperty");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// ReflexiveProperty
+    ///
+    /// The class of reflexive properties.
+    ///
+    /// `owl:ReflexiveProperty` (`REFLEXIVE_PROPERTY`)
+    pub static ref REFLEXIVE_PROPERTY: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "ReflexiveProperty");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// Restriction
+    ///
+    /// The class of property restrictions.
+    ///
+    /// `owl:Restriction` (`RESTRICTION`)
+    pub static ref RESTRICTION: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "Restriction");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// SymmetricProperty
+    ///
+    /// The class of symmetric properties.
+    ///
+    /// `owl:SymmetricProperty` (`SYMMETRIC_PROPERTY`)
+    pub static ref SYMMETRIC_PROPERTY: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "SymmetricProperty");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// Thing
+    ///
+    /// The class of OWL individuals.
+    ///
+    /// `owl:Thing` (`THING`)
+    pub static ref THING: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "Thing");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// TransitiveProperty
+    ///
+    /// The class of transitive properties.
+    ///
+    /// `owl:TransitiveProperty` (`TRANSITIVE_PROPERTY`)
+    pub static ref TRANSITIVE_PROPERTY: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "TransitiveProperty");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// rational
+    ///
+    /// The datatype of rational numbers, the values of which are written as a numerator and a denominator separated by a slash.
+    ///
+    /// `owl:rational` (`RATIONAL`)
+    pub static ref RATIONAL: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "rational");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// real
+    ///
+    /// The datatype of real numbers, which has no lexical forms.
+    ///
+    /// `owl:real` (`REAL`)
+    pub static ref REAL: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "real");
+}
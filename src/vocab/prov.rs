@@ -0,0 +1,811 @@
+// Copyright (c) 2018-2023, agnos.ai UK Ltd, all rights reserved.
+//---------------------------------------------------------------
+//! The PROV-O vocabulary, `prov:` <http://www.w3.org/ns/prov#>.
+
+// Generated by rdf_store_rs::Vocabulary, do not edit.
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// The `prov:` namespace `<http://www.w3.org/ns/prov#>`.
+    pub static ref NAMESPACE: ::std::sync::Arc<::rdf_store_rs::Namespace> =
+        ::std::sync::Arc::new(::rdf_store_rs::Namespace::declare_from_str_unchecked("prov:", "http://www.w3.org/ns/prov#"));
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// actedOnBehalfOf
+    ///
+    /// An object property to express the accountability of an agent towards another agent. The subordinate agent acted on behalf of the responsible agent in an actual activity.
+    ///
+    /// `prov:actedOnBehalfOf` (`ACTED_ON_BEHALF_OF`)
+    pub static ref ACTED_ON_BEHALF_OF: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "actedOnBehalfOf");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// activity
+    ///
+    /// The activity of an activity influence, such as a generation or a communication.
+    ///
+    /// `prov:activity` (`ACTIVITY_PROPERTY`)
+    pub static ref ACTIVITY_PROPERTY: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "activity");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// agent
+    ///
+    /// The agent of an agent influence, such as an attribution or an association.
+    ///
+    /// `prov:agent` (`AGENT_PROPERTY`)
+    pub static ref AGENT_PROPERTY: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "agent");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// alternateOf
+    ///
+    /// Two alternate entities present aspects of the same thing. These aspects may be the same or different, and the alternate entities may or may not overlap in time.
+    ///
+    /// `prov:alternateOf` (`ALTERNATE_OF`)
+    pub static ref ALTERNATE_OF: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "alternateOf");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// atLocation
+    ///
+    /// The Location of any resource.
+    ///
+    /// `prov:atLocation` (`AT_LOCATION`)
+    pub static ref AT_LOCATION: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "atLocation");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// atTime
+    ///
+    /// The time at which an InstantaneousEvent occurred, in the form of xsd:dateTime.
+    ///
+    /// `prov:atTime` (`AT_TIME`)
+    pub static ref AT_TIME: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "atTime");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// endedAtTime
+    ///
+    /// The time at which an activity ended. See also prov:startedAtTime.
+    ///
+    /// `prov:endedAtTime` (`ENDED_AT_TIME`)
+    pub static ref ENDED_AT_TIME: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "endedAtTime");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// entity
+    ///
+    /// The entity of an entity influence, such as a usage or a derivation.
+    ///
+    /// `prov:entity` (`ENTITY_PROPERTY`)
+    pub static ref ENTITY_PROPERTY: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "entity");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// generated
+    ///
+    /// The inverse of prov:wasGeneratedBy.
+    ///
+    /// `prov:generated` (`GENERATED`)
+    pub static ref GENERATED: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "generated");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// generatedAtTime
+    ///
+    /// The time at which an entity was completely created and is available for use.
+    ///
+    /// `prov:generatedAtTime` (`GENERATED_AT_TIME`)
+    pub static ref GENERATED_AT_TIME: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "generatedAtTime");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// hadActivity
+    ///
+    /// The optional Activity of an Influence, which used, generated, invalidated, or was the responsibility of some Entity. This property is not used by ActivityInfluence (use prov:activity instead).
+    ///
+    /// `prov:hadActivity` (`HAD_ACTIVITY`)
+    pub static ref HAD_ACTIVITY: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "hadActivity");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// hadGeneration
+    ///
+    /// The optional Generation involved in an Entity's Derivation.
+    ///
+    /// `prov:hadGeneration` (`HAD_GENERATION`)
+    pub static ref HAD_GENERATION: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "hadGeneration");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// hadMember
+    ///
+    /// A member of a collection.
+    ///
+    /// `prov:hadMember` (`HAD_MEMBER`)
+    pub static ref HAD_MEMBER: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "hadMember");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// hadPlan
+    ///
+    /// The optional Plan adopted by an Agent in Association with some Activity. Plan specifications are out of the scope of this specification.
+    ///
+    /// `prov:hadPlan` (`HAD_PLAN`)
+    pub static ref HAD_PLAN: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "hadPlan");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// hadPrimarySource
+    ///
+    /// An entity that is a primary source of another entity.
+    ///
+    /// `prov:hadPrimarySource` (`HAD_PRIMARY_SOURCE`)
+    pub static ref HAD_PRIMARY_SOURCE: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "hadPrimarySource");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// hadRole
+    ///
+    /// The optional Role that an Entity assumed in the context of an Activity. For example, :baking prov:used :spoon; prov:qualified [ a prov:Usage; prov:entity :spoon; prov:hadRole roles:mixing_implement ].
+    ///
+    /// `prov:hadRole` (`HAD_ROLE`)
+    pub static ref HAD_ROLE: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "hadRole");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// hadUsage
+    ///
+    /// The optional Usage involved in an Entity's Derivation.
+    ///
+    /// `prov:hadUsage` (`HAD_USAGE`)
+    pub static ref HAD_USAGE: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "hadUsage");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// influenced
+    ///
+    /// The inverse of prov:wasInfluencedBy.
+    ///
+    /// `prov:influenced` (`INFLUENCED`)
+    pub static ref INFLUENCED: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "influenced");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// influencer
+    ///
+    /// Subproperties of prov:influencer are used to cite the object of an unqualified PROV-O triple whose predicate is a subproperty of prov:wasInfluencedBy.
+    ///
+    /// `prov:influencer` (`INFLUENCER`)
+    pub static ref INFLUENCER: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "influencer");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// invalidated
+    ///
+    /// The inverse of prov:wasInvalidatedBy.
+    ///
+    /// `prov:invalidated` (`INVALIDATED`)
+    pub static ref INVALIDATED: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "invalidated");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// invalidatedAtTime
+    ///
+    /// The time at which an entity was invalidated (i.e., no longer usable).
+    ///
+    /// `prov:invalidatedAtTime` (`INVALIDATED_AT_TIME`)
+    pub static ref INVALIDATED_AT_TIME: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "invalidatedAtTime");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// qualifiedAssociation
+    ///
+    /// If this Activity prov:wasAssociatedWith Agent :ag, then it can qualify the Association using prov:qualifiedAssociation [ a prov:Association; prov:agent :ag; :foo :bar ].
+    ///
+    /// `prov:qualifiedAssociation` (`QUALIFIED_ASSOCIATION`)
+    pub static ref QUALIFIED_ASSOCIATION: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "qualifiedAssociation");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// qualifiedAttribution
+    ///
+    /// If this Entity prov:wasAttributedTo Agent :ag, then it can qualify how it was influenced using prov:qualifiedAttribution [ a prov:Attribution; prov:agent :ag; :foo :bar ].
+    ///
+    /// `prov:qualifiedAttribution` (`QUALIFIED_ATTRIBUTION`)
+    pub static ref QUALIFIED_ATTRIBUTION: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "qualifiedAttribution");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// qualifiedCommunication
+    ///
+    /// If this Activity prov:wasInformedBy Activity :a, then it can qualify how it was influenced using prov:qualifiedCommunication [ a prov:Communication; prov:activity :a; :foo :bar ].
+    ///
+    /// `prov:qualifiedCommunication` (`QUALIFIED_COMMUNICATION`)
+    pub static ref QUALIFIED_COMMUNICATION: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "qualifiedCommunication");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// qualifiedDelegation
+    ///
+    /// If this Agent prov:actedOnBehalfOf Agent :ag, then it can qualify how with prov:qualifiedResponsibility [ a prov:Responsibility; prov:agent :ag; :foo :bar ].
+    ///
+    /// `prov:qualifiedDelegation` (`QUALIFIED_DELEGATION`)
+    pub static ref QUALIFIED_DELEGATION: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "qualifiedDelegation");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// qualifiedDerivation
+    ///
+    /// If this Entity prov:wasDerivedFrom Entity :e, then it can qualify how it was derived using prov:qualifiedDerivation [ a prov:Derivation; prov:entity :e; :foo :bar ].
+    ///
+    /// `prov:qualifiedDerivation` (`QUALIFIED_DERIVATION`)
+    pub static ref QUALIFIED_DERIVATION: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "qualifiedDerivation");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// qualifiedEnd
+    ///
+    /// If this Activity prov:wasEndedBy Entity :e1, then it can qualify how it was ended using prov:qualifiedEnd [ a prov:End; prov:entity :e1; :foo :bar ].
+    ///
+    /// `prov:qualifiedEnd` (`QUALIFIED_END`)
+    pub static ref QUALIFIED_END: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "qualifiedEnd");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// qualifiedGeneration
+    ///
+    /// If this Activity prov:generated Entity :e, then it can qualify how it performed the Generation using prov:qualifiedGeneration [ a prov:Generation; prov:entity :e; :foo :bar ].
+    ///
+    /// `prov:qualifiedGeneration` (`QUALIFIED_GENERATION`)
+    pub static ref QUALIFIED_GENERATION: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "qualifiedGeneration");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// qualifiedInfluence
+    ///
+    /// Because prov:qualifiedInfluence is a broad relation, the more specific relations (qualifiedCommunication, qualifiedDelegation, qualifiedEnd, etc.) should be used when applicable.
+    ///
+    /// `prov:qualifiedInfluence` (`QUALIFIED_INFLUENCE`)
+    pub static ref QUALIFIED_INFLUENCE: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "qualifiedInfluence");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// qualifiedInvalidation
+    ///
+    /// If this Entity prov:wasInvalidatedBy Activity :a, then it can qualify how it was invalidated using prov:qualifiedInvalidation [ a prov:Invalidation; prov:activity :a; :foo :bar ].
+    ///
+    /// `prov:qualifiedInvalidation` (`QUALIFIED_INVALIDATION`)
+    pub static ref QUALIFIED_INVALIDATION: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "qualifiedInvalidation");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// qualifiedPrimarySource
+    ///
+    /// If this Entity prov:hadPrimarySource Entity :e, then it can qualify how using prov:qualifiedPrimarySource [ a prov:PrimarySource; prov:entity :e; :foo :bar ].
+    ///
+    /// `prov:qualifiedPrimarySource` (`QUALIFIED_PRIMARY_SOURCE`)
+    pub static ref QUALIFIED_PRIMARY_SOURCE: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "qualifiedPrimarySource");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// qualifiedQuotation
+    ///
+    /// If this Entity prov:wasQuotedFrom Entity :e, then it can qualify how using prov:qualifiedQuotation [ a prov:Quotation; prov:entity :e; :foo :bar ].
+    ///
+    /// `prov:qualifiedQuotation` (`QUALIFIED_QUOTATION`)
+    pub static ref QUALIFIED_QUOTATION: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "qualifiedQuotation");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// qualifiedRevision
+    ///
+    /// If this Entity prov:wasRevisionOf Entity :e, then it can qualify how it was revised using prov:qualifiedRevision [ a prov:Revision; prov:entity :e; :foo :bar ].
+    ///
+    /// `prov:qualifiedRevision` (`QUALIFIED_REVISION`)
+    pub static ref QUALIFIED_REVISION: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "qualifiedRevision");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// qualifiedStart
+    ///
+    /// If this Activity prov:wasStartedBy Entity :e1, then it can qualify how it was started using prov:qualifiedStart [ a prov:Start; prov:entity :e1; :foo :bar ].
+    ///
+    /// `prov:qualifiedStart` (`QUALIFIED_START`)
+    pub static ref QUALIFIED_START: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "qualifiedStart");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// qualifiedUsage
+    ///
+    /// If this Activity prov:used Entity :e, then it can qualify how it used it using prov:qualifiedUsage [ a prov:Usage; prov:entity :e; :foo :bar ].
+    ///
+    /// `prov:qualifiedUsage` (`QUALIFIED_USAGE`)
+    pub static ref QUALIFIED_USAGE: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "qualifiedUsage");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// specializationOf
+    ///
+    /// An entity that is a specialization of another shares all aspects of the latter, and additionally presents more specific aspects of the same thing as the latter. In particular, the lifetime of the entity being specialized contains that of any specialization.
+    ///
+    /// `prov:specializationOf` (`SPECIALIZATION_OF`)
+    pub static ref SPECIALIZATION_OF: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "specializationOf");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// startedAtTime
+    ///
+    /// The time at which an activity started. See also prov:endedAtTime.
+    ///
+    /// `prov:startedAtTime` (`STARTED_AT_TIME`)
+    pub static ref STARTED_AT_TIME: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "startedAtTime");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// used
+    ///
+    /// A prov:Entity that was used by this prov:Activity. For example, :baking prov:used :spoon, :egg, :oven .
+    ///
+    /// `prov:used` (`USED`)
+    pub static ref USED: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "used");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// value
+    ///
+    /// Provides a value that is a direct representation of an entity.
+    ///
+    /// `prov:value` (`VALUE`)
+    pub static ref VALUE: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "value");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// wasAssociatedWith
+    ///
+    /// An prov:Agent that had some (unspecified) responsibility for the occurrence of this prov:Activity.
+    ///
+    /// `prov:wasAssociatedWith` (`WAS_ASSOCIATED_WITH`)
+    pub static ref WAS_ASSOCIATED_WITH: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "wasAssociatedWith");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// wasAttributedTo
+    ///
+    /// Attribution is the ascribing of an entity to an agent.
+    ///
+    /// `prov:wasAttributedTo` (`WAS_ATTRIBUTED_TO`)
+    pub static ref WAS_ATTRIBUTED_TO: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "wasAttributedTo");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// wasDerivedFrom
+    ///
+    /// The more specific subproperties of prov:wasDerivedFrom (i.e., prov:wasQuotedFrom, prov:wasRevisionOf, prov:hadPrimarySource) should be used when applicable.
+    ///
+    /// `prov:wasDerivedFrom` (`WAS_DERIVED_FROM`)
+    pub static ref WAS_DERIVED_FROM: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "wasDerivedFrom");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// wasEndedBy
+    ///
+    /// End is when an activity is deemed to have ended. An end may refer to an entity, known as trigger, that terminated the activity.
+    ///
+    /// `prov:wasEndedBy` (`WAS_ENDED_BY`)
+    pub static ref WAS_ENDED_BY: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "wasEndedBy");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// wasGeneratedBy
+    ///
+    /// Generation is the completion of production of a new entity by an activity. This entity did not exist before generation and becomes available for usage after this generation.
+    ///
+    /// `prov:wasGeneratedBy` (`WAS_GENERATED_BY`)
+    pub static ref WAS_GENERATED_BY: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "wasGeneratedBy");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// wasInfluencedBy
+    ///
+    /// Because prov:wasInfluencedBy is a broad relation, its more specific subproperties (e.g. prov:wasInformedBy, prov:actedOnBehalfOf, prov:wasEndedBy, etc.) should be used when applicable.
+    ///
+    /// `prov:wasInfluencedBy` (`WAS_INFLUENCED_BY`)
+    pub static ref WAS_INFLUENCED_BY: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "wasInfluencedBy");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// wasInformedBy
+    ///
+    /// An activity a2 is dependent on or informed by another activity a1, by way of some unspecified entity that is generated by a1 and used by a2.
+    ///
+    /// `prov:wasInformedBy` (`WAS_INFORMED_BY`)
+    pub static ref WAS_INFORMED_BY: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "wasInformedBy");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// wasInvalidatedBy
+    ///
+    /// Invalidation is the start of the destruction, cessation, or expiry of an existing entity by an activity. The entity is no longer available for use (or further invalidation) after invalidation.
+    ///
+    /// `prov:wasInvalidatedBy` (`WAS_INVALIDATED_BY`)
+    pub static ref WAS_INVALIDATED_BY: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "wasInvalidatedBy");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// wasQuotedFrom
+    ///
+    /// An entity is derived from an original entity by copying, or 'quoting', some or all of it.
+    ///
+    /// `prov:wasQuotedFrom` (`WAS_QUOTED_FROM`)
+    pub static ref WAS_QUOTED_FROM: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "wasQuotedFrom");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// wasRevisionOf
+    ///
+    /// A revision is a derivation that revises an entity into a revised version.
+    ///
+    /// `prov:wasRevisionOf` (`WAS_REVISION_OF`)
+    pub static ref WAS_REVISION_OF: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "wasRevisionOf");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// wasStartedBy
+    ///
+    /// Start is when an activity is deemed to have started. A start may refer to an entity, known as trigger, that initiated the activity.
+    ///
+    /// `prov:wasStartedBy` (`WAS_STARTED_BY`)
+    pub static ref WAS_STARTED_BY: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "wasStartedBy");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// Activity
+    ///
+    /// An activity is something that occurs over a period of time and acts upon or with entities; it may include consuming, processing, transforming, modifying, relocating, using, or generating entities.
+    ///
+    /// `prov:Activity` (`ACTIVITY_CLASS`)
+    pub static ref ACTIVITY_CLASS: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "Activity");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// ActivityInfluence
+    ///
+    /// ActivityInfluence is the capacity of an activity to have an effect on the character, development, or behavior of another by means of generation, invalidation, communication, or other.
+    ///
+    /// `prov:ActivityInfluence` (`ACTIVITY_INFLUENCE`)
+    pub static ref ACTIVITY_INFLUENCE: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "ActivityInfluence");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// Agent
+    ///
+    /// An agent is something that bears some form of responsibility for an activity taking place, for the existence of an entity, or for another agent's activity.
+    ///
+    /// `prov:Agent` (`AGENT_CLASS`)
+    pub static ref AGENT_CLASS: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "Agent");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// AgentInfluence
+    ///
+    /// AgentInfluence is the capacity of an agent to have an effect on the character, development, or behavior of another by means of attribution, association, delegation, or other.
+    ///
+    /// `prov:AgentInfluence` (`AGENT_INFLUENCE`)
+    pub static ref AGENT_INFLUENCE: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "AgentInfluence");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// Association
+    ///
+    /// An activity association is an assignment of responsibility to an agent for an activity, indicating that the agent had a role in the activity. It further allows for a plan to be specified, which is the plan intended by the agent to achieve some goals in the context of this activity.
+    ///
+    /// `prov:Association` (`ASSOCIATION`)
+    pub static ref ASSOCIATION: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "Association");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// Attribution
+    ///
+    /// Attribution is the ascribing of an entity to an agent.
+    ///
+    /// `prov:Attribution` (`ATTRIBUTION`)
+    pub static ref ATTRIBUTION: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "Attribution");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// Bundle
+    ///
+    /// A bundle is a named set of provenance descriptions, and is itself an Entity, so allowing provenance of provenance to be expressed.
+    ///
+    /// `prov:Bundle` (`BUNDLE`)
+    pub static ref BUNDLE: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "Bundle");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// Collection
+    ///
+    /// A collection is an entity that provides a structure to some constituents, which are themselves entities. These constituents are said to be member of the collections.
+    ///
+    /// `prov:Collection` (`COLLECTION`)
+    pub static ref COLLECTION: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "Collection");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// Communication
+    ///
+    /// Communication is the exchange of an entity by two activities, one activity using the entity generated by the other.
+    ///
+    /// `prov:Communication` (`COMMUNICATION`)
+    pub static ref COMMUNICATION: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "Communication");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// Delegation
+    ///
+    /// Delegation is the assignment of authority and responsibility to an agent (by itself or by another agent) to carry out a specific activity as a delegate or representative, while the agent it acts on behalf of retains some responsibility for the outcome of the delegated work.
+    ///
+    /// `prov:Delegation` (`DELEGATION`)
+    pub static ref DELEGATION: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "Delegation");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// Derivation
+    ///
+    /// A derivation is a transformation of an entity into another, an update of an entity resulting in a new one, or the construction of a new entity based on a pre-existing entity.
+    ///
+    /// `prov:Derivation` (`DERIVATION`)
+    pub static ref DERIVATION: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "Derivation");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// EmptyCollection
+    ///
+    /// An empty collection is a collection without members.
+    ///
+    /// `prov:EmptyCollection` (`EMPTY_COLLECTION`)
+    pub static ref EMPTY_COLLECTION: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "EmptyCollection");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// End
+    ///
+    /// End is when an activity is deemed to have been ended by an entity, known as trigger. The activity no longer exists after its end. Any usage, generation, or invalidation involving an activity precedes the activity's end. An end may refer to a trigger entity that terminated the activity, or to an activity, known as ender that generated the trigger.
+    ///
+    /// `prov:End` (`END`)
+    pub static ref END: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "End");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// Entity
+    ///
+    /// An entity is a physical, digital, conceptual, or other kind of thing with some fixed aspects; entities may be real or imaginary.
+    ///
+    /// `prov:Entity` (`ENTITY_CLASS`)
+    pub static ref ENTITY_CLASS: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "Entity");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// EntityInfluence
+    ///
+    /// EntityInfluence is the capacity of an entity to have an effect on the character, development, or behavior of another by means of usage, start, end, derivation, or other.
+    ///
+    /// `prov:EntityInfluence` (`ENTITY_INFLUENCE`)
+    pub static ref ENTITY_INFLUENCE: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "EntityInfluence");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// Generation
+    ///
+    /// Generation is the completion of production of a new entity by an activity. This entity did not exist before generation and becomes available for usage after this generation.
+    ///
+    /// `prov:Generation` (`GENERATION`)
+    pub static ref GENERATION: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "Generation");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// Influence
+    ///
+    /// An instance of prov:Influence provides additional descriptions about the binary prov:wasInfluencedBy relation from some influenced Activity, Entity, or Agent to the influencing Activity, Entity, or Agent.
+    ///
+    /// `prov:Influence` (`INFLUENCE`)
+    pub static ref INFLUENCE: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "Influence");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// InstantaneousEvent
+    ///
+    /// An instantaneous event, or event for short, happens in the world and marks a change in the world, in its activities and in its entities. The term 'event' is commonly used in process algebra with a similar meaning. Events represent communications or interactions; they are assumed to be atomic and instantaneous.
+    ///
+    /// `prov:InstantaneousEvent` (`INSTANTANEOUS_EVENT`)
+    pub static ref INSTANTANEOUS_EVENT: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "InstantaneousEvent");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// Invalidation
+    ///
+    /// Invalidation is the start of the destruction, cessation, or expiry of an existing entity by an activity. The entity is no longer available for use (or further invalidation) after invalidation. Any generation or usage of an entity precedes its invalidation.
+    ///
+    /// `prov:Invalidation` (`INVALIDATION`)
+    pub static ref INVALIDATION: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "Invalidation");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// Location
+    ///
+    /// A location can be an identifiable geographic place (ISO 19112), but it can also be a non-geographic place such as a directory, row, or column. As such, there are numerous ways in which location can be expressed, such as by a coordinate, address, landmark, and so forth.
+    ///
+    /// `prov:Location` (`LOCATION`)
+    pub static ref LOCATION: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "Location");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// Organization
+    ///
+    /// An organization is a social or legal institution such as a company, society, etc.
+    ///
+    /// `prov:Organization` (`ORGANIZATION`)
+    pub static ref ORGANIZATION: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "Organization");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// Person
+    ///
+    /// Person agents are people.
+    ///
+    /// `prov:Person` (`PERSON`)
+    pub static ref PERSON: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "Person");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// Plan
+    ///
+    /// A plan is an entity that represents a set of actions or steps intended by one or more agents to achieve some goals.
+    ///
+    /// `prov:Plan` (`PLAN`)
+    pub static ref PLAN: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "Plan");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// PrimarySource
+    ///
+    /// A primary source for a topic refers to something produced by some agent with direct experience and knowledge about the topic, at the time of the topic's study, without benefit from hindsight.
+    ///
+    /// `prov:PrimarySource` (`PRIMARY_SOURCE`)
+    pub static ref PRIMARY_SOURCE: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "PrimarySource");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// Quotation
+    ///
+    /// A quotation is the repeat of (some or all of) an entity, such as text or image, by someone who may or may not be its original author.
+    ///
+    /// `prov:Quotation` (`QUOTATION`)
+    pub static ref QUOTATION: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "Quotation");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// Revision
+    ///
+    /// A revision is a derivation for which the resulting entity is a revised version of some original. The implication here is that the resulting entity contains substantial content from the original.
+    ///
+    /// `prov:Revision` (`REVISION`)
+    pub static ref REVISION: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "Revision");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// Role
+    ///
+    /// A role is the function of an entity or agent with respect to an activity, in the context of a usage, generation, invalidation, association, start, and end.
+    ///
+    /// `prov:Role` (`ROLE`)
+    pub static ref ROLE: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "Role");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// SoftwareAgent
+    ///
+    /// A software agent is running software.
+    ///
+    /// `prov:SoftwareAgent` (`SOFTWARE_AGENT`)
+    pub static ref SOFTWARE_AGENT: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "SoftwareAgent");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// Start
+    ///
+    /// Start is when an activity is deemed to have been started by an entity, known as trigger. The activity did not exist before its start. Any usage, generation, or invalidation involving an activity follows the activity's start. A start may refer to a trigger entity that set off the activity, or to an activity, known as starter, that generated the trigger.
+    ///
+    /// `prov:Start` (`START`)
+    pub static ref START: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "Start");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// Usage
+    ///
+    /// Usage is the beginning of utilizing an entity by an activity. Before usage, the activity had not begun to utilize this entity and could not have been affected by the entity.
+    ///
+    /// `prov:Usage` (`USAGE`)
+    pub static ref USAGE: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "Usage");
+}
@@ -0,0 +1,231 @@
+// Copyright (c) 2018-2023, agnos.ai UK Ltd, all rights reserved.
+//---------------------------------------------------------------
+//! The RDF vocabulary, `rdf:` <http://www.w3.org/1999/02/22-rdf-syntax-ns#>.
+
+// Generated by rdf_store_rs::Vocabulary, do not edit.
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// The `rdf:` namespace `<http://www.w3.org/1999/02/22-rdf-syntax-ns#>`.
+    pub static ref NAMESPACE: ::std::sync::Arc<::rdf_store_rs::Namespace> =
+        ::std::sync::Arc::new(::rdf_store_rs::Namespace::declare_from_str_unchecked("rdf:", "http://www.w3.org/1999/02/22-rdf-syntax-ns#"));
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// direction
+    ///
+    /// The base direction component of a CompoundLiteral.
+    ///
+    /// `rdf:direction` (`DIRECTION`)
+    pub static ref DIRECTION: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "direction");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// first
+    ///
+    /// The first item in the subject RDF list.
+    ///
+    /// `rdf:first` (`FIRST`)
+    pub static ref FIRST: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "first");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// language
+    ///
+    /// The language component of a CompoundLiteral.
+    ///
+    /// `rdf:language` (`LANGUAGE`)
+    pub static ref LANGUAGE: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "language");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// object
+    ///
+    /// The object of the subject RDF statement.
+    ///
+    /// `rdf:object` (`OBJECT`)
+    pub static ref OBJECT: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "object");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// predicate
+    ///
+    /// The predicate of the subject RDF statement.
+    ///
+    /// `rdf:predicate` (`PREDICATE`)
+    pub static ref PREDICATE: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "predicate");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// rest
+    ///
+    /// The rest of the subject RDF list after the first item.
+    ///
+    /// `rdf:rest` (`REST`)
+    pub static ref REST: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "rest");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// subject
+    ///
+    /// The subject of the subject RDF statement.
+    ///
+    /// `rdf:subject` (`SUBJECT`)
+    pub static ref SUBJECT: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "subject");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// type
+    ///
+    /// The subject is an instance of a class.
+    ///
+    /// `rdf:type` (`TYPE`)
+    pub static ref TYPE: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "type");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// value
+    ///
+    /// Idiomatic property used for structured values.
+    ///
+    /// `rdf:value` (`VALUE`)
+    pub static ref VALUE: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "value");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// Alt
+    ///
+    /// The class of containers of alternatives.
+    ///
+    /// `rdf:Alt` (`ALT`)
+    pub static ref ALT: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "Alt");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// Bag
+    ///
+    /// The class of unordered containers.
+    ///
+    /// `rdf:Bag` (`BAG`)
+    pub static ref BAG: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "Bag");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// CompoundLiteral
+    ///
+    /// A class representing a compound literal.
+    ///
+    /// `rdf:CompoundLiteral` (`COMPOUND_LITERAL`)
+    pub static ref COMPOUND_LITERAL: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "CompoundLiteral");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// HTML
+    ///
+    /// The datatype of RDF literals storing fragments of HTML content.
+    ///
+    /// `rdf:HTML` (`HTML`)
+    pub static ref HTML: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "HTML");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// JSON
+    ///
+    /// The datatype of RDF literals storing JSON content.
+    ///
+    /// `rdf:JSON` (`JSON`)
+    pub static ref JSON: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "JSON");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// List
+    ///
+    /// The class of RDF Lists.
+    ///
+    /// `rdf:List` (`LIST`)
+    pub static ref LIST: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "List");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// PlainLiteral
+    ///
+    /// The class of plain (i.e. untyped) literal values, as used in RIF and OWL 2.
+    ///
+    /// `rdf:PlainLiteral` (`PLAIN_LITERAL`)
+    pub static ref PLAIN_LITERAL: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "PlainLiteral");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// Property
+    ///
+    /// The class of RDF properties.
+    ///
+    /// `rdf:Property` (`PROPERTY`)
+    pub static ref PROPERTY: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "Property");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// Seq
+    ///
+    /// The class of ordered containers.
+    ///
+    /// `rdf:Seq` (`SEQ`)
+    pub static ref SEQ: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "Seq");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// Statement
+    ///
+    /// The class of RDF statements.
+    ///
+    /// `rdf:Statement` (`STATEMENT`)
+    pub static ref STATEMENT: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "Statement");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// XMLLiteral
+    ///
+    /// The datatype of XML literal values.
+    ///
+    /// `rdf:XMLLiteral` (`XML_LITERAL`)
+    pub static ref XML_LITERAL: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "XMLLiteral");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// dirLangString
+    ///
+    /// The datatype of directional language-tagged string values.
+    ///
+    /// `rdf:dirLangString` (`DIR_LANG_STRING`)
+    pub static ref DIR_LANG_STRING: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "dirLangString");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// langString
+    ///
+    /// The datatype of language-tagged string values.
+    ///
+    /// `rdf:langString` (`LANG_STRING`)
+    pub static ref LANG_STRING: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "langString");
+}
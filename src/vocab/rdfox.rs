@@ -0,0 +1,29 @@
+// Copyright (c) 2018-2023, agnos.ai UK Ltd, all rights reserved.
+//---------------------------------------------------------------
+//! The RDFox vocabulary, `rdfox:` <http://oxfordsemantic.tech/RDFox#>.
+//!
+//! RDFox has no published ontology, so unlike the other vocabularies this
+//! one is maintained by hand.
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// The `rdfox:` namespace `<http://oxfordsemantic.tech/RDFox#>`.
+    pub static ref NAMESPACE: ::std::sync::Arc<::rdf_store_rs::Namespace> =
+        ::std::sync::Arc::new(::rdf_store_rs::Namespace::declare_from_str_unchecked("rdfox:", "http://oxfordsemantic.tech/RDFox#"));
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// The class of the resources that RDFox derives when a constraint
+    /// (a rule with `rdfox:ConstraintViolation` in its head) is violated.
+    ///
+    /// `rdfox:ConstraintViolation` (`CONSTRAINT_VIOLATION`)
+    pub static ref CONSTRAINT_VIOLATION: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "ConstraintViolation");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// The named graph that RDFox uses for the triples of the default graph.
+    ///
+    /// `rdfox:DefaultTriples` (`DEFAULT_TRIPLES`)
+    pub static ref DEFAULT_TRIPLES: ::rdf_store_rs::Graph =
+        ::rdf_store_rs::Graph::declare(NAMESPACE.clone(), "DefaultTriples");
+}
@@ -0,0 +1,161 @@
+// Copyright (c) 2018-2023, agnos.ai UK Ltd, all rights reserved.
+//---------------------------------------------------------------
+//! The RDF Schema vocabulary, `rdfs:` <http://www.w3.org/2000/01/rdf-schema#>.
+
+// Generated by rdf_store_rs::Vocabulary, do not edit.
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// The `rdfs:` namespace `<http://www.w3.org/2000/01/rdf-schema#>`.
+    pub static ref NAMESPACE: ::std::sync::Arc<::rdf_store_rs::Namespace> =
+        ::std::sync::Arc::new(::rdf_store_rs::Namespace::declare_from_str_unchecked("rdfs:", "http://www.w3.org/2000/01/rdf-schema#"));
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// comment
+    ///
+    /// A description of the subject resource.
+    ///
+    /// `rdfs:comment` (`COMMENT`)
+    pub static ref COMMENT: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "comment");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// domain
+    ///
+    /// A domain of the subject property.
+    ///
+    /// `rdfs:domain` (`DOMAIN`)
+    pub static ref DOMAIN: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "domain");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// isDefinedBy
+    ///
+    /// The definition of the subject resource.
+    ///
+    /// `rdfs:isDefinedBy` (`IS_DEFINED_BY`)
+    pub static ref IS_DEFINED_BY: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "isDefinedBy");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// label
+    ///
+    /// A human-readable name for the subject.
+    ///
+    /// `rdfs:label` (`LABEL`)
+    pub static ref LABEL: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "label");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// member
+    ///
+    /// A member of the subject resource.
+    ///
+    /// `rdfs:member` (`MEMBER`)
+    pub static ref MEMBER: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "member");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// range
+    ///
+    /// A range of the subject property.
+    ///
+    /// `rdfs:range` (`RANGE`)
+    pub static ref RANGE: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "range");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// seeAlso
+    ///
+    /// Further information about the subject resource.
+    ///
+    /// `rdfs:seeAlso` (`SEE_ALSO`)
+    pub static ref SEE_ALSO: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "seeAlso");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// subClassOf
+    ///
+    /// The subject is a subclass of a class.
+    ///
+    /// `rdfs:subClassOf` (`SUB_CLASS_OF`)
+    pub static ref SUB_CLASS_OF: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "subClassOf");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// subPropertyOf
+    ///
+    /// The subject is a subproperty of a property.
+    ///
+    /// `rdfs:subPropertyOf` (`SUB_PROPERTY_OF`)
+    pub static ref SUB_PROPERTY_OF: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "subPropertyOf");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// Class
+    ///
+    /// The class of classes.
+    ///
+    /// `rdfs:Class` (`CLASS`)
+    pub static ref CLASS: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "Class");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// Container
+    ///
+    /// The class of RDF containers.
+    ///
+    /// `rdfs:Container` (`CONTAINER`)
+    pub static ref CONTAINER: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "Container");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// ContainerMembershipProperty
+    ///
+    /// The class of container membership properties, rdf:_1, rdf:_2, ..., all of which are sub-properties of 'member'.
+    ///
+    /// `rdfs:ContainerMembershipProperty` (`CONTAINER_MEMBERSHIP_PROPERTY`)
+    pub static ref CONTAINER_MEMBERSHIP_PROPERTY: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "ContainerMembershipProperty");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// Datatype
+    ///
+    /// The class of RDF datatypes.
+    ///
+    /// `rdfs:Datatype` (`DATATYPE`)
+    pub static ref DATATYPE: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "Datatype");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// Literal
+    ///
+    /// The class of literal values, eg. textual strings and integers.
+    ///
+    /// `rdfs:Literal` (`LITERAL`)
+    pub static ref LITERAL: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "Literal");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// Resource
+    ///
+    /// The class resource, everything.
+    ///
+    /// `rdfs:Resource` (`RESOURCE`)
+    pub static ref RESOURCE: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "Resource");
+}
@@ -0,0 +1,24091 @@
+// Copyright (c) 2018-2023, agnos.ai UK Ltd, all rights reserved.
+//---------------------------------------------------------------
+//! The schema.org vocabulary, `schema:` <https://schema.org/>.
+
+// Generated by rdf_store_rs::Vocabulary, do not edit.
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// The `schema:` namespace `<https://schema.org/>`.
+    pub static ref NAMESPACE: ::std::sync::Arc<::rdf_store_rs::Namespace> =
+        ::std::sync::Arc::new(::rdf_store_rs::Namespace::declare_from_str_unchecked("schema:", "https://schema.org/"));
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// about
+    ///
+    /// The subject matter of the content.
+    ///
+    /// `schema:about` (`ABOUT`)
+    pub static ref ABOUT: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "about");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// abridged
+    ///
+    /// Indicates whether the book is an abridged edition.
+    ///
+    /// `schema:abridged` (`ABRIDGED`)
+    pub static ref ABRIDGED: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "abridged");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// abstract
+    ///
+    /// An abstract is a short description that summarizes a CreativeWork.
+    ///
+    /// `schema:abstract` (`ABSTRACT`)
+    pub static ref ABSTRACT: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "abstract");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// accelerationTime
+    ///
+    /// The time needed to accelerate the vehicle from a given start velocity to a given target velocity.
+    ///
+    /// `schema:accelerationTime` (`ACCELERATION_TIME`)
+    pub static ref ACCELERATION_TIME: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "accelerationTime");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// acceptedAnswer
+    ///
+    /// The answer(s) that has been accepted as best, typically on a Question/Answer site. Sites vary in their selection mechanisms, e.g. drawing on community opinion and/or the view of the Question author.
+    ///
+    /// `schema:acceptedAnswer` (`ACCEPTED_ANSWER`)
+    pub static ref ACCEPTED_ANSWER: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "acceptedAnswer");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// acceptedOffer
+    ///
+    /// The offer(s) -- e.g., product, quantity and price combinations -- included in the order.
+    ///
+    /// `schema:acceptedOffer` (`ACCEPTED_OFFER`)
+    pub static ref ACCEPTED_OFFER: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "acceptedOffer");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// acceptedPaymentMethod
+    ///
+    /// The payment method(s) that are accepted in general by an organization, or for some specific demand or offer.
+    ///
+    /// `schema:acceptedPaymentMethod` (`ACCEPTED_PAYMENT_METHOD`)
+    pub static ref ACCEPTED_PAYMENT_METHOD: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "acceptedPaymentMethod");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// acceptsReservations
+    ///
+    /// Indicates whether a FoodEstablishment accepts reservations. Values can be Boolean, an URL at which reservations can be made or (for backwards compatibility) the strings Yes or No.
+    ///
+    /// `schema:acceptsReservations` (`ACCEPTS_RESERVATIONS`)
+    pub static ref ACCEPTS_RESERVATIONS: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "acceptsReservations");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// accessCode
+    ///
+    /// Password, PIN, or access code needed for delivery (e.g. from a locker).
+    ///
+    /// `schema:accessCode` (`ACCESS_CODE`)
+    pub static ref ACCESS_CODE: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "accessCode");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// accessMode
+    ///
+    /// The human sensory perceptual system or cognitive faculty through which a person may process or perceive information. Values should be drawn from the approved vocabulary.
+    ///
+    /// `schema:accessMode` (`ACCESS_MODE`)
+    pub static ref ACCESS_MODE: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "accessMode");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// accessModeSufficient
+    ///
+    /// A list of single or combined accessModes that are sufficient to understand all the intellectual content of a resource. Values should be drawn from the approved vocabulary.
+    ///
+    /// `schema:accessModeSufficient` (`ACCESS_MODE_SUFFICIENT`)
+    pub static ref ACCESS_MODE_SUFFICIENT: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "accessModeSufficient");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// accessibilityAPI
+    ///
+    /// Indicates that the resource is compatible with the referenced accessibility API. Values should be drawn from the approved vocabulary.
+    ///
+    /// `schema:accessibilityAPI` (`ACCESSIBILITY_API`)
+    pub static ref ACCESSIBILITY_API: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "accessibilityAPI");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// accessibilityControl
+    ///
+    /// Identifies input methods that are sufficient to fully control the described resource. Values should be drawn from the approved vocabulary.
+    ///
+    /// `schema:accessibilityControl` (`ACCESSIBILITY_CONTROL`)
+    pub static ref ACCESSIBILITY_CONTROL: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "accessibilityControl");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// accessibilityFeature
+    ///
+    /// Content features of the resource, such as accessible media, alternatives and supported enhancements for accessibility. Values should be drawn from the approved vocabulary.
+    ///
+    /// `schema:accessibilityFeature` (`ACCESSIBILITY_FEATURE`)
+    pub static ref ACCESSIBILITY_FEATURE: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "accessibilityFeature");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// accessibilityHazard
+    ///
+    /// A characteristic of the described resource that is physiologically dangerous to some users. Related to WCAG 2.0 guideline 2.3. Values should be drawn from the approved vocabulary.
+    ///
+    /// `schema:accessibilityHazard` (`ACCESSIBILITY_HAZARD`)
+    pub static ref ACCESSIBILITY_HAZARD: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "accessibilityHazard");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// accessibilitySummary
+    ///
+    /// A human-readable summary of specific accessibility features or deficiencies, consistent with the other accessibility metadata but expressing subtleties such as "short descriptions are present but long descriptions will be needed for non-visual users" or "short descriptions are present and no long descriptions are needed".
+    ///
+    /// `schema:accessibilitySummary` (`ACCESSIBILITY_SUMMARY`)
+    pub static ref ACCESSIBILITY_SUMMARY: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "accessibilitySummary");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// accommodationCategory
+    ///
+    /// Category of an Accommodation, following real estate conventions, e.g. RESO (see PropertySubType, and PropertyType fields for suggested values).
+    ///
+    /// `schema:accommodationCategory` (`ACCOMMODATION_CATEGORY`)
+    pub static ref ACCOMMODATION_CATEGORY: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "accommodationCategory");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// accommodationFloorPlan
+    ///
+    /// A floorplan of some Accommodation.
+    ///
+    /// `schema:accommodationFloorPlan` (`ACCOMMODATION_FLOOR_PLAN`)
+    pub static ref ACCOMMODATION_FLOOR_PLAN: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "accommodationFloorPlan");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// accountId
+    ///
+    /// The identifier for the account the payment will be applied to.
+    ///
+    /// `schema:accountId` (`ACCOUNT_ID`)
+    pub static ref ACCOUNT_ID: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "accountId");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// accountMinimumInflow
+    ///
+    /// A minimum amount that has to be paid in every month.
+    ///
+    /// `schema:accountMinimumInflow` (`ACCOUNT_MINIMUM_INFLOW`)
+    pub static ref ACCOUNT_MINIMUM_INFLOW: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "accountMinimumInflow");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// accountOverdraftLimit
+    ///
+    /// An overdraft is an extension of credit from a lending institution when an account reaches zero. An overdraft allows the individual to continue withdrawing money even if the account has no funds in it. Basically the bank allows people to borrow a set amount of money.
+    ///
+    /// `schema:accountOverdraftLimit` (`ACCOUNT_OVERDRAFT_LIMIT`)
+    pub static ref ACCOUNT_OVERDRAFT_LIMIT: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "accountOverdraftLimit");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// accountablePerson
+    ///
+    /// Specifies the Person that is legally accountable for the CreativeWork.
+    ///
+    /// `schema:accountablePerson` (`ACCOUNTABLE_PERSON`)
+    pub static ref ACCOUNTABLE_PERSON: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "accountablePerson");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// acquireLicensePage
+    ///
+    /// Indicates a page documenting how licenses can be purchased or otherwise acquired, for the current item.
+    ///
+    /// `schema:acquireLicensePage` (`ACQUIRE_LICENSE_PAGE`)
+    pub static ref ACQUIRE_LICENSE_PAGE: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "acquireLicensePage");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// acquiredFrom
+    ///
+    /// The organization or person from which the product was acquired.
+    ///
+    /// `schema:acquiredFrom` (`ACQUIRED_FROM`)
+    pub static ref ACQUIRED_FROM: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "acquiredFrom");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// acrissCode
+    ///
+    /// The ACRISS Car Classification Code is a code used by many car rental companies, for classifying vehicles. ACRISS stands for Association of Car Rental Industry Systems and Standards.
+    ///
+    /// `schema:acrissCode` (`ACRISS_CODE`)
+    pub static ref ACRISS_CODE: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "acrissCode");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// actionAccessibilityRequirement
+    ///
+    /// A set of requirements that must be fulfilled in order to perform an Action. If more than one value is specified, fulfilling one set of requirements will allow the Action to be performed.
+    ///
+    /// `schema:actionAccessibilityRequirement` (`ACTION_ACCESSIBILITY_REQUIREMENT`)
+    pub static ref ACTION_ACCESSIBILITY_REQUIREMENT: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "actionAccessibilityRequirement");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// actionApplication
+    ///
+    /// An application that can complete the request.
+    ///
+    /// `schema:actionApplication` (`ACTION_APPLICATION`)
+    pub static ref ACTION_APPLICATION: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "actionApplication");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// actionOption
+    ///
+    /// A sub property of object. The options subject to this action.
+    ///
+    /// `schema:actionOption` (`ACTION_OPTION`)
+    pub static ref ACTION_OPTION: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "actionOption");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// actionPlatform
+    ///
+    /// The high level platform(s) where the Action can be performed for the given URL. To specify a specific application or operating system instance, use actionApplication.
+    ///
+    /// `schema:actionPlatform` (`ACTION_PLATFORM`)
+    pub static ref ACTION_PLATFORM: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "actionPlatform");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// actionStatus
+    ///
+    /// Indicates the current disposition of the Action.
+    ///
+    /// `schema:actionStatus` (`ACTION_STATUS`)
+    pub static ref ACTION_STATUS: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "actionStatus");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// actionableFeedbackPolicy
+    ///
+    /// For a NewsMediaOrganization or other news-related Organization, a statement about public engagement activities (for news media, the newsroom's), including involving the public - digitally or otherwise -- in coverage decisions, reporting and activities after publication.
+    ///
+    /// `schema:actionableFeedbackPolicy` (`ACTIONABLE_FEEDBACK_POLICY`)
+    pub static ref ACTIONABLE_FEEDBACK_POLICY: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "actionableFeedbackPolicy");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// activeIngredient
+    ///
+    /// An active ingredient, typically chemical compounds and/or biologic substances.
+    ///
+    /// `schema:activeIngredient` (`ACTIVE_INGREDIENT`)
+    pub static ref ACTIVE_INGREDIENT: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "activeIngredient");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// activityDuration
+    ///
+    /// Length of time to engage in the activity.
+    ///
+    /// `schema:activityDuration` (`ACTIVITY_DURATION`)
+    pub static ref ACTIVITY_DURATION: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "activityDuration");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// activityFrequency
+    ///
+    /// How often one should engage in the activity.
+    ///
+    /// `schema:activityFrequency` (`ACTIVITY_FREQUENCY`)
+    pub static ref ACTIVITY_FREQUENCY: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "activityFrequency");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// actor
+    ///
+    /// An actor (individual or a group), e.g. in TV, radio, movie, video games etc., or in an event. Actors can be associated with individual items or with a series, episode, clip.
+    ///
+    /// `schema:actor` (`ACTOR`)
+    pub static ref ACTOR: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "actor");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// actors
+    ///
+    /// An actor, e.g. in TV, radio, movie, video games etc. Actors can be associated with individual items or with a series, episode, clip.
+    ///
+    /// `schema:actors` (`ACTORS`)
+    pub static ref ACTORS: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "actors");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// addOn
+    ///
+    /// An additional offer that can only be obtained in combination with the first base offer (e.g. supplements and extensions that are available for a surcharge).
+    ///
+    /// `schema:addOn` (`ADD_ON`)
+    pub static ref ADD_ON: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "addOn");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// additionalName
+    ///
+    /// An additional name for a Person, can be used for a middle name.
+    ///
+    /// `schema:additionalName` (`ADDITIONAL_NAME`)
+    pub static ref ADDITIONAL_NAME: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "additionalName");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// additionalNumberOfGuests
+    ///
+    /// If responding yes, the number of guests who will attend in addition to the invitee.
+    ///
+    /// `schema:additionalNumberOfGuests` (`ADDITIONAL_NUMBER_OF_GUESTS`)
+    pub static ref ADDITIONAL_NUMBER_OF_GUESTS: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "additionalNumberOfGuests");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// additionalProperty
+    ///
+    /// A property-value pair representing an additional characteristic of the entity, e.g. a product feature or another characteristic for which there is no matching property in schema.org.
+    ///
+    /// `schema:additionalProperty` (`ADDITIONAL_PROPERTY`)
+    pub static ref ADDITIONAL_PROPERTY: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "additionalProperty");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// additionalType
+    ///
+    /// An additional type for the item, typically used for adding more specific types from external vocabularies in microdata syntax. This is a relationship between something and a class that the thing is in.
+    ///
+    /// `schema:additionalType` (`ADDITIONAL_TYPE`)
+    pub static ref ADDITIONAL_TYPE: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "additionalType");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// additionalVariable
+    ///
+    /// Any additional component of the exercise prescription that may need to be articulated to the patient. This may include the order of exercises, the number of repetitions of movement, quantitative distance, progressions over time, etc.
+    ///
+    /// `schema:additionalVariable` (`ADDITIONAL_VARIABLE`)
+    pub static ref ADDITIONAL_VARIABLE: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "additionalVariable");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// address
+    ///
+    /// Physical address of the item.
+    ///
+    /// `schema:address` (`ADDRESS`)
+    pub static ref ADDRESS: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "address");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// addressCountry
+    ///
+    /// The country. Recommended to be in 2-letter ISO 3166-1 alpha-2 format, for example "US". For backward compatibility, a 3-letter ISO 3166-1 alpha-3 country code such as "SGP" or a full country name such as "Singapore" can also be used.
+    ///
+    /// `schema:addressCountry` (`ADDRESS_COUNTRY`)
+    pub static ref ADDRESS_COUNTRY: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "addressCountry");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// addressLocality
+    ///
+    /// The locality in which the street address is, and which is in the region. For example, Mountain View.
+    ///
+    /// `schema:addressLocality` (`ADDRESS_LOCALITY`)
+    pub static ref ADDRESS_LOCALITY: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "addressLocality");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// addressRegion
+    ///
+    /// The region in which the locality is, and which is in the country. For example, California or another appropriate first-level Administrative division.
+    ///
+    /// `schema:addressRegion` (`ADDRESS_REGION`)
+    pub static ref ADDRESS_REGION: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "addressRegion");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// administrationRoute
+    ///
+    /// A route by which this drug may be administered, e.g. 'oral'.
+    ///
+    /// `schema:administrationRoute` (`ADMINISTRATION_ROUTE`)
+    pub static ref ADMINISTRATION_ROUTE: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "administrationRoute");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// advanceBookingRequirement
+    ///
+    /// The amount of time that is required between accepting the offer and the actual usage of the resource or service.
+    ///
+    /// `schema:advanceBookingRequirement` (`ADVANCE_BOOKING_REQUIREMENT`)
+    pub static ref ADVANCE_BOOKING_REQUIREMENT: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "advanceBookingRequirement");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// adverseOutcome
+    ///
+    /// A possible complication and/or side effect of this therapy. If it is known that an adverse outcome is serious (resulting in death, disability, or permanent damage; requiring hospitalization; or otherwise life-threatening or requiring immediate medical attention), tag it as a seriousAdverseOutcome instead.
+    ///
+    /// `schema:adverseOutcome` (`ADVERSE_OUTCOME`)
+    pub static ref ADVERSE_OUTCOME: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "adverseOutcome");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// affectedBy
+    ///
+    /// Drugs that affect the test's results.
+    ///
+    /// `schema:affectedBy` (`AFFECTED_BY`)
+    pub static ref AFFECTED_BY: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "affectedBy");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// affiliation
+    ///
+    /// An organization that this person is affiliated with. For example, a school/university, a club, or a team.
+    ///
+    /// `schema:affiliation` (`AFFILIATION`)
+    pub static ref AFFILIATION: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "affiliation");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// afterMedia
+    ///
+    /// A media object representing the circumstances after performing this direction.
+    ///
+    /// `schema:afterMedia` (`AFTER_MEDIA`)
+    pub static ref AFTER_MEDIA: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "afterMedia");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// agent
+    ///
+    /// The direct performer or driver of the action (animate or inanimate). E.g. John wrote a book.
+    ///
+    /// `schema:agent` (`AGENT`)
+    pub static ref AGENT: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "agent");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// agentInteractionStatistic
+    ///
+    /// The number of completed interactions for this entity, in a particular role (the 'agent'), in a particular action (indicated in the statistic), and in a particular context (i.e. interactionService).
+    ///
+    /// `schema:agentInteractionStatistic` (`AGENT_INTERACTION_STATISTIC`)
+    pub static ref AGENT_INTERACTION_STATISTIC: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "agentInteractionStatistic");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// aggregateRating
+    ///
+    /// The overall rating, based on a collection of reviews or ratings, of the item.
+    ///
+    /// `schema:aggregateRating` (`AGGREGATE_RATING_PROPERTY`)
+    pub static ref AGGREGATE_RATING_PROPERTY: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "aggregateRating");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// aircraft
+    ///
+    /// The kind of aircraft (e.g., "Boeing 747").
+    ///
+    /// `schema:aircraft` (`AIRCRAFT`)
+    pub static ref AIRCRAFT: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "aircraft");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// album
+    ///
+    /// A music album.
+    ///
+    /// `schema:album` (`ALBUM`)
+    pub static ref ALBUM: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "album");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// albumProductionType
+    ///
+    /// Classification of the album by its type of content: soundtrack, live album, studio album, etc.
+    ///
+    /// `schema:albumProductionType` (`ALBUM_PRODUCTION_TYPE`)
+    pub static ref ALBUM_PRODUCTION_TYPE: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "albumProductionType");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// albumRelease
+    ///
+    /// A release of this album.
+    ///
+    /// `schema:albumRelease` (`ALBUM_RELEASE`)
+    pub static ref ALBUM_RELEASE: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "albumRelease");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// albumReleaseType
+    ///
+    /// The kind of release which this album is: single, EP or album.
+    ///
+    /// `schema:albumReleaseType` (`ALBUM_RELEASE_TYPE`)
+    pub static ref ALBUM_RELEASE_TYPE: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "albumReleaseType");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// albums
+    ///
+    /// A collection of music albums.
+    ///
+    /// `schema:albums` (`ALBUMS`)
+    pub static ref ALBUMS: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "albums");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// alcoholWarning
+    ///
+    /// Any precaution, guidance, contraindication, etc. related to consumption of alcohol while taking this drug.
+    ///
+    /// `schema:alcoholWarning` (`ALCOHOL_WARNING`)
+    pub static ref ALCOHOL_WARNING: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "alcoholWarning");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// algorithm
+    ///
+    /// The algorithm or rules to follow to compute the score.
+    ///
+    /// `schema:algorithm` (`ALGORITHM`)
+    pub static ref ALGORITHM: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "algorithm");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// alignmentType
+    ///
+    /// A category of alignment between the learning resource and the framework node. Recommended values include: 'requires', 'textComplexity', 'readingLevel', and 'educationalSubject'.
+    ///
+    /// `schema:alignmentType` (`ALIGNMENT_TYPE`)
+    pub static ref ALIGNMENT_TYPE: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "alignmentType");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// alternateName
+    ///
+    /// An alias for the item.
+    ///
+    /// `schema:alternateName` (`ALTERNATE_NAME`)
+    pub static ref ALTERNATE_NAME: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "alternateName");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// alternativeHeadline
+    ///
+    /// A secondary title of the CreativeWork.
+    ///
+    /// `schema:alternativeHeadline` (`ALTERNATIVE_HEADLINE`)
+    pub static ref ALTERNATIVE_HEADLINE: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "alternativeHeadline");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// alternativeOf
+    ///
+    /// Another gene which is a variation of this one.
+    ///
+    /// `schema:alternativeOf` (`ALTERNATIVE_OF`)
+    pub static ref ALTERNATIVE_OF: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "alternativeOf");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// alumni
+    ///
+    /// Alumni of an organization.
+    ///
+    /// `schema:alumni` (`ALUMNI`)
+    pub static ref ALUMNI: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "alumni");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// alumniOf
+    ///
+    /// An organization that the person is an alumni of.
+    ///
+    /// `schema:alumniOf` (`ALUMNI_OF`)
+    pub static ref ALUMNI_OF: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "alumniOf");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// amenityFeature
+    ///
+    /// An amenity feature (e.g. a characteristic or service) of the Accommodation. This generic property does not make a statement about whether the feature is included in an offer for the main accommodation or available at extra costs.
+    ///
+    /// `schema:amenityFeature` (`AMENITY_FEATURE`)
+    pub static ref AMENITY_FEATURE: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "amenityFeature");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// amount
+    ///
+    /// The amount of money.
+    ///
+    /// `schema:amount` (`AMOUNT`)
+    pub static ref AMOUNT: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "amount");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// amountOfThisGood
+    ///
+    /// The quantity of the goods included in the offer.
+    ///
+    /// `schema:amountOfThisGood` (`AMOUNT_OF_THIS_GOOD`)
+    pub static ref AMOUNT_OF_THIS_GOOD: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "amountOfThisGood");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// announcementLocation
+    ///
+    /// Indicates a specific CivicStructure or LocalBusiness associated with the SpecialAnnouncement. For example, a specific testing facility or business with special opening hours.
+    ///
+    /// `schema:announcementLocation` (`ANNOUNCEMENT_LOCATION`)
+    pub static ref ANNOUNCEMENT_LOCATION: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "announcementLocation");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// annualPercentageRate
+    ///
+    /// The annual rate that is charged for borrowing (or made by investing), expressed as a single percentage number that represents the actual yearly cost of funds over the term of a loan. This includes any fees or additional costs associated with the transaction.
+    ///
+    /// `schema:annualPercentageRate` (`ANNUAL_PERCENTAGE_RATE`)
+    pub static ref ANNUAL_PERCENTAGE_RATE: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "annualPercentageRate");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// answerCount
+    ///
+    /// The number of answers this question has received.
+    ///
+    /// `schema:answerCount` (`ANSWER_COUNT`)
+    pub static ref ANSWER_COUNT: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "answerCount");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// answerExplanation
+    ///
+    /// A step-by-step or full explanation about Answer. Can outline how this Answer was achieved or contain more broad clarification or statement about it.
+    ///
+    /// `schema:answerExplanation` (`ANSWER_EXPLANATION`)
+    pub static ref ANSWER_EXPLANATION: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "answerExplanation");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// antagonist
+    ///
+    /// The muscle whose action counteracts the specified muscle.
+    ///
+    /// `schema:antagonist` (`ANTAGONIST`)
+    pub static ref ANTAGONIST: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "antagonist");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// appearance
+    ///
+    /// Indicates an occurrence of a Claim in some CreativeWork.
+    ///
+    /// `schema:appearance` (`APPEARANCE`)
+    pub static ref APPEARANCE: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "appearance");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// applicableCountry
+    ///
+    /// A country where a particular merchant return policy applies to, for example the two-letter ISO 3166-1 alpha-2 country code.
+    ///
+    /// `schema:applicableCountry` (`APPLICABLE_COUNTRY`)
+    pub static ref APPLICABLE_COUNTRY: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "applicableCountry");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// applicableLocation
+    ///
+    /// The location in which the status applies.
+    ///
+    /// `schema:applicableLocation` (`APPLICABLE_LOCATION`)
+    pub static ref APPLICABLE_LOCATION: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "applicableLocation");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// applicantLocationRequirements
+    ///
+    /// The location(s) applicants can apply from. This is usually used for telecommuting jobs where the applicant does not need to be in a physical office. Note: This should not be used for citizenship or work visa requirements.
+    ///
+    /// `schema:applicantLocationRequirements` (`APPLICANT_LOCATION_REQUIREMENTS`)
+    pub static ref APPLICANT_LOCATION_REQUIREMENTS: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "applicantLocationRequirements");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// application
+    ///
+    /// An application that can complete the request.
+    ///
+    /// `schema:application` (`APPLICATION`)
+    pub static ref APPLICATION: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "application");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// applicationCategory
+    ///
+    /// Type of software application, e.g. 'Game, Multimedia'.
+    ///
+    /// `schema:applicationCategory` (`APPLICATION_CATEGORY`)
+    pub static ref APPLICATION_CATEGORY: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "applicationCategory");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// applicationContact
+    ///
+    /// Contact details for further information relevant to this job posting.
+    ///
+    /// `schema:applicationContact` (`APPLICATION_CONTACT`)
+    pub static ref APPLICATION_CONTACT: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "applicationContact");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// applicationDeadline
+    ///
+    /// The date at which the program stops collecting applications for the next enrollment cycle.
+    ///
+    /// `schema:applicationDeadline` (`APPLICATION_DEADLINE`)
+    pub static ref APPLICATION_DEADLINE: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "applicationDeadline");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// applicationStartDate
+    ///
+    /// The date at which the program begins collecting applications for the next enrollment cycle.
+    ///
+    /// `schema:applicationStartDate` (`APPLICATION_START_DATE`)
+    pub static ref APPLICATION_START_DATE: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "applicationStartDate");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// applicationSubCategory
+    ///
+    /// Subcategory of the application, e.g. 'Arcade Game'.
+    ///
+    /// `schema:applicationSubCategory` (`APPLICATION_SUB_CATEGORY`)
+    pub static ref APPLICATION_SUB_CATEGORY: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "applicationSubCategory");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// applicationSuite
+    ///
+    /// The name of the application suite to which the application belongs (e.g. Excel belongs to Office).
+    ///
+    /// `schema:applicationSuite` (`APPLICATION_SUITE`)
+    pub static ref APPLICATION_SUITE: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "applicationSuite");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// appliesToDeliveryMethod
+    ///
+    /// The delivery method(s) to which the delivery charge or payment charge specification applies.
+    ///
+    /// `schema:appliesToDeliveryMethod` (`APPLIES_TO_DELIVERY_METHOD`)
+    pub static ref APPLIES_TO_DELIVERY_METHOD: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "appliesToDeliveryMethod");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// appliesToPaymentMethod
+    ///
+    /// The payment method(s) to which the payment charge specification applies.
+    ///
+    /// `schema:appliesToPaymentMethod` (`APPLIES_TO_PAYMENT_METHOD`)
+    pub static ref APPLIES_TO_PAYMENT_METHOD: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "appliesToPaymentMethod");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// archiveHeld
+    ///
+    /// Collection, fonds, or item held, kept or maintained by an ArchiveOrganization.
+    ///
+    /// `schema:archiveHeld` (`ARCHIVE_HELD`)
+    pub static ref ARCHIVE_HELD: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "archiveHeld");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// archivedAt
+    ///
+    /// Indicates a page or other link involved in archival of a CreativeWork. In the case of MediaReview, the items in a MediaReviewItem may often become inaccessible, but be archived by archival, journalistic, activist, or law enforcement organizations.
+    ///
+    /// `schema:archivedAt` (`ARCHIVED_AT`)
+    pub static ref ARCHIVED_AT: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "archivedAt");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// area
+    ///
+    /// The area within which users can expect to reach the broadcast service.
+    ///
+    /// `schema:area` (`AREA`)
+    pub static ref AREA: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "area");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// areaServed
+    ///
+    /// The geographic area where a service or offered item is provided.
+    ///
+    /// `schema:areaServed` (`AREA_SERVED`)
+    pub static ref AREA_SERVED: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "areaServed");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// arrivalAirport
+    ///
+    /// The airport where the flight terminates.
+    ///
+    /// `schema:arrivalAirport` (`ARRIVAL_AIRPORT`)
+    pub static ref ARRIVAL_AIRPORT: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "arrivalAirport");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// arrivalBoatTerminal
+    ///
+    /// The terminal or port from which the boat arrives.
+    ///
+    /// `schema:arrivalBoatTerminal` (`ARRIVAL_BOAT_TERMINAL`)
+    pub static ref ARRIVAL_BOAT_TERMINAL: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "arrivalBoatTerminal");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// arrivalBusStop
+    ///
+    /// The stop or station from which the bus arrives.
+    ///
+    /// `schema:arrivalBusStop` (`ARRIVAL_BUS_STOP`)
+    pub static ref ARRIVAL_BUS_STOP: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "arrivalBusStop");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// arrivalGate
+    ///
+    /// Identifier of the flight's arrival gate.
+    ///
+    /// `schema:arrivalGate` (`ARRIVAL_GATE`)
+    pub static ref ARRIVAL_GATE: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "arrivalGate");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// arrivalPlatform
+    ///
+    /// The platform where the train arrives.
+    ///
+    /// `schema:arrivalPlatform` (`ARRIVAL_PLATFORM`)
+    pub static ref ARRIVAL_PLATFORM: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "arrivalPlatform");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// arrivalStation
+    ///
+    /// The station where the train trip ends.
+    ///
+    /// `schema:arrivalStation` (`ARRIVAL_STATION`)
+    pub static ref ARRIVAL_STATION: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "arrivalStation");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// arrivalTerminal
+    ///
+    /// Identifier of the flight's arrival terminal.
+    ///
+    /// `schema:arrivalTerminal` (`ARRIVAL_TERMINAL`)
+    pub static ref ARRIVAL_TERMINAL: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "arrivalTerminal");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// arrivalTime
+    ///
+    /// The expected arrival time.
+    ///
+    /// `schema:arrivalTime` (`ARRIVAL_TIME`)
+    pub static ref ARRIVAL_TIME: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "arrivalTime");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// artEdition
+    ///
+    /// The number of copies when multiple copies of a piece of artwork are produced - e.g. for a limited edition of 20 prints, 'artEdition' refers to the total number of copies (in this example "20").
+    ///
+    /// `schema:artEdition` (`ART_EDITION`)
+    pub static ref ART_EDITION: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "artEdition");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// artMedium
+    ///
+    /// The material used. (E.g. Oil, Watercolour, Acrylic, Linoprint, Marble, Cyanotype, Digital, Lithograph, DryPoint, Intaglio, Pastel, Woodcut, Pencil, Mixed Media, etc.)
+    ///
+    /// `schema:artMedium` (`ART_MEDIUM`)
+    pub static ref ART_MEDIUM: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "artMedium");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// arterialBranch
+    ///
+    /// The branches that comprise the arterial structure.
+    ///
+    /// `schema:arterialBranch` (`ARTERIAL_BRANCH`)
+    pub static ref ARTERIAL_BRANCH: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "arterialBranch");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// artform
+    ///
+    /// e.g. Painting, Drawing, Sculpture, Print, Photograph, Assemblage, Collage, etc.
+    ///
+    /// `schema:artform` (`ARTFORM`)
+    pub static ref ARTFORM: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "artform");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// articleBody
+    ///
+    /// The actual body of the article.
+    ///
+    /// `schema:articleBody` (`ARTICLE_BODY`)
+    pub static ref ARTICLE_BODY: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "articleBody");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// articleSection
+    ///
+    /// Articles may belong to one or more 'sections' in a magazine or newspaper, such as Sports, Lifestyle, etc.
+    ///
+    /// `schema:articleSection` (`ARTICLE_SECTION`)
+    pub static ref ARTICLE_SECTION: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "articleSection");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// artist
+    ///
+    /// The primary artist for a work in a medium other than pencils or digital line art--for example, if the primary artwork is done in watercolors or digital paints.
+    ///
+    /// `schema:artist` (`ARTIST`)
+    pub static ref ARTIST: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "artist");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// artworkSurface
+    ///
+    /// The supporting materials for the artwork, e.g. Canvas, Paper, Wood, Board, etc.
+    ///
+    /// `schema:artworkSurface` (`ARTWORK_SURFACE`)
+    pub static ref ARTWORK_SURFACE: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "artworkSurface");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// asin
+    ///
+    /// An Amazon Standard Identification Number (ASIN) is a 10-character alphanumeric unique identifier assigned by Amazon.com and its partners for product identification within the Amazon organization.
+    ///
+    /// `schema:asin` (`ASIN`)
+    pub static ref ASIN: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "asin");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// aspect
+    ///
+    /// An aspect of medical practice that is considered on the page, such as 'diagnosis', 'treatment', 'causes', 'prognosis', 'etiology', 'epidemiology', etc.
+    ///
+    /// `schema:aspect` (`ASPECT`)
+    pub static ref ASPECT: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "aspect");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// assembly
+    ///
+    /// Library file name, e.g., mscorlib.dll, system.web.dll.
+    ///
+    /// `schema:assembly` (`ASSEMBLY`)
+    pub static ref ASSEMBLY: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "assembly");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// assemblyVersion
+    ///
+    /// Associated product/technology version. E.g., .NET Framework 4.5.
+    ///
+    /// `schema:assemblyVersion` (`ASSEMBLY_VERSION`)
+    pub static ref ASSEMBLY_VERSION: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "assemblyVersion");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// assesses
+    ///
+    /// The item being described is intended to assess the competency or learning outcome defined by the referenced term.
+    ///
+    /// `schema:assesses` (`ASSESSES`)
+    pub static ref ASSESSES: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "assesses");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// associatedAnatomy
+    ///
+    /// The anatomy of the underlying organ system or structures associated with this entity.
+    ///
+    /// `schema:associatedAnatomy` (`ASSOCIATED_ANATOMY`)
+    pub static ref ASSOCIATED_ANATOMY: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "associatedAnatomy");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// associatedArticle
+    ///
+    /// A NewsArticle associated with the Media Object.
+    ///
+    /// `schema:associatedArticle` (`ASSOCIATED_ARTICLE`)
+    pub static ref ASSOCIATED_ARTICLE: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "associatedArticle");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// associatedClaimReview
+    ///
+    /// An associated ClaimReview, related by specific common content, topic or claim. The expectation is that this property would be most typically used in cases where a single activity is conducting both claim reviews and media reviews, in which case relatedMediaReview would commonly be used on a ClaimReview, while relatedClaimReview would be used on MediaReview.
+    ///
+    /// `schema:associatedClaimReview` (`ASSOCIATED_CLAIM_REVIEW`)
+    pub static ref ASSOCIATED_CLAIM_REVIEW: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "associatedClaimReview");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// associatedDisease
+    ///
+    /// Disease associated to this BioChemEntity. Such disease can be a MedicalCondition or a URL. If you want to add an evidence supporting the association, please use PropertyValue.
+    ///
+    /// `schema:associatedDisease` (`ASSOCIATED_DISEASE`)
+    pub static ref ASSOCIATED_DISEASE: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "associatedDisease");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// associatedMedia
+    ///
+    /// A media object that encodes this CreativeWork. This property is a synonym for encoding.
+    ///
+    /// `schema:associatedMedia` (`ASSOCIATED_MEDIA`)
+    pub static ref ASSOCIATED_MEDIA: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "associatedMedia");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// associatedMediaReview
+    ///
+    /// An associated MediaReview, related by specific common content, topic or claim.
+    ///
+    /// `schema:associatedMediaReview` (`ASSOCIATED_MEDIA_REVIEW`)
+    pub static ref ASSOCIATED_MEDIA_REVIEW: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "associatedMediaReview");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// associatedPathophysiology
+    ///
+    /// If applicable, a description of the pathophysiology associated with the anatomical system, including potential abnormal changes in the mechanical, physical, and biochemical functions of the system.
+    ///
+    /// `schema:associatedPathophysiology` (`ASSOCIATED_PATHOPHYSIOLOGY`)
+    pub static ref ASSOCIATED_PATHOPHYSIOLOGY: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "associatedPathophysiology");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// associatedReview
+    ///
+    /// An associated Review.
+    ///
+    /// `schema:associatedReview` (`ASSOCIATED_REVIEW`)
+    pub static ref ASSOCIATED_REVIEW: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "associatedReview");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// athlete
+    ///
+    /// A person that acts as performing member of a sports team; a player as opposed to a coach.
+    ///
+    /// `schema:athlete` (`ATHLETE`)
+    pub static ref ATHLETE: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "athlete");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// attendee
+    ///
+    /// A person or organization attending the event.
+    ///
+    /// `schema:attendee` (`ATTENDEE`)
+    pub static ref ATTENDEE: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "attendee");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// attendees
+    ///
+    /// A person attending the event.
+    ///
+    /// `schema:attendees` (`ATTENDEES`)
+    pub static ref ATTENDEES: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "attendees");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// audience
+    ///
+    /// An intended audience, i.e. a group for whom something was created.
+    ///
+    /// `schema:audience` (`AUDIENCE_PROPERTY`)
+    pub static ref AUDIENCE_PROPERTY: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "audience");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// audienceType
+    ///
+    /// The target group associated with a given audience (e.g. veterans, car owners, musicians, etc.).
+    ///
+    /// `schema:audienceType` (`AUDIENCE_TYPE`)
+    pub static ref AUDIENCE_TYPE: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "audienceType");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// audio
+    ///
+    /// An embedded audio object.
+    ///
+    /// `schema:audio` (`AUDIO`)
+    pub static ref AUDIO: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "audio");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// authenticator
+    ///
+    /// The Organization responsible for authenticating the user's subscription. For example, many media apps require a cable/satellite provider to authenticate your subscription before playing media.
+    ///
+    /// `schema:authenticator` (`AUTHENTICATOR`)
+    pub static ref AUTHENTICATOR: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "authenticator");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// author
+    ///
+    /// The author of this content or rating. Please note that author is special in that HTML 5 provides a special mechanism for indicating authorship via the rel tag. That is equivalent to this and may be used interchangeably.
+    ///
+    /// `schema:author` (`AUTHOR`)
+    pub static ref AUTHOR: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "author");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// availability
+    ///
+    /// The availability of this item—for example In stock, Out of stock, Pre-order, etc.
+    ///
+    /// `schema:availability` (`AVAILABILITY`)
+    pub static ref AVAILABILITY: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "availability");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// availabilityEnds
+    ///
+    /// The end of the availability of the product or service included in the offer.
+    ///
+    /// `schema:availabilityEnds` (`AVAILABILITY_ENDS`)
+    pub static ref AVAILABILITY_ENDS: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "availabilityEnds");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// availabilityStarts
+    ///
+    /// The beginning of the availability of the product or service included in the offer.
+    ///
+    /// `schema:availabilityStarts` (`AVAILABILITY_STARTS`)
+    pub static ref AVAILABILITY_STARTS: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "availabilityStarts");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// availableAtOrFrom
+    ///
+    /// The place(s) from which the offer can be obtained (e.g. store locations).
+    ///
+    /// `schema:availableAtOrFrom` (`AVAILABLE_AT_OR_FROM`)
+    pub static ref AVAILABLE_AT_OR_FROM: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "availableAtOrFrom");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// availableChannel
+    ///
+    /// A means of accessing the service (e.g. a phone bank, a web site, a location, etc.).
+    ///
+    /// `schema:availableChannel` (`AVAILABLE_CHANNEL`)
+    pub static ref AVAILABLE_CHANNEL: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "availableChannel");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// availableDeliveryMethod
+    ///
+    /// The delivery method(s) available for this offer.
+    ///
+    /// `schema:availableDeliveryMethod` (`AVAILABLE_DELIVERY_METHOD`)
+    pub static ref AVAILABLE_DELIVERY_METHOD: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "availableDeliveryMethod");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// availableFrom
+    ///
+    /// When the item is available for pickup from the store, locker, etc.
+    ///
+    /// `schema:availableFrom` (`AVAILABLE_FROM`)
+    pub static ref AVAILABLE_FROM: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "availableFrom");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// availableIn
+    ///
+    /// The location in which the strength is available.
+    ///
+    /// `schema:availableIn` (`AVAILABLE_IN`)
+    pub static ref AVAILABLE_IN: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "availableIn");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// availableLanguage
+    ///
+    /// A language someone may use with or at the item, service or place. Please use one of the language codes from the IETF BCP 47 standard. See also inLanguage.
+    ///
+    /// `schema:availableLanguage` (`AVAILABLE_LANGUAGE`)
+    pub static ref AVAILABLE_LANGUAGE: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "availableLanguage");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// availableOnDevice
+    ///
+    /// Device required to run the application. Used in cases where a specific make/model is required to run the application.
+    ///
+    /// `schema:availableOnDevice` (`AVAILABLE_ON_DEVICE`)
+    pub static ref AVAILABLE_ON_DEVICE: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "availableOnDevice");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// availableService
+    ///
+    /// A medical service available from this provider.
+    ///
+    /// `schema:availableService` (`AVAILABLE_SERVICE`)
+    pub static ref AVAILABLE_SERVICE: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "availableService");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// availableStrength
+    ///
+    /// An available dosage strength for the drug.
+    ///
+    /// `schema:availableStrength` (`AVAILABLE_STRENGTH`)
+    pub static ref AVAILABLE_STRENGTH: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "availableStrength");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// availableTest
+    ///
+    /// A diagnostic test or procedure offered by this lab.
+    ///
+    /// `schema:availableTest` (`AVAILABLE_TEST`)
+    pub static ref AVAILABLE_TEST: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "availableTest");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// availableThrough
+    ///
+    /// After this date, the item will no longer be available for pickup.
+    ///
+    /// `schema:availableThrough` (`AVAILABLE_THROUGH`)
+    pub static ref AVAILABLE_THROUGH: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "availableThrough");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// award
+    ///
+    /// An award won by or for this item.
+    ///
+    /// `schema:award` (`AWARD`)
+    pub static ref AWARD: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "award");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// awards
+    ///
+    /// Awards won by or for this item.
+    ///
+    /// `schema:awards` (`AWARDS`)
+    pub static ref AWARDS: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "awards");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// awayTeam
+    ///
+    /// The away team in a sports event.
+    ///
+    /// `schema:awayTeam` (`AWAY_TEAM`)
+    pub static ref AWAY_TEAM: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "awayTeam");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// backstory
+    ///
+    /// For an Article, typically a NewsArticle, the backstory property provides a textual summary giving a brief explanation of why and how an article was created. In a journalistic setting this could include information about reporting process, methods, interviews, data sources, etc.
+    ///
+    /// `schema:backstory` (`BACKSTORY`)
+    pub static ref BACKSTORY: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "backstory");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// bankAccountType
+    ///
+    /// The type of a bank account.
+    ///
+    /// `schema:bankAccountType` (`BANK_ACCOUNT_TYPE`)
+    pub static ref BANK_ACCOUNT_TYPE: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "bankAccountType");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// baseSalary
+    ///
+    /// The base salary of the job or of an employee in an EmployeeRole.
+    ///
+    /// `schema:baseSalary` (`BASE_SALARY`)
+    pub static ref BASE_SALARY: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "baseSalary");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// bccRecipient
+    ///
+    /// A sub property of recipient. The recipient blind copied on a message.
+    ///
+    /// `schema:bccRecipient` (`BCC_RECIPIENT`)
+    pub static ref BCC_RECIPIENT: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "bccRecipient");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// bed
+    ///
+    /// The type of bed or beds included in the accommodation. For the single case of just one bed of a certain type, you use bed directly with a text. If you want to indicate the quantity of a certain kind of bed, use an instance of BedDetails.
+    ///
+    /// `schema:bed` (`BED`)
+    pub static ref BED: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "bed");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// beforeMedia
+    ///
+    /// A media object representing the circumstances before performing this direction.
+    ///
+    /// `schema:beforeMedia` (`BEFORE_MEDIA`)
+    pub static ref BEFORE_MEDIA: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "beforeMedia");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// beneficiaryBank
+    ///
+    /// A bank or bank’s branch, financial institution or international financial institution operating the beneficiary’s bank account or releasing funds for the beneficiary.
+    ///
+    /// `schema:beneficiaryBank` (`BENEFICIARY_BANK`)
+    pub static ref BENEFICIARY_BANK: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "beneficiaryBank");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// benefits
+    ///
+    /// Description of benefits associated with the job.
+    ///
+    /// `schema:benefits` (`BENEFITS`)
+    pub static ref BENEFITS: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "benefits");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// benefitsSummaryUrl
+    ///
+    /// The URL that goes directly to the summary of benefits and coverage for the specific standard plan or plan variation.
+    ///
+    /// `schema:benefitsSummaryUrl` (`BENEFITS_SUMMARY_URL`)
+    pub static ref BENEFITS_SUMMARY_URL: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "benefitsSummaryUrl");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// bestRating
+    ///
+    /// The highest value allowed in this rating system.
+    ///
+    /// `schema:bestRating` (`BEST_RATING`)
+    pub static ref BEST_RATING: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "bestRating");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// billingAddress
+    ///
+    /// The billing address for the order.
+    ///
+    /// `schema:billingAddress` (`BILLING_ADDRESS`)
+    pub static ref BILLING_ADDRESS: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "billingAddress");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// billingDuration
+    ///
+    /// Specifies for how long this price (or price component) will be billed. Can be used, for example, to model the contractual duration of a subscription or payment plan. Type can be either a Duration or a Number (in which case the unit of measurement, for example month, is specified by the unitCode property).
+    ///
+    /// `schema:billingDuration` (`BILLING_DURATION`)
+    pub static ref BILLING_DURATION: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "billingDuration");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// billingIncrement
+    ///
+    /// This property specifies the minimal quantity and rounding increment that will be the basis for the billing. The unit of measurement is specified by the unitCode property.
+    ///
+    /// `schema:billingIncrement` (`BILLING_INCREMENT`)
+    pub static ref BILLING_INCREMENT: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "billingIncrement");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// billingPeriod
+    ///
+    /// The time interval used to compute the invoice.
+    ///
+    /// `schema:billingPeriod` (`BILLING_PERIOD`)
+    pub static ref BILLING_PERIOD: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "billingPeriod");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// billingStart
+    ///
+    /// Specifies after how much time this price (or price component) becomes valid and billing starts. Can be used, for example, to model a price increase after the first year of a subscription. The unit of measurement is specified by the unitCode property.
+    ///
+    /// `schema:billingStart` (`BILLING_START`)
+    pub static ref BILLING_START: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "billingStart");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// bioChemInteraction
+    ///
+    /// A BioChemEntity that is known to interact with this item.
+    ///
+    /// `schema:bioChemInteraction` (`BIO_CHEM_INTERACTION`)
+    pub static ref BIO_CHEM_INTERACTION: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "bioChemInteraction");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// bioChemSimilarity
+    ///
+    /// A similar BioChemEntity, e.g., obtained by fingerprint similarity algorithms.
+    ///
+    /// `schema:bioChemSimilarity` (`BIO_CHEM_SIMILARITY`)
+    pub static ref BIO_CHEM_SIMILARITY: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "bioChemSimilarity");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// biologicalRole
+    ///
+    /// A role played by the BioChemEntity within a biological context.
+    ///
+    /// `schema:biologicalRole` (`BIOLOGICAL_ROLE`)
+    pub static ref BIOLOGICAL_ROLE: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "biologicalRole");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// biomechnicalClass
+    ///
+    /// The biomechanical properties of the bone.
+    ///
+    /// `schema:biomechnicalClass` (`BIOMECHNICAL_CLASS`)
+    pub static ref BIOMECHNICAL_CLASS: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "biomechnicalClass");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// birthDate
+    ///
+    /// Date of birth.
+    ///
+    /// `schema:birthDate` (`BIRTH_DATE`)
+    pub static ref BIRTH_DATE: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "birthDate");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// birthPlace
+    ///
+    /// The place where the person was born.
+    ///
+    /// `schema:birthPlace` (`BIRTH_PLACE`)
+    pub static ref BIRTH_PLACE: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "birthPlace");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// bitrate
+    ///
+    /// The bitrate of the media object.
+    ///
+    /// `schema:bitrate` (`BITRATE`)
+    pub static ref BITRATE: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "bitrate");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// blogPost
+    ///
+    /// A posting that is part of this blog.
+    ///
+    /// `schema:blogPost` (`BLOG_POST`)
+    pub static ref BLOG_POST: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "blogPost");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// blogPosts
+    ///
+    /// Indicates a post that is part of a Blog. Note that historically, what we term a "Blog" was once known as a "weblog", and that what we term a "BlogPosting" is now often colloquially referred to as a "blog".
+    ///
+    /// `schema:blogPosts` (`BLOG_POSTS`)
+    pub static ref BLOG_POSTS: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "blogPosts");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// bloodSupply
+    ///
+    /// The blood vessel that carries blood from the heart to the muscle.
+    ///
+    /// `schema:bloodSupply` (`BLOOD_SUPPLY`)
+    pub static ref BLOOD_SUPPLY: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "bloodSupply");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// boardingGroup
+    ///
+    /// The airline-specific indicator of boarding order / preference.
+    ///
+    /// `schema:boardingGroup` (`BOARDING_GROUP`)
+    pub static ref BOARDING_GROUP: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "boardingGroup");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// boardingPolicy
+    ///
+    /// The type of boarding policy used by the airline (e.g. zone-based or group-based).
+    ///
+    /// `schema:boardingPolicy` (`BOARDING_POLICY`)
+    pub static ref BOARDING_POLICY: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "boardingPolicy");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// bodyLocation
+    ///
+    /// Location in the body of the anatomical structure.
+    ///
+    /// `schema:bodyLocation` (`BODY_LOCATION`)
+    pub static ref BODY_LOCATION: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "bodyLocation");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// bodyType
+    ///
+    /// Indicates the design and body style of the vehicle (e.g. station wagon, hatchback, etc.).
+    ///
+    /// `schema:bodyType` (`BODY_TYPE`)
+    pub static ref BODY_TYPE: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "bodyType");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// bookEdition
+    ///
+    /// The edition of the book.
+    ///
+    /// `schema:bookEdition` (`BOOK_EDITION`)
+    pub static ref BOOK_EDITION: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "bookEdition");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// bookFormat
+    ///
+    /// The format of the book.
+    ///
+    /// `schema:bookFormat` (`BOOK_FORMAT`)
+    pub static ref BOOK_FORMAT: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "bookFormat");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// bookingAgent
+    ///
+    /// 'bookingAgent' is an out-dated term indicating a 'broker' that serves as a booking agent.
+    ///
+    /// `schema:bookingAgent` (`BOOKING_AGENT`)
+    pub static ref BOOKING_AGENT: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "bookingAgent");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// bookingTime
+    ///
+    /// The date and time the reservation was booked.
+    ///
+    /// `schema:bookingTime` (`BOOKING_TIME`)
+    pub static ref BOOKING_TIME: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "bookingTime");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// borrower
+    ///
+    /// A sub property of participant. The person that borrows the object being lent.
+    ///
+    /// `schema:borrower` (`BORROWER`)
+    pub static ref BORROWER: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "borrower");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// box
+    ///
+    /// A box is the area enclosed by the rectangle formed by two points. The first point is the lower corner, the second point is the upper corner. A box is expressed as two points separated by a space character.
+    ///
+    /// `schema:box` (`BOX`)
+    pub static ref BOX: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "box");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// branch
+    ///
+    /// The branches that delineate from the nerve bundle. Not to be confused with branchOf.
+    ///
+    /// `schema:branch` (`BRANCH`)
+    pub static ref BRANCH: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "branch");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// branchCode
+    ///
+    /// A short textual code (also called "store code") that uniquely identifies a place of business. The code is typically assigned by the parentOrganization and used in structured URLs.
+    ///
+    /// `schema:branchCode` (`BRANCH_CODE`)
+    pub static ref BRANCH_CODE: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "branchCode");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// branchOf
+    ///
+    /// The larger organization that this local business is a branch of, if any. Not to be confused with (anatomical) branch.
+    ///
+    /// `schema:branchOf` (`BRANCH_OF`)
+    pub static ref BRANCH_OF: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "branchOf");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// brand
+    ///
+    /// The brand(s) associated with a product or service, or the brand(s) maintained by an organization or business person.
+    ///
+    /// `schema:brand` (`BRAND_PROPERTY`)
+    pub static ref BRAND_PROPERTY: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "brand");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// breadcrumb
+    ///
+    /// A set of links that can help a user understand and navigate a website hierarchy.
+    ///
+    /// `schema:breadcrumb` (`BREADCRUMB`)
+    pub static ref BREADCRUMB: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "breadcrumb");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// breastfeedingWarning
+    ///
+    /// Any precaution, guidance, contraindication, etc. related to this drug's use by breastfeeding mothers.
+    ///
+    /// `schema:breastfeedingWarning` (`BREASTFEEDING_WARNING`)
+    pub static ref BREASTFEEDING_WARNING: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "breastfeedingWarning");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// broadcastAffiliateOf
+    ///
+    /// The media network(s) whose content is broadcast on this station.
+    ///
+    /// `schema:broadcastAffiliateOf` (`BROADCAST_AFFILIATE_OF`)
+    pub static ref BROADCAST_AFFILIATE_OF: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "broadcastAffiliateOf");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// broadcastChannelId
+    ///
+    /// The unique address by which the BroadcastService can be identified in a provider lineup. In US, this is typically a number.
+    ///
+    /// `schema:broadcastChannelId` (`BROADCAST_CHANNEL_ID`)
+    pub static ref BROADCAST_CHANNEL_ID: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "broadcastChannelId");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// broadcastDisplayName
+    ///
+    /// The name displayed in the channel guide. For many US affiliates, it is the network name.
+    ///
+    /// `schema:broadcastDisplayName` (`BROADCAST_DISPLAY_NAME`)
+    pub static ref BROADCAST_DISPLAY_NAME: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "broadcastDisplayName");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// broadcastFrequency
+    ///
+    /// The frequency used for over-the-air broadcasts. Numeric values or simple ranges, e.g. 87-99. In addition a shortcut idiom is supported for frequencies of AM and FM radio channels, e.g. "87 FM".
+    ///
+    /// `schema:broadcastFrequency` (`BROADCAST_FREQUENCY`)
+    pub static ref BROADCAST_FREQUENCY: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "broadcastFrequency");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// broadcastFrequencyValue
+    ///
+    /// The frequency in MHz for a particular broadcast.
+    ///
+    /// `schema:broadcastFrequencyValue` (`BROADCAST_FREQUENCY_VALUE`)
+    pub static ref BROADCAST_FREQUENCY_VALUE: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "broadcastFrequencyValue");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// broadcastOfEvent
+    ///
+    /// The event being broadcast such as a sporting event or awards ceremony.
+    ///
+    /// `schema:broadcastOfEvent` (`BROADCAST_OF_EVENT`)
+    pub static ref BROADCAST_OF_EVENT: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "broadcastOfEvent");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// broadcastServiceTier
+    ///
+    /// The type of service required to have access to the channel (e.g. Standard or Premium).
+    ///
+    /// `schema:broadcastServiceTier` (`BROADCAST_SERVICE_TIER`)
+    pub static ref BROADCAST_SERVICE_TIER: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "broadcastServiceTier");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// broadcastSignalModulation
+    ///
+    /// The modulation (e.g. FM, AM, etc) used by a particular broadcast service.
+    ///
+    /// `schema:broadcastSignalModulation` (`BROADCAST_SIGNAL_MODULATION`)
+    pub static ref BROADCAST_SIGNAL_MODULATION: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "broadcastSignalModulation");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// broadcastSubChannel
+    ///
+    /// The subchannel used for the broadcast.
+    ///
+    /// `schema:broadcastSubChannel` (`BROADCAST_SUB_CHANNEL`)
+    pub static ref BROADCAST_SUB_CHANNEL: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "broadcastSubChannel");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// broadcastTimezone
+    ///
+    /// The timezone in ISO 8601 format for which the service bases its broadcasts.
+    ///
+    /// `schema:broadcastTimezone` (`BROADCAST_TIMEZONE`)
+    pub static ref BROADCAST_TIMEZONE: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "broadcastTimezone");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// broadcaster
+    ///
+    /// The organization owning or operating the broadcast service.
+    ///
+    /// `schema:broadcaster` (`BROADCASTER`)
+    pub static ref BROADCASTER: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "broadcaster");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// broker
+    ///
+    /// An entity that arranges for an exchange between a buyer and a seller. In most cases a broker never acquires or releases ownership of a product or service involved in an exchange.
+    ///
+    /// `schema:broker` (`BROKER`)
+    pub static ref BROKER: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "broker");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// browserRequirements
+    ///
+    /// Specifies browser requirements in human-readable text. For example, 'requires HTML5 support'.
+    ///
+    /// `schema:browserRequirements` (`BROWSER_REQUIREMENTS`)
+    pub static ref BROWSER_REQUIREMENTS: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "browserRequirements");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// busName
+    ///
+    /// The name of the bus (e.g. Bolt Express).
+    ///
+    /// `schema:busName` (`BUS_NAME`)
+    pub static ref BUS_NAME: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "busName");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// busNumber
+    ///
+    /// The unique identifier for the bus.
+    ///
+    /// `schema:busNumber` (`BUS_NUMBER`)
+    pub static ref BUS_NUMBER: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "busNumber");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// businessDays
+    ///
+    /// Days of the week when the merchant typically operates, indicated via opening hours markup.
+    ///
+    /// `schema:businessDays` (`BUSINESS_DAYS`)
+    pub static ref BUSINESS_DAYS: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "businessDays");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// businessFunction
+    ///
+    /// The business function (e.g. sell, lease, repair, dispose) of the offer or component of a bundle (TypeAndQuantityNode). The default is sell.
+    ///
+    /// `schema:businessFunction` (`BUSINESS_FUNCTION_PROPERTY`)
+    pub static ref BUSINESS_FUNCTION_PROPERTY: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "businessFunction");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// buyer
+    ///
+    /// A sub property of participant. The participant/person/organization that bought the object.
+    ///
+    /// `schema:buyer` (`BUYER`)
+    pub static ref BUYER: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "buyer");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// byArtist
+    ///
+    /// The artist that performed this album or recording.
+    ///
+    /// `schema:byArtist` (`BY_ARTIST`)
+    pub static ref BY_ARTIST: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "byArtist");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// byDay
+    ///
+    /// Defines the day(s) of the week on which a recurring Event takes place. May be specified using either DayOfWeek, or alternatively Text conforming to iCal's syntax for byDay recurrence rules.
+    ///
+    /// `schema:byDay` (`BY_DAY`)
+    pub static ref BY_DAY: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "byDay");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// byMonth
+    ///
+    /// Defines the month(s) of the year on which a recurring Event takes place. Specified as an Integer between 1-12. January is 1.
+    ///
+    /// `schema:byMonth` (`BY_MONTH`)
+    pub static ref BY_MONTH: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "byMonth");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// byMonthDay
+    ///
+    /// Defines the day(s) of the month on which a recurring Event takes place. Specified as an Integer between 1-31.
+    ///
+    /// `schema:byMonthDay` (`BY_MONTH_DAY`)
+    pub static ref BY_MONTH_DAY: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "byMonthDay");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// byMonthWeek
+    ///
+    /// Defines the week(s) of the month on which a recurring Event takes place. Specified as an Integer between 1-5. For clarity, byMonthWeek is best used in conjunction with byDay to indicate concepts like the first and third Mondays of a month.
+    ///
+    /// `schema:byMonthWeek` (`BY_MONTH_WEEK`)
+    pub static ref BY_MONTH_WEEK: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "byMonthWeek");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// callSign
+    ///
+    /// A callsign, as used in broadcasting and radio communications to identify people, radio and TV stations, or vehicles.
+    ///
+    /// `schema:callSign` (`CALL_SIGN`)
+    pub static ref CALL_SIGN: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "callSign");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// calories
+    ///
+    /// The number of calories.
+    ///
+    /// `schema:calories` (`CALORIES`)
+    pub static ref CALORIES: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "calories");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// candidate
+    ///
+    /// A sub property of object. The candidate subject of this action.
+    ///
+    /// `schema:candidate` (`CANDIDATE`)
+    pub static ref CANDIDATE: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "candidate");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// caption
+    ///
+    /// The caption for this object. For downloadable machine formats (closed caption, subtitles etc.) use MediaObject and indicate the encodingFormat.
+    ///
+    /// `schema:caption` (`CAPTION`)
+    pub static ref CAPTION: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "caption");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// carbohydrateContent
+    ///
+    /// The number of grams of carbohydrates.
+    ///
+    /// `schema:carbohydrateContent` (`CARBOHYDRATE_CONTENT`)
+    pub static ref CARBOHYDRATE_CONTENT: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "carbohydrateContent");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// cargoVolume
+    ///
+    /// The available volume for cargo or luggage. For automobiles, this is usually the trunk volume.
+    ///
+    /// `schema:cargoVolume` (`CARGO_VOLUME`)
+    pub static ref CARGO_VOLUME: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "cargoVolume");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// carrier
+    ///
+    /// 'carrier' is an out-dated term indicating the 'provider' for parcel delivery and flights.
+    ///
+    /// `schema:carrier` (`CARRIER`)
+    pub static ref CARRIER: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "carrier");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// carrierRequirements
+    ///
+    /// Specifies specific carrier(s) requirements for the application (e.g. an application may only work on a specific carrier network).
+    ///
+    /// `schema:carrierRequirements` (`CARRIER_REQUIREMENTS`)
+    pub static ref CARRIER_REQUIREMENTS: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "carrierRequirements");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// cashBack
+    ///
+    /// A cardholder benefit that pays the cardholder a small percentage of their net expenditures.
+    ///
+    /// `schema:cashBack` (`CASH_BACK`)
+    pub static ref CASH_BACK: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "cashBack");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// catalog
+    ///
+    /// A data catalog which contains this dataset.
+    ///
+    /// `schema:catalog` (`CATALOG`)
+    pub static ref CATALOG: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "catalog");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// catalogNumber
+    ///
+    /// The catalog number for the release.
+    ///
+    /// `schema:catalogNumber` (`CATALOG_NUMBER`)
+    pub static ref CATALOG_NUMBER: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "catalogNumber");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// category
+    ///
+    /// A category for the item. Greater signs or slashes can be used to informally indicate a category hierarchy.
+    ///
+    /// `schema:category` (`CATEGORY`)
+    pub static ref CATEGORY: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "category");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// causeOf
+    ///
+    /// The condition, complication, symptom, sign, etc. caused.
+    ///
+    /// `schema:causeOf` (`CAUSE_OF`)
+    pub static ref CAUSE_OF: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "causeOf");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// ccRecipient
+    ///
+    /// A sub property of recipient. The recipient copied on a message.
+    ///
+    /// `schema:ccRecipient` (`CC_RECIPIENT`)
+    pub static ref CC_RECIPIENT: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "ccRecipient");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// certificationIdentification
+    ///
+    /// Identifier of a certification instance (as registered with an independent certification body). Typically this identifier can be used to consult and verify the certification instance.
+    ///
+    /// `schema:certificationIdentification` (`CERTIFICATION_IDENTIFICATION`)
+    pub static ref CERTIFICATION_IDENTIFICATION: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "certificationIdentification");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// certificationRating
+    ///
+    /// Rating of a certification instance (as defined by an independent certification body). Typically this rating can be used to rate the level to which the requirements of the certification instance are fulfilled.
+    ///
+    /// `schema:certificationRating` (`CERTIFICATION_RATING`)
+    pub static ref CERTIFICATION_RATING: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "certificationRating");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// certificationStatus
+    ///
+    /// Indicates the current status of a certification: active or inactive.
+    ///
+    /// `schema:certificationStatus` (`CERTIFICATION_STATUS`)
+    pub static ref CERTIFICATION_STATUS: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "certificationStatus");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// character
+    ///
+    /// Fictional person connected with a creative work.
+    ///
+    /// `schema:character` (`CHARACTER`)
+    pub static ref CHARACTER: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "character");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// characterAttribute
+    ///
+    /// A piece of data that represents a particular aspect of a fictional character (skill, power, character points, advantage, disadvantage).
+    ///
+    /// `schema:characterAttribute` (`CHARACTER_ATTRIBUTE`)
+    pub static ref CHARACTER_ATTRIBUTE: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "characterAttribute");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// characterName
+    ///
+    /// The name of a character played in some acting or performing role, i.e. in a PerformanceRole.
+    ///
+    /// `schema:characterName` (`CHARACTER_NAME`)
+    pub static ref CHARACTER_NAME: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "characterName");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// cheatCode
+    ///
+    /// Cheat codes to the game.
+    ///
+    /// `schema:cheatCode` (`CHEAT_CODE`)
+    pub static ref CHEAT_CODE: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "cheatCode");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// checkinTime
+    ///
+    /// The earliest someone may check into a lodging establishment.
+    ///
+    /// `schema:checkinTime` (`CHECKIN_TIME`)
+    pub static ref CHECKIN_TIME: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "checkinTime");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// checkoutTime
+    ///
+    /// The latest someone may check out of a lodging establishment.
+    ///
+    /// `schema:checkoutTime` (`CHECKOUT_TIME`)
+    pub static ref CHECKOUT_TIME: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "checkoutTime");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// chemicalComposition
+    ///
+    /// The chemical composition describes the identity and relative ratio of the chemical elements that make up the substance.
+    ///
+    /// `schema:chemicalComposition` (`CHEMICAL_COMPOSITION`)
+    pub static ref CHEMICAL_COMPOSITION: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "chemicalComposition");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// chemicalRole
+    ///
+    /// A role played by the BioChemEntity within a chemical context.
+    ///
+    /// `schema:chemicalRole` (`CHEMICAL_ROLE`)
+    pub static ref CHEMICAL_ROLE: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "chemicalRole");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// childMaxAge
+    ///
+    /// Maximal age of the child.
+    ///
+    /// `schema:childMaxAge` (`CHILD_MAX_AGE`)
+    pub static ref CHILD_MAX_AGE: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "childMaxAge");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// childMinAge
+    ///
+    /// Minimal age of the child.
+    ///
+    /// `schema:childMinAge` (`CHILD_MIN_AGE`)
+    pub static ref CHILD_MIN_AGE: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "childMinAge");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// childTaxon
+    ///
+    /// Closest child taxa of the taxon in question.
+    ///
+    /// `schema:childTaxon` (`CHILD_TAXON`)
+    pub static ref CHILD_TAXON: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "childTaxon");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// children
+    ///
+    /// A child of the person.
+    ///
+    /// `schema:children` (`CHILDREN`)
+    pub static ref CHILDREN: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "children");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// cholesterolContent
+    ///
+    /// The number of milligrams of cholesterol.
+    ///
+    /// `schema:cholesterolContent` (`CHOLESTEROL_CONTENT`)
+    pub static ref CHOLESTEROL_CONTENT: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "cholesterolContent");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// circle
+    ///
+    /// A circle is the circular region of a specified radius centered at a specified latitude and longitude. A circle is expressed as a pair followed by a radius in meters.
+    ///
+    /// `schema:circle` (`CIRCLE`)
+    pub static ref CIRCLE: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "circle");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// citation
+    ///
+    /// A citation or reference to another creative work, such as another publication, web page, scholarly article, etc.
+    ///
+    /// `schema:citation` (`CITATION`)
+    pub static ref CITATION: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "citation");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// claimInterpreter
+    ///
+    /// For a Claim interpreted from MediaObject content, the agent (a person or organization) who interpreted the claim.
+    ///
+    /// `schema:claimInterpreter` (`CLAIM_INTERPRETER`)
+    pub static ref CLAIM_INTERPRETER: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "claimInterpreter");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// claimReviewed
+    ///
+    /// A short summary of the specific claims reviewed in a ClaimReview.
+    ///
+    /// `schema:claimReviewed` (`CLAIM_REVIEWED`)
+    pub static ref CLAIM_REVIEWED: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "claimReviewed");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// clincalPharmacology
+    ///
+    /// Description of the absorption and elimination of drugs, including their concentration (pharmacokinetics, pK) and biological effects (pharmacodynamics, pD).
+    ///
+    /// `schema:clincalPharmacology` (`CLINCAL_PHARMACOLOGY`)
+    pub static ref CLINCAL_PHARMACOLOGY: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "clincalPharmacology");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// clinicalPharmacology
+    ///
+    /// Description of the absorption and elimination of drugs, including their concentration (pharmacokinetics, pK) and biological effects (pharmacodynamics, pD).
+    ///
+    /// `schema:clinicalPharmacology` (`CLINICAL_PHARMACOLOGY`)
+    pub static ref CLINICAL_PHARMACOLOGY: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "clinicalPharmacology");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// clipNumber
+    ///
+    /// Position of the clip within an ordered group of clips.
+    ///
+    /// `schema:clipNumber` (`CLIP_NUMBER`)
+    pub static ref CLIP_NUMBER: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "clipNumber");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// closes
+    ///
+    /// The closing hour of the place or service on the given day(s) of the week.
+    ///
+    /// `schema:closes` (`CLOSES`)
+    pub static ref CLOSES: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "closes");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// coach
+    ///
+    /// A person that acts in a coaching role for a sports team.
+    ///
+    /// `schema:coach` (`COACH`)
+    pub static ref COACH: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "coach");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// code
+    ///
+    /// A medical code for the entity, taken from a controlled vocabulary or ontology such as ICD-9, DiseasesDB, MeSH, SNOMED-CT, RxNorm, etc.
+    ///
+    /// `schema:code` (`CODE_PROPERTY`)
+    pub static ref CODE_PROPERTY: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "code");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// codeRepository
+    ///
+    /// Link to the repository where the un-compiled, human readable code and related code is located (SVN, GitHub, CodePlex).
+    ///
+    /// `schema:codeRepository` (`CODE_REPOSITORY`)
+    pub static ref CODE_REPOSITORY: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "codeRepository");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// codeSampleType
+    ///
+    /// What type of code sample: full (compile ready) solution, code snippet, inline code, scripts, template.
+    ///
+    /// `schema:codeSampleType` (`CODE_SAMPLE_TYPE`)
+    pub static ref CODE_SAMPLE_TYPE: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "codeSampleType");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// codeValue
+    ///
+    /// A short textual code that uniquely identifies the value.
+    ///
+    /// `schema:codeValue` (`CODE_VALUE`)
+    pub static ref CODE_VALUE: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "codeValue");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// codingSystem
+    ///
+    /// The coding system, e.g. 'ICD-10'.
+    ///
+    /// `schema:codingSystem` (`CODING_SYSTEM`)
+    pub static ref CODING_SYSTEM: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "codingSystem");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// colleague
+    ///
+    /// A colleague of the person.
+    ///
+    /// `schema:colleague` (`COLLEAGUE`)
+    pub static ref COLLEAGUE: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "colleague");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// colleagues
+    ///
+    /// A colleague of the person.
+    ///
+    /// `schema:colleagues` (`COLLEAGUES`)
+    pub static ref COLLEAGUES: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "colleagues");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// collection
+    ///
+    /// A sub property of object. The collection target of the action.
+    ///
+    /// `schema:collection` (`COLLECTION_PROPERTY`)
+    pub static ref COLLECTION_PROPERTY: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "collection");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// collectionSize
+    ///
+    /// The number of items in the Collection.
+    ///
+    /// `schema:collectionSize` (`COLLECTION_SIZE`)
+    pub static ref COLLECTION_SIZE: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "collectionSize");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// color
+    ///
+    /// The color of the product.
+    ///
+    /// `schema:color` (`COLOR`)
+    pub static ref COLOR: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "color");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// colorSwatch
+    ///
+    /// A color swatch image, visualizing the color of a Product. Should match the textual description specified in the color property. This can be a URL or a fully described ImageObject.
+    ///
+    /// `schema:colorSwatch` (`COLOR_SWATCH`)
+    pub static ref COLOR_SWATCH: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "colorSwatch");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// colorist
+    ///
+    /// The individual who adds color to inked drawings.
+    ///
+    /// `schema:colorist` (`COLORIST`)
+    pub static ref COLORIST: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "colorist");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// comment
+    ///
+    /// Comments, typically from users.
+    ///
+    /// `schema:comment` (`COMMENT_PROPERTY`)
+    pub static ref COMMENT_PROPERTY: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "comment");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// commentCount
+    ///
+    /// The number of comments this CreativeWork (e.g. Article, Question or Answer) has received. This is most applicable to works published in Web sites with commenting system; additional comments may exist elsewhere.
+    ///
+    /// `schema:commentCount` (`COMMENT_COUNT`)
+    pub static ref COMMENT_COUNT: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "commentCount");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// commentText
+    ///
+    /// The text of the UserComment.
+    ///
+    /// `schema:commentText` (`COMMENT_TEXT`)
+    pub static ref COMMENT_TEXT: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "commentText");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// commentTime
+    ///
+    /// The time at which the UserComment was made.
+    ///
+    /// `schema:commentTime` (`COMMENT_TIME`)
+    pub static ref COMMENT_TIME: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "commentTime");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// competencyRequired
+    ///
+    /// Knowledge, skill, ability or personal attribute that must be demonstrated by a person or other entity in order to do something such as earn an Educational Occupational Credential or understand a LearningResource.
+    ///
+    /// `schema:competencyRequired` (`COMPETENCY_REQUIRED`)
+    pub static ref COMPETENCY_REQUIRED: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "competencyRequired");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// competitor
+    ///
+    /// A competitor in a sports event.
+    ///
+    /// `schema:competitor` (`COMPETITOR`)
+    pub static ref COMPETITOR: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "competitor");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// composer
+    ///
+    /// The person or organization who wrote a composition, or who is the composer of a work performed at some event.
+    ///
+    /// `schema:composer` (`COMPOSER`)
+    pub static ref COMPOSER: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "composer");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// comprisedOf
+    ///
+    /// Specifying something physically contained by something else. Typically used here for the underlying anatomical structures, such as organs, that comprise the anatomical system.
+    ///
+    /// `schema:comprisedOf` (`COMPRISED_OF`)
+    pub static ref COMPRISED_OF: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "comprisedOf");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// conditionsOfAccess
+    ///
+    /// Conditions that affect the availability of, or method(s) of access to, an item. Typically used for real world items such as an ArchiveComponent held by an ArchiveOrganization.
+    ///
+    /// `schema:conditionsOfAccess` (`CONDITIONS_OF_ACCESS`)
+    pub static ref CONDITIONS_OF_ACCESS: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "conditionsOfAccess");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// confirmationNumber
+    ///
+    /// A number that confirms the given order or payment has been received.
+    ///
+    /// `schema:confirmationNumber` (`CONFIRMATION_NUMBER`)
+    pub static ref CONFIRMATION_NUMBER: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "confirmationNumber");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// connectedTo
+    ///
+    /// Other anatomical structures to which this structure is connected.
+    ///
+    /// `schema:connectedTo` (`CONNECTED_TO`)
+    pub static ref CONNECTED_TO: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "connectedTo");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// constraintProperty
+    ///
+    /// Indicates a property used as a constraint. For example, in the definition of a StatisticalVariable. The value is a property, either from within Schema.org or from other compatible (e.g. RDF) systems such as DataCommons.org or Wikidata.org.
+    ///
+    /// `schema:constraintProperty` (`CONSTRAINT_PROPERTY`)
+    pub static ref CONSTRAINT_PROPERTY: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "constraintProperty");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// contactOption
+    ///
+    /// An option available on this contact point (e.g. a toll-free number or support for hearing-impaired callers).
+    ///
+    /// `schema:contactOption` (`CONTACT_OPTION`)
+    pub static ref CONTACT_OPTION: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "contactOption");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// contactPoint
+    ///
+    /// A contact point for a person or organization.
+    ///
+    /// `schema:contactPoint` (`CONTACT_POINT_PROPERTY`)
+    pub static ref CONTACT_POINT_PROPERTY: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "contactPoint");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// contactPoints
+    ///
+    /// A contact point for a person or organization.
+    ///
+    /// `schema:contactPoints` (`CONTACT_POINTS`)
+    pub static ref CONTACT_POINTS: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "contactPoints");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// contactType
+    ///
+    /// A person or organization can have different contact points, for different purposes. For example, a sales contact point, a PR contact point and so on. This property is used to specify the kind of contact point.
+    ///
+    /// `schema:contactType` (`CONTACT_TYPE`)
+    pub static ref CONTACT_TYPE: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "contactType");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// contactlessPayment
+    ///
+    /// A secure method for consumers to purchase products or services via debit, credit or smartcards by using RFID or NFC technology.
+    ///
+    /// `schema:contactlessPayment` (`CONTACTLESS_PAYMENT`)
+    pub static ref CONTACTLESS_PAYMENT: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "contactlessPayment");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// containedIn
+    ///
+    /// The basic containment relation between a place and one that contains it.
+    ///
+    /// `schema:containedIn` (`CONTAINED_IN`)
+    pub static ref CONTAINED_IN: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "containedIn");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// containedInPlace
+    ///
+    /// The basic containment relation between a place and one that contains it.
+    ///
+    /// `schema:containedInPlace` (`CONTAINED_IN_PLACE`)
+    pub static ref CONTAINED_IN_PLACE: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "containedInPlace");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// containsPlace
+    ///
+    /// The basic containment relation between a place and another that it contains.
+    ///
+    /// `schema:containsPlace` (`CONTAINS_PLACE`)
+    pub static ref CONTAINS_PLACE: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "containsPlace");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// containsSeason
+    ///
+    /// A season that is part of the media series.
+    ///
+    /// `schema:containsSeason` (`CONTAINS_SEASON`)
+    pub static ref CONTAINS_SEASON: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "containsSeason");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// contentLocation
+    ///
+    /// The location depicted or described in the content. For example, the location in a photograph or painting.
+    ///
+    /// `schema:contentLocation` (`CONTENT_LOCATION`)
+    pub static ref CONTENT_LOCATION: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "contentLocation");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// contentRating
+    ///
+    /// Official rating of a piece of content—for example, 'MPAA PG-13'.
+    ///
+    /// `schema:contentRating` (`CONTENT_RATING`)
+    pub static ref CONTENT_RATING: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "contentRating");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// contentReferenceTime
+    ///
+    /// The specific time described by a creative work, for works (e.g. articles, video objects etc.) that emphasise a particular moment within an Event.
+    ///
+    /// `schema:contentReferenceTime` (`CONTENT_REFERENCE_TIME`)
+    pub static ref CONTENT_REFERENCE_TIME: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "contentReferenceTime");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// contentSize
+    ///
+    /// File size in (mega/kilo)bytes.
+    ///
+    /// `schema:contentSize` (`CONTENT_SIZE`)
+    pub static ref CONTENT_SIZE: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "contentSize");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// contentType
+    ///
+    /// The supported content type(s) for an EntryPoint response.
+    ///
+    /// `schema:contentType` (`CONTENT_TYPE`)
+    pub static ref CONTENT_TYPE: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "contentType");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// contentUrl
+    ///
+    /// Actual bytes of the media object, for example the image file or video file.
+    ///
+    /// `schema:contentUrl` (`CONTENT_URL`)
+    pub static ref CONTENT_URL: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "contentUrl");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// contraindication
+    ///
+    /// A contraindication for this therapy.
+    ///
+    /// `schema:contraindication` (`CONTRAINDICATION`)
+    pub static ref CONTRAINDICATION: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "contraindication");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// contributor
+    ///
+    /// A secondary contributor to the CreativeWork or Event.
+    ///
+    /// `schema:contributor` (`CONTRIBUTOR`)
+    pub static ref CONTRIBUTOR: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "contributor");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// cookTime
+    ///
+    /// The time it takes to actually cook the dish, in ISO 8601 duration format.
+    ///
+    /// `schema:cookTime` (`COOK_TIME`)
+    pub static ref COOK_TIME: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "cookTime");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// cookingMethod
+    ///
+    /// The method of cooking, such as Frying, Steaming, ...
+    ///
+    /// `schema:cookingMethod` (`COOKING_METHOD`)
+    pub static ref COOKING_METHOD: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "cookingMethod");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// copyrightHolder
+    ///
+    /// The party holding the legal copyright to the CreativeWork.
+    ///
+    /// `schema:copyrightHolder` (`COPYRIGHT_HOLDER`)
+    pub static ref COPYRIGHT_HOLDER: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "copyrightHolder");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// copyrightNotice
+    ///
+    /// Text of a notice appropriate for describing the copyright aspects of this Creative Work, ideally indicating the owner of the copyright for the Work.
+    ///
+    /// `schema:copyrightNotice` (`COPYRIGHT_NOTICE`)
+    pub static ref COPYRIGHT_NOTICE: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "copyrightNotice");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// copyrightYear
+    ///
+    /// The year during which the claimed copyright for the CreativeWork was first asserted.
+    ///
+    /// `schema:copyrightYear` (`COPYRIGHT_YEAR`)
+    pub static ref COPYRIGHT_YEAR: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "copyrightYear");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// correction
+    ///
+    /// Indicates a correction to a CreativeWork, either via a CorrectionComment, textually or in another document.
+    ///
+    /// `schema:correction` (`CORRECTION`)
+    pub static ref CORRECTION: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "correction");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// correctionsPolicy
+    ///
+    /// For an Organization (e.g. NewsMediaOrganization), a statement describing (in news media, the newsroom’s) disclosure and correction policy for errors.
+    ///
+    /// `schema:correctionsPolicy` (`CORRECTIONS_POLICY`)
+    pub static ref CORRECTIONS_POLICY: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "correctionsPolicy");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// costCategory
+    ///
+    /// The category of cost, such as wholesale, retail, reimbursement cap, etc.
+    ///
+    /// `schema:costCategory` (`COST_CATEGORY`)
+    pub static ref COST_CATEGORY: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "costCategory");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// costCurrency
+    ///
+    /// The currency (in 3-letter) of the drug cost. See: http://en.wikipedia.org/wiki/ISO_4217.
+    ///
+    /// `schema:costCurrency` (`COST_CURRENCY`)
+    pub static ref COST_CURRENCY: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "costCurrency");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// costOrigin
+    ///
+    /// Additional details to capture the origin of the cost data. For example, 'Medicare Part B'.
+    ///
+    /// `schema:costOrigin` (`COST_ORIGIN`)
+    pub static ref COST_ORIGIN: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "costOrigin");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// costPerUnit
+    ///
+    /// The cost per unit of the drug.
+    ///
+    /// `schema:costPerUnit` (`COST_PER_UNIT`)
+    pub static ref COST_PER_UNIT: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "costPerUnit");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// countriesNotSupported
+    ///
+    /// Countries for which the application is not supported. You can also provide the two-letter ISO 3166-1 alpha-2 country code.
+    ///
+    /// `schema:countriesNotSupported` (`COUNTRIES_NOT_SUPPORTED`)
+    pub static ref COUNTRIES_NOT_SUPPORTED: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "countriesNotSupported");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// countriesSupported
+    ///
+    /// Countries for which the application is supported. You can also provide the two-letter ISO 3166-1 alpha-2 country code.
+    ///
+    /// `schema:countriesSupported` (`COUNTRIES_SUPPORTED`)
+    pub static ref COUNTRIES_SUPPORTED: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "countriesSupported");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// countryOfAssembly
+    ///
+    /// The place where the product was assembled.
+    ///
+    /// `schema:countryOfAssembly` (`COUNTRY_OF_ASSEMBLY`)
+    pub static ref COUNTRY_OF_ASSEMBLY: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "countryOfAssembly");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// countryOfLastProcessing
+    ///
+    /// The place where the item (typically Product) was last processed and tested before importation.
+    ///
+    /// `schema:countryOfLastProcessing` (`COUNTRY_OF_LAST_PROCESSING`)
+    pub static ref COUNTRY_OF_LAST_PROCESSING: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "countryOfLastProcessing");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// countryOfOrigin
+    ///
+    /// The country of origin of something, including products as well as creative works such as movie and TV content.
+    ///
+    /// `schema:countryOfOrigin` (`COUNTRY_OF_ORIGIN`)
+    pub static ref COUNTRY_OF_ORIGIN: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "countryOfOrigin");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// course
+    ///
+    /// A sub property of location. The course where this action was taken.
+    ///
+    /// `schema:course` (`COURSE_PROPERTY`)
+    pub static ref COURSE_PROPERTY: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "course");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// courseCode
+    ///
+    /// The identifier for the Course used by the course provider (e.g. CS101 or 6.001).
+    ///
+    /// `schema:courseCode` (`COURSE_CODE`)
+    pub static ref COURSE_CODE: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "courseCode");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// courseMode
+    ///
+    /// The medium or means of delivery of the course instance or the mode of study, either as a text label (e.g. "online", "onsite" or "blended"; "synchronous" or "asynchronous"; "full-time" or "part-time") or as a URL reference to a term from a controlled vocabulary.
+    ///
+    /// `schema:courseMode` (`COURSE_MODE`)
+    pub static ref COURSE_MODE: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "courseMode");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// coursePrerequisites
+    ///
+    /// Requirements for taking the Course. May be completion of another Course or a textual description like "permission of instructor". Requirements may be a pre-requisite competency, referenced using AlignmentObject.
+    ///
+    /// `schema:coursePrerequisites` (`COURSE_PREREQUISITES`)
+    pub static ref COURSE_PREREQUISITES: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "coursePrerequisites");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// courseSchedule
+    ///
+    /// Represents the length and pace of a course, expressed as a Schedule.
+    ///
+    /// `schema:courseSchedule` (`COURSE_SCHEDULE`)
+    pub static ref COURSE_SCHEDULE: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "courseSchedule");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// courseWorkload
+    ///
+    /// The amount of work expected of students taking the course, often provided as a figure per week or per month, and may be broken down by type. For example, "2 hours of lectures, 1 hour of lab work and 3 hours of independent study per week".
+    ///
+    /// `schema:courseWorkload` (`COURSE_WORKLOAD`)
+    pub static ref COURSE_WORKLOAD: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "courseWorkload");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// coverageEndTime
+    ///
+    /// The time when the live blog will stop covering the Event. Note that coverage may continue after the Event concludes.
+    ///
+    /// `schema:coverageEndTime` (`COVERAGE_END_TIME`)
+    pub static ref COVERAGE_END_TIME: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "coverageEndTime");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// coverageStartTime
+    ///
+    /// The time when the live blog will begin covering the Event. Note that coverage may begin before the Event's start time. The LiveBlogPosting may also be created before coverage begins.
+    ///
+    /// `schema:coverageStartTime` (`COVERAGE_START_TIME`)
+    pub static ref COVERAGE_START_TIME: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "coverageStartTime");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// creativeWorkStatus
+    ///
+    /// The status of a creative work in terms of its stage in a lifecycle. Example terms include Incomplete, Draft, Published, Obsolete. Some organizations define a set of terms for the stages of their publication lifecycle.
+    ///
+    /// `schema:creativeWorkStatus` (`CREATIVE_WORK_STATUS`)
+    pub static ref CREATIVE_WORK_STATUS: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "creativeWorkStatus");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// creator
+    ///
+    /// The creator/author of this CreativeWork. This is the same as the Author property for CreativeWork.
+    ///
+    /// `schema:creator` (`CREATOR`)
+    pub static ref CREATOR: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "creator");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// credentialCategory
+    ///
+    /// The category or type of credential being described, for example "degree”, “certificate”, “badge”, or more specific term.
+    ///
+    /// `schema:credentialCategory` (`CREDENTIAL_CATEGORY`)
+    pub static ref CREDENTIAL_CATEGORY: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "credentialCategory");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// creditText
+    ///
+    /// Text that can be used to credit person(s) and/or organization(s) associated with a published Creative Work.
+    ///
+    /// `schema:creditText` (`CREDIT_TEXT`)
+    pub static ref CREDIT_TEXT: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "creditText");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// creditedTo
+    ///
+    /// The group the release is credited to if different than the byArtist. For example, Red and Blue is credited to "Stefani Germanotta Band", but by Lady Gaga.
+    ///
+    /// `schema:creditedTo` (`CREDITED_TO`)
+    pub static ref CREDITED_TO: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "creditedTo");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// cssSelector
+    ///
+    /// A CSS selector, e.g. of a SpeakableSpecification or WebPageElement. In the latter case, multiple matches within a page can constitute a single conceptual "Web page element".
+    ///
+    /// `schema:cssSelector` (`CSS_SELECTOR`)
+    pub static ref CSS_SELECTOR: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "cssSelector");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// currenciesAccepted
+    ///
+    /// The currency accepted. Use standard formats: ISO 4217 currency format, e.g. "USD"; Ticker symbol for cryptocurrencies, e.g. "BTC"; well known names for Local Exchange Trading Systems (LETS) and other currency types, e.g. "Ithaca HOUR".
+    ///
+    /// `schema:currenciesAccepted` (`CURRENCIES_ACCEPTED`)
+    pub static ref CURRENCIES_ACCEPTED: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "currenciesAccepted");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// currency
+    ///
+    /// The currency in which the monetary amount is expressed. Use standard formats: ISO 4217 currency format, e.g. "USD"; Ticker symbol for cryptocurrencies, e.g. "BTC"; well known names for Local Exchange Trading Systems (LETS) and other currency types, e.g. "Ithaca HOUR".
+    ///
+    /// `schema:currency` (`CURRENCY`)
+    pub static ref CURRENCY: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "currency");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// currentExchangeRate
+    ///
+    /// The current price of a currency.
+    ///
+    /// `schema:currentExchangeRate` (`CURRENT_EXCHANGE_RATE`)
+    pub static ref CURRENT_EXCHANGE_RATE: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "currentExchangeRate");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// customer
+    ///
+    /// Party placing the order or paying the invoice.
+    ///
+    /// `schema:customer` (`CUSTOMER`)
+    pub static ref CUSTOMER: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "customer");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// customerRemorseReturnFees
+    ///
+    /// The type of return fees if the product is returned due to customer remorse.
+    ///
+    /// `schema:customerRemorseReturnFees` (`CUSTOMER_REMORSE_RETURN_FEES`)
+    pub static ref CUSTOMER_REMORSE_RETURN_FEES: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "customerRemorseReturnFees");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// customerRemorseReturnLabelSource
+    ///
+    /// The method (from an enumeration) by which the customer obtains a return shipping label for a product returned due to customer remorse.
+    ///
+    /// `schema:customerRemorseReturnLabelSource` (`CUSTOMER_REMORSE_RETURN_LABEL_SOURCE`)
+    pub static ref CUSTOMER_REMORSE_RETURN_LABEL_SOURCE: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "customerRemorseReturnLabelSource");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// customerRemorseReturnShippingFeesAmount
+    ///
+    /// The amount of shipping costs if a product is returned due to customer remorse. Applicable when property customerRemorseReturnFees equals ReturnShippingFees.
+    ///
+    /// `schema:customerRemorseReturnShippingFeesAmount` (`CUSTOMER_REMORSE_RETURN_SHIPPING_FEES_AMOUNT`)
+    pub static ref CUSTOMER_REMORSE_RETURN_SHIPPING_FEES_AMOUNT: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "customerRemorseReturnShippingFeesAmount");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// cutoffTime
+    ///
+    /// Order cutoff time allows merchants to describe the time after which they will no longer process orders received on that day. For orders processed after cutoff time, one day gets added to the delivery time estimate.
+    ///
+    /// `schema:cutoffTime` (`CUTOFF_TIME`)
+    pub static ref CUTOFF_TIME: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "cutoffTime");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// cvdCollectionDate
+    ///
+    /// collectiondate - Date for which patient counts are reported.
+    ///
+    /// `schema:cvdCollectionDate` (`CVD_COLLECTION_DATE`)
+    pub static ref CVD_COLLECTION_DATE: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "cvdCollectionDate");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// cvdFacilityCounty
+    ///
+    /// Name of the County of the NHSN facility that this data record applies to. Use cvdFacilityId to identify the facility.
+    ///
+    /// `schema:cvdFacilityCounty` (`CVD_FACILITY_COUNTY`)
+    pub static ref CVD_FACILITY_COUNTY: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "cvdFacilityCounty");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// cvdFacilityId
+    ///
+    /// Identifier of the NHSN facility that this data record applies to. Use cvdFacilityCounty to indicate the county.
+    ///
+    /// `schema:cvdFacilityId` (`CVD_FACILITY_ID`)
+    pub static ref CVD_FACILITY_ID: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "cvdFacilityId");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// cvdNumBeds
+    ///
+    /// numbeds - HOSPITAL INPATIENT BEDS: Inpatient beds, including all staffed, licensed, and overflow (surge) beds used for inpatients.
+    ///
+    /// `schema:cvdNumBeds` (`CVD_NUM_BEDS`)
+    pub static ref CVD_NUM_BEDS: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "cvdNumBeds");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// cvdNumBedsOcc
+    ///
+    /// numbedsocc - HOSPITAL INPATIENT BED OCCUPANCY: Total number of staffed inpatient beds that are occupied.
+    ///
+    /// `schema:cvdNumBedsOcc` (`CVD_NUM_BEDS_OCC`)
+    pub static ref CVD_NUM_BEDS_OCC: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "cvdNumBedsOcc");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// cvdNumC19Died
+    ///
+    /// numc19died - DEATHS: Patients with suspected or confirmed COVID-19 who died in the hospital, ED, or any overflow location.
+    ///
+    /// `schema:cvdNumC19Died` (`CVD_NUM_C19_DIED`)
+    pub static ref CVD_NUM_C19_DIED: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "cvdNumC19Died");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// cvdNumC19HOPats
+    ///
+    /// numc19hopats - HOSPITAL ONSET: Patients hospitalized in an NHSN inpatient care location with onset of suspected or confirmed COVID-19 14 or more days after hospitalization.
+    ///
+    /// `schema:cvdNumC19HOPats` (`CVD_NUM_C19_HO_PATS`)
+    pub static ref CVD_NUM_C19_HO_PATS: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "cvdNumC19HOPats");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// cvdNumC19HospPats
+    ///
+    /// numc19hosppats - HOSPITALIZED: Patients currently hospitalized in an inpatient care location who have suspected or confirmed COVID-19.
+    ///
+    /// `schema:cvdNumC19HospPats` (`CVD_NUM_C19_HOSP_PATS`)
+    pub static ref CVD_NUM_C19_HOSP_PATS: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "cvdNumC19HospPats");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// cvdNumC19MechVentPats
+    ///
+    /// numc19mechventpats - HOSPITALIZED and VENTILATED: Patients hospitalized in an NHSN inpatient care location who have suspected or confirmed COVID-19 and are on a mechanical ventilator.
+    ///
+    /// `schema:cvdNumC19MechVentPats` (`CVD_NUM_C19_MECH_VENT_PATS`)
+    pub static ref CVD_NUM_C19_MECH_VENT_PATS: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "cvdNumC19MechVentPats");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// cvdNumC19OFMechVentPats
+    ///
+    /// numc19ofmechventpats - ED/OVERFLOW and VENTILATED: Patients with suspected or confirmed COVID-19 who are in the ED or any overflow location awaiting an inpatient bed and on a mechanical ventilator.
+    ///
+    /// `schema:cvdNumC19OFMechVentPats` (`CVD_NUM_C19_OF_MECH_VENT_PATS`)
+    pub static ref CVD_NUM_C19_OF_MECH_VENT_PATS: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "cvdNumC19OFMechVentPats");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// cvdNumC19OverflowPats
+    ///
+    /// numc19overflowpats - ED/OVERFLOW: Patients with suspected or confirmed COVID-19 who are in the ED or any overflow location awaiting an inpatient bed.
+    ///
+    /// `schema:cvdNumC19OverflowPats` (`CVD_NUM_C19_OVERFLOW_PATS`)
+    pub static ref CVD_NUM_C19_OVERFLOW_PATS: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "cvdNumC19OverflowPats");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// cvdNumICUBeds
+    ///
+    /// numicubeds - ICU BEDS: Total number of staffed inpatient intensive care unit (ICU) beds.
+    ///
+    /// `schema:cvdNumICUBeds` (`CVD_NUM_ICU_BEDS`)
+    pub static ref CVD_NUM_ICU_BEDS: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "cvdNumICUBeds");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// cvdNumICUBedsOcc
+    ///
+    /// numicubedsocc - ICU BED OCCUPANCY: Total number of staffed inpatient ICU beds that are occupied.
+    ///
+    /// `schema:cvdNumICUBedsOcc` (`CVD_NUM_ICU_BEDS_OCC`)
+    pub static ref CVD_NUM_ICU_BEDS_OCC: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "cvdNumICUBedsOcc");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// cvdNumTotBeds
+    ///
+    /// numtotbeds - ALL HOSPITAL BEDS: Total number of all inpatient and outpatient beds, including all staffed, ICU, licensed, and overflow (surge) beds used for inpatients or outpatients.
+    ///
+    /// `schema:cvdNumTotBeds` (`CVD_NUM_TOT_BEDS`)
+    pub static ref CVD_NUM_TOT_BEDS: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "cvdNumTotBeds");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// cvdNumVent
+    ///
+    /// numvent - MECHANICAL VENTILATORS: Total number of ventilators available.
+    ///
+    /// `schema:cvdNumVent` (`CVD_NUM_VENT`)
+    pub static ref CVD_NUM_VENT: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "cvdNumVent");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// cvdNumVentUse
+    ///
+    /// numventuse - MECHANICAL VENTILATORS IN USE: Total number of ventilators in use.
+    ///
+    /// `schema:cvdNumVentUse` (`CVD_NUM_VENT_USE`)
+    pub static ref CVD_NUM_VENT_USE: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "cvdNumVentUse");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// dataFeedElement
+    ///
+    /// An item within a data feed. Data feeds may have many elements.
+    ///
+    /// `schema:dataFeedElement` (`DATA_FEED_ELEMENT`)
+    pub static ref DATA_FEED_ELEMENT: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "dataFeedElement");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// dataset
+    ///
+    /// A dataset contained in this catalog.
+    ///
+    /// `schema:dataset` (`DATASET_PROPERTY`)
+    pub static ref DATASET_PROPERTY: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "dataset");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// datasetTimeInterval
+    ///
+    /// The range of temporal applicability of a dataset, e.g. for a 2011 census dataset, the year 2011 (in ISO 8601 time interval format).
+    ///
+    /// `schema:datasetTimeInterval` (`DATASET_TIME_INTERVAL`)
+    pub static ref DATASET_TIME_INTERVAL: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "datasetTimeInterval");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// dateCreated
+    ///
+    /// The date on which the CreativeWork was created or the item was added to a DataFeed.
+    ///
+    /// `schema:dateCreated` (`DATE_CREATED`)
+    pub static ref DATE_CREATED: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "dateCreated");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// dateDeleted
+    ///
+    /// The datetime the item was removed from the DataFeed.
+    ///
+    /// `schema:dateDeleted` (`DATE_DELETED`)
+    pub static ref DATE_DELETED: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "dateDeleted");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// dateIssued
+    ///
+    /// The date the ticket was issued.
+    ///
+    /// `schema:dateIssued` (`DATE_ISSUED`)
+    pub static ref DATE_ISSUED: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "dateIssued");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// dateModified
+    ///
+    /// The date on which the CreativeWork was most recently modified or when the item's entry was modified within a DataFeed.
+    ///
+    /// `schema:dateModified` (`DATE_MODIFIED`)
+    pub static ref DATE_MODIFIED: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "dateModified");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// datePosted
+    ///
+    /// Publication date of an online listing.
+    ///
+    /// `schema:datePosted` (`DATE_POSTED`)
+    pub static ref DATE_POSTED: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "datePosted");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// datePublished
+    ///
+    /// Date of first publication or broadcast. For example the date a CreativeWork was broadcast or a Certification was issued.
+    ///
+    /// `schema:datePublished` (`DATE_PUBLISHED`)
+    pub static ref DATE_PUBLISHED: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "datePublished");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// dateRead
+    ///
+    /// The date/time at which the message has been read by the recipient if a single recipient exists.
+    ///
+    /// `schema:dateRead` (`DATE_READ`)
+    pub static ref DATE_READ: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "dateRead");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// dateReceived
+    ///
+    /// The date/time the message was received if a single recipient exists.
+    ///
+    /// `schema:dateReceived` (`DATE_RECEIVED`)
+    pub static ref DATE_RECEIVED: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "dateReceived");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// dateSent
+    ///
+    /// The date/time at which the message was sent.
+    ///
+    /// `schema:dateSent` (`DATE_SENT`)
+    pub static ref DATE_SENT: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "dateSent");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// dateVehicleFirstRegistered
+    ///
+    /// The date of the first registration of the vehicle with the respective public authorities.
+    ///
+    /// `schema:dateVehicleFirstRegistered` (`DATE_VEHICLE_FIRST_REGISTERED`)
+    pub static ref DATE_VEHICLE_FIRST_REGISTERED: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "dateVehicleFirstRegistered");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// dateline
+    ///
+    /// A dateline is a brief piece of text included in news articles that describes where and when the story was written or filed though the date is often omitted.
+    ///
+    /// `schema:dateline` (`DATELINE`)
+    pub static ref DATELINE: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "dateline");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// dayOfWeek
+    ///
+    /// The day of the week for which these opening hours are valid.
+    ///
+    /// `schema:dayOfWeek` (`DAY_OF_WEEK_PROPERTY`)
+    pub static ref DAY_OF_WEEK_PROPERTY: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "dayOfWeek");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// deathDate
+    ///
+    /// Date of death.
+    ///
+    /// `schema:deathDate` (`DEATH_DATE`)
+    pub static ref DEATH_DATE: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "deathDate");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// deathPlace
+    ///
+    /// The place where the person died.
+    ///
+    /// `schema:deathPlace` (`DEATH_PLACE`)
+    pub static ref DEATH_PLACE: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "deathPlace");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// defaultValue
+    ///
+    /// The default value of the input. For properties that expect a literal, the default is a literal value, for properties that expect an object, it's an ID reference to one of the current values.
+    ///
+    /// `schema:defaultValue` (`DEFAULT_VALUE`)
+    pub static ref DEFAULT_VALUE: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "defaultValue");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// deliveryAddress
+    ///
+    /// Destination address.
+    ///
+    /// `schema:deliveryAddress` (`DELIVERY_ADDRESS`)
+    pub static ref DELIVERY_ADDRESS: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "deliveryAddress");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// deliveryLeadTime
+    ///
+    /// The typical delay between the receipt of the order and the goods either leaving the warehouse or being prepared for pickup, in case the delivery method is on site pickup.
+    ///
+    /// `schema:deliveryLeadTime` (`DELIVERY_LEAD_TIME`)
+    pub static ref DELIVERY_LEAD_TIME: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "deliveryLeadTime");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// deliveryMethod
+    ///
+    /// A sub property of instrument. The method of delivery.
+    ///
+    /// `schema:deliveryMethod` (`DELIVERY_METHOD_PROPERTY`)
+    pub static ref DELIVERY_METHOD_PROPERTY: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "deliveryMethod");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// deliveryStatus
+    ///
+    /// New entry added as the package passes through each leg of its journey (from shipment to final delivery).
+    ///
+    /// `schema:deliveryStatus` (`DELIVERY_STATUS`)
+    pub static ref DELIVERY_STATUS: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "deliveryStatus");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// deliveryTime
+    ///
+    /// The total delay between the receipt of the order and the goods reaching the final customer.
+    ///
+    /// `schema:deliveryTime` (`DELIVERY_TIME`)
+    pub static ref DELIVERY_TIME: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "deliveryTime");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// department
+    ///
+    /// A relationship between an organization and a department of that organization, also described as an organization (allowing different urls, logos, opening hours). For example: a store with a pharmacy, or a bakery with a cafe.
+    ///
+    /// `schema:department` (`DEPARTMENT`)
+    pub static ref DEPARTMENT: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "department");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// departureAirport
+    ///
+    /// The airport where the flight originates.
+    ///
+    /// `schema:departureAirport` (`DEPARTURE_AIRPORT`)
+    pub static ref DEPARTURE_AIRPORT: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "departureAirport");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// departureBoatTerminal
+    ///
+    /// The terminal or port from which the boat departs.
+    ///
+    /// `schema:departureBoatTerminal` (`DEPARTURE_BOAT_TERMINAL`)
+    pub static ref DEPARTURE_BOAT_TERMINAL: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "departureBoatTerminal");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// departureBusStop
+    ///
+    /// The stop or station from which the bus departs.
+    ///
+    /// `schema:departureBusStop` (`DEPARTURE_BUS_STOP`)
+    pub static ref DEPARTURE_BUS_STOP: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "departureBusStop");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// departureGate
+    ///
+    /// Identifier of the flight's departure gate.
+    ///
+    /// `schema:departureGate` (`DEPARTURE_GATE`)
+    pub static ref DEPARTURE_GATE: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "departureGate");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// departurePlatform
+    ///
+    /// The platform from which the train departs.
+    ///
+    /// `schema:departurePlatform` (`DEPARTURE_PLATFORM`)
+    pub static ref DEPARTURE_PLATFORM: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "departurePlatform");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// departureStation
+    ///
+    /// The station from which the train departs.
+    ///
+    /// `schema:departureStation` (`DEPARTURE_STATION`)
+    pub static ref DEPARTURE_STATION: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "departureStation");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// departureTerminal
+    ///
+    /// Identifier of the flight's departure terminal.
+    ///
+    /// `schema:departureTerminal` (`DEPARTURE_TERMINAL`)
+    pub static ref DEPARTURE_TERMINAL: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "departureTerminal");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// departureTime
+    ///
+    /// The expected departure time.
+    ///
+    /// `schema:departureTime` (`DEPARTURE_TIME`)
+    pub static ref DEPARTURE_TIME: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "departureTime");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// dependencies
+    ///
+    /// Prerequisites needed to fulfill steps in article.
+    ///
+    /// `schema:dependencies` (`DEPENDENCIES`)
+    pub static ref DEPENDENCIES: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "dependencies");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// depth
+    ///
+    /// The depth of the item.
+    ///
+    /// `schema:depth` (`DEPTH`)
+    pub static ref DEPTH: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "depth");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// description
+    ///
+    /// A description of the item.
+    ///
+    /// `schema:description` (`DESCRIPTION`)
+    pub static ref DESCRIPTION: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "description");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// device
+    ///
+    /// Device required to run the application. Used in cases where a specific make/model is required to run the application.
+    ///
+    /// `schema:device` (`DEVICE`)
+    pub static ref DEVICE: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "device");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// diagnosis
+    ///
+    /// One or more alternative conditions considered in the differential diagnosis process as output of a diagnosis process.
+    ///
+    /// `schema:diagnosis` (`DIAGNOSIS`)
+    pub static ref DIAGNOSIS: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "diagnosis");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// diagram
+    ///
+    /// An image containing a diagram that illustrates the structure and/or its component substructures and/or connections with other structures.
+    ///
+    /// `schema:diagram` (`DIAGRAM`)
+    pub static ref DIAGRAM: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "diagram");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// diet
+    ///
+    /// A sub property of instrument. The diet used in this action.
+    ///
+    /// `schema:diet` (`DIET_PROPERTY`)
+    pub static ref DIET_PROPERTY: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "diet");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// dietFeatures
+    ///
+    /// Nutritional information specific to the dietary plan. May include dietary recommendations on what foods to avoid, what foods to consume, and specific alterations/deviations from the USDA or other regulatory body's approved dietary guidelines.
+    ///
+    /// `schema:dietFeatures` (`DIET_FEATURES`)
+    pub static ref DIET_FEATURES: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "dietFeatures");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// differentialDiagnosis
+    ///
+    /// One of a set of differential diagnoses for the condition. Specifically, a closely-related or competing diagnosis typically considered later in the cognitive process whereby this medical condition is distinguished from others most likely responsible for a similar collection of signs and symptoms to reach the most parsimonious diagnosis or diagnoses in a patient.
+    ///
+    /// `schema:differentialDiagnosis` (`DIFFERENTIAL_DIAGNOSIS`)
+    pub static ref DIFFERENTIAL_DIAGNOSIS: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "differentialDiagnosis");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// directApply
+    ///
+    /// Indicates whether an url that is associated with a JobPosting enables direct application for the job, via the posting website. A job posting is considered to have directApply of True if an application process for the specified job can be directly initiated via the url(s) given.
+    ///
+    /// `schema:directApply` (`DIRECT_APPLY`)
+    pub static ref DIRECT_APPLY: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "directApply");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// director
+    ///
+    /// A director of e.g. TV, radio, movie, video gaming etc. content, or of an event. Directors can be associated with individual items or with a series, episode, clip.
+    ///
+    /// `schema:director` (`DIRECTOR`)
+    pub static ref DIRECTOR: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "director");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// directors
+    ///
+    /// A director of e.g. TV, radio, movie, video games etc. content. Directors can be associated with individual items or with a series, episode, clip.
+    ///
+    /// `schema:directors` (`DIRECTORS`)
+    pub static ref DIRECTORS: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "directors");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// disambiguatingDescription
+    ///
+    /// A sub property of description. A short description of the item used to disambiguate from other, similar items. Information from other properties (in particular, name) may be necessary for the description to be useful for disambiguation.
+    ///
+    /// `schema:disambiguatingDescription` (`DISAMBIGUATING_DESCRIPTION`)
+    pub static ref DISAMBIGUATING_DESCRIPTION: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "disambiguatingDescription");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// discount
+    ///
+    /// Any discount applied (to an Order).
+    ///
+    /// `schema:discount` (`DISCOUNT`)
+    pub static ref DISCOUNT: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "discount");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// discountCode
+    ///
+    /// Code used to redeem a discount.
+    ///
+    /// `schema:discountCode` (`DISCOUNT_CODE`)
+    pub static ref DISCOUNT_CODE: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "discountCode");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// discountCurrency
+    ///
+    /// The currency of the discount.
+    ///
+    /// `schema:discountCurrency` (`DISCOUNT_CURRENCY`)
+    pub static ref DISCOUNT_CURRENCY: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "discountCurrency");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// discusses
+    ///
+    /// Specifies the CreativeWork associated with the UserComment.
+    ///
+    /// `schema:discusses` (`DISCUSSES`)
+    pub static ref DISCUSSES: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "discusses");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// discussionUrl
+    ///
+    /// A link to the page containing the comments of the CreativeWork.
+    ///
+    /// `schema:discussionUrl` (`DISCUSSION_URL`)
+    pub static ref DISCUSSION_URL: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "discussionUrl");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// diseasePreventionInfo
+    ///
+    /// Information about disease prevention.
+    ///
+    /// `schema:diseasePreventionInfo` (`DISEASE_PREVENTION_INFO`)
+    pub static ref DISEASE_PREVENTION_INFO: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "diseasePreventionInfo");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// diseaseSpreadStatistics
+    ///
+    /// Statistical information about the spread of a disease, either as WebContent, or described directly as a Dataset, or the specific Observations in the dataset.
+    ///
+    /// `schema:diseaseSpreadStatistics` (`DISEASE_SPREAD_STATISTICS`)
+    pub static ref DISEASE_SPREAD_STATISTICS: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "diseaseSpreadStatistics");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// dissolutionDate
+    ///
+    /// The date that this organization was dissolved.
+    ///
+    /// `schema:dissolutionDate` (`DISSOLUTION_DATE`)
+    pub static ref DISSOLUTION_DATE: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "dissolutionDate");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// distance
+    ///
+    /// The distance travelled, e.g. exercising or travelling.
+    ///
+    /// `schema:distance` (`DISTANCE_PROPERTY`)
+    pub static ref DISTANCE_PROPERTY: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "distance");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// distinguishingSign
+    ///
+    /// One of a set of signs and symptoms that can be used to distinguish this diagnosis from others in the differential diagnosis.
+    ///
+    /// `schema:distinguishingSign` (`DISTINGUISHING_SIGN`)
+    pub static ref DISTINGUISHING_SIGN: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "distinguishingSign");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// distribution
+    ///
+    /// A downloadable form of this dataset, at a specific location, in a specific format. This property can be repeated if different variations are available.
+    ///
+    /// `schema:distribution` (`DISTRIBUTION`)
+    pub static ref DISTRIBUTION: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "distribution");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// diversityPolicy
+    ///
+    /// Statement on diversity policy by an Organization e.g. a NewsMediaOrganization.
+    ///
+    /// `schema:diversityPolicy` (`DIVERSITY_POLICY`)
+    pub static ref DIVERSITY_POLICY: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "diversityPolicy");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// diversityStaffingReport
+    ///
+    /// For an Organization (often but not necessarily a NewsMediaOrganization), a report on staffing diversity issues.
+    ///
+    /// `schema:diversityStaffingReport` (`DIVERSITY_STAFFING_REPORT`)
+    pub static ref DIVERSITY_STAFFING_REPORT: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "diversityStaffingReport");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// documentation
+    ///
+    /// Further documentation describing the Web API in more detail.
+    ///
+    /// `schema:documentation` (`DOCUMENTATION`)
+    pub static ref DOCUMENTATION: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "documentation");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// doesNotShip
+    ///
+    /// Indicates when shipping to a particular shippingDestination is not available.
+    ///
+    /// `schema:doesNotShip` (`DOES_NOT_SHIP`)
+    pub static ref DOES_NOT_SHIP: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "doesNotShip");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// domainIncludes
+    ///
+    /// Relates a property to a class that is (one of) the type(s) the property is expected to be used on.
+    ///
+    /// `schema:domainIncludes` (`DOMAIN_INCLUDES`)
+    pub static ref DOMAIN_INCLUDES: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "domainIncludes");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// domiciledMortgage
+    ///
+    /// Whether borrower is a resident of the jurisdiction where the property is located.
+    ///
+    /// `schema:domiciledMortgage` (`DOMICILED_MORTGAGE`)
+    pub static ref DOMICILED_MORTGAGE: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "domiciledMortgage");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// doorTime
+    ///
+    /// The time admission will commence.
+    ///
+    /// `schema:doorTime` (`DOOR_TIME`)
+    pub static ref DOOR_TIME: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "doorTime");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// dosageForm
+    ///
+    /// A dosage form in which this drug/supplement is available, e.g. 'tablet', 'suspension', 'injection'.
+    ///
+    /// `schema:dosageForm` (`DOSAGE_FORM`)
+    pub static ref DOSAGE_FORM: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "dosageForm");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// doseSchedule
+    ///
+    /// A dosing schedule for the drug for a given population, either observed, recommended, or maximum dose based on the type used.
+    ///
+    /// `schema:doseSchedule` (`DOSE_SCHEDULE_PROPERTY`)
+    pub static ref DOSE_SCHEDULE_PROPERTY: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "doseSchedule");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// doseUnit
+    ///
+    /// The unit of the dose, e.g. 'mg'.
+    ///
+    /// `schema:doseUnit` (`DOSE_UNIT`)
+    pub static ref DOSE_UNIT: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "doseUnit");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// doseValue
+    ///
+    /// The value of the dose, e.g. 500.
+    ///
+    /// `schema:doseValue` (`DOSE_VALUE`)
+    pub static ref DOSE_VALUE: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "doseValue");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// downPayment
+    ///
+    /// a type of payment made in cash during the onset of the purchase of an expensive good/service. The payment typically represents only a percentage of the full purchase price.
+    ///
+    /// `schema:downPayment` (`DOWN_PAYMENT`)
+    pub static ref DOWN_PAYMENT: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "downPayment");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// downloadUrl
+    ///
+    /// If the file can be downloaded, URL to download the binary.
+    ///
+    /// `schema:downloadUrl` (`DOWNLOAD_URL`)
+    pub static ref DOWNLOAD_URL: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "downloadUrl");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// downvoteCount
+    ///
+    /// The number of downvotes this question, answer or comment has received from the community.
+    ///
+    /// `schema:downvoteCount` (`DOWNVOTE_COUNT`)
+    pub static ref DOWNVOTE_COUNT: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "downvoteCount");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// drainsTo
+    ///
+    /// The vasculature that the vein drains into.
+    ///
+    /// `schema:drainsTo` (`DRAINS_TO`)
+    pub static ref DRAINS_TO: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "drainsTo");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// driveWheelConfiguration
+    ///
+    /// The drive wheel configuration, i.e. which roadwheels will receive torque from the vehicle's engine via the drivetrain.
+    ///
+    /// `schema:driveWheelConfiguration` (`DRIVE_WHEEL_CONFIGURATION`)
+    pub static ref DRIVE_WHEEL_CONFIGURATION: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "driveWheelConfiguration");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// dropoffLocation
+    ///
+    /// Where a rental car can be dropped off.
+    ///
+    /// `schema:dropoffLocation` (`DROPOFF_LOCATION`)
+    pub static ref DROPOFF_LOCATION: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "dropoffLocation");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// dropoffTime
+    ///
+    /// When a rental car can be dropped off.
+    ///
+    /// `schema:dropoffTime` (`DROPOFF_TIME`)
+    pub static ref DROPOFF_TIME: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "dropoffTime");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// drug
+    ///
+    /// Specifying a drug or medicine used in a medication procedure.
+    ///
+    /// `schema:drug` (`DRUG_PROPERTY`)
+    pub static ref DRUG_PROPERTY: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "drug");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// drugClass
+    ///
+    /// The class of drug this belongs to (e.g., statins).
+    ///
+    /// `schema:drugClass` (`DRUG_CLASS_PROPERTY`)
+    pub static ref DRUG_CLASS_PROPERTY: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "drugClass");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// drugUnit
+    ///
+    /// The unit in which the drug is measured, e.g. '5 mg tablet'.
+    ///
+    /// `schema:drugUnit` (`DRUG_UNIT`)
+    pub static ref DRUG_UNIT: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "drugUnit");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// duns
+    ///
+    /// The Dun & Bradstreet DUNS number for identifying an organization or business person.
+    ///
+    /// `schema:duns` (`DUNS`)
+    pub static ref DUNS: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "duns");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// duplicateTherapy
+    ///
+    /// A therapy that duplicates or overlaps this one.
+    ///
+    /// `schema:duplicateTherapy` (`DUPLICATE_THERAPY`)
+    pub static ref DUPLICATE_THERAPY: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "duplicateTherapy");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// duration
+    ///
+    /// The duration of the item (movie, audio recording, event, etc.) in ISO 8601 duration format.
+    ///
+    /// `schema:duration` (`DURATION_PROPERTY`)
+    pub static ref DURATION_PROPERTY: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "duration");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// durationOfWarranty
+    ///
+    /// The duration of the warranty promise. Common unitCode values are ANN for year, MON for months, or DAY for days.
+    ///
+    /// `schema:durationOfWarranty` (`DURATION_OF_WARRANTY`)
+    pub static ref DURATION_OF_WARRANTY: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "durationOfWarranty");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// duringMedia
+    ///
+    /// A media object representing the circumstances while performing this direction.
+    ///
+    /// `schema:duringMedia` (`DURING_MEDIA`)
+    pub static ref DURING_MEDIA: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "duringMedia");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// earlyPrepaymentPenalty
+    ///
+    /// The amount to be paid as a penalty in the event of early payment of the loan.
+    ///
+    /// `schema:earlyPrepaymentPenalty` (`EARLY_PREPAYMENT_PENALTY`)
+    pub static ref EARLY_PREPAYMENT_PENALTY: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "earlyPrepaymentPenalty");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// editEIDR
+    ///
+    /// An EIDR (Entertainment Identifier Registry) identifier representing a specific edit / edition for a work of film or television.
+    ///
+    /// `schema:editEIDR` (`EDIT_EIDR`)
+    pub static ref EDIT_EIDR: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "editEIDR");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// editor
+    ///
+    /// Specifies the Person who edited the CreativeWork.
+    ///
+    /// `schema:editor` (`EDITOR`)
+    pub static ref EDITOR: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "editor");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// eduQuestionType
+    ///
+    /// For questions that are part of learning resources (e.g. Quiz), eduQuestionType indicates the format of question being given. Example: "Multiple choice", "Open ended", "Flashcard".
+    ///
+    /// `schema:eduQuestionType` (`EDU_QUESTION_TYPE`)
+    pub static ref EDU_QUESTION_TYPE: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "eduQuestionType");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// educationRequirements
+    ///
+    /// Educational background needed for the position or Occupation.
+    ///
+    /// `schema:educationRequirements` (`EDUCATION_REQUIREMENTS`)
+    pub static ref EDUCATION_REQUIREMENTS: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "educationRequirements");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// educationalAlignment
+    ///
+    /// An alignment to an established educational framework.
+    ///
+    /// `schema:educationalAlignment` (`EDUCATIONAL_ALIGNMENT`)
+    pub static ref EDUCATIONAL_ALIGNMENT: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "educationalAlignment");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// educationalCredentialAwarded
+    ///
+    /// A description of the qualification, award, certificate, diploma or other educational credential awarded as a consequence of successful completion of this course or program.
+    ///
+    /// `schema:educationalCredentialAwarded` (`EDUCATIONAL_CREDENTIAL_AWARDED`)
+    pub static ref EDUCATIONAL_CREDENTIAL_AWARDED: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "educationalCredentialAwarded");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// educationalFramework
+    ///
+    /// The framework to which the resource being described is aligned.
+    ///
+    /// `schema:educationalFramework` (`EDUCATIONAL_FRAMEWORK`)
+    pub static ref EDUCATIONAL_FRAMEWORK: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "educationalFramework");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// educationalLevel
+    ///
+    /// The level in terms of progression through an educational or training context. Examples of educational levels include 'beginner', 'intermediate' or 'advanced', and formal sets of level indicators.
+    ///
+    /// `schema:educationalLevel` (`EDUCATIONAL_LEVEL`)
+    pub static ref EDUCATIONAL_LEVEL: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "educationalLevel");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// educationalProgramMode
+    ///
+    /// Similar to courseMode, the medium or means of delivery of the program as a whole. The value may either be a text label (e.g. "online", "onsite" or "blended"; "synchronous" or "asynchronous"; "full-time" or "part-time") or a URL reference to a term from a controlled vocabulary.
+    ///
+    /// `schema:educationalProgramMode` (`EDUCATIONAL_PROGRAM_MODE`)
+    pub static ref EDUCATIONAL_PROGRAM_MODE: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "educationalProgramMode");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// educationalRole
+    ///
+    /// An educationalRole of an EducationalAudience.
+    ///
+    /// `schema:educationalRole` (`EDUCATIONAL_ROLE`)
+    pub static ref EDUCATIONAL_ROLE: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "educationalRole");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// educationalUse
+    ///
+    /// The purpose of a work in the context of education; for example, 'assignment', 'group work'.
+    ///
+    /// `schema:educationalUse` (`EDUCATIONAL_USE`)
+    pub static ref EDUCATIONAL_USE: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "educationalUse");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// elevation
+    ///
+    /// The elevation of a location (WGS 84). Values may be of the form 'NUMBER UNIT_OF_MEASUREMENT' (e.g., '1,000 m', '3,200 ft') while numbers alone should be assumed to be a value in meters.
+    ///
+    /// `schema:elevation` (`ELEVATION`)
+    pub static ref ELEVATION: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "elevation");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// eligibilityToWorkRequirement
+    ///
+    /// The legal requirements such as citizenship, visa and other documentation required for an applicant to this job.
+    ///
+    /// `schema:eligibilityToWorkRequirement` (`ELIGIBILITY_TO_WORK_REQUIREMENT`)
+    pub static ref ELIGIBILITY_TO_WORK_REQUIREMENT: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "eligibilityToWorkRequirement");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// eligibleCustomerType
+    ///
+    /// The type(s) of customers for which the given offer is valid.
+    ///
+    /// `schema:eligibleCustomerType` (`ELIGIBLE_CUSTOMER_TYPE`)
+    pub static ref ELIGIBLE_CUSTOMER_TYPE: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "eligibleCustomerType");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// eligibleDuration
+    ///
+    /// The duration for which the given offer is valid.
+    ///
+    /// `schema:eligibleDuration` (`ELIGIBLE_DURATION`)
+    pub static ref ELIGIBLE_DURATION: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "eligibleDuration");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// eligibleQuantity
+    ///
+    /// The interval and unit of measurement of ordering quantities for which the offer or price specification is valid. This allows e.g. specifying that a certain freight charge is valid only for a certain quantity.
+    ///
+    /// `schema:eligibleQuantity` (`ELIGIBLE_QUANTITY`)
+    pub static ref ELIGIBLE_QUANTITY: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "eligibleQuantity");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// eligibleRegion
+    ///
+    /// The ISO 3166-1 (ISO 3166-1 alpha-2) or ISO 3166-2 code, the place, or the GeoShape for the geo-political region(s) for which the offer or delivery charge specification is valid.
+    ///
+    /// `schema:eligibleRegion` (`ELIGIBLE_REGION`)
+    pub static ref ELIGIBLE_REGION: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "eligibleRegion");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// eligibleTransactionVolume
+    ///
+    /// The transaction volume, in a monetary unit, for which the offer or price specification is valid, e.g. for indicating a minimal purchasing volume, to express free shipping above a certain order volume, or to limit the acceptance of credit cards to purchases to a certain minimal amount.
+    ///
+    /// `schema:eligibleTransactionVolume` (`ELIGIBLE_TRANSACTION_VOLUME`)
+    pub static ref ELIGIBLE_TRANSACTION_VOLUME: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "eligibleTransactionVolume");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// email
+    ///
+    /// Email address.
+    ///
+    /// `schema:email` (`EMAIL`)
+    pub static ref EMAIL: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "email");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// embedUrl
+    ///
+    /// A URL pointing to a player for a specific video. In general, this is the information in the src element of an embed tag and should not be the same as the content of the loc tag.
+    ///
+    /// `schema:embedUrl` (`EMBED_URL`)
+    pub static ref EMBED_URL: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "embedUrl");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// embeddedTextCaption
+    ///
+    /// Represents textual captioning from a MediaObject, e.g. text of a 'meme'.
+    ///
+    /// `schema:embeddedTextCaption` (`EMBEDDED_TEXT_CAPTION`)
+    pub static ref EMBEDDED_TEXT_CAPTION: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "embeddedTextCaption");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// emissionsCO2
+    ///
+    /// The CO2 emissions in g/km. When used in combination with a QuantitativeValue, put "g/km" into the unitText property of that value, since there is no UN/CEFACT Common Code for "g/km".
+    ///
+    /// `schema:emissionsCO2` (`EMISSIONS_CO2`)
+    pub static ref EMISSIONS_CO2: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "emissionsCO2");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// employee
+    ///
+    /// Someone working for this organization.
+    ///
+    /// `schema:employee` (`EMPLOYEE`)
+    pub static ref EMPLOYEE: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "employee");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// employees
+    ///
+    /// People working for this organization.
+    ///
+    /// `schema:employees` (`EMPLOYEES`)
+    pub static ref EMPLOYEES: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "employees");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// employerOverview
+    ///
+    /// A description of the employer, career opportunities and work environment for this position.
+    ///
+    /// `schema:employerOverview` (`EMPLOYER_OVERVIEW`)
+    pub static ref EMPLOYER_OVERVIEW: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "employerOverview");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// employmentType
+    ///
+    /// Type of employment (e.g. full-time, part-time, contract, temporary, seasonal, internship).
+    ///
+    /// `schema:employmentType` (`EMPLOYMENT_TYPE`)
+    pub static ref EMPLOYMENT_TYPE: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "employmentType");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// employmentUnit
+    ///
+    /// Indicates the department, unit and/or facility where the employee reports and/or in which the job is to be performed.
+    ///
+    /// `schema:employmentUnit` (`EMPLOYMENT_UNIT`)
+    pub static ref EMPLOYMENT_UNIT: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "employmentUnit");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// encodesBioChemEntity
+    ///
+    /// Another BioChemEntity encoded by this one.
+    ///
+    /// `schema:encodesBioChemEntity` (`ENCODES_BIO_CHEM_ENTITY`)
+    pub static ref ENCODES_BIO_CHEM_ENTITY: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "encodesBioChemEntity");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// encodesCreativeWork
+    ///
+    /// The CreativeWork encoded by this media object.
+    ///
+    /// `schema:encodesCreativeWork` (`ENCODES_CREATIVE_WORK`)
+    pub static ref ENCODES_CREATIVE_WORK: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "encodesCreativeWork");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// encoding
+    ///
+    /// A media object that encodes this CreativeWork. This property is a synonym for associatedMedia.
+    ///
+    /// `schema:encoding` (`ENCODING`)
+    pub static ref ENCODING: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "encoding");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// encodingFormat
+    ///
+    /// Media type typically expressed using a MIME format (see IANA site and MDN reference), e.g. application/zip for a SoftwareApplication binary, audio/mpeg for .mp3 etc.
+    ///
+    /// `schema:encodingFormat` (`ENCODING_FORMAT`)
+    pub static ref ENCODING_FORMAT: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "encodingFormat");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// encodingType
+    ///
+    /// The supported encoding type(s) for an EntryPoint request.
+    ///
+    /// `schema:encodingType` (`ENCODING_TYPE`)
+    pub static ref ENCODING_TYPE: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "encodingType");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// encodings
+    ///
+    /// A media object that encodes this CreativeWork.
+    ///
+    /// `schema:encodings` (`ENCODINGS`)
+    pub static ref ENCODINGS: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "encodings");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// endDate
+    ///
+    /// The end date and time of the item (in ISO 8601 date format).
+    ///
+    /// `schema:endDate` (`END_DATE`)
+    pub static ref END_DATE: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "endDate");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// endOffset
+    ///
+    /// The end time of the clip expressed as the number of seconds from the beginning of the work.
+    ///
+    /// `schema:endOffset` (`END_OFFSET`)
+    pub static ref END_OFFSET: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "endOffset");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// endTime
+    ///
+    /// The endTime of something. For a reserved event or service (e.g. FoodEstablishmentReservation), the time that it is expected to end. For actions that span a period of time, when the action was performed. E.g. John wrote a book from January to December.
+    ///
+    /// `schema:endTime` (`END_TIME`)
+    pub static ref END_TIME: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "endTime");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// endorsee
+    ///
+    /// A sub property of participant. The person/organization being supported.
+    ///
+    /// `schema:endorsee` (`ENDORSEE`)
+    pub static ref ENDORSEE: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "endorsee");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// endorsers
+    ///
+    /// People or organizations that endorse the plan.
+    ///
+    /// `schema:endorsers` (`ENDORSERS`)
+    pub static ref ENDORSERS: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "endorsers");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// energyEfficiencyScaleMax
+    ///
+    /// Specifies the most energy efficient class on the regulated EU energy consumption scale for the product category a product belongs to. For example, energy consumption scale for dishwashers covers D to A+++.
+    ///
+    /// `schema:energyEfficiencyScaleMax` (`ENERGY_EFFICIENCY_SCALE_MAX`)
+    pub static ref ENERGY_EFFICIENCY_SCALE_MAX: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "energyEfficiencyScaleMax");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// energyEfficiencyScaleMin
+    ///
+    /// Specifies the least energy efficient class on the regulated EU energy consumption scale for the product category a product belongs to. For example, energy consumption scale for dishwashers covers D to A+++.
+    ///
+    /// `schema:energyEfficiencyScaleMin` (`ENERGY_EFFICIENCY_SCALE_MIN`)
+    pub static ref ENERGY_EFFICIENCY_SCALE_MIN: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "energyEfficiencyScaleMin");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// engineDisplacement
+    ///
+    /// The volume swept by all of the pistons inside the cylinders of an internal combustion engine in a single movement.
+    ///
+    /// `schema:engineDisplacement` (`ENGINE_DISPLACEMENT`)
+    pub static ref ENGINE_DISPLACEMENT: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "engineDisplacement");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// enginePower
+    ///
+    /// The power of the vehicle's engine. Typical unit code(s): KWT for kilowatt, BHP for brake horsepower, N12 for metric horsepower (PS, with 1 N12 = 735,49875 W).
+    ///
+    /// `schema:enginePower` (`ENGINE_POWER`)
+    pub static ref ENGINE_POWER: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "enginePower");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// engineType
+    ///
+    /// The type of engine or engines powering the vehicle.
+    ///
+    /// `schema:engineType` (`ENGINE_TYPE`)
+    pub static ref ENGINE_TYPE: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "engineType");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// entertainmentBusiness
+    ///
+    /// A sub property of location. The entertainment business where the action occurred.
+    ///
+    /// `schema:entertainmentBusiness` (`ENTERTAINMENT_BUSINESS_PROPERTY`)
+    pub static ref ENTERTAINMENT_BUSINESS_PROPERTY: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "entertainmentBusiness");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// epidemiology
+    ///
+    /// The characteristics of associated patients, such as age, gender, race etc.
+    ///
+    /// `schema:epidemiology` (`EPIDEMIOLOGY`)
+    pub static ref EPIDEMIOLOGY: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "epidemiology");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// episode
+    ///
+    /// An episode of a TV, radio or game media within a series or season.
+    ///
+    /// `schema:episode` (`EPISODE_PROPERTY`)
+    pub static ref EPISODE_PROPERTY: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "episode");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// episodeNumber
+    ///
+    /// Position of the episode within an ordered group of episodes.
+    ///
+    /// `schema:episodeNumber` (`EPISODE_NUMBER`)
+    pub static ref EPISODE_NUMBER: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "episodeNumber");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// episodes
+    ///
+    /// An episode of a TV/radio series or season.
+    ///
+    /// `schema:episodes` (`EPISODES`)
+    pub static ref EPISODES: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "episodes");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// equal
+    ///
+    /// This ordering relation for qualitative values indicates that the subject is equal to the object.
+    ///
+    /// `schema:equal` (`EQUAL`)
+    pub static ref EQUAL: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "equal");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// error
+    ///
+    /// For failed actions, more information on the cause of the failure.
+    ///
+    /// `schema:error` (`ERROR`)
+    pub static ref ERROR: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "error");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// estimatedCost
+    ///
+    /// The estimated cost of the supply or supplies consumed when performing instructions.
+    ///
+    /// `schema:estimatedCost` (`ESTIMATED_COST`)
+    pub static ref ESTIMATED_COST: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "estimatedCost");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// estimatedFlightDuration
+    ///
+    /// The estimated time the flight will take.
+    ///
+    /// `schema:estimatedFlightDuration` (`ESTIMATED_FLIGHT_DURATION`)
+    pub static ref ESTIMATED_FLIGHT_DURATION: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "estimatedFlightDuration");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// estimatedSalary
+    ///
+    /// An estimated salary for a job posting or occupation, based on a variety of variables including, but not limited to industry, job title, and location. Estimated salaries are often computed by outside organizations rather than the hiring organization, who may not have committed to the estimated value.
+    ///
+    /// `schema:estimatedSalary` (`ESTIMATED_SALARY`)
+    pub static ref ESTIMATED_SALARY: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "estimatedSalary");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// estimatesRiskOf
+    ///
+    /// The condition, complication, or symptom whose risk is being estimated.
+    ///
+    /// `schema:estimatesRiskOf` (`ESTIMATES_RISK_OF`)
+    pub static ref ESTIMATES_RISK_OF: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "estimatesRiskOf");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// ethicsPolicy
+    ///
+    /// Statement about ethics policy, e.g. of a NewsMediaOrganization regarding journalistic and publishing practices, or of a Restaurant, a page describing food source policies. In the case of a NewsMediaOrganization, an ethicsPolicy is typically a statement describing the personal, organizational, and corporate standards of behavior expected by the organization.
+    ///
+    /// `schema:ethicsPolicy` (`ETHICS_POLICY`)
+    pub static ref ETHICS_POLICY: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "ethicsPolicy");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// event
+    ///
+    /// Upcoming or past event associated with this place, organization, or action.
+    ///
+    /// `schema:event` (`EVENT_PROPERTY`)
+    pub static ref EVENT_PROPERTY: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "event");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// eventAttendanceMode
+    ///
+    /// The eventAttendanceMode of an event indicates whether it occurs online, offline, or a mix.
+    ///
+    /// `schema:eventAttendanceMode` (`EVENT_ATTENDANCE_MODE`)
+    pub static ref EVENT_ATTENDANCE_MODE: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "eventAttendanceMode");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// eventSchedule
+    ///
+    /// Associates an Event with a Schedule. There are circumstances where it is preferable to share a schedule for a series of repeating events rather than data on the individual events themselves.
+    ///
+    /// `schema:eventSchedule` (`EVENT_SCHEDULE`)
+    pub static ref EVENT_SCHEDULE: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "eventSchedule");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// eventStatus
+    ///
+    /// An eventStatus of an event represents its status; particularly useful when an event is cancelled or rescheduled.
+    ///
+    /// `schema:eventStatus` (`EVENT_STATUS`)
+    pub static ref EVENT_STATUS: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "eventStatus");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// events
+    ///
+    /// Upcoming or past events associated with this place or organization.
+    ///
+    /// `schema:events` (`EVENTS`)
+    pub static ref EVENTS: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "events");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// evidenceLevel
+    ///
+    /// Strength of evidence of the data used to formulate the guideline (enumerated).
+    ///
+    /// `schema:evidenceLevel` (`EVIDENCE_LEVEL`)
+    pub static ref EVIDENCE_LEVEL: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "evidenceLevel");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// evidenceOrigin
+    ///
+    /// Source of the data used to formulate the guidance, e.g. RCT, consensus opinion, etc.
+    ///
+    /// `schema:evidenceOrigin` (`EVIDENCE_ORIGIN`)
+    pub static ref EVIDENCE_ORIGIN: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "evidenceOrigin");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// exampleOfWork
+    ///
+    /// A creative work that this work is an example/instance/realization/derivation of.
+    ///
+    /// `schema:exampleOfWork` (`EXAMPLE_OF_WORK`)
+    pub static ref EXAMPLE_OF_WORK: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "exampleOfWork");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// exceptDate
+    ///
+    /// Defines a Date or DateTime during which a scheduled Event will not take place. The property allows exceptions to a Schedule to be specified.
+    ///
+    /// `schema:exceptDate` (`EXCEPT_DATE`)
+    pub static ref EXCEPT_DATE: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "exceptDate");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// exchangeRateSpread
+    ///
+    /// The difference between the price at which a broker or other intermediary buys and sells foreign currency.
+    ///
+    /// `schema:exchangeRateSpread` (`EXCHANGE_RATE_SPREAD`)
+    pub static ref EXCHANGE_RATE_SPREAD: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "exchangeRateSpread");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// executableLibraryName
+    ///
+    /// Library file name, e.g., mscorlib.dll, system.web.dll.
+    ///
+    /// `schema:executableLibraryName` (`EXECUTABLE_LIBRARY_NAME`)
+    pub static ref EXECUTABLE_LIBRARY_NAME: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "executableLibraryName");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// exerciseCourse
+    ///
+    /// A sub property of location. The course where this action was taken.
+    ///
+    /// `schema:exerciseCourse` (`EXERCISE_COURSE`)
+    pub static ref EXERCISE_COURSE: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "exerciseCourse");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// exercisePlan
+    ///
+    /// A sub property of instrument. The exercise plan used on this action.
+    ///
+    /// `schema:exercisePlan` (`EXERCISE_PLAN_PROPERTY`)
+    pub static ref EXERCISE_PLAN_PROPERTY: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "exercisePlan");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// exerciseRelatedDiet
+    ///
+    /// A sub property of instrument. The diet used in this action.
+    ///
+    /// `schema:exerciseRelatedDiet` (`EXERCISE_RELATED_DIET`)
+    pub static ref EXERCISE_RELATED_DIET: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "exerciseRelatedDiet");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// exerciseType
+    ///
+    /// Type(s) of exercise or activity, such as strength training, flexibility training, aerobics, cardiac rehabilitation, etc.
+    ///
+    /// `schema:exerciseType` (`EXERCISE_TYPE`)
+    pub static ref EXERCISE_TYPE: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "exerciseType");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// exifData
+    ///
+    /// exif data for this object.
+    ///
+    /// `schema:exifData` (`EXIF_DATA`)
+    pub static ref EXIF_DATA: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "exifData");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// expectedArrivalFrom
+    ///
+    /// The earliest date the package may arrive.
+    ///
+    /// `schema:expectedArrivalFrom` (`EXPECTED_ARRIVAL_FROM`)
+    pub static ref EXPECTED_ARRIVAL_FROM: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "expectedArrivalFrom");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// expectedArrivalUntil
+    ///
+    /// The latest date the package may arrive.
+    ///
+    /// `schema:expectedArrivalUntil` (`EXPECTED_ARRIVAL_UNTIL`)
+    pub static ref EXPECTED_ARRIVAL_UNTIL: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "expectedArrivalUntil");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// expectedPrognosis
+    ///
+    /// The likely outcome in either the short term or long term of the medical condition.
+    ///
+    /// `schema:expectedPrognosis` (`EXPECTED_PROGNOSIS`)
+    pub static ref EXPECTED_PROGNOSIS: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "expectedPrognosis");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// expectsAcceptanceOf
+    ///
+    /// An Offer which must be accepted before the user can perform the Action. For example, the user may need to buy a movie before being able to watch it.
+    ///
+    /// `schema:expectsAcceptanceOf` (`EXPECTS_ACCEPTANCE_OF`)
+    pub static ref EXPECTS_ACCEPTANCE_OF: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "expectsAcceptanceOf");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// experienceInPlaceOfEducation
+    ///
+    /// Indicates whether a JobPosting will accept experience (as indicated by OccupationalExperienceRequirements) in place of its formal educational qualifications (as indicated by educationRequirements). If true, indicates that satisfying one of these requirements is sufficient.
+    ///
+    /// `schema:experienceInPlaceOfEducation` (`EXPERIENCE_IN_PLACE_OF_EDUCATION`)
+    pub static ref EXPERIENCE_IN_PLACE_OF_EDUCATION: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "experienceInPlaceOfEducation");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// experienceRequirements
+    ///
+    /// Description of skills and experience needed for the position or Occupation.
+    ///
+    /// `schema:experienceRequirements` (`EXPERIENCE_REQUIREMENTS`)
+    pub static ref EXPERIENCE_REQUIREMENTS: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "experienceRequirements");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// expertConsiderations
+    ///
+    /// Medical expert advice related to the plan.
+    ///
+    /// `schema:expertConsiderations` (`EXPERT_CONSIDERATIONS`)
+    pub static ref EXPERT_CONSIDERATIONS: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "expertConsiderations");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// expires
+    ///
+    /// Date the content expires and is no longer useful or available. For example a VideoObject or NewsArticle whose availability or relevance is time-limited, a ClaimReview fact check whose publisher wants to indicate that it may no longer be relevant (or helpful to highlight) after some date, or a Certification the validity has expired.
+    ///
+    /// `schema:expires` (`EXPIRES`)
+    pub static ref EXPIRES: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "expires");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// expressedIn
+    ///
+    /// Tissue, organ, biological sample, etc in which activity of this gene has been observed experimentally. For example brain, digestive system.
+    ///
+    /// `schema:expressedIn` (`EXPRESSED_IN`)
+    pub static ref EXPRESSED_IN: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "expressedIn");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// familyName
+    ///
+    /// Family name. In the U.S., the last name of a Person.
+    ///
+    /// `schema:familyName` (`FAMILY_NAME`)
+    pub static ref FAMILY_NAME: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "familyName");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// fatContent
+    ///
+    /// The number of grams of fat.
+    ///
+    /// `schema:fatContent` (`FAT_CONTENT`)
+    pub static ref FAT_CONTENT: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "fatContent");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// faxNumber
+    ///
+    /// The fax number.
+    ///
+    /// `schema:faxNumber` (`FAX_NUMBER`)
+    pub static ref FAX_NUMBER: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "faxNumber");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// featureList
+    ///
+    /// Features or modules provided by this application (and possibly required by other applications).
+    ///
+    /// `schema:featureList` (`FEATURE_LIST`)
+    pub static ref FEATURE_LIST: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "featureList");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// feesAndCommissionsSpecification
+    ///
+    /// Description of fees, commissions, and other terms applied either to a class of financial product, or by a financial service organization.
+    ///
+    /// `schema:feesAndCommissionsSpecification` (`FEES_AND_COMMISSIONS_SPECIFICATION`)
+    pub static ref FEES_AND_COMMISSIONS_SPECIFICATION: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "feesAndCommissionsSpecification");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// fiberContent
+    ///
+    /// The number of grams of fiber.
+    ///
+    /// `schema:fiberContent` (`FIBER_CONTENT`)
+    pub static ref FIBER_CONTENT: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "fiberContent");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// fileFormat
+    ///
+    /// Media type, typically MIME format (see IANA site) of the content, e.g. application/zip of a SoftwareApplication binary. In cases where a CreativeWork has several media type representations, 'encoding' can be used to indicate each MediaObject alongside particular fileFormat information.
+    ///
+    /// `schema:fileFormat` (`FILE_FORMAT`)
+    pub static ref FILE_FORMAT: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "fileFormat");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// fileSize
+    ///
+    /// Size of the application / package (e.g. 18MB). In the absence of a unit (MB, KB etc.), KB will be assumed.
+    ///
+    /// `schema:fileSize` (`FILE_SIZE`)
+    pub static ref FILE_SIZE: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "fileSize");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// financialAidEligible
+    ///
+    /// A financial aid type or program which students may use to pay for tuition or fees associated with the program.
+    ///
+    /// `schema:financialAidEligible` (`FINANCIAL_AID_ELIGIBLE`)
+    pub static ref FINANCIAL_AID_ELIGIBLE: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "financialAidEligible");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// firstAppearance
+    ///
+    /// Indicates the first known occurrence of a Claim in some CreativeWork.
+    ///
+    /// `schema:firstAppearance` (`FIRST_APPEARANCE`)
+    pub static ref FIRST_APPEARANCE: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "firstAppearance");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// firstPerformance
+    ///
+    /// The date and place the work was first performed.
+    ///
+    /// `schema:firstPerformance` (`FIRST_PERFORMANCE`)
+    pub static ref FIRST_PERFORMANCE: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "firstPerformance");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// flightDistance
+    ///
+    /// The distance of the flight.
+    ///
+    /// `schema:flightDistance` (`FLIGHT_DISTANCE`)
+    pub static ref FLIGHT_DISTANCE: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "flightDistance");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// flightNumber
+    ///
+    /// The unique identifier for a flight including the airline IATA code. For example, if describing United flight 110, where the IATA code for United is 'UA', the flightNumber is 'UA110'.
+    ///
+    /// `schema:flightNumber` (`FLIGHT_NUMBER`)
+    pub static ref FLIGHT_NUMBER: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "flightNumber");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// floorLevel
+    ///
+    /// The floor level for an Accommodation in a multi-storey building. Since counting systems vary internationally, the local system should be used where possible.
+    ///
+    /// `schema:floorLevel` (`FLOOR_LEVEL`)
+    pub static ref FLOOR_LEVEL: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "floorLevel");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// floorLimit
+    ///
+    /// A floor limit is the amount of money above which credit card transactions must be authorized.
+    ///
+    /// `schema:floorLimit` (`FLOOR_LIMIT`)
+    pub static ref FLOOR_LIMIT: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "floorLimit");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// floorSize
+    ///
+    /// The size of the accommodation, e.g. in square meter or squarefoot. Typical unit code(s): MTK for square meter, FTK for square foot, or YDK for square yard.
+    ///
+    /// `schema:floorSize` (`FLOOR_SIZE`)
+    pub static ref FLOOR_SIZE: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "floorSize");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// followee
+    ///
+    /// A sub property of object. The person or organization being followed.
+    ///
+    /// `schema:followee` (`FOLLOWEE`)
+    pub static ref FOLLOWEE: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "followee");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// follows
+    ///
+    /// The most generic uni-directional social relation.
+    ///
+    /// `schema:follows` (`FOLLOWS`)
+    pub static ref FOLLOWS: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "follows");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// followup
+    ///
+    /// Typical or recommended followup care after the procedure is performed.
+    ///
+    /// `schema:followup` (`FOLLOWUP`)
+    pub static ref FOLLOWUP: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "followup");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// foodEstablishment
+    ///
+    /// A sub property of location. The specific food establishment where the action occurred.
+    ///
+    /// `schema:foodEstablishment` (`FOOD_ESTABLISHMENT_PROPERTY`)
+    pub static ref FOOD_ESTABLISHMENT_PROPERTY: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "foodEstablishment");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// foodEvent
+    ///
+    /// A sub property of location. The specific food event where the action occurred.
+    ///
+    /// `schema:foodEvent` (`FOOD_EVENT_PROPERTY`)
+    pub static ref FOOD_EVENT_PROPERTY: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "foodEvent");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// foodWarning
+    ///
+    /// Any precaution, guidance, contraindication, etc. related to consumption of specific foods while taking this drug.
+    ///
+    /// `schema:foodWarning` (`FOOD_WARNING`)
+    pub static ref FOOD_WARNING: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "foodWarning");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// founder
+    ///
+    /// A person or organization who founded this organization.
+    ///
+    /// `schema:founder` (`FOUNDER`)
+    pub static ref FOUNDER: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "founder");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// founders
+    ///
+    /// A person who founded this organization.
+    ///
+    /// `schema:founders` (`FOUNDERS`)
+    pub static ref FOUNDERS: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "founders");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// foundingDate
+    ///
+    /// The date that this organization was founded.
+    ///
+    /// `schema:foundingDate` (`FOUNDING_DATE`)
+    pub static ref FOUNDING_DATE: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "foundingDate");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// foundingLocation
+    ///
+    /// The place where the Organization was founded.
+    ///
+    /// `schema:foundingLocation` (`FOUNDING_LOCATION`)
+    pub static ref FOUNDING_LOCATION: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "foundingLocation");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// free
+    ///
+    /// A flag to signal that the item, event, or place is accessible for free.
+    ///
+    /// `schema:free` (`FREE`)
+    pub static ref FREE: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "free");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// freeShippingThreshold
+    ///
+    /// A monetary value above (or at) which the shipping rate becomes free. Intended to be used via an OfferShippingDetails with shippingSettingsLink matching this ShippingRateSettings.
+    ///
+    /// `schema:freeShippingThreshold` (`FREE_SHIPPING_THRESHOLD`)
+    pub static ref FREE_SHIPPING_THRESHOLD: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "freeShippingThreshold");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// frequency
+    ///
+    /// How often the dose is taken, e.g. 'daily'.
+    ///
+    /// `schema:frequency` (`FREQUENCY`)
+    pub static ref FREQUENCY: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "frequency");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// fromLocation
+    ///
+    /// A sub property of location. The original location of the object or the agent before the action.
+    ///
+    /// `schema:fromLocation` (`FROM_LOCATION`)
+    pub static ref FROM_LOCATION: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "fromLocation");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// fuelCapacity
+    ///
+    /// The capacity of the fuel tank or in the case of electric cars, the battery. If there are multiple components for storage, this should indicate the total of all storage of the same type.
+    ///
+    /// `schema:fuelCapacity` (`FUEL_CAPACITY`)
+    pub static ref FUEL_CAPACITY: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "fuelCapacity");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// fuelConsumption
+    ///
+    /// The amount of fuel consumed for traveling a particular distance or temporal duration with the given vehicle (e.g. liters per 100 km).
+    ///
+    /// `schema:fuelConsumption` (`FUEL_CONSUMPTION`)
+    pub static ref FUEL_CONSUMPTION: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "fuelConsumption");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// fuelEfficiency
+    ///
+    /// The distance traveled per unit of fuel used; most commonly miles per gallon (mpg) or kilometers per liter (km/L).
+    ///
+    /// `schema:fuelEfficiency` (`FUEL_EFFICIENCY`)
+    pub static ref FUEL_EFFICIENCY: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "fuelEfficiency");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// fuelType
+    ///
+    /// The type of fuel suitable for the engine or engines of the vehicle. If the vehicle has only one engine, this property can be attached directly to the vehicle.
+    ///
+    /// `schema:fuelType` (`FUEL_TYPE`)
+    pub static ref FUEL_TYPE: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "fuelType");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// functionalClass
+    ///
+    /// The degree of mobility the joint allows.
+    ///
+    /// `schema:functionalClass` (`FUNCTIONAL_CLASS`)
+    pub static ref FUNCTIONAL_CLASS: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "functionalClass");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// fundedItem
+    ///
+    /// Indicates something directly or indirectly funded or sponsored through a Grant.
+    ///
+    /// `schema:fundedItem` (`FUNDED_ITEM`)
+    pub static ref FUNDED_ITEM: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "fundedItem");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// funder
+    ///
+    /// A person or organization that supports (sponsors) something through some kind of financial contribution.
+    ///
+    /// `schema:funder` (`FUNDER`)
+    pub static ref FUNDER: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "funder");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// funding
+    ///
+    /// A Grant that directly or indirectly provide funding or sponsorship for this item.
+    ///
+    /// `schema:funding` (`FUNDING`)
+    pub static ref FUNDING: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "funding");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// game
+    ///
+    /// Video game which is played on this server.
+    ///
+    /// `schema:game` (`GAME_PROPERTY`)
+    pub static ref GAME_PROPERTY: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "game");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// gameAvailabilityType
+    ///
+    /// Indicates the availability type of the game content associated with this action, such as whether it is a full version or a demo.
+    ///
+    /// `schema:gameAvailabilityType` (`GAME_AVAILABILITY_TYPE`)
+    pub static ref GAME_AVAILABILITY_TYPE: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "gameAvailabilityType");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// gameEdition
+    ///
+    /// The edition of a video game.
+    ///
+    /// `schema:gameEdition` (`GAME_EDITION`)
+    pub static ref GAME_EDITION: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "gameEdition");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// gameLocation
+    ///
+    /// Real or fictional location of the game (or part of game).
+    ///
+    /// `schema:gameLocation` (`GAME_LOCATION`)
+    pub static ref GAME_LOCATION: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "gameLocation");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// gamePlatform
+    ///
+    /// The electronic systems used to play video games.
+    ///
+    /// `schema:gamePlatform` (`GAME_PLATFORM`)
+    pub static ref GAME_PLATFORM: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "gamePlatform");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// gameServer
+    ///
+    /// The server on which it is possible to play the game.
+    ///
+    /// `schema:gameServer` (`GAME_SERVER_PROPERTY`)
+    pub static ref GAME_SERVER_PROPERTY: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "gameServer");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// gameTip
+    ///
+    /// Links to tips, tactics, etc.
+    ///
+    /// `schema:gameTip` (`GAME_TIP`)
+    pub static ref GAME_TIP: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "gameTip");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// gender
+    ///
+    /// Gender of something, typically a Person, but possibly also fictional characters, animals, etc. While https://schema.org/Male and https://schema.org/Female may be used, text strings are also acceptable for people who do not identify as a binary gender.
+    ///
+    /// `schema:gender` (`GENDER`)
+    pub static ref GENDER: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "gender");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// genre
+    ///
+    /// Genre of the creative work, broadcast channel or group.
+    ///
+    /// `schema:genre` (`GENRE`)
+    pub static ref GENRE: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "genre");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// geo
+    ///
+    /// The geo coordinates of the place.
+    ///
+    /// `schema:geo` (`GEO`)
+    pub static ref GEO: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "geo");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// geoContains
+    ///
+    /// Represents a relationship between two geometries (or the places they represent), relating a containing geometry to a contained geometry. "a contains b iff no points of b lie in the exterior of a, and at least one point of the interior of b lies in the interior of a". As defined in DE-9IM.
+    ///
+    /// `schema:geoContains` (`GEO_CONTAINS`)
+    pub static ref GEO_CONTAINS: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "geoContains");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// geoCoveredBy
+    ///
+    /// Represents a relationship between two geometries (or the places they represent), relating a geometry to another that covers it. As defined in DE-9IM.
+    ///
+    /// `schema:geoCoveredBy` (`GEO_COVERED_BY`)
+    pub static ref GEO_COVERED_BY: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "geoCoveredBy");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// geoCovers
+    ///
+    /// Represents a relationship between two geometries (or the places they represent), relating a covering geometry to a covered geometry. "Every point of b is a point of (the interior or boundary of) a". As defined in DE-9IM.
+    ///
+    /// `schema:geoCovers` (`GEO_COVERS`)
+    pub static ref GEO_COVERS: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "geoCovers");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// geoCrosses
+    ///
+    /// Represents a relationship between two geometries (or the places they represent), relating a geometry to another that crosses it: "a crosses b: they have some but not all interior points in common, and the dimension of the intersection is less than that of at least one of them". As defined in DE-9IM.
+    ///
+    /// `schema:geoCrosses` (`GEO_CROSSES`)
+    pub static ref GEO_CROSSES: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "geoCrosses");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// geoDisjoint
+    ///
+    /// Represents spatial relations in which two geometries (or the places they represent) are topologically disjoint: "they have no point in common. They form a set of disconnected geometries." (A symmetric relationship, as defined in DE-9IM.)
+    ///
+    /// `schema:geoDisjoint` (`GEO_DISJOINT`)
+    pub static ref GEO_DISJOINT: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "geoDisjoint");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// geoEquals
+    ///
+    /// Represents spatial relations in which two geometries (or the places they represent) are topologically equal, as defined in DE-9IM. "Two geometries are topologically equal if their interiors intersect and no part of the interior or boundary of one geometry intersects the exterior of the other" (a symmetric relationship).
+    ///
+    /// `schema:geoEquals` (`GEO_EQUALS`)
+    pub static ref GEO_EQUALS: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "geoEquals");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// geoIntersects
+    ///
+    /// Represents spatial relations in which two geometries (or the places they represent) have at least one point in common. As defined in DE-9IM.
+    ///
+    /// `schema:geoIntersects` (`GEO_INTERSECTS`)
+    pub static ref GEO_INTERSECTS: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "geoIntersects");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// geoMidpoint
+    ///
+    /// Indicates the GeoCoordinates at the centre of a GeoShape, e.g. GeoCircle.
+    ///
+    /// `schema:geoMidpoint` (`GEO_MIDPOINT`)
+    pub static ref GEO_MIDPOINT: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "geoMidpoint");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// geoOverlaps
+    ///
+    /// Represents a relationship between two geometries (or the places they represent), relating a geometry to another that geospatially overlaps it, i.e. they have some but not all points in common. As defined in DE-9IM.
+    ///
+    /// `schema:geoOverlaps` (`GEO_OVERLAPS`)
+    pub static ref GEO_OVERLAPS: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "geoOverlaps");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// geoRadius
+    ///
+    /// Indicates the approximate radius of a GeoCircle (metres unless indicated otherwise via Distance notation).
+    ///
+    /// `schema:geoRadius` (`GEO_RADIUS`)
+    pub static ref GEO_RADIUS: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "geoRadius");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// geoTouches
+    ///
+    /// Represents spatial relations in which two geometries (or the places they represent) touch: "they have at least one boundary point in common, but no interior points." (A symmetric relationship, as defined in DE-9IM.)
+    ///
+    /// `schema:geoTouches` (`GEO_TOUCHES`)
+    pub static ref GEO_TOUCHES: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "geoTouches");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// geoWithin
+    ///
+    /// Represents a relationship between two geometries (or the places they represent), relating a geometry to one that contains it, i.e. it is inside (i.e. within) its interior. As defined in DE-9IM.
+    ///
+    /// `schema:geoWithin` (`GEO_WITHIN`)
+    pub static ref GEO_WITHIN: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "geoWithin");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// geographicArea
+    ///
+    /// The geographic area associated with the audience.
+    ///
+    /// `schema:geographicArea` (`GEOGRAPHIC_AREA`)
+    pub static ref GEOGRAPHIC_AREA: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "geographicArea");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// gettingTestedInfo
+    ///
+    /// Information about getting tested (for a MedicalCondition), e.g. in the context of a pandemic.
+    ///
+    /// `schema:gettingTestedInfo` (`GETTING_TESTED_INFO`)
+    pub static ref GETTING_TESTED_INFO: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "gettingTestedInfo");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// givenName
+    ///
+    /// Given name. In the U.S., the first name of a Person.
+    ///
+    /// `schema:givenName` (`GIVEN_NAME`)
+    pub static ref GIVEN_NAME: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "givenName");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// globalLocationNumber
+    ///
+    /// The Global Location Number (GLN, sometimes also referred to as International Location Number or ILN) of the respective organization, person, or place. The GLN is a 13-digit number used to identify parties and physical locations.
+    ///
+    /// `schema:globalLocationNumber` (`GLOBAL_LOCATION_NUMBER`)
+    pub static ref GLOBAL_LOCATION_NUMBER: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "globalLocationNumber");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// governmentBenefitsInfo
+    ///
+    /// governmentBenefitsInfo provides information about government benefits associated with a SpecialAnnouncement.
+    ///
+    /// `schema:governmentBenefitsInfo` (`GOVERNMENT_BENEFITS_INFO`)
+    pub static ref GOVERNMENT_BENEFITS_INFO: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "governmentBenefitsInfo");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// gracePeriod
+    ///
+    /// The period of time after any due date that the borrower has to fulfil its obligations before a default (failure to pay) is deemed to have occurred.
+    ///
+    /// `schema:gracePeriod` (`GRACE_PERIOD`)
+    pub static ref GRACE_PERIOD: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "gracePeriod");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// grantee
+    ///
+    /// The person, organization, contact point, or audience that has been granted this permission.
+    ///
+    /// `schema:grantee` (`GRANTEE`)
+    pub static ref GRANTEE: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "grantee");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// greater
+    ///
+    /// This ordering relation for qualitative values indicates that the subject is greater than the object.
+    ///
+    /// `schema:greater` (`GREATER`)
+    pub static ref GREATER: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "greater");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// greaterOrEqual
+    ///
+    /// This ordering relation for qualitative values indicates that the subject is greater than or equal to the object.
+    ///
+    /// `schema:greaterOrEqual` (`GREATER_OR_EQUAL`)
+    pub static ref GREATER_OR_EQUAL: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "greaterOrEqual");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// gtin
+    ///
+    /// A Global Trade Item Number (GTIN). GTINs identify trade items, including products and services, using numeric identification codes.
+    ///
+    /// `schema:gtin` (`GTIN`)
+    pub static ref GTIN: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "gtin");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// gtin12
+    ///
+    /// The GTIN-12 code of the product, or the product to which the offer refers. The GTIN-12 is the 12-digit GS1 Identification Key composed of a U.P.C. Company Prefix, Item Reference, and Check Digit used to identify trade items.
+    ///
+    /// `schema:gtin12` (`GTIN12`)
+    pub static ref GTIN12: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "gtin12");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// gtin13
+    ///
+    /// The GTIN-13 code of the product, or the product to which the offer refers. This is equivalent to 13-digit ISBN codes and EAN UCC-13. Former 12-digit UPC codes can be converted into a GTIN-13 code by simply adding a preceding zero.
+    ///
+    /// `schema:gtin13` (`GTIN13`)
+    pub static ref GTIN13: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "gtin13");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// gtin14
+    ///
+    /// The GTIN-14 code of the product, or the product to which the offer refers.
+    ///
+    /// `schema:gtin14` (`GTIN14`)
+    pub static ref GTIN14: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "gtin14");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// gtin8
+    ///
+    /// The GTIN-8 code of the product, or the product to which the offer refers. This code is also known as EAN/UCC-8 or 8-digit EAN.
+    ///
+    /// `schema:gtin8` (`GTIN8`)
+    pub static ref GTIN8: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "gtin8");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// guideline
+    ///
+    /// A medical guideline related to this entity.
+    ///
+    /// `schema:guideline` (`GUIDELINE`)
+    pub static ref GUIDELINE: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "guideline");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// guidelineDate
+    ///
+    /// Date on which this guideline's recommendation was made.
+    ///
+    /// `schema:guidelineDate` (`GUIDELINE_DATE`)
+    pub static ref GUIDELINE_DATE: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "guidelineDate");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// guidelineSubject
+    ///
+    /// The medical conditions, treatments, etc. that are the subject of the guideline.
+    ///
+    /// `schema:guidelineSubject` (`GUIDELINE_SUBJECT`)
+    pub static ref GUIDELINE_SUBJECT: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "guidelineSubject");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// handlingTime
+    ///
+    /// The typical delay between the receipt of the order and the goods either leaving the warehouse or being prepared for pickup, in case the delivery method is on site pickup.
+    ///
+    /// `schema:handlingTime` (`HANDLING_TIME`)
+    pub static ref HANDLING_TIME: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "handlingTime");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// hasAdultConsideration
+    ///
+    /// Used to tag an item to be intended or suitable for consumption or use by adults only.
+    ///
+    /// `schema:hasAdultConsideration` (`HAS_ADULT_CONSIDERATION`)
+    pub static ref HAS_ADULT_CONSIDERATION: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "hasAdultConsideration");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// hasBioChemEntityPart
+    ///
+    /// Indicates a BioChemEntity that (in some sense) has this BioChemEntity as a part.
+    ///
+    /// `schema:hasBioChemEntityPart` (`HAS_BIO_CHEM_ENTITY_PART`)
+    pub static ref HAS_BIO_CHEM_ENTITY_PART: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "hasBioChemEntityPart");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// hasBioPolymerSequence
+    ///
+    /// A symbolic representation of a BioChemEntity. For example, a nucleotide sequence of a Gene or an amino acid sequence of a Protein.
+    ///
+    /// `schema:hasBioPolymerSequence` (`HAS_BIO_POLYMER_SEQUENCE`)
+    pub static ref HAS_BIO_POLYMER_SEQUENCE: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "hasBioPolymerSequence");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// hasBroadcastChannel
+    ///
+    /// A broadcast channel of a broadcast service.
+    ///
+    /// `schema:hasBroadcastChannel` (`HAS_BROADCAST_CHANNEL`)
+    pub static ref HAS_BROADCAST_CHANNEL: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "hasBroadcastChannel");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// hasCategoryCode
+    ///
+    /// A Category code contained in this code set.
+    ///
+    /// `schema:hasCategoryCode` (`HAS_CATEGORY_CODE`)
+    pub static ref HAS_CATEGORY_CODE: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "hasCategoryCode");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// hasCertification
+    ///
+    /// Certification information about a product, organization, service, place, or person.
+    ///
+    /// `schema:hasCertification` (`HAS_CERTIFICATION`)
+    pub static ref HAS_CERTIFICATION: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "hasCertification");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// hasCourse
+    ///
+    /// A course or class that is one of the learning opportunities that constitute an educational / occupational program. No information is implied about whether the course is mandatory or optional; no guarantee is implied about whether the course will be available to everyone on the program.
+    ///
+    /// `schema:hasCourse` (`HAS_COURSE`)
+    pub static ref HAS_COURSE: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "hasCourse");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// hasCourseInstance
+    ///
+    /// An offering of the course at a specific time and place or through specific media or mode of study or to a specific section of students.
+    ///
+    /// `schema:hasCourseInstance` (`HAS_COURSE_INSTANCE`)
+    pub static ref HAS_COURSE_INSTANCE: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "hasCourseInstance");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// hasCredential
+    ///
+    /// A credential awarded to the Person or Organization.
+    ///
+    /// `schema:hasCredential` (`HAS_CREDENTIAL`)
+    pub static ref HAS_CREDENTIAL: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "hasCredential");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// hasDefinedTerm
+    ///
+    /// A Defined Term contained in this term set.
+    ///
+    /// `schema:hasDefinedTerm` (`HAS_DEFINED_TERM`)
+    pub static ref HAS_DEFINED_TERM: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "hasDefinedTerm");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// hasDeliveryMethod
+    ///
+    /// Method used for delivery or shipping.
+    ///
+    /// `schema:hasDeliveryMethod` (`HAS_DELIVERY_METHOD`)
+    pub static ref HAS_DELIVERY_METHOD: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "hasDeliveryMethod");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// hasDigitalDocumentPermission
+    ///
+    /// A permission related to the access to this document (e.g. permission to read or write an electronic document). For a public document, specify a grantee with an Audience with audienceType equal to "public".
+    ///
+    /// `schema:hasDigitalDocumentPermission` (`HAS_DIGITAL_DOCUMENT_PERMISSION`)
+    pub static ref HAS_DIGITAL_DOCUMENT_PERMISSION: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "hasDigitalDocumentPermission");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// hasDriveThroughService
+    ///
+    /// Indicates whether some facility (e.g. FoodEstablishment, CovidTestingFacility) offers a service that can be used by driving through in a car. In the case of CovidTestingFacility such facilities could potentially help with social distancing from other potentially-infected users.
+    ///
+    /// `schema:hasDriveThroughService` (`HAS_DRIVE_THROUGH_SERVICE`)
+    pub static ref HAS_DRIVE_THROUGH_SERVICE: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "hasDriveThroughService");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// hasEnergyConsumptionDetails
+    ///
+    /// Defines the energy efficiency Category (also known as "class" or "rating") for a product according to an international energy efficiency standard.
+    ///
+    /// `schema:hasEnergyConsumptionDetails` (`HAS_ENERGY_CONSUMPTION_DETAILS`)
+    pub static ref HAS_ENERGY_CONSUMPTION_DETAILS: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "hasEnergyConsumptionDetails");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// hasEnergyEfficiencyCategory
+    ///
+    /// Defines the energy efficiency Category (which could be either a rating out of range of values or a yes/no certification) for a product according to an international energy efficiency standard.
+    ///
+    /// `schema:hasEnergyEfficiencyCategory` (`HAS_ENERGY_EFFICIENCY_CATEGORY`)
+    pub static ref HAS_ENERGY_EFFICIENCY_CATEGORY: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "hasEnergyEfficiencyCategory");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// hasHealthAspect
+    ///
+    /// Indicates the aspect or aspects specifically addressed in some HealthTopicContent. For example, that the content is an overview, or that it talks about treatment, self-care, treatments or their side-effects.
+    ///
+    /// `schema:hasHealthAspect` (`HAS_HEALTH_ASPECT`)
+    pub static ref HAS_HEALTH_ASPECT: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "hasHealthAspect");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// hasMap
+    ///
+    /// A URL to a map of the place.
+    ///
+    /// `schema:hasMap` (`HAS_MAP`)
+    pub static ref HAS_MAP: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "hasMap");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// hasMeasurement
+    ///
+    /// A measurement of an item, For example, the inseam of pants, the wheel size of a bicycle, the gauge of a screw, or the carbon footprint measured for certification by an authority. Usually an exact measurement, but can also be a range of measurements for adjustable products, for example belts and ski bindings.
+    ///
+    /// `schema:hasMeasurement` (`HAS_MEASUREMENT`)
+    pub static ref HAS_MEASUREMENT: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "hasMeasurement");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// hasMemberProgram
+    ///
+    /// MemberProgram offered by an Organization, for example an eCommerce merchant or an airline.
+    ///
+    /// `schema:hasMemberProgram` (`HAS_MEMBER_PROGRAM`)
+    pub static ref HAS_MEMBER_PROGRAM: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "hasMemberProgram");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// hasMenu
+    ///
+    /// Either the actual menu as a structured representation, as text, or a URL of the menu.
+    ///
+    /// `schema:hasMenu` (`HAS_MENU`)
+    pub static ref HAS_MENU: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "hasMenu");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// hasMenuItem
+    ///
+    /// A food or drink item contained in a menu or menu section.
+    ///
+    /// `schema:hasMenuItem` (`HAS_MENU_ITEM`)
+    pub static ref HAS_MENU_ITEM: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "hasMenuItem");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// hasMenuSection
+    ///
+    /// A subgrouping of the menu (by dishes, course, serving time period, etc.).
+    ///
+    /// `schema:hasMenuSection` (`HAS_MENU_SECTION`)
+    pub static ref HAS_MENU_SECTION: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "hasMenuSection");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// hasMerchantReturnPolicy
+    ///
+    /// Specifies a MerchantReturnPolicy that may be applicable.
+    ///
+    /// `schema:hasMerchantReturnPolicy` (`HAS_MERCHANT_RETURN_POLICY`)
+    pub static ref HAS_MERCHANT_RETURN_POLICY: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "hasMerchantReturnPolicy");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// hasMolecularFunction
+    ///
+    /// Molecular function performed by this BioChemEntity; please use PropertyValue if you want to include any evidence.
+    ///
+    /// `schema:hasMolecularFunction` (`HAS_MOLECULAR_FUNCTION`)
+    pub static ref HAS_MOLECULAR_FUNCTION: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "hasMolecularFunction");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// hasOccupation
+    ///
+    /// The Person's occupation. For past professions, use Role for expressing dates.
+    ///
+    /// `schema:hasOccupation` (`HAS_OCCUPATION`)
+    pub static ref HAS_OCCUPATION: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "hasOccupation");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// hasOfferCatalog
+    ///
+    /// Indicates an OfferCatalog listing for this Organization, Person, or Service.
+    ///
+    /// `schema:hasOfferCatalog` (`HAS_OFFER_CATALOG`)
+    pub static ref HAS_OFFER_CATALOG: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "hasOfferCatalog");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// hasPOS
+    ///
+    /// Points-of-Sales operated by the organization or person.
+    ///
+    /// `schema:hasPOS` (`HAS_POS`)
+    pub static ref HAS_POS: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "hasPOS");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// hasPart
+    ///
+    /// Indicates an item or CreativeWork that is part of this item, or CreativeWork (in some sense).
+    ///
+    /// `schema:hasPart` (`HAS_PART`)
+    pub static ref HAS_PART: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "hasPart");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// hasRepresentation
+    ///
+    /// A common representation such as a protein sequence or chemical structure for this entity. For images use schema.org image.
+    ///
+    /// `schema:hasRepresentation` (`HAS_REPRESENTATION`)
+    pub static ref HAS_REPRESENTATION: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "hasRepresentation");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// hasTierBenefit
+    ///
+    /// A member benefit for a particular tier of a loyalty program.
+    ///
+    /// `schema:hasTierBenefit` (`HAS_TIER_BENEFIT`)
+    pub static ref HAS_TIER_BENEFIT: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "hasTierBenefit");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// hasTierRequirement
+    ///
+    /// A requirement for a user to join a membership tier, for example: a CreditCard if the tier requires sign up for a credit card, a UnitPriceSpecification if the user is required to pay a (periodic) fee, or a MonetaryAmount if the user needs to spend a minimum amount to join the tier.
+    ///
+    /// `schema:hasTierRequirement` (`HAS_TIER_REQUIREMENT`)
+    pub static ref HAS_TIER_REQUIREMENT: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "hasTierRequirement");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// hasTiers
+    ///
+    /// The tiers of a member program.
+    ///
+    /// `schema:hasTiers` (`HAS_TIERS`)
+    pub static ref HAS_TIERS: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "hasTiers");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// hasVariant
+    ///
+    /// Indicates a Product that is a member of this ProductGroup (or ProductModel).
+    ///
+    /// `schema:hasVariant` (`HAS_VARIANT`)
+    pub static ref HAS_VARIANT: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "hasVariant");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// headline
+    ///
+    /// Headline of the article.
+    ///
+    /// `schema:headline` (`HEADLINE`)
+    pub static ref HEADLINE: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "headline");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// healthCondition
+    ///
+    /// Specifying the health condition(s) of a patient, medical study, or other target audience.
+    ///
+    /// `schema:healthCondition` (`HEALTH_CONDITION`)
+    pub static ref HEALTH_CONDITION: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "healthCondition");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// healthPlanCoinsuranceOption
+    ///
+    /// Whether the coinsurance applies before or after deductible, etc.
+    ///
+    /// `schema:healthPlanCoinsuranceOption` (`HEALTH_PLAN_COINSURANCE_OPTION`)
+    pub static ref HEALTH_PLAN_COINSURANCE_OPTION: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "healthPlanCoinsuranceOption");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// healthPlanCoinsuranceRate
+    ///
+    /// The rate of coinsurance expressed as a number between 0.0 and 1.0.
+    ///
+    /// `schema:healthPlanCoinsuranceRate` (`HEALTH_PLAN_COINSURANCE_RATE`)
+    pub static ref HEALTH_PLAN_COINSURANCE_RATE: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "healthPlanCoinsuranceRate");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// healthPlanCopay
+    ///
+    /// The copay amount.
+    ///
+    /// `schema:healthPlanCopay` (`HEALTH_PLAN_COPAY`)
+    pub static ref HEALTH_PLAN_COPAY: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "healthPlanCopay");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// healthPlanCopayOption
+    ///
+    /// Whether the copay is before or after deductible, etc.
+    ///
+    /// `schema:healthPlanCopayOption` (`HEALTH_PLAN_COPAY_OPTION`)
+    pub static ref HEALTH_PLAN_COPAY_OPTION: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "healthPlanCopayOption");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// healthPlanCostSharing
+    ///
+    /// The costs to the patient for services under this network or formulary.
+    ///
+    /// `schema:healthPlanCostSharing` (`HEALTH_PLAN_COST_SHARING`)
+    pub static ref HEALTH_PLAN_COST_SHARING: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "healthPlanCostSharing");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// healthPlanDrugOption
+    ///
+    /// The drug options covered by the health plan.
+    ///
+    /// `schema:healthPlanDrugOption` (`HEALTH_PLAN_DRUG_OPTION`)
+    pub static ref HEALTH_PLAN_DRUG_OPTION: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "healthPlanDrugOption");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// healthPlanDrugTier
+    ///
+    /// The tier(s) of drugs offered by this formulary or insurance plan.
+    ///
+    /// `schema:healthPlanDrugTier` (`HEALTH_PLAN_DRUG_TIER`)
+    pub static ref HEALTH_PLAN_DRUG_TIER: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "healthPlanDrugTier");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// healthPlanId
+    ///
+    /// The 14-character, HIOS-generated Plan ID number. (Plan IDs must be unique, even across different markets.)
+    ///
+    /// `schema:healthPlanId` (`HEALTH_PLAN_ID`)
+    pub static ref HEALTH_PLAN_ID: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "healthPlanId");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// healthPlanMarketingUrl
+    ///
+    /// The URL that goes directly to the plan brochure for the specific standard plan or plan variation.
+    ///
+    /// `schema:healthPlanMarketingUrl` (`HEALTH_PLAN_MARKETING_URL`)
+    pub static ref HEALTH_PLAN_MARKETING_URL: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "healthPlanMarketingUrl");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// healthPlanNetworkId
+    ///
+    /// Name or unique ID of network. (Networks are often reused across different insurance plans.)
+    ///
+    /// `schema:healthPlanNetworkId` (`HEALTH_PLAN_NETWORK_ID`)
+    pub static ref HEALTH_PLAN_NETWORK_ID: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "healthPlanNetworkId");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// healthPlanNetworkTier
+    ///
+    /// The tier(s) for this network.
+    ///
+    /// `schema:healthPlanNetworkTier` (`HEALTH_PLAN_NETWORK_TIER`)
+    pub static ref HEALTH_PLAN_NETWORK_TIER: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "healthPlanNetworkTier");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// healthPlanPharmacyCategory
+    ///
+    /// The category or type of pharmacy associated with this cost sharing.
+    ///
+    /// `schema:healthPlanPharmacyCategory` (`HEALTH_PLAN_PHARMACY_CATEGORY`)
+    pub static ref HEALTH_PLAN_PHARMACY_CATEGORY: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "healthPlanPharmacyCategory");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// healthcareReportingData
+    ///
+    /// Indicates data describing a hospital, e.g. a CDC CDCPMDRecord or as some kind of Dataset.
+    ///
+    /// `schema:healthcareReportingData` (`HEALTHCARE_REPORTING_DATA`)
+    pub static ref HEALTHCARE_REPORTING_DATA: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "healthcareReportingData");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// height
+    ///
+    /// The height of the item.
+    ///
+    /// `schema:height` (`HEIGHT`)
+    pub static ref HEIGHT: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "height");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// highPrice
+    ///
+    /// The highest price of all offers available. Usage guidelines: use values from 0123456789 (Unicode 'DIGIT ZERO' (U+0030) to 'DIGIT NINE' (U+0039)) rather than superficially similar Unicode symbols, and use '.' (Unicode 'FULL STOP' (U+002E)) rather than ',' to indicate a decimal point.
+    ///
+    /// `schema:highPrice` (`HIGH_PRICE`)
+    pub static ref HIGH_PRICE: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "highPrice");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// hiringOrganization
+    ///
+    /// Organization or Person offering the job position.
+    ///
+    /// `schema:hiringOrganization` (`HIRING_ORGANIZATION`)
+    pub static ref HIRING_ORGANIZATION: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "hiringOrganization");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// holdingArchive
+    ///
+    /// ArchiveOrganization that holds, keeps or maintains the ArchiveComponent.
+    ///
+    /// `schema:holdingArchive` (`HOLDING_ARCHIVE`)
+    pub static ref HOLDING_ARCHIVE: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "holdingArchive");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// homeLocation
+    ///
+    /// A contact location for a person's residence.
+    ///
+    /// `schema:homeLocation` (`HOME_LOCATION`)
+    pub static ref HOME_LOCATION: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "homeLocation");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// homeTeam
+    ///
+    /// The home team in a sports event.
+    ///
+    /// `schema:homeTeam` (`HOME_TEAM`)
+    pub static ref HOME_TEAM: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "homeTeam");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// honorificPrefix
+    ///
+    /// An honorific prefix preceding a Person's name such as Dr/Mrs/Mr.
+    ///
+    /// `schema:honorificPrefix` (`HONORIFIC_PREFIX`)
+    pub static ref HONORIFIC_PREFIX: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "honorificPrefix");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// honorificSuffix
+    ///
+    /// An honorific suffix following a Person's name such as M.D./PhD/MSCSW.
+    ///
+    /// `schema:honorificSuffix` (`HONORIFIC_SUFFIX`)
+    pub static ref HONORIFIC_SUFFIX: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "honorificSuffix");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// hospitalAffiliation
+    ///
+    /// A hospital with which the physician or office is affiliated.
+    ///
+    /// `schema:hospitalAffiliation` (`HOSPITAL_AFFILIATION`)
+    pub static ref HOSPITAL_AFFILIATION: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "hospitalAffiliation");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// hostingOrganization
+    ///
+    /// The organization (airline, travelers' club, retailer, etc.) the membership is made with or which offers the MemberProgram.
+    ///
+    /// `schema:hostingOrganization` (`HOSTING_ORGANIZATION`)
+    pub static ref HOSTING_ORGANIZATION: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "hostingOrganization");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// hoursAvailable
+    ///
+    /// The hours during which this service or contact is available.
+    ///
+    /// `schema:hoursAvailable` (`HOURS_AVAILABLE`)
+    pub static ref HOURS_AVAILABLE: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "hoursAvailable");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// howPerformed
+    ///
+    /// How the procedure is performed.
+    ///
+    /// `schema:howPerformed` (`HOW_PERFORMED`)
+    pub static ref HOW_PERFORMED: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "howPerformed");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// httpMethod
+    ///
+    /// An HTTP method that specifies the appropriate HTTP method for a request to an HTTP EntryPoint. Values are capitalized strings as used in HTTP.
+    ///
+    /// `schema:httpMethod` (`HTTP_METHOD`)
+    pub static ref HTTP_METHOD: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "httpMethod");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// iataCode
+    ///
+    /// IATA identifier for an airline or airport.
+    ///
+    /// `schema:iataCode` (`IATA_CODE`)
+    pub static ref IATA_CODE: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "iataCode");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// icaoCode
+    ///
+    /// ICAO identifier for an airport.
+    ///
+    /// `schema:icaoCode` (`ICAO_CODE`)
+    pub static ref ICAO_CODE: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "icaoCode");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// identifier
+    ///
+    /// The identifier property represents any kind of identifier for any kind of Thing, such as ISBNs, GTIN codes, UUIDs etc. Schema.org provides dedicated properties for representing many of these, either as textual strings or as URL (URI) links.
+    ///
+    /// `schema:identifier` (`IDENTIFIER`)
+    pub static ref IDENTIFIER: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "identifier");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// identifyingExam
+    ///
+    /// A physical examination that can identify this sign.
+    ///
+    /// `schema:identifyingExam` (`IDENTIFYING_EXAM`)
+    pub static ref IDENTIFYING_EXAM: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "identifyingExam");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// identifyingTest
+    ///
+    /// A diagnostic test that can identify this sign.
+    ///
+    /// `schema:identifyingTest` (`IDENTIFYING_TEST`)
+    pub static ref IDENTIFYING_TEST: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "identifyingTest");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// illustrator
+    ///
+    /// The illustrator of the book.
+    ///
+    /// `schema:illustrator` (`ILLUSTRATOR`)
+    pub static ref ILLUSTRATOR: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "illustrator");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// image
+    ///
+    /// An image of the item. This can be a URL or a fully described ImageObject.
+    ///
+    /// `schema:image` (`IMAGE`)
+    pub static ref IMAGE: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "image");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// imagingTechnique
+    ///
+    /// Imaging technique used.
+    ///
+    /// `schema:imagingTechnique` (`IMAGING_TECHNIQUE`)
+    pub static ref IMAGING_TECHNIQUE: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "imagingTechnique");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// inAlbum
+    ///
+    /// The album to which this recording belongs.
+    ///
+    /// `schema:inAlbum` (`IN_ALBUM`)
+    pub static ref IN_ALBUM: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "inAlbum");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// inBroadcastLineup
+    ///
+    /// The CableOrSatelliteService offering the channel.
+    ///
+    /// `schema:inBroadcastLineup` (`IN_BROADCAST_LINEUP`)
+    pub static ref IN_BROADCAST_LINEUP: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "inBroadcastLineup");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// inChI
+    ///
+    /// Non-proprietary identifier for molecular entity that can be used in printed and electronic data sources thus enabling easier linking of diverse data compilations.
+    ///
+    /// `schema:inChI` (`IN_CH_I`)
+    pub static ref IN_CH_I: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "inChI");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// inChIKey
+    ///
+    /// InChIKey is a hashed version of the full InChI (using the SHA-256 algorithm).
+    ///
+    /// `schema:inChIKey` (`IN_CH_I_KEY`)
+    pub static ref IN_CH_I_KEY: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "inChIKey");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// inCodeSet
+    ///
+    /// A CategoryCodeSet that contains this category code.
+    ///
+    /// `schema:inCodeSet` (`IN_CODE_SET`)
+    pub static ref IN_CODE_SET: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "inCodeSet");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// inDefinedTermSet
+    ///
+    /// A DefinedTermSet that contains this term.
+    ///
+    /// `schema:inDefinedTermSet` (`IN_DEFINED_TERM_SET`)
+    pub static ref IN_DEFINED_TERM_SET: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "inDefinedTermSet");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// inLanguage
+    ///
+    /// The language of the content or performance or used in an action. Please use one of the language codes from the IETF BCP 47 standard.
+    ///
+    /// `schema:inLanguage` (`IN_LANGUAGE`)
+    pub static ref IN_LANGUAGE: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "inLanguage");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// inPlaylist
+    ///
+    /// The playlist to which this recording belongs.
+    ///
+    /// `schema:inPlaylist` (`IN_PLAYLIST`)
+    pub static ref IN_PLAYLIST: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "inPlaylist");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// inProductGroupWithID
+    ///
+    /// Indicates the productGroupID for a ProductGroup that this product isVariantOf.
+    ///
+    /// `schema:inProductGroupWithID` (`IN_PRODUCT_GROUP_WITH_ID`)
+    pub static ref IN_PRODUCT_GROUP_WITH_ID: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "inProductGroupWithID");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// inStoreReturnsOffered
+    ///
+    /// Are in-store returns offered? (For more advanced return methods use the returnMethod property.)
+    ///
+    /// `schema:inStoreReturnsOffered` (`IN_STORE_RETURNS_OFFERED`)
+    pub static ref IN_STORE_RETURNS_OFFERED: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "inStoreReturnsOffered");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// inSupportOf
+    ///
+    /// Qualification, candidature, degree, application that Thesis supports.
+    ///
+    /// `schema:inSupportOf` (`IN_SUPPORT_OF`)
+    pub static ref IN_SUPPORT_OF: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "inSupportOf");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// incentiveCompensation
+    ///
+    /// Description of bonus and commission compensation aspects of the job.
+    ///
+    /// `schema:incentiveCompensation` (`INCENTIVE_COMPENSATION`)
+    pub static ref INCENTIVE_COMPENSATION: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "incentiveCompensation");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// incentives
+    ///
+    /// Description of bonus and commission compensation aspects of the job.
+    ///
+    /// `schema:incentives` (`INCENTIVES`)
+    pub static ref INCENTIVES: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "incentives");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// includedComposition
+    ///
+    /// Smaller compositions included in this work (e.g. a movement in a symphony).
+    ///
+    /// `schema:includedComposition` (`INCLUDED_COMPOSITION`)
+    pub static ref INCLUDED_COMPOSITION: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "includedComposition");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// includedDataCatalog
+    ///
+    /// A data catalog which contains this dataset (this property was previously 'catalog', preferred name is now 'includedInDataCatalog').
+    ///
+    /// `schema:includedDataCatalog` (`INCLUDED_DATA_CATALOG`)
+    pub static ref INCLUDED_DATA_CATALOG: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "includedDataCatalog");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// includedInDataCatalog
+    ///
+    /// A data catalog which contains this dataset.
+    ///
+    /// `schema:includedInDataCatalog` (`INCLUDED_IN_DATA_CATALOG`)
+    pub static ref INCLUDED_IN_DATA_CATALOG: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "includedInDataCatalog");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// includedInHealthInsurancePlan
+    ///
+    /// The insurance plans that cover this drug.
+    ///
+    /// `schema:includedInHealthInsurancePlan` (`INCLUDED_IN_HEALTH_INSURANCE_PLAN`)
+    pub static ref INCLUDED_IN_HEALTH_INSURANCE_PLAN: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "includedInHealthInsurancePlan");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// includedRiskFactor
+    ///
+    /// A modifiable or non-modifiable risk factor included in the calculation, e.g. age, coexisting condition.
+    ///
+    /// `schema:includedRiskFactor` (`INCLUDED_RISK_FACTOR`)
+    pub static ref INCLUDED_RISK_FACTOR: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "includedRiskFactor");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// includesAttraction
+    ///
+    /// Attraction located at destination.
+    ///
+    /// `schema:includesAttraction` (`INCLUDES_ATTRACTION`)
+    pub static ref INCLUDES_ATTRACTION: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "includesAttraction");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// includesHealthPlanFormulary
+    ///
+    /// Formularies covered by this plan.
+    ///
+    /// `schema:includesHealthPlanFormulary` (`INCLUDES_HEALTH_PLAN_FORMULARY`)
+    pub static ref INCLUDES_HEALTH_PLAN_FORMULARY: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "includesHealthPlanFormulary");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// includesHealthPlanNetwork
+    ///
+    /// Networks covered by this plan.
+    ///
+    /// `schema:includesHealthPlanNetwork` (`INCLUDES_HEALTH_PLAN_NETWORK`)
+    pub static ref INCLUDES_HEALTH_PLAN_NETWORK: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "includesHealthPlanNetwork");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// includesObject
+    ///
+    /// This links to a node or nodes indicating the exact quantity of the products included in an Offer or ProductCollection.
+    ///
+    /// `schema:includesObject` (`INCLUDES_OBJECT`)
+    pub static ref INCLUDES_OBJECT: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "includesObject");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// increasesRiskOf
+    ///
+    /// The condition, complication, etc. influenced by this factor.
+    ///
+    /// `schema:increasesRiskOf` (`INCREASES_RISK_OF`)
+    pub static ref INCREASES_RISK_OF: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "increasesRiskOf");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// industry
+    ///
+    /// The industry associated with the job position.
+    ///
+    /// `schema:industry` (`INDUSTRY`)
+    pub static ref INDUSTRY: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "industry");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// ineligibleRegion
+    ///
+    /// The ISO 3166-1 (ISO 3166-1 alpha-2) or ISO 3166-2 code, the place, or the GeoShape for the geo-political region(s) for which the offer or delivery charge specification is not valid, e.g. a region where the transaction is not allowed.
+    ///
+    /// `schema:ineligibleRegion` (`INELIGIBLE_REGION`)
+    pub static ref INELIGIBLE_REGION: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "ineligibleRegion");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// infectiousAgent
+    ///
+    /// The actual infectious agent, such as a specific bacterium.
+    ///
+    /// `schema:infectiousAgent` (`INFECTIOUS_AGENT`)
+    pub static ref INFECTIOUS_AGENT: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "infectiousAgent");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// infectiousAgentClass
+    ///
+    /// The class of infectious agent (bacteria, prion, etc.) that causes the disease.
+    ///
+    /// `schema:infectiousAgentClass` (`INFECTIOUS_AGENT_CLASS_PROPERTY`)
+    pub static ref INFECTIOUS_AGENT_CLASS_PROPERTY: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "infectiousAgentClass");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// ingredients
+    ///
+    /// A single ingredient used in the recipe, e.g. sugar, flour or garlic.
+    ///
+    /// `schema:ingredients` (`INGREDIENTS`)
+    pub static ref INGREDIENTS: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "ingredients");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// inker
+    ///
+    /// The individual who traces over the pencil drawings in ink after pencils are complete.
+    ///
+    /// `schema:inker` (`INKER`)
+    pub static ref INKER: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "inker");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// insertion
+    ///
+    /// The place of attachment of a muscle, or what the muscle moves.
+    ///
+    /// `schema:insertion` (`INSERTION`)
+    pub static ref INSERTION: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "insertion");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// installUrl
+    ///
+    /// URL at which the app may be installed, if different from the URL of the item.
+    ///
+    /// `schema:installUrl` (`INSTALL_URL`)
+    pub static ref INSTALL_URL: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "installUrl");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// instructor
+    ///
+    /// A person assigned to instruct or provide instructional assistance for the CourseInstance.
+    ///
+    /// `schema:instructor` (`INSTRUCTOR`)
+    pub static ref INSTRUCTOR: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "instructor");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// instrument
+    ///
+    /// The object that helped the agent perform the action. E.g. John wrote a book with a pen.
+    ///
+    /// `schema:instrument` (`INSTRUMENT`)
+    pub static ref INSTRUMENT: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "instrument");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// intensity
+    ///
+    /// Quantitative measure gauging the degree of force involved in the exercise, for example, heartbeats per minute. May include the velocity of the movement.
+    ///
+    /// `schema:intensity` (`INTENSITY`)
+    pub static ref INTENSITY: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "intensity");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// interactingDrug
+    ///
+    /// Another drug that is known to interact with this drug in a way that impacts the effect of this drug or causes a risk to the patient. Note: disease interactions are typically captured as contraindications.
+    ///
+    /// `schema:interactingDrug` (`INTERACTING_DRUG`)
+    pub static ref INTERACTING_DRUG: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "interactingDrug");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// interactionCount
+    ///
+    /// This property is deprecated, alongside the UserInteraction types on which it depended.
+    ///
+    /// `schema:interactionCount` (`INTERACTION_COUNT`)
+    pub static ref INTERACTION_COUNT: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "interactionCount");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// interactionService
+    ///
+    /// The WebSite or SoftwareApplication where the interactions took place.
+    ///
+    /// `schema:interactionService` (`INTERACTION_SERVICE`)
+    pub static ref INTERACTION_SERVICE: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "interactionService");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// interactionStatistic
+    ///
+    /// The number of interactions for the CreativeWork using the WebSite or SoftwareApplication. The most specific child type of InteractionCounter should be used.
+    ///
+    /// `schema:interactionStatistic` (`INTERACTION_STATISTIC`)
+    pub static ref INTERACTION_STATISTIC: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "interactionStatistic");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// interactionType
+    ///
+    /// The Action representing the type of interaction. For up votes, +1s, etc. use LikeAction. For down votes use DislikeAction. Otherwise, use the most specific Action.
+    ///
+    /// `schema:interactionType` (`INTERACTION_TYPE`)
+    pub static ref INTERACTION_TYPE: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "interactionType");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// interactivityType
+    ///
+    /// The predominant mode of learning supported by the learning resource. Acceptable values are 'active', 'expositive', or 'mixed'.
+    ///
+    /// `schema:interactivityType` (`INTERACTIVITY_TYPE`)
+    pub static ref INTERACTIVITY_TYPE: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "interactivityType");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// interestRate
+    ///
+    /// The interest rate, charged or paid, applicable to the financial product. Note: This is different from the calculated annualPercentageRate.
+    ///
+    /// `schema:interestRate` (`INTEREST_RATE`)
+    pub static ref INTEREST_RATE: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "interestRate");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// interpretedAsClaim
+    ///
+    /// Used to indicate a specific claim contained, implied, translated or refined from the content of a MediaObject or other CreativeWork. The interpreting party can be indicated using claimInterpreter.
+    ///
+    /// `schema:interpretedAsClaim` (`INTERPRETED_AS_CLAIM`)
+    pub static ref INTERPRETED_AS_CLAIM: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "interpretedAsClaim");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// inventoryLevel
+    ///
+    /// The current approximate inventory level for the item or items.
+    ///
+    /// `schema:inventoryLevel` (`INVENTORY_LEVEL`)
+    pub static ref INVENTORY_LEVEL: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "inventoryLevel");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// inverseOf
+    ///
+    /// Relates a property to a property that is its inverse. Inverse properties relate the same pairs of items to each other, but in reversed direction.
+    ///
+    /// `schema:inverseOf` (`INVERSE_OF`)
+    pub static ref INVERSE_OF: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "inverseOf");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// isAcceptingNewPatients
+    ///
+    /// Whether the provider is accepting new patients.
+    ///
+    /// `schema:isAcceptingNewPatients` (`IS_ACCEPTING_NEW_PATIENTS`)
+    pub static ref IS_ACCEPTING_NEW_PATIENTS: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "isAcceptingNewPatients");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// isAccessibleForFree
+    ///
+    /// A flag to signal that the item, event, or place is accessible for free.
+    ///
+    /// `schema:isAccessibleForFree` (`IS_ACCESSIBLE_FOR_FREE`)
+    pub static ref IS_ACCESSIBLE_FOR_FREE: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "isAccessibleForFree");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// isAccessoryOrSparePartFor
+    ///
+    /// A pointer to another product (or multiple products) for which this product is an accessory or spare part.
+    ///
+    /// `schema:isAccessoryOrSparePartFor` (`IS_ACCESSORY_OR_SPARE_PART_FOR`)
+    pub static ref IS_ACCESSORY_OR_SPARE_PART_FOR: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "isAccessoryOrSparePartFor");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// isAvailableGenerically
+    ///
+    /// True if the drug is available in a generic form (regardless of name).
+    ///
+    /// `schema:isAvailableGenerically` (`IS_AVAILABLE_GENERICALLY`)
+    pub static ref IS_AVAILABLE_GENERICALLY: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "isAvailableGenerically");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// isBasedOn
+    ///
+    /// A resource from which this work is derived or from which it is a modification or adaptation.
+    ///
+    /// `schema:isBasedOn` (`IS_BASED_ON`)
+    pub static ref IS_BASED_ON: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "isBasedOn");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// isBasedOnUrl
+    ///
+    /// A resource that was used in the creation of this resource. This term can be repeated for multiple sources.
+    ///
+    /// `schema:isBasedOnUrl` (`IS_BASED_ON_URL`)
+    pub static ref IS_BASED_ON_URL: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "isBasedOnUrl");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// isConsumableFor
+    ///
+    /// A pointer to another product (or multiple products) for which this product is a consumable.
+    ///
+    /// `schema:isConsumableFor` (`IS_CONSUMABLE_FOR`)
+    pub static ref IS_CONSUMABLE_FOR: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "isConsumableFor");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// isEncodedByBioChemEntity
+    ///
+    /// Another BioChemEntity encoding by this one.
+    ///
+    /// `schema:isEncodedByBioChemEntity` (`IS_ENCODED_BY_BIO_CHEM_ENTITY`)
+    pub static ref IS_ENCODED_BY_BIO_CHEM_ENTITY: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "isEncodedByBioChemEntity");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// isFamilyFriendly
+    ///
+    /// Indicates whether this content is family friendly.
+    ///
+    /// `schema:isFamilyFriendly` (`IS_FAMILY_FRIENDLY`)
+    pub static ref IS_FAMILY_FRIENDLY: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "isFamilyFriendly");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// isGift
+    ///
+    /// Indicates whether the offer was accepted as a gift for someone other than the buyer.
+    ///
+    /// `schema:isGift` (`IS_GIFT`)
+    pub static ref IS_GIFT: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "isGift");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// isInvolvedInBiologicalProcess
+    ///
+    /// Biological process this BioChemEntity is involved in; please use PropertyValue if you want to include any evidence.
+    ///
+    /// `schema:isInvolvedInBiologicalProcess` (`IS_INVOLVED_IN_BIOLOGICAL_PROCESS`)
+    pub static ref IS_INVOLVED_IN_BIOLOGICAL_PROCESS: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "isInvolvedInBiologicalProcess");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// isLiveBroadcast
+    ///
+    /// True if the broadcast is of a live event.
+    ///
+    /// `schema:isLiveBroadcast` (`IS_LIVE_BROADCAST`)
+    pub static ref IS_LIVE_BROADCAST: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "isLiveBroadcast");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// isLocatedInSubcellularLocation
+    ///
+    /// Subcellular location where this BioChemEntity is located; please use PropertyValue if you want to include any evidence.
+    ///
+    /// `schema:isLocatedInSubcellularLocation` (`IS_LOCATED_IN_SUBCELLULAR_LOCATION`)
+    pub static ref IS_LOCATED_IN_SUBCELLULAR_LOCATION: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "isLocatedInSubcellularLocation");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// isPartOf
+    ///
+    /// Indicates an item or CreativeWork that this item, or CreativeWork (in some sense), is part of.
+    ///
+    /// `schema:isPartOf` (`IS_PART_OF`)
+    pub static ref IS_PART_OF: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "isPartOf");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// isPartOfBioChemEntity
+    ///
+    /// Indicates a BioChemEntity that is (in some sense) a part of this BioChemEntity.
+    ///
+    /// `schema:isPartOfBioChemEntity` (`IS_PART_OF_BIO_CHEM_ENTITY`)
+    pub static ref IS_PART_OF_BIO_CHEM_ENTITY: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "isPartOfBioChemEntity");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// isPlanForApartment
+    ///
+    /// Indicates some accommodation that this floor plan describes.
+    ///
+    /// `schema:isPlanForApartment` (`IS_PLAN_FOR_APARTMENT`)
+    pub static ref IS_PLAN_FOR_APARTMENT: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "isPlanForApartment");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// isProprietary
+    ///
+    /// True if this item's name is a proprietary/brand name (vs. generic name).
+    ///
+    /// `schema:isProprietary` (`IS_PROPRIETARY`)
+    pub static ref IS_PROPRIETARY: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "isProprietary");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// isRelatedTo
+    ///
+    /// A pointer to another, somehow related product (or multiple products).
+    ///
+    /// `schema:isRelatedTo` (`IS_RELATED_TO`)
+    pub static ref IS_RELATED_TO: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "isRelatedTo");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// isResizable
+    ///
+    /// Whether the 3DModel allows resizing. For example, room layout applications often do not allow 3DModel elements to be resized to reflect reality.
+    ///
+    /// `schema:isResizable` (`IS_RESIZABLE`)
+    pub static ref IS_RESIZABLE: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "isResizable");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// isSimilarTo
+    ///
+    /// A pointer to another, functionally similar product (or multiple products).
+    ///
+    /// `schema:isSimilarTo` (`IS_SIMILAR_TO`)
+    pub static ref IS_SIMILAR_TO: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "isSimilarTo");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// isUnlabelledFallback
+    ///
+    /// This can be marked 'true' to indicate that some published DeliveryTimeSettings or ShippingRateSettings are intended to apply to all OfferShippingDetails published by the same merchant, when referenced by a shippingSettingsLink in those settings.
+    ///
+    /// `schema:isUnlabelledFallback` (`IS_UNLABELLED_FALLBACK`)
+    pub static ref IS_UNLABELLED_FALLBACK: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "isUnlabelledFallback");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// isVariantOf
+    ///
+    /// Indicates the kind of product that this is a variant of. In the case of ProductModel, this is a pointer (from a ProductModel) to a base product from which this product is a variant. In the case of a ProductGroup, the group description also serves as a template, representing a set of Products that vary on explicitly defined, specific dimensions only.
+    ///
+    /// `schema:isVariantOf` (`IS_VARIANT_OF`)
+    pub static ref IS_VARIANT_OF: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "isVariantOf");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// isbn
+    ///
+    /// The ISBN of the book.
+    ///
+    /// `schema:isbn` (`ISBN`)
+    pub static ref ISBN: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "isbn");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// isicV4
+    ///
+    /// The International Standard of Industrial Classification of All Economic Activities (ISIC), Revision 4 code for a particular organization, business person, or place.
+    ///
+    /// `schema:isicV4` (`ISIC_V4`)
+    pub static ref ISIC_V4: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "isicV4");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// iso6523Code
+    ///
+    /// An organization identifier as defined in ISO 6523(-1). The identifier should be in the XXXX:YYYYYY:ZZZ or XXXX:YYYYYYformat. Where XXXX is a 4 digit ICD (International Code Designator), YYYYYY is an OID (Organization Identifier) with all formatting removed and ZZZ is an optional OPI (Organization Part Identifier).
+    ///
+    /// `schema:iso6523Code` (`ISO6523_CODE`)
+    pub static ref ISO6523_CODE: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "iso6523Code");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// isrcCode
+    ///
+    /// The International Standard Recording Code for the recording.
+    ///
+    /// `schema:isrcCode` (`ISRC_CODE`)
+    pub static ref ISRC_CODE: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "isrcCode");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// issn
+    ///
+    /// The International Standard Serial Number (ISSN) that identifies this serial publication. You can repeat this property to identify different formats of, or the linking ISSN (ISSN-L) for, this serial publication.
+    ///
+    /// `schema:issn` (`ISSN`)
+    pub static ref ISSN: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "issn");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// issueNumber
+    ///
+    /// Identifies the issue of publication; for example, "iii" or "2".
+    ///
+    /// `schema:issueNumber` (`ISSUE_NUMBER`)
+    pub static ref ISSUE_NUMBER: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "issueNumber");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// issuedBy
+    ///
+    /// The organization issuing the item, for example a Permit, Ticket, or Certification.
+    ///
+    /// `schema:issuedBy` (`ISSUED_BY`)
+    pub static ref ISSUED_BY: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "issuedBy");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// issuedThrough
+    ///
+    /// The service through which the permit was granted.
+    ///
+    /// `schema:issuedThrough` (`ISSUED_THROUGH`)
+    pub static ref ISSUED_THROUGH: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "issuedThrough");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// iswcCode
+    ///
+    /// The International Standard Musical Work Code for the composition.
+    ///
+    /// `schema:iswcCode` (`ISWC_CODE`)
+    pub static ref ISWC_CODE: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "iswcCode");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// item
+    ///
+    /// An entity represented by an entry in a list or data feed (e.g. an 'artist' in a list of 'artists').
+    ///
+    /// `schema:item` (`ITEM`)
+    pub static ref ITEM: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "item");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// itemCondition
+    ///
+    /// A predefined value from OfferItemCondition specifying the condition of the product or service, or the products or services included in the offer. Also used for product return policies to specify the condition of products accepted for returns.
+    ///
+    /// `schema:itemCondition` (`ITEM_CONDITION`)
+    pub static ref ITEM_CONDITION: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "itemCondition");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// itemDefectReturnFees
+    ///
+    /// The type of return fees for returns of defect products.
+    ///
+    /// `schema:itemDefectReturnFees` (`ITEM_DEFECT_RETURN_FEES`)
+    pub static ref ITEM_DEFECT_RETURN_FEES: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "itemDefectReturnFees");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// itemDefectReturnLabelSource
+    ///
+    /// The method (from an enumeration) by which the customer obtains a return shipping label for a defect product.
+    ///
+    /// `schema:itemDefectReturnLabelSource` (`ITEM_DEFECT_RETURN_LABEL_SOURCE`)
+    pub static ref ITEM_DEFECT_RETURN_LABEL_SOURCE: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "itemDefectReturnLabelSource");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// itemDefectReturnShippingFeesAmount
+    ///
+    /// Amount of shipping costs for defect product returns. Applicable when property itemDefectReturnFees equals ReturnShippingFees.
+    ///
+    /// `schema:itemDefectReturnShippingFeesAmount` (`ITEM_DEFECT_RETURN_SHIPPING_FEES_AMOUNT`)
+    pub static ref ITEM_DEFECT_RETURN_SHIPPING_FEES_AMOUNT: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "itemDefectReturnShippingFeesAmount");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// itemListElement
+    ///
+    /// For itemListElement values, you can use simple strings (e.g. "Peter", "Paul", "Mary"), existing entities, or use ListItem. Text values are best if the elements in the list are plain strings. Existing entities are best for a simple, unordered list of existing things in your data. ListItem is used with ordered lists when you want to provide additional context about the element in that list or when the same item might be in different places in different lists.
+    ///
+    /// `schema:itemListElement` (`ITEM_LIST_ELEMENT`)
+    pub static ref ITEM_LIST_ELEMENT: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "itemListElement");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// itemListOrder
+    ///
+    /// Type of ordering (e.g. Ascending, Descending, Unordered).
+    ///
+    /// `schema:itemListOrder` (`ITEM_LIST_ORDER`)
+    pub static ref ITEM_LIST_ORDER: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "itemListOrder");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// itemLocation
+    ///
+    /// Current location of the item.
+    ///
+    /// `schema:itemLocation` (`ITEM_LOCATION`)
+    pub static ref ITEM_LOCATION: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "itemLocation");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// itemOffered
+    ///
+    /// An item being offered (or demanded). The transactional nature of the offer or demand is documented using businessFunction, e.g. sell, lease etc. While several common expected types are listed explicitly in this definition, others can be used. Using a second type, such as Product or a subtype of Product, can clarify the nature of the offer.
+    ///
+    /// `schema:itemOffered` (`ITEM_OFFERED`)
+    pub static ref ITEM_OFFERED: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "itemOffered");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// itemReviewed
+    ///
+    /// The item that is being reviewed/rated.
+    ///
+    /// `schema:itemReviewed` (`ITEM_REVIEWED`)
+    pub static ref ITEM_REVIEWED: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "itemReviewed");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// itemShipped
+    ///
+    /// Item(s) being shipped.
+    ///
+    /// `schema:itemShipped` (`ITEM_SHIPPED`)
+    pub static ref ITEM_SHIPPED: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "itemShipped");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// itinerary
+    ///
+    /// Destination(s) ( Place ) that make up a trip. For a trip where destination order is important use ItemList to specify that order.
+    ///
+    /// `schema:itinerary` (`ITINERARY`)
+    pub static ref ITINERARY: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "itinerary");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// jobBenefits
+    ///
+    /// Description of benefits associated with the job.
+    ///
+    /// `schema:jobBenefits` (`JOB_BENEFITS`)
+    pub static ref JOB_BENEFITS: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "jobBenefits");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// jobImmediateStart
+    ///
+    /// An indicator as to whether a position is available for an immediate start.
+    ///
+    /// `schema:jobImmediateStart` (`JOB_IMMEDIATE_START`)
+    pub static ref JOB_IMMEDIATE_START: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "jobImmediateStart");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// jobLocation
+    ///
+    /// A (typically single) geographic location associated with the job position.
+    ///
+    /// `schema:jobLocation` (`JOB_LOCATION`)
+    pub static ref JOB_LOCATION: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "jobLocation");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// jobLocationType
+    ///
+    /// A description of the job location (e.g. TELECOMMUTE for telecommute jobs).
+    ///
+    /// `schema:jobLocationType` (`JOB_LOCATION_TYPE`)
+    pub static ref JOB_LOCATION_TYPE: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "jobLocationType");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// jobStartDate
+    ///
+    /// The date on which a successful applicant for this job would be expected to start work. Choose a specific date in the future or use the jobImmediateStart property to indicate the position is to be filled as soon as possible.
+    ///
+    /// `schema:jobStartDate` (`JOB_START_DATE`)
+    pub static ref JOB_START_DATE: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "jobStartDate");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// jobTitle
+    ///
+    /// The job title of the person (for example, Financial Manager).
+    ///
+    /// `schema:jobTitle` (`JOB_TITLE`)
+    pub static ref JOB_TITLE: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "jobTitle");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// jurisdiction
+    ///
+    /// Indicates a legal jurisdiction, e.g. of some legislation, or where some government service is based.
+    ///
+    /// `schema:jurisdiction` (`JURISDICTION`)
+    pub static ref JURISDICTION: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "jurisdiction");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// keywords
+    ///
+    /// Keywords or tags used to describe some item. Multiple textual entries in a keywords list are typically delimited by commas, or by repeating the property.
+    ///
+    /// `schema:keywords` (`KEYWORDS`)
+    pub static ref KEYWORDS: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "keywords");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// knownVehicleDamages
+    ///
+    /// A textual description of known damages, both repaired and unrepaired.
+    ///
+    /// `schema:knownVehicleDamages` (`KNOWN_VEHICLE_DAMAGES`)
+    pub static ref KNOWN_VEHICLE_DAMAGES: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "knownVehicleDamages");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// knows
+    ///
+    /// The most generic bi-directional social/work relation.
+    ///
+    /// `schema:knows` (`KNOWS`)
+    pub static ref KNOWS: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "knows");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// knowsAbout
+    ///
+    /// Of a Person, and less typically of an Organization, to indicate a topic that is known about - suggesting possible expertise but not implying it. We do not distinguish skill levels here, or relate this to educational content, events, objectives or JobPosting descriptions.
+    ///
+    /// `schema:knowsAbout` (`KNOWS_ABOUT`)
+    pub static ref KNOWS_ABOUT: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "knowsAbout");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// knowsLanguage
+    ///
+    /// Of a Person, and less typically of an Organization, to indicate a known language. We do not distinguish skill levels or reading/writing/speaking/signing here. Use language codes from the IETF BCP 47 standard.
+    ///
+    /// `schema:knowsLanguage` (`KNOWS_LANGUAGE`)
+    pub static ref KNOWS_LANGUAGE: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "knowsLanguage");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// labelDetails
+    ///
+    /// Link to the drug's label details.
+    ///
+    /// `schema:labelDetails` (`LABEL_DETAILS`)
+    pub static ref LABEL_DETAILS: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "labelDetails");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// landlord
+    ///
+    /// A sub property of participant. The owner of the real estate property.
+    ///
+    /// `schema:landlord` (`LANDLORD`)
+    pub static ref LANDLORD: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "landlord");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// language
+    ///
+    /// A sub property of instrument. The language used on this action.
+    ///
+    /// `schema:language` (`LANGUAGE_PROPERTY`)
+    pub static ref LANGUAGE_PROPERTY: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "language");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// lastReviewed
+    ///
+    /// Date on which the content on this web page was last reviewed for accuracy and/or completeness.
+    ///
+    /// `schema:lastReviewed` (`LAST_REVIEWED`)
+    pub static ref LAST_REVIEWED: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "lastReviewed");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// latitude
+    ///
+    /// The latitude of a location. For example 37.42242 (WGS 84).
+    ///
+    /// `schema:latitude` (`LATITUDE`)
+    pub static ref LATITUDE: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "latitude");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// layoutImage
+    ///
+    /// A schematic image showing the floorplan layout.
+    ///
+    /// `schema:layoutImage` (`LAYOUT_IMAGE`)
+    pub static ref LAYOUT_IMAGE: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "layoutImage");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// learningResourceType
+    ///
+    /// The predominant type or kind characterizing the learning resource. For example, 'presentation', 'handout'.
+    ///
+    /// `schema:learningResourceType` (`LEARNING_RESOURCE_TYPE`)
+    pub static ref LEARNING_RESOURCE_TYPE: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "learningResourceType");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// leaseLength
+    ///
+    /// Length of the lease for some Accommodation, either particular to some Offer or in some cases intrinsic to the property.
+    ///
+    /// `schema:leaseLength` (`LEASE_LENGTH`)
+    pub static ref LEASE_LENGTH: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "leaseLength");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// legalName
+    ///
+    /// The official name of the organization, e.g. the registered company name.
+    ///
+    /// `schema:legalName` (`LEGAL_NAME`)
+    pub static ref LEGAL_NAME: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "legalName");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// legalStatus
+    ///
+    /// The drug or supplement's legal status, including any controlled substance schedules that apply.
+    ///
+    /// `schema:legalStatus` (`LEGAL_STATUS`)
+    pub static ref LEGAL_STATUS: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "legalStatus");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// legislationApplies
+    ///
+    /// Indicates that this legislation (or part of a legislation) somehow transfers another legislation in a different legislative context. This is an informative link, and it has no legal value. For legally-binding links of transposition, use the legislationTransposes property.
+    ///
+    /// `schema:legislationApplies` (`LEGISLATION_APPLIES`)
+    pub static ref LEGISLATION_APPLIES: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "legislationApplies");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// legislationChanges
+    ///
+    /// Another legislation that this legislation changes. This encompasses the notions of amendment, replacement, correction, repeal, or other types of change.
+    ///
+    /// `schema:legislationChanges` (`LEGISLATION_CHANGES`)
+    pub static ref LEGISLATION_CHANGES: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "legislationChanges");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// legislationConsolidates
+    ///
+    /// Indicates another legislation taken into account in this consolidated legislation (which is usually the product of an editorial process that revises the legislation). This property should be used multiple times to refer to both the original version or the previous consolidated version, and to the legislations making the change.
+    ///
+    /// `schema:legislationConsolidates` (`LEGISLATION_CONSOLIDATES`)
+    pub static ref LEGISLATION_CONSOLIDATES: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "legislationConsolidates");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// legislationDate
+    ///
+    /// The date of adoption or signature of the legislation. This is the date at which the text is officially aknowledged to be a legislation, even though it might not even be published or in force.
+    ///
+    /// `schema:legislationDate` (`LEGISLATION_DATE`)
+    pub static ref LEGISLATION_DATE: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "legislationDate");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// legislationDateVersion
+    ///
+    /// The point-in-time at which the provided description of the legislation is valid (e.g.: when looking at the law on the 2016-04-07 (= dateVersion), I get the consolidation of 2015-04-12 of the "National Insurance Contributions Act 2015")
+    ///
+    /// `schema:legislationDateVersion` (`LEGISLATION_DATE_VERSION`)
+    pub static ref LEGISLATION_DATE_VERSION: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "legislationDateVersion");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// legislationIdentifier
+    ///
+    /// An identifier for the legislation. This can be either a string-based identifier, like the CELEX at EU level or the NOR in France, or a web-based, URL/URI identifier, like an ELI (European Legislation Identifier) or an URN-Lex.
+    ///
+    /// `schema:legislationIdentifier` (`LEGISLATION_IDENTIFIER`)
+    pub static ref LEGISLATION_IDENTIFIER: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "legislationIdentifier");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// legislationJurisdiction
+    ///
+    /// The jurisdiction from which the legislation originates.
+    ///
+    /// `schema:legislationJurisdiction` (`LEGISLATION_JURISDICTION`)
+    pub static ref LEGISLATION_JURISDICTION: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "legislationJurisdiction");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// legislationLegalForce
+    ///
+    /// Whether the legislation is currently in force, not in force, or partially in force.
+    ///
+    /// `schema:legislationLegalForce` (`LEGISLATION_LEGAL_FORCE`)
+    pub static ref LEGISLATION_LEGAL_FORCE: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "legislationLegalForce");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// legislationLegalValue
+    ///
+    /// The legal value of this legislation file. The same legislation can be written in multiple files with different legal values. Typically a digitally signed PDF have a "stronger" legal value than the HTML file of the same act.
+    ///
+    /// `schema:legislationLegalValue` (`LEGISLATION_LEGAL_VALUE`)
+    pub static ref LEGISLATION_LEGAL_VALUE: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "legislationLegalValue");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// legislationPassedBy
+    ///
+    /// The person or organization that originally passed or made the law: typically parliament (for primary legislation) or government (for secondary legislation). This indicates the "legal author" of the law, as opposed to its physical author.
+    ///
+    /// `schema:legislationPassedBy` (`LEGISLATION_PASSED_BY`)
+    pub static ref LEGISLATION_PASSED_BY: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "legislationPassedBy");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// legislationResponsible
+    ///
+    /// An individual or organization that has some kind of responsibility for the legislation. Typically the ministry who is/was in charge of elaborating the legislation, or the adressee for potential questions about the legislation once it is published.
+    ///
+    /// `schema:legislationResponsible` (`LEGISLATION_RESPONSIBLE`)
+    pub static ref LEGISLATION_RESPONSIBLE: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "legislationResponsible");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// legislationTransposes
+    ///
+    /// Indicates that this legislation (or part of legislation) fulfills the objectives set by another legislation, by passing appropriate implementation measures. Typically, some legislations of European Union's member states or regions transpose European Directives. This indicates a legally binding link between the 2 legislations.
+    ///
+    /// `schema:legislationTransposes` (`LEGISLATION_TRANSPOSES`)
+    pub static ref LEGISLATION_TRANSPOSES: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "legislationTransposes");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// legislationType
+    ///
+    /// The type of the legislation. Examples of values are "law", "act", "directive", "decree", "regulation", "statutory instrument", "loi organique", "règlement grand-ducal", etc., depending on the country.
+    ///
+    /// `schema:legislationType` (`LEGISLATION_TYPE`)
+    pub static ref LEGISLATION_TYPE: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "legislationType");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// leiCode
+    ///
+    /// An organization identifier that uniquely identifies a legal entity as defined in ISO 17442.
+    ///
+    /// `schema:leiCode` (`LEI_CODE`)
+    pub static ref LEI_CODE: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "leiCode");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// lender
+    ///
+    /// A sub property of participant. The person that lends the object being borrowed.
+    ///
+    /// `schema:lender` (`LENDER`)
+    pub static ref LENDER: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "lender");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// lesser
+    ///
+    /// This ordering relation for qualitative values indicates that the subject is lesser than the object.
+    ///
+    /// `schema:lesser` (`LESSER`)
+    pub static ref LESSER: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "lesser");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// lesserOrEqual
+    ///
+    /// This ordering relation for qualitative values indicates that the subject is lesser than or equal to the object.
+    ///
+    /// `schema:lesserOrEqual` (`LESSER_OR_EQUAL`)
+    pub static ref LESSER_OR_EQUAL: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "lesserOrEqual");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// letterer
+    ///
+    /// The individual who adds lettering, including speech balloons and sound effects, to artwork.
+    ///
+    /// `schema:letterer` (`LETTERER`)
+    pub static ref LETTERER: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "letterer");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// license
+    ///
+    /// A license document that applies to this content, typically indicated by URL.
+    ///
+    /// `schema:license` (`LICENSE`)
+    pub static ref LICENSE: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "license");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// line
+    ///
+    /// A line is a point-to-point path consisting of two or more points. A line is expressed as a series of two or more point objects separated by space.
+    ///
+    /// `schema:line` (`LINE`)
+    pub static ref LINE: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "line");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// linkRelationship
+    ///
+    /// Indicates the relationship type of a Web link.
+    ///
+    /// `schema:linkRelationship` (`LINK_RELATIONSHIP`)
+    pub static ref LINK_RELATIONSHIP: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "linkRelationship");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// liveBlogUpdate
+    ///
+    /// An update to the LiveBlog.
+    ///
+    /// `schema:liveBlogUpdate` (`LIVE_BLOG_UPDATE`)
+    pub static ref LIVE_BLOG_UPDATE: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "liveBlogUpdate");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// loanMortgageMandateAmount
+    ///
+    /// Amount of mortgage mandate that can be converted into a proper mortgage at a later stage.
+    ///
+    /// `schema:loanMortgageMandateAmount` (`LOAN_MORTGAGE_MANDATE_AMOUNT`)
+    pub static ref LOAN_MORTGAGE_MANDATE_AMOUNT: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "loanMortgageMandateAmount");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// loanPaymentAmount
+    ///
+    /// The amount of money to pay in a single payment.
+    ///
+    /// `schema:loanPaymentAmount` (`LOAN_PAYMENT_AMOUNT`)
+    pub static ref LOAN_PAYMENT_AMOUNT: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "loanPaymentAmount");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// loanPaymentFrequency
+    ///
+    /// Frequency of payments due, i.e. number of months between payments. This is defined as a frequency, i.e. the reciprocal of a period of time.
+    ///
+    /// `schema:loanPaymentFrequency` (`LOAN_PAYMENT_FREQUENCY`)
+    pub static ref LOAN_PAYMENT_FREQUENCY: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "loanPaymentFrequency");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// loanRepaymentForm
+    ///
+    /// A form of paying back money previously borrowed from a lender. Repayment usually takes the form of periodic payments that normally include part principal plus interest in each payment.
+    ///
+    /// `schema:loanRepaymentForm` (`LOAN_REPAYMENT_FORM`)
+    pub static ref LOAN_REPAYMENT_FORM: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "loanRepaymentForm");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// loanTerm
+    ///
+    /// The duration of the loan or credit agreement.
+    ///
+    /// `schema:loanTerm` (`LOAN_TERM`)
+    pub static ref LOAN_TERM: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "loanTerm");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// loanType
+    ///
+    /// The type of a loan or credit.
+    ///
+    /// `schema:loanType` (`LOAN_TYPE`)
+    pub static ref LOAN_TYPE: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "loanType");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// location
+    ///
+    /// The location of, for example, where an event is happening, where an organization is located, or where an action takes place.
+    ///
+    /// `schema:location` (`LOCATION`)
+    pub static ref LOCATION: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "location");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// locationCreated
+    ///
+    /// The location where the CreativeWork was created, which may not be the same as the location depicted in the CreativeWork.
+    ///
+    /// `schema:locationCreated` (`LOCATION_CREATED`)
+    pub static ref LOCATION_CREATED: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "locationCreated");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// lodgingUnitDescription
+    ///
+    /// A full description of the lodging unit.
+    ///
+    /// `schema:lodgingUnitDescription` (`LODGING_UNIT_DESCRIPTION`)
+    pub static ref LODGING_UNIT_DESCRIPTION: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "lodgingUnitDescription");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// lodgingUnitType
+    ///
+    /// Textual description of the unit type (including suite vs. room, size of bed, etc.).
+    ///
+    /// `schema:lodgingUnitType` (`LODGING_UNIT_TYPE`)
+    pub static ref LODGING_UNIT_TYPE: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "lodgingUnitType");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// logo
+    ///
+    /// An associated logo.
+    ///
+    /// `schema:logo` (`LOGO`)
+    pub static ref LOGO: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "logo");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// longitude
+    ///
+    /// The longitude of a location. For example -122.08585 (WGS 84).
+    ///
+    /// `schema:longitude` (`LONGITUDE`)
+    pub static ref LONGITUDE: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "longitude");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// loser
+    ///
+    /// A sub property of participant. The loser of the action.
+    ///
+    /// `schema:loser` (`LOSER`)
+    pub static ref LOSER: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "loser");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// lowPrice
+    ///
+    /// The lowest price of all offers available. Usage guidelines: use values from 0123456789 (Unicode 'DIGIT ZERO' (U+0030) to 'DIGIT NINE' (U+0039)) rather than superficially similar Unicode symbols, and use '.' (Unicode 'FULL STOP' (U+002E)) rather than ',' to indicate a decimal point.
+    ///
+    /// `schema:lowPrice` (`LOW_PRICE`)
+    pub static ref LOW_PRICE: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "lowPrice");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// lyricist
+    ///
+    /// The person who wrote the words.
+    ///
+    /// `schema:lyricist` (`LYRICIST`)
+    pub static ref LYRICIST: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "lyricist");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// lyrics
+    ///
+    /// The words in the song.
+    ///
+    /// `schema:lyrics` (`LYRICS`)
+    pub static ref LYRICS: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "lyrics");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// mainContentOfPage
+    ///
+    /// Indicates if this web page element is the main subject of the page.
+    ///
+    /// `schema:mainContentOfPage` (`MAIN_CONTENT_OF_PAGE`)
+    pub static ref MAIN_CONTENT_OF_PAGE: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "mainContentOfPage");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// mainEntity
+    ///
+    /// Indicates the primary entity described in some page or other CreativeWork.
+    ///
+    /// `schema:mainEntity` (`MAIN_ENTITY`)
+    pub static ref MAIN_ENTITY: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "mainEntity");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// mainEntityOfPage
+    ///
+    /// Indicates a page (or other CreativeWork) for which this thing is the main entity being described.
+    ///
+    /// `schema:mainEntityOfPage` (`MAIN_ENTITY_OF_PAGE`)
+    pub static ref MAIN_ENTITY_OF_PAGE: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "mainEntityOfPage");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// maintainer
+    ///
+    /// A maintainer of a Dataset, software package (SoftwareApplication), or other Project. A maintainer is a Person or Organization that manages contributions to, and/or publication of, some (typically complex) artifact.
+    ///
+    /// `schema:maintainer` (`MAINTAINER`)
+    pub static ref MAINTAINER: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "maintainer");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// makesOffer
+    ///
+    /// A pointer to products or services offered by the organization or person.
+    ///
+    /// `schema:makesOffer` (`MAKES_OFFER`)
+    pub static ref MAKES_OFFER: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "makesOffer");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// manufacturer
+    ///
+    /// The manufacturer of the product.
+    ///
+    /// `schema:manufacturer` (`MANUFACTURER`)
+    pub static ref MANUFACTURER: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "manufacturer");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// map
+    ///
+    /// A URL to a map of the place.
+    ///
+    /// `schema:map` (`MAP_PROPERTY`)
+    pub static ref MAP_PROPERTY: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "map");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// mapType
+    ///
+    /// Indicates the kind of Map, from the MapCategoryType Enumeration.
+    ///
+    /// `schema:mapType` (`MAP_TYPE`)
+    pub static ref MAP_TYPE: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "mapType");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// maps
+    ///
+    /// A URL to a map of the place.
+    ///
+    /// `schema:maps` (`MAPS`)
+    pub static ref MAPS: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "maps");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// marginOfError
+    ///
+    /// A marginOfError for an Observation.
+    ///
+    /// `schema:marginOfError` (`MARGIN_OF_ERROR`)
+    pub static ref MARGIN_OF_ERROR: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "marginOfError");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// masthead
+    ///
+    /// For a NewsMediaOrganization, a link to the masthead page or a page listing top editorial management.
+    ///
+    /// `schema:masthead` (`MASTHEAD`)
+    pub static ref MASTHEAD: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "masthead");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// material
+    ///
+    /// A material that something is made from, e.g. leather, wool, cotton, paper.
+    ///
+    /// `schema:material` (`MATERIAL`)
+    pub static ref MATERIAL: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "material");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// materialExtent
+    ///
+    /// The quantity of the materials being described or an expression of the physical space they occupy.
+    ///
+    /// `schema:materialExtent` (`MATERIAL_EXTENT`)
+    pub static ref MATERIAL_EXTENT: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "materialExtent");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// mathExpression
+    ///
+    /// A mathematical expression (e.g. 'x^2-3x=0') that may be solved for a specific variable, simplified, or transformed. This can take many formats, e.g. LaTeX, Ascii-Math, or math as you would write with a keyboard.
+    ///
+    /// `schema:mathExpression` (`MATH_EXPRESSION`)
+    pub static ref MATH_EXPRESSION: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "mathExpression");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// maxPrice
+    ///
+    /// The highest price if the price is a range.
+    ///
+    /// `schema:maxPrice` (`MAX_PRICE`)
+    pub static ref MAX_PRICE: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "maxPrice");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// maxValue
+    ///
+    /// The upper value of some characteristic or property.
+    ///
+    /// `schema:maxValue` (`MAX_VALUE`)
+    pub static ref MAX_VALUE: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "maxValue");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// maximumAttendeeCapacity
+    ///
+    /// The total number of individuals that may attend an event or venue.
+    ///
+    /// `schema:maximumAttendeeCapacity` (`MAXIMUM_ATTENDEE_CAPACITY`)
+    pub static ref MAXIMUM_ATTENDEE_CAPACITY: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "maximumAttendeeCapacity");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// maximumEnrollment
+    ///
+    /// The maximum number of students who may be enrolled in the program.
+    ///
+    /// `schema:maximumEnrollment` (`MAXIMUM_ENROLLMENT`)
+    pub static ref MAXIMUM_ENROLLMENT: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "maximumEnrollment");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// maximumIntake
+    ///
+    /// Recommended intake of this supplement for a given population as defined by a specific recommending authority.
+    ///
+    /// `schema:maximumIntake` (`MAXIMUM_INTAKE`)
+    pub static ref MAXIMUM_INTAKE: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "maximumIntake");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// maximumPhysicalAttendeeCapacity
+    ///
+    /// The maximum physical attendee capacity of an Event whose eventAttendanceMode is OfflineEventAttendanceMode (or the offline aspects, in the case of a MixedEventAttendanceMode).
+    ///
+    /// `schema:maximumPhysicalAttendeeCapacity` (`MAXIMUM_PHYSICAL_ATTENDEE_CAPACITY`)
+    pub static ref MAXIMUM_PHYSICAL_ATTENDEE_CAPACITY: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "maximumPhysicalAttendeeCapacity");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// maximumVirtualAttendeeCapacity
+    ///
+    /// The maximum virtual attendee capacity of an Event whose eventAttendanceMode is OnlineEventAttendanceMode (or the online aspects, in the case of a MixedEventAttendanceMode).
+    ///
+    /// `schema:maximumVirtualAttendeeCapacity` (`MAXIMUM_VIRTUAL_ATTENDEE_CAPACITY`)
+    pub static ref MAXIMUM_VIRTUAL_ATTENDEE_CAPACITY: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "maximumVirtualAttendeeCapacity");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// mealService
+    ///
+    /// Description of the meals that will be provided or available for purchase.
+    ///
+    /// `schema:mealService` (`MEAL_SERVICE`)
+    pub static ref MEAL_SERVICE: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "mealService");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// measuredProperty
+    ///
+    /// The measuredProperty of an Observation, typically via its StatisticalVariable. There are various kinds of applicable Property: a schema.org Property, a Wikidata Property, or others.
+    ///
+    /// `schema:measuredProperty` (`MEASURED_PROPERTY`)
+    pub static ref MEASURED_PROPERTY: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "measuredProperty");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// measurementDenominator
+    ///
+    /// Identifies the denominator variable when an observation represents a ratio or percentage.
+    ///
+    /// `schema:measurementDenominator` (`MEASUREMENT_DENOMINATOR`)
+    pub static ref MEASUREMENT_DENOMINATOR: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "measurementDenominator");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// measurementMethod
+    ///
+    /// A subproperty of measurementTechnique that can be used for specifying specific methods, in particular via MeasurementMethodEnum.
+    ///
+    /// `schema:measurementMethod` (`MEASUREMENT_METHOD`)
+    pub static ref MEASUREMENT_METHOD: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "measurementMethod");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// measurementQualifier
+    ///
+    /// Provides additional qualification to an observation. For example, a GDP observation measures the Nominal value.
+    ///
+    /// `schema:measurementQualifier` (`MEASUREMENT_QUALIFIER`)
+    pub static ref MEASUREMENT_QUALIFIER: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "measurementQualifier");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// measurementTechnique
+    ///
+    /// A technique, method or technology used in an Observation, StatisticalVariable or Dataset (or DataDownload, DataCatalog), corresponding to the method used for measuring the corresponding variable(s).
+    ///
+    /// `schema:measurementTechnique` (`MEASUREMENT_TECHNIQUE`)
+    pub static ref MEASUREMENT_TECHNIQUE: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "measurementTechnique");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// mechanismOfAction
+    ///
+    /// The specific biochemical interaction through which this drug or supplement produces its pharmacological effect.
+    ///
+    /// `schema:mechanismOfAction` (`MECHANISM_OF_ACTION`)
+    pub static ref MECHANISM_OF_ACTION: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "mechanismOfAction");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// mediaAuthenticityCategory
+    ///
+    /// Indicates a MediaManipulationRatingEnumeration classification of a media object (in the context of how it was published or shared).
+    ///
+    /// `schema:mediaAuthenticityCategory` (`MEDIA_AUTHENTICITY_CATEGORY`)
+    pub static ref MEDIA_AUTHENTICITY_CATEGORY: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "mediaAuthenticityCategory");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// mediaItemAppearance
+    ///
+    /// In the context of a MediaReview, indicates specific media item(s) that are grouped using a MediaReviewItem.
+    ///
+    /// `schema:mediaItemAppearance` (`MEDIA_ITEM_APPEARANCE`)
+    pub static ref MEDIA_ITEM_APPEARANCE: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "mediaItemAppearance");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// median
+    ///
+    /// The median value.
+    ///
+    /// `schema:median` (`MEDIAN`)
+    pub static ref MEDIAN: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "median");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// medicalAudience
+    ///
+    /// Medical audience for page.
+    ///
+    /// `schema:medicalAudience` (`MEDICAL_AUDIENCE_PROPERTY`)
+    pub static ref MEDICAL_AUDIENCE_PROPERTY: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "medicalAudience");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// medicalSpecialty
+    ///
+    /// A medical specialty of the provider.
+    ///
+    /// `schema:medicalSpecialty` (`MEDICAL_SPECIALTY_PROPERTY`)
+    pub static ref MEDICAL_SPECIALTY_PROPERTY: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "medicalSpecialty");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// medicineSystem
+    ///
+    /// The system of medicine that includes this MedicalEntity, for example 'evidence-based', 'homeopathic', 'chiropractic', etc.
+    ///
+    /// `schema:medicineSystem` (`MEDICINE_SYSTEM_PROPERTY`)
+    pub static ref MEDICINE_SYSTEM_PROPERTY: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "medicineSystem");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// meetsEmissionStandard
+    ///
+    /// Indicates that the vehicle meets the respective emission standard.
+    ///
+    /// `schema:meetsEmissionStandard` (`MEETS_EMISSION_STANDARD`)
+    pub static ref MEETS_EMISSION_STANDARD: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "meetsEmissionStandard");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// member
+    ///
+    /// A member of an Organization or a ProgramMembership. Organizations can be members of organizations; ProgramMembership is typically for individuals.
+    ///
+    /// `schema:member` (`MEMBER`)
+    pub static ref MEMBER: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "member");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// memberOf
+    ///
+    /// An Organization (or ProgramMembership) to which this Person or Organization belongs.
+    ///
+    /// `schema:memberOf` (`MEMBER_OF`)
+    pub static ref MEMBER_OF: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "memberOf");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// members
+    ///
+    /// A member of this organization.
+    ///
+    /// `schema:members` (`MEMBERS`)
+    pub static ref MEMBERS: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "members");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// membershipNumber
+    ///
+    /// A unique identifier for the membership.
+    ///
+    /// `schema:membershipNumber` (`MEMBERSHIP_NUMBER`)
+    pub static ref MEMBERSHIP_NUMBER: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "membershipNumber");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// membershipPointsEarned
+    ///
+    /// The number of membership points earned by the member. If necessary, the unitText can be used to express the units the points are issued in. (E.g. stars, miles, etc.)
+    ///
+    /// `schema:membershipPointsEarned` (`MEMBERSHIP_POINTS_EARNED`)
+    pub static ref MEMBERSHIP_POINTS_EARNED: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "membershipPointsEarned");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// memoryRequirements
+    ///
+    /// Minimum memory requirements.
+    ///
+    /// `schema:memoryRequirements` (`MEMORY_REQUIREMENTS`)
+    pub static ref MEMORY_REQUIREMENTS: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "memoryRequirements");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// mentions
+    ///
+    /// Indicates that the CreativeWork contains a reference to, but is not necessarily about a concept.
+    ///
+    /// `schema:mentions` (`MENTIONS`)
+    pub static ref MENTIONS: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "mentions");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// menu
+    ///
+    /// Either the actual menu as a structured representation, as text, or a URL of the menu.
+    ///
+    /// `schema:menu` (`MENU_PROPERTY`)
+    pub static ref MENU_PROPERTY: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "menu");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// menuAddOn
+    ///
+    /// Additional menu item(s) such as a side dish of salad or side order of fries that can be added to this menu item. Additionally it can be a menu section containing allowed add-on menu items for this menu item.
+    ///
+    /// `schema:menuAddOn` (`MENU_ADD_ON`)
+    pub static ref MENU_ADD_ON: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "menuAddOn");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// merchant
+    ///
+    /// 'merchant' is an out-dated term for 'seller'.
+    ///
+    /// `schema:merchant` (`MERCHANT`)
+    pub static ref MERCHANT: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "merchant");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// merchantReturnDays
+    ///
+    /// Specifies either a fixed return date or the number of days (from the delivery date) that a product can be returned. Used when the returnPolicyCategory property is specified as MerchantReturnFiniteReturnWindow.
+    ///
+    /// `schema:merchantReturnDays` (`MERCHANT_RETURN_DAYS`)
+    pub static ref MERCHANT_RETURN_DAYS: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "merchantReturnDays");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// merchantReturnLink
+    ///
+    /// Specifies a Web page or service by URL, for product returns.
+    ///
+    /// `schema:merchantReturnLink` (`MERCHANT_RETURN_LINK`)
+    pub static ref MERCHANT_RETURN_LINK: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "merchantReturnLink");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// messageAttachment
+    ///
+    /// A CreativeWork attached to the message.
+    ///
+    /// `schema:messageAttachment` (`MESSAGE_ATTACHMENT`)
+    pub static ref MESSAGE_ATTACHMENT: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "messageAttachment");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// mileageFromOdometer
+    ///
+    /// The total distance travelled by the particular vehicle since its initial production, as read from its odometer.
+    ///
+    /// `schema:mileageFromOdometer` (`MILEAGE_FROM_ODOMETER`)
+    pub static ref MILEAGE_FROM_ODOMETER: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "mileageFromOdometer");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// minPrice
+    ///
+    /// The lowest price if the price is a range.
+    ///
+    /// `schema:minPrice` (`MIN_PRICE`)
+    pub static ref MIN_PRICE: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "minPrice");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// minValue
+    ///
+    /// The lower value of some characteristic or property.
+    ///
+    /// `schema:minValue` (`MIN_VALUE`)
+    pub static ref MIN_VALUE: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "minValue");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// minimumPaymentDue
+    ///
+    /// The minimum payment required at this time.
+    ///
+    /// `schema:minimumPaymentDue` (`MINIMUM_PAYMENT_DUE`)
+    pub static ref MINIMUM_PAYMENT_DUE: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "minimumPaymentDue");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// missionCoveragePrioritiesPolicy
+    ///
+    /// For a NewsMediaOrganization, a statement on coverage priorities, including any public agenda or stance on issues.
+    ///
+    /// `schema:missionCoveragePrioritiesPolicy` (`MISSION_COVERAGE_PRIORITIES_POLICY`)
+    pub static ref MISSION_COVERAGE_PRIORITIES_POLICY: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "missionCoveragePrioritiesPolicy");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// mobileUrl
+    ///
+    /// The mobileUrl property is provided for specific situations in which data consumers need to determine whether one of several provided URLs is a dedicated 'mobile site'.
+    ///
+    /// `schema:mobileUrl` (`MOBILE_URL`)
+    pub static ref MOBILE_URL: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "mobileUrl");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// model
+    ///
+    /// The model of the product. Use with the URL of a ProductModel or a textual representation of the model identifier. The URL of the ProductModel can be from an external source. It is recommended to additionally provide strong product identifiers via the gtin8/gtin13/gtin14 and mpn properties.
+    ///
+    /// `schema:model` (`MODEL`)
+    pub static ref MODEL: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "model");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// modelDate
+    ///
+    /// The release date of a vehicle model (often used to differentiate versions of the same make and model).
+    ///
+    /// `schema:modelDate` (`MODEL_DATE`)
+    pub static ref MODEL_DATE: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "modelDate");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// modifiedTime
+    ///
+    /// The date and time the reservation was modified.
+    ///
+    /// `schema:modifiedTime` (`MODIFIED_TIME`)
+    pub static ref MODIFIED_TIME: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "modifiedTime");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// molecularFormula
+    ///
+    /// The empirical formula is the simplest whole number ratio of all the atoms in a molecule.
+    ///
+    /// `schema:molecularFormula` (`MOLECULAR_FORMULA`)
+    pub static ref MOLECULAR_FORMULA: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "molecularFormula");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// molecularWeight
+    ///
+    /// This is the molecular weight of the entity being described, not of the parent. Units should be included in the form '{NUMBER} {UNIT}', for example '12 amu' or as '{QuantitativeValue}.
+    ///
+    /// `schema:molecularWeight` (`MOLECULAR_WEIGHT`)
+    pub static ref MOLECULAR_WEIGHT: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "molecularWeight");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// monoisotopicMolecularWeight
+    ///
+    /// The monoisotopic mass is the sum of the masses of the atoms in a molecule using the unbound, ground-state, rest mass of the principal (most abundant) isotope for each element instead of the isotopic average mass. Please include the units in the form '{NUMBER} {UNIT}', for example '770.230488 g/mol' or as '{QuantitativeValue}.
+    ///
+    /// `schema:monoisotopicMolecularWeight` (`MONOISOTOPIC_MOLECULAR_WEIGHT`)
+    pub static ref MONOISOTOPIC_MOLECULAR_WEIGHT: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "monoisotopicMolecularWeight");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// monthlyMinimumRepaymentAmount
+    ///
+    /// The minimum payment is the lowest amount of money that one is required to pay on a credit card statement each month.
+    ///
+    /// `schema:monthlyMinimumRepaymentAmount` (`MONTHLY_MINIMUM_REPAYMENT_AMOUNT`)
+    pub static ref MONTHLY_MINIMUM_REPAYMENT_AMOUNT: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "monthlyMinimumRepaymentAmount");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// monthsOfExperience
+    ///
+    /// Indicates the minimal number of months of experience required for a position.
+    ///
+    /// `schema:monthsOfExperience` (`MONTHS_OF_EXPERIENCE`)
+    pub static ref MONTHS_OF_EXPERIENCE: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "monthsOfExperience");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// mpn
+    ///
+    /// The Manufacturer Part Number (MPN) of the product, or the product to which the offer refers.
+    ///
+    /// `schema:mpn` (`MPN`)
+    pub static ref MPN: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "mpn");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// multipleValues
+    ///
+    /// Whether multiple values are allowed for the property. Default is false.
+    ///
+    /// `schema:multipleValues` (`MULTIPLE_VALUES`)
+    pub static ref MULTIPLE_VALUES: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "multipleValues");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// muscleAction
+    ///
+    /// The movement the muscle generates.
+    ///
+    /// `schema:muscleAction` (`MUSCLE_ACTION`)
+    pub static ref MUSCLE_ACTION: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "muscleAction");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// musicArrangement
+    ///
+    /// An arrangement derived from the composition.
+    ///
+    /// `schema:musicArrangement` (`MUSIC_ARRANGEMENT`)
+    pub static ref MUSIC_ARRANGEMENT: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "musicArrangement");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// musicBy
+    ///
+    /// The composer of the soundtrack.
+    ///
+    /// `schema:musicBy` (`MUSIC_BY`)
+    pub static ref MUSIC_BY: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "musicBy");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// musicCompositionForm
+    ///
+    /// The type of composition (e.g. overture, sonata, symphony, etc.).
+    ///
+    /// `schema:musicCompositionForm` (`MUSIC_COMPOSITION_FORM`)
+    pub static ref MUSIC_COMPOSITION_FORM: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "musicCompositionForm");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// musicGroupMember
+    ///
+    /// A member of a music group, for example, John, Paul, George, or Ringo.
+    ///
+    /// `schema:musicGroupMember` (`MUSIC_GROUP_MEMBER`)
+    pub static ref MUSIC_GROUP_MEMBER: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "musicGroupMember");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// musicReleaseFormat
+    ///
+    /// Format of this release (the type of recording media used, i.e. compact disc, digital media, LP, etc.).
+    ///
+    /// `schema:musicReleaseFormat` (`MUSIC_RELEASE_FORMAT`)
+    pub static ref MUSIC_RELEASE_FORMAT: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "musicReleaseFormat");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// musicalKey
+    ///
+    /// The key, mode, or scale this composition uses.
+    ///
+    /// `schema:musicalKey` (`MUSICAL_KEY`)
+    pub static ref MUSICAL_KEY: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "musicalKey");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// naics
+    ///
+    /// The North American Industry Classification System (NAICS) code for a particular organization or business person.
+    ///
+    /// `schema:naics` (`NAICS`)
+    pub static ref NAICS: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "naics");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// name
+    ///
+    /// The name of the item.
+    ///
+    /// `schema:name` (`NAME`)
+    pub static ref NAME: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "name");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// namedPosition
+    ///
+    /// A position played, performed or filled by a person or organization, as part of an organization. For example, an athlete in a SportsTeam might play in the position named 'Quarterback'.
+    ///
+    /// `schema:namedPosition` (`NAMED_POSITION`)
+    pub static ref NAMED_POSITION: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "namedPosition");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// nationality
+    ///
+    /// Nationality of the person.
+    ///
+    /// `schema:nationality` (`NATIONALITY`)
+    pub static ref NATIONALITY: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "nationality");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// naturalProgression
+    ///
+    /// The expected progression of the condition if it is not treated and allowed to progress naturally.
+    ///
+    /// `schema:naturalProgression` (`NATURAL_PROGRESSION`)
+    pub static ref NATURAL_PROGRESSION: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "naturalProgression");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// negativeNotes
+    ///
+    /// Provides negative considerations regarding something, most typically in pro/con lists for reviews (alongside positiveNotes).
+    ///
+    /// `schema:negativeNotes` (`NEGATIVE_NOTES`)
+    pub static ref NEGATIVE_NOTES: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "negativeNotes");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// nerve
+    ///
+    /// The underlying innervation associated with the muscle.
+    ///
+    /// `schema:nerve` (`NERVE_PROPERTY`)
+    pub static ref NERVE_PROPERTY: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "nerve");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// nerveMotor
+    ///
+    /// The neurological pathway extension that involves muscle control.
+    ///
+    /// `schema:nerveMotor` (`NERVE_MOTOR`)
+    pub static ref NERVE_MOTOR: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "nerveMotor");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// netWorth
+    ///
+    /// The total financial value of the person as calculated by subtracting assets from liabilities.
+    ///
+    /// `schema:netWorth` (`NET_WORTH`)
+    pub static ref NET_WORTH: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "netWorth");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// newsUpdatesAndGuidelines
+    ///
+    /// Indicates a page with news updates and guidelines. This could often be (but is not required to be) the main page containing SpecialAnnouncement markup on a site.
+    ///
+    /// `schema:newsUpdatesAndGuidelines` (`NEWS_UPDATES_AND_GUIDELINES`)
+    pub static ref NEWS_UPDATES_AND_GUIDELINES: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "newsUpdatesAndGuidelines");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// nextItem
+    ///
+    /// A link to the ListItem that follows the current one.
+    ///
+    /// `schema:nextItem` (`NEXT_ITEM`)
+    pub static ref NEXT_ITEM: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "nextItem");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// noBylinesPolicy
+    ///
+    /// For a NewsMediaOrganization or other news-related Organization, a statement explaining when authors of articles are not named in bylines.
+    ///
+    /// `schema:noBylinesPolicy` (`NO_BYLINES_POLICY`)
+    pub static ref NO_BYLINES_POLICY: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "noBylinesPolicy");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// nonEqual
+    ///
+    /// This ordering relation for qualitative values indicates that the subject is not equal to the object.
+    ///
+    /// `schema:nonEqual` (`NON_EQUAL`)
+    pub static ref NON_EQUAL: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "nonEqual");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// nonProprietaryName
+    ///
+    /// The generic name of this drug or supplement.
+    ///
+    /// `schema:nonProprietaryName` (`NON_PROPRIETARY_NAME`)
+    pub static ref NON_PROPRIETARY_NAME: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "nonProprietaryName");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// nonprofitStatus
+    ///
+    /// nonprofitStatus indicates the legal status of a non-profit organization in its primary place of business.
+    ///
+    /// `schema:nonprofitStatus` (`NONPROFIT_STATUS`)
+    pub static ref NONPROFIT_STATUS: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "nonprofitStatus");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// normalRange
+    ///
+    /// Range of acceptable values for a typical patient, when applicable.
+    ///
+    /// `schema:normalRange` (`NORMAL_RANGE`)
+    pub static ref NORMAL_RANGE: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "normalRange");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// nsn
+    ///
+    /// Indicates the NATO stock number (nsn) of a Product.
+    ///
+    /// `schema:nsn` (`NSN`)
+    pub static ref NSN: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "nsn");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// numAdults
+    ///
+    /// The number of adults staying in the unit.
+    ///
+    /// `schema:numAdults` (`NUM_ADULTS`)
+    pub static ref NUM_ADULTS: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "numAdults");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// numChildren
+    ///
+    /// The number of children staying in the unit.
+    ///
+    /// `schema:numChildren` (`NUM_CHILDREN`)
+    pub static ref NUM_CHILDREN: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "numChildren");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// numConstraints
+    ///
+    /// Indicates the number of constraints property values defined for a particular ConstraintNode such as StatisticalVariable. This helps applications understand if they have access to a sufficiently complete description of a StatisticalVariable or other construct that is defined using properties on template-style nodes.
+    ///
+    /// `schema:numConstraints` (`NUM_CONSTRAINTS`)
+    pub static ref NUM_CONSTRAINTS: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "numConstraints");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// numTracks
+    ///
+    /// The number of tracks in this album or playlist.
+    ///
+    /// `schema:numTracks` (`NUM_TRACKS`)
+    pub static ref NUM_TRACKS: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "numTracks");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// numberOfAccommodationUnits
+    ///
+    /// Indicates the total (available plus unavailable) number of accommodation units in an ApartmentComplex, or the number of accommodation units for a specific FloorPlan (within its specific ApartmentComplex).
+    ///
+    /// `schema:numberOfAccommodationUnits` (`NUMBER_OF_ACCOMMODATION_UNITS`)
+    pub static ref NUMBER_OF_ACCOMMODATION_UNITS: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "numberOfAccommodationUnits");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// numberOfAirbags
+    ///
+    /// The number or type of airbags in the vehicle.
+    ///
+    /// `schema:numberOfAirbags` (`NUMBER_OF_AIRBAGS`)
+    pub static ref NUMBER_OF_AIRBAGS: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "numberOfAirbags");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// numberOfAvailableAccommodationUnits
+    ///
+    /// Indicates the number of available accommodation units in an ApartmentComplex, or the number of accommodation units for a specific FloorPlan (within its specific ApartmentComplex).
+    ///
+    /// `schema:numberOfAvailableAccommodationUnits` (`NUMBER_OF_AVAILABLE_ACCOMMODATION_UNITS`)
+    pub static ref NUMBER_OF_AVAILABLE_ACCOMMODATION_UNITS: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "numberOfAvailableAccommodationUnits");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// numberOfAxles
+    ///
+    /// The number of axles.
+    ///
+    /// `schema:numberOfAxles` (`NUMBER_OF_AXLES`)
+    pub static ref NUMBER_OF_AXLES: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "numberOfAxles");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// numberOfBathroomsTotal
+    ///
+    /// The total integer number of bathrooms in some Accommodation, following real estate conventions as documented in RESO: "The simple sum of the number of bathrooms. For example for a property with two Full Bathrooms and one Half Bathroom, the Bathrooms Total Integer will be 3.".
+    ///
+    /// `schema:numberOfBathroomsTotal` (`NUMBER_OF_BATHROOMS_TOTAL`)
+    pub static ref NUMBER_OF_BATHROOMS_TOTAL: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "numberOfBathroomsTotal");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// numberOfBedrooms
+    ///
+    /// The total integer number of bedrooms in a some Accommodation, ApartmentComplex or FloorPlan.
+    ///
+    /// `schema:numberOfBedrooms` (`NUMBER_OF_BEDROOMS`)
+    pub static ref NUMBER_OF_BEDROOMS: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "numberOfBedrooms");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// numberOfBeds
+    ///
+    /// The quantity of the given bed type available in the HotelRoom, Suite, House, or Apartment.
+    ///
+    /// `schema:numberOfBeds` (`NUMBER_OF_BEDS`)
+    pub static ref NUMBER_OF_BEDS: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "numberOfBeds");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// numberOfCredits
+    ///
+    /// The number of credits or units awarded by a Course or required to complete an EducationalOccupationalProgram.
+    ///
+    /// `schema:numberOfCredits` (`NUMBER_OF_CREDITS`)
+    pub static ref NUMBER_OF_CREDITS: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "numberOfCredits");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// numberOfDoors
+    ///
+    /// The number of doors.
+    ///
+    /// `schema:numberOfDoors` (`NUMBER_OF_DOORS`)
+    pub static ref NUMBER_OF_DOORS: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "numberOfDoors");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// numberOfEmployees
+    ///
+    /// The number of employees in an organization, e.g. business.
+    ///
+    /// `schema:numberOfEmployees` (`NUMBER_OF_EMPLOYEES`)
+    pub static ref NUMBER_OF_EMPLOYEES: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "numberOfEmployees");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// numberOfEpisodes
+    ///
+    /// The number of episodes in this season or series.
+    ///
+    /// `schema:numberOfEpisodes` (`NUMBER_OF_EPISODES`)
+    pub static ref NUMBER_OF_EPISODES: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "numberOfEpisodes");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// numberOfForwardGears
+    ///
+    /// The total number of forward gears available for the transmission system of the vehicle.
+    ///
+    /// `schema:numberOfForwardGears` (`NUMBER_OF_FORWARD_GEARS`)
+    pub static ref NUMBER_OF_FORWARD_GEARS: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "numberOfForwardGears");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// numberOfFullBathrooms
+    ///
+    /// Number of full bathrooms - The total number of full and ¾ bathrooms in an Accommodation. This corresponds to the BathroomsFull field in RESO.
+    ///
+    /// `schema:numberOfFullBathrooms` (`NUMBER_OF_FULL_BATHROOMS`)
+    pub static ref NUMBER_OF_FULL_BATHROOMS: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "numberOfFullBathrooms");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// numberOfItems
+    ///
+    /// The number of items in an ItemList. Note that some descriptions might not fully describe all items in a list (e.g., multi-page pagination); in such cases, the numberOfItems would be for the entire list.
+    ///
+    /// `schema:numberOfItems` (`NUMBER_OF_ITEMS`)
+    pub static ref NUMBER_OF_ITEMS: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "numberOfItems");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// numberOfLoanPayments
+    ///
+    /// The number of payments contractually required at origination to repay the loan. For monthly paying loans this is the number of months from the contractual first payment date to the maturity date.
+    ///
+    /// `schema:numberOfLoanPayments` (`NUMBER_OF_LOAN_PAYMENTS`)
+    pub static ref NUMBER_OF_LOAN_PAYMENTS: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "numberOfLoanPayments");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// numberOfPages
+    ///
+    /// The number of pages in the book.
+    ///
+    /// `schema:numberOfPages` (`NUMBER_OF_PAGES`)
+    pub static ref NUMBER_OF_PAGES: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "numberOfPages");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// numberOfPartialBathrooms
+    ///
+    /// Number of partial bathrooms - The total number of half and ¼ bathrooms in an Accommodation. This corresponds to the BathroomsPartial field in RESO.
+    ///
+    /// `schema:numberOfPartialBathrooms` (`NUMBER_OF_PARTIAL_BATHROOMS`)
+    pub static ref NUMBER_OF_PARTIAL_BATHROOMS: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "numberOfPartialBathrooms");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// numberOfPlayers
+    ///
+    /// Indicate how many people can play this game (minimum, maximum, or range).
+    ///
+    /// `schema:numberOfPlayers` (`NUMBER_OF_PLAYERS`)
+    pub static ref NUMBER_OF_PLAYERS: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "numberOfPlayers");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// numberOfPreviousOwners
+    ///
+    /// The number of owners of the vehicle, including the current one.
+    ///
+    /// `schema:numberOfPreviousOwners` (`NUMBER_OF_PREVIOUS_OWNERS`)
+    pub static ref NUMBER_OF_PREVIOUS_OWNERS: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "numberOfPreviousOwners");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// numberOfRooms
+    ///
+    /// The number of rooms (excluding bathrooms and closets) of the accommodation or lodging business. Typical unit code(s): ROM for room or C62 for no unit. The type of room can be put in the unitText property of the QuantitativeValue.
+    ///
+    /// `schema:numberOfRooms` (`NUMBER_OF_ROOMS`)
+    pub static ref NUMBER_OF_ROOMS: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "numberOfRooms");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// numberOfSeasons
+    ///
+    /// The number of seasons in this series.
+    ///
+    /// `schema:numberOfSeasons` (`NUMBER_OF_SEASONS`)
+    pub static ref NUMBER_OF_SEASONS: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "numberOfSeasons");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// numberedPosition
+    ///
+    /// A number associated with a role in an organization, for example, the number on an athlete's jersey.
+    ///
+    /// `schema:numberedPosition` (`NUMBERED_POSITION`)
+    pub static ref NUMBERED_POSITION: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "numberedPosition");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// nutrition
+    ///
+    /// Nutrition information about the recipe or menu item.
+    ///
+    /// `schema:nutrition` (`NUTRITION`)
+    pub static ref NUTRITION: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "nutrition");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// object
+    ///
+    /// The object upon which the action is carried out, whose state is kept intact or changed. Also known as the semantic roles patient, affected or undergoer (which change their state) or theme (which doesn't). E.g. John read a book.
+    ///
+    /// `schema:object` (`OBJECT`)
+    pub static ref OBJECT: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "object");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// observationAbout
+    ///
+    /// The observationAbout property identifies an entity, often a Place, associated with an Observation.
+    ///
+    /// `schema:observationAbout` (`OBSERVATION_ABOUT`)
+    pub static ref OBSERVATION_ABOUT: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "observationAbout");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// observationDate
+    ///
+    /// The observationDate of an Observation.
+    ///
+    /// `schema:observationDate` (`OBSERVATION_DATE`)
+    pub static ref OBSERVATION_DATE: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "observationDate");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// observationPeriod
+    ///
+    /// The length of time an Observation took place over. The format follows ISO 8601 durations. For example, P1Y is Period 1 Year, P3M is Period 3 Months, P3h is Period 3 hours.
+    ///
+    /// `schema:observationPeriod` (`OBSERVATION_PERIOD`)
+    pub static ref OBSERVATION_PERIOD: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "observationPeriod");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// observedNode
+    ///
+    /// The observedNode of an Observation, often a StatisticalPopulation.
+    ///
+    /// `schema:observedNode` (`OBSERVED_NODE`)
+    pub static ref OBSERVED_NODE: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "observedNode");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// occupancy
+    ///
+    /// The allowed total occupancy for the accommodation in persons (including infants etc). For individual accommodations, this is not necessarily the legal maximum but defines the permitted usage as per the contractual agreement.
+    ///
+    /// `schema:occupancy` (`OCCUPANCY`)
+    pub static ref OCCUPANCY: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "occupancy");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// occupationLocation
+    ///
+    /// The region/country for which this occupational description is appropriate. Note that educational requirements and qualifications can vary between jurisdictions.
+    ///
+    /// `schema:occupationLocation` (`OCCUPATION_LOCATION`)
+    pub static ref OCCUPATION_LOCATION: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "occupationLocation");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// occupationalCategory
+    ///
+    /// A category describing the job, preferably using a term from a taxonomy such as BLS O*NET-SOC, ISCO-08 or similar, with the property repeated for each applicable value.
+    ///
+    /// `schema:occupationalCategory` (`OCCUPATIONAL_CATEGORY`)
+    pub static ref OCCUPATIONAL_CATEGORY: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "occupationalCategory");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// occupationalCredentialAwarded
+    ///
+    /// A description of the qualification, award, certificate, diploma or other occupational credential awarded as a consequence of successful completion of this course or program.
+    ///
+    /// `schema:occupationalCredentialAwarded` (`OCCUPATIONAL_CREDENTIAL_AWARDED`)
+    pub static ref OCCUPATIONAL_CREDENTIAL_AWARDED: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "occupationalCredentialAwarded");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// offerCount
+    ///
+    /// The number of offers for the product.
+    ///
+    /// `schema:offerCount` (`OFFER_COUNT`)
+    pub static ref OFFER_COUNT: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "offerCount");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// offeredBy
+    ///
+    /// A pointer to the organization or person making the offer.
+    ///
+    /// `schema:offeredBy` (`OFFERED_BY`)
+    pub static ref OFFERED_BY: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "offeredBy");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// offers
+    ///
+    /// An offer to provide this item, for example, an offer to sell a product, rent the DVD of a movie, perform a service, or give away tickets to an event. Use businessFunction to indicate the kind of transaction offered, i.e. sell, lease, etc.
+    ///
+    /// `schema:offers` (`OFFERS`)
+    pub static ref OFFERS: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "offers");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// offersPrescriptionByMail
+    ///
+    /// Whether prescriptions can be delivered by mail.
+    ///
+    /// `schema:offersPrescriptionByMail` (`OFFERS_PRESCRIPTION_BY_MAIL`)
+    pub static ref OFFERS_PRESCRIPTION_BY_MAIL: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "offersPrescriptionByMail");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// openingHours
+    ///
+    /// The general opening hours for a business. Opening hours can be specified as a weekly time range, starting with days, then times per day. Multiple days can be listed with commas ',' separating each day. Day or time ranges are specified using a hyphen '-'.
+    ///
+    /// `schema:openingHours` (`OPENING_HOURS`)
+    pub static ref OPENING_HOURS: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "openingHours");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// openingHoursSpecification
+    ///
+    /// The opening hours of a certain place.
+    ///
+    /// `schema:openingHoursSpecification` (`OPENING_HOURS_SPECIFICATION_PROPERTY`)
+    pub static ref OPENING_HOURS_SPECIFICATION_PROPERTY: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "openingHoursSpecification");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// opens
+    ///
+    /// The opening hour of the place or service on the given day(s) of the week.
+    ///
+    /// `schema:opens` (`OPENS`)
+    pub static ref OPENS: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "opens");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// operatingSystem
+    ///
+    /// Operating systems supported (Windows 7, OS X 10.6, Android 1.6).
+    ///
+    /// `schema:operatingSystem` (`OPERATING_SYSTEM`)
+    pub static ref OPERATING_SYSTEM: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "operatingSystem");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// opponent
+    ///
+    /// A sub property of participant. The opponent on this action.
+    ///
+    /// `schema:opponent` (`OPPONENT`)
+    pub static ref OPPONENT: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "opponent");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// option
+    ///
+    /// A sub property of object. The options subject to this action.
+    ///
+    /// `schema:option` (`OPTION`)
+    pub static ref OPTION: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "option");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// orderDate
+    ///
+    /// Date order was placed.
+    ///
+    /// `schema:orderDate` (`ORDER_DATE`)
+    pub static ref ORDER_DATE: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "orderDate");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// orderDelivery
+    ///
+    /// The delivery of the parcel related to this order or order item.
+    ///
+    /// `schema:orderDelivery` (`ORDER_DELIVERY`)
+    pub static ref ORDER_DELIVERY: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "orderDelivery");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// orderItemNumber
+    ///
+    /// The identifier of the order item.
+    ///
+    /// `schema:orderItemNumber` (`ORDER_ITEM_NUMBER`)
+    pub static ref ORDER_ITEM_NUMBER: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "orderItemNumber");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// orderItemStatus
+    ///
+    /// The current status of the order item.
+    ///
+    /// `schema:orderItemStatus` (`ORDER_ITEM_STATUS`)
+    pub static ref ORDER_ITEM_STATUS: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "orderItemStatus");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// orderNumber
+    ///
+    /// The identifier of the transaction.
+    ///
+    /// `schema:orderNumber` (`ORDER_NUMBER`)
+    pub static ref ORDER_NUMBER: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "orderNumber");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// orderQuantity
+    ///
+    /// The number of the item ordered. If the property is not set, assume the quantity is one.
+    ///
+    /// `schema:orderQuantity` (`ORDER_QUANTITY`)
+    pub static ref ORDER_QUANTITY: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "orderQuantity");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// orderStatus
+    ///
+    /// The current status of the order.
+    ///
+    /// `schema:orderStatus` (`ORDER_STATUS_PROPERTY`)
+    pub static ref ORDER_STATUS_PROPERTY: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "orderStatus");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// orderedItem
+    ///
+    /// The item ordered.
+    ///
+    /// `schema:orderedItem` (`ORDERED_ITEM`)
+    pub static ref ORDERED_ITEM: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "orderedItem");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// organizer
+    ///
+    /// An organizer of an Event.
+    ///
+    /// `schema:organizer` (`ORGANIZER`)
+    pub static ref ORGANIZER: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "organizer");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// originAddress
+    ///
+    /// Shipper's address.
+    ///
+    /// `schema:originAddress` (`ORIGIN_ADDRESS`)
+    pub static ref ORIGIN_ADDRESS: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "originAddress");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// originalMediaContextDescription
+    ///
+    /// Describes, in a MediaReview when dealing with DecontextualizedContent, background information that can contribute to better interpretation of the MediaObject.
+    ///
+    /// `schema:originalMediaContextDescription` (`ORIGINAL_MEDIA_CONTEXT_DESCRIPTION`)
+    pub static ref ORIGINAL_MEDIA_CONTEXT_DESCRIPTION: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "originalMediaContextDescription");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// originalMediaLink
+    ///
+    /// Link to the page containing an original version of the content, or directly to an online copy of the original MediaObject content, e.g. video file.
+    ///
+    /// `schema:originalMediaLink` (`ORIGINAL_MEDIA_LINK`)
+    pub static ref ORIGINAL_MEDIA_LINK: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "originalMediaLink");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// originatesFrom
+    ///
+    /// The vasculature the lymphatic structure originates, or afferents, from.
+    ///
+    /// `schema:originatesFrom` (`ORIGINATES_FROM`)
+    pub static ref ORIGINATES_FROM: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "originatesFrom");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// overdosage
+    ///
+    /// Any information related to overdose on a drug, including signs or symptoms, treatments, contact information for emergency response.
+    ///
+    /// `schema:overdosage` (`OVERDOSAGE`)
+    pub static ref OVERDOSAGE: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "overdosage");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// ownedFrom
+    ///
+    /// The date and time of obtaining the product.
+    ///
+    /// `schema:ownedFrom` (`OWNED_FROM`)
+    pub static ref OWNED_FROM: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "ownedFrom");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// ownedThrough
+    ///
+    /// The date and time of giving up ownership on the product.
+    ///
+    /// `schema:ownedThrough` (`OWNED_THROUGH`)
+    pub static ref OWNED_THROUGH: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "ownedThrough");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// ownershipFundingInfo
+    ///
+    /// For an Organization (often but not necessarily a NewsMediaOrganization), a description of organizational ownership structure; funding and grants. In a news/media setting, this is with particular reference to editorial independence.
+    ///
+    /// `schema:ownershipFundingInfo` (`OWNERSHIP_FUNDING_INFO`)
+    pub static ref OWNERSHIP_FUNDING_INFO: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "ownershipFundingInfo");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// owns
+    ///
+    /// Products owned by the organization or person.
+    ///
+    /// `schema:owns` (`OWNS`)
+    pub static ref OWNS: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "owns");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// pageEnd
+    ///
+    /// The page on which the work ends; for example "138" or "xvi".
+    ///
+    /// `schema:pageEnd` (`PAGE_END`)
+    pub static ref PAGE_END: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "pageEnd");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// pageStart
+    ///
+    /// The page on which the work starts; for example "135" or "xiii".
+    ///
+    /// `schema:pageStart` (`PAGE_START`)
+    pub static ref PAGE_START: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "pageStart");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// pagination
+    ///
+    /// Any description of pages that is not separated into pageStart and pageEnd; for example, "1-6, 9, 55" or "10-12, 46-49".
+    ///
+    /// `schema:pagination` (`PAGINATION`)
+    pub static ref PAGINATION: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "pagination");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// parent
+    ///
+    /// A parent of this person.
+    ///
+    /// `schema:parent` (`PARENT`)
+    pub static ref PARENT: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "parent");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// parentItem
+    ///
+    /// The parent of a question, answer or item in general. Typically used for Q/A discussion threads e.g. a chain of comments with the first comment being an Article or other CreativeWork.
+    ///
+    /// `schema:parentItem` (`PARENT_ITEM`)
+    pub static ref PARENT_ITEM: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "parentItem");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// parentOrganization
+    ///
+    /// The larger organization that this organization is a subOrganization of, if any.
+    ///
+    /// `schema:parentOrganization` (`PARENT_ORGANIZATION`)
+    pub static ref PARENT_ORGANIZATION: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "parentOrganization");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// parentService
+    ///
+    /// A broadcast service to which the broadcast service may belong to such as regional variations of a national channel.
+    ///
+    /// `schema:parentService` (`PARENT_SERVICE`)
+    pub static ref PARENT_SERVICE: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "parentService");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// parentTaxon
+    ///
+    /// Closest parent taxon of the taxon in question.
+    ///
+    /// `schema:parentTaxon` (`PARENT_TAXON`)
+    pub static ref PARENT_TAXON: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "parentTaxon");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// parents
+    ///
+    /// A parents of the person.
+    ///
+    /// `schema:parents` (`PARENTS`)
+    pub static ref PARENTS: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "parents");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// partOfEpisode
+    ///
+    /// The episode to which this clip belongs.
+    ///
+    /// `schema:partOfEpisode` (`PART_OF_EPISODE`)
+    pub static ref PART_OF_EPISODE: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "partOfEpisode");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// partOfInvoice
+    ///
+    /// The order is being paid as part of the referenced Invoice.
+    ///
+    /// `schema:partOfInvoice` (`PART_OF_INVOICE`)
+    pub static ref PART_OF_INVOICE: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "partOfInvoice");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// partOfOrder
+    ///
+    /// The overall order the items in this delivery were included in.
+    ///
+    /// `schema:partOfOrder` (`PART_OF_ORDER`)
+    pub static ref PART_OF_ORDER: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "partOfOrder");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// partOfSeason
+    ///
+    /// The season to which this episode belongs.
+    ///
+    /// `schema:partOfSeason` (`PART_OF_SEASON`)
+    pub static ref PART_OF_SEASON: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "partOfSeason");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// partOfSeries
+    ///
+    /// The series to which this episode or season belongs.
+    ///
+    /// `schema:partOfSeries` (`PART_OF_SERIES`)
+    pub static ref PART_OF_SERIES: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "partOfSeries");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// partOfSystem
+    ///
+    /// The anatomical or organ system that this structure is part of.
+    ///
+    /// `schema:partOfSystem` (`PART_OF_SYSTEM`)
+    pub static ref PART_OF_SYSTEM: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "partOfSystem");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// partOfTVSeries
+    ///
+    /// The TV series to which this episode or season belongs.
+    ///
+    /// `schema:partOfTVSeries` (`PART_OF_TV_SERIES`)
+    pub static ref PART_OF_TV_SERIES: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "partOfTVSeries");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// partOfTrip
+    ///
+    /// Identifies that this Trip is a subTrip of another Trip. For example Day 1, Day 2, etc. of a multi-day trip.
+    ///
+    /// `schema:partOfTrip` (`PART_OF_TRIP`)
+    pub static ref PART_OF_TRIP: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "partOfTrip");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// participant
+    ///
+    /// Other co-agents that participated in the action indirectly. E.g. John wrote a book with Steve.
+    ///
+    /// `schema:participant` (`PARTICIPANT`)
+    pub static ref PARTICIPANT: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "participant");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// partySize
+    ///
+    /// Number of people the reservation should accommodate.
+    ///
+    /// `schema:partySize` (`PARTY_SIZE`)
+    pub static ref PARTY_SIZE: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "partySize");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// passengerPriorityStatus
+    ///
+    /// The priority status assigned to a passenger for security or boarding (e.g. FastTrack or Priority).
+    ///
+    /// `schema:passengerPriorityStatus` (`PASSENGER_PRIORITY_STATUS`)
+    pub static ref PASSENGER_PRIORITY_STATUS: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "passengerPriorityStatus");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// passengerSequenceNumber
+    ///
+    /// The passenger's sequence number as assigned by the airline.
+    ///
+    /// `schema:passengerSequenceNumber` (`PASSENGER_SEQUENCE_NUMBER`)
+    pub static ref PASSENGER_SEQUENCE_NUMBER: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "passengerSequenceNumber");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// pathophysiology
+    ///
+    /// Changes in the normal mechanical, physical, and biochemical functions that are associated with this activity or condition.
+    ///
+    /// `schema:pathophysiology` (`PATHOPHYSIOLOGY`)
+    pub static ref PATHOPHYSIOLOGY: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "pathophysiology");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// pattern
+    ///
+    /// A pattern that something has, for example 'polka dot', 'striped', 'Canadian flag'. Values are typically expressed as text, although links to controlled value schemes are also supported.
+    ///
+    /// `schema:pattern` (`PATTERN`)
+    pub static ref PATTERN: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "pattern");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// payload
+    ///
+    /// The permitted weight of passengers and cargo, EXCLUDING the weight of the empty vehicle.
+    ///
+    /// `schema:payload` (`PAYLOAD`)
+    pub static ref PAYLOAD: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "payload");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// paymentAccepted
+    ///
+    /// Cash, Credit Card, Cryptocurrency, Local Exchange Tradings System, etc.
+    ///
+    /// `schema:paymentAccepted` (`PAYMENT_ACCEPTED`)
+    pub static ref PAYMENT_ACCEPTED: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "paymentAccepted");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// paymentDue
+    ///
+    /// The date that payment is due.
+    ///
+    /// `schema:paymentDue` (`PAYMENT_DUE`)
+    pub static ref PAYMENT_DUE: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "paymentDue");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// paymentDueDate
+    ///
+    /// The date that payment is due.
+    ///
+    /// `schema:paymentDueDate` (`PAYMENT_DUE_DATE`)
+    pub static ref PAYMENT_DUE_DATE: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "paymentDueDate");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// paymentMethod
+    ///
+    /// The name of the credit card or other method of payment for the order.
+    ///
+    /// `schema:paymentMethod` (`PAYMENT_METHOD_PROPERTY`)
+    pub static ref PAYMENT_METHOD_PROPERTY: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "paymentMethod");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// paymentMethodId
+    ///
+    /// An identifier for the method of payment used (e.g. the last 4 digits of the credit card).
+    ///
+    /// `schema:paymentMethodId` (`PAYMENT_METHOD_ID`)
+    pub static ref PAYMENT_METHOD_ID: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "paymentMethodId");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// paymentStatus
+    ///
+    /// The status of payment; whether the invoice has been paid or not.
+    ///
+    /// `schema:paymentStatus` (`PAYMENT_STATUS`)
+    pub static ref PAYMENT_STATUS: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "paymentStatus");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// paymentUrl
+    ///
+    /// The URL for sending a payment.
+    ///
+    /// `schema:paymentUrl` (`PAYMENT_URL`)
+    pub static ref PAYMENT_URL: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "paymentUrl");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// penciler
+    ///
+    /// The individual who draws the primary narrative artwork.
+    ///
+    /// `schema:penciler` (`PENCILER`)
+    pub static ref PENCILER: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "penciler");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// percentile10
+    ///
+    /// The 10th percentile value.
+    ///
+    /// `schema:percentile10` (`PERCENTILE10`)
+    pub static ref PERCENTILE10: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "percentile10");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// percentile25
+    ///
+    /// The 25th percentile value.
+    ///
+    /// `schema:percentile25` (`PERCENTILE25`)
+    pub static ref PERCENTILE25: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "percentile25");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// percentile75
+    ///
+    /// The 75th percentile value.
+    ///
+    /// `schema:percentile75` (`PERCENTILE75`)
+    pub static ref PERCENTILE75: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "percentile75");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// percentile90
+    ///
+    /// The 90th percentile value.
+    ///
+    /// `schema:percentile90` (`PERCENTILE90`)
+    pub static ref PERCENTILE90: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "percentile90");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// performTime
+    ///
+    /// The length of time it takes to perform instructions or a direction (not including time to prepare the supplies), in ISO 8601 duration format.
+    ///
+    /// `schema:performTime` (`PERFORM_TIME`)
+    pub static ref PERFORM_TIME: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "performTime");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// performer
+    ///
+    /// A performer at the event—for example, a presenter, musician, musical group or actor.
+    ///
+    /// `schema:performer` (`PERFORMER`)
+    pub static ref PERFORMER: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "performer");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// performerIn
+    ///
+    /// Event that this person is a performer or participant in.
+    ///
+    /// `schema:performerIn` (`PERFORMER_IN`)
+    pub static ref PERFORMER_IN: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "performerIn");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// performers
+    ///
+    /// The main performer or performers of the event—for example, a presenter, musician, or actor.
+    ///
+    /// `schema:performers` (`PERFORMERS`)
+    pub static ref PERFORMERS: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "performers");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// permissionType
+    ///
+    /// The type of permission granted the person, organization, or audience.
+    ///
+    /// `schema:permissionType` (`PERMISSION_TYPE`)
+    pub static ref PERMISSION_TYPE: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "permissionType");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// permissions
+    ///
+    /// Permission(s) required to run the app (for example, a mobile app may require full internet access or may run only on wifi).
+    ///
+    /// `schema:permissions` (`PERMISSIONS`)
+    pub static ref PERMISSIONS: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "permissions");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// permitAudience
+    ///
+    /// The target audience for this permit.
+    ///
+    /// `schema:permitAudience` (`PERMIT_AUDIENCE`)
+    pub static ref PERMIT_AUDIENCE: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "permitAudience");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// permittedUsage
+    ///
+    /// Indications regarding the permitted usage of the accommodation.
+    ///
+    /// `schema:permittedUsage` (`PERMITTED_USAGE`)
+    pub static ref PERMITTED_USAGE: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "permittedUsage");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// petsAllowed
+    ///
+    /// Indicates whether pets are allowed to enter the accommodation or lodging business. More detailed information can be put in a text value.
+    ///
+    /// `schema:petsAllowed` (`PETS_ALLOWED`)
+    pub static ref PETS_ALLOWED: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "petsAllowed");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// phoneticText
+    ///
+    /// Representation of a text textValue using the specified speechToTextMarkup. For example the city name of Houston in IPA: /ˈhjuːstən/.
+    ///
+    /// `schema:phoneticText` (`PHONETIC_TEXT`)
+    pub static ref PHONETIC_TEXT: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "phoneticText");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// photo
+    ///
+    /// A photograph of this place.
+    ///
+    /// `schema:photo` (`PHOTO`)
+    pub static ref PHOTO: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "photo");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// photos
+    ///
+    /// Photographs of this place.
+    ///
+    /// `schema:photos` (`PHOTOS`)
+    pub static ref PHOTOS: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "photos");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// physicalRequirement
+    ///
+    /// A description of the types of physical activity associated with the job. Defined terms such as those in O*net may be used, but note that there is no way to specify the level of ability as well as its nature when using a defined term.
+    ///
+    /// `schema:physicalRequirement` (`PHYSICAL_REQUIREMENT`)
+    pub static ref PHYSICAL_REQUIREMENT: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "physicalRequirement");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// physiologicalBenefits
+    ///
+    /// Specific physiologic benefits associated to the plan.
+    ///
+    /// `schema:physiologicalBenefits` (`PHYSIOLOGICAL_BENEFITS`)
+    pub static ref PHYSIOLOGICAL_BENEFITS: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "physiologicalBenefits");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// pickupLocation
+    ///
+    /// Where a taxi will pick up a passenger or a rental car can be picked up.
+    ///
+    /// `schema:pickupLocation` (`PICKUP_LOCATION`)
+    pub static ref PICKUP_LOCATION: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "pickupLocation");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// pickupTime
+    ///
+    /// When a taxi will pick up a passenger or a rental car can be picked up.
+    ///
+    /// `schema:pickupTime` (`PICKUP_TIME`)
+    pub static ref PICKUP_TIME: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "pickupTime");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// playMode
+    ///
+    /// Indicates whether this game is multi-player, co-op or single-player. The game can be marked as multi-player, co-op and single-player at the same time.
+    ///
+    /// `schema:playMode` (`PLAY_MODE`)
+    pub static ref PLAY_MODE: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "playMode");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// playerType
+    ///
+    /// Player type required—for example, Flash or Silverlight.
+    ///
+    /// `schema:playerType` (`PLAYER_TYPE`)
+    pub static ref PLAYER_TYPE: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "playerType");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// playersOnline
+    ///
+    /// Number of players on the server.
+    ///
+    /// `schema:playersOnline` (`PLAYERS_ONLINE`)
+    pub static ref PLAYERS_ONLINE: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "playersOnline");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// polygon
+    ///
+    /// A polygon is the area enclosed by a point-to-point path for which the starting and ending points are the same. A polygon is expressed as a series of four or more space delimited points where the first and final points are identical.
+    ///
+    /// `schema:polygon` (`POLYGON`)
+    pub static ref POLYGON: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "polygon");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// populationType
+    ///
+    /// Indicates the populationType common to all members of a StatisticalPopulation or all cases within the scope of a StatisticalVariable.
+    ///
+    /// `schema:populationType` (`POPULATION_TYPE`)
+    pub static ref POPULATION_TYPE: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "populationType");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// position
+    ///
+    /// The position of an item in a series or sequence of items.
+    ///
+    /// `schema:position` (`POSITION`)
+    pub static ref POSITION: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "position");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// positiveNotes
+    ///
+    /// Provides positive considerations regarding something, for example product highlights or (alongside negativeNotes) pro/con lists for reviews.
+    ///
+    /// `schema:positiveNotes` (`POSITIVE_NOTES`)
+    pub static ref POSITIVE_NOTES: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "positiveNotes");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// possibleComplication
+    ///
+    /// A possible unexpected and unfavorable evolution of a medical condition. Complications may include worsening of the signs or symptoms of the disease, extension of the condition to other organ systems, etc.
+    ///
+    /// `schema:possibleComplication` (`POSSIBLE_COMPLICATION`)
+    pub static ref POSSIBLE_COMPLICATION: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "possibleComplication");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// possibleTreatment
+    ///
+    /// A possible treatment to address this condition, sign or symptom.
+    ///
+    /// `schema:possibleTreatment` (`POSSIBLE_TREATMENT`)
+    pub static ref POSSIBLE_TREATMENT: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "possibleTreatment");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// postOfficeBoxNumber
+    ///
+    /// The post office box number for PO box addresses.
+    ///
+    /// `schema:postOfficeBoxNumber` (`POST_OFFICE_BOX_NUMBER`)
+    pub static ref POST_OFFICE_BOX_NUMBER: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "postOfficeBoxNumber");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// postOp
+    ///
+    /// A description of the postoperative procedures, care, and/or followups for this device.
+    ///
+    /// `schema:postOp` (`POST_OP`)
+    pub static ref POST_OP: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "postOp");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// postalCode
+    ///
+    /// The postal code. For example, 94043.
+    ///
+    /// `schema:postalCode` (`POSTAL_CODE`)
+    pub static ref POSTAL_CODE: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "postalCode");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// postalCodeBegin
+    ///
+    /// First postal code in a range (included).
+    ///
+    /// `schema:postalCodeBegin` (`POSTAL_CODE_BEGIN`)
+    pub static ref POSTAL_CODE_BEGIN: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "postalCodeBegin");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// postalCodeEnd
+    ///
+    /// Last postal code in the range (included). Needs to be after postalCodeBegin.
+    ///
+    /// `schema:postalCodeEnd` (`POSTAL_CODE_END`)
+    pub static ref POSTAL_CODE_END: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "postalCodeEnd");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// postalCodePrefix
+    ///
+    /// A defined range of postal codes indicated by a common textual prefix. Used for non-numeric systems such as UK.
+    ///
+    /// `schema:postalCodePrefix` (`POSTAL_CODE_PREFIX`)
+    pub static ref POSTAL_CODE_PREFIX: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "postalCodePrefix");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// postalCodeRange
+    ///
+    /// A defined range of postal codes.
+    ///
+    /// `schema:postalCodeRange` (`POSTAL_CODE_RANGE`)
+    pub static ref POSTAL_CODE_RANGE: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "postalCodeRange");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// potentialAction
+    ///
+    /// Indicates a potential Action, which describes an idealized action in which this thing would play an 'object' role.
+    ///
+    /// `schema:potentialAction` (`POTENTIAL_ACTION`)
+    pub static ref POTENTIAL_ACTION: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "potentialAction");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// potentialUse
+    ///
+    /// Intended use of the BioChemEntity by humans.
+    ///
+    /// `schema:potentialUse` (`POTENTIAL_USE`)
+    pub static ref POTENTIAL_USE: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "potentialUse");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// preOp
+    ///
+    /// A description of the workup, testing, and other preparations required before implanting this device.
+    ///
+    /// `schema:preOp` (`PRE_OP`)
+    pub static ref PRE_OP: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "preOp");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// predecessorOf
+    ///
+    /// A pointer from a previous, often discontinued variant of the product to its newer variant.
+    ///
+    /// `schema:predecessorOf` (`PREDECESSOR_OF`)
+    pub static ref PREDECESSOR_OF: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "predecessorOf");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// pregnancyCategory
+    ///
+    /// Pregnancy category of this drug.
+    ///
+    /// `schema:pregnancyCategory` (`PREGNANCY_CATEGORY`)
+    pub static ref PREGNANCY_CATEGORY: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "pregnancyCategory");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// pregnancyWarning
+    ///
+    /// Any precaution, guidance, contraindication, etc. related to this drug's use during pregnancy.
+    ///
+    /// `schema:pregnancyWarning` (`PREGNANCY_WARNING`)
+    pub static ref PREGNANCY_WARNING: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "pregnancyWarning");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// prepTime
+    ///
+    /// The length of time it takes to prepare the items to be used in instructions or a direction, in ISO 8601 duration format.
+    ///
+    /// `schema:prepTime` (`PREP_TIME`)
+    pub static ref PREP_TIME: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "prepTime");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// preparation
+    ///
+    /// Typical preparation that a patient must undergo before having the procedure performed.
+    ///
+    /// `schema:preparation` (`PREPARATION`)
+    pub static ref PREPARATION: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "preparation");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// prescribingInfo
+    ///
+    /// Link to prescribing information for the drug.
+    ///
+    /// `schema:prescribingInfo` (`PRESCRIBING_INFO`)
+    pub static ref PRESCRIBING_INFO: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "prescribingInfo");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// prescriptionStatus
+    ///
+    /// Indicates the status of drug prescription, e.g. local catalogs classifications or whether the drug is available by prescription or over-the-counter, etc.
+    ///
+    /// `schema:prescriptionStatus` (`PRESCRIPTION_STATUS`)
+    pub static ref PRESCRIPTION_STATUS: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "prescriptionStatus");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// previousItem
+    ///
+    /// A link to the ListItem that precedes the current one.
+    ///
+    /// `schema:previousItem` (`PREVIOUS_ITEM`)
+    pub static ref PREVIOUS_ITEM: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "previousItem");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// previousStartDate
+    ///
+    /// Used in conjunction with eventStatus for rescheduled or cancelled events. This property contains the previously scheduled start date. For rescheduled events, the startDate property should be used for the newly scheduled start date.
+    ///
+    /// `schema:previousStartDate` (`PREVIOUS_START_DATE`)
+    pub static ref PREVIOUS_START_DATE: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "previousStartDate");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// price
+    ///
+    /// The offer price of a product, or of a price component when attached to PriceSpecification and its subtypes.
+    ///
+    /// `schema:price` (`PRICE`)
+    pub static ref PRICE: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "price");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// priceComponent
+    ///
+    /// This property links to all UnitPriceSpecification nodes that apply in parallel for the CompoundPriceSpecification node.
+    ///
+    /// `schema:priceComponent` (`PRICE_COMPONENT`)
+    pub static ref PRICE_COMPONENT: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "priceComponent");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// priceComponentType
+    ///
+    /// Identifies a price component (for example, a line item on an invoice), part of the total price for an offer.
+    ///
+    /// `schema:priceComponentType` (`PRICE_COMPONENT_TYPE`)
+    pub static ref PRICE_COMPONENT_TYPE: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "priceComponentType");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// priceCurrency
+    ///
+    /// The currency of the price, or a price component when attached to PriceSpecification and its subtypes. Use standard formats: ISO 4217 currency format, e.g. "USD".
+    ///
+    /// `schema:priceCurrency` (`PRICE_CURRENCY`)
+    pub static ref PRICE_CURRENCY: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "priceCurrency");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// priceRange
+    ///
+    /// The price range of the business, for example $$$.
+    ///
+    /// `schema:priceRange` (`PRICE_RANGE`)
+    pub static ref PRICE_RANGE: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "priceRange");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// priceSpecification
+    ///
+    /// One or more detailed price specifications, indicating the unit price and delivery or payment charges.
+    ///
+    /// `schema:priceSpecification` (`PRICE_SPECIFICATION_PROPERTY`)
+    pub static ref PRICE_SPECIFICATION_PROPERTY: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "priceSpecification");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// priceType
+    ///
+    /// Defines the type of a price specified for an offered product, for example a list price, a (temporary) sale price or a manufacturer suggested retail price. If multiple prices are specified for an offer the priceType property can be used to identify the type of each such specified price.
+    ///
+    /// `schema:priceType` (`PRICE_TYPE`)
+    pub static ref PRICE_TYPE: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "priceType");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// priceValidUntil
+    ///
+    /// The date after which the price is no longer available.
+    ///
+    /// `schema:priceValidUntil` (`PRICE_VALID_UNTIL`)
+    pub static ref PRICE_VALID_UNTIL: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "priceValidUntil");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// primaryImageOfPage
+    ///
+    /// Indicates the main image on the page.
+    ///
+    /// `schema:primaryImageOfPage` (`PRIMARY_IMAGE_OF_PAGE`)
+    pub static ref PRIMARY_IMAGE_OF_PAGE: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "primaryImageOfPage");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// primaryPrevention
+    ///
+    /// A preventative therapy used to prevent an initial occurrence of the medical condition, such as vaccination.
+    ///
+    /// `schema:primaryPrevention` (`PRIMARY_PREVENTION`)
+    pub static ref PRIMARY_PREVENTION: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "primaryPrevention");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// printColumn
+    ///
+    /// The number of the column in which the NewsArticle appears in the print edition.
+    ///
+    /// `schema:printColumn` (`PRINT_COLUMN`)
+    pub static ref PRINT_COLUMN: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "printColumn");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// printEdition
+    ///
+    /// The edition of the print product in which the NewsArticle appears.
+    ///
+    /// `schema:printEdition` (`PRINT_EDITION`)
+    pub static ref PRINT_EDITION: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "printEdition");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// printPage
+    ///
+    /// If this NewsArticle appears in print, this field indicates the name of the page on which the article is found. Please note that this field is intended for the exact page name (e.g. A5, B18).
+    ///
+    /// `schema:printPage` (`PRINT_PAGE`)
+    pub static ref PRINT_PAGE: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "printPage");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// printSection
+    ///
+    /// If this NewsArticle appears in print, this field indicates the print section in which the article appeared.
+    ///
+    /// `schema:printSection` (`PRINT_SECTION`)
+    pub static ref PRINT_SECTION: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "printSection");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// procedure
+    ///
+    /// A description of the procedure involved in setting up, using, and/or installing the device.
+    ///
+    /// `schema:procedure` (`PROCEDURE`)
+    pub static ref PROCEDURE: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "procedure");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// procedureType
+    ///
+    /// The type of procedure, for example Surgical, Noninvasive, or Percutaneous.
+    ///
+    /// `schema:procedureType` (`PROCEDURE_TYPE`)
+    pub static ref PROCEDURE_TYPE: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "procedureType");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// processingTime
+    ///
+    /// Estimated processing time for the service using this channel.
+    ///
+    /// `schema:processingTime` (`PROCESSING_TIME`)
+    pub static ref PROCESSING_TIME: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "processingTime");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// processorRequirements
+    ///
+    /// Processor architecture required to run the application (e.g. IA64).
+    ///
+    /// `schema:processorRequirements` (`PROCESSOR_REQUIREMENTS`)
+    pub static ref PROCESSOR_REQUIREMENTS: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "processorRequirements");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// producer
+    ///
+    /// The person or organization who produced the work (e.g. music album, movie, TV/radio series etc.).
+    ///
+    /// `schema:producer` (`PRODUCER`)
+    pub static ref PRODUCER: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "producer");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// produces
+    ///
+    /// The tangible thing generated by the service, e.g. a passport, permit, etc.
+    ///
+    /// `schema:produces` (`PRODUCES`)
+    pub static ref PRODUCES: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "produces");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// productGroupID
+    ///
+    /// Indicates a textual identifier for a ProductGroup.
+    ///
+    /// `schema:productGroupID` (`PRODUCT_GROUP_ID`)
+    pub static ref PRODUCT_GROUP_ID: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "productGroupID");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// productID
+    ///
+    /// The product identifier, such as ISBN. For example: meta itemprop="productID" content="isbn:123-456-789".
+    ///
+    /// `schema:productID` (`PRODUCT_ID`)
+    pub static ref PRODUCT_ID: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "productID");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// productReturnDays
+    ///
+    /// The productReturnDays property indicates the number of days (from purchase) within which relevant product return policy is applicable.
+    ///
+    /// `schema:productReturnDays` (`PRODUCT_RETURN_DAYS`)
+    pub static ref PRODUCT_RETURN_DAYS: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "productReturnDays");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// productReturnLink
+    ///
+    /// Indicates a Web page or service by URL, for product return.
+    ///
+    /// `schema:productReturnLink` (`PRODUCT_RETURN_LINK`)
+    pub static ref PRODUCT_RETURN_LINK: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "productReturnLink");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// productSupported
+    ///
+    /// The product or service this support contact point is related to (such as product support for a particular product line). This can be a specific product or product line (e.g. "iPhone") or a general category of products or services (e.g. "smartphones").
+    ///
+    /// `schema:productSupported` (`PRODUCT_SUPPORTED`)
+    pub static ref PRODUCT_SUPPORTED: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "productSupported");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// productionCompany
+    ///
+    /// The production company or studio responsible for the item, e.g. series, video game, episode etc.
+    ///
+    /// `schema:productionCompany` (`PRODUCTION_COMPANY`)
+    pub static ref PRODUCTION_COMPANY: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "productionCompany");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// productionDate
+    ///
+    /// The date of production of the item, e.g. vehicle.
+    ///
+    /// `schema:productionDate` (`PRODUCTION_DATE`)
+    pub static ref PRODUCTION_DATE: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "productionDate");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// proficiencyLevel
+    ///
+    /// Proficiency needed for this content; expected values: 'Beginner', 'Expert'.
+    ///
+    /// `schema:proficiencyLevel` (`PROFICIENCY_LEVEL`)
+    pub static ref PROFICIENCY_LEVEL: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "proficiencyLevel");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// programMembershipUsed
+    ///
+    /// Any membership in a frequent flyer, hotel loyalty program, etc. being applied to the reservation.
+    ///
+    /// `schema:programMembershipUsed` (`PROGRAM_MEMBERSHIP_USED`)
+    pub static ref PROGRAM_MEMBERSHIP_USED: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "programMembershipUsed");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// programName
+    ///
+    /// The program providing the membership.
+    ///
+    /// `schema:programName` (`PROGRAM_NAME`)
+    pub static ref PROGRAM_NAME: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "programName");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// programPrerequisites
+    ///
+    /// Prerequisites for enrolling in the program.
+    ///
+    /// `schema:programPrerequisites` (`PROGRAM_PREREQUISITES`)
+    pub static ref PROGRAM_PREREQUISITES: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "programPrerequisites");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// programType
+    ///
+    /// The type of educational or occupational program. For example, classroom, internship, alternance, etc.
+    ///
+    /// `schema:programType` (`PROGRAM_TYPE`)
+    pub static ref PROGRAM_TYPE: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "programType");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// programmingLanguage
+    ///
+    /// The computer programming language.
+    ///
+    /// `schema:programmingLanguage` (`PROGRAMMING_LANGUAGE`)
+    pub static ref PROGRAMMING_LANGUAGE: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "programmingLanguage");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// programmingModel
+    ///
+    /// Indicates whether API is managed or unmanaged.
+    ///
+    /// `schema:programmingModel` (`PROGRAMMING_MODEL`)
+    pub static ref PROGRAMMING_MODEL: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "programmingModel");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// propertyID
+    ///
+    /// A commonly used identifier for the characteristic represented by the property, e.g. a manufacturer or a standard code for a property. propertyID can be (1) a prefixed string, mainly meant to be used with standards for product properties; (2) a site-specific, non-prefixed string (e.g. the primary key of the property or the vendor-specific ID of the property), or (3) a URL indicating the type of the property, either pointing to an external vocabulary, or a Web resource that describes the property (e.g. a glossary entry).
+    ///
+    /// `schema:propertyID` (`PROPERTY_ID`)
+    pub static ref PROPERTY_ID: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "propertyID");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// proprietaryName
+    ///
+    /// Proprietary name given to the diet plan, typically by its originator or creator.
+    ///
+    /// `schema:proprietaryName` (`PROPRIETARY_NAME`)
+    pub static ref PROPRIETARY_NAME: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "proprietaryName");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// proteinContent
+    ///
+    /// The number of grams of protein.
+    ///
+    /// `schema:proteinContent` (`PROTEIN_CONTENT`)
+    pub static ref PROTEIN_CONTENT: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "proteinContent");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// provider
+    ///
+    /// The service provider, service operator, or service performer; the goods producer. Another party (a seller) may offer those services or goods on behalf of the provider. A provider may also serve as the seller.
+    ///
+    /// `schema:provider` (`PROVIDER`)
+    pub static ref PROVIDER: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "provider");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// providerMobility
+    ///
+    /// Indicates the mobility of a provided service (e.g. 'static', 'dynamic').
+    ///
+    /// `schema:providerMobility` (`PROVIDER_MOBILITY`)
+    pub static ref PROVIDER_MOBILITY: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "providerMobility");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// providesBroadcastService
+    ///
+    /// The BroadcastService offered on this channel.
+    ///
+    /// `schema:providesBroadcastService` (`PROVIDES_BROADCAST_SERVICE`)
+    pub static ref PROVIDES_BROADCAST_SERVICE: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "providesBroadcastService");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// providesService
+    ///
+    /// The service provided by this channel.
+    ///
+    /// `schema:providesService` (`PROVIDES_SERVICE`)
+    pub static ref PROVIDES_SERVICE: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "providesService");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// publicAccess
+    ///
+    /// A flag to signal that the Place is open to public visitors. If this property is omitted there is no assumed default boolean value.
+    ///
+    /// `schema:publicAccess` (`PUBLIC_ACCESS`)
+    pub static ref PUBLIC_ACCESS: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "publicAccess");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// publicTransportClosuresInfo
+    ///
+    /// Information about public transport closures.
+    ///
+    /// `schema:publicTransportClosuresInfo` (`PUBLIC_TRANSPORT_CLOSURES_INFO`)
+    pub static ref PUBLIC_TRANSPORT_CLOSURES_INFO: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "publicTransportClosuresInfo");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// publication
+    ///
+    /// A publication event associated with the item.
+    ///
+    /// `schema:publication` (`PUBLICATION`)
+    pub static ref PUBLICATION: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "publication");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// publicationType
+    ///
+    /// The type of the medical article, taken from the US NLM MeSH publication type catalog. See also MeSH documentation.
+    ///
+    /// `schema:publicationType` (`PUBLICATION_TYPE`)
+    pub static ref PUBLICATION_TYPE: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "publicationType");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// publishedBy
+    ///
+    /// An agent associated with the publication event.
+    ///
+    /// `schema:publishedBy` (`PUBLISHED_BY`)
+    pub static ref PUBLISHED_BY: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "publishedBy");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// publishedOn
+    ///
+    /// A broadcast service associated with the publication event.
+    ///
+    /// `schema:publishedOn` (`PUBLISHED_ON`)
+    pub static ref PUBLISHED_ON: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "publishedOn");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// publisher
+    ///
+    /// The publisher of the article in question.
+    ///
+    /// `schema:publisher` (`PUBLISHER`)
+    pub static ref PUBLISHER: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "publisher");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// publisherImprint
+    ///
+    /// The publishing division which published the comic.
+    ///
+    /// `schema:publisherImprint` (`PUBLISHER_IMPRINT`)
+    pub static ref PUBLISHER_IMPRINT: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "publisherImprint");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// publishingPrinciples
+    ///
+    /// The publishingPrinciples property indicates (typically via URL) a document describing the editorial principles of an Organization (or individual, e.g. a Person writing a blog) that relate to their activities as a publisher, e.g. ethics or diversity policies.
+    ///
+    /// `schema:publishingPrinciples` (`PUBLISHING_PRINCIPLES`)
+    pub static ref PUBLISHING_PRINCIPLES: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "publishingPrinciples");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// purchaseDate
+    ///
+    /// The date the item, e.g. vehicle, was purchased by the current owner.
+    ///
+    /// `schema:purchaseDate` (`PURCHASE_DATE`)
+    pub static ref PURCHASE_DATE: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "purchaseDate");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// qualifications
+    ///
+    /// Specific qualifications required for this role or Occupation.
+    ///
+    /// `schema:qualifications` (`QUALIFICATIONS`)
+    pub static ref QUALIFICATIONS: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "qualifications");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// quarantineGuidelines
+    ///
+    /// Guidelines about quarantine rules, e.g. in the context of a pandemic.
+    ///
+    /// `schema:quarantineGuidelines` (`QUARANTINE_GUIDELINES`)
+    pub static ref QUARANTINE_GUIDELINES: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "quarantineGuidelines");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// query
+    ///
+    /// A sub property of instrument. The query used on this action.
+    ///
+    /// `schema:query` (`QUERY`)
+    pub static ref QUERY: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "query");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// quest
+    ///
+    /// The task that a player-controlled character, or group of characters may complete in order to gain a reward.
+    ///
+    /// `schema:quest` (`QUEST`)
+    pub static ref QUEST: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "quest");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// question
+    ///
+    /// A sub property of object. A question.
+    ///
+    /// `schema:question` (`QUESTION_PROPERTY`)
+    pub static ref QUESTION_PROPERTY: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "question");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// rangeIncludes
+    ///
+    /// Relates a property to a class that constitutes (one of) the expected type(s) for values of the property.
+    ///
+    /// `schema:rangeIncludes` (`RANGE_INCLUDES`)
+    pub static ref RANGE_INCLUDES: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "rangeIncludes");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// ratingCount
+    ///
+    /// The count of total number of ratings.
+    ///
+    /// `schema:ratingCount` (`RATING_COUNT`)
+    pub static ref RATING_COUNT: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "ratingCount");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// ratingExplanation
+    ///
+    /// A short explanation (e.g. one to two sentences) providing background context and other information that led to the conclusion expressed in the rating. This is particularly applicable to ratings associated with "fact check" markup using ClaimReview.
+    ///
+    /// `schema:ratingExplanation` (`RATING_EXPLANATION`)
+    pub static ref RATING_EXPLANATION: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "ratingExplanation");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// ratingValue
+    ///
+    /// The rating for the content. Usage guidelines: use values from 0123456789 (Unicode 'DIGIT ZERO' (U+0030) to 'DIGIT NINE' (U+0039)) rather than superficially similar Unicode symbols, and use '.' (Unicode 'FULL STOP' (U+002E)) rather than ',' to indicate a decimal point.
+    ///
+    /// `schema:ratingValue` (`RATING_VALUE`)
+    pub static ref RATING_VALUE: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "ratingValue");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// readBy
+    ///
+    /// A person who reads (performs) the audiobook.
+    ///
+    /// `schema:readBy` (`READ_BY`)
+    pub static ref READ_BY: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "readBy");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// readonlyValue
+    ///
+    /// Whether or not a property is mutable. Default is false. Specifying this for a property that also has a value makes it act similar to a "hidden" input in an HTML form.
+    ///
+    /// `schema:readonlyValue` (`READONLY_VALUE`)
+    pub static ref READONLY_VALUE: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "readonlyValue");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// realEstateAgent
+    ///
+    /// A sub property of participant. The real estate agent involved in the action.
+    ///
+    /// `schema:realEstateAgent` (`REAL_ESTATE_AGENT_PROPERTY`)
+    pub static ref REAL_ESTATE_AGENT_PROPERTY: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "realEstateAgent");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// recipe
+    ///
+    /// A sub property of instrument. The recipe/instructions used to perform the action.
+    ///
+    /// `schema:recipe` (`RECIPE_PROPERTY`)
+    pub static ref RECIPE_PROPERTY: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "recipe");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// recipeCategory
+    ///
+    /// The category of the recipe—for example, appetizer, entree, etc.
+    ///
+    /// `schema:recipeCategory` (`RECIPE_CATEGORY`)
+    pub static ref RECIPE_CATEGORY: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "recipeCategory");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// recipeCuisine
+    ///
+    /// The cuisine of the recipe (for example, French or Ethiopian).
+    ///
+    /// `schema:recipeCuisine` (`RECIPE_CUISINE`)
+    pub static ref RECIPE_CUISINE: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "recipeCuisine");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// recipeIngredient
+    ///
+    /// A single ingredient used in the recipe, e.g. sugar, flour or garlic.
+    ///
+    /// `schema:recipeIngredient` (`RECIPE_INGREDIENT`)
+    pub static ref RECIPE_INGREDIENT: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "recipeIngredient");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// recipeInstructions
+    ///
+    /// A step in making the recipe, in the form of a single item (document, video, etc.) or an ordered list with HowToStep and/or HowToSection items.
+    ///
+    /// `schema:recipeInstructions` (`RECIPE_INSTRUCTIONS`)
+    pub static ref RECIPE_INSTRUCTIONS: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "recipeInstructions");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// recipeYield
+    ///
+    /// The quantity produced by the recipe (for example, number of people served, number of servings, etc).
+    ///
+    /// `schema:recipeYield` (`RECIPE_YIELD`)
+    pub static ref RECIPE_YIELD: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "recipeYield");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// recipient
+    ///
+    /// A sub property of participant. The participant who is at the receiving end of the action.
+    ///
+    /// `schema:recipient` (`RECIPIENT`)
+    pub static ref RECIPIENT: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "recipient");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// recognizedBy
+    ///
+    /// An organization that acknowledges the validity, value or utility of a credential. Note: recognition may include a process of quality assurance or accreditation.
+    ///
+    /// `schema:recognizedBy` (`RECOGNIZED_BY`)
+    pub static ref RECOGNIZED_BY: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "recognizedBy");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// recognizingAuthority
+    ///
+    /// If applicable, the organization that officially recognizes this entity as part of its endorsed system of medicine.
+    ///
+    /// `schema:recognizingAuthority` (`RECOGNIZING_AUTHORITY`)
+    pub static ref RECOGNIZING_AUTHORITY: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "recognizingAuthority");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// recommendationStrength
+    ///
+    /// Strength of the guideline's recommendation (e.g. 'class I').
+    ///
+    /// `schema:recommendationStrength` (`RECOMMENDATION_STRENGTH`)
+    pub static ref RECOMMENDATION_STRENGTH: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "recommendationStrength");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// recommendedIntake
+    ///
+    /// Recommended intake of this supplement for a given population as defined by a specific recommending authority.
+    ///
+    /// `schema:recommendedIntake` (`RECOMMENDED_INTAKE`)
+    pub static ref RECOMMENDED_INTAKE: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "recommendedIntake");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// recordLabel
+    ///
+    /// The label that issued the release.
+    ///
+    /// `schema:recordLabel` (`RECORD_LABEL`)
+    pub static ref RECORD_LABEL: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "recordLabel");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// recordedAs
+    ///
+    /// An audio recording of the work.
+    ///
+    /// `schema:recordedAs` (`RECORDED_AS`)
+    pub static ref RECORDED_AS: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "recordedAs");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// recordedAt
+    ///
+    /// The Event where the CreativeWork was recorded. The CreativeWork may capture all or part of the event.
+    ///
+    /// `schema:recordedAt` (`RECORDED_AT`)
+    pub static ref RECORDED_AT: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "recordedAt");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// recordedIn
+    ///
+    /// The CreativeWork that captured all or part of this Event.
+    ///
+    /// `schema:recordedIn` (`RECORDED_IN`)
+    pub static ref RECORDED_IN: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "recordedIn");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// recordingOf
+    ///
+    /// The composition this track is a recording of.
+    ///
+    /// `schema:recordingOf` (`RECORDING_OF`)
+    pub static ref RECORDING_OF: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "recordingOf");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// recourseLoan
+    ///
+    /// The only way you get the money back in the event of default is the security. Recourse is where you still have the opportunity to go back to the borrower for the rest of the money.
+    ///
+    /// `schema:recourseLoan` (`RECOURSE_LOAN`)
+    pub static ref RECOURSE_LOAN: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "recourseLoan");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// referenceQuantity
+    ///
+    /// The reference quantity for which a certain price applies, e.g. 1 EUR per 4 kWh of electricity. This property is a replacement for unitOfMeasurement for the advanced cases where the price does not relate to a standard unit.
+    ///
+    /// `schema:referenceQuantity` (`REFERENCE_QUANTITY`)
+    pub static ref REFERENCE_QUANTITY: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "referenceQuantity");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// referencesOrder
+    ///
+    /// The Order(s) related to this Invoice. One or more Orders may be combined into a single Invoice.
+    ///
+    /// `schema:referencesOrder` (`REFERENCES_ORDER`)
+    pub static ref REFERENCES_ORDER: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "referencesOrder");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// refundType
+    ///
+    /// A refund type, from an enumerated list.
+    ///
+    /// `schema:refundType` (`REFUND_TYPE`)
+    pub static ref REFUND_TYPE: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "refundType");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// regionDrained
+    ///
+    /// The anatomical or organ system drained by this vessel; generally refers to a specific part of an organ.
+    ///
+    /// `schema:regionDrained` (`REGION_DRAINED`)
+    pub static ref REGION_DRAINED: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "regionDrained");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// regionsAllowed
+    ///
+    /// The regions where the media is allowed. If not specified, then it's assumed to be allowed everywhere. Specify the countries in ISO 3166 format.
+    ///
+    /// `schema:regionsAllowed` (`REGIONS_ALLOWED`)
+    pub static ref REGIONS_ALLOWED: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "regionsAllowed");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// relatedAnatomy
+    ///
+    /// Anatomical systems or structures that relate to the superficial anatomy.
+    ///
+    /// `schema:relatedAnatomy` (`RELATED_ANATOMY`)
+    pub static ref RELATED_ANATOMY: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "relatedAnatomy");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// relatedCondition
+    ///
+    /// A medical condition associated with this anatomy.
+    ///
+    /// `schema:relatedCondition` (`RELATED_CONDITION`)
+    pub static ref RELATED_CONDITION: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "relatedCondition");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// relatedDrug
+    ///
+    /// Any other drug related to this one, for example commonly-prescribed alternatives.
+    ///
+    /// `schema:relatedDrug` (`RELATED_DRUG`)
+    pub static ref RELATED_DRUG: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "relatedDrug");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// relatedLink
+    ///
+    /// A link related to this web page, for example to other related web pages.
+    ///
+    /// `schema:relatedLink` (`RELATED_LINK`)
+    pub static ref RELATED_LINK: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "relatedLink");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// relatedStructure
+    ///
+    /// Related anatomical structure(s) that are not part of the system but relate or connect to it, such as vascular bundles associated with an organ system.
+    ///
+    /// `schema:relatedStructure` (`RELATED_STRUCTURE`)
+    pub static ref RELATED_STRUCTURE: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "relatedStructure");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// relatedTherapy
+    ///
+    /// A medical therapy related to this anatomy.
+    ///
+    /// `schema:relatedTherapy` (`RELATED_THERAPY`)
+    pub static ref RELATED_THERAPY: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "relatedTherapy");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// relatedTo
+    ///
+    /// The most generic familial relation.
+    ///
+    /// `schema:relatedTo` (`RELATED_TO`)
+    pub static ref RELATED_TO: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "relatedTo");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// releaseDate
+    ///
+    /// The release date of a product or product model. This can be used to distinguish the exact variant of a product.
+    ///
+    /// `schema:releaseDate` (`RELEASE_DATE`)
+    pub static ref RELEASE_DATE: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "releaseDate");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// releaseNotes
+    ///
+    /// Description of what changed in this version.
+    ///
+    /// `schema:releaseNotes` (`RELEASE_NOTES`)
+    pub static ref RELEASE_NOTES: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "releaseNotes");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// releaseOf
+    ///
+    /// The album this is a release of.
+    ///
+    /// `schema:releaseOf` (`RELEASE_OF`)
+    pub static ref RELEASE_OF: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "releaseOf");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// releasedEvent
+    ///
+    /// The place and time the release was issued, expressed as a PublicationEvent.
+    ///
+    /// `schema:releasedEvent` (`RELEASED_EVENT`)
+    pub static ref RELEASED_EVENT: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "releasedEvent");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// relevantOccupation
+    ///
+    /// The Occupation for the JobPosting.
+    ///
+    /// `schema:relevantOccupation` (`RELEVANT_OCCUPATION`)
+    pub static ref RELEVANT_OCCUPATION: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "relevantOccupation");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// relevantSpecialty
+    ///
+    /// If applicable, a medical specialty in which this entity is relevant.
+    ///
+    /// `schema:relevantSpecialty` (`RELEVANT_SPECIALTY`)
+    pub static ref RELEVANT_SPECIALTY: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "relevantSpecialty");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// remainingAttendeeCapacity
+    ///
+    /// The number of attendee places for an event that remain unallocated.
+    ///
+    /// `schema:remainingAttendeeCapacity` (`REMAINING_ATTENDEE_CAPACITY`)
+    pub static ref REMAINING_ATTENDEE_CAPACITY: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "remainingAttendeeCapacity");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// renegotiableLoan
+    ///
+    /// Whether the terms for payment of interest can be renegotiated during the life of the loan.
+    ///
+    /// `schema:renegotiableLoan` (`RENEGOTIABLE_LOAN`)
+    pub static ref RENEGOTIABLE_LOAN: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "renegotiableLoan");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// repeatCount
+    ///
+    /// Defines the number of times a recurring Event will take place.
+    ///
+    /// `schema:repeatCount` (`REPEAT_COUNT`)
+    pub static ref REPEAT_COUNT: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "repeatCount");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// repeatFrequency
+    ///
+    /// Defines the frequency at which Events will occur according to a schedule Schedule. The intervals between events should be defined as a Duration of time.
+    ///
+    /// `schema:repeatFrequency` (`REPEAT_FREQUENCY`)
+    pub static ref REPEAT_FREQUENCY: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "repeatFrequency");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// repetitions
+    ///
+    /// Number of times one should repeat the activity.
+    ///
+    /// `schema:repetitions` (`REPETITIONS`)
+    pub static ref REPETITIONS: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "repetitions");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// replacee
+    ///
+    /// A sub property of object. The object that is being replaced.
+    ///
+    /// `schema:replacee` (`REPLACEE`)
+    pub static ref REPLACEE: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "replacee");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// replacer
+    ///
+    /// A sub property of object. The object that replaces.
+    ///
+    /// `schema:replacer` (`REPLACER`)
+    pub static ref REPLACER: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "replacer");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// replyToUrl
+    ///
+    /// The URL at which a reply may be posted to the specified UserComment.
+    ///
+    /// `schema:replyToUrl` (`REPLY_TO_URL`)
+    pub static ref REPLY_TO_URL: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "replyToUrl");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// reportNumber
+    ///
+    /// The number or other unique designator assigned to a Report by the publishing organization.
+    ///
+    /// `schema:reportNumber` (`REPORT_NUMBER`)
+    pub static ref REPORT_NUMBER: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "reportNumber");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// representativeOfPage
+    ///
+    /// Indicates whether this image is representative of the content of the page.
+    ///
+    /// `schema:representativeOfPage` (`REPRESENTATIVE_OF_PAGE`)
+    pub static ref REPRESENTATIVE_OF_PAGE: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "representativeOfPage");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// requiredCollateral
+    ///
+    /// Assets required to secure loan or credit repayments. It may take form of third party pledge, goods, financial instruments (cash, securities, etc.)
+    ///
+    /// `schema:requiredCollateral` (`REQUIRED_COLLATERAL`)
+    pub static ref REQUIRED_COLLATERAL: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "requiredCollateral");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// requiredGender
+    ///
+    /// Audiences defined by a person's gender.
+    ///
+    /// `schema:requiredGender` (`REQUIRED_GENDER`)
+    pub static ref REQUIRED_GENDER: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "requiredGender");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// requiredMaxAge
+    ///
+    /// Audiences defined by a person's maximum age.
+    ///
+    /// `schema:requiredMaxAge` (`REQUIRED_MAX_AGE`)
+    pub static ref REQUIRED_MAX_AGE: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "requiredMaxAge");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// requiredMinAge
+    ///
+    /// Audiences defined by a person's minimum age.
+    ///
+    /// `schema:requiredMinAge` (`REQUIRED_MIN_AGE`)
+    pub static ref REQUIRED_MIN_AGE: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "requiredMinAge");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// requiredQuantity
+    ///
+    /// The required quantity of the item(s).
+    ///
+    /// `schema:requiredQuantity` (`REQUIRED_QUANTITY`)
+    pub static ref REQUIRED_QUANTITY: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "requiredQuantity");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// requirements
+    ///
+    /// Component dependency requirements for application. This includes runtime environments and shared libraries that are not included in the application distribution package, but required to run the application (examples: DirectX, Java or .NET runtime).
+    ///
+    /// `schema:requirements` (`REQUIREMENTS`)
+    pub static ref REQUIREMENTS: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "requirements");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// requiresSubscription
+    ///
+    /// Indicates if use of the media require a subscription (either paid or free). Allowed values are true or false (note that an earlier version had 'yes', 'no').
+    ///
+    /// `schema:requiresSubscription` (`REQUIRES_SUBSCRIPTION`)
+    pub static ref REQUIRES_SUBSCRIPTION: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "requiresSubscription");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// reservationFor
+    ///
+    /// The thing -- flight, event, restaurant, etc. being reserved.
+    ///
+    /// `schema:reservationFor` (`RESERVATION_FOR`)
+    pub static ref RESERVATION_FOR: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "reservationFor");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// reservationId
+    ///
+    /// A unique identifier for the reservation.
+    ///
+    /// `schema:reservationId` (`RESERVATION_ID`)
+    pub static ref RESERVATION_ID: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "reservationId");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// reservationStatus
+    ///
+    /// The current status of the reservation.
+    ///
+    /// `schema:reservationStatus` (`RESERVATION_STATUS`)
+    pub static ref RESERVATION_STATUS: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "reservationStatus");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// reservedTicket
+    ///
+    /// A ticket associated with the reservation.
+    ///
+    /// `schema:reservedTicket` (`RESERVED_TICKET`)
+    pub static ref RESERVED_TICKET: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "reservedTicket");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// responsibilities
+    ///
+    /// Responsibilities associated with this role or Occupation.
+    ///
+    /// `schema:responsibilities` (`RESPONSIBILITIES`)
+    pub static ref RESPONSIBILITIES: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "responsibilities");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// restPeriods
+    ///
+    /// How often one should break from the activity.
+    ///
+    /// `schema:restPeriods` (`REST_PERIODS`)
+    pub static ref REST_PERIODS: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "restPeriods");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// restockingFee
+    ///
+    /// Use MonetaryAmount to specify a fixed restocking fee for product returns, or use Number to specify a percentage of the product price paid by the customer.
+    ///
+    /// `schema:restockingFee` (`RESTOCKING_FEE`)
+    pub static ref RESTOCKING_FEE: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "restockingFee");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// result
+    ///
+    /// The result produced in the action. E.g. John wrote a book.
+    ///
+    /// `schema:result` (`RESULT`)
+    pub static ref RESULT: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "result");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// resultComment
+    ///
+    /// A sub property of result. The Comment created or sent as a result of this action.
+    ///
+    /// `schema:resultComment` (`RESULT_COMMENT`)
+    pub static ref RESULT_COMMENT: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "resultComment");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// resultReview
+    ///
+    /// A sub property of result. The review that resulted in the performing of the action.
+    ///
+    /// `schema:resultReview` (`RESULT_REVIEW`)
+    pub static ref RESULT_REVIEW: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "resultReview");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// returnFees
+    ///
+    /// The type of return fees for purchased products (for any return reason).
+    ///
+    /// `schema:returnFees` (`RETURN_FEES`)
+    pub static ref RETURN_FEES: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "returnFees");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// returnLabelSource
+    ///
+    /// The method (from an enumeration) by which the customer obtains a return shipping label for a product returned for any reason.
+    ///
+    /// `schema:returnLabelSource` (`RETURN_LABEL_SOURCE`)
+    pub static ref RETURN_LABEL_SOURCE: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "returnLabelSource");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// returnMethod
+    ///
+    /// The type of return method offered, specified from an enumeration.
+    ///
+    /// `schema:returnMethod` (`RETURN_METHOD`)
+    pub static ref RETURN_METHOD: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "returnMethod");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// returnPolicyCategory
+    ///
+    /// Specifies an applicable return policy (from an enumeration).
+    ///
+    /// `schema:returnPolicyCategory` (`RETURN_POLICY_CATEGORY`)
+    pub static ref RETURN_POLICY_CATEGORY: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "returnPolicyCategory");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// returnPolicyCountry
+    ///
+    /// The country where the product has to be sent to for returns, for example "Ireland" using the name property of Country. You can also provide the two-letter ISO 3166-1 alpha-2 country code. Note that this can be different from the country where the product was originally shipped from or sent to.
+    ///
+    /// `schema:returnPolicyCountry` (`RETURN_POLICY_COUNTRY`)
+    pub static ref RETURN_POLICY_COUNTRY: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "returnPolicyCountry");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// returnPolicySeasonalOverride
+    ///
+    /// Seasonal override of a return policy.
+    ///
+    /// `schema:returnPolicySeasonalOverride` (`RETURN_POLICY_SEASONAL_OVERRIDE`)
+    pub static ref RETURN_POLICY_SEASONAL_OVERRIDE: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "returnPolicySeasonalOverride");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// returnShippingFeesAmount
+    ///
+    /// Amount of shipping costs for product returns (for any reason). Applicable when property returnFees equals ReturnShippingFees.
+    ///
+    /// `schema:returnShippingFeesAmount` (`RETURN_SHIPPING_FEES_AMOUNT`)
+    pub static ref RETURN_SHIPPING_FEES_AMOUNT: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "returnShippingFeesAmount");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// review
+    ///
+    /// A review of the item.
+    ///
+    /// `schema:review` (`REVIEW_PROPERTY`)
+    pub static ref REVIEW_PROPERTY: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "review");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// reviewAspect
+    ///
+    /// This Review or Rating is relevant to this part or facet of the itemReviewed.
+    ///
+    /// `schema:reviewAspect` (`REVIEW_ASPECT`)
+    pub static ref REVIEW_ASPECT: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "reviewAspect");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// reviewBody
+    ///
+    /// The actual body of the review.
+    ///
+    /// `schema:reviewBody` (`REVIEW_BODY`)
+    pub static ref REVIEW_BODY: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "reviewBody");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// reviewCount
+    ///
+    /// The count of total number of reviews.
+    ///
+    /// `schema:reviewCount` (`REVIEW_COUNT`)
+    pub static ref REVIEW_COUNT: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "reviewCount");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// reviewRating
+    ///
+    /// The rating given in this review. Note that reviews can themselves be rated. The reviewRating applies to rating given by the review. The aggregateRating property applies to the review itself, as a creative work.
+    ///
+    /// `schema:reviewRating` (`REVIEW_RATING`)
+    pub static ref REVIEW_RATING: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "reviewRating");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// reviewedBy
+    ///
+    /// People or organizations that have reviewed the content on this web page for accuracy and/or completeness.
+    ///
+    /// `schema:reviewedBy` (`REVIEWED_BY`)
+    pub static ref REVIEWED_BY: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "reviewedBy");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// reviews
+    ///
+    /// Review of the item.
+    ///
+    /// `schema:reviews` (`REVIEWS`)
+    pub static ref REVIEWS: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "reviews");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// riskFactor
+    ///
+    /// A modifiable or non-modifiable factor that increases the risk of a patient contracting this condition, e.g. age, coexisting condition.
+    ///
+    /// `schema:riskFactor` (`RISK_FACTOR`)
+    pub static ref RISK_FACTOR: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "riskFactor");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// risks
+    ///
+    /// Specific physiologic risks associated to the diet plan.
+    ///
+    /// `schema:risks` (`RISKS`)
+    pub static ref RISKS: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "risks");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// roleName
+    ///
+    /// A role played, performed or filled by a person or organization. For example, the team of creators for a comic book might fill the roles named 'inker', 'penciller', and 'letterer'; or an athlete in a SportsTeam might play in the position named 'Quarterback'.
+    ///
+    /// `schema:roleName` (`ROLE_NAME`)
+    pub static ref ROLE_NAME: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "roleName");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// roofLoad
+    ///
+    /// The permitted total weight of cargo and installations (e.g. a roof rack) on top of the vehicle.
+    ///
+    /// `schema:roofLoad` (`ROOF_LOAD`)
+    pub static ref ROOF_LOAD: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "roofLoad");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// rsvpResponse
+    ///
+    /// The response (yes, no, maybe) to the RSVP.
+    ///
+    /// `schema:rsvpResponse` (`RSVP_RESPONSE`)
+    pub static ref RSVP_RESPONSE: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "rsvpResponse");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// runsTo
+    ///
+    /// The vasculature the lymphatic structure runs, or efferents, to.
+    ///
+    /// `schema:runsTo` (`RUNS_TO`)
+    pub static ref RUNS_TO: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "runsTo");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// runtime
+    ///
+    /// Runtime platform or script interpreter dependencies (example: Java v1, Python 2.3, .NET Framework 3.0).
+    ///
+    /// `schema:runtime` (`RUNTIME`)
+    pub static ref RUNTIME: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "runtime");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// runtimePlatform
+    ///
+    /// Runtime platform or script interpreter dependencies (example: Java v1, Python 2.3, .NET Framework 3.0).
+    ///
+    /// `schema:runtimePlatform` (`RUNTIME_PLATFORM`)
+    pub static ref RUNTIME_PLATFORM: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "runtimePlatform");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// rxcui
+    ///
+    /// The RxCUI drug identifier from RXNORM.
+    ///
+    /// `schema:rxcui` (`RXCUI`)
+    pub static ref RXCUI: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "rxcui");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// safetyConsideration
+    ///
+    /// Any potential safety concern associated with the supplement. May include interactions with other drugs and foods, pregnancy, breastfeeding, known adverse reactions, and documented efficacy of the supplement.
+    ///
+    /// `schema:safetyConsideration` (`SAFETY_CONSIDERATION`)
+    pub static ref SAFETY_CONSIDERATION: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "safetyConsideration");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// salaryCurrency
+    ///
+    /// The currency (coded using ISO 4217) used for the main salary information in this job posting or for this employee.
+    ///
+    /// `schema:salaryCurrency` (`SALARY_CURRENCY`)
+    pub static ref SALARY_CURRENCY: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "salaryCurrency");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// salaryUponCompletion
+    ///
+    /// The expected salary upon completing the training.
+    ///
+    /// `schema:salaryUponCompletion` (`SALARY_UPON_COMPLETION`)
+    pub static ref SALARY_UPON_COMPLETION: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "salaryUponCompletion");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// sameAs
+    ///
+    /// URL of a reference Web page that unambiguously indicates the item's identity. E.g. the URL of the item's Wikipedia page, Wikidata entry, or official website.
+    ///
+    /// `schema:sameAs` (`SAME_AS`)
+    pub static ref SAME_AS: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "sameAs");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// sampleType
+    ///
+    /// What type of code sample: full (compile ready) solution, code snippet, inline code, scripts, template.
+    ///
+    /// `schema:sampleType` (`SAMPLE_TYPE`)
+    pub static ref SAMPLE_TYPE: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "sampleType");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// saturatedFatContent
+    ///
+    /// The number of grams of saturated fat.
+    ///
+    /// `schema:saturatedFatContent` (`SATURATED_FAT_CONTENT`)
+    pub static ref SATURATED_FAT_CONTENT: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "saturatedFatContent");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// scheduleTimezone
+    ///
+    /// Indicates the timezone for which the time(s) indicated in the Schedule are given. The value provided should be among those listed in the IANA Time Zone Database.
+    ///
+    /// `schema:scheduleTimezone` (`SCHEDULE_TIMEZONE`)
+    pub static ref SCHEDULE_TIMEZONE: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "scheduleTimezone");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// scheduledPaymentDate
+    ///
+    /// The date the invoice is scheduled to be paid.
+    ///
+    /// `schema:scheduledPaymentDate` (`SCHEDULED_PAYMENT_DATE`)
+    pub static ref SCHEDULED_PAYMENT_DATE: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "scheduledPaymentDate");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// scheduledTime
+    ///
+    /// The time the object is scheduled to.
+    ///
+    /// `schema:scheduledTime` (`SCHEDULED_TIME`)
+    pub static ref SCHEDULED_TIME: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "scheduledTime");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// schemaVersion
+    ///
+    /// Indicates (by URL or string) a particular version of a schema used in some CreativeWork. This property was created primarily to indicate the use of a specific schema.org release, e.g. 10.0 as a simple string, or more explicitly via URL.
+    ///
+    /// `schema:schemaVersion` (`SCHEMA_VERSION`)
+    pub static ref SCHEMA_VERSION: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "schemaVersion");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// schoolClosuresInfo
+    ///
+    /// Information about school closures.
+    ///
+    /// `schema:schoolClosuresInfo` (`SCHOOL_CLOSURES_INFO`)
+    pub static ref SCHOOL_CLOSURES_INFO: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "schoolClosuresInfo");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// screenCount
+    ///
+    /// The number of screens in the movie theater.
+    ///
+    /// `schema:screenCount` (`SCREEN_COUNT`)
+    pub static ref SCREEN_COUNT: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "screenCount");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// screenshot
+    ///
+    /// A link to a screenshot image of the app.
+    ///
+    /// `schema:screenshot` (`SCREENSHOT`)
+    pub static ref SCREENSHOT: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "screenshot");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// sdDatePublished
+    ///
+    /// Indicates the date on which the current structured data was generated / published. Typically used alongside sdPublisher.
+    ///
+    /// `schema:sdDatePublished` (`SD_DATE_PUBLISHED`)
+    pub static ref SD_DATE_PUBLISHED: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "sdDatePublished");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// sdLicense
+    ///
+    /// A license document that applies to this structured data, typically indicated by URL.
+    ///
+    /// `schema:sdLicense` (`SD_LICENSE`)
+    pub static ref SD_LICENSE: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "sdLicense");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// sdPublisher
+    ///
+    /// Indicates the party responsible for generating and publishing the current structured data markup, typically in cases where the structured data is derived automatically from existing published content but published on a different site.
+    ///
+    /// `schema:sdPublisher` (`SD_PUBLISHER`)
+    pub static ref SD_PUBLISHER: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "sdPublisher");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// season
+    ///
+    /// A season in a media series.
+    ///
+    /// `schema:season` (`SEASON_PROPERTY`)
+    pub static ref SEASON_PROPERTY: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "season");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// seasonNumber
+    ///
+    /// Position of the season within an ordered group of seasons.
+    ///
+    /// `schema:seasonNumber` (`SEASON_NUMBER`)
+    pub static ref SEASON_NUMBER: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "seasonNumber");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// seasons
+    ///
+    /// A season in a media series.
+    ///
+    /// `schema:seasons` (`SEASONS`)
+    pub static ref SEASONS: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "seasons");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// seatNumber
+    ///
+    /// The location of the reserved seat (e.g., 27).
+    ///
+    /// `schema:seatNumber` (`SEAT_NUMBER`)
+    pub static ref SEAT_NUMBER: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "seatNumber");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// seatRow
+    ///
+    /// The row location of the reserved seat (e.g., B).
+    ///
+    /// `schema:seatRow` (`SEAT_ROW`)
+    pub static ref SEAT_ROW: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "seatRow");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// seatSection
+    ///
+    /// The section location of the reserved seat (e.g. Orchestra).
+    ///
+    /// `schema:seatSection` (`SEAT_SECTION`)
+    pub static ref SEAT_SECTION: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "seatSection");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// seatingCapacity
+    ///
+    /// The number of persons that can be seated (e.g. in a vehicle), both in terms of the physical space available, and in terms of limitations set by law.
+    ///
+    /// `schema:seatingCapacity` (`SEATING_CAPACITY`)
+    pub static ref SEATING_CAPACITY: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "seatingCapacity");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// seatingType
+    ///
+    /// The type/class of the seat.
+    ///
+    /// `schema:seatingType` (`SEATING_TYPE`)
+    pub static ref SEATING_TYPE: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "seatingType");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// secondaryPrevention
+    ///
+    /// A preventative therapy used to prevent reoccurrence of the medical condition after an initial episode of the condition.
+    ///
+    /// `schema:secondaryPrevention` (`SECONDARY_PREVENTION`)
+    pub static ref SECONDARY_PREVENTION: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "secondaryPrevention");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// securityClearanceRequirement
+    ///
+    /// A description of any security clearance requirements of the job.
+    ///
+    /// `schema:securityClearanceRequirement` (`SECURITY_CLEARANCE_REQUIREMENT`)
+    pub static ref SECURITY_CLEARANCE_REQUIREMENT: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "securityClearanceRequirement");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// securityScreening
+    ///
+    /// The type of security screening the passenger is subject to.
+    ///
+    /// `schema:securityScreening` (`SECURITY_SCREENING`)
+    pub static ref SECURITY_SCREENING: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "securityScreening");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// seeks
+    ///
+    /// A pointer to products or services sought by the organization or person (demand).
+    ///
+    /// `schema:seeks` (`SEEKS`)
+    pub static ref SEEKS: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "seeks");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// seller
+    ///
+    /// An entity which offers (sells / leases / lends / loans) the services / goods. A seller may also be a provider.
+    ///
+    /// `schema:seller` (`SELLER`)
+    pub static ref SELLER: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "seller");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// sender
+    ///
+    /// A sub property of participant. The participant who is at the sending end of the action.
+    ///
+    /// `schema:sender` (`SENDER`)
+    pub static ref SENDER: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "sender");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// sensoryRequirement
+    ///
+    /// A description of any sensory requirements and levels necessary to function on the job, including hearing and vision. Defined terms such as those in O*net may be used, but note that there is no way to specify the level of ability as well as its nature when using a defined term.
+    ///
+    /// `schema:sensoryRequirement` (`SENSORY_REQUIREMENT`)
+    pub static ref SENSORY_REQUIREMENT: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "sensoryRequirement");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// sensoryUnit
+    ///
+    /// The neurological pathway extension that inputs and sends information to the brain or spinal cord.
+    ///
+    /// `schema:sensoryUnit` (`SENSORY_UNIT`)
+    pub static ref SENSORY_UNIT: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "sensoryUnit");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// serialNumber
+    ///
+    /// The serial number or any alphanumeric identifier of a particular product. When attached to an offer, it is a shortcut for the serial number of the product included in the offer.
+    ///
+    /// `schema:serialNumber` (`SERIAL_NUMBER`)
+    pub static ref SERIAL_NUMBER: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "serialNumber");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// seriousAdverseOutcome
+    ///
+    /// A possible serious complication and/or serious side effect of this therapy. Serious adverse outcomes include those that are life-threatening; result in death, disability, or permanent damage; require hospitalization or prolong existing hospitalization; cause congenital anomalies or birth defects; or jeopardize the patient and may require medical or surgical intervention to prevent one of the outcomes in this definition.
+    ///
+    /// `schema:seriousAdverseOutcome` (`SERIOUS_ADVERSE_OUTCOME`)
+    pub static ref SERIOUS_ADVERSE_OUTCOME: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "seriousAdverseOutcome");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// serverStatus
+    ///
+    /// Status of a game server.
+    ///
+    /// `schema:serverStatus` (`SERVER_STATUS`)
+    pub static ref SERVER_STATUS: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "serverStatus");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// servesCuisine
+    ///
+    /// The cuisine of the restaurant.
+    ///
+    /// `schema:servesCuisine` (`SERVES_CUISINE`)
+    pub static ref SERVES_CUISINE: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "servesCuisine");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// serviceArea
+    ///
+    /// The geographic area where the service is provided.
+    ///
+    /// `schema:serviceArea` (`SERVICE_AREA`)
+    pub static ref SERVICE_AREA: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "serviceArea");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// serviceAudience
+    ///
+    /// The audience eligible for this service.
+    ///
+    /// `schema:serviceAudience` (`SERVICE_AUDIENCE`)
+    pub static ref SERVICE_AUDIENCE: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "serviceAudience");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// serviceLocation
+    ///
+    /// The location (e.g. civic structure, local business, etc.) where a person can go to access the service.
+    ///
+    /// `schema:serviceLocation` (`SERVICE_LOCATION`)
+    pub static ref SERVICE_LOCATION: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "serviceLocation");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// serviceOperator
+    ///
+    /// The operating organization, if different from the provider. This enables the representation of services that are provided by an organization, but operated by another organization like a subcontractor.
+    ///
+    /// `schema:serviceOperator` (`SERVICE_OPERATOR`)
+    pub static ref SERVICE_OPERATOR: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "serviceOperator");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// serviceOutput
+    ///
+    /// The tangible thing generated by the service, e.g. a passport, permit, etc.
+    ///
+    /// `schema:serviceOutput` (`SERVICE_OUTPUT`)
+    pub static ref SERVICE_OUTPUT: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "serviceOutput");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// servicePhone
+    ///
+    /// The phone number to use to access the service.
+    ///
+    /// `schema:servicePhone` (`SERVICE_PHONE`)
+    pub static ref SERVICE_PHONE: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "servicePhone");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// servicePostalAddress
+    ///
+    /// The address for accessing the service by mail.
+    ///
+    /// `schema:servicePostalAddress` (`SERVICE_POSTAL_ADDRESS`)
+    pub static ref SERVICE_POSTAL_ADDRESS: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "servicePostalAddress");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// serviceSmsNumber
+    ///
+    /// The number to access the service by text message.
+    ///
+    /// `schema:serviceSmsNumber` (`SERVICE_SMS_NUMBER`)
+    pub static ref SERVICE_SMS_NUMBER: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "serviceSmsNumber");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// serviceType
+    ///
+    /// The type of service being offered, e.g. veterans' benefits, emergency relief, etc.
+    ///
+    /// `schema:serviceType` (`SERVICE_TYPE`)
+    pub static ref SERVICE_TYPE: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "serviceType");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// serviceUrl
+    ///
+    /// The website to access the service.
+    ///
+    /// `schema:serviceUrl` (`SERVICE_URL`)
+    pub static ref SERVICE_URL: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "serviceUrl");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// servingSize
+    ///
+    /// The serving size, in terms of the number of volume or mass.
+    ///
+    /// `schema:servingSize` (`SERVING_SIZE`)
+    pub static ref SERVING_SIZE: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "servingSize");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// sha256
+    ///
+    /// The SHA-2 SHA256 hash of the content of the item. For example, a zero-length input has value 'e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855'.
+    ///
+    /// `schema:sha256` (`SHA256`)
+    pub static ref SHA256: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "sha256");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// sharedContent
+    ///
+    /// A CreativeWork such as an image, video, or audio clip shared as part of this posting.
+    ///
+    /// `schema:sharedContent` (`SHARED_CONTENT`)
+    pub static ref SHARED_CONTENT: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "sharedContent");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// shippingDestination
+    ///
+    /// indicates (possibly multiple) shipping destinations. These can be defined in several ways, e.g. postalCode ranges.
+    ///
+    /// `schema:shippingDestination` (`SHIPPING_DESTINATION`)
+    pub static ref SHIPPING_DESTINATION: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "shippingDestination");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// shippingDetails
+    ///
+    /// Indicates information about the shipping policies and options associated with an Offer.
+    ///
+    /// `schema:shippingDetails` (`SHIPPING_DETAILS`)
+    pub static ref SHIPPING_DETAILS: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "shippingDetails");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// shippingLabel
+    ///
+    /// Label to match an OfferShippingDetails with a ShippingRateSettings (within the context of a shippingSettingsLink cross-reference).
+    ///
+    /// `schema:shippingLabel` (`SHIPPING_LABEL`)
+    pub static ref SHIPPING_LABEL: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "shippingLabel");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// shippingOrigin
+    ///
+    /// Indicates the origin of a shipment, i.e. where it should be coming from.
+    ///
+    /// `schema:shippingOrigin` (`SHIPPING_ORIGIN`)
+    pub static ref SHIPPING_ORIGIN: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "shippingOrigin");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// shippingRate
+    ///
+    /// The shipping rate is the cost of shipping to the specified destination. Typically, the maxValue and currency values (of the MonetaryAmount) are most appropriate.
+    ///
+    /// `schema:shippingRate` (`SHIPPING_RATE`)
+    pub static ref SHIPPING_RATE: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "shippingRate");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// shippingSettingsLink
+    ///
+    /// Link to a page containing ShippingRateSettings and DeliveryTimeSettings details.
+    ///
+    /// `schema:shippingSettingsLink` (`SHIPPING_SETTINGS_LINK`)
+    pub static ref SHIPPING_SETTINGS_LINK: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "shippingSettingsLink");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// sibling
+    ///
+    /// A sibling of the person.
+    ///
+    /// `schema:sibling` (`SIBLING`)
+    pub static ref SIBLING: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "sibling");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// siblings
+    ///
+    /// A sibling of the person.
+    ///
+    /// `schema:siblings` (`SIBLINGS`)
+    pub static ref SIBLINGS: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "siblings");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// signDetected
+    ///
+    /// A sign detected by the test.
+    ///
+    /// `schema:signDetected` (`SIGN_DETECTED`)
+    pub static ref SIGN_DETECTED: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "signDetected");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// signOrSymptom
+    ///
+    /// A sign or symptom of this condition. Signs are objective or physically observable manifestations of the medical condition while symptoms are the subjective experience of the medical condition.
+    ///
+    /// `schema:signOrSymptom` (`SIGN_OR_SYMPTOM`)
+    pub static ref SIGN_OR_SYMPTOM: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "signOrSymptom");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// significance
+    ///
+    /// The significance associated with the superficial anatomy; as an example, how characteristics of the superficial anatomy can suggest underlying medical conditions or courses of treatment.
+    ///
+    /// `schema:significance` (`SIGNIFICANCE`)
+    pub static ref SIGNIFICANCE: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "significance");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// significantLink
+    ///
+    /// One of the more significant URLs on the page. Typically, these are the non-navigation links that are clicked on the most.
+    ///
+    /// `schema:significantLink` (`SIGNIFICANT_LINK`)
+    pub static ref SIGNIFICANT_LINK: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "significantLink");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// significantLinks
+    ///
+    /// The most significant URLs on the page. Typically, these are the non-navigation links that are clicked on the most.
+    ///
+    /// `schema:significantLinks` (`SIGNIFICANT_LINKS`)
+    pub static ref SIGNIFICANT_LINKS: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "significantLinks");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// size
+    ///
+    /// A standardized size of a product or creative work, specified either through a simple textual string (for example 'XL', '32Wx34L'), a QuantitativeValue with a unitCode, or a comprehensive and structured SizeSpecification; in other cases, the width, height, depth and weight properties may be more applicable.
+    ///
+    /// `schema:size` (`SIZE`)
+    pub static ref SIZE: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "size");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// sizeGroup
+    ///
+    /// The size group (also known as "size type") for a product's size. Size groups are common in the fashion industry to define size segments and suggested audiences for wearable products. Multiple values can be combined, for example "men's big and tall", "petite maternity" or "regular".
+    ///
+    /// `schema:sizeGroup` (`SIZE_GROUP`)
+    pub static ref SIZE_GROUP: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "sizeGroup");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// sizeSystem
+    ///
+    /// The size system used to identify a product's size. Typically either a standard (for example, "GS1" or "ISO-EN13402"), country code (for example "US" or "JP"), or a measuring system (for example "Metric" or "Imperial").
+    ///
+    /// `schema:sizeSystem` (`SIZE_SYSTEM`)
+    pub static ref SIZE_SYSTEM: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "sizeSystem");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// skills
+    ///
+    /// A statement of knowledge, skill, ability, task or any other assertion expressing a competency that is either claimed by a person, an organization or desired or required to fulfill a role or to work in an occupation.
+    ///
+    /// `schema:skills` (`SKILLS`)
+    pub static ref SKILLS: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "skills");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// sku
+    ///
+    /// The Stock Keeping Unit (SKU), i.e. a merchant-specific identifier for a product or service, or the product to which the offer refers.
+    ///
+    /// `schema:sku` (`SKU`)
+    pub static ref SKU: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "sku");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// slogan
+    ///
+    /// A slogan or motto associated with the item.
+    ///
+    /// `schema:slogan` (`SLOGAN`)
+    pub static ref SLOGAN: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "slogan");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// smiles
+    ///
+    /// A specification in form of a line notation for describing the structure of chemical species using short ASCII strings. Double bond stereochemistry indicators may need to be escaped in the string in formats where the backslash is an escape character.
+    ///
+    /// `schema:smiles` (`SMILES`)
+    pub static ref SMILES: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "smiles");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// smokingAllowed
+    ///
+    /// Indicates whether it is allowed to smoke in the place, e.g. in the restaurant, hotel or hotel room.
+    ///
+    /// `schema:smokingAllowed` (`SMOKING_ALLOWED`)
+    pub static ref SMOKING_ALLOWED: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "smokingAllowed");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// sodiumContent
+    ///
+    /// The number of milligrams of sodium.
+    ///
+    /// `schema:sodiumContent` (`SODIUM_CONTENT`)
+    pub static ref SODIUM_CONTENT: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "sodiumContent");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// softwareAddOn
+    ///
+    /// Additional content for a software application.
+    ///
+    /// `schema:softwareAddOn` (`SOFTWARE_ADD_ON`)
+    pub static ref SOFTWARE_ADD_ON: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "softwareAddOn");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// softwareHelp
+    ///
+    /// Software application help.
+    ///
+    /// `schema:softwareHelp` (`SOFTWARE_HELP`)
+    pub static ref SOFTWARE_HELP: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "softwareHelp");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// softwareRequirements
+    ///
+    /// Component dependency requirements for application. This includes runtime environments and shared libraries that are not included in the application distribution package, but required to run the application (examples: DirectX, Java or .NET runtime).
+    ///
+    /// `schema:softwareRequirements` (`SOFTWARE_REQUIREMENTS`)
+    pub static ref SOFTWARE_REQUIREMENTS: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "softwareRequirements");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// softwareVersion
+    ///
+    /// Version of the software instance.
+    ///
+    /// `schema:softwareVersion` (`SOFTWARE_VERSION`)
+    pub static ref SOFTWARE_VERSION: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "softwareVersion");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// sourceOrganization
+    ///
+    /// The Organization on whose behalf the creator was working.
+    ///
+    /// `schema:sourceOrganization` (`SOURCE_ORGANIZATION`)
+    pub static ref SOURCE_ORGANIZATION: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "sourceOrganization");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// sourcedFrom
+    ///
+    /// The neurological pathway that originates the neurons.
+    ///
+    /// `schema:sourcedFrom` (`SOURCED_FROM`)
+    pub static ref SOURCED_FROM: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "sourcedFrom");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// spatial
+    ///
+    /// The "spatial" property can be used in cases when more specific properties (e.g. locationCreated, spatialCoverage, contentLocation) are not known to be appropriate.
+    ///
+    /// `schema:spatial` (`SPATIAL`)
+    pub static ref SPATIAL: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "spatial");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// spatialCoverage
+    ///
+    /// The spatialCoverage of a CreativeWork indicates the place(s) which are the focus of the content. It is a subproperty of contentLocation intended primarily for more technical and detailed materials. For example with a Dataset, it indicates areas that the dataset describes: a dataset of New York weather would have spatialCoverage which was the place: the state of New York.
+    ///
+    /// `schema:spatialCoverage` (`SPATIAL_COVERAGE`)
+    pub static ref SPATIAL_COVERAGE: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "spatialCoverage");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// speakable
+    ///
+    /// Indicates sections of a Web page that are particularly 'speakable' in the sense of being highlighted as being especially appropriate for text-to-speech conversion. Other sections of a page may also be usefully spoken in particular circumstances; the 'speakable' property serves to indicate the parts most likely to be generally useful for speech.
+    ///
+    /// `schema:speakable` (`SPEAKABLE`)
+    pub static ref SPEAKABLE: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "speakable");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// specialCommitments
+    ///
+    /// Any special commitments associated with this job posting. Valid entries include VeteranCommit, MilitarySpouseCommit, etc.
+    ///
+    /// `schema:specialCommitments` (`SPECIAL_COMMITMENTS`)
+    pub static ref SPECIAL_COMMITMENTS: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "specialCommitments");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// specialOpeningHoursSpecification
+    ///
+    /// The special opening hours of a certain place. Use this to explicitly override general opening hours brought in scope by openingHoursSpecification or openingHours.
+    ///
+    /// `schema:specialOpeningHoursSpecification` (`SPECIAL_OPENING_HOURS_SPECIFICATION`)
+    pub static ref SPECIAL_OPENING_HOURS_SPECIFICATION: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "specialOpeningHoursSpecification");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// specialty
+    ///
+    /// One of the domain specialities to which this web page's content applies.
+    ///
+    /// `schema:specialty` (`SPECIALTY_PROPERTY`)
+    pub static ref SPECIALTY_PROPERTY: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "specialty");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// speechToTextMarkup
+    ///
+    /// Form of markup used. eg. SSML or IPA.
+    ///
+    /// `schema:speechToTextMarkup` (`SPEECH_TO_TEXT_MARKUP`)
+    pub static ref SPEECH_TO_TEXT_MARKUP: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "speechToTextMarkup");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// speed
+    ///
+    /// The speed range of the vehicle. If the vehicle is powered by an engine, the upper limit of the speed range (indicated by maxValue) should be the maximum speed achievable under regular conditions.
+    ///
+    /// `schema:speed` (`SPEED`)
+    pub static ref SPEED: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "speed");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// spokenByCharacter
+    ///
+    /// The (e.g. fictional) character, Person or Organization to whom the quotation is attributed within the containing CreativeWork.
+    ///
+    /// `schema:spokenByCharacter` (`SPOKEN_BY_CHARACTER`)
+    pub static ref SPOKEN_BY_CHARACTER: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "spokenByCharacter");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// sponsor
+    ///
+    /// A person or organization that supports a thing through a pledge, promise, or financial contribution. E.g. a sponsor of a Medical Study or a corporate sponsor of an event.
+    ///
+    /// `schema:sponsor` (`SPONSOR`)
+    pub static ref SPONSOR: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "sponsor");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// sport
+    ///
+    /// A type of sport (e.g. Baseball).
+    ///
+    /// `schema:sport` (`SPORT`)
+    pub static ref SPORT: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "sport");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// sportsActivityLocation
+    ///
+    /// A sub property of location. The sports activity location where this action occurred.
+    ///
+    /// `schema:sportsActivityLocation` (`SPORTS_ACTIVITY_LOCATION_PROPERTY`)
+    pub static ref SPORTS_ACTIVITY_LOCATION_PROPERTY: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "sportsActivityLocation");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// sportsEvent
+    ///
+    /// A sub property of location. The sports event where this action occurred.
+    ///
+    /// `schema:sportsEvent` (`SPORTS_EVENT_PROPERTY`)
+    pub static ref SPORTS_EVENT_PROPERTY: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "sportsEvent");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// sportsTeam
+    ///
+    /// A sub property of participant. The sports team that participated on this action.
+    ///
+    /// `schema:sportsTeam` (`SPORTS_TEAM_PROPERTY`)
+    pub static ref SPORTS_TEAM_PROPERTY: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "sportsTeam");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// spouse
+    ///
+    /// The person's spouse.
+    ///
+    /// `schema:spouse` (`SPOUSE`)
+    pub static ref SPOUSE: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "spouse");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// stage
+    ///
+    /// The stage of the condition, if applicable.
+    ///
+    /// `schema:stage` (`STAGE`)
+    pub static ref STAGE: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "stage");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// stageAsNumber
+    ///
+    /// The stage represented as a number, e.g. 3.
+    ///
+    /// `schema:stageAsNumber` (`STAGE_AS_NUMBER`)
+    pub static ref STAGE_AS_NUMBER: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "stageAsNumber");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// starRating
+    ///
+    /// An official rating for a lodging business or food establishment, e.g. from national associations or standards bodies. Use the author property to indicate the rating organization, e.g. as an Organization with name such as (e.g. HOTREC, DEHOGA, WHR, or Hotelstars).
+    ///
+    /// `schema:starRating` (`STAR_RATING`)
+    pub static ref STAR_RATING: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "starRating");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// startDate
+    ///
+    /// The start date and time of the item (in ISO 8601 date format).
+    ///
+    /// `schema:startDate` (`START_DATE`)
+    pub static ref START_DATE: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "startDate");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// startOffset
+    ///
+    /// The start time of the clip expressed as the number of seconds from the beginning of the work.
+    ///
+    /// `schema:startOffset` (`START_OFFSET`)
+    pub static ref START_OFFSET: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "startOffset");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// startTime
+    ///
+    /// The startTime of something. For a reserved event or service (e.g. FoodEstablishmentReservation), the time that it is expected to start. For actions that span a period of time, when the action was performed. E.g. John wrote a book from January to December.
+    ///
+    /// `schema:startTime` (`START_TIME`)
+    pub static ref START_TIME: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "startTime");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// statType
+    ///
+    /// Indicates the kind of statistic represented by a StatisticalVariable, e.g. mean, count etc. The value of statType is a property, either from within Schema.org (e.g. count, median, marginOfError, maxValue, minValue) or from other compatible (e.g. RDF) systems such as DataCommons.org or Wikidata.org.
+    ///
+    /// `schema:statType` (`STAT_TYPE`)
+    pub static ref STAT_TYPE: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "statType");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// status
+    ///
+    /// The status of the study (enumerated).
+    ///
+    /// `schema:status` (`STATUS`)
+    pub static ref STATUS: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "status");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// steeringPosition
+    ///
+    /// The position of the steering wheel or similar device (mostly for cars).
+    ///
+    /// `schema:steeringPosition` (`STEERING_POSITION`)
+    pub static ref STEERING_POSITION: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "steeringPosition");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// step
+    ///
+    /// A single step item (as HowToStep, text, document, video, etc.) or a HowToSection.
+    ///
+    /// `schema:step` (`STEP`)
+    pub static ref STEP: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "step");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// stepValue
+    ///
+    /// The stepValue attribute indicates the granularity that is expected (and required) of the value in a PropertyValueSpecification.
+    ///
+    /// `schema:stepValue` (`STEP_VALUE`)
+    pub static ref STEP_VALUE: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "stepValue");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// steps
+    ///
+    /// A single step item (as HowToStep, text, document, video, etc.) or a HowToSection (originally misnamed 'steps'; 'step' is preferred).
+    ///
+    /// `schema:steps` (`STEPS`)
+    pub static ref STEPS: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "steps");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// storageRequirements
+    ///
+    /// Storage requirements (free space required).
+    ///
+    /// `schema:storageRequirements` (`STORAGE_REQUIREMENTS`)
+    pub static ref STORAGE_REQUIREMENTS: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "storageRequirements");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// streetAddress
+    ///
+    /// The street address. For example, 1600 Amphitheatre Pkwy.
+    ///
+    /// `schema:streetAddress` (`STREET_ADDRESS`)
+    pub static ref STREET_ADDRESS: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "streetAddress");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// strengthUnit
+    ///
+    /// The units of an active ingredient's strength, e.g. mg.
+    ///
+    /// `schema:strengthUnit` (`STRENGTH_UNIT`)
+    pub static ref STRENGTH_UNIT: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "strengthUnit");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// strengthValue
+    ///
+    /// The value of an active ingredient's strength, e.g. 325.
+    ///
+    /// `schema:strengthValue` (`STRENGTH_VALUE`)
+    pub static ref STRENGTH_VALUE: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "strengthValue");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// structuralClass
+    ///
+    /// The name given to how bone physically connects to each other.
+    ///
+    /// `schema:structuralClass` (`STRUCTURAL_CLASS`)
+    pub static ref STRUCTURAL_CLASS: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "structuralClass");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// study
+    ///
+    /// A medical study or trial related to this entity.
+    ///
+    /// `schema:study` (`STUDY`)
+    pub static ref STUDY: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "study");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// studyDesign
+    ///
+    /// Specifics about the observational study design (enumerated).
+    ///
+    /// `schema:studyDesign` (`STUDY_DESIGN`)
+    pub static ref STUDY_DESIGN: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "studyDesign");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// studyLocation
+    ///
+    /// The location in which the study is taking/took place.
+    ///
+    /// `schema:studyLocation` (`STUDY_LOCATION`)
+    pub static ref STUDY_LOCATION: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "studyLocation");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// studySubject
+    ///
+    /// A subject of the study, i.e. one of the medical conditions, therapies, devices, drugs, etc. investigated by the study.
+    ///
+    /// `schema:studySubject` (`STUDY_SUBJECT`)
+    pub static ref STUDY_SUBJECT: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "studySubject");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// subEvent
+    ///
+    /// An Event that is part of this event. For example, a conference event includes many presentations, each of which is a subEvent of the conference.
+    ///
+    /// `schema:subEvent` (`SUB_EVENT`)
+    pub static ref SUB_EVENT: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "subEvent");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// subEvents
+    ///
+    /// Events that are a part of this event. For example, a conference event includes many presentations, each subEvents of the conference.
+    ///
+    /// `schema:subEvents` (`SUB_EVENTS`)
+    pub static ref SUB_EVENTS: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "subEvents");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// subOrganization
+    ///
+    /// A relationship between two organizations where the first includes the second, e.g., as a subsidiary.
+    ///
+    /// `schema:subOrganization` (`SUB_ORGANIZATION`)
+    pub static ref SUB_ORGANIZATION: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "subOrganization");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// subReservation
+    ///
+    /// The individual reservations included in the package. Typically a repeated property.
+    ///
+    /// `schema:subReservation` (`SUB_RESERVATION`)
+    pub static ref SUB_RESERVATION: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "subReservation");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// subStageSuffix
+    ///
+    /// The substage, e.g. 'a' for Stage IIIa.
+    ///
+    /// `schema:subStageSuffix` (`SUB_STAGE_SUFFIX`)
+    pub static ref SUB_STAGE_SUFFIX: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "subStageSuffix");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// subStructure
+    ///
+    /// Component (sub-)structure(s) that comprise this anatomical structure.
+    ///
+    /// `schema:subStructure` (`SUB_STRUCTURE`)
+    pub static ref SUB_STRUCTURE: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "subStructure");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// subTest
+    ///
+    /// A component test of the panel.
+    ///
+    /// `schema:subTest` (`SUB_TEST`)
+    pub static ref SUB_TEST: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "subTest");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// subTrip
+    ///
+    /// Identifies a Trip that is a subTrip of this Trip. For example Day 1, Day 2, etc. of a multi-day trip.
+    ///
+    /// `schema:subTrip` (`SUB_TRIP`)
+    pub static ref SUB_TRIP: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "subTrip");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// subjectOf
+    ///
+    /// A CreativeWork or Event about this Thing.
+    ///
+    /// `schema:subjectOf` (`SUBJECT_OF`)
+    pub static ref SUBJECT_OF: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "subjectOf");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// subtitleLanguage
+    ///
+    /// Languages in which subtitles/captions are available, in IETF BCP 47 standard format.
+    ///
+    /// `schema:subtitleLanguage` (`SUBTITLE_LANGUAGE`)
+    pub static ref SUBTITLE_LANGUAGE: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "subtitleLanguage");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// successorOf
+    ///
+    /// A pointer from a newer variant of a product to its previous, often discontinued predecessor.
+    ///
+    /// `schema:successorOf` (`SUCCESSOR_OF`)
+    pub static ref SUCCESSOR_OF: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "successorOf");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// sugarContent
+    ///
+    /// The number of grams of sugar.
+    ///
+    /// `schema:sugarContent` (`SUGAR_CONTENT`)
+    pub static ref SUGAR_CONTENT: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "sugarContent");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// suggestedAge
+    ///
+    /// The age or age range for the intended audience or person, for example 3-12 months for infants, 1-5 years for toddlers.
+    ///
+    /// `schema:suggestedAge` (`SUGGESTED_AGE`)
+    pub static ref SUGGESTED_AGE: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "suggestedAge");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// suggestedAnswer
+    ///
+    /// An answer (possibly one of several, possibly incorrect) to a Question, e.g. on a Question/Answer site.
+    ///
+    /// `schema:suggestedAnswer` (`SUGGESTED_ANSWER`)
+    pub static ref SUGGESTED_ANSWER: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "suggestedAnswer");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// suggestedGender
+    ///
+    /// The suggested gender of the intended person or audience, for example "male", "female", or "unisex".
+    ///
+    /// `schema:suggestedGender` (`SUGGESTED_GENDER`)
+    pub static ref SUGGESTED_GENDER: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "suggestedGender");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// suggestedMaxAge
+    ///
+    /// Maximum recommended age in years for the audience or user.
+    ///
+    /// `schema:suggestedMaxAge` (`SUGGESTED_MAX_AGE`)
+    pub static ref SUGGESTED_MAX_AGE: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "suggestedMaxAge");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// suggestedMeasurement
+    ///
+    /// A suggested range of body measurements for the intended audience or person, for example inseam between 32 and 34 inches or height between 170 and 190 cm. Typically found on a size chart for wearable products.
+    ///
+    /// `schema:suggestedMeasurement` (`SUGGESTED_MEASUREMENT`)
+    pub static ref SUGGESTED_MEASUREMENT: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "suggestedMeasurement");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// suggestedMinAge
+    ///
+    /// Minimum recommended age in years for the audience or user.
+    ///
+    /// `schema:suggestedMinAge` (`SUGGESTED_MIN_AGE`)
+    pub static ref SUGGESTED_MIN_AGE: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "suggestedMinAge");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// suitableForDiet
+    ///
+    /// Indicates a dietary restriction or guideline for which this recipe or menu item is suitable, e.g. diabetic, halal etc.
+    ///
+    /// `schema:suitableForDiet` (`SUITABLE_FOR_DIET`)
+    pub static ref SUITABLE_FOR_DIET: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "suitableForDiet");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// superEvent
+    ///
+    /// An event that this event is a part of. For example, a collection of individual music performances might each have a music festival as their superEvent.
+    ///
+    /// `schema:superEvent` (`SUPER_EVENT`)
+    pub static ref SUPER_EVENT: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "superEvent");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// supersededBy
+    ///
+    /// Relates a term (i.e. a property, class or enumeration) to one that supersedes it.
+    ///
+    /// `schema:supersededBy` (`SUPERSEDED_BY`)
+    pub static ref SUPERSEDED_BY: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "supersededBy");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// supply
+    ///
+    /// A sub-property of instrument. A supply consumed when performing instructions or a direction.
+    ///
+    /// `schema:supply` (`SUPPLY`)
+    pub static ref SUPPLY: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "supply");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// supplyTo
+    ///
+    /// The area to which the artery supplies blood.
+    ///
+    /// `schema:supplyTo` (`SUPPLY_TO`)
+    pub static ref SUPPLY_TO: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "supplyTo");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// supportingData
+    ///
+    /// Supporting data for a SoftwareApplication.
+    ///
+    /// `schema:supportingData` (`SUPPORTING_DATA`)
+    pub static ref SUPPORTING_DATA: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "supportingData");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// surface
+    ///
+    /// A material used as a surface in some artwork, e.g. Canvas, Paper, Wood, Board, etc.
+    ///
+    /// `schema:surface` (`SURFACE`)
+    pub static ref SURFACE: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "surface");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// syllabusSections
+    ///
+    /// Indicates (typically several) Syllabus entities that lay out what each section of the overall course will cover.
+    ///
+    /// `schema:syllabusSections` (`SYLLABUS_SECTIONS`)
+    pub static ref SYLLABUS_SECTIONS: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "syllabusSections");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// target
+    ///
+    /// Indicates a target EntryPoint, or url, for an Action.
+    ///
+    /// `schema:target` (`TARGET`)
+    pub static ref TARGET: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "target");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// targetCollection
+    ///
+    /// A sub property of object. The collection target of the action.
+    ///
+    /// `schema:targetCollection` (`TARGET_COLLECTION`)
+    pub static ref TARGET_COLLECTION: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "targetCollection");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// targetDescription
+    ///
+    /// The description of a node in an established educational framework.
+    ///
+    /// `schema:targetDescription` (`TARGET_DESCRIPTION`)
+    pub static ref TARGET_DESCRIPTION: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "targetDescription");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// targetName
+    ///
+    /// The name of a node in an established educational framework.
+    ///
+    /// `schema:targetName` (`TARGET_NAME`)
+    pub static ref TARGET_NAME: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "targetName");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// targetPlatform
+    ///
+    /// Type of app development: phone, Metro style, desktop, XBox, etc.
+    ///
+    /// `schema:targetPlatform` (`TARGET_PLATFORM`)
+    pub static ref TARGET_PLATFORM: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "targetPlatform");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// targetPopulation
+    ///
+    /// Characteristics of the population for which this is intended, or which typically uses it, e.g. 'adults'.
+    ///
+    /// `schema:targetPopulation` (`TARGET_POPULATION`)
+    pub static ref TARGET_POPULATION: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "targetPopulation");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// targetProduct
+    ///
+    /// Target Operating System / Product to which the code applies. If applies to several versions, just the product name can be used.
+    ///
+    /// `schema:targetProduct` (`TARGET_PRODUCT`)
+    pub static ref TARGET_PRODUCT: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "targetProduct");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// targetUrl
+    ///
+    /// The URL of a node in an established educational framework.
+    ///
+    /// `schema:targetUrl` (`TARGET_URL`)
+    pub static ref TARGET_URL: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "targetUrl");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// taxID
+    ///
+    /// The Tax / Fiscal ID of the organization or person, e.g. the TIN in the US or the CIF/NIF in Spain.
+    ///
+    /// `schema:taxID` (`TAX_ID`)
+    pub static ref TAX_ID: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "taxID");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// taxonRank
+    ///
+    /// The taxonomic rank of this taxon given preferably as a URI from a controlled vocabulary – typically the ranks from TDWG TaxonRank ontology or equivalent Wikidata URIs.
+    ///
+    /// `schema:taxonRank` (`TAXON_RANK`)
+    pub static ref TAXON_RANK: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "taxonRank");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// taxonomicRange
+    ///
+    /// The taxonomic grouping of the organism that expresses, encodes, or in some way related to the BioChemEntity.
+    ///
+    /// `schema:taxonomicRange` (`TAXONOMIC_RANGE`)
+    pub static ref TAXONOMIC_RANGE: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "taxonomicRange");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// teaches
+    ///
+    /// The item being described is intended to help a person learn the competency or learning outcome defined by the referenced term.
+    ///
+    /// `schema:teaches` (`TEACHES`)
+    pub static ref TEACHES: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "teaches");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// telephone
+    ///
+    /// The telephone number.
+    ///
+    /// `schema:telephone` (`TELEPHONE`)
+    pub static ref TELEPHONE: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "telephone");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// temporal
+    ///
+    /// The "temporal" property can be used in cases where more specific properties (e.g. temporalCoverage, dateCreated, dateModified, datePublished) are not known to be appropriate.
+    ///
+    /// `schema:temporal` (`TEMPORAL`)
+    pub static ref TEMPORAL: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "temporal");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// temporalCoverage
+    ///
+    /// The temporalCoverage of a CreativeWork indicates the period that the content applies to, i.e. that it describes, either as a DateTime or as a textual string indicating a time period in ISO 8601 time interval format. In the case of a Dataset it will typically indicate the relevant time period in a precise notation (e.g. for a 2011 census dataset, the year 2011 would be written "2011/2012").
+    ///
+    /// `schema:temporalCoverage` (`TEMPORAL_COVERAGE`)
+    pub static ref TEMPORAL_COVERAGE: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "temporalCoverage");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// termCode
+    ///
+    /// A code that identifies this DefinedTerm within a DefinedTermSet.
+    ///
+    /// `schema:termCode` (`TERM_CODE`)
+    pub static ref TERM_CODE: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "termCode");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// termDuration
+    ///
+    /// The amount of time in a term as defined by the institution. A term is a length of time where students take one or more classes. Semesters and quarters are common units for term.
+    ///
+    /// `schema:termDuration` (`TERM_DURATION`)
+    pub static ref TERM_DURATION: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "termDuration");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// termsOfService
+    ///
+    /// Human-readable terms of service documentation.
+    ///
+    /// `schema:termsOfService` (`TERMS_OF_SERVICE`)
+    pub static ref TERMS_OF_SERVICE: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "termsOfService");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// termsPerYear
+    ///
+    /// The number of times terms of study are offered per year. Semesters and quarters are common units for term. For example, if the student can only take 2 semesters for the program in one year, then termsPerYear should be 2.
+    ///
+    /// `schema:termsPerYear` (`TERMS_PER_YEAR`)
+    pub static ref TERMS_PER_YEAR: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "termsPerYear");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// text
+    ///
+    /// The textual content of this CreativeWork.
+    ///
+    /// `schema:text` (`TEXT_PROPERTY`)
+    pub static ref TEXT_PROPERTY: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "text");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// textValue
+    ///
+    /// Text value being annotated.
+    ///
+    /// `schema:textValue` (`TEXT_VALUE`)
+    pub static ref TEXT_VALUE: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "textValue");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// thumbnail
+    ///
+    /// Thumbnail image for an image or video.
+    ///
+    /// `schema:thumbnail` (`THUMBNAIL`)
+    pub static ref THUMBNAIL: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "thumbnail");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// thumbnailUrl
+    ///
+    /// A thumbnail image relevant to the Thing.
+    ///
+    /// `schema:thumbnailUrl` (`THUMBNAIL_URL`)
+    pub static ref THUMBNAIL_URL: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "thumbnailUrl");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// tickerSymbol
+    ///
+    /// The exchange traded instrument associated with a Corporation object. The tickerSymbol is expressed as an exchange and an instrument name separated by a space character.
+    ///
+    /// `schema:tickerSymbol` (`TICKER_SYMBOL`)
+    pub static ref TICKER_SYMBOL: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "tickerSymbol");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// ticketNumber
+    ///
+    /// The unique identifier for the ticket.
+    ///
+    /// `schema:ticketNumber` (`TICKET_NUMBER`)
+    pub static ref TICKET_NUMBER: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "ticketNumber");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// ticketToken
+    ///
+    /// Reference to an asset (e.g., Barcode, QR code image or PDF) usable for entrance.
+    ///
+    /// `schema:ticketToken` (`TICKET_TOKEN`)
+    pub static ref TICKET_TOKEN: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "ticketToken");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// ticketedSeat
+    ///
+    /// The seat associated with the ticket.
+    ///
+    /// `schema:ticketedSeat` (`TICKETED_SEAT`)
+    pub static ref TICKETED_SEAT: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "ticketedSeat");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// timeOfDay
+    ///
+    /// The time of day the program normally runs. For example, "evenings".
+    ///
+    /// `schema:timeOfDay` (`TIME_OF_DAY`)
+    pub static ref TIME_OF_DAY: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "timeOfDay");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// timeRequired
+    ///
+    /// Approximate or typical time it usually takes to work with or through the content of this work for the typical or target audience.
+    ///
+    /// `schema:timeRequired` (`TIME_REQUIRED`)
+    pub static ref TIME_REQUIRED: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "timeRequired");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// timeToComplete
+    ///
+    /// The expected length of time to complete the program if attending full-time.
+    ///
+    /// `schema:timeToComplete` (`TIME_TO_COMPLETE`)
+    pub static ref TIME_TO_COMPLETE: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "timeToComplete");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// tissueSample
+    ///
+    /// The type of tissue sample required for the test.
+    ///
+    /// `schema:tissueSample` (`TISSUE_SAMPLE`)
+    pub static ref TISSUE_SAMPLE: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "tissueSample");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// title
+    ///
+    /// The title of the job.
+    ///
+    /// `schema:title` (`TITLE`)
+    pub static ref TITLE: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "title");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// titleEIDR
+    ///
+    /// An EIDR (Entertainment Identifier Registry) identifier representing at the most general/abstract level, a work of film or television.
+    ///
+    /// `schema:titleEIDR` (`TITLE_EIDR`)
+    pub static ref TITLE_EIDR: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "titleEIDR");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// toLocation
+    ///
+    /// A sub property of location. The final location of the object or the agent after the action.
+    ///
+    /// `schema:toLocation` (`TO_LOCATION`)
+    pub static ref TO_LOCATION: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "toLocation");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// toRecipient
+    ///
+    /// A sub property of recipient. The recipient who was directly sent the message.
+    ///
+    /// `schema:toRecipient` (`TO_RECIPIENT`)
+    pub static ref TO_RECIPIENT: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "toRecipient");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// tocContinuation
+    ///
+    /// A HyperTocEntry can have a tocContinuation indicated, which is another HyperTocEntry that would be the default next item to play or render.
+    ///
+    /// `schema:tocContinuation` (`TOC_CONTINUATION`)
+    pub static ref TOC_CONTINUATION: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "tocContinuation");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// tocEntry
+    ///
+    /// Indicates a HyperTocEntry in a HyperToc.
+    ///
+    /// `schema:tocEntry` (`TOC_ENTRY`)
+    pub static ref TOC_ENTRY: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "tocEntry");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// tongueWeight
+    ///
+    /// The permitted vertical load (TWR) of a trailer attached to the vehicle. Also referred to as Tongue Load Rating (TLR) or Vertical Load Rating (VLR).
+    ///
+    /// `schema:tongueWeight` (`TONGUE_WEIGHT`)
+    pub static ref TONGUE_WEIGHT: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "tongueWeight");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// tool
+    ///
+    /// A sub property of instrument. An object used (but not consumed) when performing instructions or a direction.
+    ///
+    /// `schema:tool` (`TOOL`)
+    pub static ref TOOL: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "tool");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// torque
+    ///
+    /// The torque (turning force) of the vehicle's engine.
+    ///
+    /// `schema:torque` (`TORQUE`)
+    pub static ref TORQUE: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "torque");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// totalHistoricalEnrollment
+    ///
+    /// The total number of students that have enrolled in the history of the course.
+    ///
+    /// `schema:totalHistoricalEnrollment` (`TOTAL_HISTORICAL_ENROLLMENT`)
+    pub static ref TOTAL_HISTORICAL_ENROLLMENT: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "totalHistoricalEnrollment");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// totalJobOpenings
+    ///
+    /// The number of positions open for this job posting. Use a positive integer. Do not use if the number of positions is unclear or not known.
+    ///
+    /// `schema:totalJobOpenings` (`TOTAL_JOB_OPENINGS`)
+    pub static ref TOTAL_JOB_OPENINGS: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "totalJobOpenings");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// totalPaymentDue
+    ///
+    /// The total amount due.
+    ///
+    /// `schema:totalPaymentDue` (`TOTAL_PAYMENT_DUE`)
+    pub static ref TOTAL_PAYMENT_DUE: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "totalPaymentDue");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// totalPrice
+    ///
+    /// The total price for the reservation or ticket, including applicable taxes, shipping, etc.
+    ///
+    /// `schema:totalPrice` (`TOTAL_PRICE`)
+    pub static ref TOTAL_PRICE: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "totalPrice");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// totalTime
+    ///
+    /// The total time required to perform instructions or a direction (including time to prepare the supplies), in ISO 8601 duration format.
+    ///
+    /// `schema:totalTime` (`TOTAL_TIME`)
+    pub static ref TOTAL_TIME: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "totalTime");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// tourBookingPage
+    ///
+    /// A page providing information on how to book a tour of some Place, such as an Accommodation or ApartmentComplex in a real estate setting, as well as other kinds of tours as appropriate.
+    ///
+    /// `schema:tourBookingPage` (`TOUR_BOOKING_PAGE`)
+    pub static ref TOUR_BOOKING_PAGE: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "tourBookingPage");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// touristType
+    ///
+    /// Attraction suitable for type(s) of tourist. E.g. children, visitors from a particular country, etc.
+    ///
+    /// `schema:touristType` (`TOURIST_TYPE`)
+    pub static ref TOURIST_TYPE: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "touristType");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// track
+    ///
+    /// A music recording (track)—usually a single song. If an ItemList is given, the list should contain items of type MusicRecording.
+    ///
+    /// `schema:track` (`TRACK`)
+    pub static ref TRACK: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "track");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// trackingNumber
+    ///
+    /// Shipper tracking number.
+    ///
+    /// `schema:trackingNumber` (`TRACKING_NUMBER`)
+    pub static ref TRACKING_NUMBER: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "trackingNumber");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// trackingUrl
+    ///
+    /// Tracking url for the parcel delivery.
+    ///
+    /// `schema:trackingUrl` (`TRACKING_URL`)
+    pub static ref TRACKING_URL: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "trackingUrl");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// tracks
+    ///
+    /// A music recording (track)—usually a single song.
+    ///
+    /// `schema:tracks` (`TRACKS`)
+    pub static ref TRACKS: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "tracks");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// trailer
+    ///
+    /// The trailer of a movie or TV/radio series, season, episode, etc.
+    ///
+    /// `schema:trailer` (`TRAILER`)
+    pub static ref TRAILER: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "trailer");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// trailerWeight
+    ///
+    /// The permitted weight of a trailer attached to the vehicle.
+    ///
+    /// `schema:trailerWeight` (`TRAILER_WEIGHT`)
+    pub static ref TRAILER_WEIGHT: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "trailerWeight");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// trainName
+    ///
+    /// The name of the train (e.g. The Orient Express).
+    ///
+    /// `schema:trainName` (`TRAIN_NAME`)
+    pub static ref TRAIN_NAME: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "trainName");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// trainNumber
+    ///
+    /// The unique identifier for the train.
+    ///
+    /// `schema:trainNumber` (`TRAIN_NUMBER`)
+    pub static ref TRAIN_NUMBER: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "trainNumber");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// trainingSalary
+    ///
+    /// The estimated salary earned while in the program.
+    ///
+    /// `schema:trainingSalary` (`TRAINING_SALARY`)
+    pub static ref TRAINING_SALARY: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "trainingSalary");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// transFatContent
+    ///
+    /// The number of grams of trans fat.
+    ///
+    /// `schema:transFatContent` (`TRANS_FAT_CONTENT`)
+    pub static ref TRANS_FAT_CONTENT: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "transFatContent");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// transcript
+    ///
+    /// If this MediaObject is an AudioObject or VideoObject, the transcript of that object.
+    ///
+    /// `schema:transcript` (`TRANSCRIPT`)
+    pub static ref TRANSCRIPT: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "transcript");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// transitTime
+    ///
+    /// The typical delay the order has been sent for delivery and the goods reach the final customer.
+    ///
+    /// `schema:transitTime` (`TRANSIT_TIME`)
+    pub static ref TRANSIT_TIME: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "transitTime");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// transitTimeLabel
+    ///
+    /// Label to match an OfferShippingDetails with a DeliveryTimeSettings (within the context of a shippingSettingsLink cross-reference).
+    ///
+    /// `schema:transitTimeLabel` (`TRANSIT_TIME_LABEL`)
+    pub static ref TRANSIT_TIME_LABEL: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "transitTimeLabel");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// translationOfWork
+    ///
+    /// The work that this work has been translated from. E.g. 物种起源 is a translationOf “On the Origin of Species”.
+    ///
+    /// `schema:translationOfWork` (`TRANSLATION_OF_WORK`)
+    pub static ref TRANSLATION_OF_WORK: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "translationOfWork");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// translator
+    ///
+    /// Organization or person who adapts a creative work to different languages, regional differences and technical requirements of a target market, or that translates during some event.
+    ///
+    /// `schema:translator` (`TRANSLATOR`)
+    pub static ref TRANSLATOR: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "translator");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// transmissionMethod
+    ///
+    /// How the disease spreads, either as a route or vector, for example 'direct contact', 'Aedes aegypti', etc.
+    ///
+    /// `schema:transmissionMethod` (`TRANSMISSION_METHOD`)
+    pub static ref TRANSMISSION_METHOD: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "transmissionMethod");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// travelBans
+    ///
+    /// Information about travel bans, e.g. in the context of a pandemic.
+    ///
+    /// `schema:travelBans` (`TRAVEL_BANS`)
+    pub static ref TRAVEL_BANS: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "travelBans");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// trialDesign
+    ///
+    /// Specifics about the trial design (enumerated).
+    ///
+    /// `schema:trialDesign` (`TRIAL_DESIGN`)
+    pub static ref TRIAL_DESIGN: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "trialDesign");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// tributary
+    ///
+    /// The anatomical or organ system that the vein flows into; a larger structure that the vein connects to.
+    ///
+    /// `schema:tributary` (`TRIBUTARY`)
+    pub static ref TRIBUTARY: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "tributary");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// tripOrigin
+    ///
+    /// The location of origin of the trip, prior to any destination(s).
+    ///
+    /// `schema:tripOrigin` (`TRIP_ORIGIN`)
+    pub static ref TRIP_ORIGIN: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "tripOrigin");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// typeOfBed
+    ///
+    /// The type of bed to which the BedDetail refers, i.e. the type of bed available in the quantity indicated by quantity.
+    ///
+    /// `schema:typeOfBed` (`TYPE_OF_BED`)
+    pub static ref TYPE_OF_BED: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "typeOfBed");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// typeOfGood
+    ///
+    /// The product that this structured value is referring to.
+    ///
+    /// `schema:typeOfGood` (`TYPE_OF_GOOD`)
+    pub static ref TYPE_OF_GOOD: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "typeOfGood");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// typicalAgeRange
+    ///
+    /// The typical expected age range, e.g. '7-9', '11-'.
+    ///
+    /// `schema:typicalAgeRange` (`TYPICAL_AGE_RANGE`)
+    pub static ref TYPICAL_AGE_RANGE: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "typicalAgeRange");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// typicalCreditsPerTerm
+    ///
+    /// The number of credits or units a full-time student would be expected to take in 1 term however 'term' is defined by the institution.
+    ///
+    /// `schema:typicalCreditsPerTerm` (`TYPICAL_CREDITS_PER_TERM`)
+    pub static ref TYPICAL_CREDITS_PER_TERM: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "typicalCreditsPerTerm");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// typicalTest
+    ///
+    /// A medical test typically performed given this condition.
+    ///
+    /// `schema:typicalTest` (`TYPICAL_TEST`)
+    pub static ref TYPICAL_TEST: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "typicalTest");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// underName
+    ///
+    /// The person or organization the reservation or ticket is for.
+    ///
+    /// `schema:underName` (`UNDER_NAME`)
+    pub static ref UNDER_NAME: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "underName");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// unitCode
+    ///
+    /// The unit of measurement given using the UN/CEFACT Common Code (3 characters) or a URL. Other codes than the UN/CEFACT Common Code may be used with a prefix followed by a colon.
+    ///
+    /// `schema:unitCode` (`UNIT_CODE`)
+    pub static ref UNIT_CODE: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "unitCode");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// unitText
+    ///
+    /// A string or text indicating the unit of measurement. Useful if you cannot provide a standard unit code for unitCode.
+    ///
+    /// `schema:unitText` (`UNIT_TEXT`)
+    pub static ref UNIT_TEXT: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "unitText");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// unnamedSourcesPolicy
+    ///
+    /// For an Organization (typically a NewsMediaOrganization), a statement about policy on use of unnamed sources and the decision process required.
+    ///
+    /// `schema:unnamedSourcesPolicy` (`UNNAMED_SOURCES_POLICY`)
+    pub static ref UNNAMED_SOURCES_POLICY: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "unnamedSourcesPolicy");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// unsaturatedFatContent
+    ///
+    /// The number of grams of unsaturated fat.
+    ///
+    /// `schema:unsaturatedFatContent` (`UNSATURATED_FAT_CONTENT`)
+    pub static ref UNSATURATED_FAT_CONTENT: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "unsaturatedFatContent");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// uploadDate
+    ///
+    /// Date (including time if available) when this media object was uploaded to this site.
+    ///
+    /// `schema:uploadDate` (`UPLOAD_DATE`)
+    pub static ref UPLOAD_DATE: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "uploadDate");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// upvoteCount
+    ///
+    /// The number of upvotes this question, answer or comment has received from the community.
+    ///
+    /// `schema:upvoteCount` (`UPVOTE_COUNT`)
+    pub static ref UPVOTE_COUNT: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "upvoteCount");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// url
+    ///
+    /// URL of the item.
+    ///
+    /// `schema:url` (`URL_PROPERTY`)
+    pub static ref URL_PROPERTY: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "url");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// urlTemplate
+    ///
+    /// An url template (RFC6570) that will be used to construct the target of the execution of the action.
+    ///
+    /// `schema:urlTemplate` (`URL_TEMPLATE`)
+    pub static ref URL_TEMPLATE: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "urlTemplate");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// usNPI
+    ///
+    /// A National Provider Identifier (NPI) is a unique 10-digit identification number issued to health care providers in the United States by the Centers for Medicare and Medicaid Services.
+    ///
+    /// `schema:usNPI` (`US_NPI`)
+    pub static ref US_NPI: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "usNPI");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// usageInfo
+    ///
+    /// The schema.org usageInfo property indicates further information about a CreativeWork. This information may be related to the licenses under which the work is made available, or to the permissions the viewer has to use the work.
+    ///
+    /// `schema:usageInfo` (`USAGE_INFO`)
+    pub static ref USAGE_INFO: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "usageInfo");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// usedToDiagnose
+    ///
+    /// A condition the test is used to diagnose.
+    ///
+    /// `schema:usedToDiagnose` (`USED_TO_DIAGNOSE`)
+    pub static ref USED_TO_DIAGNOSE: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "usedToDiagnose");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// userInteractionCount
+    ///
+    /// The number of interactions for the CreativeWork using the WebSite or SoftwareApplication.
+    ///
+    /// `schema:userInteractionCount` (`USER_INTERACTION_COUNT`)
+    pub static ref USER_INTERACTION_COUNT: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "userInteractionCount");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// usesDevice
+    ///
+    /// Device used to perform the test.
+    ///
+    /// `schema:usesDevice` (`USES_DEVICE`)
+    pub static ref USES_DEVICE: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "usesDevice");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// usesHealthPlanIdStandard
+    ///
+    /// The standard for interpreting the Plan ID. The preferred is "HIOS".
+    ///
+    /// `schema:usesHealthPlanIdStandard` (`USES_HEALTH_PLAN_ID_STANDARD`)
+    pub static ref USES_HEALTH_PLAN_ID_STANDARD: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "usesHealthPlanIdStandard");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// utterances
+    ///
+    /// Text of an utterances (spoken words, lyrics etc.) that occurs at a certain section of a media object, represented as a HyperTocEntry.
+    ///
+    /// `schema:utterances` (`UTTERANCES`)
+    pub static ref UTTERANCES: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "utterances");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// validFor
+    ///
+    /// The duration of validity of a permit or similar thing.
+    ///
+    /// `schema:validFor` (`VALID_FOR`)
+    pub static ref VALID_FOR: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "validFor");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// validForMemberTier
+    ///
+    /// The membership program tier an Offer (or a PriceSpecification, OfferShippingDetails, or MerchantReturnPolicy under an Offer) is valid for.
+    ///
+    /// `schema:validForMemberTier` (`VALID_FOR_MEMBER_TIER`)
+    pub static ref VALID_FOR_MEMBER_TIER: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "validForMemberTier");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// validFrom
+    ///
+    /// The date when the item becomes valid.
+    ///
+    /// `schema:validFrom` (`VALID_FROM`)
+    pub static ref VALID_FROM: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "validFrom");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// validIn
+    ///
+    /// The geographic area where the item is valid. Applies for example to a Permit, a Certification, or an EducationalOccupationalCredential.
+    ///
+    /// `schema:validIn` (`VALID_IN`)
+    pub static ref VALID_IN: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "validIn");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// validThrough
+    ///
+    /// The date after when the item is not valid. For example the end of an offer, salary period, or a period of opening hours.
+    ///
+    /// `schema:validThrough` (`VALID_THROUGH`)
+    pub static ref VALID_THROUGH: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "validThrough");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// validUntil
+    ///
+    /// The date when the item is no longer valid.
+    ///
+    /// `schema:validUntil` (`VALID_UNTIL`)
+    pub static ref VALID_UNTIL: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "validUntil");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// value
+    ///
+    /// The value of a QuantitativeValue (including Observation) or property value node.
+    ///
+    /// `schema:value` (`VALUE`)
+    pub static ref VALUE: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "value");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// valueAddedTaxIncluded
+    ///
+    /// Specifies whether the applicable value-added tax (VAT) is included in the price specification or not.
+    ///
+    /// `schema:valueAddedTaxIncluded` (`VALUE_ADDED_TAX_INCLUDED`)
+    pub static ref VALUE_ADDED_TAX_INCLUDED: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "valueAddedTaxIncluded");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// valueMaxLength
+    ///
+    /// Specifies the allowed range for number of characters in a literal value.
+    ///
+    /// `schema:valueMaxLength` (`VALUE_MAX_LENGTH`)
+    pub static ref VALUE_MAX_LENGTH: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "valueMaxLength");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// valueMinLength
+    ///
+    /// Specifies the minimum allowed range for number of characters in a literal value.
+    ///
+    /// `schema:valueMinLength` (`VALUE_MIN_LENGTH`)
+    pub static ref VALUE_MIN_LENGTH: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "valueMinLength");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// valueName
+    ///
+    /// Indicates the name of the PropertyValueSpecification to be used in URL templates and form encoding in a manner analogous to HTML's input@name.
+    ///
+    /// `schema:valueName` (`VALUE_NAME`)
+    pub static ref VALUE_NAME: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "valueName");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// valuePattern
+    ///
+    /// Specifies a regular expression for testing literal values according to the HTML spec.
+    ///
+    /// `schema:valuePattern` (`VALUE_PATTERN`)
+    pub static ref VALUE_PATTERN: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "valuePattern");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// valueReference
+    ///
+    /// A secondary value that provides additional information on the original value, e.g. a reference temperature or a type of measurement.
+    ///
+    /// `schema:valueReference` (`VALUE_REFERENCE`)
+    pub static ref VALUE_REFERENCE: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "valueReference");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// valueRequired
+    ///
+    /// Whether the property must be filled in to complete the action. Default is false.
+    ///
+    /// `schema:valueRequired` (`VALUE_REQUIRED`)
+    pub static ref VALUE_REQUIRED: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "valueRequired");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// variableMeasured
+    ///
+    /// The variableMeasured property can indicate (repeated as necessary) the variables that are measured in some dataset, either described as text or as pairs of identifier and description using PropertyValue, or more explicitly as a StatisticalVariable.
+    ///
+    /// `schema:variableMeasured` (`VARIABLE_MEASURED`)
+    pub static ref VARIABLE_MEASURED: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "variableMeasured");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// variantCover
+    ///
+    /// A description of the variant cover for the issue, if the issue is a variant printing. For example, "Bryan Hitch Variant Cover" or "2nd Printing Variant".
+    ///
+    /// `schema:variantCover` (`VARIANT_COVER`)
+    pub static ref VARIANT_COVER: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "variantCover");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// variesBy
+    ///
+    /// Indicates the property or properties by which the variants in a ProductGroup vary, e.g. their size, color etc. Schema.org properties can be referenced by their short name e.g. "color"; terms defined elsewhere can be referenced with their URIs.
+    ///
+    /// `schema:variesBy` (`VARIES_BY`)
+    pub static ref VARIES_BY: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "variesBy");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// vatID
+    ///
+    /// The Value-added Tax ID of the organization or person.
+    ///
+    /// `schema:vatID` (`VAT_ID`)
+    pub static ref VAT_ID: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "vatID");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// vehicleConfiguration
+    ///
+    /// A short text indicating the configuration of the vehicle, e.g. '5dr hatchback ST 2.5 MT 225 hp' or 'limited edition'.
+    ///
+    /// `schema:vehicleConfiguration` (`VEHICLE_CONFIGURATION`)
+    pub static ref VEHICLE_CONFIGURATION: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "vehicleConfiguration");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// vehicleEngine
+    ///
+    /// Information about the engine or engines of the vehicle.
+    ///
+    /// `schema:vehicleEngine` (`VEHICLE_ENGINE`)
+    pub static ref VEHICLE_ENGINE: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "vehicleEngine");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// vehicleIdentificationNumber
+    ///
+    /// The Vehicle Identification Number (VIN) is a unique serial number used by the automotive industry to identify individual motor vehicles.
+    ///
+    /// `schema:vehicleIdentificationNumber` (`VEHICLE_IDENTIFICATION_NUMBER`)
+    pub static ref VEHICLE_IDENTIFICATION_NUMBER: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "vehicleIdentificationNumber");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// vehicleInteriorColor
+    ///
+    /// The color or color combination of the interior of the vehicle.
+    ///
+    /// `schema:vehicleInteriorColor` (`VEHICLE_INTERIOR_COLOR`)
+    pub static ref VEHICLE_INTERIOR_COLOR: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "vehicleInteriorColor");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// vehicleInteriorType
+    ///
+    /// The type or material of the interior of the vehicle (e.g. synthetic fabric, leather, wood, etc.). While most interior types are characterized by the material used, an interior type can also be based on vehicle usage or target audience.
+    ///
+    /// `schema:vehicleInteriorType` (`VEHICLE_INTERIOR_TYPE`)
+    pub static ref VEHICLE_INTERIOR_TYPE: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "vehicleInteriorType");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// vehicleModelDate
+    ///
+    /// The release date of a vehicle model (often used to differentiate versions of the same make and model).
+    ///
+    /// `schema:vehicleModelDate` (`VEHICLE_MODEL_DATE`)
+    pub static ref VEHICLE_MODEL_DATE: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "vehicleModelDate");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// vehicleSeatingCapacity
+    ///
+    /// The number of passengers that can be seated in the vehicle, both in terms of the physical space available, and in terms of limitations set by law.
+    ///
+    /// `schema:vehicleSeatingCapacity` (`VEHICLE_SEATING_CAPACITY`)
+    pub static ref VEHICLE_SEATING_CAPACITY: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "vehicleSeatingCapacity");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// vehicleSpecialUsage
+    ///
+    /// Indicates whether the vehicle has been used for special purposes, like commercial rental, driving school, or as a taxi. The legislation in many countries requires this information to be revealed when offering a car for sale.
+    ///
+    /// `schema:vehicleSpecialUsage` (`VEHICLE_SPECIAL_USAGE`)
+    pub static ref VEHICLE_SPECIAL_USAGE: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "vehicleSpecialUsage");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// vehicleTransmission
+    ///
+    /// The type of component used for transmitting the power from a rotating power source to the wheels or other relevant component(s) ("gearbox" for cars).
+    ///
+    /// `schema:vehicleTransmission` (`VEHICLE_TRANSMISSION`)
+    pub static ref VEHICLE_TRANSMISSION: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "vehicleTransmission");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// vendor
+    ///
+    /// 'vendor' is an earlier term for 'seller'.
+    ///
+    /// `schema:vendor` (`VENDOR`)
+    pub static ref VENDOR: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "vendor");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// verificationFactCheckingPolicy
+    ///
+    /// Disclosure about verification and fact-checking processes for a NewsMediaOrganization or other fact-checking Organization.
+    ///
+    /// `schema:verificationFactCheckingPolicy` (`VERIFICATION_FACT_CHECKING_POLICY`)
+    pub static ref VERIFICATION_FACT_CHECKING_POLICY: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "verificationFactCheckingPolicy");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// version
+    ///
+    /// The version of the CreativeWork embodied by a specified resource.
+    ///
+    /// `schema:version` (`VERSION`)
+    pub static ref VERSION: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "version");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// video
+    ///
+    /// An embedded video object.
+    ///
+    /// `schema:video` (`VIDEO`)
+    pub static ref VIDEO: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "video");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// videoFormat
+    ///
+    /// The type of screening or video broadcast used (e.g. IMAX, 3D, SD, HD, etc.).
+    ///
+    /// `schema:videoFormat` (`VIDEO_FORMAT`)
+    pub static ref VIDEO_FORMAT: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "videoFormat");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// videoFrameSize
+    ///
+    /// The frame size of the video.
+    ///
+    /// `schema:videoFrameSize` (`VIDEO_FRAME_SIZE`)
+    pub static ref VIDEO_FRAME_SIZE: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "videoFrameSize");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// videoQuality
+    ///
+    /// The quality of the video.
+    ///
+    /// `schema:videoQuality` (`VIDEO_QUALITY`)
+    pub static ref VIDEO_QUALITY: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "videoQuality");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// volumeNumber
+    ///
+    /// Identifies the volume of publication or multi-part work; for example, "iii" or "2".
+    ///
+    /// `schema:volumeNumber` (`VOLUME_NUMBER`)
+    pub static ref VOLUME_NUMBER: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "volumeNumber");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// warning
+    ///
+    /// Any FDA or other warnings about the drug (text or URL).
+    ///
+    /// `schema:warning` (`WARNING`)
+    pub static ref WARNING: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "warning");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// warranty
+    ///
+    /// The warranty promise(s) included in the offer.
+    ///
+    /// `schema:warranty` (`WARRANTY`)
+    pub static ref WARRANTY: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "warranty");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// warrantyPromise
+    ///
+    /// The warranty promise(s) included in the offer.
+    ///
+    /// `schema:warrantyPromise` (`WARRANTY_PROMISE_PROPERTY`)
+    pub static ref WARRANTY_PROMISE_PROPERTY: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "warrantyPromise");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// warrantyScope
+    ///
+    /// The scope of the warranty promise.
+    ///
+    /// `schema:warrantyScope` (`WARRANTY_SCOPE_PROPERTY`)
+    pub static ref WARRANTY_SCOPE_PROPERTY: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "warrantyScope");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// webCheckinTime
+    ///
+    /// The time when a passenger can check into the flight online.
+    ///
+    /// `schema:webCheckinTime` (`WEB_CHECKIN_TIME`)
+    pub static ref WEB_CHECKIN_TIME: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "webCheckinTime");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// webFeed
+    ///
+    /// The URL for a feed, e.g. associated with a podcast series, blog, or series of date-stamped updates. This is usually RSS or Atom.
+    ///
+    /// `schema:webFeed` (`WEB_FEED`)
+    pub static ref WEB_FEED: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "webFeed");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// weight
+    ///
+    /// The weight of the product or person.
+    ///
+    /// `schema:weight` (`WEIGHT`)
+    pub static ref WEIGHT: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "weight");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// weightPercentage
+    ///
+    /// The weight of the product as a percentage of the weight of the person or entity.
+    ///
+    /// `schema:weightPercentage` (`WEIGHT_PERCENTAGE`)
+    pub static ref WEIGHT_PERCENTAGE: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "weightPercentage");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// weightTotal
+    ///
+    /// The permitted total weight of the loaded vehicle, including passengers and cargo and the weight of the empty vehicle.
+    ///
+    /// `schema:weightTotal` (`WEIGHT_TOTAL`)
+    pub static ref WEIGHT_TOTAL: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "weightTotal");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// wheelbase
+    ///
+    /// The distance between the centers of the front and rear wheels.
+    ///
+    /// `schema:wheelbase` (`WHEELBASE`)
+    pub static ref WHEELBASE: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "wheelbase");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// width
+    ///
+    /// The width of the item.
+    ///
+    /// `schema:width` (`WIDTH`)
+    pub static ref WIDTH: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "width");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// winner
+    ///
+    /// A sub property of participant. The winner of the action.
+    ///
+    /// `schema:winner` (`WINNER`)
+    pub static ref WINNER: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "winner");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// wordCount
+    ///
+    /// The number of words in the text of the CreativeWork such as an Article, Book, etc.
+    ///
+    /// `schema:wordCount` (`WORD_COUNT`)
+    pub static ref WORD_COUNT: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "wordCount");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// workExample
+    ///
+    /// Example/instance/realization/derivation of the concept of this creative work. E.g. the paperback edition, first edition, or e-book.
+    ///
+    /// `schema:workExample` (`WORK_EXAMPLE`)
+    pub static ref WORK_EXAMPLE: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "workExample");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// workFeatured
+    ///
+    /// A work featured in some event, e.g. exhibited in an ExhibitionEvent. Specific subproperties are available for workPerformed (e.g. a play), or a workPresented (a Movie at a ScreeningEvent).
+    ///
+    /// `schema:workFeatured` (`WORK_FEATURED`)
+    pub static ref WORK_FEATURED: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "workFeatured");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// workHours
+    ///
+    /// The typical working hours for this job (e.g. 1st shift, night shift, 8am-5pm).
+    ///
+    /// `schema:workHours` (`WORK_HOURS`)
+    pub static ref WORK_HOURS: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "workHours");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// workLocation
+    ///
+    /// A contact location for a person's place of work.
+    ///
+    /// `schema:workLocation` (`WORK_LOCATION`)
+    pub static ref WORK_LOCATION: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "workLocation");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// workPerformed
+    ///
+    /// A work performed in some event, for example a play performed in a TheaterEvent.
+    ///
+    /// `schema:workPerformed` (`WORK_PERFORMED`)
+    pub static ref WORK_PERFORMED: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "workPerformed");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// workPresented
+    ///
+    /// The movie presented during this event.
+    ///
+    /// `schema:workPresented` (`WORK_PRESENTED`)
+    pub static ref WORK_PRESENTED: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "workPresented");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// workTranslation
+    ///
+    /// A work that is a translation of the content of this work. E.g. 西遊記 has an English workTranslation “Journey to the West”, a German workTranslation “Monkeys Pilgerfahrt” and a Vietnamese translation Tây du ký bình khảo.
+    ///
+    /// `schema:workTranslation` (`WORK_TRANSLATION`)
+    pub static ref WORK_TRANSLATION: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "workTranslation");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// workload
+    ///
+    /// Quantitative measure of the physiologic output of the exercise; also referred to as energy expenditure.
+    ///
+    /// `schema:workload` (`WORKLOAD`)
+    pub static ref WORKLOAD: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "workload");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// worksFor
+    ///
+    /// Organizations that the person works for.
+    ///
+    /// `schema:worksFor` (`WORKS_FOR`)
+    pub static ref WORKS_FOR: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "worksFor");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// worstRating
+    ///
+    /// The lowest value allowed in this rating system.
+    ///
+    /// `schema:worstRating` (`WORST_RATING`)
+    pub static ref WORST_RATING: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "worstRating");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// xpath
+    ///
+    /// An XPath, e.g. of a SpeakableSpecification or WebPageElement. In the latter case, multiple matches within a page can constitute a single conceptual "Web page element".
+    ///
+    /// `schema:xpath` (`XPATH`)
+    pub static ref XPATH: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "xpath");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// yearBuilt
+    ///
+    /// The year an Accommodation was constructed. This corresponds to the YearBuilt field in RESO.
+    ///
+    /// `schema:yearBuilt` (`YEAR_BUILT`)
+    pub static ref YEAR_BUILT: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "yearBuilt");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// yearlyRevenue
+    ///
+    /// The size of the business in annual revenue.
+    ///
+    /// `schema:yearlyRevenue` (`YEARLY_REVENUE`)
+    pub static ref YEARLY_REVENUE: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "yearlyRevenue");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// yearsInOperation
+    ///
+    /// The age of the business.
+    ///
+    /// `schema:yearsInOperation` (`YEARS_IN_OPERATION`)
+    pub static ref YEARS_IN_OPERATION: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "yearsInOperation");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// yield
+    ///
+    /// The quantity that results by performing instructions. For example, a paper airplane, 10 personalized candles.
+    ///
+    /// `schema:yield` (`YIELD`)
+    pub static ref YIELD: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "yield");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// 3DModel
+    ///
+    /// A 3D model represents some kind of 3D content, which may have encodings in one or more MediaObjects.
+    ///
+    /// `schema:3DModel` (`_3_D_MODEL`)
+    pub static ref _3_D_MODEL: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "3DModel");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// AMRadioChannel
+    ///
+    /// A radio channel that uses AM.
+    ///
+    /// `schema:AMRadioChannel` (`AM_RADIO_CHANNEL`)
+    pub static ref AM_RADIO_CHANNEL: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "AMRadioChannel");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// APIReference
+    ///
+    /// Reference documentation for application programming interfaces (APIs).
+    ///
+    /// `schema:APIReference` (`API_REFERENCE`)
+    pub static ref API_REFERENCE: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "APIReference");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// AboutPage
+    ///
+    /// Web page type: About page.
+    ///
+    /// `schema:AboutPage` (`ABOUT_PAGE`)
+    pub static ref ABOUT_PAGE: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "AboutPage");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// AcceptAction
+    ///
+    /// The act of committing to/adopting an object.
+    ///
+    /// `schema:AcceptAction` (`ACCEPT_ACTION`)
+    pub static ref ACCEPT_ACTION: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "AcceptAction");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// Accommodation
+    ///
+    /// An accommodation is a place that can accommodate human beings, e.g. a hotel room, a camping pitch, or a meeting room. Many accommodations are for overnight stays, but this is not a mandatory requirement.
+    ///
+    /// `schema:Accommodation` (`ACCOMMODATION`)
+    pub static ref ACCOMMODATION: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "Accommodation");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// AccountingService
+    ///
+    /// Accountancy business. As a LocalBusiness it can be described as a provider of one or more Service(s).
+    ///
+    /// `schema:AccountingService` (`ACCOUNTING_SERVICE`)
+    pub static ref ACCOUNTING_SERVICE: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "AccountingService");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// AchieveAction
+    ///
+    /// The act of accomplishing something via previous efforts. It is an instantaneous action rather than an ongoing process.
+    ///
+    /// `schema:AchieveAction` (`ACHIEVE_ACTION`)
+    pub static ref ACHIEVE_ACTION: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "AchieveAction");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// Action
+    ///
+    /// An action performed by a direct agent and indirect participants upon a direct object. Optionally happens at a location with the help of an inanimate instrument. The execution of the action may produce a result. Specific action sub-type documentation specifies the exact expectation of each argument/role.
+    ///
+    /// `schema:Action` (`ACTION`)
+    pub static ref ACTION: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "Action");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// ActionAccessSpecification
+    ///
+    /// A set of requirements that must be fulfilled in order to perform an Action.
+    ///
+    /// `schema:ActionAccessSpecification` (`ACTION_ACCESS_SPECIFICATION`)
+    pub static ref ACTION_ACCESS_SPECIFICATION: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "ActionAccessSpecification");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// ActionStatusType
+    ///
+    /// The status of an Action.
+    ///
+    /// `schema:ActionStatusType` (`ACTION_STATUS_TYPE`)
+    pub static ref ACTION_STATUS_TYPE: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "ActionStatusType");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// ActivateAction
+    ///
+    /// The act of starting or activating a device or application (e.g. starting a timer or turning on a flashlight).
+    ///
+    /// `schema:ActivateAction` (`ACTIVATE_ACTION`)
+    pub static ref ACTIVATE_ACTION: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "ActivateAction");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// AddAction
+    ///
+    /// The act of editing by adding an object to a collection.
+    ///
+    /// `schema:AddAction` (`ADD_ACTION`)
+    pub static ref ADD_ACTION: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "AddAction");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// AdministrativeArea
+    ///
+    /// A geographical region, typically under the jurisdiction of a particular government.
+    ///
+    /// `schema:AdministrativeArea` (`ADMINISTRATIVE_AREA`)
+    pub static ref ADMINISTRATIVE_AREA: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "AdministrativeArea");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// AdultEntertainment
+    ///
+    /// An adult entertainment establishment.
+    ///
+    /// `schema:AdultEntertainment` (`ADULT_ENTERTAINMENT`)
+    pub static ref ADULT_ENTERTAINMENT: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "AdultEntertainment");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// AdultOrientedEnumeration
+    ///
+    /// Enumeration of considerations that make a product relevant or potentially restricted for adults only.
+    ///
+    /// `schema:AdultOrientedEnumeration` (`ADULT_ORIENTED_ENUMERATION`)
+    pub static ref ADULT_ORIENTED_ENUMERATION: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "AdultOrientedEnumeration");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// AdvertiserContentArticle
+    ///
+    /// An Article that an external entity has paid to place or to produce to its specifications. Includes advertorials, sponsored content, native advertising and other paid content.
+    ///
+    /// `schema:AdvertiserContentArticle` (`ADVERTISER_CONTENT_ARTICLE`)
+    pub static ref ADVERTISER_CONTENT_ARTICLE: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "AdvertiserContentArticle");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// AggregateOffer
+    ///
+    /// When a single product is associated with multiple offers (for example, the same pair of shoes is offered by different merchants), then AggregateOffer can be used.
+    ///
+    /// `schema:AggregateOffer` (`AGGREGATE_OFFER`)
+    pub static ref AGGREGATE_OFFER: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "AggregateOffer");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// AggregateRating
+    ///
+    /// The average rating based on multiple ratings or reviews.
+    ///
+    /// `schema:AggregateRating` (`AGGREGATE_RATING_CLASS`)
+    pub static ref AGGREGATE_RATING_CLASS: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "AggregateRating");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// AgreeAction
+    ///
+    /// The act of expressing a consistency of opinion with the object. An agent agrees to/about an object (a proposition, topic or theme) with participants.
+    ///
+    /// `schema:AgreeAction` (`AGREE_ACTION`)
+    pub static ref AGREE_ACTION: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "AgreeAction");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// Airline
+    ///
+    /// An organization that provides flights for passengers.
+    ///
+    /// `schema:Airline` (`AIRLINE`)
+    pub static ref AIRLINE: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "Airline");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// Airport
+    ///
+    /// An airport.
+    ///
+    /// `schema:Airport` (`AIRPORT`)
+    pub static ref AIRPORT: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "Airport");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// AlignmentObject
+    ///
+    /// An intangible item that describes an alignment between a learning resource and a node in an educational framework. Should not be used where the nature of the alignment can be described using a simple property, for example to express that a resource teaches or assesses a competency.
+    ///
+    /// `schema:AlignmentObject` (`ALIGNMENT_OBJECT`)
+    pub static ref ALIGNMENT_OBJECT: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "AlignmentObject");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// AllocateAction
+    ///
+    /// The act of organizing tasks/objects/events by associating resources to it.
+    ///
+    /// `schema:AllocateAction` (`ALLOCATE_ACTION`)
+    pub static ref ALLOCATE_ACTION: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "AllocateAction");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// AmpStory
+    ///
+    /// A creative work with a visual storytelling format intended to be viewed online, particularly on mobile devices.
+    ///
+    /// `schema:AmpStory` (`AMP_STORY`)
+    pub static ref AMP_STORY: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "AmpStory");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// AmusementPark
+    ///
+    /// An amusement park.
+    ///
+    /// `schema:AmusementPark` (`AMUSEMENT_PARK`)
+    pub static ref AMUSEMENT_PARK: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "AmusementPark");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// AnalysisNewsArticle
+    ///
+    /// An AnalysisNewsArticle is a NewsArticle that, while based on factual reporting, incorporates the expertise of the author/producer, offering interpretations and conclusions.
+    ///
+    /// `schema:AnalysisNewsArticle` (`ANALYSIS_NEWS_ARTICLE`)
+    pub static ref ANALYSIS_NEWS_ARTICLE: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "AnalysisNewsArticle");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// AnatomicalStructure
+    ///
+    /// Any part of the human body, typically a component of an anatomical system. Organs, tissues, and cells are all anatomical structures.
+    ///
+    /// `schema:AnatomicalStructure` (`ANATOMICAL_STRUCTURE`)
+    pub static ref ANATOMICAL_STRUCTURE: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "AnatomicalStructure");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// AnatomicalSystem
+    ///
+    /// An anatomical system is a group of anatomical structures that work together to perform a certain task. Anatomical systems, such as organ systems, are one organizing principle of anatomy, and can include circulatory, digestive, endocrine, integumentary, immune, lymphatic, muscular, nervous, reproductive, respiratory, skeletal, urinary, vestibular, and other systems.
+    ///
+    /// `schema:AnatomicalSystem` (`ANATOMICAL_SYSTEM`)
+    pub static ref ANATOMICAL_SYSTEM: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "AnatomicalSystem");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// AnimalShelter
+    ///
+    /// Animal shelter.
+    ///
+    /// `schema:AnimalShelter` (`ANIMAL_SHELTER`)
+    pub static ref ANIMAL_SHELTER: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "AnimalShelter");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// Answer
+    ///
+    /// An answer offered to a question; perhaps correct, perhaps opinionated or wrong.
+    ///
+    /// `schema:Answer` (`ANSWER`)
+    pub static ref ANSWER: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "Answer");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// Apartment
+    ///
+    /// An apartment (in American English) or flat (in British English) is a self-contained housing unit (a type of residential real estate) that occupies only part of a building.
+    ///
+    /// `schema:Apartment` (`APARTMENT`)
+    pub static ref APARTMENT: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "Apartment");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// ApartmentComplex
+    ///
+    /// Residence type: Apartment complex.
+    ///
+    /// `schema:ApartmentComplex` (`APARTMENT_COMPLEX`)
+    pub static ref APARTMENT_COMPLEX: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "ApartmentComplex");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// AppendAction
+    ///
+    /// The act of inserting at the end if an ordered collection.
+    ///
+    /// `schema:AppendAction` (`APPEND_ACTION`)
+    pub static ref APPEND_ACTION: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "AppendAction");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// ApplyAction
+    ///
+    /// The act of registering to an organization/service without the guarantee to receive it.
+    ///
+    /// `schema:ApplyAction` (`APPLY_ACTION`)
+    pub static ref APPLY_ACTION: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "ApplyAction");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// ApprovedIndication
+    ///
+    /// An indication for a medical therapy that has been formally specified or approved by a regulatory body that regulates use of the therapy; for example, the US FDA approves indications for most drugs in the US.
+    ///
+    /// `schema:ApprovedIndication` (`APPROVED_INDICATION`)
+    pub static ref APPROVED_INDICATION: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "ApprovedIndication");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// Aquarium
+    ///
+    /// Aquarium.
+    ///
+    /// `schema:Aquarium` (`AQUARIUM`)
+    pub static ref AQUARIUM: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "Aquarium");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// ArchiveComponent
+    ///
+    /// An intangible type to be applied to any archive content, carrying with it a set of properties required to describe archival items and collections.
+    ///
+    /// `schema:ArchiveComponent` (`ARCHIVE_COMPONENT`)
+    pub static ref ARCHIVE_COMPONENT: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "ArchiveComponent");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// ArchiveOrganization
+    ///
+    /// An organization with archival holdings. An organization which keeps and preserves archival material and typically makes it accessible to the public.
+    ///
+    /// `schema:ArchiveOrganization` (`ARCHIVE_ORGANIZATION`)
+    pub static ref ARCHIVE_ORGANIZATION: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "ArchiveOrganization");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// ArriveAction
+    ///
+    /// The act of arriving at a place. An agent arrives at a destination from a fromLocation, optionally with participants.
+    ///
+    /// `schema:ArriveAction` (`ARRIVE_ACTION`)
+    pub static ref ARRIVE_ACTION: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "ArriveAction");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// ArtGallery
+    ///
+    /// An art gallery.
+    ///
+    /// `schema:ArtGallery` (`ART_GALLERY`)
+    pub static ref ART_GALLERY: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "ArtGallery");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// Artery
+    ///
+    /// A type of blood vessel that specifically carries blood away from the heart.
+    ///
+    /// `schema:Artery` (`ARTERY`)
+    pub static ref ARTERY: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "Artery");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// Article
+    ///
+    /// An article, such as a news article or piece of investigative report. Newspapers and magazines have articles of many different types and this is intended to cover them all.
+    ///
+    /// `schema:Article` (`ARTICLE`)
+    pub static ref ARTICLE: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "Article");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// AskAction
+    ///
+    /// The act of posing a question / favor to someone.
+    ///
+    /// `schema:AskAction` (`ASK_ACTION`)
+    pub static ref ASK_ACTION: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "AskAction");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// AskPublicNewsArticle
+    ///
+    /// A NewsArticle expressing an open call by a NewsMediaOrganization asking the public for input, insights, clarifications, anecdotes, documentation, etc., on an issue, for reporting purposes.
+    ///
+    /// `schema:AskPublicNewsArticle` (`ASK_PUBLIC_NEWS_ARTICLE`)
+    pub static ref ASK_PUBLIC_NEWS_ARTICLE: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "AskPublicNewsArticle");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// AssessAction
+    ///
+    /// The act of forming one's opinion, reaction or sentiment.
+    ///
+    /// `schema:AssessAction` (`ASSESS_ACTION`)
+    pub static ref ASSESS_ACTION: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "AssessAction");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// AssignAction
+    ///
+    /// The act of allocating an action/event/task to some destination (someone or something).
+    ///
+    /// `schema:AssignAction` (`ASSIGN_ACTION`)
+    pub static ref ASSIGN_ACTION: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "AssignAction");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// Atlas
+    ///
+    /// A collection or bound volume of maps, charts, plates or tables, physical or in media form illustrating any subject.
+    ///
+    /// `schema:Atlas` (`ATLAS`)
+    pub static ref ATLAS: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "Atlas");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// Attorney
+    ///
+    /// Professional service: Attorney.
+    ///
+    /// `schema:Attorney` (`ATTORNEY`)
+    pub static ref ATTORNEY: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "Attorney");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// Audience
+    ///
+    /// Intended audience for an item, i.e. the group for whom the item was created.
+    ///
+    /// `schema:Audience` (`AUDIENCE_CLASS`)
+    pub static ref AUDIENCE_CLASS: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "Audience");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// AudioObject
+    ///
+    /// An audio file.
+    ///
+    /// `schema:AudioObject` (`AUDIO_OBJECT`)
+    pub static ref AUDIO_OBJECT: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "AudioObject");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// AudioObjectSnapshot
+    ///
+    /// A specific and exact (byte-for-byte) version of an AudioObject. Two byte-for-byte identical files, for the purposes of this type, considered identical.
+    ///
+    /// `schema:AudioObjectSnapshot` (`AUDIO_OBJECT_SNAPSHOT`)
+    pub static ref AUDIO_OBJECT_SNAPSHOT: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "AudioObjectSnapshot");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// Audiobook
+    ///
+    /// An audiobook.
+    ///
+    /// `schema:Audiobook` (`AUDIOBOOK`)
+    pub static ref AUDIOBOOK: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "Audiobook");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// AuthorizeAction
+    ///
+    /// The act of granting permission to an object.
+    ///
+    /// `schema:AuthorizeAction` (`AUTHORIZE_ACTION`)
+    pub static ref AUTHORIZE_ACTION: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "AuthorizeAction");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// AutoBodyShop
+    ///
+    /// Auto body shop.
+    ///
+    /// `schema:AutoBodyShop` (`AUTO_BODY_SHOP`)
+    pub static ref AUTO_BODY_SHOP: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "AutoBodyShop");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// AutoDealer
+    ///
+    /// An car dealership.
+    ///
+    /// `schema:AutoDealer` (`AUTO_DEALER`)
+    pub static ref AUTO_DEALER: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "AutoDealer");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// AutoPartsStore
+    ///
+    /// An auto parts store.
+    ///
+    /// `schema:AutoPartsStore` (`AUTO_PARTS_STORE`)
+    pub static ref AUTO_PARTS_STORE: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "AutoPartsStore");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// AutoRental
+    ///
+    /// A car rental business.
+    ///
+    /// `schema:AutoRental` (`AUTO_RENTAL`)
+    pub static ref AUTO_RENTAL: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "AutoRental");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// AutoRepair
+    ///
+    /// Car repair business.
+    ///
+    /// `schema:AutoRepair` (`AUTO_REPAIR`)
+    pub static ref AUTO_REPAIR: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "AutoRepair");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// AutoWash
+    ///
+    /// A car wash business.
+    ///
+    /// `schema:AutoWash` (`AUTO_WASH`)
+    pub static ref AUTO_WASH: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "AutoWash");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// AutomatedTeller
+    ///
+    /// ATM/cash machine.
+    ///
+    /// `schema:AutomatedTeller` (`AUTOMATED_TELLER`)
+    pub static ref AUTOMATED_TELLER: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "AutomatedTeller");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// AutomotiveBusiness
+    ///
+    /// Car repair, sales, or parts.
+    ///
+    /// `schema:AutomotiveBusiness` (`AUTOMOTIVE_BUSINESS`)
+    pub static ref AUTOMOTIVE_BUSINESS: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "AutomotiveBusiness");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// BackgroundNewsArticle
+    ///
+    /// A NewsArticle providing historical context, definition and detail on a specific topic (aka "explainer" or "backgrounder").
+    ///
+    /// `schema:BackgroundNewsArticle` (`BACKGROUND_NEWS_ARTICLE`)
+    pub static ref BACKGROUND_NEWS_ARTICLE: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "BackgroundNewsArticle");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// Bakery
+    ///
+    /// A bakery.
+    ///
+    /// `schema:Bakery` (`BAKERY`)
+    pub static ref BAKERY: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "Bakery");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// BankAccount
+    ///
+    /// A product or service offered by a bank whereby one may deposit, withdraw or transfer money and in some cases be paid interest.
+    ///
+    /// `schema:BankAccount` (`BANK_ACCOUNT`)
+    pub static ref BANK_ACCOUNT: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "BankAccount");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// BankOrCreditUnion
+    ///
+    /// Bank or credit union.
+    ///
+    /// `schema:BankOrCreditUnion` (`BANK_OR_CREDIT_UNION`)
+    pub static ref BANK_OR_CREDIT_UNION: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "BankOrCreditUnion");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// BarOrPub
+    ///
+    /// A bar or pub.
+    ///
+    /// `schema:BarOrPub` (`BAR_OR_PUB`)
+    pub static ref BAR_OR_PUB: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "BarOrPub");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// Barcode
+    ///
+    /// An image of a visual machine-readable code such as a barcode or QR code.
+    ///
+    /// `schema:Barcode` (`BARCODE`)
+    pub static ref BARCODE: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "Barcode");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// Beach
+    ///
+    /// Beach.
+    ///
+    /// `schema:Beach` (`BEACH`)
+    pub static ref BEACH: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "Beach");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// BeautySalon
+    ///
+    /// Beauty salon.
+    ///
+    /// `schema:BeautySalon` (`BEAUTY_SALON`)
+    pub static ref BEAUTY_SALON: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "BeautySalon");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// BedAndBreakfast
+    ///
+    /// Bed and breakfast.
+    ///
+    /// `schema:BedAndBreakfast` (`BED_AND_BREAKFAST`)
+    pub static ref BED_AND_BREAKFAST: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "BedAndBreakfast");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// BedDetails
+    ///
+    /// An entity holding detailed information about the available bed types, e.g. the quantity of twin beds for a hotel room. For the single case of just one bed of a certain type, you can use bed directly with a text.
+    ///
+    /// `schema:BedDetails` (`BED_DETAILS`)
+    pub static ref BED_DETAILS: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "BedDetails");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// BefriendAction
+    ///
+    /// The act of forming a personal connection with someone (object) mutually/bidirectionally/symmetrically.
+    ///
+    /// `schema:BefriendAction` (`BEFRIEND_ACTION`)
+    pub static ref BEFRIEND_ACTION: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "BefriendAction");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// BikeStore
+    ///
+    /// A bike store.
+    ///
+    /// `schema:BikeStore` (`BIKE_STORE`)
+    pub static ref BIKE_STORE: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "BikeStore");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// BioChemEntity
+    ///
+    /// Any biological, chemical, or biochemical thing. For example: a protein; a gene; a chemical; a synthetic chemical.
+    ///
+    /// `schema:BioChemEntity` (`BIO_CHEM_ENTITY`)
+    pub static ref BIO_CHEM_ENTITY: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "BioChemEntity");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// Blog
+    ///
+    /// A blog, sometimes known as a "weblog". Note that the individual posts (BlogPostings) in a Blog are often colloquially referred to by the same term.
+    ///
+    /// `schema:Blog` (`BLOG`)
+    pub static ref BLOG: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "Blog");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// BlogPosting
+    ///
+    /// A blog post.
+    ///
+    /// `schema:BlogPosting` (`BLOG_POSTING`)
+    pub static ref BLOG_POSTING: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "BlogPosting");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// BloodTest
+    ///
+    /// A medical test performed on a sample of a patient's blood.
+    ///
+    /// `schema:BloodTest` (`BLOOD_TEST`)
+    pub static ref BLOOD_TEST: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "BloodTest");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// BoardingPolicyType
+    ///
+    /// A type of boarding policy used by an airline.
+    ///
+    /// `schema:BoardingPolicyType` (`BOARDING_POLICY_TYPE`)
+    pub static ref BOARDING_POLICY_TYPE: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "BoardingPolicyType");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// BoatReservation
+    ///
+    /// A reservation for boat travel.
+    ///
+    /// `schema:BoatReservation` (`BOAT_RESERVATION`)
+    pub static ref BOAT_RESERVATION: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "BoatReservation");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// BoatTerminal
+    ///
+    /// A terminal for boats, ships, and other water vessels.
+    ///
+    /// `schema:BoatTerminal` (`BOAT_TERMINAL`)
+    pub static ref BOAT_TERMINAL: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "BoatTerminal");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// BoatTrip
+    ///
+    /// A trip on a commercial ferry line.
+    ///
+    /// `schema:BoatTrip` (`BOAT_TRIP`)
+    pub static ref BOAT_TRIP: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "BoatTrip");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// BodyMeasurementTypeEnumeration
+    ///
+    /// Enumerates types (or dimensions) of a person's body measurements, for example for fitting of clothes.
+    ///
+    /// `schema:BodyMeasurementTypeEnumeration` (`BODY_MEASUREMENT_TYPE_ENUMERATION`)
+    pub static ref BODY_MEASUREMENT_TYPE_ENUMERATION: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "BodyMeasurementTypeEnumeration");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// BodyOfWater
+    ///
+    /// A body of water, such as a sea, ocean, or lake.
+    ///
+    /// `schema:BodyOfWater` (`BODY_OF_WATER`)
+    pub static ref BODY_OF_WATER: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "BodyOfWater");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// Bone
+    ///
+    /// Rigid connective tissue that comprises up the skeletal structure of the human body.
+    ///
+    /// `schema:Bone` (`BONE`)
+    pub static ref BONE: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "Bone");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// Book
+    ///
+    /// A book.
+    ///
+    /// `schema:Book` (`BOOK`)
+    pub static ref BOOK: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "Book");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// BookFormatType
+    ///
+    /// The publication format of the book.
+    ///
+    /// `schema:BookFormatType` (`BOOK_FORMAT_TYPE`)
+    pub static ref BOOK_FORMAT_TYPE: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "BookFormatType");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// BookSeries
+    ///
+    /// A series of books. Included books can be indicated with the hasPart property.
+    ///
+    /// `schema:BookSeries` (`BOOK_SERIES`)
+    pub static ref BOOK_SERIES: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "BookSeries");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// BookStore
+    ///
+    /// A bookstore.
+    ///
+    /// `schema:BookStore` (`BOOK_STORE`)
+    pub static ref BOOK_STORE: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "BookStore");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// BookmarkAction
+    ///
+    /// An agent bookmarks/flags/labels/tags/marks an object.
+    ///
+    /// `schema:BookmarkAction` (`BOOKMARK_ACTION`)
+    pub static ref BOOKMARK_ACTION: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "BookmarkAction");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// Boolean
+    ///
+    /// Boolean: True or False.
+    ///
+    /// `schema:Boolean` (`BOOLEAN`)
+    pub static ref BOOLEAN: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "Boolean");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// BorrowAction
+    ///
+    /// The act of obtaining an object under an agreement to return it at a later date. Reciprocal of LendAction.
+    ///
+    /// `schema:BorrowAction` (`BORROW_ACTION`)
+    pub static ref BORROW_ACTION: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "BorrowAction");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// BowlingAlley
+    ///
+    /// A bowling alley.
+    ///
+    /// `schema:BowlingAlley` (`BOWLING_ALLEY`)
+    pub static ref BOWLING_ALLEY: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "BowlingAlley");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// BrainStructure
+    ///
+    /// Any anatomical structure which pertains to the soft nervous tissue functioning as the coordinating center of sensation and intellectual and nervous activity.
+    ///
+    /// `schema:BrainStructure` (`BRAIN_STRUCTURE`)
+    pub static ref BRAIN_STRUCTURE: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "BrainStructure");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// Brand
+    ///
+    /// A brand is a name used by an organization or business person for labeling a product, product group, or similar.
+    ///
+    /// `schema:Brand` (`BRAND_CLASS`)
+    pub static ref BRAND_CLASS: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "Brand");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// BreadcrumbList
+    ///
+    /// A BreadcrumbList is an ItemList consisting of a chain of linked Web pages, typically described using at least their URL and their name, and typically ending with the current page.
+    ///
+    /// `schema:BreadcrumbList` (`BREADCRUMB_LIST`)
+    pub static ref BREADCRUMB_LIST: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "BreadcrumbList");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// Brewery
+    ///
+    /// Brewery.
+    ///
+    /// `schema:Brewery` (`BREWERY`)
+    pub static ref BREWERY: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "Brewery");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// Bridge
+    ///
+    /// A bridge.
+    ///
+    /// `schema:Bridge` (`BRIDGE`)
+    pub static ref BRIDGE: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "Bridge");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// BroadcastChannel
+    ///
+    /// A unique instance of a BroadcastService on a CableOrSatelliteService lineup.
+    ///
+    /// `schema:BroadcastChannel` (`BROADCAST_CHANNEL`)
+    pub static ref BROADCAST_CHANNEL: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "BroadcastChannel");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// BroadcastEvent
+    ///
+    /// An over the air or online broadcast event.
+    ///
+    /// `schema:BroadcastEvent` (`BROADCAST_EVENT`)
+    pub static ref BROADCAST_EVENT: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "BroadcastEvent");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// BroadcastFrequencySpecification
+    ///
+    /// The frequency in MHz and the modulation used for a particular BroadcastService.
+    ///
+    /// `schema:BroadcastFrequencySpecification` (`BROADCAST_FREQUENCY_SPECIFICATION`)
+    pub static ref BROADCAST_FREQUENCY_SPECIFICATION: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "BroadcastFrequencySpecification");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// BroadcastService
+    ///
+    /// A delivery service through which content is provided via broadcast over the air or online.
+    ///
+    /// `schema:BroadcastService` (`BROADCAST_SERVICE`)
+    pub static ref BROADCAST_SERVICE: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "BroadcastService");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// BrokerageAccount
+    ///
+    /// An account that allows an investor to deposit funds and place investment orders with a licensed broker or brokerage firm.
+    ///
+    /// `schema:BrokerageAccount` (`BROKERAGE_ACCOUNT`)
+    pub static ref BROKERAGE_ACCOUNT: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "BrokerageAccount");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// BuddhistTemple
+    ///
+    /// A Buddhist temple.
+    ///
+    /// `schema:BuddhistTemple` (`BUDDHIST_TEMPLE`)
+    pub static ref BUDDHIST_TEMPLE: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "BuddhistTemple");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// BusOrCoach
+    ///
+    /// A bus (also omnibus or autobus) is a road vehicle designed to carry passengers. Coaches are luxury buses, usually in service for long distance travel.
+    ///
+    /// `schema:BusOrCoach` (`BUS_OR_COACH`)
+    pub static ref BUS_OR_COACH: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "BusOrCoach");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// BusReservation
+    ///
+    /// A reservation for bus travel.
+    ///
+    /// `schema:BusReservation` (`BUS_RESERVATION`)
+    pub static ref BUS_RESERVATION: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "BusReservation");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// BusStation
+    ///
+    /// A bus station.
+    ///
+    /// `schema:BusStation` (`BUS_STATION`)
+    pub static ref BUS_STATION: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "BusStation");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// BusStop
+    ///
+    /// A bus stop.
+    ///
+    /// `schema:BusStop` (`BUS_STOP`)
+    pub static ref BUS_STOP: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "BusStop");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// BusTrip
+    ///
+    /// A trip on a commercial bus line.
+    ///
+    /// `schema:BusTrip` (`BUS_TRIP`)
+    pub static ref BUS_TRIP: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "BusTrip");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// BusinessAudience
+    ///
+    /// A set of characteristics belonging to businesses, e.g. who compose an item's target audience.
+    ///
+    /// `schema:BusinessAudience` (`BUSINESS_AUDIENCE`)
+    pub static ref BUSINESS_AUDIENCE: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "BusinessAudience");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// BusinessEntityType
+    ///
+    /// A business entity type is a conceptual entity representing the legal form, the size, the main line of business, the position in the value chain, or any combination thereof, of an organization or business person.
+    ///
+    /// `schema:BusinessEntityType` (`BUSINESS_ENTITY_TYPE`)
+    pub static ref BUSINESS_ENTITY_TYPE: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "BusinessEntityType");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// BusinessEvent
+    ///
+    /// Event type: Business event.
+    ///
+    /// `schema:BusinessEvent` (`BUSINESS_EVENT`)
+    pub static ref BUSINESS_EVENT: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "BusinessEvent");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// BusinessFunction
+    ///
+    /// The business function specifies the type of activity or access (i.e., the bundle of rights) offered by the organization or business person through the offer. Typical are sell, rental or lease, maintenance or repair, manufacture / produce, recycle / dispose, engineering / construction, or installation.
+    ///
+    /// `schema:BusinessFunction` (`BUSINESS_FUNCTION_CLASS`)
+    pub static ref BUSINESS_FUNCTION_CLASS: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "BusinessFunction");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// BuyAction
+    ///
+    /// The act of giving money to a seller in exchange for goods or services rendered. An agent buys an object, product, or service from a seller for a price.
+    ///
+    /// `schema:BuyAction` (`BUY_ACTION`)
+    pub static ref BUY_ACTION: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "BuyAction");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// CDCPMDRecord
+    ///
+    /// A CDCPMDRecord is a data structure representing a record in a CDC tabular data format used for hospital data reporting. See documentation for details, and the linked CDC materials for authoritative definitions used as the source here.
+    ///
+    /// `schema:CDCPMDRecord` (`CDCPMD_RECORD`)
+    pub static ref CDCPMD_RECORD: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "CDCPMDRecord");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// CableOrSatelliteService
+    ///
+    /// A service which provides access to media programming like TV or radio. Access may be via cable or satellite.
+    ///
+    /// `schema:CableOrSatelliteService` (`CABLE_OR_SATELLITE_SERVICE`)
+    pub static ref CABLE_OR_SATELLITE_SERVICE: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "CableOrSatelliteService");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// CafeOrCoffeeShop
+    ///
+    /// A cafe or coffee shop.
+    ///
+    /// `schema:CafeOrCoffeeShop` (`CAFE_OR_COFFEE_SHOP`)
+    pub static ref CAFE_OR_COFFEE_SHOP: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "CafeOrCoffeeShop");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// Campground
+    ///
+    /// A camping site, campsite, or Campground is a place used for overnight stay in the outdoors, typically containing individual CampingPitch locations.
+    ///
+    /// `schema:Campground` (`CAMPGROUND`)
+    pub static ref CAMPGROUND: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "Campground");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// CampingPitch
+    ///
+    /// A CampingPitch is an individual place for overnight stay in the outdoors, typically being part of a larger camping site, or Campground.
+    ///
+    /// `schema:CampingPitch` (`CAMPING_PITCH`)
+    pub static ref CAMPING_PITCH: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "CampingPitch");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// Canal
+    ///
+    /// A canal, like the Panama Canal.
+    ///
+    /// `schema:Canal` (`CANAL`)
+    pub static ref CANAL: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "Canal");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// CancelAction
+    ///
+    /// The act of asserting that a future event/action is no longer going to happen.
+    ///
+    /// `schema:CancelAction` (`CANCEL_ACTION`)
+    pub static ref CANCEL_ACTION: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "CancelAction");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// Car
+    ///
+    /// A car is a wheeled, self-powered motor vehicle used for transportation.
+    ///
+    /// `schema:Car` (`CAR`)
+    pub static ref CAR: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "Car");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// CarUsageType
+    ///
+    /// A value indicating a special usage of a car, e.g. commercial rental, driving school, or as a taxi.
+    ///
+    /// `schema:CarUsageType` (`CAR_USAGE_TYPE`)
+    pub static ref CAR_USAGE_TYPE: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "CarUsageType");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// Casino
+    ///
+    /// A casino.
+    ///
+    /// `schema:Casino` (`CASINO`)
+    pub static ref CASINO: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "Casino");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// CategoryCode
+    ///
+    /// A Category Code.
+    ///
+    /// `schema:CategoryCode` (`CATEGORY_CODE`)
+    pub static ref CATEGORY_CODE: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "CategoryCode");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// CategoryCodeSet
+    ///
+    /// A set of Category Code values.
+    ///
+    /// `schema:CategoryCodeSet` (`CATEGORY_CODE_SET`)
+    pub static ref CATEGORY_CODE_SET: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "CategoryCodeSet");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// CatholicChurch
+    ///
+    /// A Catholic church.
+    ///
+    /// `schema:CatholicChurch` (`CATHOLIC_CHURCH`)
+    pub static ref CATHOLIC_CHURCH: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "CatholicChurch");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// Cemetery
+    ///
+    /// A graveyard.
+    ///
+    /// `schema:Cemetery` (`CEMETERY`)
+    pub static ref CEMETERY: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "Cemetery");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// Certification
+    ///
+    /// A Certification is an official and authoritative statement about a subject, for example a product, service, person, or organization. A certification is typically issued by an indendent certification body.
+    ///
+    /// `schema:Certification` (`CERTIFICATION`)
+    pub static ref CERTIFICATION: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "Certification");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// CertificationStatusEnumeration
+    ///
+    /// Enumerates the different statuses of a Certification (Active and Inactive).
+    ///
+    /// `schema:CertificationStatusEnumeration` (`CERTIFICATION_STATUS_ENUMERATION`)
+    pub static ref CERTIFICATION_STATUS_ENUMERATION: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "CertificationStatusEnumeration");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// Chapter
+    ///
+    /// One of the sections into which a book is divided. A chapter usually has a section number or a name.
+    ///
+    /// `schema:Chapter` (`CHAPTER`)
+    pub static ref CHAPTER: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "Chapter");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// CheckAction
+    ///
+    /// An agent inspects, determines, investigates, inquires, or examines an object's accuracy, quality, condition, or state.
+    ///
+    /// `schema:CheckAction` (`CHECK_ACTION`)
+    pub static ref CHECK_ACTION: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "CheckAction");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// CheckInAction
+    ///
+    /// The act of an agent communicating (service provider, social media, etc) their arrival by registering/confirming for a previously reserved service (e.g. flight check-in) or at a place (e.g. hotel), possibly resulting in a result (boarding pass, etc).
+    ///
+    /// `schema:CheckInAction` (`CHECK_IN_ACTION`)
+    pub static ref CHECK_IN_ACTION: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "CheckInAction");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// CheckOutAction
+    ///
+    /// The act of an agent communicating (service provider, social media, etc) their departure of a previously reserved service (e.g. flight check-in) or place (e.g. hotel).
+    ///
+    /// `schema:CheckOutAction` (`CHECK_OUT_ACTION`)
+    pub static ref CHECK_OUT_ACTION: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "CheckOutAction");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// CheckoutPage
+    ///
+    /// Web page type: Checkout page.
+    ///
+    /// `schema:CheckoutPage` (`CHECKOUT_PAGE`)
+    pub static ref CHECKOUT_PAGE: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "CheckoutPage");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// ChemicalSubstance
+    ///
+    /// A chemical substance is 'a portion of matter of constant composition, composed of molecular entities of the same type or of different types' (source: ChEBI:59999).
+    ///
+    /// `schema:ChemicalSubstance` (`CHEMICAL_SUBSTANCE`)
+    pub static ref CHEMICAL_SUBSTANCE: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "ChemicalSubstance");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// ChildCare
+    ///
+    /// A Childcare center.
+    ///
+    /// `schema:ChildCare` (`CHILD_CARE`)
+    pub static ref CHILD_CARE: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "ChildCare");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// ChildrensEvent
+    ///
+    /// Event type: Children's event.
+    ///
+    /// `schema:ChildrensEvent` (`CHILDRENS_EVENT`)
+    pub static ref CHILDRENS_EVENT: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "ChildrensEvent");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// ChooseAction
+    ///
+    /// The act of expressing a preference from a set of options or a large or unbounded set of choices/options.
+    ///
+    /// `schema:ChooseAction` (`CHOOSE_ACTION`)
+    pub static ref CHOOSE_ACTION: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "ChooseAction");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// Church
+    ///
+    /// A church.
+    ///
+    /// `schema:Church` (`CHURCH`)
+    pub static ref CHURCH: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "Church");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// City
+    ///
+    /// A city or town.
+    ///
+    /// `schema:City` (`CITY`)
+    pub static ref CITY: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "City");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// CityHall
+    ///
+    /// A city hall.
+    ///
+    /// `schema:CityHall` (`CITY_HALL`)
+    pub static ref CITY_HALL: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "CityHall");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// CivicStructure
+    ///
+    /// A public structure, such as a town hall or concert hall.
+    ///
+    /// `schema:CivicStructure` (`CIVIC_STRUCTURE`)
+    pub static ref CIVIC_STRUCTURE: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "CivicStructure");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// Claim
+    ///
+    /// A Claim in Schema.org represents a specific, factually-oriented claim that could be the itemReviewed in a ClaimReview.
+    ///
+    /// `schema:Claim` (`CLAIM`)
+    pub static ref CLAIM: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "Claim");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// ClaimReview
+    ///
+    /// A fact-checking review of claims made (or reported) in some creative work (referenced via itemReviewed).
+    ///
+    /// `schema:ClaimReview` (`CLAIM_REVIEW`)
+    pub static ref CLAIM_REVIEW: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "ClaimReview");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// Class
+    ///
+    /// A class, also often called a 'Type'; equivalent to rdfs:Class.
+    ///
+    /// `schema:Class` (`CLASS`)
+    pub static ref CLASS: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "Class");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// Clip
+    ///
+    /// A short TV or radio program or a segment/part of a program.
+    ///
+    /// `schema:Clip` (`CLIP`)
+    pub static ref CLIP: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "Clip");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// ClothingStore
+    ///
+    /// A clothing store.
+    ///
+    /// `schema:ClothingStore` (`CLOTHING_STORE`)
+    pub static ref CLOTHING_STORE: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "ClothingStore");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// Code
+    ///
+    /// Computer programming source code. Example: Full (compile ready) solutions, code snippet samples, scripts, templates.
+    ///
+    /// `schema:Code` (`CODE_CLASS`)
+    pub static ref CODE_CLASS: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "Code");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// Collection
+    ///
+    /// A collection of items, e.g. creative works or products.
+    ///
+    /// `schema:Collection` (`COLLECTION_CLASS`)
+    pub static ref COLLECTION_CLASS: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "Collection");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// CollectionPage
+    ///
+    /// Web page type: Collection page.
+    ///
+    /// `schema:CollectionPage` (`COLLECTION_PAGE`)
+    pub static ref COLLECTION_PAGE: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "CollectionPage");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// CollegeOrUniversity
+    ///
+    /// A college, university, or other third-level educational institution.
+    ///
+    /// `schema:CollegeOrUniversity` (`COLLEGE_OR_UNIVERSITY`)
+    pub static ref COLLEGE_OR_UNIVERSITY: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "CollegeOrUniversity");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// ComedyClub
+    ///
+    /// A comedy club.
+    ///
+    /// `schema:ComedyClub` (`COMEDY_CLUB`)
+    pub static ref COMEDY_CLUB: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "ComedyClub");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// ComedyEvent
+    ///
+    /// Event type: Comedy event.
+    ///
+    /// `schema:ComedyEvent` (`COMEDY_EVENT`)
+    pub static ref COMEDY_EVENT: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "ComedyEvent");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// ComicCoverArt
+    ///
+    /// The artwork on the cover of a comic.
+    ///
+    /// `schema:ComicCoverArt` (`COMIC_COVER_ART`)
+    pub static ref COMIC_COVER_ART: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "ComicCoverArt");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// ComicIssue
+    ///
+    /// Individual comic issues are serially published as part of a larger series. For the sake of consistency, even one-shot issues belong to a series comprised of a single issue. All comic issues can be uniquely identified by: the combination of the name and volume number of the series to which the issue belongs; the issue number; and the variant description of the issue (if any).
+    ///
+    /// `schema:ComicIssue` (`COMIC_ISSUE`)
+    pub static ref COMIC_ISSUE: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "ComicIssue");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// ComicSeries
+    ///
+    /// A sequential publication of comic stories under a unifying title, for example "The Amazing Spider-Man" or "Groo the Wanderer".
+    ///
+    /// `schema:ComicSeries` (`COMIC_SERIES`)
+    pub static ref COMIC_SERIES: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "ComicSeries");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// ComicStory
+    ///
+    /// The term "story" is any indivisible, re-printable unit of a comic, including the interior stories, covers, and backmatter. Most comics have at least two stories: a cover (ComicCoverArt) and an interior story.
+    ///
+    /// `schema:ComicStory` (`COMIC_STORY`)
+    pub static ref COMIC_STORY: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "ComicStory");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// Comment
+    ///
+    /// A comment on an item - for example, a comment on a blog post. The comment's content is expressed via the text property, and its topic via about, properties shared with all CreativeWorks.
+    ///
+    /// `schema:Comment` (`COMMENT_CLASS`)
+    pub static ref COMMENT_CLASS: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "Comment");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// CommentAction
+    ///
+    /// The act of generating a comment about a subject.
+    ///
+    /// `schema:CommentAction` (`COMMENT_ACTION`)
+    pub static ref COMMENT_ACTION: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "CommentAction");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// CommunicateAction
+    ///
+    /// The act of conveying information to another person via a communication medium (instrument) such as speech, email, or telephone conversation.
+    ///
+    /// `schema:CommunicateAction` (`COMMUNICATE_ACTION`)
+    pub static ref COMMUNICATE_ACTION: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "CommunicateAction");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// CommunityHealth
+    ///
+    /// A field of public health focusing on improving health characteristics of a defined population in relation with their geographical or environment areas.
+    ///
+    /// `schema:CommunityHealth` (`COMMUNITY_HEALTH`)
+    pub static ref COMMUNITY_HEALTH: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "CommunityHealth");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// CompoundPriceSpecification
+    ///
+    /// A compound price specification is one that bundles multiple prices that all apply in combination for different dimensions of consumption.
+    ///
+    /// `schema:CompoundPriceSpecification` (`COMPOUND_PRICE_SPECIFICATION`)
+    pub static ref COMPOUND_PRICE_SPECIFICATION: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "CompoundPriceSpecification");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// ComputerLanguage
+    ///
+    /// This type covers computer programming languages such as Scheme and Lisp, as well as other language-like computer representations. Natural languages are best represented with the Language type.
+    ///
+    /// `schema:ComputerLanguage` (`COMPUTER_LANGUAGE`)
+    pub static ref COMPUTER_LANGUAGE: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "ComputerLanguage");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// ComputerStore
+    ///
+    /// A computer store.
+    ///
+    /// `schema:ComputerStore` (`COMPUTER_STORE`)
+    pub static ref COMPUTER_STORE: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "ComputerStore");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// ConfirmAction
+    ///
+    /// The act of notifying someone that a future event/action is going to happen as expected.
+    ///
+    /// `schema:ConfirmAction` (`CONFIRM_ACTION`)
+    pub static ref CONFIRM_ACTION: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "ConfirmAction");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// Consortium
+    ///
+    /// A Consortium is a membership Organization whose members are typically Organizations.
+    ///
+    /// `schema:Consortium` (`CONSORTIUM`)
+    pub static ref CONSORTIUM: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "Consortium");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// ConstraintNode
+    ///
+    /// The ConstraintNode type is provided to support usecases in which a node in a structured data graph is described with properties which appear to describe a single entity, but are being used in a situation where they serve a more abstract purpose.
+    ///
+    /// `schema:ConstraintNode` (`CONSTRAINT_NODE`)
+    pub static ref CONSTRAINT_NODE: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "ConstraintNode");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// ConsumeAction
+    ///
+    /// The act of ingesting information/resources/food.
+    ///
+    /// `schema:ConsumeAction` (`CONSUME_ACTION`)
+    pub static ref CONSUME_ACTION: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "ConsumeAction");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// ContactPage
+    ///
+    /// Web page type: Contact page.
+    ///
+    /// `schema:ContactPage` (`CONTACT_PAGE`)
+    pub static ref CONTACT_PAGE: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "ContactPage");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// ContactPoint
+    ///
+    /// A contact point—for example, a Customer Complaints department.
+    ///
+    /// `schema:ContactPoint` (`CONTACT_POINT_CLASS`)
+    pub static ref CONTACT_POINT_CLASS: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "ContactPoint");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// ContactPointOption
+    ///
+    /// Enumerated options related to a ContactPoint.
+    ///
+    /// `schema:ContactPointOption` (`CONTACT_POINT_OPTION`)
+    pub static ref CONTACT_POINT_OPTION: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "ContactPointOption");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// Continent
+    ///
+    /// One of the continents (for example, Europe or Africa).
+    ///
+    /// `schema:Continent` (`CONTINENT`)
+    pub static ref CONTINENT: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "Continent");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// ControlAction
+    ///
+    /// An agent controls a device or application.
+    ///
+    /// `schema:ControlAction` (`CONTROL_ACTION`)
+    pub static ref CONTROL_ACTION: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "ControlAction");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// ConvenienceStore
+    ///
+    /// A convenience store.
+    ///
+    /// `schema:ConvenienceStore` (`CONVENIENCE_STORE`)
+    pub static ref CONVENIENCE_STORE: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "ConvenienceStore");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// Conversation
+    ///
+    /// One or more messages between organizations or people on a particular topic. Individual messages can be linked to the conversation with isPartOf or hasPart properties.
+    ///
+    /// `schema:Conversation` (`CONVERSATION`)
+    pub static ref CONVERSATION: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "Conversation");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// CookAction
+    ///
+    /// The act of producing/preparing food.
+    ///
+    /// `schema:CookAction` (`COOK_ACTION`)
+    pub static ref COOK_ACTION: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "CookAction");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// Cooperative
+    ///
+    /// An organization that is a joint project of multiple organizations or persons.
+    ///
+    /// `schema:Cooperative` (`COOPERATIVE`)
+    pub static ref COOPERATIVE: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "Cooperative");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// Corporation
+    ///
+    /// Organization: A business corporation.
+    ///
+    /// `schema:Corporation` (`CORPORATION`)
+    pub static ref CORPORATION: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "Corporation");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// CorrectionComment
+    ///
+    /// A comment that corrects CreativeWork.
+    ///
+    /// `schema:CorrectionComment` (`CORRECTION_COMMENT`)
+    pub static ref CORRECTION_COMMENT: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "CorrectionComment");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// Country
+    ///
+    /// A country.
+    ///
+    /// `schema:Country` (`COUNTRY`)
+    pub static ref COUNTRY: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "Country");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// Course
+    ///
+    /// A description of an educational course which may be offered as distinct instances which take place at different times or take place at different locations, or be offered through different media or modes of study.
+    ///
+    /// `schema:Course` (`COURSE_CLASS`)
+    pub static ref COURSE_CLASS: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "Course");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// CourseInstance
+    ///
+    /// An instance of a Course which is distinct from other instances because it is offered at a different time or location or through different media or modes of study or to a specific section of students.
+    ///
+    /// `schema:CourseInstance` (`COURSE_INSTANCE`)
+    pub static ref COURSE_INSTANCE: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "CourseInstance");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// Courthouse
+    ///
+    /// A courthouse.
+    ///
+    /// `schema:Courthouse` (`COURTHOUSE`)
+    pub static ref COURTHOUSE: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "Courthouse");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// CoverArt
+    ///
+    /// The artwork on the outer surface of a CreativeWork.
+    ///
+    /// `schema:CoverArt` (`COVER_ART`)
+    pub static ref COVER_ART: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "CoverArt");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// CovidTestingFacility
+    ///
+    /// A CovidTestingFacility is a MedicalClinic where testing for the COVID-19 Coronavirus disease is available.
+    ///
+    /// `schema:CovidTestingFacility` (`COVID_TESTING_FACILITY`)
+    pub static ref COVID_TESTING_FACILITY: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "CovidTestingFacility");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// CreateAction
+    ///
+    /// The act of deliberately creating/producing/generating/building a result out of the agent.
+    ///
+    /// `schema:CreateAction` (`CREATE_ACTION`)
+    pub static ref CREATE_ACTION: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "CreateAction");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// CreativeWork
+    ///
+    /// The most generic kind of creative work, including books, movies, photographs, software programs, etc.
+    ///
+    /// `schema:CreativeWork` (`CREATIVE_WORK`)
+    pub static ref CREATIVE_WORK: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "CreativeWork");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// CreativeWorkSeason
+    ///
+    /// A media season, e.g. TV, radio, video game etc.
+    ///
+    /// `schema:CreativeWorkSeason` (`CREATIVE_WORK_SEASON`)
+    pub static ref CREATIVE_WORK_SEASON: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "CreativeWorkSeason");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// CreativeWorkSeries
+    ///
+    /// A CreativeWorkSeries in schema.org is a group of related items, typically but not necessarily of the same kind. CreativeWorkSeries are usually organized into some order, often chronological.
+    ///
+    /// `schema:CreativeWorkSeries` (`CREATIVE_WORK_SERIES`)
+    pub static ref CREATIVE_WORK_SERIES: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "CreativeWorkSeries");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// CreditCard
+    ///
+    /// A card payment method of a particular brand or name. Used to mark up a particular payment method and/or the financial product/service that supplies the card account.
+    ///
+    /// `schema:CreditCard` (`CREDIT_CARD`)
+    pub static ref CREDIT_CARD: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "CreditCard");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// Crematorium
+    ///
+    /// A crematorium.
+    ///
+    /// `schema:Crematorium` (`CREMATORIUM`)
+    pub static ref CREMATORIUM: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "Crematorium");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// CriticReview
+    ///
+    /// A CriticReview is a more specialized form of Review written or published by a source that is recognized for its reviewing activities. These can include online columns, travel and food guides, TV and radio shows, blogs and other independent Web sites.
+    ///
+    /// `schema:CriticReview` (`CRITIC_REVIEW`)
+    pub static ref CRITIC_REVIEW: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "CriticReview");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// CssSelectorType
+    ///
+    /// Text representing a CSS selector.
+    ///
+    /// `schema:CssSelectorType` (`CSS_SELECTOR_TYPE`)
+    pub static ref CSS_SELECTOR_TYPE: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "CssSelectorType");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// CurrencyConversionService
+    ///
+    /// A service to convert funds from one currency to another currency.
+    ///
+    /// `schema:CurrencyConversionService` (`CURRENCY_CONVERSION_SERVICE`)
+    pub static ref CURRENCY_CONVERSION_SERVICE: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "CurrencyConversionService");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// DDxElement
+    ///
+    /// An alternative, closely-related condition typically considered later in the differential diagnosis process along with the signs that are used to distinguish it.
+    ///
+    /// `schema:DDxElement` (`D_DX_ELEMENT`)
+    pub static ref D_DX_ELEMENT: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "DDxElement");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// DanceEvent
+    ///
+    /// Event type: A social dance.
+    ///
+    /// `schema:DanceEvent` (`DANCE_EVENT`)
+    pub static ref DANCE_EVENT: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "DanceEvent");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// DanceGroup
+    ///
+    /// A dance group—for example, the Alvin Ailey Dance Theater or Riverdance.
+    ///
+    /// `schema:DanceGroup` (`DANCE_GROUP`)
+    pub static ref DANCE_GROUP: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "DanceGroup");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// DataCatalog
+    ///
+    /// A collection of datasets.
+    ///
+    /// `schema:DataCatalog` (`DATA_CATALOG`)
+    pub static ref DATA_CATALOG: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "DataCatalog");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// DataDownload
+    ///
+    /// All or part of a Dataset in downloadable form.
+    ///
+    /// `schema:DataDownload` (`DATA_DOWNLOAD`)
+    pub static ref DATA_DOWNLOAD: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "DataDownload");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// DataFeed
+    ///
+    /// A single feed providing structured information about one or more entities or topics.
+    ///
+    /// `schema:DataFeed` (`DATA_FEED`)
+    pub static ref DATA_FEED: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "DataFeed");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// DataFeedItem
+    ///
+    /// A single item within a larger data feed.
+    ///
+    /// `schema:DataFeedItem` (`DATA_FEED_ITEM`)
+    pub static ref DATA_FEED_ITEM: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "DataFeedItem");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// DataType
+    ///
+    /// The basic data types such as Integers, Strings, etc.
+    ///
+    /// `schema:DataType` (`DATA_TYPE`)
+    pub static ref DATA_TYPE: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "DataType");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// Dataset
+    ///
+    /// A body of structured information describing some topic(s) of interest.
+    ///
+    /// `schema:Dataset` (`DATASET_CLASS`)
+    pub static ref DATASET_CLASS: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "Dataset");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// Date
+    ///
+    /// A date value in ISO 8601 date format.
+    ///
+    /// `schema:Date` (`DATE`)
+    pub static ref DATE: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "Date");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// DateTime
+    ///
+    /// A combination of date and time of day in the form [-]CCYY-MM-DDThh:mm:ss[Z|(+|-)hh:mm] (see Chapter 5.4 of ISO 8601).
+    ///
+    /// `schema:DateTime` (`DATE_TIME`)
+    pub static ref DATE_TIME: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "DateTime");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// DatedMoneySpecification
+    ///
+    /// A DatedMoneySpecification represents monetary values with optional start and end dates. For example, this could represent an employee's salary over a specific period of time.
+    ///
+    /// `schema:DatedMoneySpecification` (`DATED_MONEY_SPECIFICATION`)
+    pub static ref DATED_MONEY_SPECIFICATION: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "DatedMoneySpecification");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// DayOfWeek
+    ///
+    /// The day of the week, e.g. used to specify to which day the opening hours of an OpeningHoursSpecification refer.
+    ///
+    /// `schema:DayOfWeek` (`DAY_OF_WEEK_CLASS`)
+    pub static ref DAY_OF_WEEK_CLASS: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "DayOfWeek");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// DaySpa
+    ///
+    /// A day spa.
+    ///
+    /// `schema:DaySpa` (`DAY_SPA`)
+    pub static ref DAY_SPA: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "DaySpa");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// DeactivateAction
+    ///
+    /// The act of stopping or deactivating a device or application (e.g. stopping a timer or turning off a flashlight).
+    ///
+    /// `schema:DeactivateAction` (`DEACTIVATE_ACTION`)
+    pub static ref DEACTIVATE_ACTION: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "DeactivateAction");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// DefenceEstablishment
+    ///
+    /// A defence establishment, such as an army or navy base.
+    ///
+    /// `schema:DefenceEstablishment` (`DEFENCE_ESTABLISHMENT`)
+    pub static ref DEFENCE_ESTABLISHMENT: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "DefenceEstablishment");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// DefinedRegion
+    ///
+    /// A DefinedRegion is a geographic area defined by potentially arbitrary (rather than political, administrative or natural geographical) criteria. Properties are provided for defining a region by reference to sets of postal codes.
+    ///
+    /// `schema:DefinedRegion` (`DEFINED_REGION`)
+    pub static ref DEFINED_REGION: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "DefinedRegion");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// DefinedTerm
+    ///
+    /// A word, name, acronym, phrase, etc. with a formal definition. Often used in the context of category or subject classification, glossaries or dictionaries, product or creative work types, etc. Use the name property for the term being defined, use termCode if the term has an alpha-numeric code allocated, use description to provide the definition of the term.
+    ///
+    /// `schema:DefinedTerm` (`DEFINED_TERM`)
+    pub static ref DEFINED_TERM: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "DefinedTerm");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// DefinedTermSet
+    ///
+    /// A set of defined terms, for example a set of categories or a classification scheme, a glossary, dictionary or enumeration.
+    ///
+    /// `schema:DefinedTermSet` (`DEFINED_TERM_SET`)
+    pub static ref DEFINED_TERM_SET: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "DefinedTermSet");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// DeleteAction
+    ///
+    /// The act of editing a recipient by removing one of its objects.
+    ///
+    /// `schema:DeleteAction` (`DELETE_ACTION`)
+    pub static ref DELETE_ACTION: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "DeleteAction");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// DeliveryChargeSpecification
+    ///
+    /// The price for the delivery of an offer using a particular delivery method.
+    ///
+    /// `schema:DeliveryChargeSpecification` (`DELIVERY_CHARGE_SPECIFICATION`)
+    pub static ref DELIVERY_CHARGE_SPECIFICATION: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "DeliveryChargeSpecification");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// DeliveryEvent
+    ///
+    /// An event involving the delivery of an item.
+    ///
+    /// `schema:DeliveryEvent` (`DELIVERY_EVENT`)
+    pub static ref DELIVERY_EVENT: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "DeliveryEvent");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// DeliveryMethod
+    ///
+    /// A delivery method is a standardized procedure for transferring the product or service to the destination of fulfillment chosen by the customer. Delivery methods are characterized by the means of transportation used, and by the organization or group that is the contracting party for the sending organization or person.
+    ///
+    /// `schema:DeliveryMethod` (`DELIVERY_METHOD_CLASS`)
+    pub static ref DELIVERY_METHOD_CLASS: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "DeliveryMethod");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// DeliveryTimeSettings
+    ///
+    /// A DeliveryTimeSettings represents re-usable pieces of shipping information, relating to timing. It is designed for publication on an URL that may be referenced via the shippingSettingsLink property of an OfferShippingDetails.
+    ///
+    /// `schema:DeliveryTimeSettings` (`DELIVERY_TIME_SETTINGS`)
+    pub static ref DELIVERY_TIME_SETTINGS: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "DeliveryTimeSettings");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// Demand
+    ///
+    /// A demand entity represents the public, not necessarily binding, not necessarily exclusive, announcement by an organization or person to seek a certain type of goods or services.
+    ///
+    /// `schema:Demand` (`DEMAND`)
+    pub static ref DEMAND: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "Demand");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// Dentist
+    ///
+    /// A dentist.
+    ///
+    /// `schema:Dentist` (`DENTIST`)
+    pub static ref DENTIST: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "Dentist");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// DepartAction
+    ///
+    /// The act of departing from a place. An agent departs from a fromLocation for a destination, optionally with participants.
+    ///
+    /// `schema:DepartAction` (`DEPART_ACTION`)
+    pub static ref DEPART_ACTION: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "DepartAction");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// DepartmentStore
+    ///
+    /// A department store.
+    ///
+    /// `schema:DepartmentStore` (`DEPARTMENT_STORE`)
+    pub static ref DEPARTMENT_STORE: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "DepartmentStore");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// DepositAccount
+    ///
+    /// A type of Bank Account with a main purpose of depositing funds to gain interest or other benefits.
+    ///
+    /// `schema:DepositAccount` (`DEPOSIT_ACCOUNT`)
+    pub static ref DEPOSIT_ACCOUNT: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "DepositAccount");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// Dermatology
+    ///
+    /// A specific branch of medical science that pertains to diagnosis and treatment of disorders of skin.
+    ///
+    /// `schema:Dermatology` (`DERMATOLOGY`)
+    pub static ref DERMATOLOGY: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "Dermatology");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// DiagnosticLab
+    ///
+    /// A medical laboratory that offers on-site or off-site diagnostic services.
+    ///
+    /// `schema:DiagnosticLab` (`DIAGNOSTIC_LAB`)
+    pub static ref DIAGNOSTIC_LAB: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "DiagnosticLab");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// DiagnosticProcedure
+    ///
+    /// A medical procedure intended primarily for diagnostic, as opposed to therapeutic, purposes.
+    ///
+    /// `schema:DiagnosticProcedure` (`DIAGNOSTIC_PROCEDURE`)
+    pub static ref DIAGNOSTIC_PROCEDURE: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "DiagnosticProcedure");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// Diet
+    ///
+    /// A strategy of regulating the intake of food to achieve or maintain a specific health-related goal.
+    ///
+    /// `schema:Diet` (`DIET_CLASS`)
+    pub static ref DIET_CLASS: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "Diet");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// DietNutrition
+    ///
+    /// Dietetic and nutrition as a medical specialty.
+    ///
+    /// `schema:DietNutrition` (`DIET_NUTRITION`)
+    pub static ref DIET_NUTRITION: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "DietNutrition");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// DietarySupplement
+    ///
+    /// A product taken by mouth that contains a dietary ingredient intended to supplement the diet. Dietary ingredients may include vitamins, minerals, herbs or other botanicals, amino acids, and substances such as enzymes, organ tissues, glandulars and metabolites.
+    ///
+    /// `schema:DietarySupplement` (`DIETARY_SUPPLEMENT`)
+    pub static ref DIETARY_SUPPLEMENT: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "DietarySupplement");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// DigitalDocument
+    ///
+    /// An electronic file or document.
+    ///
+    /// `schema:DigitalDocument` (`DIGITAL_DOCUMENT`)
+    pub static ref DIGITAL_DOCUMENT: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "DigitalDocument");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// DigitalDocumentPermission
+    ///
+    /// A permission for a particular person or group to access a particular file.
+    ///
+    /// `schema:DigitalDocumentPermission` (`DIGITAL_DOCUMENT_PERMISSION`)
+    pub static ref DIGITAL_DOCUMENT_PERMISSION: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "DigitalDocumentPermission");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// DigitalDocumentPermissionType
+    ///
+    /// A type of permission which can be granted for accessing a digital document.
+    ///
+    /// `schema:DigitalDocumentPermissionType` (`DIGITAL_DOCUMENT_PERMISSION_TYPE`)
+    pub static ref DIGITAL_DOCUMENT_PERMISSION_TYPE: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "DigitalDocumentPermissionType");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// DigitalPlatformEnumeration
+    ///
+    /// Enumerates some common technology platforms, for use with properties such as actionPlatform. It is not supposed to be comprehensive - when a suitable code is not enumerated here, textual or URL values can be used instead.
+    ///
+    /// `schema:DigitalPlatformEnumeration` (`DIGITAL_PLATFORM_ENUMERATION`)
+    pub static ref DIGITAL_PLATFORM_ENUMERATION: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "DigitalPlatformEnumeration");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// DisagreeAction
+    ///
+    /// The act of expressing a difference of opinion with the object. An agent disagrees to/about an object (a proposition, topic or theme) with participants.
+    ///
+    /// `schema:DisagreeAction` (`DISAGREE_ACTION`)
+    pub static ref DISAGREE_ACTION: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "DisagreeAction");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// DiscoverAction
+    ///
+    /// The act of discovering/finding an object.
+    ///
+    /// `schema:DiscoverAction` (`DISCOVER_ACTION`)
+    pub static ref DISCOVER_ACTION: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "DiscoverAction");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// DiscussionForumPosting
+    ///
+    /// A posting to a discussion forum.
+    ///
+    /// `schema:DiscussionForumPosting` (`DISCUSSION_FORUM_POSTING`)
+    pub static ref DISCUSSION_FORUM_POSTING: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "DiscussionForumPosting");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// DislikeAction
+    ///
+    /// The act of expressing a negative sentiment about the object. An agent dislikes an object (a proposition, topic or theme) with participants.
+    ///
+    /// `schema:DislikeAction` (`DISLIKE_ACTION`)
+    pub static ref DISLIKE_ACTION: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "DislikeAction");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// Distance
+    ///
+    /// Properties that take Distances as values are of the form 'Number Length-unit-of-measure'. E.g., '7 ft'.
+    ///
+    /// `schema:Distance` (`DISTANCE_CLASS`)
+    pub static ref DISTANCE_CLASS: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "Distance");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// Distillery
+    ///
+    /// A distillery.
+    ///
+    /// `schema:Distillery` (`DISTILLERY`)
+    pub static ref DISTILLERY: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "Distillery");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// DonateAction
+    ///
+    /// The act of providing goods, services, or money without compensation, often for philanthropic reasons.
+    ///
+    /// `schema:DonateAction` (`DONATE_ACTION`)
+    pub static ref DONATE_ACTION: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "DonateAction");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// DoseSchedule
+    ///
+    /// A specific dosing schedule for a drug or supplement.
+    ///
+    /// `schema:DoseSchedule` (`DOSE_SCHEDULE_CLASS`)
+    pub static ref DOSE_SCHEDULE_CLASS: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "DoseSchedule");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// DownloadAction
+    ///
+    /// The act of downloading an object.
+    ///
+    /// `schema:DownloadAction` (`DOWNLOAD_ACTION`)
+    pub static ref DOWNLOAD_ACTION: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "DownloadAction");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// DrawAction
+    ///
+    /// The act of producing a visual/graphical representation of an object, typically with a pen/pencil and paper as instruments.
+    ///
+    /// `schema:DrawAction` (`DRAW_ACTION`)
+    pub static ref DRAW_ACTION: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "DrawAction");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// Drawing
+    ///
+    /// A picture or diagram made with a pencil, pen, or crayon rather than paint.
+    ///
+    /// `schema:Drawing` (`DRAWING`)
+    pub static ref DRAWING: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "Drawing");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// DrinkAction
+    ///
+    /// The act of swallowing liquids.
+    ///
+    /// `schema:DrinkAction` (`DRINK_ACTION`)
+    pub static ref DRINK_ACTION: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "DrinkAction");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// DriveWheelConfigurationValue
+    ///
+    /// A value indicating which roadwheels will receive torque.
+    ///
+    /// `schema:DriveWheelConfigurationValue` (`DRIVE_WHEEL_CONFIGURATION_VALUE`)
+    pub static ref DRIVE_WHEEL_CONFIGURATION_VALUE: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "DriveWheelConfigurationValue");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// Drug
+    ///
+    /// A chemical or biologic substance, used as a medical therapy, that has a physiological effect on an organism. Here the term drug is used interchangeably with the term medicine although clinical knowledge makes a clear difference between them.
+    ///
+    /// `schema:Drug` (`DRUG_CLASS_2`)
+    pub static ref DRUG_CLASS_2: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "Drug");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// DrugClass
+    ///
+    /// A class of medical drugs, e.g., statins. Classes can represent general pharmacological class, common mechanisms of action, common physiological effects, etc.
+    ///
+    /// `schema:DrugClass` (`DRUG_CLASS_CLASS`)
+    pub static ref DRUG_CLASS_CLASS: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "DrugClass");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// DrugCost
+    ///
+    /// The cost per unit of a medical drug. Note that this type is not meant to represent the price in an offer of a drug for sale; see the Offer type for that. This type will typically be used to tag wholesale or average retail cost of a drug, or maximum reimbursable cost.
+    ///
+    /// `schema:DrugCost` (`DRUG_COST`)
+    pub static ref DRUG_COST: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "DrugCost");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// DrugCostCategory
+    ///
+    /// Enumerated categories of medical drug costs.
+    ///
+    /// `schema:DrugCostCategory` (`DRUG_COST_CATEGORY`)
+    pub static ref DRUG_COST_CATEGORY: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "DrugCostCategory");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// DrugLegalStatus
+    ///
+    /// The legal availability status of a medical drug.
+    ///
+    /// `schema:DrugLegalStatus` (`DRUG_LEGAL_STATUS`)
+    pub static ref DRUG_LEGAL_STATUS: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "DrugLegalStatus");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// DrugPregnancyCategory
+    ///
+    /// Categories that represent an assessment of the risk of fetal injury due to a drug or pharmaceutical used as directed by the mother during pregnancy.
+    ///
+    /// `schema:DrugPregnancyCategory` (`DRUG_PREGNANCY_CATEGORY`)
+    pub static ref DRUG_PREGNANCY_CATEGORY: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "DrugPregnancyCategory");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// DrugPrescriptionStatus
+    ///
+    /// Indicates whether this drug is available by prescription or over-the-counter.
+    ///
+    /// `schema:DrugPrescriptionStatus` (`DRUG_PRESCRIPTION_STATUS`)
+    pub static ref DRUG_PRESCRIPTION_STATUS: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "DrugPrescriptionStatus");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// DrugStrength
+    ///
+    /// A specific strength in which a medical drug is available in a specific country.
+    ///
+    /// `schema:DrugStrength` (`DRUG_STRENGTH`)
+    pub static ref DRUG_STRENGTH: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "DrugStrength");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// DryCleaningOrLaundry
+    ///
+    /// A dry-cleaning business.
+    ///
+    /// `schema:DryCleaningOrLaundry` (`DRY_CLEANING_OR_LAUNDRY`)
+    pub static ref DRY_CLEANING_OR_LAUNDRY: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "DryCleaningOrLaundry");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// Duration
+    ///
+    /// Quantity: Duration (use ISO 8601 duration format).
+    ///
+    /// `schema:Duration` (`DURATION_CLASS`)
+    pub static ref DURATION_CLASS: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "Duration");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// EUEnergyEfficiencyEnumeration
+    ///
+    /// Enumerates the EU energy efficiency classes A-G as well as A+, A++, and A+++ as defined in EU directive 2017/1369.
+    ///
+    /// `schema:EUEnergyEfficiencyEnumeration` (`EU_ENERGY_EFFICIENCY_ENUMERATION`)
+    pub static ref EU_ENERGY_EFFICIENCY_ENUMERATION: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "EUEnergyEfficiencyEnumeration");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// EatAction
+    ///
+    /// The act of swallowing solid objects.
+    ///
+    /// `schema:EatAction` (`EAT_ACTION`)
+    pub static ref EAT_ACTION: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "EatAction");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// EducationEvent
+    ///
+    /// Event type: Education event.
+    ///
+    /// `schema:EducationEvent` (`EDUCATION_EVENT`)
+    pub static ref EDUCATION_EVENT: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "EducationEvent");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// EducationalAudience
+    ///
+    /// An EducationalAudience.
+    ///
+    /// `schema:EducationalAudience` (`EDUCATIONAL_AUDIENCE`)
+    pub static ref EDUCATIONAL_AUDIENCE: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "EducationalAudience");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// EducationalOccupationalCredential
+    ///
+    /// An educational or occupational credential. A diploma, academic degree, certification, qualification, badge, etc., that may be awarded to a person or other entity that meets the requirements defined by the credentialer.
+    ///
+    /// `schema:EducationalOccupationalCredential` (`EDUCATIONAL_OCCUPATIONAL_CREDENTIAL`)
+    pub static ref EDUCATIONAL_OCCUPATIONAL_CREDENTIAL: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "EducationalOccupationalCredential");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// EducationalOccupationalProgram
+    ///
+    /// A program offered by an institution which determines the learning progress to achieve an outcome, usually a credential like a degree or certificate.
+    ///
+    /// `schema:EducationalOccupationalProgram` (`EDUCATIONAL_OCCUPATIONAL_PROGRAM`)
+    pub static ref EDUCATIONAL_OCCUPATIONAL_PROGRAM: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "EducationalOccupationalProgram");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// EducationalOrganization
+    ///
+    /// An educational organization.
+    ///
+    /// `schema:EducationalOrganization` (`EDUCATIONAL_ORGANIZATION`)
+    pub static ref EDUCATIONAL_ORGANIZATION: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "EducationalOrganization");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// Electrician
+    ///
+    /// An electrician.
+    ///
+    /// `schema:Electrician` (`ELECTRICIAN`)
+    pub static ref ELECTRICIAN: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "Electrician");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// ElectronicsStore
+    ///
+    /// An electronics store.
+    ///
+    /// `schema:ElectronicsStore` (`ELECTRONICS_STORE`)
+    pub static ref ELECTRONICS_STORE: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "ElectronicsStore");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// ElementarySchool
+    ///
+    /// An elementary school.
+    ///
+    /// `schema:ElementarySchool` (`ELEMENTARY_SCHOOL`)
+    pub static ref ELEMENTARY_SCHOOL: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "ElementarySchool");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// EmailMessage
+    ///
+    /// An email message.
+    ///
+    /// `schema:EmailMessage` (`EMAIL_MESSAGE`)
+    pub static ref EMAIL_MESSAGE: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "EmailMessage");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// Embassy
+    ///
+    /// An embassy.
+    ///
+    /// `schema:Embassy` (`EMBASSY`)
+    pub static ref EMBASSY: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "Embassy");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// Emergency
+    ///
+    /// A specific branch of medical science that deals with the evaluation and initial treatment of medical conditions caused by trauma or sudden illness.
+    ///
+    /// `schema:Emergency` (`EMERGENCY`)
+    pub static ref EMERGENCY: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "Emergency");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// EmergencyService
+    ///
+    /// An emergency service, such as a fire station or ER.
+    ///
+    /// `schema:EmergencyService` (`EMERGENCY_SERVICE`)
+    pub static ref EMERGENCY_SERVICE: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "EmergencyService");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// EmployeeRole
+    ///
+    /// A subclass of OrganizationRole used to describe employee relationships.
+    ///
+    /// `schema:EmployeeRole` (`EMPLOYEE_ROLE`)
+    pub static ref EMPLOYEE_ROLE: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "EmployeeRole");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// EmployerAggregateRating
+    ///
+    /// An aggregate rating of an Organization related to its role as an employer.
+    ///
+    /// `schema:EmployerAggregateRating` (`EMPLOYER_AGGREGATE_RATING`)
+    pub static ref EMPLOYER_AGGREGATE_RATING: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "EmployerAggregateRating");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// EmployerReview
+    ///
+    /// An EmployerReview is a review of an Organization regarding its role as an employer, written by a current or former employee of that organization.
+    ///
+    /// `schema:EmployerReview` (`EMPLOYER_REVIEW`)
+    pub static ref EMPLOYER_REVIEW: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "EmployerReview");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// EmploymentAgency
+    ///
+    /// An employment agency.
+    ///
+    /// `schema:EmploymentAgency` (`EMPLOYMENT_AGENCY`)
+    pub static ref EMPLOYMENT_AGENCY: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "EmploymentAgency");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// EndorseAction
+    ///
+    /// An agent approves/certifies/likes/supports/sanctions an object.
+    ///
+    /// `schema:EndorseAction` (`ENDORSE_ACTION`)
+    pub static ref ENDORSE_ACTION: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "EndorseAction");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// EndorsementRating
+    ///
+    /// An EndorsementRating is a rating that expresses some level of endorsement, for example inclusion in a "critic's pick" blog, a "Like" or "+1" on a social network. It can be considered the result of an EndorseAction in which the object of the action is rated positively by some agent.
+    ///
+    /// `schema:EndorsementRating` (`ENDORSEMENT_RATING`)
+    pub static ref ENDORSEMENT_RATING: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "EndorsementRating");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// Energy
+    ///
+    /// Properties that take Energy as values are of the form 'Number Energy-unit-of-measure'.
+    ///
+    /// `schema:Energy` (`ENERGY`)
+    pub static ref ENERGY: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "Energy");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// EnergyConsumptionDetails
+    ///
+    /// EnergyConsumptionDetails represents information related to the energy efficiency of a product that consumes energy. The information that can be provided is based on international regulations such as for example EU directive 2017/1369 for energy labeling and the Energy labeling rule under the Energy Policy and Conservation Act (EPCA) in the US.
+    ///
+    /// `schema:EnergyConsumptionDetails` (`ENERGY_CONSUMPTION_DETAILS`)
+    pub static ref ENERGY_CONSUMPTION_DETAILS: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "EnergyConsumptionDetails");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// EnergyEfficiencyEnumeration
+    ///
+    /// Enumerates energy efficiency levels (also known as "classes" or "ratings") and certifications that are part of several international energy efficiency standards.
+    ///
+    /// `schema:EnergyEfficiencyEnumeration` (`ENERGY_EFFICIENCY_ENUMERATION`)
+    pub static ref ENERGY_EFFICIENCY_ENUMERATION: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "EnergyEfficiencyEnumeration");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// EnergyStarEnergyEfficiencyEnumeration
+    ///
+    /// Used to indicate whether a product is EnergyStar certified.
+    ///
+    /// `schema:EnergyStarEnergyEfficiencyEnumeration` (`ENERGY_STAR_ENERGY_EFFICIENCY_ENUMERATION`)
+    pub static ref ENERGY_STAR_ENERGY_EFFICIENCY_ENUMERATION: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "EnergyStarEnergyEfficiencyEnumeration");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// EngineSpecification
+    ///
+    /// Information about the engine of the vehicle. A vehicle can have multiple engines represented by multiple engine specification entities.
+    ///
+    /// `schema:EngineSpecification` (`ENGINE_SPECIFICATION`)
+    pub static ref ENGINE_SPECIFICATION: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "EngineSpecification");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// EntertainmentBusiness
+    ///
+    /// A business providing entertainment.
+    ///
+    /// `schema:EntertainmentBusiness` (`ENTERTAINMENT_BUSINESS_CLASS`)
+    pub static ref ENTERTAINMENT_BUSINESS_CLASS: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "EntertainmentBusiness");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// EntryPoint
+    ///
+    /// An entry point, within some Web-based protocol.
+    ///
+    /// `schema:EntryPoint` (`ENTRY_POINT`)
+    pub static ref ENTRY_POINT: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "EntryPoint");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// Enumeration
+    ///
+    /// Lists or enumerations—for example, a list of cuisines or music genres, etc.
+    ///
+    /// `schema:Enumeration` (`ENUMERATION`)
+    pub static ref ENUMERATION: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "Enumeration");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// Episode
+    ///
+    /// A media episode (e.g. TV, radio, video game) which can be part of a series or season.
+    ///
+    /// `schema:Episode` (`EPISODE_CLASS`)
+    pub static ref EPISODE_CLASS: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "Episode");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// Event
+    ///
+    /// An event happening at a certain time and location, such as a concert, lecture, or festival. Ticketing information may be added via the offers property. Repeated events may be structured as separate Event objects.
+    ///
+    /// `schema:Event` (`EVENT_CLASS`)
+    pub static ref EVENT_CLASS: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "Event");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// EventAttendanceModeEnumeration
+    ///
+    /// An EventAttendanceModeEnumeration value is one of potentially several modes of organising an event, relating to whether it is online or offline.
+    ///
+    /// `schema:EventAttendanceModeEnumeration` (`EVENT_ATTENDANCE_MODE_ENUMERATION`)
+    pub static ref EVENT_ATTENDANCE_MODE_ENUMERATION: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "EventAttendanceModeEnumeration");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// EventReservation
+    ///
+    /// A reservation for an event like a concert, sporting event, or lecture.
+    ///
+    /// `schema:EventReservation` (`EVENT_RESERVATION`)
+    pub static ref EVENT_RESERVATION: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "EventReservation");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// EventSeries
+    ///
+    /// A series of Events. Included events can relate with the series using the superEvent property.
+    ///
+    /// `schema:EventSeries` (`EVENT_SERIES`)
+    pub static ref EVENT_SERIES: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "EventSeries");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// EventStatusType
+    ///
+    /// EventStatusType is an enumeration type whose instances represent several states that an Event may be in.
+    ///
+    /// `schema:EventStatusType` (`EVENT_STATUS_TYPE`)
+    pub static ref EVENT_STATUS_TYPE: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "EventStatusType");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// EventVenue
+    ///
+    /// An event venue.
+    ///
+    /// `schema:EventVenue` (`EVENT_VENUE`)
+    pub static ref EVENT_VENUE: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "EventVenue");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// ExchangeRateSpecification
+    ///
+    /// A structured value representing exchange rate.
+    ///
+    /// `schema:ExchangeRateSpecification` (`EXCHANGE_RATE_SPECIFICATION`)
+    pub static ref EXCHANGE_RATE_SPECIFICATION: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "ExchangeRateSpecification");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// ExerciseAction
+    ///
+    /// The act of participating in exertive activity for the purposes of improving health and fitness.
+    ///
+    /// `schema:ExerciseAction` (`EXERCISE_ACTION`)
+    pub static ref EXERCISE_ACTION: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "ExerciseAction");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// ExerciseGym
+    ///
+    /// A gym.
+    ///
+    /// `schema:ExerciseGym` (`EXERCISE_GYM`)
+    pub static ref EXERCISE_GYM: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "ExerciseGym");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// ExercisePlan
+    ///
+    /// Fitness-related activity designed for a specific health-related purpose, including defined exercise routines as well as activity prescribed by a clinician.
+    ///
+    /// `schema:ExercisePlan` (`EXERCISE_PLAN_CLASS`)
+    pub static ref EXERCISE_PLAN_CLASS: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "ExercisePlan");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// ExhibitionEvent
+    ///
+    /// Event type: Exhibition event, e.g. at a museum, library, archive, tradeshow, ...
+    ///
+    /// `schema:ExhibitionEvent` (`EXHIBITION_EVENT`)
+    pub static ref EXHIBITION_EVENT: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "ExhibitionEvent");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// FAQPage
+    ///
+    /// A FAQPage is a WebPage presenting one or more "Frequently asked questions" (see also QAPage).
+    ///
+    /// `schema:FAQPage` (`FAQ_PAGE`)
+    pub static ref FAQ_PAGE: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "FAQPage");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// FMRadioChannel
+    ///
+    /// A radio channel that uses FM.
+    ///
+    /// `schema:FMRadioChannel` (`FM_RADIO_CHANNEL`)
+    pub static ref FM_RADIO_CHANNEL: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "FMRadioChannel");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// FastFoodRestaurant
+    ///
+    /// A fast-food restaurant.
+    ///
+    /// `schema:FastFoodRestaurant` (`FAST_FOOD_RESTAURANT`)
+    pub static ref FAST_FOOD_RESTAURANT: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "FastFoodRestaurant");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// Festival
+    ///
+    /// Event type: Festival.
+    ///
+    /// `schema:Festival` (`FESTIVAL`)
+    pub static ref FESTIVAL: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "Festival");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// FilmAction
+    ///
+    /// The act of capturing sound and moving images on film, video, or digitally.
+    ///
+    /// `schema:FilmAction` (`FILM_ACTION`)
+    pub static ref FILM_ACTION: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "FilmAction");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// FinancialIncentive
+    ///
+    /// Represents financial incentives for goods/services offered by an organization (or individual).
+    ///
+    /// `schema:FinancialIncentive` (`FINANCIAL_INCENTIVE`)
+    pub static ref FINANCIAL_INCENTIVE: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "FinancialIncentive");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// FinancialProduct
+    ///
+    /// A product provided to consumers and businesses by financial institutions such as banks, insurance companies, brokerage firms, consumer finance companies, and investment companies which comprise the financial services industry.
+    ///
+    /// `schema:FinancialProduct` (`FINANCIAL_PRODUCT`)
+    pub static ref FINANCIAL_PRODUCT: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "FinancialProduct");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// FinancialService
+    ///
+    /// Financial services business.
+    ///
+    /// `schema:FinancialService` (`FINANCIAL_SERVICE`)
+    pub static ref FINANCIAL_SERVICE: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "FinancialService");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// FindAction
+    ///
+    /// The act of finding an object.
+    ///
+    /// `schema:FindAction` (`FIND_ACTION`)
+    pub static ref FIND_ACTION: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "FindAction");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// FireStation
+    ///
+    /// A fire station. With firemen.
+    ///
+    /// `schema:FireStation` (`FIRE_STATION`)
+    pub static ref FIRE_STATION: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "FireStation");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// Flight
+    ///
+    /// An airline flight.
+    ///
+    /// `schema:Flight` (`FLIGHT`)
+    pub static ref FLIGHT: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "Flight");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// FlightReservation
+    ///
+    /// A reservation for air travel.
+    ///
+    /// `schema:FlightReservation` (`FLIGHT_RESERVATION`)
+    pub static ref FLIGHT_RESERVATION: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "FlightReservation");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// Float
+    ///
+    /// Data type: Floating number.
+    ///
+    /// `schema:Float` (`FLOAT`)
+    pub static ref FLOAT: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "Float");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// FloorPlan
+    ///
+    /// A FloorPlan is an explicit representation of a collection of similar accommodations, allowing the provision of common information (room counts, sizes, layout diagrams) and offers for rental or sale.
+    ///
+    /// `schema:FloorPlan` (`FLOOR_PLAN`)
+    pub static ref FLOOR_PLAN: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "FloorPlan");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// Florist
+    ///
+    /// A florist.
+    ///
+    /// `schema:Florist` (`FLORIST`)
+    pub static ref FLORIST: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "Florist");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// FollowAction
+    ///
+    /// The act of forming a personal connection with someone/something (object) unidirectionally/asymmetrically to get updates polled from.
+    ///
+    /// `schema:FollowAction` (`FOLLOW_ACTION`)
+    pub static ref FOLLOW_ACTION: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "FollowAction");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// FoodEstablishment
+    ///
+    /// A food-related business.
+    ///
+    /// `schema:FoodEstablishment` (`FOOD_ESTABLISHMENT_CLASS`)
+    pub static ref FOOD_ESTABLISHMENT_CLASS: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "FoodEstablishment");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// FoodEstablishmentReservation
+    ///
+    /// A reservation to dine at a food-related business.
+    ///
+    /// `schema:FoodEstablishmentReservation` (`FOOD_ESTABLISHMENT_RESERVATION`)
+    pub static ref FOOD_ESTABLISHMENT_RESERVATION: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "FoodEstablishmentReservation");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// FoodEvent
+    ///
+    /// Event type: Food event.
+    ///
+    /// `schema:FoodEvent` (`FOOD_EVENT_CLASS`)
+    pub static ref FOOD_EVENT_CLASS: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "FoodEvent");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// FoodService
+    ///
+    /// A food service, like breakfast, lunch, or dinner.
+    ///
+    /// `schema:FoodService` (`FOOD_SERVICE`)
+    pub static ref FOOD_SERVICE: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "FoodService");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// FulfillmentTypeEnumeration
+    ///
+    /// A type of product fulfillment.
+    ///
+    /// `schema:FulfillmentTypeEnumeration` (`FULFILLMENT_TYPE_ENUMERATION`)
+    pub static ref FULFILLMENT_TYPE_ENUMERATION: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "FulfillmentTypeEnumeration");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// FundingAgency
+    ///
+    /// A FundingAgency is an organization that implements one or more FundingSchemes and manages the granting process (via Grants, typically MonetaryGrants).
+    ///
+    /// `schema:FundingAgency` (`FUNDING_AGENCY`)
+    pub static ref FUNDING_AGENCY: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "FundingAgency");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// FundingScheme
+    ///
+    /// A FundingScheme combines organizational, project and policy aspects of grant-based funding that sets guidelines, principles and mechanisms to support other kinds of projects and activities.
+    ///
+    /// `schema:FundingScheme` (`FUNDING_SCHEME`)
+    pub static ref FUNDING_SCHEME: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "FundingScheme");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// FurnitureStore
+    ///
+    /// A furniture store.
+    ///
+    /// `schema:FurnitureStore` (`FURNITURE_STORE`)
+    pub static ref FURNITURE_STORE: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "FurnitureStore");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// Game
+    ///
+    /// The Game type represents things which are games. These are typically rule-governed recreational activities, e.g. role-playing games in which players assume the role of characters in a fictional setting.
+    ///
+    /// `schema:Game` (`GAME_CLASS`)
+    pub static ref GAME_CLASS: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "Game");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// GameAvailabilityEnumeration
+    ///
+    /// For a VideoGame, such as used with a PlayGameAction, an enumeration of the kind of game availability offered.
+    ///
+    /// `schema:GameAvailabilityEnumeration` (`GAME_AVAILABILITY_ENUMERATION`)
+    pub static ref GAME_AVAILABILITY_ENUMERATION: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "GameAvailabilityEnumeration");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// GamePlayMode
+    ///
+    /// Indicates whether this game is multi-player, co-op or single-player.
+    ///
+    /// `schema:GamePlayMode` (`GAME_PLAY_MODE`)
+    pub static ref GAME_PLAY_MODE: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "GamePlayMode");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// GameServer
+    ///
+    /// Server that provides game interaction in a multiplayer game.
+    ///
+    /// `schema:GameServer` (`GAME_SERVER_CLASS`)
+    pub static ref GAME_SERVER_CLASS: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "GameServer");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// GameServerStatus
+    ///
+    /// Status of a game server.
+    ///
+    /// `schema:GameServerStatus` (`GAME_SERVER_STATUS`)
+    pub static ref GAME_SERVER_STATUS: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "GameServerStatus");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// GardenStore
+    ///
+    /// A garden store.
+    ///
+    /// `schema:GardenStore` (`GARDEN_STORE`)
+    pub static ref GARDEN_STORE: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "GardenStore");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// GasStation
+    ///
+    /// A gas station.
+    ///
+    /// `schema:GasStation` (`GAS_STATION`)
+    pub static ref GAS_STATION: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "GasStation");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// GatedResidenceCommunity
+    ///
+    /// Residence type: Gated community.
+    ///
+    /// `schema:GatedResidenceCommunity` (`GATED_RESIDENCE_COMMUNITY`)
+    pub static ref GATED_RESIDENCE_COMMUNITY: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "GatedResidenceCommunity");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// GenderType
+    ///
+    /// An enumeration of genders.
+    ///
+    /// `schema:GenderType` (`GENDER_TYPE`)
+    pub static ref GENDER_TYPE: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "GenderType");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// Gene
+    ///
+    /// A discrete unit of inheritance which affects one or more biological traits (Source: https://en.wikipedia.org/wiki/Gene). Examples include FOXP2 (Forkhead box protein P2), SCARNA21 (small Cajal body-specific RNA 21), A- (agouti genotype).
+    ///
+    /// `schema:Gene` (`GENE`)
+    pub static ref GENE: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "Gene");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// GeneralContractor
+    ///
+    /// A general contractor.
+    ///
+    /// `schema:GeneralContractor` (`GENERAL_CONTRACTOR`)
+    pub static ref GENERAL_CONTRACTOR: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "GeneralContractor");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// GeoCircle
+    ///
+    /// A GeoCircle is a GeoShape representing a circular geographic area. As it is a GeoShape it provides the simple textual property 'circle', but also allows the combination of postalCode alongside geoRadius.
+    ///
+    /// `schema:GeoCircle` (`GEO_CIRCLE`)
+    pub static ref GEO_CIRCLE: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "GeoCircle");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// GeoCoordinates
+    ///
+    /// The geographic coordinates of a place or event.
+    ///
+    /// `schema:GeoCoordinates` (`GEO_COORDINATES`)
+    pub static ref GEO_COORDINATES: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "GeoCoordinates");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// GeoShape
+    ///
+    /// The geographic shape of a place. A GeoShape can be described using several properties whose values are based on latitude/longitude pairs. Either whitespace or commas can be used to separate latitude and longitude; whitespace should be used when writing a list of several such points.
+    ///
+    /// `schema:GeoShape` (`GEO_SHAPE`)
+    pub static ref GEO_SHAPE: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "GeoShape");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// GeospatialGeometry
+    ///
+    /// (Eventually to be defined as) a supertype of GeoShape designed to accommodate definitions from Geo-Spatial best practices.
+    ///
+    /// `schema:GeospatialGeometry` (`GEOSPATIAL_GEOMETRY`)
+    pub static ref GEOSPATIAL_GEOMETRY: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "GeospatialGeometry");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// Geriatric
+    ///
+    /// A specific branch of medical science that is concerned with the diagnosis and treatment of diseases, debilities and provision of care to the aged.
+    ///
+    /// `schema:Geriatric` (`GERIATRIC`)
+    pub static ref GERIATRIC: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "Geriatric");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// GiveAction
+    ///
+    /// The act of transferring ownership of an object to a destination. Reciprocal of TakeAction.
+    ///
+    /// `schema:GiveAction` (`GIVE_ACTION`)
+    pub static ref GIVE_ACTION: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "GiveAction");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// GolfCourse
+    ///
+    /// A golf course.
+    ///
+    /// `schema:GolfCourse` (`GOLF_COURSE`)
+    pub static ref GOLF_COURSE: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "GolfCourse");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// GovernmentBenefitsType
+    ///
+    /// GovernmentBenefitsType enumerates several kinds of government benefits to support the COVID-19 situation. Note that this structure may not capture all benefits offered.
+    ///
+    /// `schema:GovernmentBenefitsType` (`GOVERNMENT_BENEFITS_TYPE`)
+    pub static ref GOVERNMENT_BENEFITS_TYPE: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "GovernmentBenefitsType");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// GovernmentBuilding
+    ///
+    /// A government building.
+    ///
+    /// `schema:GovernmentBuilding` (`GOVERNMENT_BUILDING`)
+    pub static ref GOVERNMENT_BUILDING: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "GovernmentBuilding");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// GovernmentOffice
+    ///
+    /// A government office—for example, an IRS or DMV office.
+    ///
+    /// `schema:GovernmentOffice` (`GOVERNMENT_OFFICE`)
+    pub static ref GOVERNMENT_OFFICE: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "GovernmentOffice");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// GovernmentOrganization
+    ///
+    /// A governmental organization or agency.
+    ///
+    /// `schema:GovernmentOrganization` (`GOVERNMENT_ORGANIZATION`)
+    pub static ref GOVERNMENT_ORGANIZATION: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "GovernmentOrganization");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// GovernmentPermit
+    ///
+    /// A permit issued by a government agency.
+    ///
+    /// `schema:GovernmentPermit` (`GOVERNMENT_PERMIT`)
+    pub static ref GOVERNMENT_PERMIT: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "GovernmentPermit");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// GovernmentService
+    ///
+    /// A service provided by a government organization, e.g. food stamps, veterans benefits, etc.
+    ///
+    /// `schema:GovernmentService` (`GOVERNMENT_SERVICE`)
+    pub static ref GOVERNMENT_SERVICE: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "GovernmentService");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// Grant
+    ///
+    /// A grant, typically financial or otherwise quantifiable, of resources. Typically a funder sponsors some MonetaryAmount to an Organization or Person, sometimes not necessarily via a dedicated or long-lived Project, resulting in one or more outputs, or fundedItems.
+    ///
+    /// `schema:Grant` (`GRANT`)
+    pub static ref GRANT: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "Grant");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// GroceryStore
+    ///
+    /// A grocery store.
+    ///
+    /// `schema:GroceryStore` (`GROCERY_STORE`)
+    pub static ref GROCERY_STORE: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "GroceryStore");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// Guide
+    ///
+    /// Guide is a page or article that recommends specific products or services, or aspects of a thing for a user to consider. A Guide may represent a Buying Guide and detail aspects of products or services for a user to consider.
+    ///
+    /// `schema:Guide` (`GUIDE`)
+    pub static ref GUIDE: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "Guide");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// Gynecologic
+    ///
+    /// A specific branch of medical science that pertains to the health care of women, particularly in the diagnosis and treatment of disorders affecting the female reproductive system.
+    ///
+    /// `schema:Gynecologic` (`GYNECOLOGIC`)
+    pub static ref GYNECOLOGIC: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "Gynecologic");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// HVACBusiness
+    ///
+    /// A business that provides Heating, Ventilation and Air Conditioning services.
+    ///
+    /// `schema:HVACBusiness` (`HVAC_BUSINESS`)
+    pub static ref HVAC_BUSINESS: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "HVACBusiness");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// Hackathon
+    ///
+    /// A hackathon event.
+    ///
+    /// `schema:Hackathon` (`HACKATHON`)
+    pub static ref HACKATHON: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "Hackathon");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// HairSalon
+    ///
+    /// A hair salon.
+    ///
+    /// `schema:HairSalon` (`HAIR_SALON`)
+    pub static ref HAIR_SALON: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "HairSalon");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// HardwareStore
+    ///
+    /// A hardware store.
+    ///
+    /// `schema:HardwareStore` (`HARDWARE_STORE`)
+    pub static ref HARDWARE_STORE: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "HardwareStore");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// HealthAndBeautyBusiness
+    ///
+    /// Health and beauty.
+    ///
+    /// `schema:HealthAndBeautyBusiness` (`HEALTH_AND_BEAUTY_BUSINESS`)
+    pub static ref HEALTH_AND_BEAUTY_BUSINESS: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "HealthAndBeautyBusiness");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// HealthAspectEnumeration
+    ///
+    /// HealthAspectEnumeration enumerates several aspects of health content online, each of which might be described using hasHealthAspect and HealthTopicContent.
+    ///
+    /// `schema:HealthAspectEnumeration` (`HEALTH_ASPECT_ENUMERATION`)
+    pub static ref HEALTH_ASPECT_ENUMERATION: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "HealthAspectEnumeration");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// HealthClub
+    ///
+    /// A health club.
+    ///
+    /// `schema:HealthClub` (`HEALTH_CLUB`)
+    pub static ref HEALTH_CLUB: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "HealthClub");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// HealthInsurancePlan
+    ///
+    /// A US-style health insurance plan, including PPOs, EPOs, and HMOs.
+    ///
+    /// `schema:HealthInsurancePlan` (`HEALTH_INSURANCE_PLAN`)
+    pub static ref HEALTH_INSURANCE_PLAN: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "HealthInsurancePlan");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// HealthPlanCostSharingSpecification
+    ///
+    /// A description of costs to the patient under a given network or formulary.
+    ///
+    /// `schema:HealthPlanCostSharingSpecification` (`HEALTH_PLAN_COST_SHARING_SPECIFICATION`)
+    pub static ref HEALTH_PLAN_COST_SHARING_SPECIFICATION: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "HealthPlanCostSharingSpecification");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// HealthPlanFormulary
+    ///
+    /// For a given health insurance plan, the specification for costs and coverage of prescription drugs.
+    ///
+    /// `schema:HealthPlanFormulary` (`HEALTH_PLAN_FORMULARY`)
+    pub static ref HEALTH_PLAN_FORMULARY: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "HealthPlanFormulary");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// HealthPlanNetwork
+    ///
+    /// A US-style health insurance plan network.
+    ///
+    /// `schema:HealthPlanNetwork` (`HEALTH_PLAN_NETWORK`)
+    pub static ref HEALTH_PLAN_NETWORK: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "HealthPlanNetwork");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// HealthTopicContent
+    ///
+    /// HealthTopicContent is WebContent that is about some aspect of a health topic, e.g. a condition, its symptoms or treatments. Such content may be comprised of several parts or sections and use different types of media.
+    ///
+    /// `schema:HealthTopicContent` (`HEALTH_TOPIC_CONTENT`)
+    pub static ref HEALTH_TOPIC_CONTENT: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "HealthTopicContent");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// HighSchool
+    ///
+    /// A high school.
+    ///
+    /// `schema:HighSchool` (`HIGH_SCHOOL`)
+    pub static ref HIGH_SCHOOL: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "HighSchool");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// HinduTemple
+    ///
+    /// A Hindu temple.
+    ///
+    /// `schema:HinduTemple` (`HINDU_TEMPLE`)
+    pub static ref HINDU_TEMPLE: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "HinduTemple");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// HobbyShop
+    ///
+    /// A store that sells materials useful or necessary for various hobbies.
+    ///
+    /// `schema:HobbyShop` (`HOBBY_SHOP`)
+    pub static ref HOBBY_SHOP: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "HobbyShop");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// HomeAndConstructionBusiness
+    ///
+    /// A construction business.
+    ///
+    /// `schema:HomeAndConstructionBusiness` (`HOME_AND_CONSTRUCTION_BUSINESS`)
+    pub static ref HOME_AND_CONSTRUCTION_BUSINESS: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "HomeAndConstructionBusiness");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// HomeGoodsStore
+    ///
+    /// A home goods store.
+    ///
+    /// `schema:HomeGoodsStore` (`HOME_GOODS_STORE`)
+    pub static ref HOME_GOODS_STORE: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "HomeGoodsStore");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// Hospital
+    ///
+    /// A hospital.
+    ///
+    /// `schema:Hospital` (`HOSPITAL`)
+    pub static ref HOSPITAL: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "Hospital");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// Hostel
+    ///
+    /// A hostel - cheap accommodation, often in shared dormitories.
+    ///
+    /// `schema:Hostel` (`HOSTEL`)
+    pub static ref HOSTEL: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "Hostel");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// Hotel
+    ///
+    /// A hotel is an establishment that provides lodging paid on a short-term basis (source: Wikipedia, the free encyclopedia).
+    ///
+    /// `schema:Hotel` (`HOTEL`)
+    pub static ref HOTEL: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "Hotel");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// HotelRoom
+    ///
+    /// A hotel room is a single room in a hotel.
+    ///
+    /// `schema:HotelRoom` (`HOTEL_ROOM`)
+    pub static ref HOTEL_ROOM: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "HotelRoom");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// House
+    ///
+    /// A house is a building or structure that has the ability to be occupied for habitation by humans or other creatures.
+    ///
+    /// `schema:House` (`HOUSE`)
+    pub static ref HOUSE: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "House");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// HousePainter
+    ///
+    /// A house painting service.
+    ///
+    /// `schema:HousePainter` (`HOUSE_PAINTER`)
+    pub static ref HOUSE_PAINTER: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "HousePainter");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// HowTo
+    ///
+    /// Instructions that explain how to achieve a result by performing a sequence of steps.
+    ///
+    /// `schema:HowTo` (`HOW_TO`)
+    pub static ref HOW_TO: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "HowTo");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// HowToDirection
+    ///
+    /// A direction indicating a single action to do in the instructions for how to achieve a result.
+    ///
+    /// `schema:HowToDirection` (`HOW_TO_DIRECTION`)
+    pub static ref HOW_TO_DIRECTION: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "HowToDirection");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// HowToItem
+    ///
+    /// An item used as either a tool or supply when performing the instructions for how to achieve a result.
+    ///
+    /// `schema:HowToItem` (`HOW_TO_ITEM`)
+    pub static ref HOW_TO_ITEM: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "HowToItem");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// HowToSection
+    ///
+    /// A sub-grouping of steps in the instructions for how to achieve a result (e.g. steps for making a pie crust within a pie recipe).
+    ///
+    /// `schema:HowToSection` (`HOW_TO_SECTION`)
+    pub static ref HOW_TO_SECTION: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "HowToSection");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// HowToStep
+    ///
+    /// A step in the instructions for how to achieve a result. It is an ordered list with HowToDirection and/or HowToTip items.
+    ///
+    /// `schema:HowToStep` (`HOW_TO_STEP`)
+    pub static ref HOW_TO_STEP: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "HowToStep");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// HowToSupply
+    ///
+    /// A supply consumed when performing the instructions for how to achieve a result.
+    ///
+    /// `schema:HowToSupply` (`HOW_TO_SUPPLY`)
+    pub static ref HOW_TO_SUPPLY: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "HowToSupply");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// HowToTip
+    ///
+    /// An explanation in the instructions for how to achieve a result. It provides supplementary information about a technique, supply, author's preference, etc. It can explain what could be done, or what should not be done, but doesn't specify what should be done (see HowToDirection).
+    ///
+    /// `schema:HowToTip` (`HOW_TO_TIP`)
+    pub static ref HOW_TO_TIP: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "HowToTip");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// HowToTool
+    ///
+    /// A tool used (but not consumed) when performing instructions for how to achieve a result.
+    ///
+    /// `schema:HowToTool` (`HOW_TO_TOOL`)
+    pub static ref HOW_TO_TOOL: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "HowToTool");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// HyperToc
+    ///
+    /// A HyperToc represents a hypertext table of contents for complex media objects, such as VideoObject, AudioObject. Items in the table of contents are indicated using the tocEntry property, and typed HyperTocEntry.
+    ///
+    /// `schema:HyperToc` (`HYPER_TOC`)
+    pub static ref HYPER_TOC: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "HyperToc");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// HyperTocEntry
+    ///
+    /// A HyperToEntry is an item within a HyperToc, which represents a hypertext table of contents for complex media objects, such as VideoObject, AudioObject.
+    ///
+    /// `schema:HyperTocEntry` (`HYPER_TOC_ENTRY`)
+    pub static ref HYPER_TOC_ENTRY: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "HyperTocEntry");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// IceCreamShop
+    ///
+    /// An ice cream shop.
+    ///
+    /// `schema:IceCreamShop` (`ICE_CREAM_SHOP`)
+    pub static ref ICE_CREAM_SHOP: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "IceCreamShop");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// IgnoreAction
+    ///
+    /// The act of intentionally disregarding the object. An agent ignores an object.
+    ///
+    /// `schema:IgnoreAction` (`IGNORE_ACTION`)
+    pub static ref IGNORE_ACTION: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "IgnoreAction");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// ImageGallery
+    ///
+    /// Web page type: Image gallery page.
+    ///
+    /// `schema:ImageGallery` (`IMAGE_GALLERY`)
+    pub static ref IMAGE_GALLERY: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "ImageGallery");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// ImageObject
+    ///
+    /// An image file.
+    ///
+    /// `schema:ImageObject` (`IMAGE_OBJECT`)
+    pub static ref IMAGE_OBJECT: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "ImageObject");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// ImageObjectSnapshot
+    ///
+    /// A specific and exact (byte-for-byte) version of an ImageObject. Two byte-for-byte identical files, for the purposes of this type, considered identical.
+    ///
+    /// `schema:ImageObjectSnapshot` (`IMAGE_OBJECT_SNAPSHOT`)
+    pub static ref IMAGE_OBJECT_SNAPSHOT: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "ImageObjectSnapshot");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// ImagingTest
+    ///
+    /// Any medical imaging modality typically used for diagnostic purposes.
+    ///
+    /// `schema:ImagingTest` (`IMAGING_TEST`)
+    pub static ref IMAGING_TEST: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "ImagingTest");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// IncentiveEligibilityEnumeration
+    ///
+    /// Enumerates common financial incentives for products, including tax credits, tax deductions, rebates and subsidies, etc.
+    ///
+    /// `schema:IncentiveEligibilityEnumeration` (`INCENTIVE_ELIGIBILITY_ENUMERATION`)
+    pub static ref INCENTIVE_ELIGIBILITY_ENUMERATION: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "IncentiveEligibilityEnumeration");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// IncentiveQualifiedExpenseType
+    ///
+    /// The types of expenses that are covered by the incentive. For example some incentives are only for the goods (tangible items) but the services (labor) are excluded.
+    ///
+    /// `schema:IncentiveQualifiedExpenseType` (`INCENTIVE_QUALIFIED_EXPENSE_TYPE`)
+    pub static ref INCENTIVE_QUALIFIED_EXPENSE_TYPE: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "IncentiveQualifiedExpenseType");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// IncentiveStatus
+    ///
+    /// Enumerates a status for an incentive, such as whether it is active.
+    ///
+    /// `schema:IncentiveStatus` (`INCENTIVE_STATUS`)
+    pub static ref INCENTIVE_STATUS: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "IncentiveStatus");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// IncentiveType
+    ///
+    /// Enumerates common financial incentives for products, including tax credits, tax deductions, rebates and subsidies, etc.
+    ///
+    /// `schema:IncentiveType` (`INCENTIVE_TYPE`)
+    pub static ref INCENTIVE_TYPE: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "IncentiveType");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// IndividualPhysician
+    ///
+    /// An individual medical practitioner. For their official address use address, for affiliations to hospitals use hospitalAffiliation. The practicesAt property can be used to indicate MedicalOrganization hospitals, clinics, pharmacies etc. where this physician practices.
+    ///
+    /// `schema:IndividualPhysician` (`INDIVIDUAL_PHYSICIAN`)
+    pub static ref INDIVIDUAL_PHYSICIAN: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "IndividualPhysician");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// IndividualProduct
+    ///
+    /// A single, identifiable product instance (e.g. a laptop with a particular serial number).
+    ///
+    /// `schema:IndividualProduct` (`INDIVIDUAL_PRODUCT`)
+    pub static ref INDIVIDUAL_PRODUCT: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "IndividualProduct");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// InfectiousAgentClass
+    ///
+    /// Classes of agents or pathogens that transmit infectious diseases. Enumerated type.
+    ///
+    /// `schema:InfectiousAgentClass` (`INFECTIOUS_AGENT_CLASS_CLASS`)
+    pub static ref INFECTIOUS_AGENT_CLASS_CLASS: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "InfectiousAgentClass");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// InfectiousDisease
+    ///
+    /// An infectious disease is a clinically evident human disease resulting from the presence of pathogenic microbial agents, like pathogenic viruses, pathogenic bacteria, fungi, protozoa, multicellular parasites, and prions.
+    ///
+    /// `schema:InfectiousDisease` (`INFECTIOUS_DISEASE`)
+    pub static ref INFECTIOUS_DISEASE: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "InfectiousDisease");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// InformAction
+    ///
+    /// The act of notifying someone of information pertinent to them, with no expectation of a response.
+    ///
+    /// `schema:InformAction` (`INFORM_ACTION`)
+    pub static ref INFORM_ACTION: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "InformAction");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// InsertAction
+    ///
+    /// The act of adding at a specific location in an ordered collection.
+    ///
+    /// `schema:InsertAction` (`INSERT_ACTION`)
+    pub static ref INSERT_ACTION: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "InsertAction");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// InstallAction
+    ///
+    /// The act of installing an application.
+    ///
+    /// `schema:InstallAction` (`INSTALL_ACTION`)
+    pub static ref INSTALL_ACTION: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "InstallAction");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// InsuranceAgency
+    ///
+    /// An Insurance agency.
+    ///
+    /// `schema:InsuranceAgency` (`INSURANCE_AGENCY`)
+    pub static ref INSURANCE_AGENCY: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "InsuranceAgency");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// Intangible
+    ///
+    /// A utility class that serves as the umbrella for a number of 'intangible' things such as quantities, structured values, etc.
+    ///
+    /// `schema:Intangible` (`INTANGIBLE`)
+    pub static ref INTANGIBLE: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "Intangible");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// Integer
+    ///
+    /// Data type: Integer.
+    ///
+    /// `schema:Integer` (`INTEGER`)
+    pub static ref INTEGER: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "Integer");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// InteractAction
+    ///
+    /// The act of interacting with another person or organization.
+    ///
+    /// `schema:InteractAction` (`INTERACT_ACTION`)
+    pub static ref INTERACT_ACTION: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "InteractAction");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// InteractionCounter
+    ///
+    /// A summary of how users have interacted with this CreativeWork. In most cases, authors will use a subtype to specify the specific type of interaction.
+    ///
+    /// `schema:InteractionCounter` (`INTERACTION_COUNTER`)
+    pub static ref INTERACTION_COUNTER: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "InteractionCounter");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// InternetCafe
+    ///
+    /// An internet cafe.
+    ///
+    /// `schema:InternetCafe` (`INTERNET_CAFE`)
+    pub static ref INTERNET_CAFE: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "InternetCafe");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// InvestmentFund
+    ///
+    /// A company or fund that gathers capital from a number of investors to create a pool of money that is then re-invested into stocks, bonds and other assets.
+    ///
+    /// `schema:InvestmentFund` (`INVESTMENT_FUND`)
+    pub static ref INVESTMENT_FUND: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "InvestmentFund");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// InvestmentOrDeposit
+    ///
+    /// A type of financial product that typically requires the client to transfer funds to a financial service in return for potential beneficial financial return.
+    ///
+    /// `schema:InvestmentOrDeposit` (`INVESTMENT_OR_DEPOSIT`)
+    pub static ref INVESTMENT_OR_DEPOSIT: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "InvestmentOrDeposit");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// InviteAction
+    ///
+    /// The act of asking someone to attend an event. Another common synonym is Invite.
+    ///
+    /// `schema:InviteAction` (`INVITE_ACTION`)
+    pub static ref INVITE_ACTION: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "InviteAction");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// Invoice
+    ///
+    /// A statement of the money due for goods or services; a bill.
+    ///
+    /// `schema:Invoice` (`INVOICE`)
+    pub static ref INVOICE: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "Invoice");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// ItemAvailability
+    ///
+    /// A list of possible product availability options.
+    ///
+    /// `schema:ItemAvailability` (`ITEM_AVAILABILITY`)
+    pub static ref ITEM_AVAILABILITY: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "ItemAvailability");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// ItemList
+    ///
+    /// A list of items of any sort—for example, Top 10 Movies About Weathermen, or Top 100 Party Songs. Not to be confused with HTML lists, which are often used only for formatting.
+    ///
+    /// `schema:ItemList` (`ITEM_LIST`)
+    pub static ref ITEM_LIST: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "ItemList");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// ItemListOrderType
+    ///
+    /// Enumerated for values for itemListOrder for indicating how an ordered ItemList is organized.
+    ///
+    /// `schema:ItemListOrderType` (`ITEM_LIST_ORDER_TYPE`)
+    pub static ref ITEM_LIST_ORDER_TYPE: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "ItemListOrderType");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// ItemPage
+    ///
+    /// A page devoted to a single item, such as a particular product or hotel.
+    ///
+    /// `schema:ItemPage` (`ITEM_PAGE`)
+    pub static ref ITEM_PAGE: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "ItemPage");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// JewelryStore
+    ///
+    /// A jewelry store.
+    ///
+    /// `schema:JewelryStore` (`JEWELRY_STORE`)
+    pub static ref JEWELRY_STORE: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "JewelryStore");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// JobPosting
+    ///
+    /// A listing that describes a job opening in a certain organization.
+    ///
+    /// `schema:JobPosting` (`JOB_POSTING`)
+    pub static ref JOB_POSTING: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "JobPosting");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// JoinAction
+    ///
+    /// An agent joins an event/group with participants/friends at a location.
+    ///
+    /// `schema:JoinAction` (`JOIN_ACTION`)
+    pub static ref JOIN_ACTION: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "JoinAction");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// Joint
+    ///
+    /// The anatomical location at which two or more bones make contact.
+    ///
+    /// `schema:Joint` (`JOINT`)
+    pub static ref JOINT: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "Joint");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// LakeBodyOfWater
+    ///
+    /// A lake (for example, Lake Pontrachain).
+    ///
+    /// `schema:LakeBodyOfWater` (`LAKE_BODY_OF_WATER`)
+    pub static ref LAKE_BODY_OF_WATER: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "LakeBodyOfWater");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// Landform
+    ///
+    /// A landform or physical feature. Landform elements include mountains, plains, lakes, rivers, seascape and oceanic waterbody interface features such as bays, peninsulas, seas and so forth, including sub-aqueous terrain features such as submersed mountain ranges, volcanoes, and the great ocean basins.
+    ///
+    /// `schema:Landform` (`LANDFORM`)
+    pub static ref LANDFORM: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "Landform");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// LandmarksOrHistoricalBuildings
+    ///
+    /// An historical landmark or building.
+    ///
+    /// `schema:LandmarksOrHistoricalBuildings` (`LANDMARKS_OR_HISTORICAL_BUILDINGS`)
+    pub static ref LANDMARKS_OR_HISTORICAL_BUILDINGS: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "LandmarksOrHistoricalBuildings");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// Language
+    ///
+    /// Natural languages such as Spanish, Tamil, Hindi, English, etc. Formal language code tags expressed in BCP 47 can be used via the alternateName property.
+    ///
+    /// `schema:Language` (`LANGUAGE_CLASS`)
+    pub static ref LANGUAGE_CLASS: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "Language");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// LearningResource
+    ///
+    /// The LearningResource type can be used to indicate CreativeWorks (whether physical or digital) that have a particular and explicit orientation towards learning, education, skill acquisition, and other educational purposes.
+    ///
+    /// `schema:LearningResource` (`LEARNING_RESOURCE`)
+    pub static ref LEARNING_RESOURCE: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "LearningResource");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// LeaveAction
+    ///
+    /// An agent leaves an event / group with participants/friends at a location.
+    ///
+    /// `schema:LeaveAction` (`LEAVE_ACTION`)
+    pub static ref LEAVE_ACTION: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "LeaveAction");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// LegalForceStatus
+    ///
+    /// A list of possible statuses for the legal force of a legislation.
+    ///
+    /// `schema:LegalForceStatus` (`LEGAL_FORCE_STATUS`)
+    pub static ref LEGAL_FORCE_STATUS: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "LegalForceStatus");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// LegalService
+    ///
+    /// A LegalService is a business that provides legally-oriented services, advice and representation, e.g. law firms.
+    ///
+    /// `schema:LegalService` (`LEGAL_SERVICE`)
+    pub static ref LEGAL_SERVICE: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "LegalService");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// LegalValueLevel
+    ///
+    /// A list of possible levels for the legal validity of a legislation.
+    ///
+    /// `schema:LegalValueLevel` (`LEGAL_VALUE_LEVEL`)
+    pub static ref LEGAL_VALUE_LEVEL: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "LegalValueLevel");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// Legislation
+    ///
+    /// A legal document such as an act, decree, bill, etc. (enforceable or not) or a component of a legal act (like an article).
+    ///
+    /// `schema:Legislation` (`LEGISLATION`)
+    pub static ref LEGISLATION: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "Legislation");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// LegislationObject
+    ///
+    /// A specific object or file containing a Legislation. Note that the same Legislation can be published in multiple files.
+    ///
+    /// `schema:LegislationObject` (`LEGISLATION_OBJECT`)
+    pub static ref LEGISLATION_OBJECT: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "LegislationObject");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// LegislativeBuilding
+    ///
+    /// A legislative building—for example, the state capitol.
+    ///
+    /// `schema:LegislativeBuilding` (`LEGISLATIVE_BUILDING`)
+    pub static ref LEGISLATIVE_BUILDING: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "LegislativeBuilding");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// LendAction
+    ///
+    /// The act of providing an object under an agreement that it will be returned at a later date. Reciprocal of BorrowAction.
+    ///
+    /// `schema:LendAction` (`LEND_ACTION`)
+    pub static ref LEND_ACTION: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "LendAction");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// Library
+    ///
+    /// A library.
+    ///
+    /// `schema:Library` (`LIBRARY`)
+    pub static ref LIBRARY: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "Library");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// LibrarySystem
+    ///
+    /// A LibrarySystem is a collaborative system amongst several libraries.
+    ///
+    /// `schema:LibrarySystem` (`LIBRARY_SYSTEM`)
+    pub static ref LIBRARY_SYSTEM: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "LibrarySystem");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// LifestyleModification
+    ///
+    /// A process of care involving exercise, changes to diet, fitness routines, and other lifestyle changes aimed at improving a health condition.
+    ///
+    /// `schema:LifestyleModification` (`LIFESTYLE_MODIFICATION`)
+    pub static ref LIFESTYLE_MODIFICATION: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "LifestyleModification");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// Ligament
+    ///
+    /// A short band of tough, flexible, fibrous connective tissue that functions to connect multiple bones, cartilages, and structurally support joints.
+    ///
+    /// `schema:Ligament` (`LIGAMENT`)
+    pub static ref LIGAMENT: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "Ligament");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// LikeAction
+    ///
+    /// The act of expressing a positive sentiment about the object. An agent likes an object (a proposition, topic or theme) with participants.
+    ///
+    /// `schema:LikeAction` (`LIKE_ACTION`)
+    pub static ref LIKE_ACTION: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "LikeAction");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// LinkRole
+    ///
+    /// A Role that represents a Web link, e.g. as expressed via the 'url' property. Its linkRelationship property can indicate URL-based and plain textual link types, e.g. those in IANA link registry or others such as 'amphtml'.
+    ///
+    /// `schema:LinkRole` (`LINK_ROLE`)
+    pub static ref LINK_ROLE: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "LinkRole");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// LiquorStore
+    ///
+    /// A shop that sells alcoholic drinks such as wine, beer, whisky and other spirits.
+    ///
+    /// `schema:LiquorStore` (`LIQUOR_STORE`)
+    pub static ref LIQUOR_STORE: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "LiquorStore");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// ListItem
+    ///
+    /// An list item, e.g. a step in a checklist or how-to description.
+    ///
+    /// `schema:ListItem` (`LIST_ITEM`)
+    pub static ref LIST_ITEM: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "ListItem");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// ListenAction
+    ///
+    /// The act of consuming audio content.
+    ///
+    /// `schema:ListenAction` (`LISTEN_ACTION`)
+    pub static ref LISTEN_ACTION: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "ListenAction");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// LiteraryEvent
+    ///
+    /// Event type: Literary event.
+    ///
+    /// `schema:LiteraryEvent` (`LITERARY_EVENT`)
+    pub static ref LITERARY_EVENT: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "LiteraryEvent");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// LiveBlogPosting
+    ///
+    /// A LiveBlogPosting is a BlogPosting intended to provide a rolling textual coverage of an ongoing event through continuous updates.
+    ///
+    /// `schema:LiveBlogPosting` (`LIVE_BLOG_POSTING`)
+    pub static ref LIVE_BLOG_POSTING: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "LiveBlogPosting");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// LoanOrCredit
+    ///
+    /// A financial product for the loaning of an amount of money, or line of credit, under agreed terms and charges.
+    ///
+    /// `schema:LoanOrCredit` (`LOAN_OR_CREDIT`)
+    pub static ref LOAN_OR_CREDIT: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "LoanOrCredit");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// LocalBusiness
+    ///
+    /// A particular physical business or branch of an organization. Examples of LocalBusiness include a restaurant, a particular branch of a restaurant chain, a branch of a bank, a medical practice, a club, a bowling alley, etc.
+    ///
+    /// `schema:LocalBusiness` (`LOCAL_BUSINESS`)
+    pub static ref LOCAL_BUSINESS: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "LocalBusiness");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// LocationFeatureSpecification
+    ///
+    /// Specifies a location feature by providing a structured value representing a feature of an accommodation as a property-value pair of varying degrees of formality.
+    ///
+    /// `schema:LocationFeatureSpecification` (`LOCATION_FEATURE_SPECIFICATION`)
+    pub static ref LOCATION_FEATURE_SPECIFICATION: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "LocationFeatureSpecification");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// Locksmith
+    ///
+    /// A locksmith.
+    ///
+    /// `schema:Locksmith` (`LOCKSMITH`)
+    pub static ref LOCKSMITH: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "Locksmith");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// LodgingBusiness
+    ///
+    /// A lodging business, such as a motel, hotel, or inn.
+    ///
+    /// `schema:LodgingBusiness` (`LODGING_BUSINESS`)
+    pub static ref LODGING_BUSINESS: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "LodgingBusiness");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// LodgingReservation
+    ///
+    /// A reservation for lodging at a hotel, motel, inn, etc.
+    ///
+    /// `schema:LodgingReservation` (`LODGING_RESERVATION`)
+    pub static ref LODGING_RESERVATION: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "LodgingReservation");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// LoseAction
+    ///
+    /// The act of being defeated in a competitive activity.
+    ///
+    /// `schema:LoseAction` (`LOSE_ACTION`)
+    pub static ref LOSE_ACTION: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "LoseAction");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// LymphaticVessel
+    ///
+    /// A type of blood vessel that specifically carries lymph fluid unidirectionally toward the heart.
+    ///
+    /// `schema:LymphaticVessel` (`LYMPHATIC_VESSEL`)
+    pub static ref LYMPHATIC_VESSEL: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "LymphaticVessel");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// Manuscript
+    ///
+    /// A book, document, or piece of music written by hand rather than typed or printed.
+    ///
+    /// `schema:Manuscript` (`MANUSCRIPT`)
+    pub static ref MANUSCRIPT: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "Manuscript");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// Map
+    ///
+    /// A map.
+    ///
+    /// `schema:Map` (`MAP_CLASS`)
+    pub static ref MAP_CLASS: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "Map");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// MapCategoryType
+    ///
+    /// An enumeration of several kinds of Map.
+    ///
+    /// `schema:MapCategoryType` (`MAP_CATEGORY_TYPE`)
+    pub static ref MAP_CATEGORY_TYPE: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "MapCategoryType");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// MarryAction
+    ///
+    /// The act of marrying a person.
+    ///
+    /// `schema:MarryAction` (`MARRY_ACTION`)
+    pub static ref MARRY_ACTION: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "MarryAction");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// Mass
+    ///
+    /// Properties that take Mass as values are of the form 'Number Mass-unit-of-measure'. E.g., '7 kg'.
+    ///
+    /// `schema:Mass` (`MASS`)
+    pub static ref MASS: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "Mass");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// MathSolver
+    ///
+    /// A math solver which is capable of solving a subset of mathematical problems.
+    ///
+    /// `schema:MathSolver` (`MATH_SOLVER`)
+    pub static ref MATH_SOLVER: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "MathSolver");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// MaximumDoseSchedule
+    ///
+    /// The maximum dosing schedule considered safe for a drug or supplement as recommended by an authority or by the drug/supplement's manufacturer. Capture the recommending authority in the recognizingAuthority property of MedicalEntity.
+    ///
+    /// `schema:MaximumDoseSchedule` (`MAXIMUM_DOSE_SCHEDULE`)
+    pub static ref MAXIMUM_DOSE_SCHEDULE: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "MaximumDoseSchedule");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// MeasurementMethodEnum
+    ///
+    /// Enumeration(s) for use with measurementMethod.
+    ///
+    /// `schema:MeasurementMethodEnum` (`MEASUREMENT_METHOD_ENUM`)
+    pub static ref MEASUREMENT_METHOD_ENUM: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "MeasurementMethodEnum");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// MeasurementTypeEnumeration
+    ///
+    /// Enumeration of common measurement types (or dimensions), for example "chest" for a person, "inseam" for pants, "gauge" for screws, or "wheel" for bicycles.
+    ///
+    /// `schema:MeasurementTypeEnumeration` (`MEASUREMENT_TYPE_ENUMERATION`)
+    pub static ref MEASUREMENT_TYPE_ENUMERATION: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "MeasurementTypeEnumeration");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// MediaGallery
+    ///
+    /// Web page type: Media gallery page. A mixed-media page that can contain media such as images, videos, and other multimedia.
+    ///
+    /// `schema:MediaGallery` (`MEDIA_GALLERY`)
+    pub static ref MEDIA_GALLERY: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "MediaGallery");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// MediaManipulationRatingEnumeration
+    ///
+    /// Codes for use with the mediaAuthenticityCategory property, indicating the authenticity of a media object (in the context of how it may have been manipulated).
+    ///
+    /// `schema:MediaManipulationRatingEnumeration` (`MEDIA_MANIPULATION_RATING_ENUMERATION`)
+    pub static ref MEDIA_MANIPULATION_RATING_ENUMERATION: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "MediaManipulationRatingEnumeration");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// MediaObject
+    ///
+    /// A media object, such as an image, video, audio, or text object embedded in a web page or a downloadable dataset i.e. DataDownload. Note that a creative work may have many media objects associated with it on the same web page.
+    ///
+    /// `schema:MediaObject` (`MEDIA_OBJECT`)
+    pub static ref MEDIA_OBJECT: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "MediaObject");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// MediaReview
+    ///
+    /// A MediaReview is a more specialized form of Review dedicated to the evaluation of media content online, typically in the context of fact-checking and misinformation.
+    ///
+    /// `schema:MediaReview` (`MEDIA_REVIEW`)
+    pub static ref MEDIA_REVIEW: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "MediaReview");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// MediaReviewItem
+    ///
+    /// Represents an item or group of closely related items treated as a unit for the sake of evaluation in a MediaReview.
+    ///
+    /// `schema:MediaReviewItem` (`MEDIA_REVIEW_ITEM`)
+    pub static ref MEDIA_REVIEW_ITEM: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "MediaReviewItem");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// MediaSubscription
+    ///
+    /// A subscription which allows a user to access media including audio, video, books, etc.
+    ///
+    /// `schema:MediaSubscription` (`MEDIA_SUBSCRIPTION`)
+    pub static ref MEDIA_SUBSCRIPTION: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "MediaSubscription");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// MedicalAudience
+    ///
+    /// Target audiences for medical web pages.
+    ///
+    /// `schema:MedicalAudience` (`MEDICAL_AUDIENCE_CLASS`)
+    pub static ref MEDICAL_AUDIENCE_CLASS: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "MedicalAudience");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// MedicalAudienceType
+    ///
+    /// Target audiences types for medical web pages. Enumerated type.
+    ///
+    /// `schema:MedicalAudienceType` (`MEDICAL_AUDIENCE_TYPE`)
+    pub static ref MEDICAL_AUDIENCE_TYPE: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "MedicalAudienceType");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// MedicalBusiness
+    ///
+    /// A particular physical or virtual business of an organization for medical purposes. Examples of MedicalBusiness include different businesses run by health professionals.
+    ///
+    /// `schema:MedicalBusiness` (`MEDICAL_BUSINESS`)
+    pub static ref MEDICAL_BUSINESS: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "MedicalBusiness");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// MedicalCause
+    ///
+    /// The causative agent(s) that are responsible for the pathophysiologic process that eventually results in a medical condition, symptom or sign.
+    ///
+    /// `schema:MedicalCause` (`MEDICAL_CAUSE`)
+    pub static ref MEDICAL_CAUSE: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "MedicalCause");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// MedicalClinic
+    ///
+    /// A facility, often associated with a hospital or medical school, that is devoted to the specific diagnosis and/or healthcare. Previously limited to outpatients but with evolution it may be open to inpatients as well.
+    ///
+    /// `schema:MedicalClinic` (`MEDICAL_CLINIC`)
+    pub static ref MEDICAL_CLINIC: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "MedicalClinic");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// MedicalCode
+    ///
+    /// A code for a medical entity.
+    ///
+    /// `schema:MedicalCode` (`MEDICAL_CODE`)
+    pub static ref MEDICAL_CODE: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "MedicalCode");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// MedicalCondition
+    ///
+    /// Any condition of the human body that affects the normal functioning of a person, whether physically or mentally. Includes diseases, injuries, disabilities, disorders, syndromes, etc.
+    ///
+    /// `schema:MedicalCondition` (`MEDICAL_CONDITION`)
+    pub static ref MEDICAL_CONDITION: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "MedicalCondition");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// MedicalConditionStage
+    ///
+    /// A stage of a medical condition, such as 'Stage IIIa'.
+    ///
+    /// `schema:MedicalConditionStage` (`MEDICAL_CONDITION_STAGE`)
+    pub static ref MEDICAL_CONDITION_STAGE: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "MedicalConditionStage");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// MedicalContraindication
+    ///
+    /// A condition or factor that serves as a reason to withhold a certain medical therapy. Contraindications can be absolute (there are no reasonable circumstances for undertaking a course of action) or relative (the patient is at higher risk of complications, but these risks may be outweighed by other considerations or mitigated by other measures).
+    ///
+    /// `schema:MedicalContraindication` (`MEDICAL_CONTRAINDICATION`)
+    pub static ref MEDICAL_CONTRAINDICATION: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "MedicalContraindication");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// MedicalDevice
+    ///
+    /// Any object used in a medical capacity, such as to diagnose or treat a patient.
+    ///
+    /// `schema:MedicalDevice` (`MEDICAL_DEVICE`)
+    pub static ref MEDICAL_DEVICE: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "MedicalDevice");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// MedicalDevicePurpose
+    ///
+    /// Categories of medical devices, organized by the purpose or intended use of the device.
+    ///
+    /// `schema:MedicalDevicePurpose` (`MEDICAL_DEVICE_PURPOSE`)
+    pub static ref MEDICAL_DEVICE_PURPOSE: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "MedicalDevicePurpose");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// MedicalEntity
+    ///
+    /// The most generic type of entity related to health and the practice of medicine.
+    ///
+    /// `schema:MedicalEntity` (`MEDICAL_ENTITY`)
+    pub static ref MEDICAL_ENTITY: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "MedicalEntity");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// MedicalEnumeration
+    ///
+    /// Enumerations related to health and the practice of medicine: A concept that is used to attribute a quality to another concept, as a qualifier, a collection of items or a listing of all of the elements of a set in medicine practice.
+    ///
+    /// `schema:MedicalEnumeration` (`MEDICAL_ENUMERATION`)
+    pub static ref MEDICAL_ENUMERATION: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "MedicalEnumeration");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// MedicalEvidenceLevel
+    ///
+    /// Level of evidence for a medical guideline. Enumerated type.
+    ///
+    /// `schema:MedicalEvidenceLevel` (`MEDICAL_EVIDENCE_LEVEL`)
+    pub static ref MEDICAL_EVIDENCE_LEVEL: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "MedicalEvidenceLevel");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// MedicalGuideline
+    ///
+    /// Any recommendation made by a standard society (e.g. ACC/AHA) or consensus statement that denotes how to diagnose and treat a particular condition. Note: this type should be used to tag the actual guideline recommendation; if the guideline recommendation occurs in a larger scholarly article, use MedicalScholarlyArticle to tag the overall article, not this type.
+    ///
+    /// `schema:MedicalGuideline` (`MEDICAL_GUIDELINE`)
+    pub static ref MEDICAL_GUIDELINE: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "MedicalGuideline");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// MedicalGuidelineContraindication
+    ///
+    /// A guideline contraindication that designates a process as harmful and where quality of the data supporting the contraindication is sound.
+    ///
+    /// `schema:MedicalGuidelineContraindication` (`MEDICAL_GUIDELINE_CONTRAINDICATION`)
+    pub static ref MEDICAL_GUIDELINE_CONTRAINDICATION: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "MedicalGuidelineContraindication");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// MedicalGuidelineRecommendation
+    ///
+    /// A guideline recommendation that is regarded as efficacious and where quality of the data supporting the recommendation is sound.
+    ///
+    /// `schema:MedicalGuidelineRecommendation` (`MEDICAL_GUIDELINE_RECOMMENDATION`)
+    pub static ref MEDICAL_GUIDELINE_RECOMMENDATION: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "MedicalGuidelineRecommendation");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// MedicalImagingTechnique
+    ///
+    /// Any medical imaging modality typically used for diagnostic purposes. Enumerated type.
+    ///
+    /// `schema:MedicalImagingTechnique` (`MEDICAL_IMAGING_TECHNIQUE`)
+    pub static ref MEDICAL_IMAGING_TECHNIQUE: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "MedicalImagingTechnique");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// MedicalIndication
+    ///
+    /// A condition or factor that indicates use of a medical therapy, including signs, symptoms, risk factors, anatomical states, etc.
+    ///
+    /// `schema:MedicalIndication` (`MEDICAL_INDICATION`)
+    pub static ref MEDICAL_INDICATION: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "MedicalIndication");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// MedicalIntangible
+    ///
+    /// A utility class that serves as the umbrella for a number of 'intangible' things in the medical space.
+    ///
+    /// `schema:MedicalIntangible` (`MEDICAL_INTANGIBLE`)
+    pub static ref MEDICAL_INTANGIBLE: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "MedicalIntangible");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// MedicalObservationalStudy
+    ///
+    /// An observational study is a type of medical study that attempts to infer the possible effect of a treatment through observation of a cohort of subjects over a period of time.
+    ///
+    /// `schema:MedicalObservationalStudy` (`MEDICAL_OBSERVATIONAL_STUDY`)
+    pub static ref MEDICAL_OBSERVATIONAL_STUDY: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "MedicalObservationalStudy");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// MedicalObservationalStudyDesign
+    ///
+    /// Design models for observational medical studies. Enumerated type.
+    ///
+    /// `schema:MedicalObservationalStudyDesign` (`MEDICAL_OBSERVATIONAL_STUDY_DESIGN`)
+    pub static ref MEDICAL_OBSERVATIONAL_STUDY_DESIGN: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "MedicalObservationalStudyDesign");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// MedicalOrganization
+    ///
+    /// A medical organization (physical or not), such as hospital, institution or clinic.
+    ///
+    /// `schema:MedicalOrganization` (`MEDICAL_ORGANIZATION`)
+    pub static ref MEDICAL_ORGANIZATION: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "MedicalOrganization");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// MedicalProcedure
+    ///
+    /// A process of care used in either a diagnostic, therapeutic, preventive or palliative capacity that relies on invasive (surgical), non-invasive, or other techniques.
+    ///
+    /// `schema:MedicalProcedure` (`MEDICAL_PROCEDURE`)
+    pub static ref MEDICAL_PROCEDURE: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "MedicalProcedure");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// MedicalProcedureType
+    ///
+    /// An enumeration that describes different types of medical procedures.
+    ///
+    /// `schema:MedicalProcedureType` (`MEDICAL_PROCEDURE_TYPE`)
+    pub static ref MEDICAL_PROCEDURE_TYPE: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "MedicalProcedureType");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// MedicalRiskCalculator
+    ///
+    /// A complex mathematical calculation requiring an online calculator, used to assess prognosis. Note: use the url property of Thing to record any URLs for online calculators.
+    ///
+    /// `schema:MedicalRiskCalculator` (`MEDICAL_RISK_CALCULATOR`)
+    pub static ref MEDICAL_RISK_CALCULATOR: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "MedicalRiskCalculator");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// MedicalRiskEstimator
+    ///
+    /// Any rule set or interactive tool for estimating the risk of developing a complication or condition.
+    ///
+    /// `schema:MedicalRiskEstimator` (`MEDICAL_RISK_ESTIMATOR`)
+    pub static ref MEDICAL_RISK_ESTIMATOR: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "MedicalRiskEstimator");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// MedicalRiskFactor
+    ///
+    /// A risk factor is anything that increases a person's likelihood of developing or contracting a disease, medical condition, or complication.
+    ///
+    /// `schema:MedicalRiskFactor` (`MEDICAL_RISK_FACTOR`)
+    pub static ref MEDICAL_RISK_FACTOR: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "MedicalRiskFactor");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// MedicalRiskScore
+    ///
+    /// A simple system that adds up the number of risk factors to yield a score that is associated with prognosis, e.g. CHAD score, TIMI risk score.
+    ///
+    /// `schema:MedicalRiskScore` (`MEDICAL_RISK_SCORE`)
+    pub static ref MEDICAL_RISK_SCORE: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "MedicalRiskScore");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// MedicalScholarlyArticle
+    ///
+    /// A scholarly article in the medical domain.
+    ///
+    /// `schema:MedicalScholarlyArticle` (`MEDICAL_SCHOLARLY_ARTICLE`)
+    pub static ref MEDICAL_SCHOLARLY_ARTICLE: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "MedicalScholarlyArticle");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// MedicalSign
+    ///
+    /// Any physical manifestation of a person's medical condition discoverable by objective diagnostic tests or physical examination.
+    ///
+    /// `schema:MedicalSign` (`MEDICAL_SIGN`)
+    pub static ref MEDICAL_SIGN: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "MedicalSign");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// MedicalSignOrSymptom
+    ///
+    /// Any feature associated or not with a medical condition. In medicine a symptom is generally subjective while a sign is objective.
+    ///
+    /// `schema:MedicalSignOrSymptom` (`MEDICAL_SIGN_OR_SYMPTOM`)
+    pub static ref MEDICAL_SIGN_OR_SYMPTOM: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "MedicalSignOrSymptom");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// MedicalSpecialty
+    ///
+    /// Any specific branch of medical science or practice. Medical specialities include clinical specialties that pertain to particular organ systems and their respective disease states, as well as allied health specialties. Enumerated type.
+    ///
+    /// `schema:MedicalSpecialty` (`MEDICAL_SPECIALTY_CLASS`)
+    pub static ref MEDICAL_SPECIALTY_CLASS: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "MedicalSpecialty");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// MedicalStudy
+    ///
+    /// A medical study is an umbrella type covering all kinds of research studies relating to human medicine or health, including observational studies and interventional trials and registries, randomized, controlled or not.
+    ///
+    /// `schema:MedicalStudy` (`MEDICAL_STUDY`)
+    pub static ref MEDICAL_STUDY: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "MedicalStudy");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// MedicalStudyStatus
+    ///
+    /// The status of a medical study. Enumerated type.
+    ///
+    /// `schema:MedicalStudyStatus` (`MEDICAL_STUDY_STATUS`)
+    pub static ref MEDICAL_STUDY_STATUS: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "MedicalStudyStatus");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// MedicalSymptom
+    ///
+    /// Any complaint sensed and expressed by the patient (therefore defined as subjective) like stomachache, lower-back pain, or fatigue.
+    ///
+    /// `schema:MedicalSymptom` (`MEDICAL_SYMPTOM`)
+    pub static ref MEDICAL_SYMPTOM: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "MedicalSymptom");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// MedicalTest
+    ///
+    /// Any medical test, typically performed for diagnostic purposes.
+    ///
+    /// `schema:MedicalTest` (`MEDICAL_TEST`)
+    pub static ref MEDICAL_TEST: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "MedicalTest");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// MedicalTestPanel
+    ///
+    /// Any collection of tests commonly ordered together.
+    ///
+    /// `schema:MedicalTestPanel` (`MEDICAL_TEST_PANEL`)
+    pub static ref MEDICAL_TEST_PANEL: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "MedicalTestPanel");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// MedicalTherapy
+    ///
+    /// Any medical intervention designed to prevent, treat, and cure human diseases and medical conditions, including both curative and palliative therapies. Medical therapies are typically processes of care relying upon pharmacotherapy, behavioral therapy, supportive therapy (with fluid or nutrition for example), or detoxification (e.g. hemodialysis) aimed at improving or preventing a health condition.
+    ///
+    /// `schema:MedicalTherapy` (`MEDICAL_THERAPY`)
+    pub static ref MEDICAL_THERAPY: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "MedicalTherapy");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// MedicalTrial
+    ///
+    /// A medical trial is a type of medical study that uses a scientific process to compare the safety and efficacy of medical therapies or medical procedures.
+    ///
+    /// `schema:MedicalTrial` (`MEDICAL_TRIAL`)
+    pub static ref MEDICAL_TRIAL: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "MedicalTrial");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// MedicalTrialDesign
+    ///
+    /// Design models for medical trials. Enumerated type.
+    ///
+    /// `schema:MedicalTrialDesign` (`MEDICAL_TRIAL_DESIGN`)
+    pub static ref MEDICAL_TRIAL_DESIGN: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "MedicalTrialDesign");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// MedicalWebPage
+    ///
+    /// A web page that provides medical information.
+    ///
+    /// `schema:MedicalWebPage` (`MEDICAL_WEB_PAGE`)
+    pub static ref MEDICAL_WEB_PAGE: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "MedicalWebPage");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// MedicineSystem
+    ///
+    /// Systems of medical practice.
+    ///
+    /// `schema:MedicineSystem` (`MEDICINE_SYSTEM_CLASS`)
+    pub static ref MEDICINE_SYSTEM_CLASS: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "MedicineSystem");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// MeetingRoom
+    ///
+    /// A meeting room, conference room, or conference hall is a room provided for singular events such as business conferences and meetings.
+    ///
+    /// `schema:MeetingRoom` (`MEETING_ROOM`)
+    pub static ref MEETING_ROOM: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "MeetingRoom");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// MemberProgram
+    ///
+    /// A MemberProgram defines a loyalty (or membership) program that provides its members with certain benefits, for example better pricing, free shipping or returns, or the ability to earn loyalty points. Member programs may have multiple tiers, for example silver and gold members, each with different benefits.
+    ///
+    /// `schema:MemberProgram` (`MEMBER_PROGRAM`)
+    pub static ref MEMBER_PROGRAM: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "MemberProgram");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// MemberProgramTier
+    ///
+    /// A MemberProgramTier specifies a tier under a loyalty (member) program, for example "gold".
+    ///
+    /// `schema:MemberProgramTier` (`MEMBER_PROGRAM_TIER`)
+    pub static ref MEMBER_PROGRAM_TIER: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "MemberProgramTier");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// MensClothingStore
+    ///
+    /// A men's clothing store.
+    ///
+    /// `schema:MensClothingStore` (`MENS_CLOTHING_STORE`)
+    pub static ref MENS_CLOTHING_STORE: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "MensClothingStore");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// Menu
+    ///
+    /// A structured representation of food or drink items available from a FoodEstablishment.
+    ///
+    /// `schema:Menu` (`MENU_CLASS`)
+    pub static ref MENU_CLASS: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "Menu");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// MenuItem
+    ///
+    /// A food or drink item listed in a menu or menu section.
+    ///
+    /// `schema:MenuItem` (`MENU_ITEM`)
+    pub static ref MENU_ITEM: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "MenuItem");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// MenuSection
+    ///
+    /// A sub-grouping of food or drink items in a menu. E.g. courses (such as 'Dinner', 'Breakfast', etc.), specific type of dishes (such as 'Meat', 'Vegan', 'Drinks', etc.), or some other classification made by the menu provider.
+    ///
+    /// `schema:MenuSection` (`MENU_SECTION`)
+    pub static ref MENU_SECTION: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "MenuSection");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// MerchantReturnEnumeration
+    ///
+    /// Enumerates several kinds of product return policies.
+    ///
+    /// `schema:MerchantReturnEnumeration` (`MERCHANT_RETURN_ENUMERATION`)
+    pub static ref MERCHANT_RETURN_ENUMERATION: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "MerchantReturnEnumeration");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// MerchantReturnPolicy
+    ///
+    /// A MerchantReturnPolicy provides information about product return policies associated with an Organization, Product, or Offer.
+    ///
+    /// `schema:MerchantReturnPolicy` (`MERCHANT_RETURN_POLICY`)
+    pub static ref MERCHANT_RETURN_POLICY: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "MerchantReturnPolicy");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// MerchantReturnPolicySeasonalOverride
+    ///
+    /// A seasonal override of a return policy, for example used for holidays.
+    ///
+    /// `schema:MerchantReturnPolicySeasonalOverride` (`MERCHANT_RETURN_POLICY_SEASONAL_OVERRIDE`)
+    pub static ref MERCHANT_RETURN_POLICY_SEASONAL_OVERRIDE: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "MerchantReturnPolicySeasonalOverride");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// Message
+    ///
+    /// A single message from a sender to one or more organizations or people.
+    ///
+    /// `schema:Message` (`MESSAGE`)
+    pub static ref MESSAGE: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "Message");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// MiddleSchool
+    ///
+    /// A middle school (typically for children aged around 11-14, although this varies somewhat).
+    ///
+    /// `schema:MiddleSchool` (`MIDDLE_SCHOOL`)
+    pub static ref MIDDLE_SCHOOL: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "MiddleSchool");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// Midwifery
+    ///
+    /// A nurse-like health profession that deals with pregnancy, childbirth, and the postpartum period (including care of the newborn), besides sexual and reproductive health of women throughout their lives.
+    ///
+    /// `schema:Midwifery` (`MIDWIFERY`)
+    pub static ref MIDWIFERY: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "Midwifery");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// MobileApplication
+    ///
+    /// A software application designed specifically to work well on a mobile device such as a telephone.
+    ///
+    /// `schema:MobileApplication` (`MOBILE_APPLICATION`)
+    pub static ref MOBILE_APPLICATION: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "MobileApplication");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// MobilePhoneStore
+    ///
+    /// A store that sells mobile phones and related accessories.
+    ///
+    /// `schema:MobilePhoneStore` (`MOBILE_PHONE_STORE`)
+    pub static ref MOBILE_PHONE_STORE: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "MobilePhoneStore");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// MolecularEntity
+    ///
+    /// Any constitutionally or isotopically distinct atom, molecule, ion, ion pair, radical, radical ion, complex, conformer etc., identifiable as a separately distinguishable entity.
+    ///
+    /// `schema:MolecularEntity` (`MOLECULAR_ENTITY`)
+    pub static ref MOLECULAR_ENTITY: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "MolecularEntity");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// MonetaryAmount
+    ///
+    /// A monetary value or range. This type can be used to describe an amount of money such as $50 USD, or a range as in describing a bank account being suitable for a balance between £1,000 and £1,000,000 GBP, or the value of a salary, etc.
+    ///
+    /// `schema:MonetaryAmount` (`MONETARY_AMOUNT`)
+    pub static ref MONETARY_AMOUNT: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "MonetaryAmount");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// MonetaryAmountDistribution
+    ///
+    /// A statistical distribution of monetary amounts.
+    ///
+    /// `schema:MonetaryAmountDistribution` (`MONETARY_AMOUNT_DISTRIBUTION`)
+    pub static ref MONETARY_AMOUNT_DISTRIBUTION: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "MonetaryAmountDistribution");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// MonetaryGrant
+    ///
+    /// A monetary grant.
+    ///
+    /// `schema:MonetaryGrant` (`MONETARY_GRANT`)
+    pub static ref MONETARY_GRANT: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "MonetaryGrant");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// MoneyTransfer
+    ///
+    /// The act of transferring money from one place to another place. This may occur electronically or physically.
+    ///
+    /// `schema:MoneyTransfer` (`MONEY_TRANSFER`)
+    pub static ref MONEY_TRANSFER: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "MoneyTransfer");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// MortgageLoan
+    ///
+    /// A loan in which property or real estate is used as collateral. (A loan securitized against some real estate.)
+    ///
+    /// `schema:MortgageLoan` (`MORTGAGE_LOAN`)
+    pub static ref MORTGAGE_LOAN: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "MortgageLoan");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// Mosque
+    ///
+    /// A mosque.
+    ///
+    /// `schema:Mosque` (`MOSQUE`)
+    pub static ref MOSQUE: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "Mosque");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// Motel
+    ///
+    /// A motel.
+    ///
+    /// `schema:Motel` (`MOTEL`)
+    pub static ref MOTEL: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "Motel");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// Motorcycle
+    ///
+    /// A motorcycle or motorbike is a single-track, two-wheeled motor vehicle.
+    ///
+    /// `schema:Motorcycle` (`MOTORCYCLE`)
+    pub static ref MOTORCYCLE: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "Motorcycle");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// MotorcycleDealer
+    ///
+    /// A motorcycle dealer.
+    ///
+    /// `schema:MotorcycleDealer` (`MOTORCYCLE_DEALER`)
+    pub static ref MOTORCYCLE_DEALER: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "MotorcycleDealer");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// MotorcycleRepair
+    ///
+    /// A motorcycle repair shop.
+    ///
+    /// `schema:MotorcycleRepair` (`MOTORCYCLE_REPAIR`)
+    pub static ref MOTORCYCLE_REPAIR: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "MotorcycleRepair");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// MotorizedBicycle
+    ///
+    /// A motorized bicycle is a bicycle with an attached motor used to power the vehicle, or to assist with pedaling.
+    ///
+    /// `schema:MotorizedBicycle` (`MOTORIZED_BICYCLE`)
+    pub static ref MOTORIZED_BICYCLE: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "MotorizedBicycle");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// Mountain
+    ///
+    /// A mountain, like Mount Whitney or Mount Everest.
+    ///
+    /// `schema:Mountain` (`MOUNTAIN`)
+    pub static ref MOUNTAIN: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "Mountain");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// MoveAction
+    ///
+    /// The act of an agent relocating to a place.
+    ///
+    /// `schema:MoveAction` (`MOVE_ACTION`)
+    pub static ref MOVE_ACTION: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "MoveAction");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// Movie
+    ///
+    /// A movie.
+    ///
+    /// `schema:Movie` (`MOVIE`)
+    pub static ref MOVIE: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "Movie");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// MovieClip
+    ///
+    /// A short segment/part of a movie.
+    ///
+    /// `schema:MovieClip` (`MOVIE_CLIP`)
+    pub static ref MOVIE_CLIP: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "MovieClip");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// MovieRentalStore
+    ///
+    /// A movie rental store.
+    ///
+    /// `schema:MovieRentalStore` (`MOVIE_RENTAL_STORE`)
+    pub static ref MOVIE_RENTAL_STORE: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "MovieRentalStore");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// MovieSeries
+    ///
+    /// A series of movies. Included movies can be indicated with the hasPart property.
+    ///
+    /// `schema:MovieSeries` (`MOVIE_SERIES`)
+    pub static ref MOVIE_SERIES: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "MovieSeries");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// MovieTheater
+    ///
+    /// A movie theater.
+    ///
+    /// `schema:MovieTheater` (`MOVIE_THEATER`)
+    pub static ref MOVIE_THEATER: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "MovieTheater");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// MovingCompany
+    ///
+    /// A moving company.
+    ///
+    /// `schema:MovingCompany` (`MOVING_COMPANY`)
+    pub static ref MOVING_COMPANY: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "MovingCompany");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// Muscle
+    ///
+    /// A muscle is an anatomical structure consisting of a contractile form of tissue that animals use to effect movement.
+    ///
+    /// `schema:Muscle` (`MUSCLE`)
+    pub static ref MUSCLE: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "Muscle");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// Museum
+    ///
+    /// A museum.
+    ///
+    /// `schema:Museum` (`MUSEUM`)
+    pub static ref MUSEUM: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "Museum");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// MusicAlbum
+    ///
+    /// A collection of music tracks.
+    ///
+    /// `schema:MusicAlbum` (`MUSIC_ALBUM`)
+    pub static ref MUSIC_ALBUM: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "MusicAlbum");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// MusicAlbumProductionType
+    ///
+    /// Classification of the album by its type of content: soundtrack, live album, studio album, etc.
+    ///
+    /// `schema:MusicAlbumProductionType` (`MUSIC_ALBUM_PRODUCTION_TYPE`)
+    pub static ref MUSIC_ALBUM_PRODUCTION_TYPE: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "MusicAlbumProductionType");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// MusicAlbumReleaseType
+    ///
+    /// The kind of release which this album is: single, EP or album.
+    ///
+    /// `schema:MusicAlbumReleaseType` (`MUSIC_ALBUM_RELEASE_TYPE`)
+    pub static ref MUSIC_ALBUM_RELEASE_TYPE: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "MusicAlbumReleaseType");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// MusicComposition
+    ///
+    /// A musical composition.
+    ///
+    /// `schema:MusicComposition` (`MUSIC_COMPOSITION`)
+    pub static ref MUSIC_COMPOSITION: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "MusicComposition");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// MusicEvent
+    ///
+    /// Event type: Music event.
+    ///
+    /// `schema:MusicEvent` (`MUSIC_EVENT`)
+    pub static ref MUSIC_EVENT: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "MusicEvent");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// MusicGroup
+    ///
+    /// A musical group, such as a band, an orchestra, or a choir. Can also be a solo musician.
+    ///
+    /// `schema:MusicGroup` (`MUSIC_GROUP`)
+    pub static ref MUSIC_GROUP: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "MusicGroup");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// MusicPlaylist
+    ///
+    /// A collection of music tracks in playlist form.
+    ///
+    /// `schema:MusicPlaylist` (`MUSIC_PLAYLIST`)
+    pub static ref MUSIC_PLAYLIST: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "MusicPlaylist");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// MusicRecording
+    ///
+    /// A music recording (track), usually a single song.
+    ///
+    /// `schema:MusicRecording` (`MUSIC_RECORDING`)
+    pub static ref MUSIC_RECORDING: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "MusicRecording");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// MusicRelease
+    ///
+    /// A MusicRelease is a specific release of a music album.
+    ///
+    /// `schema:MusicRelease` (`MUSIC_RELEASE`)
+    pub static ref MUSIC_RELEASE: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "MusicRelease");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// MusicReleaseFormatType
+    ///
+    /// Format of this release (the type of recording media used, i.e. compact disc, digital media, LP, etc.).
+    ///
+    /// `schema:MusicReleaseFormatType` (`MUSIC_RELEASE_FORMAT_TYPE`)
+    pub static ref MUSIC_RELEASE_FORMAT_TYPE: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "MusicReleaseFormatType");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// MusicStore
+    ///
+    /// A music store.
+    ///
+    /// `schema:MusicStore` (`MUSIC_STORE`)
+    pub static ref MUSIC_STORE: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "MusicStore");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// MusicVenue
+    ///
+    /// A music venue.
+    ///
+    /// `schema:MusicVenue` (`MUSIC_VENUE`)
+    pub static ref MUSIC_VENUE: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "MusicVenue");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// MusicVideoObject
+    ///
+    /// A music video file.
+    ///
+    /// `schema:MusicVideoObject` (`MUSIC_VIDEO_OBJECT`)
+    pub static ref MUSIC_VIDEO_OBJECT: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "MusicVideoObject");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// NGO
+    ///
+    /// Organization: Non-governmental Organization.
+    ///
+    /// `schema:NGO` (`NGO`)
+    pub static ref NGO: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "NGO");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// NLNonprofitType
+    ///
+    /// NLNonprofitType: Non-profit organization type originating from the Netherlands.
+    ///
+    /// `schema:NLNonprofitType` (`NL_NONPROFIT_TYPE`)
+    pub static ref NL_NONPROFIT_TYPE: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "NLNonprofitType");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// NailSalon
+    ///
+    /// A nail salon.
+    ///
+    /// `schema:NailSalon` (`NAIL_SALON`)
+    pub static ref NAIL_SALON: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "NailSalon");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// Nerve
+    ///
+    /// A common pathway for the electrochemical nerve impulses that are transmitted along each of the axons.
+    ///
+    /// `schema:Nerve` (`NERVE_CLASS`)
+    pub static ref NERVE_CLASS: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "Nerve");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// NewsArticle
+    ///
+    /// A NewsArticle is an article whose content reports news, or provides background context and supporting materials for understanding the news.
+    ///
+    /// `schema:NewsArticle` (`NEWS_ARTICLE`)
+    pub static ref NEWS_ARTICLE: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "NewsArticle");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// NewsMediaOrganization
+    ///
+    /// A News/Media organization such as a newspaper or TV station.
+    ///
+    /// `schema:NewsMediaOrganization` (`NEWS_MEDIA_ORGANIZATION`)
+    pub static ref NEWS_MEDIA_ORGANIZATION: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "NewsMediaOrganization");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// Newspaper
+    ///
+    /// A publication containing information about varied topics that are pertinent to general information, a geographic area, or a specific subject matter (i.e. business, culture, education). Often published daily.
+    ///
+    /// `schema:Newspaper` (`NEWSPAPER`)
+    pub static ref NEWSPAPER: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "Newspaper");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// NightClub
+    ///
+    /// A nightclub or discotheque.
+    ///
+    /// `schema:NightClub` (`NIGHT_CLUB`)
+    pub static ref NIGHT_CLUB: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "NightClub");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// NonprofitType
+    ///
+    /// NonprofitType enumerates several kinds of official non-profit types of which a non-profit organization can be.
+    ///
+    /// `schema:NonprofitType` (`NONPROFIT_TYPE`)
+    pub static ref NONPROFIT_TYPE: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "NonprofitType");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// Notary
+    ///
+    /// A notary.
+    ///
+    /// `schema:Notary` (`NOTARY`)
+    pub static ref NOTARY: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "Notary");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// NoteDigitalDocument
+    ///
+    /// A file containing a note, primarily for the author.
+    ///
+    /// `schema:NoteDigitalDocument` (`NOTE_DIGITAL_DOCUMENT`)
+    pub static ref NOTE_DIGITAL_DOCUMENT: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "NoteDigitalDocument");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// Number
+    ///
+    /// Data type: Number. Usage guidelines: use values from 0123456789 (Unicode 'DIGIT ZERO' (U+0030) to 'DIGIT NINE' (U+0039)) rather than superficially similar Unicode symbols, and use '.' (Unicode 'FULL STOP' (U+002E)) rather than ',' to indicate a decimal point.
+    ///
+    /// `schema:Number` (`NUMBER`)
+    pub static ref NUMBER: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "Number");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// Nursing
+    ///
+    /// A health profession of a person formally educated and trained in the care of the sick or infirm person.
+    ///
+    /// `schema:Nursing` (`NURSING`)
+    pub static ref NURSING: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "Nursing");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// NutritionInformation
+    ///
+    /// Nutritional information about the recipe.
+    ///
+    /// `schema:NutritionInformation` (`NUTRITION_INFORMATION`)
+    pub static ref NUTRITION_INFORMATION: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "NutritionInformation");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// Observation
+    ///
+    /// Instances of the class Observation are used to specify observations about an entity at a particular time. The principal properties of an Observation are observationAbout, measuredProperty, statType, value and observationDate and measuredProperty.
+    ///
+    /// `schema:Observation` (`OBSERVATION`)
+    pub static ref OBSERVATION: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "Observation");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// Obstetric
+    ///
+    /// A specific branch of medical science that specializes in the care of women during the prenatal and postnatal care and with the delivery of the child.
+    ///
+    /// `schema:Obstetric` (`OBSTETRIC`)
+    pub static ref OBSTETRIC: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "Obstetric");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// Occupation
+    ///
+    /// A profession, may involve prolonged training and/or a formal qualification.
+    ///
+    /// `schema:Occupation` (`OCCUPATION`)
+    pub static ref OCCUPATION: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "Occupation");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// OccupationalExperienceRequirements
+    ///
+    /// Indicates employment-related experience requirements, e.g. monthsOfExperience.
+    ///
+    /// `schema:OccupationalExperienceRequirements` (`OCCUPATIONAL_EXPERIENCE_REQUIREMENTS`)
+    pub static ref OCCUPATIONAL_EXPERIENCE_REQUIREMENTS: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "OccupationalExperienceRequirements");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// OccupationalTherapy
+    ///
+    /// A treatment of people with physical, emotional, or social problems, using purposeful activity to help them overcome or learn to deal with their problems.
+    ///
+    /// `schema:OccupationalTherapy` (`OCCUPATIONAL_THERAPY`)
+    pub static ref OCCUPATIONAL_THERAPY: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "OccupationalTherapy");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// OceanBodyOfWater
+    ///
+    /// An ocean (for example, the Pacific).
+    ///
+    /// `schema:OceanBodyOfWater` (`OCEAN_BODY_OF_WATER`)
+    pub static ref OCEAN_BODY_OF_WATER: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "OceanBodyOfWater");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// Offer
+    ///
+    /// An offer to transfer some rights to an item or to provide a service — for example, an offer to sell tickets to an event, to rent the DVD of a movie, to stream a TV show over the internet, to repair a motorcycle, or to loan a book.
+    ///
+    /// `schema:Offer` (`OFFER`)
+    pub static ref OFFER: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "Offer");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// OfferCatalog
+    ///
+    /// An OfferCatalog is an ItemList that contains related Offers and/or further OfferCatalogs that are offeredBy the same provider.
+    ///
+    /// `schema:OfferCatalog` (`OFFER_CATALOG`)
+    pub static ref OFFER_CATALOG: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "OfferCatalog");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// OfferForLease
+    ///
+    /// An OfferForLease in Schema.org represents an Offer to lease out something, i.e. an Offer whose businessFunction is lease out. See Good Relations for background on the underlying concepts.
+    ///
+    /// `schema:OfferForLease` (`OFFER_FOR_LEASE`)
+    pub static ref OFFER_FOR_LEASE: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "OfferForLease");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// OfferForPurchase
+    ///
+    /// An OfferForPurchase in Schema.org represents an Offer to sell something, i.e. an Offer whose businessFunction is sell. See Good Relations for background on the underlying concepts.
+    ///
+    /// `schema:OfferForPurchase` (`OFFER_FOR_PURCHASE`)
+    pub static ref OFFER_FOR_PURCHASE: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "OfferForPurchase");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// OfferItemCondition
+    ///
+    /// A list of possible conditions for the item.
+    ///
+    /// `schema:OfferItemCondition` (`OFFER_ITEM_CONDITION`)
+    pub static ref OFFER_ITEM_CONDITION: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "OfferItemCondition");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// OfferShippingDetails
+    ///
+    /// OfferShippingDetails represents information about shipping destinations.
+    ///
+    /// `schema:OfferShippingDetails` (`OFFER_SHIPPING_DETAILS`)
+    pub static ref OFFER_SHIPPING_DETAILS: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "OfferShippingDetails");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// OfficeEquipmentStore
+    ///
+    /// An office equipment store.
+    ///
+    /// `schema:OfficeEquipmentStore` (`OFFICE_EQUIPMENT_STORE`)
+    pub static ref OFFICE_EQUIPMENT_STORE: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "OfficeEquipmentStore");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// OnDemandEvent
+    ///
+    /// A publication event, e.g. catch-up TV or radio podcast, during which a program is available on-demand.
+    ///
+    /// `schema:OnDemandEvent` (`ON_DEMAND_EVENT`)
+    pub static ref ON_DEMAND_EVENT: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "OnDemandEvent");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// Oncologic
+    ///
+    /// A specific branch of medical science that deals with benign and malignant tumors, including the study of their development, diagnosis, treatment and prevention.
+    ///
+    /// `schema:Oncologic` (`ONCOLOGIC`)
+    pub static ref ONCOLOGIC: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "Oncologic");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// OnlineBusiness
+    ///
+    /// A particular online business, either standalone or the online part of a broader organization. Examples include an eCommerce site, an online travel booking site, an online learning site, an online logistics and shipping provider, an online (virtual) doctor, etc.
+    ///
+    /// `schema:OnlineBusiness` (`ONLINE_BUSINESS`)
+    pub static ref ONLINE_BUSINESS: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "OnlineBusiness");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// OnlineStore
+    ///
+    /// An eCommerce site.
+    ///
+    /// `schema:OnlineStore` (`ONLINE_STORE`)
+    pub static ref ONLINE_STORE: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "OnlineStore");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// OpeningHoursSpecification
+    ///
+    /// A structured value providing information about the opening hours of a place or a certain service inside a place.
+    ///
+    /// `schema:OpeningHoursSpecification` (`OPENING_HOURS_SPECIFICATION_CLASS`)
+    pub static ref OPENING_HOURS_SPECIFICATION_CLASS: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "OpeningHoursSpecification");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// OpinionNewsArticle
+    ///
+    /// An OpinionNewsArticle is a NewsArticle that primarily expresses opinions rather than journalistic reporting of news and events.
+    ///
+    /// `schema:OpinionNewsArticle` (`OPINION_NEWS_ARTICLE`)
+    pub static ref OPINION_NEWS_ARTICLE: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "OpinionNewsArticle");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// Optician
+    ///
+    /// A store that sells reading glasses and similar devices for improving vision.
+    ///
+    /// `schema:Optician` (`OPTICIAN`)
+    pub static ref OPTICIAN: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "Optician");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// Optometric
+    ///
+    /// The science or practice of testing visual acuity and prescribing corrective lenses.
+    ///
+    /// `schema:Optometric` (`OPTOMETRIC`)
+    pub static ref OPTOMETRIC: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "Optometric");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// Order
+    ///
+    /// An order is a confirmation of a transaction (a receipt), which can contain multiple line items, each represented by an Offer that has been accepted by the customer.
+    ///
+    /// `schema:Order` (`ORDER`)
+    pub static ref ORDER: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "Order");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// OrderAction
+    ///
+    /// An agent orders an object/product/service to be delivered/sent.
+    ///
+    /// `schema:OrderAction` (`ORDER_ACTION`)
+    pub static ref ORDER_ACTION: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "OrderAction");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// OrderItem
+    ///
+    /// An order item is a line of an order. It includes the quantity and shipping details of a bought offer.
+    ///
+    /// `schema:OrderItem` (`ORDER_ITEM`)
+    pub static ref ORDER_ITEM: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "OrderItem");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// OrderStatus
+    ///
+    /// Enumerated status values for Order.
+    ///
+    /// `schema:OrderStatus` (`ORDER_STATUS_CLASS`)
+    pub static ref ORDER_STATUS_CLASS: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "OrderStatus");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// Organization
+    ///
+    /// An organization such as a school, NGO, corporation, club, etc.
+    ///
+    /// `schema:Organization` (`ORGANIZATION`)
+    pub static ref ORGANIZATION: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "Organization");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// OrganizationRole
+    ///
+    /// A subclass of Role used to describe roles within organizations.
+    ///
+    /// `schema:OrganizationRole` (`ORGANIZATION_ROLE`)
+    pub static ref ORGANIZATION_ROLE: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "OrganizationRole");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// OrganizeAction
+    ///
+    /// The act of manipulating/administering/supervising/controlling one or more objects.
+    ///
+    /// `schema:OrganizeAction` (`ORGANIZE_ACTION`)
+    pub static ref ORGANIZE_ACTION: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "OrganizeAction");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// Otolaryngologic
+    ///
+    /// A specific branch of medical science that is concerned with the ear, nose and throat and their respective disease states.
+    ///
+    /// `schema:Otolaryngologic` (`OTOLARYNGOLOGIC`)
+    pub static ref OTOLARYNGOLOGIC: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "Otolaryngologic");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// OutletStore
+    ///
+    /// An outlet store.
+    ///
+    /// `schema:OutletStore` (`OUTLET_STORE`)
+    pub static ref OUTLET_STORE: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "OutletStore");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// OwnershipInfo
+    ///
+    /// A structured value providing information about when a certain organization or person owned a certain product.
+    ///
+    /// `schema:OwnershipInfo` (`OWNERSHIP_INFO`)
+    pub static ref OWNERSHIP_INFO: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "OwnershipInfo");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// PaintAction
+    ///
+    /// The act of producing a painting, typically with paint and canvas as instruments.
+    ///
+    /// `schema:PaintAction` (`PAINT_ACTION`)
+    pub static ref PAINT_ACTION: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "PaintAction");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// Painting
+    ///
+    /// A painting.
+    ///
+    /// `schema:Painting` (`PAINTING`)
+    pub static ref PAINTING: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "Painting");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// PalliativeProcedure
+    ///
+    /// A medical procedure intended primarily for palliative purposes, aimed at relieving the symptoms of an underlying health condition.
+    ///
+    /// `schema:PalliativeProcedure` (`PALLIATIVE_PROCEDURE`)
+    pub static ref PALLIATIVE_PROCEDURE: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "PalliativeProcedure");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// ParcelDelivery
+    ///
+    /// The delivery of a parcel either via the postal service or a commercial service.
+    ///
+    /// `schema:ParcelDelivery` (`PARCEL_DELIVERY`)
+    pub static ref PARCEL_DELIVERY: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "ParcelDelivery");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// ParentAudience
+    ///
+    /// A set of characteristics describing parents, who can be interested in viewing some content.
+    ///
+    /// `schema:ParentAudience` (`PARENT_AUDIENCE`)
+    pub static ref PARENT_AUDIENCE: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "ParentAudience");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// Park
+    ///
+    /// A park.
+    ///
+    /// `schema:Park` (`PARK`)
+    pub static ref PARK: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "Park");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// ParkingFacility
+    ///
+    /// A parking lot or other parking facility.
+    ///
+    /// `schema:ParkingFacility` (`PARKING_FACILITY`)
+    pub static ref PARKING_FACILITY: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "ParkingFacility");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// PathologyTest
+    ///
+    /// A medical test performed by a laboratory that typically involves examination of a tissue sample by a pathologist.
+    ///
+    /// `schema:PathologyTest` (`PATHOLOGY_TEST`)
+    pub static ref PATHOLOGY_TEST: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "PathologyTest");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// Patient
+    ///
+    /// A patient is any person recipient of health care services.
+    ///
+    /// `schema:Patient` (`PATIENT`)
+    pub static ref PATIENT: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "Patient");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// PawnShop
+    ///
+    /// A shop that will buy, or lend money against the security of, personal possessions.
+    ///
+    /// `schema:PawnShop` (`PAWN_SHOP`)
+    pub static ref PAWN_SHOP: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "PawnShop");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// PayAction
+    ///
+    /// An agent pays a price to a participant.
+    ///
+    /// `schema:PayAction` (`PAY_ACTION`)
+    pub static ref PAY_ACTION: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "PayAction");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// PaymentCard
+    ///
+    /// A payment method using a credit, debit, store or other card to associate the payment with an account.
+    ///
+    /// `schema:PaymentCard` (`PAYMENT_CARD`)
+    pub static ref PAYMENT_CARD: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "PaymentCard");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// PaymentChargeSpecification
+    ///
+    /// The costs of settling the payment using a particular payment method.
+    ///
+    /// `schema:PaymentChargeSpecification` (`PAYMENT_CHARGE_SPECIFICATION`)
+    pub static ref PAYMENT_CHARGE_SPECIFICATION: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "PaymentChargeSpecification");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// PaymentMethod
+    ///
+    /// A payment method is a standardized procedure for transferring the monetary amount for a purchase. Payment methods are characterized by the legal and technical structures used, and by the organization or group carrying out the transaction.
+    ///
+    /// `schema:PaymentMethod` (`PAYMENT_METHOD_CLASS`)
+    pub static ref PAYMENT_METHOD_CLASS: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "PaymentMethod");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// PaymentService
+    ///
+    /// A Service to transfer funds from a person or organization to a beneficiary person or organization.
+    ///
+    /// `schema:PaymentService` (`PAYMENT_SERVICE`)
+    pub static ref PAYMENT_SERVICE: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "PaymentService");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// PaymentStatusType
+    ///
+    /// A specific payment status. For example, PaymentDue, PaymentComplete, etc.
+    ///
+    /// `schema:PaymentStatusType` (`PAYMENT_STATUS_TYPE`)
+    pub static ref PAYMENT_STATUS_TYPE: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "PaymentStatusType");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// Pediatric
+    ///
+    /// A specific branch of medical science that specializes in the care of infants, children and adolescents.
+    ///
+    /// `schema:Pediatric` (`PEDIATRIC`)
+    pub static ref PEDIATRIC: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "Pediatric");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// PeopleAudience
+    ///
+    /// A set of characteristics belonging to people, e.g. who compose an item's target audience.
+    ///
+    /// `schema:PeopleAudience` (`PEOPLE_AUDIENCE`)
+    pub static ref PEOPLE_AUDIENCE: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "PeopleAudience");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// PerformAction
+    ///
+    /// The act of participating in performance arts.
+    ///
+    /// `schema:PerformAction` (`PERFORM_ACTION`)
+    pub static ref PERFORM_ACTION: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "PerformAction");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// PerformanceRole
+    ///
+    /// A PerformanceRole is a Role that some entity places with regard to a theatrical performance, e.g. in a Movie, TVSeries etc.
+    ///
+    /// `schema:PerformanceRole` (`PERFORMANCE_ROLE`)
+    pub static ref PERFORMANCE_ROLE: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "PerformanceRole");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// PerformingArtsTheater
+    ///
+    /// A theater or other performing art center.
+    ///
+    /// `schema:PerformingArtsTheater` (`PERFORMING_ARTS_THEATER`)
+    pub static ref PERFORMING_ARTS_THEATER: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "PerformingArtsTheater");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// PerformingGroup
+    ///
+    /// A performance group, such as a band, an orchestra, or a circus.
+    ///
+    /// `schema:PerformingGroup` (`PERFORMING_GROUP`)
+    pub static ref PERFORMING_GROUP: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "PerformingGroup");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// Periodical
+    ///
+    /// A publication in any medium issued in successive parts bearing numerical or chronological designations and intended to continue indefinitely, such as a magazine, scholarly journal, or newspaper.
+    ///
+    /// `schema:Periodical` (`PERIODICAL`)
+    pub static ref PERIODICAL: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "Periodical");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// Permit
+    ///
+    /// A permit issued by an organization, e.g. a parking pass.
+    ///
+    /// `schema:Permit` (`PERMIT`)
+    pub static ref PERMIT: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "Permit");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// Person
+    ///
+    /// A person (alive, dead, undead, or fictional).
+    ///
+    /// `schema:Person` (`PERSON`)
+    pub static ref PERSON: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "Person");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// PetStore
+    ///
+    /// A pet store.
+    ///
+    /// `schema:PetStore` (`PET_STORE`)
+    pub static ref PET_STORE: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "PetStore");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// Pharmacy
+    ///
+    /// A pharmacy or drugstore.
+    ///
+    /// `schema:Pharmacy` (`PHARMACY`)
+    pub static ref PHARMACY: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "Pharmacy");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// Photograph
+    ///
+    /// A photograph.
+    ///
+    /// `schema:Photograph` (`PHOTOGRAPH`)
+    pub static ref PHOTOGRAPH: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "Photograph");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// PhotographAction
+    ///
+    /// The act of capturing still images of objects using a camera.
+    ///
+    /// `schema:PhotographAction` (`PHOTOGRAPH_ACTION`)
+    pub static ref PHOTOGRAPH_ACTION: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "PhotographAction");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// PhysicalActivity
+    ///
+    /// Any bodily activity that enhances or maintains physical fitness and overall health and wellness. Includes activity that is part of daily living and routine, structured exercise, and exercise prescribed as part of a medical treatment or recovery plan.
+    ///
+    /// `schema:PhysicalActivity` (`PHYSICAL_ACTIVITY`)
+    pub static ref PHYSICAL_ACTIVITY: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "PhysicalActivity");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// PhysicalActivityCategory
+    ///
+    /// Categories of physical activity, organized by physiologic classification.
+    ///
+    /// `schema:PhysicalActivityCategory` (`PHYSICAL_ACTIVITY_CATEGORY`)
+    pub static ref PHYSICAL_ACTIVITY_CATEGORY: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "PhysicalActivityCategory");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// PhysicalExam
+    ///
+    /// A type of physical examination of a patient performed by a physician.
+    ///
+    /// `schema:PhysicalExam` (`PHYSICAL_EXAM`)
+    pub static ref PHYSICAL_EXAM: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "PhysicalExam");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// PhysicalTherapy
+    ///
+    /// A process of progressive physical care and rehabilitation aimed at improving a health condition.
+    ///
+    /// `schema:PhysicalTherapy` (`PHYSICAL_THERAPY`)
+    pub static ref PHYSICAL_THERAPY: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "PhysicalTherapy");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// Physician
+    ///
+    /// An individual physician or a physician's office considered as a MedicalOrganization.
+    ///
+    /// `schema:Physician` (`PHYSICIAN`)
+    pub static ref PHYSICIAN: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "Physician");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// PhysiciansOffice
+    ///
+    /// A doctor's office or clinic.
+    ///
+    /// `schema:PhysiciansOffice` (`PHYSICIANS_OFFICE`)
+    pub static ref PHYSICIANS_OFFICE: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "PhysiciansOffice");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// Physiotherapy
+    ///
+    /// The practice of treatment of disease, injury, or deformity by physical methods such as massage, heat treatment, and exercise rather than by drugs or surgery.
+    ///
+    /// `schema:Physiotherapy` (`PHYSIOTHERAPY`)
+    pub static ref PHYSIOTHERAPY: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "Physiotherapy");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// Place
+    ///
+    /// Entities that have a somewhat fixed, physical extension.
+    ///
+    /// `schema:Place` (`PLACE`)
+    pub static ref PLACE: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "Place");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// PlaceOfWorship
+    ///
+    /// Place of worship, such as a church, synagogue, or mosque.
+    ///
+    /// `schema:PlaceOfWorship` (`PLACE_OF_WORSHIP`)
+    pub static ref PLACE_OF_WORSHIP: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "PlaceOfWorship");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// PlanAction
+    ///
+    /// The act of planning the execution of an event/task/action/reservation/plan to a future date.
+    ///
+    /// `schema:PlanAction` (`PLAN_ACTION`)
+    pub static ref PLAN_ACTION: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "PlanAction");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// PlasticSurgery
+    ///
+    /// A specific branch of medical science that pertains to therapeutic or cosmetic repair or re-formation of missing, injured or malformed tissues or body parts by manual and instrumental means.
+    ///
+    /// `schema:PlasticSurgery` (`PLASTIC_SURGERY`)
+    pub static ref PLASTIC_SURGERY: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "PlasticSurgery");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// Play
+    ///
+    /// A play is a form of literature, usually consisting of dialogue between characters, intended for theatrical performance rather than just reading.
+    ///
+    /// `schema:Play` (`PLAY`)
+    pub static ref PLAY: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "Play");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// PlayAction
+    ///
+    /// The act of playing/exercising/training/performing for enjoyment, leisure, recreation, competition or exercise.
+    ///
+    /// `schema:PlayAction` (`PLAY_ACTION`)
+    pub static ref PLAY_ACTION: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "PlayAction");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// PlayGameAction
+    ///
+    /// The act of playing a video game.
+    ///
+    /// `schema:PlayGameAction` (`PLAY_GAME_ACTION`)
+    pub static ref PLAY_GAME_ACTION: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "PlayGameAction");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// Playground
+    ///
+    /// A playground.
+    ///
+    /// `schema:Playground` (`PLAYGROUND`)
+    pub static ref PLAYGROUND: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "Playground");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// Plumber
+    ///
+    /// A plumbing service.
+    ///
+    /// `schema:Plumber` (`PLUMBER`)
+    pub static ref PLUMBER: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "Plumber");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// PodcastEpisode
+    ///
+    /// A single episode of a podcast series.
+    ///
+    /// `schema:PodcastEpisode` (`PODCAST_EPISODE`)
+    pub static ref PODCAST_EPISODE: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "PodcastEpisode");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// PodcastSeason
+    ///
+    /// A single season of a podcast. Many podcasts do not break down into separate seasons. In that case, PodcastSeries should be used.
+    ///
+    /// `schema:PodcastSeason` (`PODCAST_SEASON`)
+    pub static ref PODCAST_SEASON: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "PodcastSeason");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// PodcastSeries
+    ///
+    /// A podcast is an episodic series of digital audio or video files which a user can download and listen to.
+    ///
+    /// `schema:PodcastSeries` (`PODCAST_SERIES`)
+    pub static ref PODCAST_SERIES: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "PodcastSeries");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// Podiatric
+    ///
+    /// Podiatry is the care of the human foot, especially the diagnosis and treatment of foot disorders.
+    ///
+    /// `schema:Podiatric` (`PODIATRIC`)
+    pub static ref PODIATRIC: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "Podiatric");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// PoliceStation
+    ///
+    /// A police station.
+    ///
+    /// `schema:PoliceStation` (`POLICE_STATION`)
+    pub static ref POLICE_STATION: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "PoliceStation");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// PoliticalParty
+    ///
+    /// Organization: Political Party.
+    ///
+    /// `schema:PoliticalParty` (`POLITICAL_PARTY`)
+    pub static ref POLITICAL_PARTY: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "PoliticalParty");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// Pond
+    ///
+    /// A pond.
+    ///
+    /// `schema:Pond` (`POND`)
+    pub static ref POND: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "Pond");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// PostOffice
+    ///
+    /// A post office.
+    ///
+    /// `schema:PostOffice` (`POST_OFFICE`)
+    pub static ref POST_OFFICE: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "PostOffice");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// PostalAddress
+    ///
+    /// The mailing address.
+    ///
+    /// `schema:PostalAddress` (`POSTAL_ADDRESS`)
+    pub static ref POSTAL_ADDRESS: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "PostalAddress");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// PostalCodeRangeSpecification
+    ///
+    /// Indicates a range of postal codes, usually defined as the set of valid codes between postalCodeBegin and postalCodeEnd, inclusively.
+    ///
+    /// `schema:PostalCodeRangeSpecification` (`POSTAL_CODE_RANGE_SPECIFICATION`)
+    pub static ref POSTAL_CODE_RANGE_SPECIFICATION: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "PostalCodeRangeSpecification");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// Poster
+    ///
+    /// A large, usually printed placard, bill, or announcement, often illustrated, that is posted to advertise or publicize something.
+    ///
+    /// `schema:Poster` (`POSTER`)
+    pub static ref POSTER: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "Poster");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// PreOrderAction
+    ///
+    /// An agent orders a (not yet released) object/product/service to be delivered/sent.
+    ///
+    /// `schema:PreOrderAction` (`PRE_ORDER_ACTION`)
+    pub static ref PRE_ORDER_ACTION: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "PreOrderAction");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// PrependAction
+    ///
+    /// The act of inserting at the beginning if an ordered collection.
+    ///
+    /// `schema:PrependAction` (`PREPEND_ACTION`)
+    pub static ref PREPEND_ACTION: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "PrependAction");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// Preschool
+    ///
+    /// A preschool.
+    ///
+    /// `schema:Preschool` (`PRESCHOOL`)
+    pub static ref PRESCHOOL: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "Preschool");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// PresentationDigitalDocument
+    ///
+    /// A file containing slides or used for a presentation.
+    ///
+    /// `schema:PresentationDigitalDocument` (`PRESENTATION_DIGITAL_DOCUMENT`)
+    pub static ref PRESENTATION_DIGITAL_DOCUMENT: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "PresentationDigitalDocument");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// PreventionIndication
+    ///
+    /// An indication for preventing an underlying condition, symptom, etc.
+    ///
+    /// `schema:PreventionIndication` (`PREVENTION_INDICATION`)
+    pub static ref PREVENTION_INDICATION: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "PreventionIndication");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// PriceComponentTypeEnumeration
+    ///
+    /// Enumerates different price components that together make up the total price for an offered product.
+    ///
+    /// `schema:PriceComponentTypeEnumeration` (`PRICE_COMPONENT_TYPE_ENUMERATION`)
+    pub static ref PRICE_COMPONENT_TYPE_ENUMERATION: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "PriceComponentTypeEnumeration");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// PriceSpecification
+    ///
+    /// A structured value representing a price or price range. Typically, only the subclasses of this type are used for markup. It is recommended to use MonetaryAmount to describe independent amounts of money such as a salary, credit card limits, etc.
+    ///
+    /// `schema:PriceSpecification` (`PRICE_SPECIFICATION_CLASS`)
+    pub static ref PRICE_SPECIFICATION_CLASS: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "PriceSpecification");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// PriceTypeEnumeration
+    ///
+    /// Enumerates different price types, for example list price, invoice price, and sale price.
+    ///
+    /// `schema:PriceTypeEnumeration` (`PRICE_TYPE_ENUMERATION`)
+    pub static ref PRICE_TYPE_ENUMERATION: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "PriceTypeEnumeration");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// PrimaryCare
+    ///
+    /// The medical care by a physician, or other health-care professional, who is the patient's first contact with the health-care system and who may recommend a specialist if necessary.
+    ///
+    /// `schema:PrimaryCare` (`PRIMARY_CARE`)
+    pub static ref PRIMARY_CARE: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "PrimaryCare");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// Product
+    ///
+    /// Any offered product or service. For example: a pair of shoes; a concert ticket; the rental of a car; a haircut; or an episode of a TV show streamed online.
+    ///
+    /// `schema:Product` (`PRODUCT`)
+    pub static ref PRODUCT: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "Product");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// ProductCollection
+    ///
+    /// A set of products (either ProductGroups or specific variants) that are listed together e.g. in an Offer.
+    ///
+    /// `schema:ProductCollection` (`PRODUCT_COLLECTION`)
+    pub static ref PRODUCT_COLLECTION: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "ProductCollection");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// ProductGroup
+    ///
+    /// A ProductGroup represents a group of Products that vary only in certain well-described ways, such as by size, color, material etc.
+    ///
+    /// `schema:ProductGroup` (`PRODUCT_GROUP`)
+    pub static ref PRODUCT_GROUP: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "ProductGroup");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// ProductModel
+    ///
+    /// A datasheet or vendor specification of a product (in the sense of a prototypical description).
+    ///
+    /// `schema:ProductModel` (`PRODUCT_MODEL`)
+    pub static ref PRODUCT_MODEL: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "ProductModel");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// ProfessionalService
+    ///
+    /// Original definition: "provider of professional services." The general ProfessionalService type for local businesses was deprecated due to confusion with Service.
+    ///
+    /// `schema:ProfessionalService` (`PROFESSIONAL_SERVICE`)
+    pub static ref PROFESSIONAL_SERVICE: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "ProfessionalService");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// ProfilePage
+    ///
+    /// Web page type: Profile page.
+    ///
+    /// `schema:ProfilePage` (`PROFILE_PAGE`)
+    pub static ref PROFILE_PAGE: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "ProfilePage");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// ProgramMembership
+    ///
+    /// Used to describe membership in a loyalty programs (e.g. "StarAliance"), traveler clubs (e.g. "AAA"), purchase clubs ("Safeway Club"), etc.
+    ///
+    /// `schema:ProgramMembership` (`PROGRAM_MEMBERSHIP`)
+    pub static ref PROGRAM_MEMBERSHIP: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "ProgramMembership");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// Project
+    ///
+    /// An enterprise (potentially individual but typically collaborative), planned to achieve a particular aim. Use properties from Organization, subOrganization/parentOrganization to indicate project sub-structures.
+    ///
+    /// `schema:Project` (`PROJECT`)
+    pub static ref PROJECT: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "Project");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// PronounceableText
+    ///
+    /// Data type: PronounceableText.
+    ///
+    /// `schema:PronounceableText` (`PRONOUNCEABLE_TEXT`)
+    pub static ref PRONOUNCEABLE_TEXT: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "PronounceableText");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// Property
+    ///
+    /// A property, used to indicate attributes and relationships of some Thing; equivalent to rdf:Property.
+    ///
+    /// `schema:Property` (`PROPERTY`)
+    pub static ref PROPERTY: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "Property");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// PropertyValue
+    ///
+    /// A property-value pair, e.g. representing a feature of a product or place. Use the 'name' property for the name of the property.
+    ///
+    /// `schema:PropertyValue` (`PROPERTY_VALUE`)
+    pub static ref PROPERTY_VALUE: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "PropertyValue");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// PropertyValueSpecification
+    ///
+    /// A Property value specification.
+    ///
+    /// `schema:PropertyValueSpecification` (`PROPERTY_VALUE_SPECIFICATION`)
+    pub static ref PROPERTY_VALUE_SPECIFICATION: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "PropertyValueSpecification");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// Protein
+    ///
+    /// Protein is here used in its widest possible definition, as classes of amino acid based molecules. Amyloid-beta Protein in human (UniProt P05067), eukaryota (e.g. an OrthoDB group) or even a single molecule that one can point to are all of type Protein.
+    ///
+    /// `schema:Protein` (`PROTEIN`)
+    pub static ref PROTEIN: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "Protein");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// Psychiatric
+    ///
+    /// A specific branch of medical science that is concerned with the study, treatment, and prevention of mental illness, using both medical and psychological therapies.
+    ///
+    /// `schema:Psychiatric` (`PSYCHIATRIC`)
+    pub static ref PSYCHIATRIC: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "Psychiatric");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// PublicHealth
+    ///
+    /// Branch of medicine that pertains to the health services to improve and protect community health, especially epidemiology, sanitation, immunization, and preventive medicine.
+    ///
+    /// `schema:PublicHealth` (`PUBLIC_HEALTH`)
+    pub static ref PUBLIC_HEALTH: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "PublicHealth");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// PublicSwimmingPool
+    ///
+    /// A public swimming pool.
+    ///
+    /// `schema:PublicSwimmingPool` (`PUBLIC_SWIMMING_POOL`)
+    pub static ref PUBLIC_SWIMMING_POOL: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "PublicSwimmingPool");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// PublicToilet
+    ///
+    /// A public toilet is a room or small building containing one or more toilets (and possibly also urinals) which is available for use by the general public, or by customers or employees of certain businesses.
+    ///
+    /// `schema:PublicToilet` (`PUBLIC_TOILET`)
+    pub static ref PUBLIC_TOILET: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "PublicToilet");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// PublicationEvent
+    ///
+    /// A PublicationEvent corresponds indifferently to the event of publication for a CreativeWork of any type, e.g. a broadcast event, an on-demand event, a book/journal publication via a variety of delivery media.
+    ///
+    /// `schema:PublicationEvent` (`PUBLICATION_EVENT`)
+    pub static ref PUBLICATION_EVENT: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "PublicationEvent");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// PublicationIssue
+    ///
+    /// A part of a successively published publication such as a periodical or publication volume, often numbered, usually containing a grouping of works such as articles.
+    ///
+    /// `schema:PublicationIssue` (`PUBLICATION_ISSUE`)
+    pub static ref PUBLICATION_ISSUE: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "PublicationIssue");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// PublicationVolume
+    ///
+    /// A part of a successively published publication such as a periodical or multi-volume work, often numbered. It may represent a time span, such as a year.
+    ///
+    /// `schema:PublicationVolume` (`PUBLICATION_VOLUME`)
+    pub static ref PUBLICATION_VOLUME: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "PublicationVolume");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// PurchaseType
+    ///
+    /// Enumerates a purchase type for an item.
+    ///
+    /// `schema:PurchaseType` (`PURCHASE_TYPE`)
+    pub static ref PURCHASE_TYPE: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "PurchaseType");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// QAPage
+    ///
+    /// A QAPage is a WebPage focussed on a specific Question and its Answer(s), e.g. in a question answering site or documenting Frequently Asked Questions (FAQs).
+    ///
+    /// `schema:QAPage` (`QA_PAGE`)
+    pub static ref QA_PAGE: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "QAPage");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// QualitativeValue
+    ///
+    /// A predefined value for a product characteristic, e.g. the power cord plug type 'US' or the garment sizes 'S', 'M', 'L', and 'XL'.
+    ///
+    /// `schema:QualitativeValue` (`QUALITATIVE_VALUE`)
+    pub static ref QUALITATIVE_VALUE: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "QualitativeValue");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// QuantitativeValue
+    ///
+    /// A point value or interval for product characteristics and other purposes.
+    ///
+    /// `schema:QuantitativeValue` (`QUANTITATIVE_VALUE`)
+    pub static ref QUANTITATIVE_VALUE: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "QuantitativeValue");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// QuantitativeValueDistribution
+    ///
+    /// A statistical distribution of values.
+    ///
+    /// `schema:QuantitativeValueDistribution` (`QUANTITATIVE_VALUE_DISTRIBUTION`)
+    pub static ref QUANTITATIVE_VALUE_DISTRIBUTION: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "QuantitativeValueDistribution");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// Quantity
+    ///
+    /// Quantities such as distance, time, mass, weight, etc. Particular instances of say Mass are entities like '3 kg' or '4 milligrams'.
+    ///
+    /// `schema:Quantity` (`QUANTITY`)
+    pub static ref QUANTITY: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "Quantity");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// Question
+    ///
+    /// A specific question - e.g. from a user seeking answers online, or collected in a Frequently Asked Questions (FAQ) document.
+    ///
+    /// `schema:Question` (`QUESTION_CLASS`)
+    pub static ref QUESTION_CLASS: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "Question");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// Quiz
+    ///
+    /// Quiz: A test of knowledge, skills and abilities.
+    ///
+    /// `schema:Quiz` (`QUIZ`)
+    pub static ref QUIZ: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "Quiz");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// Quotation
+    ///
+    /// A quotation. Often but not necessarily from some written work, attributable to a real world author and - if associated with a fictional character - to any fictional Person. Use isBasedOn to link to source/origin.
+    ///
+    /// `schema:Quotation` (`QUOTATION`)
+    pub static ref QUOTATION: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "Quotation");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// QuoteAction
+    ///
+    /// An agent quotes/estimates/appraises an object/product/service with a price at a location/store.
+    ///
+    /// `schema:QuoteAction` (`QUOTE_ACTION`)
+    pub static ref QUOTE_ACTION: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "QuoteAction");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// RVPark
+    ///
+    /// A place offering space for "Recreational Vehicles", Caravans, mobile homes and the like.
+    ///
+    /// `schema:RVPark` (`RV_PARK`)
+    pub static ref RV_PARK: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "RVPark");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// RadiationTherapy
+    ///
+    /// A process of care using radiation aimed at improving a health condition.
+    ///
+    /// `schema:RadiationTherapy` (`RADIATION_THERAPY`)
+    pub static ref RADIATION_THERAPY: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "RadiationTherapy");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// RadioBroadcastService
+    ///
+    /// A delivery service through which radio content is provided via broadcast over the air or online.
+    ///
+    /// `schema:RadioBroadcastService` (`RADIO_BROADCAST_SERVICE`)
+    pub static ref RADIO_BROADCAST_SERVICE: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "RadioBroadcastService");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// RadioChannel
+    ///
+    /// A unique instance of a radio BroadcastService on a CableOrSatelliteService lineup.
+    ///
+    /// `schema:RadioChannel` (`RADIO_CHANNEL`)
+    pub static ref RADIO_CHANNEL: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "RadioChannel");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// RadioClip
+    ///
+    /// A short radio program or a segment/part of a radio program.
+    ///
+    /// `schema:RadioClip` (`RADIO_CLIP`)
+    pub static ref RADIO_CLIP: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "RadioClip");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// RadioEpisode
+    ///
+    /// A radio episode which can be part of a series or season.
+    ///
+    /// `schema:RadioEpisode` (`RADIO_EPISODE`)
+    pub static ref RADIO_EPISODE: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "RadioEpisode");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// RadioSeason
+    ///
+    /// Season dedicated to radio broadcast and associated online delivery.
+    ///
+    /// `schema:RadioSeason` (`RADIO_SEASON`)
+    pub static ref RADIO_SEASON: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "RadioSeason");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// RadioSeries
+    ///
+    /// CreativeWorkSeries dedicated to radio broadcast and associated online delivery.
+    ///
+    /// `schema:RadioSeries` (`RADIO_SERIES`)
+    pub static ref RADIO_SERIES: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "RadioSeries");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// RadioStation
+    ///
+    /// A radio station.
+    ///
+    /// `schema:RadioStation` (`RADIO_STATION`)
+    pub static ref RADIO_STATION: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "RadioStation");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// Rating
+    ///
+    /// A rating is an evaluation on a numeric scale, such as 1 to 5 stars.
+    ///
+    /// `schema:Rating` (`RATING`)
+    pub static ref RATING: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "Rating");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// ReactAction
+    ///
+    /// The act of responding instinctively and emotionally to an object, expressing a sentiment.
+    ///
+    /// `schema:ReactAction` (`REACT_ACTION`)
+    pub static ref REACT_ACTION: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "ReactAction");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// ReadAction
+    ///
+    /// The act of consuming written content.
+    ///
+    /// `schema:ReadAction` (`READ_ACTION`)
+    pub static ref READ_ACTION: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "ReadAction");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// RealEstateAgent
+    ///
+    /// A real-estate agent.
+    ///
+    /// `schema:RealEstateAgent` (`REAL_ESTATE_AGENT_CLASS`)
+    pub static ref REAL_ESTATE_AGENT_CLASS: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "RealEstateAgent");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// RealEstateListing
+    ///
+    /// A RealEstateListing is a listing that describes one or more real-estate Offers (whose businessFunction is typically to lease out, or to sell). The RealEstateListing type itself represents the overall listing, as manifested in some WebPage.
+    ///
+    /// `schema:RealEstateListing` (`REAL_ESTATE_LISTING`)
+    pub static ref REAL_ESTATE_LISTING: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "RealEstateListing");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// ReceiveAction
+    ///
+    /// The act of physically/electronically taking delivery of an object that has been transferred from an origin to a destination. Reciprocal of SendAction.
+    ///
+    /// `schema:ReceiveAction` (`RECEIVE_ACTION`)
+    pub static ref RECEIVE_ACTION: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "ReceiveAction");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// Recipe
+    ///
+    /// A recipe. For dietary restrictions covered by the recipe, a few common restrictions are enumerated via suitableForDiet. The keywords property can also be used to add more detail.
+    ///
+    /// `schema:Recipe` (`RECIPE_CLASS`)
+    pub static ref RECIPE_CLASS: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "Recipe");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// Recommendation
+    ///
+    /// Recommendation is a type of Review that suggests or proposes something as the best option or best course of action. Recommendations may be for products or services, or other concrete things, as in the case of a ranked list or product guide.
+    ///
+    /// `schema:Recommendation` (`RECOMMENDATION`)
+    pub static ref RECOMMENDATION: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "Recommendation");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// RecommendedDoseSchedule
+    ///
+    /// A recommended dosing schedule for a drug or supplement as prescribed or recommended by an authority or by the drug/supplement's manufacturer. Capture the recommending authority in the recognizingAuthority property of MedicalEntity.
+    ///
+    /// `schema:RecommendedDoseSchedule` (`RECOMMENDED_DOSE_SCHEDULE`)
+    pub static ref RECOMMENDED_DOSE_SCHEDULE: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "RecommendedDoseSchedule");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// RecyclingCenter
+    ///
+    /// A recycling center.
+    ///
+    /// `schema:RecyclingCenter` (`RECYCLING_CENTER`)
+    pub static ref RECYCLING_CENTER: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "RecyclingCenter");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// RefundTypeEnumeration
+    ///
+    /// Enumerates several kinds of product return refund types.
+    ///
+    /// `schema:RefundTypeEnumeration` (`REFUND_TYPE_ENUMERATION`)
+    pub static ref REFUND_TYPE_ENUMERATION: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "RefundTypeEnumeration");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// RegisterAction
+    ///
+    /// The act of registering to be a user of a service, product or web page.
+    ///
+    /// `schema:RegisterAction` (`REGISTER_ACTION`)
+    pub static ref REGISTER_ACTION: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "RegisterAction");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// RejectAction
+    ///
+    /// The act of rejecting to/adopting an object.
+    ///
+    /// `schema:RejectAction` (`REJECT_ACTION`)
+    pub static ref REJECT_ACTION: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "RejectAction");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// RentAction
+    ///
+    /// The act of giving money in return for temporary use, but not ownership, of an object such as a vehicle or property.
+    ///
+    /// `schema:RentAction` (`RENT_ACTION`)
+    pub static ref RENT_ACTION: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "RentAction");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// RentalCarReservation
+    ///
+    /// A reservation for a rental car.
+    ///
+    /// `schema:RentalCarReservation` (`RENTAL_CAR_RESERVATION`)
+    pub static ref RENTAL_CAR_RESERVATION: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "RentalCarReservation");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// RepaymentSpecification
+    ///
+    /// A structured value representing repayment.
+    ///
+    /// `schema:RepaymentSpecification` (`REPAYMENT_SPECIFICATION`)
+    pub static ref REPAYMENT_SPECIFICATION: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "RepaymentSpecification");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// ReplaceAction
+    ///
+    /// The act of editing a recipient by replacing an old object with a new object.
+    ///
+    /// `schema:ReplaceAction` (`REPLACE_ACTION`)
+    pub static ref REPLACE_ACTION: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "ReplaceAction");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// ReplyAction
+    ///
+    /// The act of responding to a question/message asked/sent by the object. Related to AskAction.
+    ///
+    /// `schema:ReplyAction` (`REPLY_ACTION`)
+    pub static ref REPLY_ACTION: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "ReplyAction");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// Report
+    ///
+    /// A Report generated by governmental or non-governmental organization.
+    ///
+    /// `schema:Report` (`REPORT`)
+    pub static ref REPORT: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "Report");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// ReportageNewsArticle
+    ///
+    /// The ReportageNewsArticle type is a subtype of NewsArticle representing news articles which are the result of journalistic news reporting conventions.
+    ///
+    /// `schema:ReportageNewsArticle` (`REPORTAGE_NEWS_ARTICLE`)
+    pub static ref REPORTAGE_NEWS_ARTICLE: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "ReportageNewsArticle");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// ReportedDoseSchedule
+    ///
+    /// A patient-reported or observed dosing schedule for a drug or supplement.
+    ///
+    /// `schema:ReportedDoseSchedule` (`REPORTED_DOSE_SCHEDULE`)
+    pub static ref REPORTED_DOSE_SCHEDULE: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "ReportedDoseSchedule");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// ResearchOrganization
+    ///
+    /// A Research Organization (e.g. scientific institute, research company).
+    ///
+    /// `schema:ResearchOrganization` (`RESEARCH_ORGANIZATION`)
+    pub static ref RESEARCH_ORGANIZATION: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "ResearchOrganization");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// ResearchProject
+    ///
+    /// A Research project.
+    ///
+    /// `schema:ResearchProject` (`RESEARCH_PROJECT`)
+    pub static ref RESEARCH_PROJECT: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "ResearchProject");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// Researcher
+    ///
+    /// Researchers.
+    ///
+    /// `schema:Researcher` (`RESEARCHER`)
+    pub static ref RESEARCHER: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "Researcher");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// Reservation
+    ///
+    /// Describes a reservation for travel, dining or an event. Some reservations require tickets.
+    ///
+    /// `schema:Reservation` (`RESERVATION`)
+    pub static ref RESERVATION: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "Reservation");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// ReservationPackage
+    ///
+    /// A group of multiple reservations with common values for all sub-reservations.
+    ///
+    /// `schema:ReservationPackage` (`RESERVATION_PACKAGE`)
+    pub static ref RESERVATION_PACKAGE: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "ReservationPackage");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// ReservationStatusType
+    ///
+    /// Enumerated status values for Reservation.
+    ///
+    /// `schema:ReservationStatusType` (`RESERVATION_STATUS_TYPE`)
+    pub static ref RESERVATION_STATUS_TYPE: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "ReservationStatusType");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// ReserveAction
+    ///
+    /// Reserving a concrete object.
+    ///
+    /// `schema:ReserveAction` (`RESERVE_ACTION`)
+    pub static ref RESERVE_ACTION: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "ReserveAction");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// Reservoir
+    ///
+    /// A reservoir of water, typically an artificially created lake, like the Lake Kariba reservoir.
+    ///
+    /// `schema:Reservoir` (`RESERVOIR`)
+    pub static ref RESERVOIR: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "Reservoir");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// Residence
+    ///
+    /// The place where a person lives.
+    ///
+    /// `schema:Residence` (`RESIDENCE`)
+    pub static ref RESIDENCE: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "Residence");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// Resort
+    ///
+    /// A resort is a place used for relaxation or recreation, attracting visitors for holidays or vacations. Resorts are places, towns or sometimes commercial establishments operated by a single company (source: Wikipedia, the free encyclopedia).
+    ///
+    /// `schema:Resort` (`RESORT`)
+    pub static ref RESORT: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "Resort");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// RespiratoryTherapy
+    ///
+    /// The therapy that is concerned with the maintenance or improvement of respiratory function (as in patients with pulmonary disease).
+    ///
+    /// `schema:RespiratoryTherapy` (`RESPIRATORY_THERAPY`)
+    pub static ref RESPIRATORY_THERAPY: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "RespiratoryTherapy");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// Restaurant
+    ///
+    /// A restaurant.
+    ///
+    /// `schema:Restaurant` (`RESTAURANT`)
+    pub static ref RESTAURANT: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "Restaurant");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// RestrictedDiet
+    ///
+    /// A diet restricted to certain foods or preparations for cultural, religious, health or lifestyle reasons.
+    ///
+    /// `schema:RestrictedDiet` (`RESTRICTED_DIET`)
+    pub static ref RESTRICTED_DIET: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "RestrictedDiet");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// ResumeAction
+    ///
+    /// The act of resuming a device or application which was formerly paused (e.g. resume music playback or resume a timer).
+    ///
+    /// `schema:ResumeAction` (`RESUME_ACTION`)
+    pub static ref RESUME_ACTION: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "ResumeAction");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// ReturnAction
+    ///
+    /// The act of returning to the origin that which was previously received (concrete objects) or taken (ownership).
+    ///
+    /// `schema:ReturnAction` (`RETURN_ACTION`)
+    pub static ref RETURN_ACTION: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "ReturnAction");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// ReturnFeesEnumeration
+    ///
+    /// Enumerates several kinds of policies for product return fees.
+    ///
+    /// `schema:ReturnFeesEnumeration` (`RETURN_FEES_ENUMERATION`)
+    pub static ref RETURN_FEES_ENUMERATION: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "ReturnFeesEnumeration");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// ReturnLabelSourceEnumeration
+    ///
+    /// Enumerates several types of return labels for product returns.
+    ///
+    /// `schema:ReturnLabelSourceEnumeration` (`RETURN_LABEL_SOURCE_ENUMERATION`)
+    pub static ref RETURN_LABEL_SOURCE_ENUMERATION: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "ReturnLabelSourceEnumeration");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// ReturnMethodEnumeration
+    ///
+    /// Enumerates several types of product return methods.
+    ///
+    /// `schema:ReturnMethodEnumeration` (`RETURN_METHOD_ENUMERATION`)
+    pub static ref RETURN_METHOD_ENUMERATION: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "ReturnMethodEnumeration");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// Review
+    ///
+    /// A review of an item - for example, of a restaurant, movie, or store.
+    ///
+    /// `schema:Review` (`REVIEW_CLASS`)
+    pub static ref REVIEW_CLASS: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "Review");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// ReviewAction
+    ///
+    /// The act of producing a balanced opinion about the object for an audience. An agent reviews an object with participants resulting in a review.
+    ///
+    /// `schema:ReviewAction` (`REVIEW_ACTION`)
+    pub static ref REVIEW_ACTION: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "ReviewAction");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// ReviewNewsArticle
+    ///
+    /// A NewsArticle and CriticReview providing a professional critic's assessment of a service, product, performance, or artistic or literary work.
+    ///
+    /// `schema:ReviewNewsArticle` (`REVIEW_NEWS_ARTICLE`)
+    pub static ref REVIEW_NEWS_ARTICLE: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "ReviewNewsArticle");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// RiverBodyOfWater
+    ///
+    /// A river (for example, the broad majestic Shannon).
+    ///
+    /// `schema:RiverBodyOfWater` (`RIVER_BODY_OF_WATER`)
+    pub static ref RIVER_BODY_OF_WATER: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "RiverBodyOfWater");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// Role
+    ///
+    /// Represents additional information about a relationship or property. For example a Role can be used to say that a 'member' role linking some SportsTeam to a player occurred during a particular time period.
+    ///
+    /// `schema:Role` (`ROLE`)
+    pub static ref ROLE: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "Role");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// RoofingContractor
+    ///
+    /// A roofing contractor.
+    ///
+    /// `schema:RoofingContractor` (`ROOFING_CONTRACTOR`)
+    pub static ref ROOFING_CONTRACTOR: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "RoofingContractor");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// Room
+    ///
+    /// A room is a distinguishable space within a structure, usually separated from other spaces by interior walls.
+    ///
+    /// `schema:Room` (`ROOM`)
+    pub static ref ROOM: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "Room");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// RsvpAction
+    ///
+    /// The act of notifying an event organizer as to whether you expect to attend the event.
+    ///
+    /// `schema:RsvpAction` (`RSVP_ACTION`)
+    pub static ref RSVP_ACTION: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "RsvpAction");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// RsvpResponseType
+    ///
+    /// RsvpResponseType is an enumeration type whose instances represent responding to an RSVP request.
+    ///
+    /// `schema:RsvpResponseType` (`RSVP_RESPONSE_TYPE`)
+    pub static ref RSVP_RESPONSE_TYPE: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "RsvpResponseType");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// SaleEvent
+    ///
+    /// Event type: Sales event.
+    ///
+    /// `schema:SaleEvent` (`SALE_EVENT`)
+    pub static ref SALE_EVENT: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "SaleEvent");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// SatiricalArticle
+    ///
+    /// An Article whose content is primarily satirical in nature, i.e. unlikely to be literally true.
+    ///
+    /// `schema:SatiricalArticle` (`SATIRICAL_ARTICLE`)
+    pub static ref SATIRICAL_ARTICLE: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "SatiricalArticle");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// Schedule
+    ///
+    /// A schedule defines a repeating time period used to describe a regularly occurring Event. At a minimum a schedule will specify repeatFrequency which describes the interval between occurrences of the event.
+    ///
+    /// `schema:Schedule` (`SCHEDULE`)
+    pub static ref SCHEDULE: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "Schedule");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// ScheduleAction
+    ///
+    /// Scheduling future actions, events, or tasks.
+    ///
+    /// `schema:ScheduleAction` (`SCHEDULE_ACTION`)
+    pub static ref SCHEDULE_ACTION: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "ScheduleAction");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// ScholarlyArticle
+    ///
+    /// A scholarly article.
+    ///
+    /// `schema:ScholarlyArticle` (`SCHOLARLY_ARTICLE`)
+    pub static ref SCHOLARLY_ARTICLE: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "ScholarlyArticle");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// School
+    ///
+    /// A school.
+    ///
+    /// `schema:School` (`SCHOOL`)
+    pub static ref SCHOOL: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "School");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// SchoolDistrict
+    ///
+    /// A School District is an administrative area for the administration of schools.
+    ///
+    /// `schema:SchoolDistrict` (`SCHOOL_DISTRICT`)
+    pub static ref SCHOOL_DISTRICT: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "SchoolDistrict");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// ScreeningEvent
+    ///
+    /// A screening of a movie or other video.
+    ///
+    /// `schema:ScreeningEvent` (`SCREENING_EVENT`)
+    pub static ref SCREENING_EVENT: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "ScreeningEvent");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// Sculpture
+    ///
+    /// A piece of sculpture.
+    ///
+    /// `schema:Sculpture` (`SCULPTURE`)
+    pub static ref SCULPTURE: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "Sculpture");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// SeaBodyOfWater
+    ///
+    /// A sea (for example, the Caspian sea).
+    ///
+    /// `schema:SeaBodyOfWater` (`SEA_BODY_OF_WATER`)
+    pub static ref SEA_BODY_OF_WATER: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "SeaBodyOfWater");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// SearchAction
+    ///
+    /// The act of searching for an object.
+    ///
+    /// `schema:SearchAction` (`SEARCH_ACTION`)
+    pub static ref SEARCH_ACTION: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "SearchAction");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// SearchRescueOrganization
+    ///
+    /// A Search and Rescue organization of some kind.
+    ///
+    /// `schema:SearchRescueOrganization` (`SEARCH_RESCUE_ORGANIZATION`)
+    pub static ref SEARCH_RESCUE_ORGANIZATION: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "SearchRescueOrganization");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// SearchResultsPage
+    ///
+    /// Web page type: Search results page.
+    ///
+    /// `schema:SearchResultsPage` (`SEARCH_RESULTS_PAGE`)
+    pub static ref SEARCH_RESULTS_PAGE: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "SearchResultsPage");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// Season
+    ///
+    /// A media season e.g. tv, radio, video game etc.
+    ///
+    /// `schema:Season` (`SEASON_CLASS`)
+    pub static ref SEASON_CLASS: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "Season");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// Seat
+    ///
+    /// Used to describe a seat, such as a reserved seat in an event reservation.
+    ///
+    /// `schema:Seat` (`SEAT`)
+    pub static ref SEAT: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "Seat");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// SeekToAction
+    ///
+    /// This is the Action of navigating to a specific startOffset timestamp within a VideoObject, typically represented with a URL template structure.
+    ///
+    /// `schema:SeekToAction` (`SEEK_TO_ACTION`)
+    pub static ref SEEK_TO_ACTION: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "SeekToAction");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// SelfStorage
+    ///
+    /// A self-storage facility.
+    ///
+    /// `schema:SelfStorage` (`SELF_STORAGE`)
+    pub static ref SELF_STORAGE: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "SelfStorage");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// SellAction
+    ///
+    /// The act of taking money from a buyer in exchange for goods or services rendered. An agent sells an object, product, or service to a buyer for a price.
+    ///
+    /// `schema:SellAction` (`SELL_ACTION`)
+    pub static ref SELL_ACTION: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "SellAction");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// SendAction
+    ///
+    /// The act of physically/electronically dispatching an object for transfer from an origin to a destination. Related actions: ReceiveAction, GiveAction.
+    ///
+    /// `schema:SendAction` (`SEND_ACTION`)
+    pub static ref SEND_ACTION: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "SendAction");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// Series
+    ///
+    /// A Series in schema.org is a group of related items, typically but not necessarily of the same kind. See also CreativeWorkSeries, EventSeries.
+    ///
+    /// `schema:Series` (`SERIES`)
+    pub static ref SERIES: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "Series");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// Service
+    ///
+    /// A service provided by an organization, e.g. delivery service, print services, etc.
+    ///
+    /// `schema:Service` (`SERVICE`)
+    pub static ref SERVICE: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "Service");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// ServiceChannel
+    ///
+    /// A means for accessing a service, e.g. a government office location, web site, or phone number.
+    ///
+    /// `schema:ServiceChannel` (`SERVICE_CHANNEL`)
+    pub static ref SERVICE_CHANNEL: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "ServiceChannel");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// ServicePeriod
+    ///
+    /// ServicePeriod represents a duration with some constraints about cutoff time and business days. This is used e.g. in shipping for handling times or transit time.
+    ///
+    /// `schema:ServicePeriod` (`SERVICE_PERIOD`)
+    pub static ref SERVICE_PERIOD: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "ServicePeriod");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// ShareAction
+    ///
+    /// The act of distributing content to people for their amusement or edification.
+    ///
+    /// `schema:ShareAction` (`SHARE_ACTION`)
+    pub static ref SHARE_ACTION: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "ShareAction");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// SheetMusic
+    ///
+    /// Printed music, as opposed to performed or recorded music.
+    ///
+    /// `schema:SheetMusic` (`SHEET_MUSIC`)
+    pub static ref SHEET_MUSIC: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "SheetMusic");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// ShippingConditions
+    ///
+    /// ShippingConditions represent a set of constraints and information about the conditions of shipping a product. Such conditions may apply to only a subset of the products being shipped, depending on aspects of the product like weight, size, price, destination, and others.
+    ///
+    /// `schema:ShippingConditions` (`SHIPPING_CONDITIONS`)
+    pub static ref SHIPPING_CONDITIONS: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "ShippingConditions");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// ShippingDeliveryTime
+    ///
+    /// ShippingDeliveryTime provides various pieces of information about delivery times for shipping.
+    ///
+    /// `schema:ShippingDeliveryTime` (`SHIPPING_DELIVERY_TIME`)
+    pub static ref SHIPPING_DELIVERY_TIME: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "ShippingDeliveryTime");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// ShippingRateSettings
+    ///
+    /// A ShippingRateSettings represents re-usable pieces of shipping information. It is designed for publication on an URL that may be referenced via the shippingSettingsLink property of an OfferShippingDetails.
+    ///
+    /// `schema:ShippingRateSettings` (`SHIPPING_RATE_SETTINGS`)
+    pub static ref SHIPPING_RATE_SETTINGS: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "ShippingRateSettings");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// ShippingService
+    ///
+    /// ShippingService represents the criteria used to determine if and how an offer could be shipped to a customer.
+    ///
+    /// `schema:ShippingService` (`SHIPPING_SERVICE`)
+    pub static ref SHIPPING_SERVICE: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "ShippingService");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// ShoeStore
+    ///
+    /// A shoe store.
+    ///
+    /// `schema:ShoeStore` (`SHOE_STORE`)
+    pub static ref SHOE_STORE: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "ShoeStore");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// ShoppingCenter
+    ///
+    /// A shopping center or mall.
+    ///
+    /// `schema:ShoppingCenter` (`SHOPPING_CENTER`)
+    pub static ref SHOPPING_CENTER: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "ShoppingCenter");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// ShortStory
+    ///
+    /// Short story or tale. A brief work of literature, usually written in narrative prose.
+    ///
+    /// `schema:ShortStory` (`SHORT_STORY`)
+    pub static ref SHORT_STORY: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "ShortStory");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// SingleFamilyResidence
+    ///
+    /// Residence type: Single-family home.
+    ///
+    /// `schema:SingleFamilyResidence` (`SINGLE_FAMILY_RESIDENCE`)
+    pub static ref SINGLE_FAMILY_RESIDENCE: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "SingleFamilyResidence");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// SiteNavigationElement
+    ///
+    /// A navigation element of the page.
+    ///
+    /// `schema:SiteNavigationElement` (`SITE_NAVIGATION_ELEMENT`)
+    pub static ref SITE_NAVIGATION_ELEMENT: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "SiteNavigationElement");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// SizeGroupEnumeration
+    ///
+    /// Enumerates common size groups for various product categories.
+    ///
+    /// `schema:SizeGroupEnumeration` (`SIZE_GROUP_ENUMERATION`)
+    pub static ref SIZE_GROUP_ENUMERATION: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "SizeGroupEnumeration");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// SizeSpecification
+    ///
+    /// Size related properties of a product, typically a size code (name) and optionally a sizeSystem, sizeGroup, and product measurements (hasMeasurement). In addition, the intended audience can be defined through suggestedAge, suggestedGender, and suggested body measurements (suggestedMeasurement).
+    ///
+    /// `schema:SizeSpecification` (`SIZE_SPECIFICATION`)
+    pub static ref SIZE_SPECIFICATION: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "SizeSpecification");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// SizeSystemEnumeration
+    ///
+    /// Enumerates common size systems for different categories of products, for example "EN-13402" or "UK" for wearables or "Imperial" for screws.
+    ///
+    /// `schema:SizeSystemEnumeration` (`SIZE_SYSTEM_ENUMERATION`)
+    pub static ref SIZE_SYSTEM_ENUMERATION: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "SizeSystemEnumeration");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// SkiResort
+    ///
+    /// A ski resort.
+    ///
+    /// `schema:SkiResort` (`SKI_RESORT`)
+    pub static ref SKI_RESORT: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "SkiResort");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// SocialEvent
+    ///
+    /// Event type: Social event.
+    ///
+    /// `schema:SocialEvent` (`SOCIAL_EVENT`)
+    pub static ref SOCIAL_EVENT: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "SocialEvent");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// SocialMediaPosting
+    ///
+    /// A post to a social media platform, including blog posts, tweets, Facebook posts, etc.
+    ///
+    /// `schema:SocialMediaPosting` (`SOCIAL_MEDIA_POSTING`)
+    pub static ref SOCIAL_MEDIA_POSTING: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "SocialMediaPosting");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// SoftwareApplication
+    ///
+    /// A software application.
+    ///
+    /// `schema:SoftwareApplication` (`SOFTWARE_APPLICATION`)
+    pub static ref SOFTWARE_APPLICATION: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "SoftwareApplication");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// SoftwareSourceCode
+    ///
+    /// Computer programming source code. Example: Full (compile ready) solutions, code snippet samples, scripts, templates.
+    ///
+    /// `schema:SoftwareSourceCode` (`SOFTWARE_SOURCE_CODE`)
+    pub static ref SOFTWARE_SOURCE_CODE: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "SoftwareSourceCode");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// SolveMathAction
+    ///
+    /// The action that takes in a math expression and directs users to a page potentially capable of solving/simplifying that expression.
+    ///
+    /// `schema:SolveMathAction` (`SOLVE_MATH_ACTION`)
+    pub static ref SOLVE_MATH_ACTION: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "SolveMathAction");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// SomeProducts
+    ///
+    /// A placeholder for multiple similar products of the same kind.
+    ///
+    /// `schema:SomeProducts` (`SOME_PRODUCTS`)
+    pub static ref SOME_PRODUCTS: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "SomeProducts");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// SpeakableSpecification
+    ///
+    /// A SpeakableSpecification indicates (typically via xpath or cssSelector) sections of a document that are highlighted as particularly speakable. Instances of this type are expected to be used primarily as values of the speakable property.
+    ///
+    /// `schema:SpeakableSpecification` (`SPEAKABLE_SPECIFICATION`)
+    pub static ref SPEAKABLE_SPECIFICATION: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "SpeakableSpecification");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// SpecialAnnouncement
+    ///
+    /// A SpecialAnnouncement combines a simple date-stamped textual information update with contextualized Web links and other structured data. It represents an information update made by a locally-oriented organization, for example schools, pharmacies, healthcare providers, community groups, police, local government.
+    ///
+    /// `schema:SpecialAnnouncement` (`SPECIAL_ANNOUNCEMENT`)
+    pub static ref SPECIAL_ANNOUNCEMENT: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "SpecialAnnouncement");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// Specialty
+    ///
+    /// Any branch of a field in which people typically develop specific expertise, usually after significant study, time, and effort.
+    ///
+    /// `schema:Specialty` (`SPECIALTY_CLASS`)
+    pub static ref SPECIALTY_CLASS: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "Specialty");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// SportingGoodsStore
+    ///
+    /// A sporting goods store.
+    ///
+    /// `schema:SportingGoodsStore` (`SPORTING_GOODS_STORE`)
+    pub static ref SPORTING_GOODS_STORE: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "SportingGoodsStore");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// SportsActivityLocation
+    ///
+    /// A sports location, such as a playing field.
+    ///
+    /// `schema:SportsActivityLocation` (`SPORTS_ACTIVITY_LOCATION_CLASS`)
+    pub static ref SPORTS_ACTIVITY_LOCATION_CLASS: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "SportsActivityLocation");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// SportsClub
+    ///
+    /// A sports club.
+    ///
+    /// `schema:SportsClub` (`SPORTS_CLUB`)
+    pub static ref SPORTS_CLUB: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "SportsClub");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// SportsEvent
+    ///
+    /// Event type: Sports event.
+    ///
+    /// `schema:SportsEvent` (`SPORTS_EVENT_CLASS`)
+    pub static ref SPORTS_EVENT_CLASS: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "SportsEvent");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// SportsOrganization
+    ///
+    /// Represents the collection of all sports organizations, including sports teams, governing bodies, and sports associations.
+    ///
+    /// `schema:SportsOrganization` (`SPORTS_ORGANIZATION`)
+    pub static ref SPORTS_ORGANIZATION: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "SportsOrganization");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// SportsTeam
+    ///
+    /// Organization: Sports team.
+    ///
+    /// `schema:SportsTeam` (`SPORTS_TEAM_CLASS`)
+    pub static ref SPORTS_TEAM_CLASS: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "SportsTeam");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// SpreadsheetDigitalDocument
+    ///
+    /// A spreadsheet file.
+    ///
+    /// `schema:SpreadsheetDigitalDocument` (`SPREADSHEET_DIGITAL_DOCUMENT`)
+    pub static ref SPREADSHEET_DIGITAL_DOCUMENT: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "SpreadsheetDigitalDocument");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// StadiumOrArena
+    ///
+    /// A stadium.
+    ///
+    /// `schema:StadiumOrArena` (`STADIUM_OR_ARENA`)
+    pub static ref STADIUM_OR_ARENA: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "StadiumOrArena");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// State
+    ///
+    /// A state or province of a country.
+    ///
+    /// `schema:State` (`STATE`)
+    pub static ref STATE: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "State");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// Statement
+    ///
+    /// A statement about something, for example a fun or interesting fact. If known, the main entity this statement is about can be indicated using mainEntity.
+    ///
+    /// `schema:Statement` (`STATEMENT`)
+    pub static ref STATEMENT: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "Statement");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// StatisticalPopulation
+    ///
+    /// A StatisticalPopulation is a set of instances of a certain given type that satisfy some set of constraints. The property populationType is used to specify the type. Any property that can be used on instances of that type can appear on the statistical population.
+    ///
+    /// `schema:StatisticalPopulation` (`STATISTICAL_POPULATION`)
+    pub static ref STATISTICAL_POPULATION: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "StatisticalPopulation");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// StatisticalVariable
+    ///
+    /// StatisticalVariable represents any type of statistical metric that can be measured at a place and time. The usage pattern for StatisticalVariable is typically expressed using Observation with an explicit populationType, which is a type, typically drawn from Schema.org.
+    ///
+    /// `schema:StatisticalVariable` (`STATISTICAL_VARIABLE`)
+    pub static ref STATISTICAL_VARIABLE: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "StatisticalVariable");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// StatusEnumeration
+    ///
+    /// Lists or enumerations dealing with status types.
+    ///
+    /// `schema:StatusEnumeration` (`STATUS_ENUMERATION`)
+    pub static ref STATUS_ENUMERATION: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "StatusEnumeration");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// SteeringPositionValue
+    ///
+    /// A value indicating a steering position.
+    ///
+    /// `schema:SteeringPositionValue` (`STEERING_POSITION_VALUE`)
+    pub static ref STEERING_POSITION_VALUE: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "SteeringPositionValue");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// Store
+    ///
+    /// A retail good store.
+    ///
+    /// `schema:Store` (`STORE`)
+    pub static ref STORE: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "Store");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// StructuredValue
+    ///
+    /// Structured values are used when the value of a property has a more complex structure than simply being a textual value or a reference to another thing.
+    ///
+    /// `schema:StructuredValue` (`STRUCTURED_VALUE`)
+    pub static ref STRUCTURED_VALUE: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "StructuredValue");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// SubscribeAction
+    ///
+    /// The act of forming a personal connection with someone/something (object) unidirectionally/asymmetrically to get updates pushed to.
+    ///
+    /// `schema:SubscribeAction` (`SUBSCRIBE_ACTION`)
+    pub static ref SUBSCRIBE_ACTION: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "SubscribeAction");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// Substance
+    ///
+    /// Any matter of defined composition that has discrete existence, whose origin may be biological, mineral or chemical.
+    ///
+    /// `schema:Substance` (`SUBSTANCE`)
+    pub static ref SUBSTANCE: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "Substance");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// SubwayStation
+    ///
+    /// A subway station.
+    ///
+    /// `schema:SubwayStation` (`SUBWAY_STATION`)
+    pub static ref SUBWAY_STATION: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "SubwayStation");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// Suite
+    ///
+    /// A suite in a hotel or other public accommodation, denotes a class of luxury accommodations, the key feature of which is multiple rooms.
+    ///
+    /// `schema:Suite` (`SUITE`)
+    pub static ref SUITE: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "Suite");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// SuperficialAnatomy
+    ///
+    /// Anatomical features that can be observed by sight (without dissection), including the form and proportions of the human body as well as surface landmarks that correspond to deeper subcutaneous structures.
+    ///
+    /// `schema:SuperficialAnatomy` (`SUPERFICIAL_ANATOMY`)
+    pub static ref SUPERFICIAL_ANATOMY: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "SuperficialAnatomy");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// SurgicalProcedure
+    ///
+    /// A medical procedure involving an incision with instruments; performed for diagnose, or therapeutic purposes.
+    ///
+    /// `schema:SurgicalProcedure` (`SURGICAL_PROCEDURE`)
+    pub static ref SURGICAL_PROCEDURE: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "SurgicalProcedure");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// SuspendAction
+    ///
+    /// The act of momentarily pausing a device or application (e.g. pause music playback or pause a timer).
+    ///
+    /// `schema:SuspendAction` (`SUSPEND_ACTION`)
+    pub static ref SUSPEND_ACTION: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "SuspendAction");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// Syllabus
+    ///
+    /// A syllabus that describes the material covered in a course, often with several such sections per Course so that a distinct timeRequired can be provided for that section of the Course.
+    ///
+    /// `schema:Syllabus` (`SYLLABUS`)
+    pub static ref SYLLABUS: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "Syllabus");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// Synagogue
+    ///
+    /// A synagogue.
+    ///
+    /// `schema:Synagogue` (`SYNAGOGUE`)
+    pub static ref SYNAGOGUE: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "Synagogue");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// TVClip
+    ///
+    /// A short TV program or a segment/part of a TV program.
+    ///
+    /// `schema:TVClip` (`TV_CLIP`)
+    pub static ref TV_CLIP: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "TVClip");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// TVEpisode
+    ///
+    /// A TV episode which can be part of a series or season.
+    ///
+    /// `schema:TVEpisode` (`TV_EPISODE`)
+    pub static ref TV_EPISODE: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "TVEpisode");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// TVSeason
+    ///
+    /// Season dedicated to TV broadcast and associated online delivery.
+    ///
+    /// `schema:TVSeason` (`TV_SEASON`)
+    pub static ref TV_SEASON: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "TVSeason");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// TVSeries
+    ///
+    /// CreativeWorkSeries dedicated to TV broadcast and associated online delivery.
+    ///
+    /// `schema:TVSeries` (`TV_SERIES`)
+    pub static ref TV_SERIES: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "TVSeries");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// Table
+    ///
+    /// A table on a Web page.
+    ///
+    /// `schema:Table` (`TABLE`)
+    pub static ref TABLE: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "Table");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// TakeAction
+    ///
+    /// The act of gaining ownership of an object from an origin. Reciprocal of GiveAction.
+    ///
+    /// `schema:TakeAction` (`TAKE_ACTION`)
+    pub static ref TAKE_ACTION: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "TakeAction");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// TattooParlor
+    ///
+    /// A tattoo parlor.
+    ///
+    /// `schema:TattooParlor` (`TATTOO_PARLOR`)
+    pub static ref TATTOO_PARLOR: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "TattooParlor");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// Taxi
+    ///
+    /// A taxi.
+    ///
+    /// `schema:Taxi` (`TAXI`)
+    pub static ref TAXI: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "Taxi");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// TaxiReservation
+    ///
+    /// A reservation for a taxi.
+    ///
+    /// `schema:TaxiReservation` (`TAXI_RESERVATION`)
+    pub static ref TAXI_RESERVATION: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "TaxiReservation");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// TaxiService
+    ///
+    /// A service for a vehicle for hire with a driver for local travel. Fares are usually calculated based on distance traveled.
+    ///
+    /// `schema:TaxiService` (`TAXI_SERVICE`)
+    pub static ref TAXI_SERVICE: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "TaxiService");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// TaxiStand
+    ///
+    /// A taxi stand.
+    ///
+    /// `schema:TaxiStand` (`TAXI_STAND`)
+    pub static ref TAXI_STAND: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "TaxiStand");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// Taxon
+    ///
+    /// A set of organisms asserted to represent a natural cohesive biological unit.
+    ///
+    /// `schema:Taxon` (`TAXON`)
+    pub static ref TAXON: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "Taxon");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// TechArticle
+    ///
+    /// A technical article - Example: How-to (task) topics, step-by-step, procedural troubleshooting, specifications, etc.
+    ///
+    /// `schema:TechArticle` (`TECH_ARTICLE`)
+    pub static ref TECH_ARTICLE: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "TechArticle");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// TelevisionChannel
+    ///
+    /// A unique instance of a television BroadcastService on a CableOrSatelliteService lineup.
+    ///
+    /// `schema:TelevisionChannel` (`TELEVISION_CHANNEL`)
+    pub static ref TELEVISION_CHANNEL: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "TelevisionChannel");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// TelevisionStation
+    ///
+    /// A television station.
+    ///
+    /// `schema:TelevisionStation` (`TELEVISION_STATION`)
+    pub static ref TELEVISION_STATION: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "TelevisionStation");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// TennisComplex
+    ///
+    /// A tennis complex.
+    ///
+    /// `schema:TennisComplex` (`TENNIS_COMPLEX`)
+    pub static ref TENNIS_COMPLEX: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "TennisComplex");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// Text
+    ///
+    /// Data type: Text.
+    ///
+    /// `schema:Text` (`TEXT_CLASS`)
+    pub static ref TEXT_CLASS: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "Text");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// TextDigitalDocument
+    ///
+    /// A file composed primarily of text.
+    ///
+    /// `schema:TextDigitalDocument` (`TEXT_DIGITAL_DOCUMENT`)
+    pub static ref TEXT_DIGITAL_DOCUMENT: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "TextDigitalDocument");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// TextObject
+    ///
+    /// A text file. The text can be unformatted or contain markup, html, etc.
+    ///
+    /// `schema:TextObject` (`TEXT_OBJECT`)
+    pub static ref TEXT_OBJECT: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "TextObject");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// TheaterEvent
+    ///
+    /// Event type: Theater performance.
+    ///
+    /// `schema:TheaterEvent` (`THEATER_EVENT`)
+    pub static ref THEATER_EVENT: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "TheaterEvent");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// TheaterGroup
+    ///
+    /// A theater group or company, for example, the Royal Shakespeare Company or Druid Theatre.
+    ///
+    /// `schema:TheaterGroup` (`THEATER_GROUP`)
+    pub static ref THEATER_GROUP: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "TheaterGroup");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// TherapeuticProcedure
+    ///
+    /// A medical procedure intended primarily for therapeutic purposes, aimed at improving a health condition.
+    ///
+    /// `schema:TherapeuticProcedure` (`THERAPEUTIC_PROCEDURE`)
+    pub static ref THERAPEUTIC_PROCEDURE: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "TherapeuticProcedure");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// Thesis
+    ///
+    /// A thesis or dissertation document submitted in support of candidature for an academic degree or professional qualification.
+    ///
+    /// `schema:Thesis` (`THESIS`)
+    pub static ref THESIS: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "Thesis");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// Thing
+    ///
+    /// The most generic type of item.
+    ///
+    /// `schema:Thing` (`THING`)
+    pub static ref THING: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "Thing");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// Ticket
+    ///
+    /// Used to describe a ticket to an event, a flight, a bus ride, etc.
+    ///
+    /// `schema:Ticket` (`TICKET`)
+    pub static ref TICKET: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "Ticket");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// TieAction
+    ///
+    /// The act of reaching a draw in a competitive activity.
+    ///
+    /// `schema:TieAction` (`TIE_ACTION`)
+    pub static ref TIE_ACTION: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "TieAction");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// TierBenefitEnumeration
+    ///
+    /// An enumeration of possible benefits as part of a loyalty (members) program.
+    ///
+    /// `schema:TierBenefitEnumeration` (`TIER_BENEFIT_ENUMERATION`)
+    pub static ref TIER_BENEFIT_ENUMERATION: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "TierBenefitEnumeration");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// Time
+    ///
+    /// A point in time recurring on multiple days in the form hh:mm:ss[Z|(+|-)hh:mm] (see XML schema for details).
+    ///
+    /// `schema:Time` (`TIME`)
+    pub static ref TIME: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "Time");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// TipAction
+    ///
+    /// The act of giving money voluntarily to a beneficiary in recognition of services rendered.
+    ///
+    /// `schema:TipAction` (`TIP_ACTION`)
+    pub static ref TIP_ACTION: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "TipAction");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// TireShop
+    ///
+    /// A tire shop.
+    ///
+    /// `schema:TireShop` (`TIRE_SHOP`)
+    pub static ref TIRE_SHOP: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "TireShop");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// TouristAttraction
+    ///
+    /// A tourist attraction. In principle any Thing can be a TouristAttraction, from a Mountain and LandmarksOrHistoricalBuildings to a LocalBusiness.
+    ///
+    /// `schema:TouristAttraction` (`TOURIST_ATTRACTION`)
+    pub static ref TOURIST_ATTRACTION: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "TouristAttraction");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// TouristDestination
+    ///
+    /// A tourist destination. In principle any Place can be a TouristDestination from a City, Region or Country to an AmusementPark or Hotel.
+    ///
+    /// `schema:TouristDestination` (`TOURIST_DESTINATION`)
+    pub static ref TOURIST_DESTINATION: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "TouristDestination");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// TouristInformationCenter
+    ///
+    /// A tourist information center.
+    ///
+    /// `schema:TouristInformationCenter` (`TOURIST_INFORMATION_CENTER`)
+    pub static ref TOURIST_INFORMATION_CENTER: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "TouristInformationCenter");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// TouristTrip
+    ///
+    /// A tourist trip. A created itinerary of visits to one or more places of interest (TouristAttraction/TouristDestination) often linked by a similar theme, geographic area, or interest to a particular touristType.
+    ///
+    /// `schema:TouristTrip` (`TOURIST_TRIP`)
+    pub static ref TOURIST_TRIP: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "TouristTrip");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// ToyStore
+    ///
+    /// A toy store.
+    ///
+    /// `schema:ToyStore` (`TOY_STORE`)
+    pub static ref TOY_STORE: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "ToyStore");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// TrackAction
+    ///
+    /// An agent tracks an object for updates.
+    ///
+    /// `schema:TrackAction` (`TRACK_ACTION`)
+    pub static ref TRACK_ACTION: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "TrackAction");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// TradeAction
+    ///
+    /// The act of participating in an exchange of goods and services for monetary compensation. An agent trades an object, product or service with a participant in exchange for a one time or periodic payment.
+    ///
+    /// `schema:TradeAction` (`TRADE_ACTION`)
+    pub static ref TRADE_ACTION: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "TradeAction");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// TrainReservation
+    ///
+    /// A reservation for train travel.
+    ///
+    /// `schema:TrainReservation` (`TRAIN_RESERVATION`)
+    pub static ref TRAIN_RESERVATION: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "TrainReservation");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// TrainStation
+    ///
+    /// A train station.
+    ///
+    /// `schema:TrainStation` (`TRAIN_STATION`)
+    pub static ref TRAIN_STATION: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "TrainStation");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// TrainTrip
+    ///
+    /// A trip on a commercial train line.
+    ///
+    /// `schema:TrainTrip` (`TRAIN_TRIP`)
+    pub static ref TRAIN_TRIP: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "TrainTrip");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// TransferAction
+    ///
+    /// The act of transferring/moving (abstract or concrete) animate or inanimate objects from one place to another.
+    ///
+    /// `schema:TransferAction` (`TRANSFER_ACTION`)
+    pub static ref TRANSFER_ACTION: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "TransferAction");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// TravelAction
+    ///
+    /// The act of traveling from a fromLocation to a destination by a specified mode of transport, optionally with participants.
+    ///
+    /// `schema:TravelAction` (`TRAVEL_ACTION`)
+    pub static ref TRAVEL_ACTION: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "TravelAction");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// TravelAgency
+    ///
+    /// A travel agency.
+    ///
+    /// `schema:TravelAgency` (`TRAVEL_AGENCY`)
+    pub static ref TRAVEL_AGENCY: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "TravelAgency");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// TreatmentIndication
+    ///
+    /// An indication for treating an underlying condition, symptom, etc.
+    ///
+    /// `schema:TreatmentIndication` (`TREATMENT_INDICATION`)
+    pub static ref TREATMENT_INDICATION: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "TreatmentIndication");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// Trip
+    ///
+    /// A trip or journey. An itinerary of visits to one or more places.
+    ///
+    /// `schema:Trip` (`TRIP`)
+    pub static ref TRIP: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "Trip");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// TypeAndQuantityNode
+    ///
+    /// A structured value indicating the quantity, unit of measurement, and business function of goods included in a bundle offer.
+    ///
+    /// `schema:TypeAndQuantityNode` (`TYPE_AND_QUANTITY_NODE`)
+    pub static ref TYPE_AND_QUANTITY_NODE: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "TypeAndQuantityNode");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// UKNonprofitType
+    ///
+    /// UKNonprofitType: Non-profit organization type originating from the United Kingdom.
+    ///
+    /// `schema:UKNonprofitType` (`UK_NONPROFIT_TYPE`)
+    pub static ref UK_NONPROFIT_TYPE: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "UKNonprofitType");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// URL
+    ///
+    /// Data type: URL.
+    ///
+    /// `schema:URL` (`URL_CLASS`)
+    pub static ref URL_CLASS: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "URL");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// USNonprofitType
+    ///
+    /// USNonprofitType: Non-profit organization type originating from the United States.
+    ///
+    /// `schema:USNonprofitType` (`US_NONPROFIT_TYPE`)
+    pub static ref US_NONPROFIT_TYPE: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "USNonprofitType");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// UnRegisterAction
+    ///
+    /// The act of un-registering from a service.
+    ///
+    /// `schema:UnRegisterAction` (`UN_REGISTER_ACTION`)
+    pub static ref UN_REGISTER_ACTION: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "UnRegisterAction");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// UnitPriceSpecification
+    ///
+    /// The price asked for a given offer by the respective organization or person.
+    ///
+    /// `schema:UnitPriceSpecification` (`UNIT_PRICE_SPECIFICATION`)
+    pub static ref UNIT_PRICE_SPECIFICATION: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "UnitPriceSpecification");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// UpdateAction
+    ///
+    /// The act of managing by changing/editing the state of the object.
+    ///
+    /// `schema:UpdateAction` (`UPDATE_ACTION`)
+    pub static ref UPDATE_ACTION: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "UpdateAction");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// UseAction
+    ///
+    /// The act of applying an object to its intended purpose.
+    ///
+    /// `schema:UseAction` (`USE_ACTION`)
+    pub static ref USE_ACTION: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "UseAction");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// UserBlocks
+    ///
+    /// UserInteraction and its subtypes is an old way of talking about users interacting with pages. It is generally better to use Action-based vocabulary, alongside types such as Comment.
+    ///
+    /// `schema:UserBlocks` (`USER_BLOCKS`)
+    pub static ref USER_BLOCKS: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "UserBlocks");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// UserCheckins
+    ///
+    /// UserInteraction and its subtypes is an old way of talking about users interacting with pages. It is generally better to use Action-based vocabulary, alongside types such as Comment.
+    ///
+    /// `schema:UserCheckins` (`USER_CHECKINS`)
+    pub static ref USER_CHECKINS: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "UserCheckins");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// UserComments
+    ///
+    /// UserInteraction and its subtypes is an old way of talking about users interacting with pages. It is generally better to use Action-based vocabulary, alongside types such as Comment.
+    ///
+    /// `schema:UserComments` (`USER_COMMENTS`)
+    pub static ref USER_COMMENTS: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "UserComments");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// UserDownloads
+    ///
+    /// UserInteraction and its subtypes is an old way of talking about users interacting with pages. It is generally better to use Action-based vocabulary, alongside types such as Comment.
+    ///
+    /// `schema:UserDownloads` (`USER_DOWNLOADS`)
+    pub static ref USER_DOWNLOADS: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "UserDownloads");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// UserInteraction
+    ///
+    /// UserInteraction and its subtypes is an old way of talking about users interacting with pages. It is generally better to use Action-based vocabulary, alongside types such as Comment.
+    ///
+    /// `schema:UserInteraction` (`USER_INTERACTION`)
+    pub static ref USER_INTERACTION: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "UserInteraction");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// UserLikes
+    ///
+    /// UserInteraction and its subtypes is an old way of talking about users interacting with pages. It is generally better to use Action-based vocabulary, alongside types such as Comment.
+    ///
+    /// `schema:UserLikes` (`USER_LIKES`)
+    pub static ref USER_LIKES: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "UserLikes");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// UserPageVisits
+    ///
+    /// UserInteraction and its subtypes is an old way of talking about users interacting with pages. It is generally better to use Action-based vocabulary, alongside types such as Comment.
+    ///
+    /// `schema:UserPageVisits` (`USER_PAGE_VISITS`)
+    pub static ref USER_PAGE_VISITS: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "UserPageVisits");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// UserPlays
+    ///
+    /// UserInteraction and its subtypes is an old way of talking about users interacting with pages. It is generally better to use Action-based vocabulary, alongside types such as Comment.
+    ///
+    /// `schema:UserPlays` (`USER_PLAYS`)
+    pub static ref USER_PLAYS: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "UserPlays");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// UserPlusOnes
+    ///
+    /// UserInteraction and its subtypes is an old way of talking about users interacting with pages. It is generally better to use Action-based vocabulary, alongside types such as Comment.
+    ///
+    /// `schema:UserPlusOnes` (`USER_PLUS_ONES`)
+    pub static ref USER_PLUS_ONES: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "UserPlusOnes");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// UserReview
+    ///
+    /// A review created by an end-user (e.g. consumer, purchaser, attendee etc.), in contrast with CriticReview.
+    ///
+    /// `schema:UserReview` (`USER_REVIEW`)
+    pub static ref USER_REVIEW: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "UserReview");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// UserTweets
+    ///
+    /// UserInteraction and its subtypes is an old way of talking about users interacting with pages. It is generally better to use Action-based vocabulary, alongside types such as Comment.
+    ///
+    /// `schema:UserTweets` (`USER_TWEETS`)
+    pub static ref USER_TWEETS: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "UserTweets");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// VacationRental
+    ///
+    /// A kind of lodging business that focuses on renting single properties for limited time.
+    ///
+    /// `schema:VacationRental` (`VACATION_RENTAL`)
+    pub static ref VACATION_RENTAL: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "VacationRental");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// Vehicle
+    ///
+    /// A vehicle is a device that is designed or used to transport people or cargo over land, water, air, or through space.
+    ///
+    /// `schema:Vehicle` (`VEHICLE`)
+    pub static ref VEHICLE: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "Vehicle");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// Vein
+    ///
+    /// A type of blood vessel that specifically carries blood to the heart.
+    ///
+    /// `schema:Vein` (`VEIN`)
+    pub static ref VEIN: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "Vein");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// Vessel
+    ///
+    /// A component of the human body circulatory system comprised of an intricate network of hollow tubes that transport blood throughout the entire body.
+    ///
+    /// `schema:Vessel` (`VESSEL`)
+    pub static ref VESSEL: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "Vessel");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// VeterinaryCare
+    ///
+    /// A vet's office.
+    ///
+    /// `schema:VeterinaryCare` (`VETERINARY_CARE`)
+    pub static ref VETERINARY_CARE: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "VeterinaryCare");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// VideoGallery
+    ///
+    /// Web page type: Video gallery page.
+    ///
+    /// `schema:VideoGallery` (`VIDEO_GALLERY`)
+    pub static ref VIDEO_GALLERY: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "VideoGallery");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// VideoGame
+    ///
+    /// A video game is an electronic game that involves human interaction with a user interface to generate visual feedback on a video device.
+    ///
+    /// `schema:VideoGame` (`VIDEO_GAME`)
+    pub static ref VIDEO_GAME: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "VideoGame");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// VideoGameClip
+    ///
+    /// A short segment/part of a video game.
+    ///
+    /// `schema:VideoGameClip` (`VIDEO_GAME_CLIP`)
+    pub static ref VIDEO_GAME_CLIP: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "VideoGameClip");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// VideoGameSeries
+    ///
+    /// A video game series.
+    ///
+    /// `schema:VideoGameSeries` (`VIDEO_GAME_SERIES`)
+    pub static ref VIDEO_GAME_SERIES: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "VideoGameSeries");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// VideoObject
+    ///
+    /// A video file.
+    ///
+    /// `schema:VideoObject` (`VIDEO_OBJECT`)
+    pub static ref VIDEO_OBJECT: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "VideoObject");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// VideoObjectSnapshot
+    ///
+    /// A specific and exact (byte-for-byte) version of a VideoObject. Two byte-for-byte identical files, for the purposes of this type, considered identical.
+    ///
+    /// `schema:VideoObjectSnapshot` (`VIDEO_OBJECT_SNAPSHOT`)
+    pub static ref VIDEO_OBJECT_SNAPSHOT: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "VideoObjectSnapshot");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// ViewAction
+    ///
+    /// The act of consuming static visual content.
+    ///
+    /// `schema:ViewAction` (`VIEW_ACTION`)
+    pub static ref VIEW_ACTION: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "ViewAction");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// VirtualLocation
+    ///
+    /// An online or virtual location for attending events. For example, one may attend an online seminar or educational event. While a virtual location may be used as the location of an event, virtual locations should not be confused with physical locations in the real world.
+    ///
+    /// `schema:VirtualLocation` (`VIRTUAL_LOCATION`)
+    pub static ref VIRTUAL_LOCATION: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "VirtualLocation");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// VisualArtsEvent
+    ///
+    /// Event type: Visual arts event.
+    ///
+    /// `schema:VisualArtsEvent` (`VISUAL_ARTS_EVENT`)
+    pub static ref VISUAL_ARTS_EVENT: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "VisualArtsEvent");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// VisualArtwork
+    ///
+    /// A work of art that is primarily visual in character.
+    ///
+    /// `schema:VisualArtwork` (`VISUAL_ARTWORK`)
+    pub static ref VISUAL_ARTWORK: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "VisualArtwork");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// VitalSign
+    ///
+    /// Vital signs are measures of various physiological functions in order to assess the most basic body functions.
+    ///
+    /// `schema:VitalSign` (`VITAL_SIGN`)
+    pub static ref VITAL_SIGN: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "VitalSign");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// Volcano
+    ///
+    /// A volcano, like Fuji san.
+    ///
+    /// `schema:Volcano` (`VOLCANO`)
+    pub static ref VOLCANO: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "Volcano");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// VoteAction
+    ///
+    /// The act of expressing a preference from a fixed/finite/structured set of choices/options.
+    ///
+    /// `schema:VoteAction` (`VOTE_ACTION`)
+    pub static ref VOTE_ACTION: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "VoteAction");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// WPAdBlock
+    ///
+    /// An advertising section of the page.
+    ///
+    /// `schema:WPAdBlock` (`WP_AD_BLOCK`)
+    pub static ref WP_AD_BLOCK: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "WPAdBlock");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// WPFooter
+    ///
+    /// The footer section of the page.
+    ///
+    /// `schema:WPFooter` (`WP_FOOTER`)
+    pub static ref WP_FOOTER: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "WPFooter");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// WPHeader
+    ///
+    /// The header section of the page.
+    ///
+    /// `schema:WPHeader` (`WP_HEADER`)
+    pub static ref WP_HEADER: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "WPHeader");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// WPSideBar
+    ///
+    /// A sidebar section of the page.
+    ///
+    /// `schema:WPSideBar` (`WP_SIDE_BAR`)
+    pub static ref WP_SIDE_BAR: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "WPSideBar");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// WantAction
+    ///
+    /// The act of expressing a desire about the object. An agent wants an object.
+    ///
+    /// `schema:WantAction` (`WANT_ACTION`)
+    pub static ref WANT_ACTION: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "WantAction");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// WarrantyPromise
+    ///
+    /// A structured value representing the duration and scope of services that will be provided to a customer free of charge in case of a defect or malfunction of a product.
+    ///
+    /// `schema:WarrantyPromise` (`WARRANTY_PROMISE_CLASS`)
+    pub static ref WARRANTY_PROMISE_CLASS: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "WarrantyPromise");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// WarrantyScope
+    ///
+    /// A range of services that will be provided to a customer free of charge in case of a defect or malfunction of a product.
+    ///
+    /// `schema:WarrantyScope` (`WARRANTY_SCOPE_CLASS`)
+    pub static ref WARRANTY_SCOPE_CLASS: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "WarrantyScope");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// WatchAction
+    ///
+    /// The act of consuming dynamic/moving visual content.
+    ///
+    /// `schema:WatchAction` (`WATCH_ACTION`)
+    pub static ref WATCH_ACTION: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "WatchAction");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// Waterfall
+    ///
+    /// A waterfall, like Niagara.
+    ///
+    /// `schema:Waterfall` (`WATERFALL`)
+    pub static ref WATERFALL: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "Waterfall");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// WearAction
+    ///
+    /// The act of dressing oneself in clothing.
+    ///
+    /// `schema:WearAction` (`WEAR_ACTION`)
+    pub static ref WEAR_ACTION: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "WearAction");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// WearableMeasurementTypeEnumeration
+    ///
+    /// Enumerates common types of measurement for wearables products.
+    ///
+    /// `schema:WearableMeasurementTypeEnumeration` (`WEARABLE_MEASUREMENT_TYPE_ENUMERATION`)
+    pub static ref WEARABLE_MEASUREMENT_TYPE_ENUMERATION: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "WearableMeasurementTypeEnumeration");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// WearableSizeGroupEnumeration
+    ///
+    /// Enumerates common size groups (also known as "size types") for wearable products.
+    ///
+    /// `schema:WearableSizeGroupEnumeration` (`WEARABLE_SIZE_GROUP_ENUMERATION`)
+    pub static ref WEARABLE_SIZE_GROUP_ENUMERATION: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "WearableSizeGroupEnumeration");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// WearableSizeSystemEnumeration
+    ///
+    /// Enumerates common size systems specific for wearable products.
+    ///
+    /// `schema:WearableSizeSystemEnumeration` (`WEARABLE_SIZE_SYSTEM_ENUMERATION`)
+    pub static ref WEARABLE_SIZE_SYSTEM_ENUMERATION: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "WearableSizeSystemEnumeration");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// WebAPI
+    ///
+    /// An application programming interface accessible over Web/Internet technologies.
+    ///
+    /// `schema:WebAPI` (`WEB_API`)
+    pub static ref WEB_API: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "WebAPI");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// WebApplication
+    ///
+    /// Web applications.
+    ///
+    /// `schema:WebApplication` (`WEB_APPLICATION`)
+    pub static ref WEB_APPLICATION: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "WebApplication");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// WebContent
+    ///
+    /// WebContent is a type representing all WebPage, WebSite and WebPageElement content. It is sometimes the case that detailed distinctions between Web pages, sites and their parts are not always important or obvious.
+    ///
+    /// `schema:WebContent` (`WEB_CONTENT`)
+    pub static ref WEB_CONTENT: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "WebContent");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// WebPage
+    ///
+    /// A web page. Every web page is implicitly assumed to be declared to be of type WebPage, so the various properties about that webpage, such as breadcrumb may be used.
+    ///
+    /// `schema:WebPage` (`WEB_PAGE`)
+    pub static ref WEB_PAGE: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "WebPage");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// WebPageElement
+    ///
+    /// A web page element, like a table or an image.
+    ///
+    /// `schema:WebPageElement` (`WEB_PAGE_ELEMENT`)
+    pub static ref WEB_PAGE_ELEMENT: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "WebPageElement");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// WebSite
+    ///
+    /// A WebSite is a set of related web pages and other items typically served from a single web domain and accessible via URLs.
+    ///
+    /// `schema:WebSite` (`WEB_SITE`)
+    pub static ref WEB_SITE: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "WebSite");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// WholesaleStore
+    ///
+    /// A wholesale store.
+    ///
+    /// `schema:WholesaleStore` (`WHOLESALE_STORE`)
+    pub static ref WHOLESALE_STORE: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "WholesaleStore");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// WinAction
+    ///
+    /// The act of achieving victory in a competitive activity.
+    ///
+    /// `schema:WinAction` (`WIN_ACTION`)
+    pub static ref WIN_ACTION: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "WinAction");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// Winery
+    ///
+    /// A winery.
+    ///
+    /// `schema:Winery` (`WINERY`)
+    pub static ref WINERY: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "Winery");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// WorkBasedProgram
+    ///
+    /// A program with both an educational and employment component. Typically based at a workplace and structured around work-based learning, with the aim of instilling competencies related to an occupation.
+    ///
+    /// `schema:WorkBasedProgram` (`WORK_BASED_PROGRAM`)
+    pub static ref WORK_BASED_PROGRAM: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "WorkBasedProgram");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// WorkersUnion
+    ///
+    /// A Workers Union (also known as a Labor Union, Labour Union, or Trade Union) is an organization that promotes the interests of its worker members by collectively bargaining with management, organizing, and political lobbying.
+    ///
+    /// `schema:WorkersUnion` (`WORKERS_UNION`)
+    pub static ref WORKERS_UNION: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "WorkersUnion");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// WriteAction
+    ///
+    /// The act of authoring written creative content.
+    ///
+    /// `schema:WriteAction` (`WRITE_ACTION`)
+    pub static ref WRITE_ACTION: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "WriteAction");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// XPathType
+    ///
+    /// Text representing an XPath (typically but not necessarily version 1.0).
+    ///
+    /// `schema:XPathType` (`X_PATH_TYPE`)
+    pub static ref X_PATH_TYPE: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "XPathType");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// Zoo
+    ///
+    /// A zoo.
+    ///
+    /// `schema:Zoo` (`ZOO`)
+    pub static ref ZOO: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "Zoo");
+}
@@ -0,0 +1,1431 @@
+// Copyright (c) 2018-2023, agnos.ai UK Ltd, all rights reserved.
+//---------------------------------------------------------------
+//! The SHACL vocabulary, `sh:` <http://www.w3.org/ns/shacl#>.
+
+// Generated by rdf_store_rs::Vocabulary, do not edit.
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// The `sh:` namespace `<http://www.w3.org/ns/shacl#>`.
+    pub static ref NAMESPACE: ::std::sync::Arc<::rdf_store_rs::Namespace> =
+        ::std::sync::Arc::new(::rdf_store_rs::Namespace::declare_from_str_unchecked("sh:", "http://www.w3.org/ns/shacl#"));
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// alternative path
+    ///
+    /// The (single) value of this property must be a list of path elements, representing the elements of alternative paths.
+    ///
+    /// `sh:alternativePath` (`ALTERNATIVE_PATH`)
+    pub static ref ALTERNATIVE_PATH: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "alternativePath");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// and
+    ///
+    /// RDF list of shapes to validate the value nodes against.
+    ///
+    /// `sh:and` (`AND`)
+    pub static ref AND: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "and");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// annotation property
+    ///
+    /// The annotation property that shall be set.
+    ///
+    /// `sh:annotationProperty` (`ANNOTATION_PROPERTY`)
+    pub static ref ANNOTATION_PROPERTY: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "annotationProperty");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// annotation value
+    ///
+    /// The (default) values of the annotation property.
+    ///
+    /// `sh:annotationValue` (`ANNOTATION_VALUE`)
+    pub static ref ANNOTATION_VALUE: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "annotationValue");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// annotation variable name
+    ///
+    /// The name of the SPARQL variable from the SELECT clause that shall be used for the values.
+    ///
+    /// `sh:annotationVarName` (`ANNOTATION_VAR_NAME`)
+    pub static ref ANNOTATION_VAR_NAME: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "annotationVarName");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// ask
+    ///
+    /// The SPARQL ASK query to execute.
+    ///
+    /// `sh:ask` (`ASK`)
+    pub static ref ASK: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "ask");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// class
+    ///
+    /// The type that all value nodes must have.
+    ///
+    /// `sh:class` (`CLASS`)
+    pub static ref CLASS: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "class");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// closed
+    ///
+    /// If set to true then the shape is closed.
+    ///
+    /// `sh:closed` (`CLOSED`)
+    pub static ref CLOSED: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "closed");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// condition
+    ///
+    /// The shapes that the focus nodes need to conform to before a rule is executed on them.
+    ///
+    /// `sh:condition` (`CONDITION`)
+    pub static ref CONDITION: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "condition");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// conforms
+    ///
+    /// True if the validation did not produce any validation results, and false otherwise.
+    ///
+    /// `sh:conforms` (`CONFORMS`)
+    pub static ref CONFORMS: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "conforms");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// construct
+    ///
+    /// The SPARQL CONSTRUCT query to execute.
+    ///
+    /// `sh:construct` (`CONSTRUCT`)
+    pub static ref CONSTRUCT: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "construct");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// datatype
+    ///
+    /// Specifies an RDF datatype that all value nodes must have.
+    ///
+    /// `sh:datatype` (`DATATYPE`)
+    pub static ref DATATYPE: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "datatype");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// deactivated
+    ///
+    /// If set to true then all nodes conform to this.
+    ///
+    /// `sh:deactivated` (`DEACTIVATED`)
+    pub static ref DEACTIVATED: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "deactivated");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// declare
+    ///
+    /// Links a resource with its namespace prefix declarations.
+    ///
+    /// `sh:declare` (`DECLARE`)
+    pub static ref DECLARE: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "declare");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// default value
+    ///
+    /// A default value for a property, for example for user interface tools to pre-populate input fields.
+    ///
+    /// `sh:defaultValue` (`DEFAULT_VALUE`)
+    pub static ref DEFAULT_VALUE: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "defaultValue");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// description
+    ///
+    /// Human-readable descriptions for the property in the context of the surrounding shape.
+    ///
+    /// `sh:description` (`DESCRIPTION`)
+    pub static ref DESCRIPTION: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "description");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// detail
+    ///
+    /// Links a result with other results that provide more details, for example to describe violations against nested shapes.
+    ///
+    /// `sh:detail` (`DETAIL`)
+    pub static ref DETAIL: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "detail");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// disjoint
+    ///
+    /// Specifies a property where the set of values must be disjoint with the value nodes.
+    ///
+    /// `sh:disjoint` (`DISJOINT`)
+    pub static ref DISJOINT: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "disjoint");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// entailment
+    ///
+    /// An entailment regime that indicates what kind of inferencing is required by a shapes graph.
+    ///
+    /// `sh:entailment` (`ENTAILMENT`)
+    pub static ref ENTAILMENT: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "entailment");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// equals
+    ///
+    /// Specifies a property that must have the same values as the value nodes.
+    ///
+    /// `sh:equals` (`EQUALS`)
+    pub static ref EQUALS: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "equals");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// expression
+    ///
+    /// The node expression that must return true for the value nodes.
+    ///
+    /// `sh:expression` (`EXPRESSION`)
+    pub static ref EXPRESSION: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "expression");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// filter shape
+    ///
+    /// The shape that all input nodes of the expression need to conform to.
+    ///
+    /// `sh:filterShape` (`FILTER_SHAPE`)
+    pub static ref FILTER_SHAPE: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "filterShape");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// flags
+    ///
+    /// An optional flag to be used with regular expression pattern matching.
+    ///
+    /// `sh:flags` (`FLAGS`)
+    pub static ref FLAGS: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "flags");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// focus node
+    ///
+    /// The focus node that was validated when the result was produced.
+    ///
+    /// `sh:focusNode` (`FOCUS_NODE`)
+    pub static ref FOCUS_NODE: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "focusNode");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// group
+    ///
+    /// Can be used to link to a property group to indicate that a property shape belongs to a group of related property shapes.
+    ///
+    /// `sh:group` (`GROUP`)
+    pub static ref GROUP: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "group");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// has value
+    ///
+    /// Specifies a value that must be among the value nodes.
+    ///
+    /// `sh:hasValue` (`HAS_VALUE`)
+    pub static ref HAS_VALUE: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "hasValue");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// ignored properties
+    ///
+    /// An optional RDF list of properties that are also permitted in addition to those explicitly enumerated via sh:property/sh:path.
+    ///
+    /// `sh:ignoredProperties` (`IGNORED_PROPERTIES`)
+    pub static ref IGNORED_PROPERTIES: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "ignoredProperties");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// in
+    ///
+    /// Specifies a list of allowed values so that each value node must be among the members of the given list.
+    ///
+    /// `sh:in` (`IN`)
+    pub static ref IN: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "in");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// intersection
+    ///
+    /// A list of node expressions that shall be intersected.
+    ///
+    /// `sh:intersection` (`INTERSECTION`)
+    pub static ref INTERSECTION: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "intersection");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// inverse path
+    ///
+    /// The (single) value of this property represents an inverse path (object to subject).
+    ///
+    /// `sh:inversePath` (`INVERSE_PATH`)
+    pub static ref INVERSE_PATH: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "inversePath");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// JavaScript constraint
+    ///
+    /// Constraints expressed in JavaScript.
+    ///
+    /// `sh:js` (`JS`)
+    pub static ref JS: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "js");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// JavaScript function name
+    ///
+    /// The name of the JavaScript function to execute.
+    ///
+    /// `sh:jsFunctionName` (`JS_FUNCTION_NAME`)
+    pub static ref JS_FUNCTION_NAME: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "jsFunctionName");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// JavaScript library
+    ///
+    /// Declares which JavaScript libraries are needed to execute this.
+    ///
+    /// `sh:jsLibrary` (`JS_LIBRARY_PROPERTY`)
+    pub static ref JS_LIBRARY_PROPERTY: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "jsLibrary");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// JavaScript library URL
+    ///
+    /// Declares the URLs of a JavaScript library. This should be the absolute URL of a JavaScript file. Implementations may redirect those to local files.
+    ///
+    /// `sh:jsLibraryURL` (`JS_LIBRARY_URL`)
+    pub static ref JS_LIBRARY_URL: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "jsLibraryURL");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// label template
+    ///
+    /// Outlines how human-readable labels of instances of the associated Parameterizable shall be produced. The values can contain {?paramName} as placeholders for the actual values of the given parameter.
+    ///
+    /// `sh:labelTemplate` (`LABEL_TEMPLATE`)
+    pub static ref LABEL_TEMPLATE: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "labelTemplate");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// language in
+    ///
+    /// Specifies a list of language tags that all value nodes must have.
+    ///
+    /// `sh:languageIn` (`LANGUAGE_IN`)
+    pub static ref LANGUAGE_IN: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "languageIn");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// less than
+    ///
+    /// Specifies a property that must have smaller values than the value nodes.
+    ///
+    /// `sh:lessThan` (`LESS_THAN`)
+    pub static ref LESS_THAN: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "lessThan");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// less than or equals
+    ///
+    /// Specifies a property that must have smaller or equal values than the value nodes.
+    ///
+    /// `sh:lessThanOrEquals` (`LESS_THAN_OR_EQUALS`)
+    pub static ref LESS_THAN_OR_EQUALS: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "lessThanOrEquals");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// max count
+    ///
+    /// Specifies the maximum number of values in the set of value nodes.
+    ///
+    /// `sh:maxCount` (`MAX_COUNT`)
+    pub static ref MAX_COUNT: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "maxCount");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// max exclusive
+    ///
+    /// Specifies the maximum exclusive value of each value node.
+    ///
+    /// `sh:maxExclusive` (`MAX_EXCLUSIVE`)
+    pub static ref MAX_EXCLUSIVE: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "maxExclusive");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// max inclusive
+    ///
+    /// Specifies the maximum inclusive value of each value node.
+    ///
+    /// `sh:maxInclusive` (`MAX_INCLUSIVE`)
+    pub static ref MAX_INCLUSIVE: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "maxInclusive");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// max length
+    ///
+    /// Specifies the maximum string length of each value node.
+    ///
+    /// `sh:maxLength` (`MAX_LENGTH`)
+    pub static ref MAX_LENGTH: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "maxLength");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// message
+    ///
+    /// A human-readable message (possibly with placeholders for variables) explaining the cause of the result.
+    ///
+    /// `sh:message` (`MESSAGE`)
+    pub static ref MESSAGE: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "message");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// min count
+    ///
+    /// Specifies the minimum number of values in the set of value nodes.
+    ///
+    /// `sh:minCount` (`MIN_COUNT`)
+    pub static ref MIN_COUNT: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "minCount");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// min exclusive
+    ///
+    /// Specifies the minimum exclusive value of each value node.
+    ///
+    /// `sh:minExclusive` (`MIN_EXCLUSIVE`)
+    pub static ref MIN_EXCLUSIVE: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "minExclusive");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// min inclusive
+    ///
+    /// Specifies the minimum inclusive value of each value node.
+    ///
+    /// `sh:minInclusive` (`MIN_INCLUSIVE`)
+    pub static ref MIN_INCLUSIVE: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "minInclusive");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// min length
+    ///
+    /// Specifies the minimum string length of each value node.
+    ///
+    /// `sh:minLength` (`MIN_LENGTH`)
+    pub static ref MIN_LENGTH: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "minLength");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// name
+    ///
+    /// Human-readable labels for the property in the context of the surrounding shape.
+    ///
+    /// `sh:name` (`NAME`)
+    pub static ref NAME: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "name");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// namespace
+    ///
+    /// The namespace associated with a prefix in a prefix declaration.
+    ///
+    /// `sh:namespace` (`NAMESPACE_PROPERTY`)
+    pub static ref NAMESPACE_PROPERTY: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "namespace");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// shape
+    ///
+    /// Specifies the node shape that all value nodes must conform to.
+    ///
+    /// `sh:node` (`NODE`)
+    pub static ref NODE: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "node");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// node kind
+    ///
+    /// Specifies the node kind (e.g. IRI or literal) each value node.
+    ///
+    /// `sh:nodeKind` (`NODE_KIND_PROPERTY`)
+    pub static ref NODE_KIND_PROPERTY: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "nodeKind");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// shape validator
+    ///
+    /// The validator(s) used to evaluate a constraint in the context of a node shape.
+    ///
+    /// `sh:nodeValidator` (`NODE_VALIDATOR`)
+    pub static ref NODE_VALIDATOR: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "nodeValidator");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// nodes
+    ///
+    /// The node expression producing the input nodes of a filter shape expression.
+    ///
+    /// `sh:nodes` (`NODES`)
+    pub static ref NODES: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "nodes");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// not
+    ///
+    /// Specifies a shape that all value nodes must not conform to.
+    ///
+    /// `sh:not` (`NOT`)
+    pub static ref NOT: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "not");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// object
+    ///
+    /// An expression producing the nodes that shall be inferred as objects.
+    ///
+    /// `sh:object` (`OBJECT`)
+    pub static ref OBJECT: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "object");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// one or more path
+    ///
+    /// The (single) value of this property represents a path that is matched one or more times.
+    ///
+    /// `sh:oneOrMorePath` (`ONE_OR_MORE_PATH`)
+    pub static ref ONE_OR_MORE_PATH: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "oneOrMorePath");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// optional
+    ///
+    /// Indicates whether a parameter is optional.
+    ///
+    /// `sh:optional` (`OPTIONAL`)
+    pub static ref OPTIONAL: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "optional");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// or
+    ///
+    /// Specifies a list of shapes so that the value nodes must conform to at least one of the shapes.
+    ///
+    /// `sh:or` (`OR`)
+    pub static ref OR: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "or");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// order
+    ///
+    /// Specifies the relative order of this compared to its siblings. For example use 0 for the first, 1 for the second.
+    ///
+    /// `sh:order` (`ORDER`)
+    pub static ref ORDER: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "order");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// parameter
+    ///
+    /// The parameters of a function or constraint component.
+    ///
+    /// `sh:parameter` (`PARAMETER_PROPERTY`)
+    pub static ref PARAMETER_PROPERTY: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "parameter");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// path
+    ///
+    /// Specifies the property path of a property shape.
+    ///
+    /// `sh:path` (`PATH`)
+    pub static ref PATH: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "path");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// pattern
+    ///
+    /// Specifies a regular expression pattern that the string representations of the value nodes must match.
+    ///
+    /// `sh:pattern` (`PATTERN`)
+    pub static ref PATTERN: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "pattern");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// predicate
+    ///
+    /// An expression producing the properties that shall be inferred as predicates.
+    ///
+    /// `sh:predicate` (`PREDICATE`)
+    pub static ref PREDICATE: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "predicate");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// prefix
+    ///
+    /// The prefix of a prefix declaration.
+    ///
+    /// `sh:prefix` (`PREFIX`)
+    pub static ref PREFIX: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "prefix");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// prefixes
+    ///
+    /// The prefixes that shall be applied before parsing the associated SPARQL query.
+    ///
+    /// `sh:prefixes` (`PREFIXES`)
+    pub static ref PREFIXES: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "prefixes");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// property
+    ///
+    /// Links a shape to its property shapes.
+    ///
+    /// `sh:property` (`PROPERTY`)
+    pub static ref PROPERTY: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "property");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// property validator
+    ///
+    /// The validator(s) used to evaluate a constraint in the context of a property shape.
+    ///
+    /// `sh:propertyValidator` (`PROPERTY_VALIDATOR`)
+    pub static ref PROPERTY_VALIDATOR: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "propertyValidator");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// qualified max count
+    ///
+    /// The maximum number of value nodes that can conform to the shape.
+    ///
+    /// `sh:qualifiedMaxCount` (`QUALIFIED_MAX_COUNT`)
+    pub static ref QUALIFIED_MAX_COUNT: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "qualifiedMaxCount");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// qualified min count
+    ///
+    /// The minimum number of value nodes that must conform to the shape.
+    ///
+    /// `sh:qualifiedMinCount` (`QUALIFIED_MIN_COUNT`)
+    pub static ref QUALIFIED_MIN_COUNT: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "qualifiedMinCount");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// qualified value shape
+    ///
+    /// The shape that a specified number of values must conform to.
+    ///
+    /// `sh:qualifiedValueShape` (`QUALIFIED_VALUE_SHAPE`)
+    pub static ref QUALIFIED_VALUE_SHAPE: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "qualifiedValueShape");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// qualified value shapes disjoint
+    ///
+    /// Can be used to mark the qualified value shape to be disjoint with its sibling shapes.
+    ///
+    /// `sh:qualifiedValueShapesDisjoint` (`QUALIFIED_VALUE_SHAPES_DISJOINT`)
+    pub static ref QUALIFIED_VALUE_SHAPES_DISJOINT: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "qualifiedValueShapesDisjoint");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// result
+    ///
+    /// The validation results contained in a validation report.
+    ///
+    /// `sh:result` (`RESULT`)
+    pub static ref RESULT: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "result");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// result annotation
+    ///
+    /// Links a SPARQL validator with zero or more sh:ResultAnnotation instances, defining how to derive additional result properties based on the variables of the SELECT query.
+    ///
+    /// `sh:resultAnnotation` (`RESULT_ANNOTATION_PROPERTY`)
+    pub static ref RESULT_ANNOTATION_PROPERTY: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "resultAnnotation");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// result message
+    ///
+    /// Human-readable messages explaining the cause of the result.
+    ///
+    /// `sh:resultMessage` (`RESULT_MESSAGE`)
+    pub static ref RESULT_MESSAGE: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "resultMessage");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// result path
+    ///
+    /// The path of a validation result, based on the path of the validated property shape.
+    ///
+    /// `sh:resultPath` (`RESULT_PATH`)
+    pub static ref RESULT_PATH: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "resultPath");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// result severity
+    ///
+    /// The severity of the result, e.g. warning.
+    ///
+    /// `sh:resultSeverity` (`RESULT_SEVERITY`)
+    pub static ref RESULT_SEVERITY: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "resultSeverity");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// return type
+    ///
+    /// The expected type of values returned by the associated function.
+    ///
+    /// `sh:returnType` (`RETURN_TYPE`)
+    pub static ref RETURN_TYPE: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "returnType");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// rule
+    ///
+    /// The rules linked to a shape.
+    ///
+    /// `sh:rule` (`RULE_PROPERTY`)
+    pub static ref RULE_PROPERTY: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "rule");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// select
+    ///
+    /// The SPARQL SELECT query to execute.
+    ///
+    /// `sh:select` (`SELECT`)
+    pub static ref SELECT: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "select");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// severity
+    ///
+    /// Defines the severity that validation results produced by a shape must have. Defaults to sh:Violation.
+    ///
+    /// `sh:severity` (`SEVERITY_PROPERTY`)
+    pub static ref SEVERITY_PROPERTY: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "severity");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// shapes graph
+    ///
+    /// Shapes graphs that should be used when validating this data graph.
+    ///
+    /// `sh:shapesGraph` (`SHAPES_GRAPH`)
+    pub static ref SHAPES_GRAPH: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "shapesGraph");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// shapes graph well-formed
+    ///
+    /// If true then the validation engine was certain that the shapes graph has passed all SHACL syntax requirements during the validation process.
+    ///
+    /// `sh:shapesGraphWellFormed` (`SHAPES_GRAPH_WELL_FORMED`)
+    pub static ref SHAPES_GRAPH_WELL_FORMED: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "shapesGraphWellFormed");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// source constraint
+    ///
+    /// The constraint that was validated when the result was produced.
+    ///
+    /// `sh:sourceConstraint` (`SOURCE_CONSTRAINT`)
+    pub static ref SOURCE_CONSTRAINT: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "sourceConstraint");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// source constraint component
+    ///
+    /// The constraint component that is the source of the result.
+    ///
+    /// `sh:sourceConstraintComponent` (`SOURCE_CONSTRAINT_COMPONENT`)
+    pub static ref SOURCE_CONSTRAINT_COMPONENT: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "sourceConstraintComponent");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// source shape
+    ///
+    /// The shape that is was validated when the result was produced.
+    ///
+    /// `sh:sourceShape` (`SOURCE_SHAPE`)
+    pub static ref SOURCE_SHAPE: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "sourceShape");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// constraint (in SPARQL)
+    ///
+    /// Links a shape with SPARQL constraints.
+    ///
+    /// `sh:sparql` (`SPARQL`)
+    pub static ref SPARQL: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "sparql");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// subject
+    ///
+    /// An expression producing the resources that shall be inferred as subjects.
+    ///
+    /// `sh:subject` (`SUBJECT`)
+    pub static ref SUBJECT: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "subject");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// suggested shapes graph
+    ///
+    /// Suggested shapes graphs for this ontology. The values of this property may be used in the absence of specific sh:shapesGraph statements.
+    ///
+    /// `sh:suggestedShapesGraph` (`SUGGESTED_SHAPES_GRAPH`)
+    pub static ref SUGGESTED_SHAPES_GRAPH: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "suggestedShapesGraph");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// target
+    ///
+    /// Links a shape to a target specified by an extension language, for example instances of sh:SPARQLTarget.
+    ///
+    /// `sh:target` (`TARGET_PROPERTY`)
+    pub static ref TARGET_PROPERTY: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "target");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// target class
+    ///
+    /// Links a shape to a class, indicating that all instances of the class must conform to the shape.
+    ///
+    /// `sh:targetClass` (`TARGET_CLASS`)
+    pub static ref TARGET_CLASS: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "targetClass");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// target node
+    ///
+    /// Links a shape to individual nodes, indicating that these nodes must conform to the shape.
+    ///
+    /// `sh:targetNode` (`TARGET_NODE`)
+    pub static ref TARGET_NODE: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "targetNode");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// target objects of
+    ///
+    /// Links a shape to a property, indicating that all all objects of triples that have the given property as their predicate must conform to the shape.
+    ///
+    /// `sh:targetObjectsOf` (`TARGET_OBJECTS_OF`)
+    pub static ref TARGET_OBJECTS_OF: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "targetObjectsOf");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// target subjects of
+    ///
+    /// Links a shape to a property, indicating that all subjects of triples that have the given property as their predicate must conform to the shape.
+    ///
+    /// `sh:targetSubjectsOf` (`TARGET_SUBJECTS_OF`)
+    pub static ref TARGET_SUBJECTS_OF: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "targetSubjectsOf");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// union
+    ///
+    /// A list of node expressions that shall be used together.
+    ///
+    /// `sh:union` (`UNION`)
+    pub static ref UNION: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "union");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// unique languages
+    ///
+    /// Specifies whether all node values must have a unique (or no) language tag.
+    ///
+    /// `sh:uniqueLang` (`UNIQUE_LANG`)
+    pub static ref UNIQUE_LANG: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "uniqueLang");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// update
+    ///
+    /// The SPARQL UPDATE to execute.
+    ///
+    /// `sh:update` (`UPDATE`)
+    pub static ref UPDATE: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "update");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// validator
+    ///
+    /// The validator(s) used to evaluate constraints of either node or property shapes.
+    ///
+    /// `sh:validator` (`VALIDATOR_PROPERTY`)
+    pub static ref VALIDATOR_PROPERTY: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "validator");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// value
+    ///
+    /// An RDF node that has caused the result.
+    ///
+    /// `sh:value` (`VALUE`)
+    pub static ref VALUE: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "value");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// exactly one
+    ///
+    /// Specifies a list of shapes so that the value nodes must conform to exactly one of the shapes.
+    ///
+    /// `sh:xone` (`XONE`)
+    pub static ref XONE: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "xone");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// zero or more path
+    ///
+    /// The (single) value of this property represents a path that is matched zero or more times.
+    ///
+    /// `sh:zeroOrMorePath` (`ZERO_OR_MORE_PATH`)
+    pub static ref ZERO_OR_MORE_PATH: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "zeroOrMorePath");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// zero or one path
+    ///
+    /// The (single) value of this property represents a path that is matched zero or one times.
+    ///
+    /// `sh:zeroOrOnePath` (`ZERO_OR_ONE_PATH`)
+    pub static ref ZERO_OR_ONE_PATH: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "zeroOrOnePath");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// Abstract result
+    ///
+    /// The base class of validation results, typically not instantiated directly.
+    ///
+    /// `sh:AbstractResult` (`ABSTRACT_RESULT`)
+    pub static ref ABSTRACT_RESULT: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "AbstractResult");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// Constraint component
+    ///
+    /// The class of constraint components.
+    ///
+    /// `sh:ConstraintComponent` (`CONSTRAINT_COMPONENT`)
+    pub static ref CONSTRAINT_COMPONENT: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "ConstraintComponent");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// Function
+    ///
+    /// The class of SHACL functions.
+    ///
+    /// `sh:Function` (`FUNCTION`)
+    pub static ref FUNCTION: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "Function");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// JavaScript-based constraint
+    ///
+    /// The class of constraints backed by a JavaScript function.
+    ///
+    /// `sh:JSConstraint` (`JS_CONSTRAINT`)
+    pub static ref JS_CONSTRAINT: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "JSConstraint");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// JavaScript executable
+    ///
+    /// Abstract base class of resources that declare an executable JavaScript.
+    ///
+    /// `sh:JSExecutable` (`JS_EXECUTABLE`)
+    pub static ref JS_EXECUTABLE: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "JSExecutable");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// JavaScript function
+    ///
+    /// The class of SHACL functions that execute a JavaScript function when called.
+    ///
+    /// `sh:JSFunction` (`JS_FUNCTION`)
+    pub static ref JS_FUNCTION: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "JSFunction");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// JavaScript library
+    ///
+    /// Represents a JavaScript library, typically identified by one or more URLs of files to include.
+    ///
+    /// `sh:JSLibrary` (`JS_LIBRARY_CLASS`)
+    pub static ref JS_LIBRARY_CLASS: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "JSLibrary");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// JavaScript rule
+    ///
+    /// The class of SHACL rules expressed using JavaScript.
+    ///
+    /// `sh:JSRule` (`JS_RULE`)
+    pub static ref JS_RULE: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "JSRule");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// JavaScript target
+    ///
+    /// The class of targets that are based on JavaScript functions.
+    ///
+    /// `sh:JSTarget` (`JS_TARGET`)
+    pub static ref JS_TARGET: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "JSTarget");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// JavaScript target type
+    ///
+    /// The (meta) class for parameterizable targets that are based on JavaScript functions.
+    ///
+    /// `sh:JSTargetType` (`JS_TARGET_TYPE`)
+    pub static ref JS_TARGET_TYPE: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "JSTargetType");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// JavaScript validator
+    ///
+    /// A SHACL validator based on JavaScript. This can be used to declare SHACL constraint components that perform JavaScript-based validation when used.
+    ///
+    /// `sh:JSValidator` (`JS_VALIDATOR`)
+    pub static ref JS_VALIDATOR: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "JSValidator");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// Node kind
+    ///
+    /// The class of all node kinds, including sh:BlankNode, sh:IRI, sh:Literal or the combinations of these: sh:BlankNodeOrIRI, sh:BlankNodeOrLiteral, sh:IRIOrLiteral.
+    ///
+    /// `sh:NodeKind` (`NODE_KIND_CLASS`)
+    pub static ref NODE_KIND_CLASS: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "NodeKind");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// Node shape
+    ///
+    /// A node shape is a shape that specifies constraint that need to be met with respect to focus nodes.
+    ///
+    /// `sh:NodeShape` (`NODE_SHAPE`)
+    pub static ref NODE_SHAPE: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "NodeShape");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// Parameter
+    ///
+    /// The class of parameter declarations, consisting of a path predicate and (possibly) information about allowed value type, cardinality and other characteristics.
+    ///
+    /// `sh:Parameter` (`PARAMETER_CLASS`)
+    pub static ref PARAMETER_CLASS: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "Parameter");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// Parameterizable
+    ///
+    /// Superclass of components that can take parameters, especially functions and constraint components.
+    ///
+    /// `sh:Parameterizable` (`PARAMETERIZABLE`)
+    pub static ref PARAMETERIZABLE: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "Parameterizable");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// Prefix declaration
+    ///
+    /// The class of prefix declarations, consisting of pairs of a prefix with a namespace.
+    ///
+    /// `sh:PrefixDeclaration` (`PREFIX_DECLARATION`)
+    pub static ref PREFIX_DECLARATION: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "PrefixDeclaration");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// Property group
+    ///
+    /// Instances of this class represent groups of property shapes that belong together.
+    ///
+    /// `sh:PropertyGroup` (`PROPERTY_GROUP`)
+    pub static ref PROPERTY_GROUP: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "PropertyGroup");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// Property shape
+    ///
+    /// A property shape is a shape that specifies constraints on the values of a focus node for a given property or path.
+    ///
+    /// `sh:PropertyShape` (`PROPERTY_SHAPE`)
+    pub static ref PROPERTY_SHAPE: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "PropertyShape");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// Result annotation
+    ///
+    /// A class of result annotations, which define the rules to derive the values of a given annotation property as extra values for a validation result.
+    ///
+    /// `sh:ResultAnnotation` (`RESULT_ANNOTATION_CLASS`)
+    pub static ref RESULT_ANNOTATION_CLASS: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "ResultAnnotation");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// Rule
+    ///
+    /// The class of SHACL rules. Never instantiated directly.
+    ///
+    /// `sh:Rule` (`RULE_CLASS`)
+    pub static ref RULE_CLASS: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "Rule");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// SPARQL ASK executable
+    ///
+    /// The class of SPARQL executables that are based on an ASK query.
+    ///
+    /// `sh:SPARQLAskExecutable` (`SPARQL_ASK_EXECUTABLE`)
+    pub static ref SPARQL_ASK_EXECUTABLE: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "SPARQLAskExecutable");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// SPARQL ASK validator
+    ///
+    /// The class of validators based on SPARQL ASK queries. The queries are evaluated for each value node and are supposed to return true if the given node conforms.
+    ///
+    /// `sh:SPARQLAskValidator` (`SPARQL_ASK_VALIDATOR`)
+    pub static ref SPARQL_ASK_VALIDATOR: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "SPARQLAskValidator");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// SPARQL-based constraint
+    ///
+    /// The class of constraints based on SPARQL SELECT queries.
+    ///
+    /// `sh:SPARQLConstraint` (`SPARQL_CONSTRAINT`)
+    pub static ref SPARQL_CONSTRAINT: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "SPARQLConstraint");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// SPARQL CONSTRUCT executable
+    ///
+    /// The class of SPARQL executables that are based on a CONSTRUCT query.
+    ///
+    /// `sh:SPARQLConstructExecutable` (`SPARQL_CONSTRUCT_EXECUTABLE`)
+    pub static ref SPARQL_CONSTRUCT_EXECUTABLE: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "SPARQLConstructExecutable");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// SPARQL executable
+    ///
+    /// The class of resources that encapsulate a SPARQL query.
+    ///
+    /// `sh:SPARQLExecutable` (`SPARQL_EXECUTABLE`)
+    pub static ref SPARQL_EXECUTABLE: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "SPARQLExecutable");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// SPARQL function
+    ///
+    /// A function backed by a SPARQL query - either ASK or SELECT.
+    ///
+    /// `sh:SPARQLFunction` (`SPARQL_FUNCTION`)
+    pub static ref SPARQL_FUNCTION: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "SPARQLFunction");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// SPARQL CONSTRUCT rule
+    ///
+    /// The class of SHACL rules based on SPARQL CONSTRUCT queries.
+    ///
+    /// `sh:SPARQLRule` (`SPARQL_RULE`)
+    pub static ref SPARQL_RULE: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "SPARQLRule");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// SPARQL SELECT executable
+    ///
+    /// The class of SPARQL executables based on a SELECT query.
+    ///
+    /// `sh:SPARQLSelectExecutable` (`SPARQL_SELECT_EXECUTABLE`)
+    pub static ref SPARQL_SELECT_EXECUTABLE: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "SPARQLSelectExecutable");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// SPARQL SELECT validator
+    ///
+    /// The class of validators based on SPARQL SELECT queries. The queries are evaluated for each focus node and are supposed to produce bindings for all focus nodes that do not conform.
+    ///
+    /// `sh:SPARQLSelectValidator` (`SPARQL_SELECT_VALIDATOR`)
+    pub static ref SPARQL_SELECT_VALIDATOR: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "SPARQLSelectValidator");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// SPARQL target
+    ///
+    /// The class of targets that are based on SPARQL queries.
+    ///
+    /// `sh:SPARQLTarget` (`SPARQL_TARGET`)
+    pub static ref SPARQL_TARGET: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "SPARQLTarget");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// SPARQL target type
+    ///
+    /// The (meta) class for parameterizable targets that are based on SPARQL queries.
+    ///
+    /// `sh:SPARQLTargetType` (`SPARQL_TARGET_TYPE`)
+    pub static ref SPARQL_TARGET_TYPE: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "SPARQLTargetType");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// SPARQL UPDATE executable
+    ///
+    /// The class of SPARQL executables based on a SPARQL UPDATE.
+    ///
+    /// `sh:SPARQLUpdateExecutable` (`SPARQL_UPDATE_EXECUTABLE`)
+    pub static ref SPARQL_UPDATE_EXECUTABLE: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "SPARQLUpdateExecutable");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// SPARQL values deriver
+    ///
+    /// The class of values derivers based on a SPARQL SELECT query.
+    ///
+    /// `sh:SPARQLValuesDeriver` (`SPARQL_VALUES_DERIVER`)
+    pub static ref SPARQL_VALUES_DERIVER: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "SPARQLValuesDeriver");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// Severity
+    ///
+    /// The class of validation result severity levels, including violation and warning levels.
+    ///
+    /// `sh:Severity` (`SEVERITY_CLASS`)
+    pub static ref SEVERITY_CLASS: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "Severity");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// Shape
+    ///
+    /// A shape is a collection of constraints that may be targeted for certain nodes.
+    ///
+    /// `sh:Shape` (`SHAPE`)
+    pub static ref SHAPE: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "Shape");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// Target
+    ///
+    /// The base class of targets such as those based on SPARQL queries.
+    ///
+    /// `sh:Target` (`TARGET_CLASS_2`)
+    pub static ref TARGET_CLASS_2: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "Target");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// Target type
+    ///
+    /// The (meta) class for parameterizable targets. Instances of this are instantiated as values of the sh:target property.
+    ///
+    /// `sh:TargetType` (`TARGET_TYPE`)
+    pub static ref TARGET_TYPE: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "TargetType");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// A rule based on triple (subject, predicate, object) pattern.
+    ///
+    /// A rule based on triple (subject, predicate, object) pattern.
+    ///
+    /// `sh:TripleRule` (`TRIPLE_RULE`)
+    pub static ref TRIPLE_RULE: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "TripleRule");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// Validation report
+    ///
+    /// The class of SHACL validation reports.
+    ///
+    /// `sh:ValidationReport` (`VALIDATION_REPORT`)
+    pub static ref VALIDATION_REPORT: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "ValidationReport");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// Validation result
+    ///
+    /// The class of validation results.
+    ///
+    /// `sh:ValidationResult` (`VALIDATION_RESULT`)
+    pub static ref VALIDATION_RESULT: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "ValidationResult");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// Validator
+    ///
+    /// The class of validators, which provide instructions on how to process a constraint definition. This class serves as base class for the SPARQL-based validators and other possible implementations.
+    ///
+    /// `sh:Validator` (`VALIDATOR_CLASS`)
+    pub static ref VALIDATOR_CLASS: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "Validator");
+}
@@ -0,0 +1,331 @@
+// Copyright (c) 2018-2023, agnos.ai UK Ltd, all rights reserved.
+//---------------------------------------------------------------
+//! The SKOS vocabulary, `skos:` <http://www.w3.org/2004/02/skos/core#>.
+
+// Generated by rdf_store_rs::Vocabulary, do not edit.
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// The `skos:` namespace `<http://www.w3.org/2004/02/skos/core#>`.
+    pub static ref NAMESPACE: ::std::sync::Arc<::rdf_store_rs::Namespace> =
+        ::std::sync::Arc::new(::rdf_store_rs::Namespace::declare_from_str_unchecked("skos:", "http://www.w3.org/2004/02/skos/core#"));
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// alternative label
+    ///
+    /// An alternative lexical label for a resource.
+    ///
+    /// `skos:altLabel` (`ALT_LABEL`)
+    pub static ref ALT_LABEL: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "altLabel");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// has broader match
+    ///
+    /// skos:broadMatch is used to state a hierarchical mapping link between two conceptual resources in different concept schemes.
+    ///
+    /// `skos:broadMatch` (`BROAD_MATCH`)
+    pub static ref BROAD_MATCH: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "broadMatch");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// has broader
+    ///
+    /// Relates a concept to a concept that is more general in meaning.
+    ///
+    /// `skos:broader` (`BROADER`)
+    pub static ref BROADER: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "broader");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// has broader transitive
+    ///
+    /// skos:broaderTransitive is a transitive superproperty of skos:broader.
+    ///
+    /// `skos:broaderTransitive` (`BROADER_TRANSITIVE`)
+    pub static ref BROADER_TRANSITIVE: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "broaderTransitive");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// change note
+    ///
+    /// A note about a modification to a concept.
+    ///
+    /// `skos:changeNote` (`CHANGE_NOTE`)
+    pub static ref CHANGE_NOTE: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "changeNote");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// has close match
+    ///
+    /// skos:closeMatch is used to link two concepts that are sufficiently similar that they can be used interchangeably in some information retrieval applications.
+    ///
+    /// `skos:closeMatch` (`CLOSE_MATCH`)
+    pub static ref CLOSE_MATCH: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "closeMatch");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// definition
+    ///
+    /// A statement or formal explanation of the meaning of a concept.
+    ///
+    /// `skos:definition` (`DEFINITION`)
+    pub static ref DEFINITION: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "definition");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// editorial note
+    ///
+    /// A note for an editor, translator or maintainer of the vocabulary.
+    ///
+    /// `skos:editorialNote` (`EDITORIAL_NOTE`)
+    pub static ref EDITORIAL_NOTE: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "editorialNote");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// has exact match
+    ///
+    /// skos:exactMatch is used to link two concepts, indicating a high degree of confidence that the concepts can be used interchangeably across a wide range of information retrieval applications.
+    ///
+    /// `skos:exactMatch` (`EXACT_MATCH`)
+    pub static ref EXACT_MATCH: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "exactMatch");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// example
+    ///
+    /// An example of the use of a concept.
+    ///
+    /// `skos:example` (`EXAMPLE`)
+    pub static ref EXAMPLE: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "example");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// has top concept
+    ///
+    /// Relates, by convention, a concept scheme to a concept which is topmost in the broader/narrower concept hierarchies for that scheme, providing an entry point to these hierarchies.
+    ///
+    /// `skos:hasTopConcept` (`HAS_TOP_CONCEPT`)
+    pub static ref HAS_TOP_CONCEPT: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "hasTopConcept");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// hidden label
+    ///
+    /// A lexical label for a resource that should be hidden when generating visual displays of the resource, but should still be accessible to free text search operations.
+    ///
+    /// `skos:hiddenLabel` (`HIDDEN_LABEL`)
+    pub static ref HIDDEN_LABEL: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "hiddenLabel");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// history note
+    ///
+    /// A note about the past state/use/meaning of a concept.
+    ///
+    /// `skos:historyNote` (`HISTORY_NOTE`)
+    pub static ref HISTORY_NOTE: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "historyNote");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// is in scheme
+    ///
+    /// Relates a resource (for example a concept) to a concept scheme in which it is included.
+    ///
+    /// `skos:inScheme` (`IN_SCHEME`)
+    pub static ref IN_SCHEME: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "inScheme");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// is in mapping relation with
+    ///
+    /// Relates two concepts coming, by convention, from different schemes, and that have comparable meanings.
+    ///
+    /// `skos:mappingRelation` (`MAPPING_RELATION`)
+    pub static ref MAPPING_RELATION: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "mappingRelation");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// has member
+    ///
+    /// Relates a collection to one of its members.
+    ///
+    /// `skos:member` (`MEMBER`)
+    pub static ref MEMBER: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "member");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// has member list
+    ///
+    /// Relates an ordered collection to the RDF list containing its members.
+    ///
+    /// `skos:memberList` (`MEMBER_LIST`)
+    pub static ref MEMBER_LIST: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "memberList");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// has narrower match
+    ///
+    /// skos:narrowMatch is used to state a hierarchical mapping link between two conceptual resources in different concept schemes.
+    ///
+    /// `skos:narrowMatch` (`NARROW_MATCH`)
+    pub static ref NARROW_MATCH: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "narrowMatch");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// has narrower
+    ///
+    /// Relates a concept to a concept that is more specific in meaning.
+    ///
+    /// `skos:narrower` (`NARROWER`)
+    pub static ref NARROWER: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "narrower");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// has narrower transitive
+    ///
+    /// skos:narrowerTransitive is a transitive superproperty of skos:narrower.
+    ///
+    /// `skos:narrowerTransitive` (`NARROWER_TRANSITIVE`)
+    pub static ref NARROWER_TRANSITIVE: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "narrowerTransitive");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// notation
+    ///
+    /// A notation, also known as classification code, is a string of characters such as "T58.5" or "303.4833" used to uniquely identify a concept within the scope of a given concept scheme.
+    ///
+    /// `skos:notation` (`NOTATION`)
+    pub static ref NOTATION: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "notation");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// note
+    ///
+    /// A general note, for any purpose.
+    ///
+    /// `skos:note` (`NOTE`)
+    pub static ref NOTE: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "note");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// preferred label
+    ///
+    /// The preferred and emphasized human-readable label for a resource, in a given language.
+    ///
+    /// `skos:prefLabel` (`PREF_LABEL`)
+    pub static ref PREF_LABEL: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "prefLabel");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// has related
+    ///
+    /// Relates a concept to a concept with which there is an associative semantic relationship.
+    ///
+    /// `skos:related` (`RELATED`)
+    pub static ref RELATED: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "related");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// has related match
+    ///
+    /// skos:relatedMatch is used to state an associative mapping link between two conceptual resources in different concept schemes.
+    ///
+    /// `skos:relatedMatch` (`RELATED_MATCH`)
+    pub static ref RELATED_MATCH: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "relatedMatch");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// scope note
+    ///
+    /// A note that helps to clarify the meaning and/or the use of a concept.
+    ///
+    /// `skos:scopeNote` (`SCOPE_NOTE`)
+    pub static ref SCOPE_NOTE: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "scopeNote");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// is in semantic relation with
+    ///
+    /// Links a concept to a concept related by meaning.
+    ///
+    /// `skos:semanticRelation` (`SEMANTIC_RELATION`)
+    pub static ref SEMANTIC_RELATION: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "semanticRelation");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// is top concept in scheme
+    ///
+    /// Relates a concept to the concept scheme that it is a top level concept of.
+    ///
+    /// `skos:topConceptOf` (`TOP_CONCEPT_OF`)
+    pub static ref TOP_CONCEPT_OF: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "topConceptOf");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// Collection
+    ///
+    /// A meaningful collection of concepts.
+    ///
+    /// `skos:Collection` (`COLLECTION`)
+    pub static ref COLLECTION: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "Collection");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// Concept
+    ///
+    /// An idea or notion; a unit of thought.
+    ///
+    /// `skos:Concept` (`CONCEPT`)
+    pub static ref CONCEPT: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "Concept");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// Concept Scheme
+    ///
+    /// A set of concepts, optionally including statements about semantic relationships between those concepts.
+    ///
+    /// `skos:ConceptScheme` (`CONCEPT_SCHEME`)
+    pub static ref CONCEPT_SCHEME: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "ConceptScheme");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// Ordered Collection
+    ///
+    /// An ordered collection of concepts, where both the grouping and the ordering are meaningful.
+    ///
+    /// `skos:OrderedCollection` (`ORDERED_COLLECTION`)
+    pub static ref ORDERED_COLLECTION: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "OrderedCollection");
+}
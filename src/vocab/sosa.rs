@@ -0,0 +1,371 @@
+// Copyright (c) 2018-2023, agnos.ai UK Ltd, all rights reserved.
+//---------------------------------------------------------------
+//! The SOSA vocabulary, `sosa:` <http://www.w3.org/ns/sosa/>.
+
+// Generated by rdf_store_rs::Vocabulary, do not edit.
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// The `sosa:` namespace `<http://www.w3.org/ns/sosa/>`.
+    pub static ref NAMESPACE: ::std::sync::Arc<::rdf_store_rs::Namespace> =
+        ::std::sync::Arc::new(::rdf_store_rs::Namespace::declare_from_str_unchecked("sosa:", "http://www.w3.org/ns/sosa/"));
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// acts on property
+    ///
+    /// Relation between an Actuation and the property of a FeatureOfInterest it is acting upon.
+    ///
+    /// `sosa:actsOnProperty` (`ACTS_ON_PROPERTY`)
+    pub static ref ACTS_ON_PROPERTY: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "actsOnProperty");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// has feature of interest
+    ///
+    /// A relation between an Observation and the entity whose quality was observed, or between an Actuation and the entity whose property was modified, or between an act of Sampling and the entity that was sampled.
+    ///
+    /// `sosa:hasFeatureOfInterest` (`HAS_FEATURE_OF_INTEREST`)
+    pub static ref HAS_FEATURE_OF_INTEREST: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "hasFeatureOfInterest");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// has result
+    ///
+    /// Relation linking an Observation or Actuation or act of Sampling and a Result or Sample.
+    ///
+    /// `sosa:hasResult` (`HAS_RESULT`)
+    pub static ref HAS_RESULT: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "hasResult");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// has sample
+    ///
+    /// Relation between a FeatureOfInterest and the Sample used to represent it.
+    ///
+    /// `sosa:hasSample` (`HAS_SAMPLE`)
+    pub static ref HAS_SAMPLE: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "hasSample");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// has simple result
+    ///
+    /// The simple value of an Observation or Actuation or act of Sampling.
+    ///
+    /// `sosa:hasSimpleResult` (`HAS_SIMPLE_RESULT`)
+    pub static ref HAS_SIMPLE_RESULT: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "hasSimpleResult");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// hosts
+    ///
+    /// Relation between a Platform and a Sensor, Actuator, Sampler, or Platform, hosted or mounted on it.
+    ///
+    /// `sosa:hosts` (`HOSTS`)
+    pub static ref HOSTS: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "hosts");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// is acted on by
+    ///
+    /// Relation between an ActuatableProperty of a FeatureOfInterest and an Actuation changing its state.
+    ///
+    /// `sosa:isActedOnBy` (`IS_ACTED_ON_BY`)
+    pub static ref IS_ACTED_ON_BY: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "isActedOnBy");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// is feature of interest of
+    ///
+    /// A relation between a FeatureOfInterest and an Observation about it, an Actuation acting on it, or an act of Sampling that sampled it.
+    ///
+    /// `sosa:isFeatureOfInterestOf` (`IS_FEATURE_OF_INTEREST_OF`)
+    pub static ref IS_FEATURE_OF_INTEREST_OF: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "isFeatureOfInterestOf");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// is hosted by
+    ///
+    /// Relation between a Sensor, Actuator, Sampler, or Platform, and the Platform that it is mounted on or hosted by.
+    ///
+    /// `sosa:isHostedBy` (`IS_HOSTED_BY`)
+    pub static ref IS_HOSTED_BY: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "isHostedBy");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// is observed by
+    ///
+    /// Relation between an ObservableProperty and the Sensor able to observe it.
+    ///
+    /// `sosa:isObservedBy` (`IS_OBSERVED_BY`)
+    pub static ref IS_OBSERVED_BY: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "isObservedBy");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// is result of
+    ///
+    /// Relation linking a Result to the Observation or Actuation or act of Sampling that created or caused it.
+    ///
+    /// `sosa:isResultOf` (`IS_RESULT_OF`)
+    pub static ref IS_RESULT_OF: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "isResultOf");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// is sample of
+    ///
+    /// Relation from a Sample to the FeatureOfInterest that it is intended to be representative of.
+    ///
+    /// `sosa:isSampleOf` (`IS_SAMPLE_OF`)
+    pub static ref IS_SAMPLE_OF: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "isSampleOf");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// made actuation
+    ///
+    /// Relation between an Actuator and the Actuation it has made.
+    ///
+    /// `sosa:madeActuation` (`MADE_ACTUATION`)
+    pub static ref MADE_ACTUATION: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "madeActuation");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// made by actuator
+    ///
+    /// Relation linking an Actuation to the Actuator that made that Actuation.
+    ///
+    /// `sosa:madeByActuator` (`MADE_BY_ACTUATOR`)
+    pub static ref MADE_BY_ACTUATOR: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "madeByActuator");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// made by sampler
+    ///
+    /// Relation linking an act of Sampling to the Sampler (sampling device or entity) that made it.
+    ///
+    /// `sosa:madeBySampler` (`MADE_BY_SAMPLER`)
+    pub static ref MADE_BY_SAMPLER: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "madeBySampler");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// made by sensor
+    ///
+    /// Relation between an Observation and the Sensor which made the Observation.
+    ///
+    /// `sosa:madeBySensor` (`MADE_BY_SENSOR`)
+    pub static ref MADE_BY_SENSOR: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "madeBySensor");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// made observation
+    ///
+    /// Relation between a Sensor and an Observation made by the Sensor.
+    ///
+    /// `sosa:madeObservation` (`MADE_OBSERVATION`)
+    pub static ref MADE_OBSERVATION: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "madeObservation");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// made sampling
+    ///
+    /// Relation between a Sampler (sampling device or entity) and the Sampling act it performed.
+    ///
+    /// `sosa:madeSampling` (`MADE_SAMPLING`)
+    pub static ref MADE_SAMPLING: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "madeSampling");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// observed property
+    ///
+    /// Relation linking an Observation to the property that was observed. The ObservableProperty should be a property of the FeatureOfInterest (linked by hasFeatureOfInterest) of this Observation.
+    ///
+    /// `sosa:observedProperty` (`OBSERVED_PROPERTY`)
+    pub static ref OBSERVED_PROPERTY: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "observedProperty");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// observes
+    ///
+    /// Relation between a Sensor and an ObservableProperty that it is capable of sensing.
+    ///
+    /// `sosa:observes` (`OBSERVES`)
+    pub static ref OBSERVES: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "observes");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// phenomenon time
+    ///
+    /// The time that the Result of an Observation, Actuation or Sampling applies to the FeatureOfInterest. Not necessarily the same as the resultTime. May be an Interval or an Instant, or some other compound temporal entity.
+    ///
+    /// `sosa:phenomenonTime` (`PHENOMENON_TIME`)
+    pub static ref PHENOMENON_TIME: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "phenomenonTime");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// result time
+    ///
+    /// The result time is the instant of time when the Observation, Actuation or Sampling activity was completed.
+    ///
+    /// `sosa:resultTime` (`RESULT_TIME`)
+    pub static ref RESULT_TIME: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "resultTime");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// used procedure
+    ///
+    /// A relation to link to a re-usable Procedure used in making an Observation, an Actuation, or a Sample, typically through a Sensor, Actuator or Sampler.
+    ///
+    /// `sosa:usedProcedure` (`USED_PROCEDURE`)
+    pub static ref USED_PROCEDURE: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "usedProcedure");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// Actuatable Property
+    ///
+    /// An actuatable quality (property, characteristic) of a FeatureOfInterest.
+    ///
+    /// `sosa:ActuatableProperty` (`ACTUATABLE_PROPERTY`)
+    pub static ref ACTUATABLE_PROPERTY: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "ActuatableProperty");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// Actuation
+    ///
+    /// An Actuation carries out an (Actuation) Procedure to change the state of the world using an Actuator.
+    ///
+    /// `sosa:Actuation` (`ACTUATION`)
+    pub static ref ACTUATION: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "Actuation");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// Actuator
+    ///
+    /// A device that is used by, or implements, an (Actuation) Procedure that changes the state of the world.
+    ///
+    /// `sosa:Actuator` (`ACTUATOR`)
+    pub static ref ACTUATOR: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "Actuator");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// Feature Of Interest
+    ///
+    /// The thing whose property is being estimated or calculated in the course of an Observation to arrive at a Result or whose property is being manipulated by an Actuator, or which is being sampled or transformed in an act of Sampling.
+    ///
+    /// `sosa:FeatureOfInterest` (`FEATURE_OF_INTEREST`)
+    pub static ref FEATURE_OF_INTEREST: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "FeatureOfInterest");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// Observable Property
+    ///
+    /// An observable quality (property, characteristic) of a FeatureOfInterest.
+    ///
+    /// `sosa:ObservableProperty` (`OBSERVABLE_PROPERTY`)
+    pub static ref OBSERVABLE_PROPERTY: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "ObservableProperty");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// Observation
+    ///
+    /// Act of carrying out an (Observation) Procedure to estimate or calculate a value of a property of a FeatureOfInterest. Links to a Sensor to describe what made the Observation and how; links to an ObservableProperty to describe what the result is an estimate of, and to a FeatureOfInterest to detail what that property was associated with.
+    ///
+    /// `sosa:Observation` (`OBSERVATION`)
+    pub static ref OBSERVATION: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "Observation");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// Platform
+    ///
+    /// A Platform is an entity that hosts other entities, particularly Sensors, Actuators, Samplers, and other Platforms.
+    ///
+    /// `sosa:Platform` (`PLATFORM`)
+    pub static ref PLATFORM: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "Platform");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// Procedure
+    ///
+    /// A workflow, protocol, plan, algorithm, or computational method specifying how to make an Observation, create a Sample, or make a change to the state of the world (via an Actuator). A Procedure is re-usable, and might be involved in many Observations, Samplings, or Actuations. It explains the steps to be carried out to arrive at reproducible results.
+    ///
+    /// `sosa:Procedure` (`PROCEDURE`)
+    pub static ref PROCEDURE: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "Procedure");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// Result
+    ///
+    /// The Result of an Observation, Actuation, or act of Sampling. To store an observation's simple result value one can use the hasSimpleResult property.
+    ///
+    /// `sosa:Result` (`RESULT`)
+    pub static ref RESULT: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "Result");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// Sample
+    ///
+    /// Feature which is intended to be representative of a FeatureOfInterest on which Observations may be made.
+    ///
+    /// `sosa:Sample` (`SAMPLE`)
+    pub static ref SAMPLE: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "Sample");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// Sampler
+    ///
+    /// A device that is used by, or implements, a Sampling Procedure to create or transform one or more samples.
+    ///
+    /// `sosa:Sampler` (`SAMPLER`)
+    pub static ref SAMPLER: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "Sampler");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// Sampling
+    ///
+    /// An act of Sampling carries out a sampling Procedure to create or transform one or more samples.
+    ///
+    /// `sosa:Sampling` (`SAMPLING`)
+    pub static ref SAMPLING: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "Sampling");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// Sensor
+    ///
+    /// Device, agent (including humans), or software (simulation) involved in, or implementing, a Procedure. Sensors respond to a stimulus, e.g., a change in the environment, or input data composed from the results of prior Observations, and generate a Result. Sensors can be hosted by Platforms.
+    ///
+    /// `sosa:Sensor` (`SENSOR`)
+    pub static ref SENSOR: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "Sensor");
+}
@@ -0,0 +1,321 @@
+// Copyright (c) 2018-2023, agnos.ai UK Ltd, all rights reserved.
+//---------------------------------------------------------------
+//! The VoID vocabulary, `void:` <http://rdfs.org/ns/void#>.
+
+// Generated by rdf_store_rs::Vocabulary, do not edit.
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// The `void:` namespace `<http://rdfs.org/ns/void#>`.
+    pub static ref NAMESPACE: ::std::sync::Arc<::rdf_store_rs::Namespace> =
+        ::std::sync::Arc::new(::rdf_store_rs::Namespace::declare_from_str_unchecked("void:", "http://rdfs.org/ns/void#"));
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// class
+    ///
+    /// The rdfs:Class that is the rdf:type of all entities in a class-based partition.
+    ///
+    /// `void:class` (`CLASS`)
+    pub static ref CLASS: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "class");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// class partition
+    ///
+    /// A subset of a void:Dataset that contains only the entities of a certain rdfs:Class.
+    ///
+    /// `void:classPartition` (`CLASS_PARTITION`)
+    pub static ref CLASS_PARTITION: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "classPartition");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// classes
+    ///
+    /// The total number of distinct classes in a void:Dataset. In other words, the number of distinct resources occurring as objects of rdf:type triples in the dataset.
+    ///
+    /// `void:classes` (`CLASSES`)
+    pub static ref CLASSES: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "classes");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// Data Dump
+    ///
+    /// An RDF dump, partial or complete, of a void:Dataset.
+    ///
+    /// `void:dataDump` (`DATA_DUMP`)
+    pub static ref DATA_DUMP: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "dataDump");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// distinct objects
+    ///
+    /// The total number of distinct objects in a void:Dataset. In other words, the number of distinct resources that occur in the object position of triples in the dataset. Literals are included in this count.
+    ///
+    /// `void:distinctObjects` (`DISTINCT_OBJECTS`)
+    pub static ref DISTINCT_OBJECTS: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "distinctObjects");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// distinct subjects
+    ///
+    /// The total number of distinct subjects in a void:Dataset. In other words, the number of distinct resources that occur in the subject position of triples in the dataset.
+    ///
+    /// `void:distinctSubjects` (`DISTINCT_SUBJECTS`)
+    pub static ref DISTINCT_SUBJECTS: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "distinctSubjects");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// number of documents
+    ///
+    /// The total number of documents, for datasets that are published as a set of individual documents, such as RDF/XML documents or RDFa-annotated web pages. Non-RDF documents, such as web pages in HTML or images, are usually not included in this count. This property is intended for datasets where the total number of triples or entities is hard to determine. void:triples or void:entities should be preferred where practical.
+    ///
+    /// `void:documents` (`DOCUMENTS`)
+    pub static ref DOCUMENTS: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "documents");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// number of entities
+    ///
+    /// The total number of entities that are described in a void:Dataset.
+    ///
+    /// `void:entities` (`ENTITIES`)
+    pub static ref ENTITIES: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "entities");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// example resource of dataset
+    ///
+    /// An example resource of a void:Dataset.
+    ///
+    /// `void:exampleResource` (`EXAMPLE_RESOURCE`)
+    pub static ref EXAMPLE_RESOURCE: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "exampleResource");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// feature
+    ///
+    /// A technical feature of a void:Dataset, such as a supported RDF serialization format.
+    ///
+    /// `void:feature` (`FEATURE`)
+    pub static ref FEATURE: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "feature");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// in dataset
+    ///
+    /// Points to the void:Dataset that a document is a part of.
+    ///
+    /// `void:inDataset` (`IN_DATASET`)
+    pub static ref IN_DATASET: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "inDataset");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// a link predicate
+    ///
+    /// The rdf:Property that is the predicate of all triples in a void:Linkset.
+    ///
+    /// `void:linkPredicate` (`LINK_PREDICATE`)
+    pub static ref LINK_PREDICATE: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "linkPredicate");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// Objects Target
+    ///
+    /// The dataset describing the objects of the triples contained in the Linkset.
+    ///
+    /// `void:objectsTarget` (`OBJECTS_TARGET`)
+    pub static ref OBJECTS_TARGET: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "objectsTarget");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// open search description
+    ///
+    /// An OpenSearch description document for a free-text search service over a void:Dataset.
+    ///
+    /// `void:openSearchDescription` (`OPEN_SEARCH_DESCRIPTION`)
+    pub static ref OPEN_SEARCH_DESCRIPTION: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "openSearchDescription");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// number of properties
+    ///
+    /// The total number of distinct properties in a void:Dataset. In other words, the number of distinct resources that occur in the predicate position of triples in the dataset.
+    ///
+    /// `void:properties` (`PROPERTIES`)
+    pub static ref PROPERTIES: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "properties");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// property
+    ///
+    /// The rdf:Property that is the predicate of all triples in a property-based partition.
+    ///
+    /// `void:property` (`PROPERTY`)
+    pub static ref PROPERTY: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "property");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// property partition
+    ///
+    /// A subset of a void:Dataset that contains only the triples of a certain rdf:Property.
+    ///
+    /// `void:propertyPartition` (`PROPERTY_PARTITION`)
+    pub static ref PROPERTY_PARTITION: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "propertyPartition");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// root resource
+    ///
+    /// A top concept or entry point for a void:Dataset that is structured in a tree-like fashion. All resources in a dataset can be reached by following links from its root resources in a small number of steps.
+    ///
+    /// `void:rootResource` (`ROOT_RESOURCE`)
+    pub static ref ROOT_RESOURCE: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "rootResource");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// has a SPARQL endpoint at
+    ///
+    /// A SPARQL protocol endpoint that allows SPARQL query access to a void:Dataset.
+    ///
+    /// `void:sparqlEndpoint` (`SPARQL_ENDPOINT`)
+    pub static ref SPARQL_ENDPOINT: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "sparqlEndpoint");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// Subjects Target
+    ///
+    /// The dataset describing the subjects of triples contained in the Linkset.
+    ///
+    /// `void:subjectsTarget` (`SUBJECTS_TARGET`)
+    pub static ref SUBJECTS_TARGET: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "subjectsTarget");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// has subset
+    ///
+    /// A void:Dataset that is part of another void:Dataset.
+    ///
+    /// `void:subset` (`SUBSET`)
+    pub static ref SUBSET: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "subset");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// Target
+    ///
+    /// One of the two datasets linked by the Linkset.
+    ///
+    /// `void:target` (`TARGET`)
+    pub static ref TARGET: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "target");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// number of triples
+    ///
+    /// The total number of triples contained in a void:Dataset.
+    ///
+    /// `void:triples` (`TRIPLES`)
+    pub static ref TRIPLES: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "triples");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// has an URI look-up endpoint at
+    ///
+    /// Defines a simple URI look-up protocol for accessing a dataset.
+    ///
+    /// `void:uriLookupEndpoint` (`URI_LOOKUP_ENDPOINT`)
+    pub static ref URI_LOOKUP_ENDPOINT: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "uriLookupEndpoint");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// has URI regular expression pattern
+    ///
+    /// Defines a regular expression pattern matching URIs in the dataset.
+    ///
+    /// `void:uriRegexPattern` (`URI_REGEX_PATTERN`)
+    pub static ref URI_REGEX_PATTERN: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "uriRegexPattern");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// URI space
+    ///
+    /// A URI that is a common string prefix of all the entity URIs in a void:Dataset.
+    ///
+    /// `void:uriSpace` (`URI_SPACE`)
+    pub static ref URI_SPACE: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "uriSpace");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// vocabulary
+    ///
+    /// A vocabulary that is used in the dataset.
+    ///
+    /// `void:vocabulary` (`VOCABULARY`)
+    pub static ref VOCABULARY: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "vocabulary");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// dataset
+    ///
+    /// A set of RDF triples that are published, maintained or aggregated by a single provider.
+    ///
+    /// `void:Dataset` (`DATASET`)
+    pub static ref DATASET: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "Dataset");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// dataset description
+    ///
+    /// A web resource whose foaf:primaryTopic or foaf:topics include void:Datasets.
+    ///
+    /// `void:DatasetDescription` (`DATASET_DESCRIPTION`)
+    pub static ref DATASET_DESCRIPTION: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "DatasetDescription");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// linkset
+    ///
+    /// A collection of RDF links between two void:Datasets.
+    ///
+    /// `void:Linkset` (`LINKSET`)
+    pub static ref LINKSET: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "Linkset");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// technical feature
+    ///
+    /// A technical feature of a void:Dataset, such as a supported RDF serialization format.
+    ///
+    /// `void:TechnicalFeature` (`TECHNICAL_FEATURE`)
+    pub static ref TECHNICAL_FEATURE: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "TechnicalFeature");
+}
@@ -0,0 +1,641 @@
+// Copyright (c) 2018-2023, agnos.ai UK Ltd, all rights reserved.
+//---------------------------------------------------------------
+//! The XML Schema datatypes, `xsd:` <http://www.w3.org/2001/XMLSchema#>.
+
+// Generated by rdf_store_rs::Vocabulary, do not edit.
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// The `xsd:` namespace `<http://www.w3.org/2001/XMLSchema#>`.
+    pub static ref NAMESPACE: ::std::sync::Arc<::rdf_store_rs::Namespace> =
+        ::std::sync::Arc::new(::rdf_store_rs::Namespace::declare_from_str_unchecked("xsd:", "http://www.w3.org/2001/XMLSchema#"));
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// enumeration
+    ///
+    /// The constraining facet that limits a value space to a specified set of values.
+    ///
+    /// `xsd:enumeration` (`ENUMERATION`)
+    pub static ref ENUMERATION: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "enumeration");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// explicitTimezone
+    ///
+    /// The constraining facet that requires, allows or prohibits a time zone offset in the values of date and time datatypes.
+    ///
+    /// `xsd:explicitTimezone` (`EXPLICIT_TIMEZONE`)
+    pub static ref EXPLICIT_TIMEZONE: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "explicitTimezone");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// fractionDigits
+    ///
+    /// The constraining facet that limits the number of digits after the decimal point of a decimal value.
+    ///
+    /// `xsd:fractionDigits` (`FRACTION_DIGITS`)
+    pub static ref FRACTION_DIGITS: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "fractionDigits");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// length
+    ///
+    /// The constraining facet that fixes the number of units of length of a value, such as characters, octets or list items.
+    ///
+    /// `xsd:length` (`LENGTH`)
+    pub static ref LENGTH: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "length");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// maxExclusive
+    ///
+    /// The constraining facet that gives the exclusive upper bound of an ordered value space.
+    ///
+    /// `xsd:maxExclusive` (`MAX_EXCLUSIVE`)
+    pub static ref MAX_EXCLUSIVE: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "maxExclusive");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// maxInclusive
+    ///
+    /// The constraining facet that gives the inclusive upper bound of an ordered value space.
+    ///
+    /// `xsd:maxInclusive` (`MAX_INCLUSIVE`)
+    pub static ref MAX_INCLUSIVE: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "maxInclusive");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// maxLength
+    ///
+    /// The constraining facet that gives the maximum number of units of length of a value.
+    ///
+    /// `xsd:maxLength` (`MAX_LENGTH`)
+    pub static ref MAX_LENGTH: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "maxLength");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// minExclusive
+    ///
+    /// The constraining facet that gives the exclusive lower bound of an ordered value space.
+    ///
+    /// `xsd:minExclusive` (`MIN_EXCLUSIVE`)
+    pub static ref MIN_EXCLUSIVE: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "minExclusive");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// minInclusive
+    ///
+    /// The constraining facet that gives the inclusive lower bound of an ordered value space.
+    ///
+    /// `xsd:minInclusive` (`MIN_INCLUSIVE`)
+    pub static ref MIN_INCLUSIVE: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "minInclusive");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// minLength
+    ///
+    /// The constraining facet that gives the minimum number of units of length of a value.
+    ///
+    /// `xsd:minLength` (`MIN_LENGTH`)
+    pub static ref MIN_LENGTH: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "minLength");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// pattern
+    ///
+    /// The constraining facet that limits a value space to the values whose lexical forms match a regular expression.
+    ///
+    /// `xsd:pattern` (`PATTERN`)
+    pub static ref PATTERN: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "pattern");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// totalDigits
+    ///
+    /// The constraining facet that limits the total number of digits of a decimal value.
+    ///
+    /// `xsd:totalDigits` (`TOTAL_DIGITS`)
+    pub static ref TOTAL_DIGITS: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "totalDigits");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// whiteSpace
+    ///
+    /// The constraining facet that determines how white space in a lexical form is normalized: preserve, replace or collapse.
+    ///
+    /// `xsd:whiteSpace` (`WHITE_SPACE`)
+    pub static ref WHITE_SPACE: ::rdf_store_rs::Predicate =
+        ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "whiteSpace");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// ENTITIES
+    ///
+    /// The datatype of whitespace-separated lists of ENTITY values, which are declared as unparsed entities in a DTD.
+    ///
+    /// `xsd:ENTITIES` (`ENTITIES`)
+    pub static ref ENTITIES: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "ENTITIES");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// ENTITY
+    ///
+    /// The datatype of the names of unparsed entities declared in a DTD.
+    ///
+    /// `xsd:ENTITY` (`ENTITY`)
+    pub static ref ENTITY: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "ENTITY");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// ID
+    ///
+    /// The datatype of the ID attribute type from XML, an NCName that identifies an element.
+    ///
+    /// `xsd:ID` (`ID`)
+    pub static ref ID: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "ID");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// IDREF
+    ///
+    /// The datatype of the IDREF attribute type from XML, an NCName that refers to an ID.
+    ///
+    /// `xsd:IDREF` (`IDREF`)
+    pub static ref IDREF: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "IDREF");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// IDREFS
+    ///
+    /// The datatype of whitespace-separated lists of IDREF values.
+    ///
+    /// `xsd:IDREFS` (`IDREFS`)
+    pub static ref IDREFS: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "IDREFS");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// NCName
+    ///
+    /// The datatype of non-colonized XML names, which do not contain a colon.
+    ///
+    /// `xsd:NCName` (`NC_NAME`)
+    pub static ref NC_NAME: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "NCName");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// NMTOKEN
+    ///
+    /// The datatype of the NMTOKEN attribute type from XML, a string of XML name characters.
+    ///
+    /// `xsd:NMTOKEN` (`NMTOKEN`)
+    pub static ref NMTOKEN: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "NMTOKEN");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// NMTOKENS
+    ///
+    /// The datatype of whitespace-separated lists of NMTOKEN values.
+    ///
+    /// `xsd:NMTOKENS` (`NMTOKENS`)
+    pub static ref NMTOKENS: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "NMTOKENS");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// NOTATION
+    ///
+    /// The datatype of the NOTATION attribute type from XML, the qualified names of declared notations.
+    ///
+    /// `xsd:NOTATION` (`NOTATION`)
+    pub static ref NOTATION: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "NOTATION");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// Name
+    ///
+    /// The datatype of XML names.
+    ///
+    /// `xsd:Name` (`NAME`)
+    pub static ref NAME: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "Name");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// QName
+    ///
+    /// The datatype of XML qualified names, a namespace name and a local part.
+    ///
+    /// `xsd:QName` (`Q_NAME`)
+    pub static ref Q_NAME: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "QName");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// anyAtomicType
+    ///
+    /// The base type of all the primitive datatypes, whose value space is the union of their value spaces.
+    ///
+    /// `xsd:anyAtomicType` (`ANY_ATOMIC_TYPE`)
+    pub static ref ANY_ATOMIC_TYPE: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "anyAtomicType");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// anySimpleType
+    ///
+    /// The base type of all simple types, whose value space is the union of the value spaces of anyAtomicType and all list datatypes.
+    ///
+    /// `xsd:anySimpleType` (`ANY_SIMPLE_TYPE`)
+    pub static ref ANY_SIMPLE_TYPE: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "anySimpleType");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// anyType
+    ///
+    /// The root of the XML Schema type hierarchy, the base type of all simple and complex types.
+    ///
+    /// `xsd:anyType` (`ANY_TYPE`)
+    pub static ref ANY_TYPE: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "anyType");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// anyURI
+    ///
+    /// The datatype of Uniform Resource Identifier references.
+    ///
+    /// `xsd:anyURI` (`ANY_URI`)
+    pub static ref ANY_URI: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "anyURI");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// base64Binary
+    ///
+    /// The datatype of arbitrary Base64-encoded binary data.
+    ///
+    /// `xsd:base64Binary` (`BASE64_BINARY`)
+    pub static ref BASE64_BINARY: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "base64Binary");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// boolean
+    ///
+    /// The datatype of the values of two-valued logic, true and false.
+    ///
+    /// `xsd:boolean` (`BOOLEAN`)
+    pub static ref BOOLEAN: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "boolean");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// byte
+    ///
+    /// The datatype of integers from -128 to 127, derived from short.
+    ///
+    /// `xsd:byte` (`BYTE`)
+    pub static ref BYTE: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "byte");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// date
+    ///
+    /// The datatype of top-open intervals of exactly one day in length on the timelines of dateTime, with an optional time zone offset.
+    ///
+    /// `xsd:date` (`DATE`)
+    pub static ref DATE: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "date");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// dateTime
+    ///
+    /// The datatype of instants in time, with year, month, day, hour, minute and second, and an optional time zone offset.
+    ///
+    /// `xsd:dateTime` (`DATE_TIME`)
+    pub static ref DATE_TIME: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "dateTime");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// dateTimeStamp
+    ///
+    /// The datatype of dateTime values with a required time zone offset.
+    ///
+    /// `xsd:dateTimeStamp` (`DATE_TIME_STAMP`)
+    pub static ref DATE_TIME_STAMP: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "dateTimeStamp");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// dayTimeDuration
+    ///
+    /// The datatype of durations that only have a day, hour, minute and second component.
+    ///
+    /// `xsd:dayTimeDuration` (`DAY_TIME_DURATION`)
+    pub static ref DAY_TIME_DURATION: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "dayTimeDuration");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// decimal
+    ///
+    /// The datatype of the subset of the real numbers that can be represented by decimal numerals.
+    ///
+    /// `xsd:decimal` (`DECIMAL`)
+    pub static ref DECIMAL: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "decimal");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// double
+    ///
+    /// The datatype of IEEE double-precision 64-bit floating point numbers.
+    ///
+    /// `xsd:double` (`DOUBLE`)
+    pub static ref DOUBLE: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "double");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// duration
+    ///
+    /// The datatype of durations of time, with a months component and a seconds component.
+    ///
+    /// `xsd:duration` (`DURATION`)
+    pub static ref DURATION: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "duration");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// float
+    ///
+    /// The datatype of IEEE single-precision 32-bit floating point numbers.
+    ///
+    /// `xsd:float` (`FLOAT`)
+    pub static ref FLOAT: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "float");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// gDay
+    ///
+    /// The datatype of recurring Gregorian days of the month, with an optional time zone offset.
+    ///
+    /// `xsd:gDay` (`G_DAY`)
+    pub static ref G_DAY: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "gDay");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// gMonth
+    ///
+    /// The datatype of recurring Gregorian months of the year, with an optional time zone offset.
+    ///
+    /// `xsd:gMonth` (`G_MONTH`)
+    pub static ref G_MONTH: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "gMonth");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// gMonthDay
+    ///
+    /// The datatype of recurring Gregorian days of a month of the year, with an optional time zone offset.
+    ///
+    /// `xsd:gMonthDay` (`G_MONTH_DAY`)
+    pub static ref G_MONTH_DAY: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "gMonthDay");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// gYear
+    ///
+    /// The datatype of Gregorian calendar years, with an optional time zone offset.
+    ///
+    /// `xsd:gYear` (`G_YEAR`)
+    pub static ref G_YEAR: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "gYear");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// gYearMonth
+    ///
+    /// The datatype of specific months of specific Gregorian years, with an optional time zone offset.
+    ///
+    /// `xsd:gYearMonth` (`G_YEAR_MONTH`)
+    pub static ref G_YEAR_MONTH: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "gYearMonth");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// hexBinary
+    ///
+    /// The datatype of arbitrary hex-encoded binary data.
+    ///
+    /// `xsd:hexBinary` (`HEX_BINARY`)
+    pub static ref HEX_BINARY: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "hexBinary");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// int
+    ///
+    /// The datatype of integers from -2147483648 to 2147483647, derived from long.
+    ///
+    /// `xsd:int` (`INT`)
+    pub static ref INT: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "int");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// integer
+    ///
+    /// The datatype of the infinite set of whole numbers, derived from decimal.
+    ///
+    /// `xsd:integer` (`INTEGER`)
+    pub static ref INTEGER: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "integer");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// language
+    ///
+    /// The datatype of natural language identifiers, as defined by BCP 47.
+    ///
+    /// `xsd:language` (`LANGUAGE`)
+    pub static ref LANGUAGE: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "language");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// long
+    ///
+    /// The datatype of integers from -9223372036854775808 to 9223372036854775807, derived from integer.
+    ///
+    /// `xsd:long` (`LONG`)
+    pub static ref LONG: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "long");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// negativeInteger
+    ///
+    /// The datatype of integers less than zero, derived from nonPositiveInteger.
+    ///
+    /// `xsd:negativeInteger` (`NEGATIVE_INTEGER`)
+    pub static ref NEGATIVE_INTEGER: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "negativeInteger");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// nonNegativeInteger
+    ///
+    /// The datatype of integers greater than or equal to zero, derived from integer.
+    ///
+    /// `xsd:nonNegativeInteger` (`NON_NEGATIVE_INTEGER`)
+    pub static ref NON_NEGATIVE_INTEGER: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "nonNegativeInteger");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// nonPositiveInteger
+    ///
+    /// The datatype of integers less than or equal to zero, derived from integer.
+    ///
+    /// `xsd:nonPositiveInteger` (`NON_POSITIVE_INTEGER`)
+    pub static ref NON_POSITIVE_INTEGER: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "nonPositiveInteger");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// normalizedString
+    ///
+    /// The datatype of strings that do not contain carriage return, line feed or tab characters.
+    ///
+    /// `xsd:normalizedString` (`NORMALIZED_STRING`)
+    pub static ref NORMALIZED_STRING: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "normalizedString");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// positiveInteger
+    ///
+    /// The datatype of integers greater than zero, derived from nonNegativeInteger.
+    ///
+    /// `xsd:positiveInteger` (`POSITIVE_INTEGER`)
+    pub static ref POSITIVE_INTEGER: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "positiveInteger");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// short
+    ///
+    /// The datatype of integers from -32768 to 32767, derived from int.
+    ///
+    /// `xsd:short` (`SHORT`)
+    pub static ref SHORT: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "short");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// string
+    ///
+    /// The datatype of character strings.
+    ///
+    /// `xsd:string` (`STRING`)
+    pub static ref STRING: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "string");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// time
+    ///
+    /// The datatype of instants of time that recur at the same point in each calendar day, with an optional time zone offset.
+    ///
+    /// `xsd:time` (`TIME`)
+    pub static ref TIME: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "time");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// token
+    ///
+    /// The datatype of tokenized strings, which have no line feeds, tabs, leading or trailing spaces or internal sequences of two or more spaces.
+    ///
+    /// `xsd:token` (`TOKEN`)
+    pub static ref TOKEN: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "token");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// unsignedByte
+    ///
+    /// The datatype of integers from 0 to 255, derived from unsignedShort.
+    ///
+    /// `xsd:unsignedByte` (`UNSIGNED_BYTE`)
+    pub static ref UNSIGNED_BYTE: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "unsignedByte");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// unsignedInt
+    ///
+    /// The datatype of integers from 0 to 4294967295, derived from unsignedLong.
+    ///
+    /// `xsd:unsignedInt` (`UNSIGNED_INT`)
+    pub static ref UNSIGNED_INT: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "unsignedInt");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// unsignedLong
+    ///
+    /// The datatype of integers from 0 to 18446744073709551615, derived from nonNegativeInteger.
+    ///
+    /// `xsd:unsignedLong` (`UNSIGNED_LONG`)
+    pub static ref UNSIGNED_LONG: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "unsignedLong");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// unsignedShort
+    ///
+    /// The datatype of integers from 0 to 65535, derived from unsignedInt.
+    ///
+    /// `xsd:unsignedShort` (`UNSIGNED_SHORT`)
+    pub static ref UNSIGNED_SHORT: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "unsignedShort");
+}
+
+::rdf_store_rs::lazy_static::lazy_static! {
+    /// yearMonthDuration
+    ///
+    /// The datatype of durations that only have a year and month component.
+    ///
+    /// `xsd:yearMonthDuration` (`YEAR_MONTH_DURATION`)
+    pub static ref YEAR_MONTH_DURATION: ::rdf_store_rs::Class =
+        ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "yearMonthDuration");
+}
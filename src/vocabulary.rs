@@ -0,0 +1,551 @@
+// Copyright (c) 2018-2023, agnos.ai UK Ltd, all rights reserved.
+//---------------------------------------------------------------
+//! Generating Rust constants for the classes and predicates of an ontology.
+//!
+//! A [`Vocabulary`] is read from a Turtle (or N-Triples) ontology and
+//! written as the items of a Rust module: a `NAMESPACE` constant with the
+//! [`Namespace`], and a [`Class`] or [`Predicate`] constant for each class
+//! or property of that namespace, documented with its `rdfs:label` and
+//! `rdfs:comment`.
+//!
+//! In a build script, [`generate_vocabulary`] writes such a module to
+//! `OUT_DIR`, for instance with `rdf-store-rs` (with the `nom-support`
+//! feature) as a build dependency:
+//!
+//! ```rust,ignore
+//! // build.rs
+//! use rdf_store_rs::{generate_vocabulary, Namespace, RDFStoreError};
+//!
+//! fn main() -> Result<(), RDFStoreError> {
+//!     let namespace = Namespace::try_declare_from_str("ekg:", "https://ekgf.org/ontology/ekg#")?;
+//!     generate_vocabulary("ontology/ekg.ttl", &namespace)?;
+//!     Ok(())
+//! }
+//!
+//! // src/lib.rs
+//! pub mod ekg {
+//!     include!(concat!(env!("OUT_DIR"), "/ekg.rs"));
+//! }
+//! ```
+//!
+//! The names of the constants are the local names in upper snake case, so
+//! `skos:prefLabel` becomes `PREF_LABEL`. When a class and a property have
+//! the same name, such as `prov:Agent` and `prov:agent`, they become
+//! `AGENT_CLASS` and `AGENT_PROPERTY`.
+
+use {
+    crate::{
+        sparql::{PropertyPath, SparqlIri, SparqlLiteral, TermPattern, Verb},
+        turtle::parse_turtle,
+        Class,
+        Namespace,
+        Predicate,
+        RDFStoreError,
+    },
+    std::{
+        collections::{BTreeMap, HashSet},
+        fmt::Write,
+        path::{Path, PathBuf},
+        sync::Arc,
+    },
+};
+
+const RDF_TYPE: &str = "http://www.w3.org/1999/02/22-rdf-syntax-ns#type";
+const RDFS_LABEL: &str = "http://www.w3.org/2000/01/rdf-schema#label";
+const RDFS_COMMENT: &str = "http://www.w3.org/2000/01/rdf-schema#comment";
+
+/// The name of the constant of the namespace of a generated module.
+const NAMESPACE: &str = "NAMESPACE";
+
+/// The types of the classes of an ontology, where datatypes such as
+/// `xsd:string` are classes of literals.
+const CLASS_TYPES: [&str; 3] = [
+    "http://www.w3.org/2000/01/rdf-schema#Class",
+    "http://www.w3.org/2000/01/rdf-schema#Datatype",
+    "http://www.w3.org/2002/07/owl#Class",
+];
+
+/// The types of the properties of an ontology.
+const PROPERTY_TYPES: [&str; 12] = [
+    "http://www.w3.org/1999/02/22-rdf-syntax-ns#Property",
+    "http://www.w3.org/2002/07/owl#ObjectProperty",
+    "http://www.w3.org/2002/07/owl#DatatypeProperty",
+    "http://www.w3.org/2002/07/owl#AnnotationProperty",
+    "http://www.w3.org/2002/07/owl#OntologyProperty",
+    "http://www.w3.org/2002/07/owl#FunctionalProperty",
+    "http://www.w3.org/2002/07/owl#InverseFunctionalProperty",
+    "http://www.w3.org/2002/07/owl#TransitiveProperty",
+    "http://www.w3.org/2002/07/owl#SymmetricProperty",
+    "http://www.w3.org/2002/07/owl#AsymmetricProperty",
+    "http://www.w3.org/2002/07/owl#ReflexiveProperty",
+    "http://www.w3.org/2002/07/owl#IrreflexiveProperty",
+];
+
+/// A class or property of a [`Vocabulary`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct VocabularyTerm {
+    pub local_name: String,
+    /// The English (or otherwise untagged) `rdfs:label`, if any.
+    pub label:      Option<String>,
+    /// The English (or otherwise untagged) `rdfs:comment`, if any.
+    pub comment:    Option<String>,
+}
+
+/// The classes and properties of one namespace of an ontology, ordered by
+/// local name.
+#[derive(Debug, Clone)]
+pub struct Vocabulary {
+    pub namespace:  Namespace,
+    pub classes:    Vec<VocabularyTerm>,
+    pub predicates: Vec<VocabularyTerm>,
+}
+
+/// What is known about a resource of the ontology.
+#[derive(Default)]
+struct Resource {
+    is_class:    bool,
+    is_property: bool,
+    labels:      Vec<SparqlLiteral>,
+    comments:    Vec<SparqlLiteral>,
+}
+
+impl Vocabulary {
+    /// Read the classes and properties in the given namespace from a
+    /// Turtle (or N-Triples) ontology. A resource is a class if it has
+    /// `rdfs:Class`, `rdfs:Datatype` or `owl:Class` as type, and a property
+    /// if it has `rdf:Property` or one of the OWL property types as type.
+    pub fn from_turtle(turtle: &str, namespace: &Namespace) -> Result<Self, RDFStoreError> {
+        let (_, triples) = parse_turtle(turtle)?;
+        let mut resources: BTreeMap<String, Resource> = BTreeMap::new();
+        for triple in triples {
+            let local_name = match &triple.subject {
+                TermPattern::Iri(SparqlIri::Iri(iri)) => {
+                    match namespace.local_name_of(iri.as_str()) {
+                        Some(local_name) if !local_name.is_empty() => local_name.to_string(),
+                        _ => continue,
+                    }
+                },
+                _ => continue,
+            };
+            let predicate = match &triple.predicate {
+                Verb::Path(PropertyPath::Iri(SparqlIri::Iri(predicate))) => predicate.as_str(),
+                _ => continue,
+            };
+            let resource = resources.entry(local_name).or_default();
+            match (predicate, triple.object) {
+                (RDF_TYPE, TermPattern::Iri(SparqlIri::Iri(type_iri))) => {
+                    resource.is_class |= CLASS_TYPES.contains(&type_iri.as_str());
+                    resource.is_property |= PROPERTY_TYPES.contains(&type_iri.as_str());
+                },
+                (RDFS_LABEL, TermPattern::Literal(label)) => resource.labels.push(label),
+                (RDFS_COMMENT, TermPattern::Literal(comment)) => resource.comments.push(comment),
+                _ => {},
+            }
+        }
+        let mut vocabulary = Self {
+            namespace:  namespace.clone(),
+            classes:    Vec::new(),
+            predicates: Vec::new(),
+        };
+        for (local_name, resource) in resources {
+            let term = VocabularyTerm {
+                local_name,
+                label: english_text(&resource.labels),
+                comment: english_text(&resource.comments),
+            };
+            if resource.is_class {
+                vocabulary.classes.push(term);
+            } else if resource.is_property {
+                vocabulary.predicates.push(term);
+            }
+        }
+        Ok(vocabulary)
+    }
+
+    /// The [`Class`]es of this vocabulary, which share one namespace.
+    pub fn classes(&self) -> impl Iterator<Item = Class> + '_ {
+        let namespace = Arc::new(self.namespace.clone());
+        self.classes
+            .iter()
+            .map(move |term| Class::declare(namespace.clone(), term.local_name.as_str()))
+    }
+
+    /// The [`Predicate`]s of this vocabulary, which share one namespace.
+    pub fn predicates(&self) -> impl Iterator<Item = Predicate> + '_ {
+        let namespace = Arc::new(self.namespace.clone());
+        self.predicates
+            .iter()
+            .map(move |term| Predicate::declare(namespace.clone(), term.local_name.as_str()))
+    }
+
+    /// The items of a Rust module with a constant for the namespace and for
+    /// each class and predicate of this vocabulary, which only needs
+    /// `rdf-store-rs` as a dependency.
+    pub fn to_rust(&self) -> String {
+        let mut rust = String::from("// Generated by rdf_store_rs::Vocabulary, do not edit.\n");
+        write_static(
+            &mut rust,
+            &[format!(
+                "The `{}` namespace `<{}>`.",
+                self.namespace.name,
+                self.namespace.iri.as_str()
+            )],
+            NAMESPACE,
+            "::std::sync::Arc<::rdf_store_rs::Namespace>",
+            format!(
+                "::std::sync::Arc::new(::rdf_store_rs::Namespace::declare_from_str_unchecked({:?}, {:?}))",
+                self.namespace.name,
+                self.namespace.iri.as_str()
+            )
+            .as_str(),
+        );
+        let (predicate_names, class_names) = self.constant_names();
+        for (term, name) in self.predicates.iter().zip(predicate_names) {
+            write_static(
+                &mut rust,
+                &self.doc(term, name.as_str()),
+                name.as_str(),
+                "::rdf_store_rs::Predicate",
+                format!(
+                    "::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), {:?})",
+                    term.local_name
+                )
+                .as_str(),
+            );
+        }
+        for (term, name) in self.classes.iter().zip(class_names) {
+            write_static(
+                &mut rust,
+                &self.doc(term, name.as_str()),
+                name.as_str(),
+                "::rdf_store_rs::Class",
+                format!(
+                    "::rdf_store_rs::Class::declare(NAMESPACE.clone(), {:?})",
+                    term.local_name
+                )
+                .as_str(),
+            );
+        }
+        rust
+    }
+
+    /// The lines of the doc comment of the constant of a term: its label
+    /// and comment, if any, and its prefixed name.
+    fn doc(&self, term: &VocabularyTerm, name: &str) -> Vec<String> {
+        let mut lines = Vec::new();
+        for text in [term.label.as_deref(), term.comment.as_deref()]
+            .into_iter()
+            .flatten()
+        {
+            lines.extend(text.lines().map(|line| line.trim().to_string()));
+            lines.push(String::new());
+        }
+        lines.push(format!(
+            "`{}{}` (`{name}`)",
+            self.namespace.name, term.local_name
+        ));
+        lines
+    }
+
+    /// The names of the constants of the predicates and of the classes: the
+    /// local names in upper snake case, with a `_PROPERTY` or `_CLASS`
+    /// suffix for a name that a predicate and a class (or the namespace)
+    /// would share, and a number for any other duplicate.
+    fn constant_names(&self) -> (Vec<String>, Vec<String>) {
+        let constant_names = |terms: &[VocabularyTerm]| {
+            terms
+                .iter()
+                .map(|term| constant_name(term.local_name.as_str()))
+                .collect::<Vec<_>>()
+        };
+        let predicate_names = constant_names(&self.predicates);
+        let class_names = constant_names(&self.classes);
+        let taken = predicate_names
+            .iter()
+            .chain(class_names.iter())
+            .cloned()
+            .collect::<HashSet<_>>();
+        let mut assigned = HashSet::from([NAMESPACE.to_string()]);
+        let mut assign = |name: &String, others: &[String], suffix: &str| {
+            let shared = name == NAMESPACE || others.contains(name);
+            let base = if shared {
+                format!("{name}{suffix}")
+            } else {
+                name.clone()
+            };
+            let mut unique = base.clone();
+            let mut count = 1;
+            while assigned.contains(&unique) || (&unique != name && taken.contains(&unique)) {
+                count += 1;
+                unique = format!("{base}_{count}");
+            }
+            assigned.insert(unique.clone());
+            unique
+        };
+        let predicates = predicate_names
+            .iter()
+            .map(|name| assign(name, &class_names, "_PROPERTY"))
+            .collect();
+        let classes = class_names
+            .iter()
+            .map(|name| assign(name, &predicate_names, "_CLASS"))
+            .collect();
+        (predicates, classes)
+    }
+}
+
+/// Generate the Rust module of the vocabulary of the given namespace in the
+/// given ontology in a build script: the module is written to
+/// `$OUT_DIR/<prefix>.rs`, where `<prefix>` is the name of the namespace
+/// without its `:`, and Cargo is told to run the build script again when
+/// the ontology changes. Returns the path of the module.
+pub fn generate_vocabulary(
+    ontology: impl AsRef<Path>,
+    namespace: &Namespace,
+) -> Result<PathBuf, RDFStoreError> {
+    let ontology = ontology.as_ref();
+    let out_dir = std::env::var_os("OUT_DIR").ok_or_else(|| {
+        RDFStoreError::Exception {
+            action:  "generating a vocabulary".to_string(),
+            message: "OUT_DIR is not set, this should be called from a build script".to_string(),
+        }
+    })?;
+    let vocabulary = Vocabulary::from_turtle(
+        std::fs::read_to_string(ontology)?.as_str(),
+        namespace,
+    )?;
+    let module = PathBuf::from(out_dir).join(format!(
+        "{}.rs",
+        namespace.name.trim_end_matches(':')
+    ));
+    std::fs::write(&module, vocabulary.to_rust())?;
+    println!("cargo:rerun-if-changed={}", ontology.display());
+    Ok(module)
+}
+
+/// The text of the English label (or comment), or of the one without a
+/// language tag, or else of the first one.
+fn english_text(literals: &[SparqlLiteral]) -> Option<String> {
+    let is_english = |literal: &&SparqlLiteral| {
+        literal.language.as_deref().map_or(false, |language| {
+            language.eq_ignore_ascii_case("en") || language.to_lowercase().starts_with("en-")
+        })
+    };
+    literals
+        .iter()
+        .find(is_english)
+        .or_else(|| literals.iter().find(|literal| literal.language.is_none()))
+        .or_else(|| literals.first())
+        .map(|literal| literal.lexical_form.clone())
+}
+
+/// The local name in upper snake case, such as `PREF_LABEL` for
+/// `prefLabel` and `HTTP_URL` for `httpURL`.
+fn constant_name(local_name: &str) -> String {
+    let chars = local_name.chars().collect::<Vec<_>>();
+    let mut name = String::with_capacity(local_name.len() + 4);
+    for (idx, c) in chars.iter().enumerate() {
+        if !c.is_alphanumeric() {
+            if !name.ends_with('_') {
+                name.push('_');
+            }
+            continue
+        }
+        if c.is_uppercase() && idx > 0 {
+            let previous = chars[idx - 1];
+            let next_is_lower = chars.get(idx + 1).map_or(false, |next| next.is_lowercase());
+            if (previous.is_lowercase() ||
+                previous.is_ascii_digit() ||
+                (previous.is_uppercase() && next_is_lower)) &&
+                !name.ends_with('_')
+            {
+                name.push('_');
+            }
+        }
+        name.extend(c.to_uppercase());
+    }
+    let name = name.trim_matches('_').to_string();
+    if name.is_empty() || name.starts_with(|c: char| c.is_ascii_digit()) {
+        format!("_{name}")
+    } else {
+        name
+    }
+}
+
+/// A `lazy_static!` block with one constant, since larger blocks soon reach
+/// the recursion limit of the macro.
+fn write_static(rust: &mut String, doc: &[String], name: &str, rust_type: &str, value: &str) {
+    writeln!(
+        rust,
+        "\n::rdf_store_rs::lazy_static::lazy_static! {{"
+    )
+    .unwrap();
+    for line in doc {
+        if line.is_empty() {
+            writeln!(rust, "    ///").unwrap();
+        } else {
+            writeln!(rust, "    /// {line}").unwrap();
+        }
+    }
+    writeln!(
+        rust,
+        "    pub static ref {name}: {rust_type} =\n        {value};\n}}"
+    )
+    .unwrap();
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::{constant_name, Vocabulary, VocabularyTerm},
+        crate::{Namespace, RDFStoreError},
+        indoc::indoc,
+    };
+
+    const ONTOLOGY: &str = indoc! {r#"
+        @prefix prov: <http://www.w3.org/ns/prov#> .
+        @prefix owl: <http://www.w3.org/2002/07/owl#> .
+        @prefix rdfs: <http://www.w3.org/2000/01/rdf-schema#> .
+
+        prov:Agent a owl:Class ;
+            rdfs:label "Agent"@en, "Agent"@de ;
+            rdfs:comment """An agent is something that bears some form
+                of responsibility for an activity."""@en .
+        prov:agent a owl:ObjectProperty ;
+            rdfs:label "agent" .
+        prov:wasGeneratedBy a owl:ObjectProperty, owl:FunctionalProperty .
+        <http://www.w3.org/ns/prov#> a owl:Ontology .
+        rdfs:label a owl:AnnotationProperty .
+    "#};
+
+    fn prov() -> Namespace {
+        Namespace::declare_from_str_unchecked("prov:", "http://www.w3.org/ns/prov#")
+    }
+
+    #[test_log::test]
+    fn test_vocabulary() -> Result<(), RDFStoreError> {
+        let vocabulary = Vocabulary::from_turtle(ONTOLOGY, &prov())?;
+        let classes = vocabulary
+            .classes()
+            .map(|class| class.to_string())
+            .collect::<Vec<_>>();
+        assert_eq!(classes, vec!["prov:Agent"]);
+        let predicates = vocabulary
+            .predicates()
+            .map(|predicate| predicate.display_turtle().to_string())
+            .collect::<Vec<_>>();
+        assert_eq!(predicates, vec![
+            "prov:agent",
+            "prov:wasGeneratedBy"
+        ]);
+        assert_eq!(
+            vocabulary.classes[0].label.as_deref(),
+            Some("Agent")
+        );
+        assert_eq!(
+            vocabulary.predicates[0].label.as_deref(),
+            Some("agent")
+        );
+        assert_eq!(vocabulary.predicates[1].label, None);
+
+        let xsd = Vocabulary::from_turtle(
+            indoc! {r#"
+                @prefix xsd: <http://www.w3.org/2001/XMLSchema#> .
+                @prefix rdfs: <http://www.w3.org/2000/01/rdf-schema#> .
+
+                xsd:string a rdfs:Datatype ;
+                    rdfs:label "string" .
+            "#},
+            &Namespace::declare_from_str_unchecked("xsd:", "http://www.w3.org/2001/XMLSchema#"),
+        )?;
+        let classes = xsd
+            .classes()
+            .map(|class| class.to_string())
+            .collect::<Vec<_>>();
+        assert_eq!(classes, vec!["xsd:string"]);
+        Ok(())
+    }
+
+    #[test_log::test]
+    fn test_to_rust() -> Result<(), RDFStoreError> {
+        let rust = Vocabulary::from_turtle(ONTOLOGY, &prov())?
+            .to_rust()
+            .lines()
+            .map(str::trim)
+            .collect::<Vec<_>>()
+            .join("\n");
+
+        assert!(rust.contains(indoc! {r#"
+                /// Agent
+                ///
+                /// An agent is something that bears some form
+                /// of responsibility for an activity.
+                ///
+                /// `prov:Agent` (`AGENT_CLASS`)
+                pub static ref AGENT_CLASS: ::rdf_store_rs::Class =
+                ::rdf_store_rs::Class::declare(NAMESPACE.clone(), "Agent");
+        "#}));
+        assert!(rust.contains(indoc! {r#"
+                /// `prov:wasGeneratedBy` (`WAS_GENERATED_BY`)
+                pub static ref WAS_GENERATED_BY: ::rdf_store_rs::Predicate =
+                ::rdf_store_rs::Predicate::declare(NAMESPACE.clone(), "wasGeneratedBy");
+        "#}));
+        assert!(rust.contains("pub static ref AGENT_PROPERTY: ::rdf_store_rs::Predicate ="));
+        Ok(())
+    }
+
+    #[test_log::test]
+    fn test_constant_names() {
+        let terms = |local_names: &[&str]| {
+            local_names
+                .iter()
+                .map(|local_name| {
+                    VocabularyTerm {
+                        local_name: local_name.to_string(),
+                        label:      None,
+                        comment:    None,
+                    }
+                })
+                .collect::<Vec<_>>()
+        };
+        let vocabulary = Vocabulary {
+            namespace:  Namespace::declare_from_str_unchecked("sh:", "http://www.w3.org/ns/shacl#"),
+            classes:    terms(&["Target", "TargetType"]),
+            predicates: terms(&[
+                "namespace",
+                "target",
+                "targetClass",
+                "targetType",
+                "target_type",
+            ]),
+        };
+        let (predicates, classes) = vocabulary.constant_names();
+        assert_eq!(predicates, vec![
+            "NAMESPACE_PROPERTY",
+            "TARGET_PROPERTY",
+            "TARGET_CLASS",
+            "TARGET_TYPE_PROPERTY",
+            "TARGET_TYPE_PROPERTY_2",
+        ]);
+        assert_eq!(classes, vec![
+            "TARGET_CLASS_2",
+            "TARGET_TYPE_CLASS"
+        ]);
+    }
+
+    #[test_log::test]
+    fn test_constant_name() {
+        for (local_name, name) in [
+            ("type", "TYPE"),
+            ("prefLabel", "PREF_LABEL"),
+            ("Person", "PERSON"),
+            ("httpURL", "HTTP_URL"),
+            ("URLPattern", "URL_PATTERN"),
+            ("has-part", "HAS_PART"),
+            ("sha256Hash", "SHA256_HASH"),
+            ("_1", "_1"),
+        ] {
+            assert_eq!(constant_name(local_name), name, "{local_name}");
+        }
+    }
+}